@@ -0,0 +1,189 @@
+//! Sidechain ducker - an effect-style example of an optional sidechain input
+//!
+//! The main input passes through, ducked by the level of the sidechain. The sidechain
+//! port is declared with `PortConfig::EffectWithSidechain`, so hosts with nothing routed
+//! to it can deactivate it (CLAP audio-ports-activation); the ducker then passes audio
+//! through untouched without reading a sidechain buffer.
+
+use dsynth_clap::*;
+use std::ffi::CString;
+use std::sync::OnceLock;
+
+/// Gain reduction at full sidechain level (0.0 = none, 1.0 = silence)
+const DUCK_DEPTH: f32 = 0.8;
+/// Envelope follower release time
+const RELEASE_MS: f32 = 150.0;
+
+// ============================================================================
+// PLUGIN
+// ============================================================================
+
+pub struct SidechainDucker;
+
+impl ClapPlugin for SidechainDucker {
+    type Processor = DuckerProcessor;
+    type Params = DuckerParams;
+
+    fn descriptor() -> PluginDescriptor {
+        PluginDescriptor::effect("Sidechain Ducker", "com.dsynth.sidechain-ducker")
+            .version("0.1.0")
+            .description("Ducks the input by the level of an optional sidechain")
+            .audio_ports(PortConfig::EffectWithSidechain)
+    }
+
+    fn clap_descriptor() -> &'static clap_sys::plugin::clap_plugin_descriptor {
+        use clap_sys::plugin::clap_plugin_descriptor;
+
+        struct Descriptor {
+            clap: clap_plugin_descriptor,
+            _strings: [CString; 6],
+            _features: Box<[*const std::os::raw::c_char; 3]>,
+        }
+
+        unsafe impl Sync for Descriptor {}
+        unsafe impl Send for Descriptor {}
+
+        static DESCRIPTOR: OnceLock<Descriptor> = OnceLock::new();
+        &DESCRIPTOR
+            .get_or_init(|| {
+                let strings = [
+                    "com.dsynth.sidechain-ducker",
+                    "Sidechain Ducker",
+                    "DSynth",
+                    "0.1.0",
+                    "Ducks the input by the level of an optional sidechain",
+                    "audio-effect",
+                ]
+                .map(|s| CString::new(s).unwrap());
+                let features =
+                    Box::new([strings[5].as_ptr(), c"mixing".as_ptr(), std::ptr::null()]);
+                Descriptor {
+                    clap: clap_plugin_descriptor {
+                        clap_version: clap_sys::version::CLAP_VERSION,
+                        id: strings[0].as_ptr(),
+                        name: strings[1].as_ptr(),
+                        vendor: strings[2].as_ptr(),
+                        url: std::ptr::null(),
+                        manual_url: std::ptr::null(),
+                        support_url: std::ptr::null(),
+                        version: strings[3].as_ptr(),
+                        description: strings[4].as_ptr(),
+                        features: features.as_ptr(),
+                    },
+                    _strings: strings,
+                    _features: features,
+                }
+            })
+            .clap
+    }
+
+    fn new() -> Self {
+        Self
+    }
+
+    fn create_processor(&mut self, sample_rate: f32) -> Self::Processor {
+        DuckerProcessor::new(sample_rate)
+    }
+}
+
+// ============================================================================
+// PROCESSOR
+// ============================================================================
+
+pub struct DuckerProcessor {
+    envelope: f32,
+    release_coeff: f32,
+}
+
+impl DuckerProcessor {
+    fn new(sample_rate: f32) -> Self {
+        Self {
+            envelope: 0.0,
+            release_coeff: Self::release_coeff(sample_rate),
+        }
+    }
+
+    fn release_coeff(sample_rate: f32) -> f32 {
+        (-1.0 / (RELEASE_MS * 0.001 * sample_rate)).exp()
+    }
+}
+
+impl ClapProcessor for DuckerProcessor {
+    fn process(&mut self, audio: &mut AudioBuffers, _events: &Events) -> ProcessStatus {
+        unsafe {
+            let frames = audio.frames_count() as usize;
+            // `None` when the host deactivated the sidechain (or left it unconnected):
+            // audio then passes through. Keep raw pointers so the output can be borrowed.
+            let sidechain = audio.input_stereo(1).map(|(l, r)| (l.as_ptr(), r.as_ptr()));
+            if sidechain.is_none() {
+                self.envelope = 0.0;
+            }
+
+            if let Some((in_l, in_r, out_l, out_r)) = audio.io_stereo_mut(0, 0) {
+                for i in 0..frames {
+                    let gain = match sidechain {
+                        Some((side_l, side_r)) => {
+                            let level = (*side_l.add(i)).abs().max((*side_r.add(i)).abs());
+                            self.envelope = level.max(self.envelope * self.release_coeff);
+                            1.0 - DUCK_DEPTH * self.envelope.min(1.0)
+                        }
+                        None => 1.0,
+                    };
+                    // Read before writing: the host may process in place
+                    let (l, r) = (in_l[i], in_r[i]);
+                    out_l[i] = l * gain;
+                    out_r[i] = r * gain;
+                }
+            }
+        }
+
+        ProcessStatus::Continue
+    }
+
+    fn activate(&mut self, sample_rate: f32) {
+        self.release_coeff = Self::release_coeff(sample_rate);
+        self.envelope = 0.0;
+    }
+
+    fn reset(&mut self) {
+        self.envelope = 0.0;
+    }
+}
+
+// ============================================================================
+// PARAMETERS
+// ============================================================================
+
+pub struct DuckerParams;
+
+impl PluginParams for DuckerParams {
+    fn param_count() -> u32 {
+        0
+    }
+
+    fn param_descriptor(_index: u32) -> Option<ParamDescriptor> {
+        None
+    }
+
+    fn param_descriptor_by_id(_id: ParamId) -> Option<ParamDescriptor> {
+        None
+    }
+
+    fn get_param(_id: ParamId) -> Option<f32> {
+        None
+    }
+
+    fn set_param(_id: ParamId, _value: f32) {}
+
+    fn save_state() -> PluginState {
+        PluginState::default()
+    }
+
+    fn load_state(_state: &PluginState) {}
+}
+
+// ============================================================================
+// CLAP ENTRY POINT
+// ============================================================================
+
+dsynth_clap::generate_clap_entry!(SidechainDucker);
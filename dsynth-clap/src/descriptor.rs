@@ -1,6 +1,6 @@
 //! Plugin descriptor and metadata
 
-use crate::{NotePortConfig, PortConfig};
+use crate::{NoteDialects, NotePortConfig, PortConfig};
 
 /// Plugin descriptor containing metadata and configuration
 #[derive(Debug, Clone)]
@@ -14,6 +14,10 @@ pub struct PluginDescriptor {
     pub features: Vec<String>,
     pub audio_ports: PortConfig,
     pub note_ports: NotePortConfig,
+    /// Note dialects accepted by every note port
+    pub supported_note_dialects: NoteDialects,
+    /// Dialect the plugin would like the host to send (should be one of the supported ones)
+    pub preferred_note_dialect: NoteDialects,
 }
 
 impl PluginDescriptor {
@@ -29,6 +33,8 @@ impl PluginDescriptor {
             features: vec!["instrument".to_string()],
             audio_ports: PortConfig::Instrument,
             note_ports: NotePortConfig::Input,
+            supported_note_dialects: NoteDialects::default(),
+            preferred_note_dialect: NoteDialects::MIDI,
         }
     }
 
@@ -44,6 +50,8 @@ impl PluginDescriptor {
             features: vec!["audio-effect".to_string()],
             audio_ports: PortConfig::Effect,
             note_ports: NotePortConfig::None,
+            supported_note_dialects: NoteDialects::default(),
+            preferred_note_dialect: NoteDialects::MIDI,
         }
     }
 
@@ -77,6 +85,16 @@ impl PluginDescriptor {
         self
     }
 
+    /// Set the note dialects supported by the note ports and the preferred one
+    ///
+    /// If `preferred` is not part of `supported`, the note ports extension falls back to
+    /// the first supported dialect.
+    pub fn note_dialects(mut self, supported: NoteDialects, preferred: NoteDialects) -> Self {
+        self.supported_note_dialects = supported;
+        self.preferred_note_dialect = preferred;
+        self
+    }
+
     /// Set the vendor name
     pub fn vendor(mut self, vendor: impl Into<String>) -> Self {
         self.vendor = vendor.into();
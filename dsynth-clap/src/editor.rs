@@ -0,0 +1,97 @@
+//! Embedded plugin editors (the managed side of CLAP_EXT_GUI)
+//!
+//! A plugin returns a `ClapEditor` from `ClapPlugin::create_editor` once the host hands over
+//! its parent window; the framework keeps it alive, forwards show/hide/resize/scale calls, and
+//! drops it when the host destroys the GUI. Dropping the editor must close its window.
+
+use clap_sys::ext::gui::{
+    clap_window, CLAP_WINDOW_API_COCOA, CLAP_WINDOW_API_WIN32, CLAP_WINDOW_API_X11,
+};
+use std::ffi::CStr;
+use std::os::raw::{c_ulong, c_void};
+
+/// Host window an editor embeds into
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParentWindow {
+    /// X11 window id (Linux)
+    X11(c_ulong),
+    /// `NSView` pointer (macOS)
+    Cocoa(*mut c_void),
+    /// `HWND` (Windows)
+    Win32(*mut c_void),
+}
+
+impl ParentWindow {
+    /// Window API editors embed into on this platform
+    pub fn platform_api() -> &'static CStr {
+        if cfg!(target_os = "macos") {
+            CLAP_WINDOW_API_COCOA
+        } else if cfg!(target_os = "windows") {
+            CLAP_WINDOW_API_WIN32
+        } else {
+            CLAP_WINDOW_API_X11
+        }
+    }
+
+    /// Read the handle out of a host `clap_window`; `None` for null or unsupported APIs
+    ///
+    /// # Safety
+    /// `window` must be null or point to a valid `clap_window` whose `api` is a valid C string.
+    pub unsafe fn from_clap(window: *const clap_window) -> Option<Self> {
+        if window.is_null() || (*window).api.is_null() {
+            return None;
+        }
+        let window = &*window;
+        let api = CStr::from_ptr(window.api);
+        if api == CLAP_WINDOW_API_X11 {
+            Some(ParentWindow::X11(window.specific.x11))
+        } else if api == CLAP_WINDOW_API_COCOA {
+            Some(ParentWindow::Cocoa(window.specific.cocoa))
+        } else if api == CLAP_WINDOW_API_WIN32 {
+            Some(ParentWindow::Win32(window.specific.win32))
+        } else {
+            None
+        }
+    }
+}
+
+/// An open editor window, embedded in the host's parent window
+///
+/// Only `size` is required; the rest default to a fixed-size editor that is always visible.
+pub trait ClapEditor {
+    /// Current size in pixels
+    fn size(&self) -> (u32, u32);
+
+    /// Whether the host may resize the editor
+    fn can_resize(&self) -> bool {
+        false
+    }
+
+    /// Apply a size chosen by the host; return false to refuse
+    fn set_size(&mut self, _width: u32, _height: u32) -> bool {
+        false
+    }
+
+    /// Apply the host's content scale (1.0 = 100%); return false if it's ignored
+    fn set_scale(&mut self, _scale: f64) -> bool {
+        false
+    }
+
+    /// Host made the editor visible
+    fn show(&mut self) -> bool {
+        true
+    }
+
+    /// Host hid the editor (it may be shown again later)
+    fn hide(&mut self) -> bool {
+        true
+    }
+}
+
+/// Per-instance editor state for plugins using `create_editor`
+#[derive(Default)]
+pub(crate) struct EditorSlot {
+    pub(crate) editor: Option<Box<dyn ClapEditor>>,
+    /// Last scale the host set, applied to editors created afterwards
+    pub(crate) scale: Option<f64>,
+}
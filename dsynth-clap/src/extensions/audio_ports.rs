@@ -22,28 +22,7 @@ unsafe extern "C" fn audio_ports_count<P: ClapPlugin>(
     _plugin: *const clap_sys::plugin::clap_plugin,
     is_input: bool,
 ) -> u32 {
-    let descriptor = P::descriptor();
-    match descriptor.audio_ports {
-        PortConfig::Instrument => {
-            // Instrument: 0 inputs, 1 stereo output
-            if is_input {
-                0
-            } else {
-                1
-            }
-        }
-        PortConfig::Effect => {
-            // Effect: 1 stereo input, 1 stereo output
-            1
-        }
-        PortConfig::Custom { inputs, outputs } => {
-            if is_input {
-                inputs
-            } else {
-                outputs
-            }
-        }
-    }
+    P::descriptor().audio_ports.port_count(is_input)
 }
 
 unsafe extern "C" fn audio_ports_get<P: ClapPlugin>(
@@ -57,23 +36,7 @@ unsafe extern "C" fn audio_ports_get<P: ClapPlugin>(
     }
 
     let descriptor = P::descriptor();
-    let count = match descriptor.audio_ports {
-        PortConfig::Instrument => {
-            if is_input {
-                0
-            } else {
-                1
-            }
-        }
-        PortConfig::Effect => 1,
-        PortConfig::Custom { inputs, outputs } => {
-            if is_input {
-                inputs
-            } else {
-                outputs
-            }
-        }
-    };
+    let count = descriptor.audio_ports.port_count(is_input);
 
     if index >= count {
         return false;
@@ -89,7 +52,10 @@ unsafe extern "C" fn audio_ports_get<P: ClapPlugin>(
     };
 
     // Set port name
-    let name = if is_input {
+    let optional = descriptor.audio_ports.is_optional(is_input, index);
+    let name = if optional {
+        "Sidechain\0".to_string()
+    } else if is_input {
         format!("Input {}\0", index + 1)
     } else {
         format!("Output {}\0", index + 1)
@@ -105,7 +71,8 @@ unsafe extern "C" fn audio_ports_get<P: ClapPlugin>(
 
     // Set stereo configuration
     info.channel_count = 2;
-    info.flags = CLAP_AUDIO_PORT_IS_MAIN;
+    // Optional ports (sidechains) are auxiliary, never the main port
+    info.flags = if optional { 0 } else { CLAP_AUDIO_PORT_IS_MAIN };
 
     // Set port type to stereo (pointer to static C string)
     info.port_type = STEREO_PORT_TYPE.as_ptr();
@@ -114,8 +81,8 @@ unsafe extern "C" fn audio_ports_get<P: ClapPlugin>(
     // For effects: output port pairs with corresponding input port
     // For instruments: no input ports, so no in-place processing
     match descriptor.audio_ports {
-        PortConfig::Effect => {
-            // Effect: output port 0 pairs with input port 0
+        PortConfig::Effect | PortConfig::EffectWithSidechain => {
+            // Effect: output port 0 pairs with input port 0 (never the sidechain)
             if is_input {
                 // Input ports: no in-place pair (they reference outputs, not vice versa)
                 info.in_place_pair = CLAP_INVALID_ID;
@@ -0,0 +1,32 @@
+//! CLAP audio-ports-activation extension implementation
+
+use crate::{instance::PluginInstance, plugin::ClapPlugin};
+use clap_sys::ext::audio_ports_activation::*;
+use std::sync::OnceLock;
+
+/// Get the audio-ports-activation extension for a plugin type
+pub fn get_extension<P: ClapPlugin>() -> &'static clap_plugin_audio_ports_activation {
+    static EXT: OnceLock<clap_plugin_audio_ports_activation> = OnceLock::new();
+    EXT.get_or_init(|| clap_plugin_audio_ports_activation {
+        can_activate_while_processing: Some(can_activate_while_processing::<P>),
+        set_active: Some(set_active::<P>),
+    })
+}
+
+unsafe extern "C" fn can_activate_while_processing<P: ClapPlugin>(
+    _plugin: *const clap_sys::plugin::clap_plugin,
+) -> bool {
+    // Port buffers are only reconfigured while deactivated
+    false
+}
+
+unsafe extern "C" fn set_active<P: ClapPlugin>(
+    plugin: *const clap_sys::plugin::clap_plugin,
+    is_input: bool,
+    port_index: u32,
+    is_active: bool,
+    _sample_size: u32,
+) -> bool {
+    let instance = PluginInstance::<P>::from_ptr_mut(plugin);
+    instance.set_port_active(is_input, port_index, is_active)
+}
@@ -1,13 +1,17 @@
 //! CLAP GUI extension implementation (CLAP_EXT_GUI)
+//!
+//! Plugins with `has_gui()` get every call forwarded to their raw `gui_*` hooks. Otherwise the
+//! framework drives the editor from `ClapPlugin::create_editor` (see `crate::editor`).
 
-use crate::{instance::PluginInstance, plugin::ClapPlugin};
+use crate::{editor::ParentWindow, instance::PluginInstance, plugin::ClapPlugin};
 use clap_sys::ext::gui::*;
 use std::ffi::CStr;
 use std::sync::OnceLock;
 
 /// Get the GUI extension for a plugin type.
 ///
-/// Plugins can opt-in by overriding `ClapPlugin::has_gui()` and the `gui_*` hooks.
+/// Plugins opt in by implementing `ClapPlugin::editor_size()` + `create_editor()`, or by
+/// overriding `ClapPlugin::has_gui()` and the raw `gui_*` hooks.
 pub fn get_extension<P: ClapPlugin>() -> &'static clap_plugin_gui {
     static EXT: OnceLock<clap_plugin_gui> = OnceLock::new();
     EXT.get_or_init(|| clap_plugin_gui {
@@ -37,9 +41,6 @@ unsafe extern "C" fn gui_is_api_supported<P: ClapPlugin>(
     if plugin.is_null() || api.is_null() {
         return false;
     }
-    if !P::has_gui() {
-        return false;
-    }
 
     let api_str = match CStr::from_ptr(api).to_str() {
         Ok(_) => CStr::from_ptr(api),
@@ -49,7 +50,10 @@ unsafe extern "C" fn gui_is_api_supported<P: ClapPlugin>(
     // SAFETY: This is a read-only query, but gui_is_api_supported takes &mut self
     // in the trait. We use from_ptr_mut to match the trait signature.
     let instance = PluginInstance::<P>::from_ptr_mut(plugin);
-    instance.plugin.gui_is_api_supported(api_str, is_floating)
+    if P::has_gui() {
+        return instance.plugin.gui_is_api_supported(api_str, is_floating);
+    }
+    has_editor(instance) && !is_floating && api_str == ParentWindow::platform_api()
 }
 
 unsafe extern "C" fn gui_get_preferred_api<P: ClapPlugin>(
@@ -60,12 +64,17 @@ unsafe extern "C" fn gui_get_preferred_api<P: ClapPlugin>(
     if plugin.is_null() || api.is_null() || is_floating.is_null() {
         return false;
     }
-    if !P::has_gui() {
-        return false;
-    }
 
     let instance = PluginInstance::<P>::from_ptr_mut(plugin);
-    instance.plugin.gui_get_preferred_api(api, is_floating)
+    if P::has_gui() {
+        return instance.plugin.gui_get_preferred_api(api, is_floating);
+    }
+    if !has_editor(instance) {
+        return false;
+    }
+    *api = ParentWindow::platform_api().as_ptr();
+    *is_floating = false;
+    true
 }
 
 unsafe extern "C" fn gui_create<P: ClapPlugin>(
@@ -76,25 +85,27 @@ unsafe extern "C" fn gui_create<P: ClapPlugin>(
     if plugin.is_null() || api.is_null() {
         return false;
     }
-    if !P::has_gui() {
-        return false;
-    }
 
     let api_str = CStr::from_ptr(api);
     let instance = PluginInstance::<P>::from_ptr_mut(plugin);
-    instance.plugin.gui_create(api_str, is_floating)
+    if P::has_gui() {
+        return instance.plugin.gui_create(api_str, is_floating);
+    }
+    // The editor itself is only opened once the host provides the parent window
+    has_editor(instance) && !is_floating && api_str == ParentWindow::platform_api()
 }
 
 unsafe extern "C" fn gui_destroy<P: ClapPlugin>(plugin: *const clap_sys::plugin::clap_plugin) {
     if plugin.is_null() {
         return;
     }
-    if !P::has_gui() {
-        return;
-    }
 
     let instance = PluginInstance::<P>::from_ptr_mut(plugin);
-    instance.plugin.gui_destroy();
+    if P::has_gui() {
+        instance.plugin.gui_destroy();
+    } else {
+        instance.editor.editor = None;
+    }
 }
 
 unsafe extern "C" fn gui_set_scale<P: ClapPlugin>(
@@ -104,12 +115,16 @@ unsafe extern "C" fn gui_set_scale<P: ClapPlugin>(
     if plugin.is_null() {
         return false;
     }
-    if !P::has_gui() {
-        return false;
-    }
 
     let instance = PluginInstance::<P>::from_ptr_mut(plugin);
-    instance.plugin.gui_set_scale(scale)
+    if P::has_gui() {
+        return instance.plugin.gui_set_scale(scale);
+    }
+    instance.editor.scale = Some(scale);
+    match instance.editor.editor.as_mut() {
+        Some(editor) => editor.set_scale(scale),
+        None => has_editor(instance),
+    }
 }
 
 unsafe extern "C" fn gui_get_size<P: ClapPlugin>(
@@ -120,12 +135,23 @@ unsafe extern "C" fn gui_get_size<P: ClapPlugin>(
     if plugin.is_null() || width.is_null() || height.is_null() {
         return false;
     }
-    if !P::has_gui() {
-        return false;
-    }
 
     let instance = PluginInstance::<P>::from_ptr_mut(plugin);
-    instance.plugin.gui_get_size(width, height)
+    if P::has_gui() {
+        return instance.plugin.gui_get_size(width, height);
+    }
+    let size = match instance.editor.editor.as_ref() {
+        Some(editor) => Some(editor.size()),
+        None => instance.plugin.editor_size(),
+    };
+    match size {
+        Some((w, h)) => {
+            *width = w;
+            *height = h;
+            true
+        }
+        None => false,
+    }
 }
 
 unsafe extern "C" fn gui_can_resize<P: ClapPlugin>(
@@ -134,14 +160,18 @@ unsafe extern "C" fn gui_can_resize<P: ClapPlugin>(
     if plugin.is_null() {
         return false;
     }
-    if !P::has_gui() {
-        return false;
-    }
 
     // SAFETY: This is a read-only query, but gui_can_resize takes &mut self
     // in the trait. We use from_ptr_mut to match the trait signature.
     let instance = PluginInstance::<P>::from_ptr_mut(plugin);
-    instance.plugin.gui_can_resize()
+    if P::has_gui() {
+        return instance.plugin.gui_can_resize();
+    }
+    instance
+        .editor
+        .editor
+        .as_ref()
+        .is_some_and(|editor| editor.can_resize())
 }
 
 unsafe extern "C" fn gui_set_size<P: ClapPlugin>(
@@ -152,12 +182,16 @@ unsafe extern "C" fn gui_set_size<P: ClapPlugin>(
     if plugin.is_null() {
         return false;
     }
-    if !P::has_gui() {
-        return false;
-    }
 
     let instance = PluginInstance::<P>::from_ptr_mut(plugin);
-    instance.plugin.gui_set_size(width, height)
+    if P::has_gui() {
+        return instance.plugin.gui_set_size(width, height);
+    }
+    instance
+        .editor
+        .editor
+        .as_mut()
+        .is_some_and(|editor| editor.set_size(width, height))
 }
 
 unsafe extern "C" fn gui_set_parent<P: ClapPlugin>(
@@ -167,34 +201,63 @@ unsafe extern "C" fn gui_set_parent<P: ClapPlugin>(
     if plugin.is_null() {
         return false;
     }
-    if !P::has_gui() {
+
+    let instance = PluginInstance::<P>::from_ptr_mut(plugin);
+    if P::has_gui() {
+        return instance.plugin.gui_set_parent(window);
+    }
+    if !has_editor(instance) {
         return false;
     }
 
-    let instance = PluginInstance::<P>::from_ptr_mut(plugin);
-    instance.plugin.gui_set_parent(window)
+    // Close any editor open in the previous parent before opening the new one
+    instance.editor.editor = None;
+    let Some(parent) = ParentWindow::from_clap(window) else {
+        return false;
+    };
+    let Some(mut editor) = instance.plugin.create_editor(parent) else {
+        return false;
+    };
+    if let Some(scale) = instance.editor.scale {
+        editor.set_scale(scale);
+    }
+    instance.editor.editor = Some(editor);
+    true
 }
 
 unsafe extern "C" fn gui_show<P: ClapPlugin>(plugin: *const clap_sys::plugin::clap_plugin) -> bool {
     if plugin.is_null() {
         return false;
     }
-    if !P::has_gui() {
-        return false;
-    }
 
     let instance = PluginInstance::<P>::from_ptr_mut(plugin);
-    instance.plugin.gui_show()
+    if P::has_gui() {
+        return instance.plugin.gui_show();
+    }
+    instance
+        .editor
+        .editor
+        .as_mut()
+        .is_some_and(|editor| editor.show())
 }
 
 unsafe extern "C" fn gui_hide<P: ClapPlugin>(plugin: *const clap_sys::plugin::clap_plugin) -> bool {
     if plugin.is_null() {
         return false;
     }
-    if !P::has_gui() {
-        return false;
-    }
 
     let instance = PluginInstance::<P>::from_ptr_mut(plugin);
-    instance.plugin.gui_hide()
+    if P::has_gui() {
+        return instance.plugin.gui_hide();
+    }
+    instance
+        .editor
+        .editor
+        .as_mut()
+        .is_some_and(|editor| editor.hide())
+}
+
+/// Does the plugin use the managed `create_editor` path?
+fn has_editor<P: ClapPlugin>(instance: &PluginInstance<P>) -> bool {
+    instance.plugin.editor_size().is_some()
 }
@@ -0,0 +1,20 @@
+//! CLAP latency extension implementation
+
+use crate::{instance::PluginInstance, plugin::ClapPlugin};
+use clap_sys::ext::latency::*;
+use std::sync::OnceLock;
+
+/// Get the latency extension for a plugin type
+pub fn get_extension<P: ClapPlugin>() -> &'static clap_plugin_latency {
+    static EXT: OnceLock<clap_plugin_latency> = OnceLock::new();
+    EXT.get_or_init(|| clap_plugin_latency {
+        get: Some(latency_get::<P>),
+    })
+}
+
+unsafe extern "C" fn latency_get<P: ClapPlugin>(
+    plugin: *const clap_sys::plugin::clap_plugin,
+) -> u32 {
+    let instance = PluginInstance::<P>::from_ptr(plugin);
+    instance.latency()
+}
@@ -1,7 +1,11 @@
 //! CLAP extension implementations
 
 pub mod audio_ports;
+pub mod audio_ports_activation;
 pub mod gui;
+pub mod latency;
 pub mod note_ports;
 pub mod params;
+pub mod remote_controls;
 pub mod state;
+pub mod tail;
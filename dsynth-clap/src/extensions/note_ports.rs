@@ -1,6 +1,6 @@
 //! CLAP note ports extension implementation
 
-use crate::{plugin::ClapPlugin, NotePortConfig};
+use crate::{plugin::ClapPlugin, NoteDialects, NotePortConfig};
 use clap_sys::ext::note_ports::*;
 use clap_sys::string_sizes::CLAP_NAME_SIZE;
 use std::sync::OnceLock;
@@ -96,11 +96,23 @@ unsafe extern "C" fn note_ports_get<P: ClapPlugin>(
     );
     info.name[copy_len] = 0;
 
-    // Support MIDI, note expressions, and per-note automation
-    info.supported_dialects =
-        CLAP_NOTE_DIALECT_MIDI | CLAP_NOTE_DIALECT_CLAP | CLAP_NOTE_DIALECT_MIDI_MPE;
-
-    info.preferred_dialect = CLAP_NOTE_DIALECT_MIDI;
+    // Dialects come from the descriptor (default: CLAP + MIDI)
+    info.supported_dialects = descriptor.supported_note_dialects.bits();
+    info.preferred_dialect = preferred_dialect(
+        descriptor.supported_note_dialects,
+        descriptor.preferred_note_dialect,
+    );
 
     true
 }
+
+/// Resolve the preferred dialect, falling back to the lowest supported dialect bit
+/// when the descriptor's preference isn't actually supported.
+fn preferred_dialect(supported: NoteDialects, preferred: NoteDialects) -> u32 {
+    if !preferred.is_empty() && supported.contains(preferred) {
+        preferred.bits()
+    } else {
+        let bits = supported.bits();
+        bits & bits.wrapping_neg()
+    }
+}
@@ -28,7 +28,7 @@ unsafe extern "C" fn params_count<P: ClapPlugin>(
 }
 
 unsafe extern "C" fn params_get_info<P: ClapPlugin>(
-    plugin: *const clap_sys::plugin::clap_plugin,
+    _plugin: *const clap_sys::plugin::clap_plugin,
     param_index: u32,
     param_info: *mut clap_param_info,
 ) -> bool {
@@ -36,8 +36,6 @@ unsafe extern "C" fn params_get_info<P: ClapPlugin>(
         return false;
     }
 
-    let _instance = PluginInstance::<P>::from_ptr(plugin);
-
     if let Some(descriptor) = P::Params::param_descriptor(param_index) {
         let info = &mut *param_info;
 
@@ -55,6 +53,12 @@ unsafe extern "C" fn params_get_info<P: ClapPlugin>(
         if descriptor.is_stepped() {
             info.flags |= CLAP_PARAM_IS_STEPPED;
         }
+        if descriptor.is_modulatable {
+            info.flags |= CLAP_PARAM_IS_MODULATABLE;
+        }
+        if descriptor.is_modulatable_per_note {
+            info.flags |= CLAP_PARAM_IS_MODULATABLE_PER_NOTE_ID | CLAP_PARAM_IS_MODULATABLE_PER_KEY;
+        }
         // clap-sys 0.3.0 does not expose boolean/enum flags, so we only
         // use CLAP_PARAM_IS_STEPPED to indicate discrete params.
 
@@ -68,7 +72,7 @@ unsafe extern "C" fn params_get_info<P: ClapPlugin>(
         );
         info.name[copy_len] = 0;
 
-        // Copy module
+        // Copy module ("/"-separated path; hosts show it as a tree)
         let module_bytes = descriptor.module.as_bytes();
         let copy_len = module_bytes.len().min(CLAP_PATH_SIZE - 1);
         std::ptr::copy_nonoverlapping(
@@ -0,0 +1,57 @@
+//! CLAP remote-controls extension implementation
+
+use crate::plugin::ClapPlugin;
+use clap_sys::ext::remote_controls::*;
+use clap_sys::id::CLAP_INVALID_ID;
+use clap_sys::string_sizes::CLAP_NAME_SIZE;
+use std::os::raw::c_char;
+use std::sync::OnceLock;
+
+/// Get the remote-controls extension for a plugin type
+pub fn get_extension<P: ClapPlugin>() -> &'static clap_plugin_remote_controls {
+    static EXT: OnceLock<clap_plugin_remote_controls> = OnceLock::new();
+    EXT.get_or_init(|| clap_plugin_remote_controls {
+        count: Some(remote_controls_count::<P>),
+        get: Some(remote_controls_get::<P>),
+    })
+}
+
+unsafe extern "C" fn remote_controls_count<P: ClapPlugin>(
+    _plugin: *const clap_sys::plugin::clap_plugin,
+) -> u32 {
+    P::remote_controls_pages().len() as u32
+}
+
+unsafe extern "C" fn remote_controls_get<P: ClapPlugin>(
+    _plugin: *const clap_sys::plugin::clap_plugin,
+    page_index: u32,
+    page: *mut clap_remote_controls_page,
+) -> bool {
+    let Some(source) = P::remote_controls_pages().get(page_index as usize) else {
+        return false;
+    };
+    if page.is_null() {
+        return false;
+    }
+
+    let page = &mut *page;
+    copy_name(&mut page.section_name, source.section);
+    copy_name(&mut page.page_name, source.name);
+    // Pages are fixed, so their index doubles as a stable ID
+    page.page_id = page_index;
+    for (slot, id) in page.param_ids.iter_mut().zip(source.param_ids) {
+        *slot = id.unwrap_or(CLAP_INVALID_ID);
+    }
+    page.is_for_preset = false;
+    true
+}
+
+/// Copy a name into a fixed-size, nul-terminated CLAP string
+fn copy_name(dest: &mut [c_char; CLAP_NAME_SIZE], name: &str) {
+    let bytes = name.as_bytes();
+    let copy_len = bytes.len().min(CLAP_NAME_SIZE - 1);
+    for (dest, &byte) in dest.iter_mut().zip(&bytes[..copy_len]) {
+        *dest = byte as c_char;
+    }
+    dest[copy_len] = 0;
+}
@@ -0,0 +1,18 @@
+//! CLAP tail extension implementation
+
+use crate::{instance::PluginInstance, plugin::ClapPlugin};
+use clap_sys::ext::tail::*;
+use std::sync::OnceLock;
+
+/// Get the tail extension for a plugin type
+pub fn get_extension<P: ClapPlugin>() -> &'static clap_plugin_tail {
+    static EXT: OnceLock<clap_plugin_tail> = OnceLock::new();
+    EXT.get_or_init(|| clap_plugin_tail {
+        get: Some(tail_get::<P>),
+    })
+}
+
+unsafe extern "C" fn tail_get<P: ClapPlugin>(plugin: *const clap_sys::plugin::clap_plugin) -> u32 {
+    let instance = PluginInstance::<P>::from_ptr(plugin);
+    instance.tail()
+}
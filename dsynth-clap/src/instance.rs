@@ -1,8 +1,8 @@
 //! Plugin instance management - bridges trait-based API to CLAP C callbacks
 
 use crate::{
-    entry::log_entry, plugin::ClapPlugin, processor::ClapProcessor, AudioBuffers, Events,
-    ProcessStatus,
+    editor::EditorSlot, entry::log_entry, plugin::ClapPlugin, processor::ClapProcessor,
+    AudioBuffers, Events, ProcessStatus,
 };
 use clap_sys::host::clap_host;
 use clap_sys::plugin::*;
@@ -24,6 +24,10 @@ pub struct PluginInstance<P: ClapPlugin> {
     sample_rate: f32,
     /// Whether the plugin is currently activated
     is_activated: bool,
+    /// Latency reported to the host, in samples (changes while active need a restart)
+    latency: u32,
+    /// Editor opened through `ClapPlugin::create_editor`
+    pub(crate) editor: EditorSlot,
 }
 
 impl<P: ClapPlugin> PluginInstance<P> {
@@ -31,12 +35,11 @@ impl<P: ClapPlugin> PluginInstance<P> {
     pub fn new(host: *const clap_host) -> Box<Self> {
         log_entry("PluginInstance::new() called");
         let descriptor = P::descriptor();
-        let (inputs, outputs) = match descriptor.audio_ports {
-            crate::PortConfig::Instrument => (0, 1),
-            crate::PortConfig::Effect => (1, 1),
-            crate::PortConfig::Custom { inputs, outputs } => (inputs as usize, outputs as usize),
-        };
-        let audio_buffers = AudioBuffers::new(inputs, outputs, 2);
+        let audio_buffers = AudioBuffers::new(
+            descriptor.audio_ports.port_count(true) as usize,
+            descriptor.audio_ports.port_count(false) as usize,
+            2,
+        );
         Box::new(Self {
             plugin: P::new(),
             processor: None,
@@ -44,6 +47,8 @@ impl<P: ClapPlugin> PluginInstance<P> {
             host,
             sample_rate: 44100.0, // Default, will be set during activate
             is_activated: false,
+            latency: 0,
+            editor: EditorSlot::default(),
         })
     }
 
@@ -93,6 +98,7 @@ impl<P: ClapPlugin> PluginInstance<P> {
 
         if let Some(processor) = self.processor.as_mut() {
             processor.activate(self.sample_rate);
+            self.latency = processor.latency();
         }
         self.is_activated = true;
         true
@@ -146,6 +152,16 @@ impl<P: ClapPlugin> PluginInstance<P> {
         // Call the trait-based processor
         let status = processor.process(&mut self.audio_buffers, &events);
 
+        // Latency may only change while deactivated: ask the host to restart the plugin so
+        // it picks up the new value on the next activation
+        let latency = processor.latency();
+        if latency != self.latency {
+            self.latency = latency;
+            if let Some(request_restart) = self.host.as_ref().and_then(|h| h.request_restart) {
+                request_restart(self.host);
+            }
+        }
+
         // Convert status back to CLAP constants
         match status {
             ProcessStatus::Continue => CLAP_PROCESS_CONTINUE,
@@ -155,6 +171,35 @@ impl<P: ClapPlugin> PluginInstance<P> {
         }
     }
 
+    /// Activate or deactivate an audio port (audio-ports-activation extension)
+    ///
+    /// Only optional ports (see `PortConfig::is_optional`) can be deactivated, and only
+    /// while the plugin is deactivated. Activating a required port is a no-op.
+    pub fn set_port_active(&mut self, is_input: bool, index: u32, active: bool) -> bool {
+        if self.is_activated {
+            return false;
+        }
+        if !P::descriptor().audio_ports.is_optional(is_input, index) {
+            return active;
+        }
+        self.audio_buffers.set_input_active(index as usize, active)
+    }
+
+    /// Whether an input port is active (ports start active, as CLAP requires)
+    pub fn is_input_port_active(&self, index: u32) -> bool {
+        self.audio_buffers.is_input_active(index as usize)
+    }
+
+    /// Get the latency reported to the host in samples (0 before the first activation)
+    pub fn latency(&self) -> u32 {
+        self.latency
+    }
+
+    /// Get the processor's tail length in samples (0 before the first activation)
+    pub fn tail(&self) -> u32 {
+        self.processor.as_ref().map_or(0, |p| p.tail())
+    }
+
     /// Get the plugin descriptor
     pub fn descriptor() -> &'static clap_plugin_descriptor {
         P::clap_descriptor()
@@ -279,7 +324,7 @@ unsafe extern "C" fn plugin_get_extension<P: ClapPlugin>(
         Err(_) => return std::ptr::null(),
     };
 
-    let _instance = PluginInstance::<P>::from_ptr(plugin);
+    let instance = PluginInstance::<P>::from_ptr(plugin);
 
     // Convert CStr constants to str for matching
     let audio_ports_id = clap_sys::ext::audio_ports::CLAP_EXT_AUDIO_PORTS
@@ -293,6 +338,20 @@ unsafe extern "C" fn plugin_get_extension<P: ClapPlugin>(
         .unwrap_or("");
     let state_id = clap_sys::ext::state::CLAP_EXT_STATE.to_str().unwrap_or("");
     let gui_id = clap_sys::ext::gui::CLAP_EXT_GUI.to_str().unwrap_or("");
+    let tail_id = clap_sys::ext::tail::CLAP_EXT_TAIL.to_str().unwrap_or("");
+    let latency_id = clap_sys::ext::latency::CLAP_EXT_LATENCY
+        .to_str()
+        .unwrap_or("");
+    let remote_controls_ids = [
+        clap_sys::ext::remote_controls::CLAP_EXT_REMOTE_CONTROLS,
+        clap_sys::ext::remote_controls::CLAP_EXT_REMOTE_CONTROLS_COMPAT,
+    ]
+    .map(|id| id.to_str().unwrap_or(""));
+    let ports_activation_ids = [
+        clap_sys::ext::audio_ports_activation::CLAP_EXT_AUDIO_PORTS_ACTIVATION,
+        clap_sys::ext::audio_ports_activation::CLAP_EXT_AUDIO_PORTS_ACTIVATION_COMPAT,
+    ]
+    .map(|id| id.to_str().unwrap_or(""));
 
     if id_str == audio_ports_id {
         crate::extensions::audio_ports::get_extension::<P>() as *const _ as *const c_void
@@ -302,8 +361,18 @@ unsafe extern "C" fn plugin_get_extension<P: ClapPlugin>(
         crate::extensions::params::get_extension::<P>() as *const _ as *const c_void
     } else if id_str == state_id {
         crate::extensions::state::get_extension::<P>() as *const _ as *const c_void
-    } else if id_str == gui_id {
+    } else if id_str == gui_id && (P::has_gui() || instance.plugin.editor_size().is_some()) {
         crate::extensions::gui::get_extension::<P>() as *const _ as *const c_void
+    } else if id_str == tail_id {
+        crate::extensions::tail::get_extension::<P>() as *const _ as *const c_void
+    } else if id_str == latency_id {
+        crate::extensions::latency::get_extension::<P>() as *const _ as *const c_void
+    } else if remote_controls_ids.contains(&id_str) && !P::remote_controls_pages().is_empty() {
+        crate::extensions::remote_controls::get_extension::<P>() as *const _ as *const c_void
+    } else if ports_activation_ids.contains(&id_str)
+        && P::descriptor().audio_ports.has_optional_ports()
+    {
+        crate::extensions::audio_ports_activation::get_extension::<P>() as *const _ as *const c_void
     } else {
         std::ptr::null()
     }
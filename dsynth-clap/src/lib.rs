@@ -10,6 +10,7 @@
 //! - **`ClapProcessor`**: Audio processing trait for real-time DSP
 //! - **`PluginParams`**: Parameter management with automation support
 //! - **`PluginState`**: Save/load functionality for presets
+//! - **`ClapEditor`**: Optional editor window embedded in the host (CLAP GUI extension)
 //!
 //! # Example
 //!
@@ -31,6 +32,7 @@
 //! ```
 
 pub mod descriptor;
+pub mod editor;
 pub mod entry;
 pub mod extensions;
 pub mod instance;
@@ -44,12 +46,20 @@ pub use clap_sys;
 
 // Re-exports for convenience
 pub use descriptor::PluginDescriptor;
+pub use editor::{ClapEditor, ParentWindow};
 pub use instance::PluginInstance;
-pub use param::{ParamDescriptor, ParamId, ParamType, PluginParams};
+pub use param::{
+    ParamDescriptor, ParamId, ParamType, PluginParams, RemoteControlsPage, REMOTE_CONTROLS_PER_PAGE,
+};
 pub use plugin::ClapPlugin;
-pub use processor::{AudioBuffers, ClapProcessor, Events, ProcessStatus};
+pub use processor::{AudioBuffers, ClapProcessor, Events, ParamMod, ProcessStatus};
 pub use state::PluginState;
 
+use clap_sys::ext::note_ports::{
+    CLAP_NOTE_DIALECT_CLAP, CLAP_NOTE_DIALECT_MIDI, CLAP_NOTE_DIALECT_MIDI2,
+    CLAP_NOTE_DIALECT_MIDI_MPE,
+};
+
 /// Audio port configuration
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PortConfig {
@@ -57,10 +67,56 @@ pub enum PortConfig {
     Instrument,
     /// Stereo input, stereo output (audio effect)
     Effect,
+    /// Stereo input, optional stereo sidechain input, stereo output (audio effect)
+    ///
+    /// The sidechain is input port 1. Hosts with nothing routed to it can deactivate it
+    /// through the audio-ports-activation extension; processors should treat
+    /// `AudioBuffers::input_stereo(1)` returning `None` as "no sidechain".
+    EffectWithSidechain,
     /// Custom port configuration
     Custom { inputs: u32, outputs: u32 },
 }
 
+impl PortConfig {
+    /// Number of input or output ports
+    pub const fn port_count(self, is_input: bool) -> u32 {
+        match self {
+            PortConfig::Instrument => {
+                if is_input {
+                    0
+                } else {
+                    1
+                }
+            }
+            PortConfig::Effect => 1,
+            PortConfig::EffectWithSidechain => {
+                if is_input {
+                    2
+                } else {
+                    1
+                }
+            }
+            PortConfig::Custom { inputs, outputs } => {
+                if is_input {
+                    inputs
+                } else {
+                    outputs
+                }
+            }
+        }
+    }
+
+    /// Whether the host may deactivate this port (audio-ports-activation extension)
+    pub const fn is_optional(self, is_input: bool, index: u32) -> bool {
+        matches!(self, PortConfig::EffectWithSidechain) && is_input && index == 1
+    }
+
+    /// Whether any port can be deactivated, i.e. the activation extension is offered
+    pub const fn has_optional_ports(self) -> bool {
+        matches!(self, PortConfig::EffectWithSidechain)
+    }
+}
+
 /// MIDI port configuration  
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum NotePortConfig {
@@ -71,3 +127,53 @@ pub enum NotePortConfig {
     /// Custom MIDI configuration
     Custom { inputs: u32, outputs: u32 },
 }
+
+/// Set of note dialects a note port can accept (CLAP, MIDI, MIDI-MPE, MIDI 2.0)
+///
+/// Combine dialects with `|`, e.g. `NoteDialects::CLAP | NoteDialects::MIDI_MPE`.
+/// Bit values match the CLAP `CLAP_NOTE_DIALECT_*` flags.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoteDialects(u32);
+
+impl NoteDialects {
+    /// No dialects
+    pub const NONE: Self = Self(0);
+    /// CLAP note events (note on/off, expressions, per-note automation)
+    pub const CLAP: Self = Self(CLAP_NOTE_DIALECT_CLAP);
+    /// MIDI 1.0
+    pub const MIDI: Self = Self(CLAP_NOTE_DIALECT_MIDI);
+    /// MIDI Polyphonic Expression (per-channel notes)
+    pub const MIDI_MPE: Self = Self(CLAP_NOTE_DIALECT_MIDI_MPE);
+    /// MIDI 2.0
+    pub const MIDI2: Self = Self(CLAP_NOTE_DIALECT_MIDI2);
+
+    /// Raw CLAP dialect bitmask
+    pub const fn bits(self) -> u32 {
+        self.0
+    }
+
+    /// True if every dialect in `other` is also in `self`
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// True if no dialects are set
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl Default for NoteDialects {
+    /// CLAP + MIDI, which every host understands
+    fn default() -> Self {
+        Self::CLAP | Self::MIDI
+    }
+}
+
+impl std::ops::BitOr for NoteDialects {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
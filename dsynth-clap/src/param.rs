@@ -3,6 +3,23 @@
 /// Parameter ID type
 pub type ParamId = u32;
 
+/// Number of knobs on a remote-controls page (fixed by CLAP)
+pub const REMOTE_CONTROLS_PER_PAGE: usize = 8;
+
+/// A page of parameters for hardware controllers (CLAP remote-controls extension)
+///
+/// Controllers with a bank of eight knobs show one page at a time, so the most useful
+/// parameters are playable without manual mapping. `None` leaves a knob unassigned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RemoteControlsPage {
+    /// Group the page belongs to (e.g. "Main")
+    pub section: &'static str,
+    /// Page title shown on the controller
+    pub name: &'static str,
+    /// Parameter on each knob, left to right
+    pub param_ids: [Option<ParamId>; REMOTE_CONTROLS_PER_PAGE],
+}
+
 /// Parameter value type
 #[derive(Debug, Clone, PartialEq)]
 pub enum ParamType {
@@ -24,11 +41,16 @@ pub enum ParamType {
 pub struct ParamDescriptor {
     pub id: ParamId,
     pub name: String,
+    /// Group path shown by hosts, "/"-separated for nested groups (e.g. "Effects/Reverb")
     pub module: String,
     pub param_type: ParamType,
     pub unit: Option<String>,
     pub is_automatable: bool,
     pub is_hidden: bool,
+    /// Host may modulate the parameter (`CLAP_EVENT_PARAM_MOD`) without changing its value
+    pub is_modulatable: bool,
+    /// Modulation may also target a single note (by note ID or key)
+    pub is_modulatable_per_note: bool,
 }
 
 impl ParamDescriptor {
@@ -49,6 +71,8 @@ impl ParamDescriptor {
             unit: None,
             is_automatable: true,
             is_hidden: false,
+            is_modulatable: false,
+            is_modulatable_per_note: false,
         }
     }
 
@@ -67,6 +91,8 @@ impl ParamDescriptor {
             unit: None,
             is_automatable: true,
             is_hidden: false,
+            is_modulatable: false,
+            is_modulatable_per_note: false,
         }
     }
 
@@ -82,6 +108,13 @@ impl ParamDescriptor {
         self
     }
 
+    /// Mark parameter as modulatable, optionally per note (polyphonic modulation)
+    pub fn modulatable(mut self, per_note: bool) -> Self {
+        self.is_modulatable = true;
+        self.is_modulatable_per_note = per_note;
+        self
+    }
+
     /// Normalize a value to 0.0-1.0 range
     pub fn normalize(&self, value: f32) -> f32 {
         match &self.param_type {
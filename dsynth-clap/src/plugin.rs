@@ -1,6 +1,8 @@
 //! Core plugin trait
 
-use crate::{ClapProcessor, PluginDescriptor, PluginParams};
+use crate::{
+    ClapEditor, ClapProcessor, ParentWindow, PluginDescriptor, PluginParams, RemoteControlsPage,
+};
 use clap_sys::ext::gui::clap_window;
 use std::ffi::CStr;
 
@@ -32,11 +34,35 @@ pub trait ClapPlugin: 'static {
     /// Create a new processor instance
     fn create_processor(&mut self, sample_rate: f32) -> Self::Processor;
 
+    /// Parameter pages for hardware controllers (CLAP_EXT_REMOTE_CONTROLS)
+    ///
+    /// The extension is only offered to the host when this is non-empty.
+    fn remote_controls_pages() -> &'static [RemoteControlsPage] {
+        &[]
+    }
+
     // ---------------------------------------------------------------------
     // Optional GUI support (CLAP_EXT_GUI)
+    //
+    // Most plugins only implement `editor_size` + `create_editor` and let the
+    // framework drive the editor. Plugins that need full control instead return
+    // true from `has_gui` and implement the raw `gui_*` hooks.
     // ---------------------------------------------------------------------
 
-    /// Whether this plugin provides a custom GUI.
+    /// Size in pixels of the editor `create_editor` opens, or `None` for a headless plugin
+    fn editor_size(&self) -> Option<(u32, u32)> {
+        None
+    }
+
+    /// Open the editor inside the host's window
+    ///
+    /// Called when the host sets the parent window. Returning `None` reports failure to the
+    /// host. The editor is dropped when the host destroys the GUI or sets another parent.
+    fn create_editor(&mut self, _parent: ParentWindow) -> Option<Box<dyn ClapEditor>> {
+        None
+    }
+
+    /// Whether this plugin implements the raw `gui_*` hooks below.
     fn has_gui() -> bool {
         false
     }
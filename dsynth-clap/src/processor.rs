@@ -1,6 +1,12 @@
 //! Audio processor trait
 
-use clap_sys::events::{clap_input_events, clap_output_events};
+use crate::param::ParamId;
+use clap_sys::events::{
+    clap_event_header, clap_event_param_gesture, clap_event_param_mod, clap_event_param_value,
+    clap_input_events, clap_output_events, CLAP_CORE_EVENT_SPACE_ID,
+    CLAP_EVENT_PARAM_GESTURE_BEGIN, CLAP_EVENT_PARAM_GESTURE_END, CLAP_EVENT_PARAM_MOD,
+    CLAP_EVENT_PARAM_VALUE,
+};
 
 /// Errors that can occur while wrapping CLAP audio buffers.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -40,6 +46,8 @@ pub struct AudioBuffers {
 
 struct InputPort {
     channels: Vec<*const f32>,
+    /// Cleared when the host deactivates an optional port (audio-ports-activation)
+    active: bool,
 }
 
 struct OutputPort {
@@ -55,6 +63,7 @@ impl AudioBuffers {
         for _ in 0..input_ports {
             in_ports.push(InputPort {
                 channels: vec![std::ptr::null(); channels_per_port],
+                active: true,
             });
         }
 
@@ -102,6 +111,11 @@ impl AudioBuffers {
         }
 
         for i in 0..self.input_ports.len() {
+            // Deactivated ports carry no buffers: read as disconnected
+            if !self.input_ports[i].active {
+                self.input_ports[i].channels.fill(std::ptr::null());
+                continue;
+            }
             let port = &*process.audio_inputs.add(i);
             if port.channel_count as usize != self.input_ports[i].channels.len() {
                 return Err(AudioBuffersError::ChannelCountMismatch);
@@ -157,7 +171,10 @@ impl AudioBuffers {
                     let ptr = *port.data32.add(ch as usize);
                     channels.push(ptr);
                 }
-                input_ports.push(InputPort { channels });
+                input_ports.push(InputPort {
+                    channels,
+                    active: true,
+                });
             }
         }
 
@@ -193,6 +210,25 @@ impl AudioBuffers {
         self.output_ports.len()
     }
 
+    /// Mark an input port active or inactive (see `PortConfig::EffectWithSidechain`)
+    ///
+    /// Inactive ports are skipped when refreshing from the host, so their channels read
+    /// as `None`. Returns false if the port doesn't exist.
+    pub fn set_input_active(&mut self, port: usize, active: bool) -> bool {
+        match self.input_ports.get_mut(port) {
+            Some(port) => {
+                port.active = active;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Whether an input port exists and is active
+    pub fn is_input_active(&self, port: usize) -> bool {
+        self.input_ports.get(port).is_some_and(|port| port.active)
+    }
+
     /// Get number of frames in this buffer
     pub fn frames_count(&self) -> u32 {
         self.frames_count
@@ -292,6 +328,31 @@ impl AudioBuffers {
     }
 }
 
+/// A decoded parameter modulation event (`CLAP_EVENT_PARAM_MOD`)
+///
+/// Modulation is an offset on top of the parameter's value that the host owns and can
+/// change at any time (e.g. a modulator in Bitwig); it never changes the value itself.
+/// `None` in a note field means "any" (CLAP's -1 wildcard).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ParamMod {
+    pub param_id: ParamId,
+    /// Offset in the parameter's (normalized) value space
+    pub amount: f64,
+    /// Target note ID
+    pub note_id: Option<i32>,
+    pub port_index: Option<i16>,
+    pub channel: Option<i16>,
+    /// Target key (0-127)
+    pub key: Option<i16>,
+}
+
+impl ParamMod {
+    /// Whether the modulation targets particular notes rather than the whole parameter
+    pub fn is_per_note(&self) -> bool {
+        self.note_id.is_some() || self.key.is_some()
+    }
+}
+
 /// CLAP events wrapper
 pub struct Events {
     input_events: *const clap_input_events,
@@ -363,6 +424,26 @@ impl Events {
         }
     }
 
+    /// Decode a parameter modulation event, or `None` for any other event type
+    ///
+    /// # Safety
+    /// `event` must be the header of a complete event as delivered by the host.
+    pub unsafe fn param_mod(event: &clap_event_header) -> Option<ParamMod> {
+        if event.space_id != CLAP_CORE_EVENT_SPACE_ID || event.type_ != CLAP_EVENT_PARAM_MOD {
+            return None;
+        }
+        let e = &*(event as *const clap_event_header as *const clap_event_param_mod);
+        let wildcard = |value: i16| (value >= 0).then_some(value);
+        Some(ParamMod {
+            param_id: e.param_id,
+            amount: e.amount,
+            note_id: (e.note_id >= 0).then_some(e.note_id),
+            port_index: wildcard(e.port_index),
+            channel: wildcard(e.channel),
+            key: wildcard(e.key),
+        })
+    }
+
     /// Try to push an output event
     ///
     /// # Safety
@@ -379,6 +460,58 @@ impl Events {
         }
     }
 
+    /// Report a parameter value changed by the plugin (e.g. from its editor) to the host
+    ///
+    /// `value` is in the same normalized domain the host sees the parameter in. Returns
+    /// false if the host gave no output queue or it is full.
+    ///
+    /// # Safety
+    /// The host must provide a valid output events pointer for the duration of this call.
+    pub unsafe fn push_param_value(&self, param_id: ParamId, value: f64) -> bool {
+        let event = clap_event_param_value {
+            header: clap_event_header {
+                size: std::mem::size_of::<clap_event_param_value>() as u32,
+                time: 0,
+                space_id: CLAP_CORE_EVENT_SPACE_ID,
+                type_: CLAP_EVENT_PARAM_VALUE,
+                flags: 0,
+            },
+            param_id,
+            cookie: std::ptr::null_mut(),
+            note_id: -1,
+            port_index: -1,
+            channel: -1,
+            key: -1,
+            value,
+        };
+        self.try_push_output(&event.header)
+    }
+
+    /// Start (`begin = true`) or end a user edit of a parameter.
+    ///
+    /// Hosts record the `push_param_value()` changes between a begin and an end as one
+    /// automation gesture, the same as a knob drag on their own controls.
+    ///
+    /// # Safety
+    /// The host must provide a valid output events pointer for the duration of this call.
+    pub unsafe fn push_param_gesture(&self, param_id: ParamId, begin: bool) -> bool {
+        let event = clap_event_param_gesture {
+            header: clap_event_header {
+                size: std::mem::size_of::<clap_event_param_gesture>() as u32,
+                time: 0,
+                space_id: CLAP_CORE_EVENT_SPACE_ID,
+                type_: if begin {
+                    CLAP_EVENT_PARAM_GESTURE_BEGIN
+                } else {
+                    CLAP_EVENT_PARAM_GESTURE_END
+                },
+                flags: 0,
+            },
+            param_id,
+        };
+        self.try_push_output(&event.header)
+    }
+
     /// Get raw input events pointer (for advanced use)
     pub fn input_events_ptr(&self) -> *const clap_input_events {
         self.input_events
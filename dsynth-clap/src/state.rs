@@ -15,6 +15,10 @@ pub struct PluginState {
 
     /// Version for forward/backward compatibility
     pub version: u32,
+
+    /// Named non-parameter state (e.g. A/B compare slots), serialized by the plugin
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub extras: HashMap<String, String>,
 }
 
 impl PluginState {
@@ -42,4 +46,14 @@ impl PluginState {
     pub fn get_param(&self, id: u32) -> Option<f32> {
         self.params.get(&id).copied()
     }
+
+    /// Set a named extra state entry
+    pub fn set_extra(&mut self, key: impl Into<String>, value: impl Into<String>) {
+        self.extras.insert(key.into(), value.into());
+    }
+
+    /// Get a named extra state entry
+    pub fn get_extra(&self, key: &str) -> Option<&str> {
+        self.extras.get(key).map(String::as_str)
+    }
 }
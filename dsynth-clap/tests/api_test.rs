@@ -23,6 +23,8 @@ mod tests {
                 features: vec![],
                 audio_ports: PortConfig::Instrument,
                 note_ports: NotePortConfig::Input,
+                supported_note_dialects: NoteDialects::CLAP | NoteDialects::MIDI_MPE,
+                preferred_note_dialect: NoteDialects::MIDI_MPE,
             }
         }
 
@@ -82,5 +84,499 @@ mod tests {
     fn test_descriptor() {
         let desc = DummyPlugin::descriptor();
         assert_eq!(desc.id, "test.plugin");
+        assert!(desc
+            .supported_note_dialects
+            .contains(NoteDialects::MIDI_MPE));
+    }
+
+    #[test]
+    fn test_default_note_dialects() {
+        let desc = PluginDescriptor::instrument("Test", "test.instrument");
+        assert_eq!(
+            desc.supported_note_dialects,
+            NoteDialects::CLAP | NoteDialects::MIDI
+        );
+        assert_eq!(desc.preferred_note_dialect, NoteDialects::MIDI);
+
+        let desc = desc.note_dialects(
+            NoteDialects::default() | NoteDialects::MIDI_MPE,
+            NoteDialects::MIDI_MPE,
+        );
+        assert!(desc
+            .supported_note_dialects
+            .contains(NoteDialects::MIDI_MPE));
+        assert_eq!(desc.preferred_note_dialect, NoteDialects::MIDI_MPE);
+    }
+
+    #[test]
+    fn test_state_extras_round_trip() {
+        let mut state = PluginState::new();
+        state.set_param(1, 0.5);
+        state.set_extra("slots", "{}");
+
+        let restored = PluginState::from_bytes(&state.to_bytes().unwrap()).unwrap();
+        assert_eq!(restored.get_extra("slots"), Some("{}"));
+
+        // States written before extras existed still load
+        let legacy = PluginState::from_bytes(br#"{"params":{},"version":1}"#).unwrap();
+        assert!(legacy.extras.is_empty());
+    }
+    thread_local! {
+        /// What the test editor was asked to do, in order
+        static EDITOR_LOG: std::cell::RefCell<Vec<String>> = const { std::cell::RefCell::new(Vec::new()) };
+    }
+
+    fn log_editor(entry: String) {
+        EDITOR_LOG.with(|log| log.borrow_mut().push(entry));
+    }
+
+    /// Plugin using the managed editor path
+    struct EditorPlugin;
+
+    struct TestEditor {
+        size: (u32, u32),
+    }
+
+    impl ClapEditor for TestEditor {
+        fn size(&self) -> (u32, u32) {
+            self.size
+        }
+
+        fn can_resize(&self) -> bool {
+            true
+        }
+
+        fn set_size(&mut self, width: u32, height: u32) -> bool {
+            self.size = (width, height);
+            true
+        }
+
+        fn set_scale(&mut self, scale: f64) -> bool {
+            log_editor(format!("scale {}", scale));
+            true
+        }
+    }
+
+    impl Drop for TestEditor {
+        fn drop(&mut self) {
+            log_editor("closed".to_string());
+        }
+    }
+
+    impl ClapPlugin for EditorPlugin {
+        type Processor = DummyProcessor;
+        type Params = DummyParams;
+
+        fn descriptor() -> PluginDescriptor {
+            PluginDescriptor::instrument("Editor", "test.editor")
+        }
+
+        fn clap_descriptor() -> &'static clap_sys::plugin::clap_plugin_descriptor {
+            unimplemented!()
+        }
+
+        fn new() -> Self {
+            Self
+        }
+
+        fn create_processor(&mut self, _sample_rate: f32) -> Self::Processor {
+            DummyProcessor
+        }
+
+        fn editor_size(&self) -> Option<(u32, u32)> {
+            Some((400, 300))
+        }
+
+        fn create_editor(&mut self, parent: ParentWindow) -> Option<Box<dyn ClapEditor>> {
+            log_editor(format!("opened in {:?}", parent));
+            Some(Box::new(TestEditor { size: (400, 300) }))
+        }
+    }
+
+    /// Plugin advertising one remote-controls page
+    struct RemotePlugin;
+
+    impl ClapPlugin for RemotePlugin {
+        type Processor = DummyProcessor;
+        type Params = DummyParams;
+
+        fn descriptor() -> PluginDescriptor {
+            PluginDescriptor::instrument("Remote", "test.remote")
+        }
+
+        fn clap_descriptor() -> &'static clap_sys::plugin::clap_plugin_descriptor {
+            unimplemented!()
+        }
+
+        fn new() -> Self {
+            Self
+        }
+
+        fn create_processor(&mut self, _sample_rate: f32) -> Self::Processor {
+            DummyProcessor
+        }
+
+        fn remote_controls_pages() -> &'static [RemoteControlsPage] {
+            const PAGES: [RemoteControlsPage; 1] = [RemoteControlsPage {
+                section: "Main",
+                name: "Performance",
+                param_ids: [Some(10), Some(11), None, None, None, None, None, Some(17)],
+            }];
+            &PAGES
+        }
+    }
+
+    #[test]
+    fn test_remote_controls_pages() {
+        use clap_sys::ext::remote_controls::clap_remote_controls_page;
+        use clap_sys::id::CLAP_INVALID_ID;
+        use std::ffi::CStr;
+
+        assert!(DummyPlugin::remote_controls_pages().is_empty());
+
+        let ext = dsynth_clap::extensions::remote_controls::get_extension::<RemotePlugin>();
+        let plugin = std::ptr::null();
+        unsafe {
+            assert_eq!(ext.count.unwrap()(plugin), 1);
+
+            let mut page: clap_remote_controls_page = std::mem::zeroed();
+            assert!(ext.get.unwrap()(plugin, 0, &mut page));
+            assert_eq!(CStr::from_ptr(page.section_name.as_ptr()), c"Main");
+            assert_eq!(CStr::from_ptr(page.page_name.as_ptr()), c"Performance");
+            assert_eq!(page.page_id, 0);
+            assert_eq!(page.param_ids[..2], [10, 11]);
+            assert_eq!(page.param_ids[2], CLAP_INVALID_ID);
+            assert_eq!(page.param_ids[7], 17);
+            assert!(!page.is_for_preset);
+
+            assert!(!ext.get.unwrap()(plugin, 1, &mut page), "only one page");
+        }
+    }
+
+    /// Effect with an optional sidechain input
+    struct SidechainPlugin;
+
+    impl ClapPlugin for SidechainPlugin {
+        type Processor = DummyProcessor;
+        type Params = DummyParams;
+
+        fn descriptor() -> PluginDescriptor {
+            PluginDescriptor::effect("Sidechain", "test.sidechain")
+                .audio_ports(PortConfig::EffectWithSidechain)
+        }
+
+        fn clap_descriptor() -> &'static clap_sys::plugin::clap_plugin_descriptor {
+            unimplemented!()
+        }
+
+        fn new() -> Self {
+            Self
+        }
+
+        fn create_processor(&mut self, _sample_rate: f32) -> Self::Processor {
+            DummyProcessor
+        }
+    }
+
+    #[test]
+    fn test_optional_sidechain_port() {
+        use clap_sys::ext::audio_ports::{clap_audio_port_info, CLAP_AUDIO_PORT_IS_MAIN};
+        use std::ffi::CStr;
+
+        assert!(!PortConfig::Effect.has_optional_ports());
+        let ports = dsynth_clap::extensions::audio_ports::get_extension::<SidechainPlugin>();
+        let activation =
+            dsynth_clap::extensions::audio_ports_activation::get_extension::<SidechainPlugin>();
+
+        let instance = Box::into_raw(PluginInstance::<SidechainPlugin>::new(std::ptr::null()));
+        let plugin = clap_sys::plugin::clap_plugin {
+            desc: std::ptr::null(),
+            plugin_data: instance as *mut _,
+            init: None,
+            destroy: None,
+            activate: None,
+            deactivate: None,
+            start_processing: None,
+            stop_processing: None,
+            reset: None,
+            process: None,
+            get_extension: None,
+            on_main_thread: None,
+        };
+
+        unsafe {
+            assert_eq!(ports.count.unwrap()(&plugin, true), 2);
+            assert_eq!(ports.count.unwrap()(&plugin, false), 1);
+
+            let mut info: clap_audio_port_info = std::mem::zeroed();
+            assert!(ports.get.unwrap()(&plugin, 1, true, &mut info));
+            assert_eq!(CStr::from_ptr(info.name.as_ptr()), c"Sidechain");
+            assert_eq!(info.flags & CLAP_AUDIO_PORT_IS_MAIN, 0);
+            assert!(ports.get.unwrap()(&plugin, 0, true, &mut info));
+            assert_eq!(
+                info.flags & CLAP_AUDIO_PORT_IS_MAIN,
+                CLAP_AUDIO_PORT_IS_MAIN
+            );
+
+            // Ports start active; only the sidechain can be switched off
+            assert!(!activation.can_activate_while_processing.unwrap()(&plugin));
+            assert!((*instance).is_input_port_active(1));
+            assert!(activation.set_active.unwrap()(&plugin, true, 1, false, 32));
+            assert!(!(*instance).is_input_port_active(1));
+            assert!(!activation.set_active.unwrap()(&plugin, true, 0, false, 32));
+            assert!((*instance).is_input_port_active(0));
+            assert!(activation.set_active.unwrap()(&plugin, true, 1, true, 32));
+            assert!((*instance).is_input_port_active(1));
+
+            drop(Box::from_raw(instance));
+        }
+    }
+
+    #[test]
+    fn test_headless_by_default() {
+        assert_eq!(DummyPlugin::new().editor_size(), None);
+    }
+
+    #[test]
+    fn test_managed_editor_lifecycle() {
+        use clap_sys::ext::gui::{clap_window, clap_window_handle, CLAP_WINDOW_API_X11};
+
+        let instance = Box::into_raw(PluginInstance::<EditorPlugin>::new(std::ptr::null()));
+        let plugin = clap_sys::plugin::clap_plugin {
+            desc: std::ptr::null(),
+            plugin_data: instance as *mut _,
+            init: None,
+            destroy: None,
+            activate: None,
+            deactivate: None,
+            start_processing: None,
+            stop_processing: None,
+            reset: None,
+            process: None,
+            get_extension: None,
+            on_main_thread: None,
+        };
+        let gui = dsynth_clap::extensions::gui::get_extension::<EditorPlugin>();
+        let api = ParentWindow::platform_api().as_ptr();
+
+        unsafe {
+            assert!(gui.create.unwrap()(&plugin, api, false));
+            assert!(
+                !gui.create.unwrap()(&plugin, api, true),
+                "floating is not supported"
+            );
+
+            // Size is known before the editor opens; scale is held until it does
+            let (mut width, mut height) = (0, 0);
+            assert!(gui.get_size.unwrap()(&plugin, &mut width, &mut height));
+            assert_eq!((width, height), (400, 300));
+            assert!(gui.set_scale.unwrap()(&plugin, 2.0));
+            assert!(
+                !gui.show.unwrap()(&plugin),
+                "nothing to show before set_parent"
+            );
+
+            let window = clap_window {
+                api: CLAP_WINDOW_API_X11.as_ptr(),
+                specific: clap_window_handle { x11: 42 },
+            };
+            assert!(gui.set_parent.unwrap()(&plugin, &window));
+            assert!(gui.show.unwrap()(&plugin));
+            assert!(gui.can_resize.unwrap()(&plugin));
+            assert!(gui.set_size.unwrap()(&plugin, 640, 480));
+            assert!(gui.get_size.unwrap()(&plugin, &mut width, &mut height));
+            assert_eq!((width, height), (640, 480));
+            assert!(gui.hide.unwrap()(&plugin));
+
+            gui.destroy.unwrap()(&plugin);
+            drop(Box::from_raw(instance));
+        }
+
+        EDITOR_LOG.with(|log| {
+            assert_eq!(
+                *log.borrow(),
+                vec!["opened in X11(42)", "scale 2", "closed"]
+            );
+        });
+    }
+
+    #[test]
+    fn test_param_mod_decoding() {
+        use clap_sys::events::*;
+
+        let mut event = clap_event_param_mod {
+            header: clap_event_header {
+                size: std::mem::size_of::<clap_event_param_mod>() as u32,
+                time: 0,
+                space_id: CLAP_CORE_EVENT_SPACE_ID,
+                type_: CLAP_EVENT_PARAM_MOD,
+                flags: 0,
+            },
+            param_id: 7,
+            cookie: std::ptr::null_mut(),
+            note_id: 42,
+            port_index: -1,
+            channel: -1,
+            key: -1,
+            amount: -0.25,
+        };
+        let decoded = unsafe { Events::param_mod(&event.header) }.unwrap();
+        assert_eq!(decoded.param_id, 7);
+        assert_eq!(decoded.amount, -0.25);
+        assert_eq!(decoded.note_id, Some(42));
+        assert_eq!((decoded.channel, decoded.key), (None, None));
+        assert!(decoded.is_per_note());
+
+        // Global modulation: every note field is a wildcard
+        event.note_id = -1;
+        let decoded = unsafe { Events::param_mod(&event.header) }.unwrap();
+        assert!(!decoded.is_per_note());
+
+        event.header.type_ = CLAP_EVENT_PARAM_VALUE;
+        assert!(unsafe { Events::param_mod(&event.header) }.is_none());
+
+        let flags = ParamDescriptor::float(1, "Cutoff", "Filter", 0.0, 1.0, 0.5).modulatable(true);
+        assert!(flags.is_modulatable && flags.is_modulatable_per_note);
+    }
+
+    #[test]
+    fn test_param_gesture_output() {
+        use clap_sys::events::*;
+        use clap_sys::process::clap_process;
+
+        // Host output queue that records (type, param_id, value) for each pushed event
+        unsafe extern "C" fn record(
+            list: *const clap_output_events,
+            event: *const clap_event_header,
+        ) -> bool {
+            let pushed = &mut *((*list).ctx as *mut Vec<(u16, u32, f64)>);
+            let header = &*event;
+            let entry = match header.type_ {
+                CLAP_EVENT_PARAM_VALUE => {
+                    let e = &*(event as *const clap_event_param_value);
+                    (header.type_, e.param_id, e.value)
+                }
+                _ => {
+                    let e = &*(event as *const clap_event_param_gesture);
+                    (header.type_, e.param_id, 0.0)
+                }
+            };
+            pushed.push(entry);
+            true
+        }
+
+        let mut pushed: Vec<(u16, u32, f64)> = Vec::new();
+        let out_events = clap_output_events {
+            ctx: &mut pushed as *mut _ as *mut std::ffi::c_void,
+            try_push: Some(record),
+        };
+        let process = clap_process {
+            steady_time: 0,
+            frames_count: 0,
+            transport: std::ptr::null(),
+            audio_inputs: std::ptr::null(),
+            audio_outputs: std::ptr::null_mut(),
+            audio_inputs_count: 0,
+            audio_outputs_count: 0,
+            in_events: std::ptr::null(),
+            out_events: &out_events,
+        };
+
+        unsafe {
+            let events = Events::from_clap_process(&process);
+            assert!(events.push_param_gesture(3, true));
+            assert!(events.push_param_value(3, 0.5));
+            assert!(events.push_param_gesture(3, false));
+        }
+        assert_eq!(
+            pushed,
+            vec![
+                (CLAP_EVENT_PARAM_GESTURE_BEGIN, 3, 0.0),
+                (CLAP_EVENT_PARAM_VALUE, 3, 0.5),
+                (CLAP_EVENT_PARAM_GESTURE_END, 3, 0.0),
+            ]
+        );
+
+        // No output queue from the host: nothing to report to
+        let events = unsafe { Events::from_clap_process(std::ptr::null()) };
+        assert!(!unsafe { events.push_param_value(3, 0.5) });
+    }
+
+    /// Plugin with one parameter in a nested module
+    struct ModulePlugin;
+    struct ModuleParams;
+
+    impl ModuleParams {
+        fn descriptor() -> ParamDescriptor {
+            ParamDescriptor::float(5, "Mix", "Effects/Reverb", 0.0, 1.0, 0.3)
+        }
+    }
+
+    impl PluginParams for ModuleParams {
+        fn param_count() -> u32 {
+            1
+        }
+
+        fn param_descriptor(index: u32) -> Option<ParamDescriptor> {
+            (index == 0).then(Self::descriptor)
+        }
+
+        fn param_descriptor_by_id(id: ParamId) -> Option<ParamDescriptor> {
+            (id == 5).then(Self::descriptor)
+        }
+
+        fn get_param(_id: ParamId) -> Option<f32> {
+            None
+        }
+
+        fn set_param(_id: ParamId, _value: f32) {}
+
+        fn save_state() -> PluginState {
+            PluginState::default()
+        }
+
+        fn load_state(_state: &PluginState) {}
+    }
+
+    impl ClapPlugin for ModulePlugin {
+        type Processor = DummyProcessor;
+        type Params = ModuleParams;
+
+        fn descriptor() -> PluginDescriptor {
+            PluginDescriptor::effect("Module", "test.module")
+        }
+
+        fn clap_descriptor() -> &'static clap_sys::plugin::clap_plugin_descriptor {
+            unimplemented!()
+        }
+
+        fn new() -> Self {
+            Self
+        }
+
+        fn create_processor(&mut self, _sample_rate: f32) -> Self::Processor {
+            DummyProcessor
+        }
+    }
+
+    #[test]
+    fn test_param_info_module_path() {
+        use clap_sys::ext::params::clap_param_info;
+        use std::ffi::CStr;
+
+        let ext = dsynth_clap::extensions::params::get_extension::<ModulePlugin>();
+        let plugin = std::ptr::null();
+        unsafe {
+            let mut info: clap_param_info = std::mem::zeroed();
+            assert!(ext.get_info.unwrap()(plugin, 0, &mut info));
+            assert_eq!(info.id, 5);
+            assert_eq!(CStr::from_ptr(info.name.as_ptr()), c"Mix");
+            assert_eq!(
+                CStr::from_ptr(info.module.as_ptr()).to_str().unwrap(),
+                ModuleParams::descriptor().module
+            );
+
+            assert!(!ext.get_info.unwrap()(plugin, 1, &mut info));
+        }
     }
 }
@@ -6,18 +6,64 @@
 #[cfg(test)]
 pub mod tests;
 
+mod modulation_feed;
+mod monitor;
+mod note_repeat;
+mod output_history;
+mod paraphonic;
+mod voice_mods;
+pub use modulation_feed::{
+    EffectLevels, EffectSlot, HeldNotes, ModulationSnapshot, SharedModulationFeed,
+    MODULATION_FEED_HZ, MODULATION_TARGETS,
+};
+pub use monitor::MonitorMode;
+use output_history::OutputHistoryTap;
+pub use output_history::{OutputHistory, SharedOutputHistory, OUTPUT_HISTORY_SECONDS};
+pub use voice_mods::MAX_VOICE_PARAM_MODS;
+
 use crate::audio::voice::Voice;
 use crate::dsp::effects::dynamics::lookahead_limiter::LookAheadLimiter;
 use crate::dsp::effects::{
     AutoPan, Bitcrusher, Chorus, CombFilter, Compressor, Distortion, Exciter, Flanger,
-    MultibandDistortion, Phaser, Reverb, RingModulator, StereoDelay, StereoWidener, Tremolo,
-    Waveshaper,
+    FormantFilter, IntelligentExciter, MidSideStereoProcessor, MultibandDistortion, NoiseGate,
+    Phaser, Reverb, RingModulator, SibilanceEq, SmartGate, StereoDelay, StereoPitchShifter,
+    StereoWidener, TransientShaper, Tremolo, VocalChoir, VocalDoubler, Waveshaper,
 };
+use crate::dsp::filters::BiquadFilter;
+use crate::dsp::modulation::envelope::EnvelopeStage;
+use crate::dsp::signal_analyzer::SignalAnalyzer;
 use crate::dsp::synthesis::wavetable_library::WavetableLibrary;
-use crate::params::SynthParams;
+use crate::dsp::{
+    MonoCompatibilityReport, PitchQuantizer, RootNote, ScaleType, SignalType, ZcrDetector,
+};
+use crate::params::{EffectRouting, EffectsParams, FilterType, SynthParams};
+use crate::plugin::param_descriptor::ParamId;
+use note_repeat::{NoteRepeat, RepeatEvent};
+use paraphonic::ParaphonicStage;
 use triple_buffer::{Input, Output, TripleBuffer};
 
-const MAX_POLYPHONY: usize = 16;
+/// Number of voices the engine allocates
+pub const MAX_POLYPHONY: usize = 16;
+
+/// Number of effects in the master chain (one bypass crossfade slot each)
+const EFFECT_COUNT: usize = 24;
+
+/// Mid/side side low cut at or below which the high-pass is bypassed
+const MID_SIDE_LOW_CUT_OFF_HZ: f32 = 20.0;
+
+/// Length of the dry/wet crossfade when an individual effect is enabled or disabled
+const EFFECT_FADE_MS: f32 = 5.0;
+
+/// Time for an effect meter peak to fall by 60 dB once the signal stops
+const EFFECT_METER_RELEASE_MS: f32 = 300.0;
+
+/// A reported song position further than this (in beats) from where playback should be
+/// means the host started, seeked or looped: synced LFOs are realigned to the timeline
+const SONG_POSITION_JUMP_BEATS: f64 = 1.0 / 64.0;
+
+/// Engine noise seed (the oscillators' noise PRNG seed); per-voice random sources such as
+/// analog drift are derived from it so renders are reproducible
+const NOISE_SEED: u32 = 0x12345678;
 
 /// The core synthesis engine that orchestrates real-time audio generation.
 ///
@@ -85,10 +131,20 @@ pub struct SynthEngine {
     /// there's another note to play. This implements "last-note priority":
     /// Hold C, press E, release E → plays C again automatically. Essential for keyboards.
     ///
-    /// Stores `(note, velocity)` so that returning to a previously-held note preserves
-    /// its original velocity (instead of using a fixed fallback).
+    /// Stores `(key, velocity)` so that returning to a previously-held note preserves
+    /// its original velocity (instead of using a fixed fallback). Keys are the incoming
+    /// note numbers; `played_notes` gives the pitch each one plays under scale lock.
     note_stack: Vec<(u8, f32)>,
 
+    /// Scale lock: when enabled, incoming notes snap to `scale_quantizer`'s scale/root
+    /// before voice allocation
+    scale_lock_enabled: bool,
+    scale_quantizer: PitchQuantizer,
+
+    /// Note actually played for each incoming note number, so a note-off releases what its
+    /// note-on started even if the scale changed in between
+    played_notes: [u8; 128],
+
     /// Counter for throttling parameter updates
     /// We don't check the parameter triple-buffer every sample (too expensive and unnecessary).
     /// Instead, we check every `param_update_interval` samples. This counter tracks progress.
@@ -100,6 +156,21 @@ pub struct SynthEngine {
     /// effects (like LFO) still work because they're applied per-sample within the voice DSP.
     param_update_interval: u32,
 
+    /// Producer for the GUI modulation feed (None until `modulation_feed()` is called)
+    modulation_feed: Option<Input<ModulationSnapshot>>,
+
+    /// Samples between modulation feed publishes (sample_rate / `MODULATION_FEED_HZ`)
+    modulation_feed_interval: u32,
+
+    /// Samples since the last modulation feed publish
+    modulation_feed_counter: u32,
+
+    /// Recent output kept for off-thread analysis (None until `set_output_history()`)
+    output_history: Option<OutputHistoryTap>,
+
+    /// Last note-on received (note, velocity), for the GUI note display
+    last_note: Option<(u8, f32)>,
+
     /// Smoothed polyphonic gain compensation.
     ///
     /// A hard step in poly compensation (e.g., 1.0 → 1/√2 when going 1→2 voices)
@@ -119,9 +190,15 @@ pub struct SynthEngine {
     reverb: Reverb,
     delay: StereoDelay,
     chorus: Chorus,
+    doubler: VocalDoubler,
+    /// Analysis feeding `doubler`'s content-aware delay and mix
+    doubler_analyzer: SignalAnalyzer,
+    choir: VocalChoir,
     distortion: Distortion,
     multiband_distortion: MultibandDistortion,
     stereo_widener: StereoWidener,
+    /// High-pass on the side signal of the mid/side insert (bypassed at its lowest setting)
+    mid_side_low_cut: BiquadFilter,
 
     // New modulation/time-based effects
     phaser: Phaser,
@@ -134,21 +211,111 @@ pub struct SynthEngine {
     ring_modulator: RingModulator,
 
     // New dynamics/distortion effects
+    gate: NoiseGate,
+    /// Signal-aware alternative to `gate`, used when `GateParams::smart` is set
+    smart_gate: SmartGate,
+    /// Analysis feeding `smart_gate`; only run while the smart gate is in use
+    gate_analyzer: SignalAnalyzer,
+    transient_shaper: TransientShaper,
+    /// Transient detection feeding `transient_shaper`
+    transient_analyzer: SignalAnalyzer,
     compressor: Compressor,
     bitcrusher: Bitcrusher,
     waveshaper: Waveshaper,
     exciter: Exciter,
+    /// Content-aware alternative to `exciter`, used when `ExciterParams::intelligent` is set
+    intelligent_exciter: IntelligentExciter,
+    /// Pitch/voicing/sibilance analysis feeding `intelligent_exciter`; only run while the
+    /// intelligent exciter is in use
+    exciter_analyzer: SignalAnalyzer,
+    sibilance_eq: SibilanceEq,
+    formant_filter: FormantFilter,
+    pitch_shifter: StereoPitchShifter,
+
+    /// Per-effect dry/wet crossfade position (0.0 = bypassed, 1.0 = fully wet).
+    ///
+    /// Moves linearly toward the effect's `enabled` state so toggling an effect fades
+    /// instead of switching abruptly. Slots follow the chain order in `effect_enable_flags()`.
+    effect_fades: [f32; EFFECT_COUNT],
+
+    /// Per-sample crossfade increment (1 / fade length in samples)
+    effect_fade_step: f32,
+
+    /// Decaying output peak per effect for the GUI meters (same slots as `effect_fades`).
+    /// Only tracked while a GUI feed is attached.
+    effect_peaks: [f32; EFFECT_COUNT],
+
+    /// Per-sample decay factor of `effect_peaks`
+    effect_meter_decay: f32,
 
     /// Wavetable library for wavetable synthesis
     wavetable_library: WavetableLibrary,
 
-    /// Current tempo in BPM from DAW transport (defaults to 120.0)
-    /// Updated by CLAP plugin from host transport events
+    /// Current tempo in BPM (defaults to 120.0)
+    /// Updated by the CLAP plugin from host transport, or by MIDI clock in standalone
     current_tempo_bpm: f64,
 
+    /// Tempo changed since the last parameter update (tempo-synced rates need recomputing)
+    tempo_changed: bool,
+
+    /// Running sample count, used to time poly legato windows
+    sample_clock: u64,
+
+    /// Host song position in beats and the `sample_clock` it was reported at, while the
+    /// transport plays (`None` when stopped or unknown)
+    song_position: Option<(f64, u64)>,
+
+    /// Sample clock value at which each voice was last released (poly mode only).
+    ///
+    /// `None` while the voice is held or idle. Used to find a voice that was released
+    /// within `legato_window_ms` so a new note can take it over legato.
+    voice_release_times: [Option<u64>; MAX_POLYPHONY],
+
+    /// Incoming key that started each voice (poly mode only). Scale lock can snap two
+    /// keys to the same note, so a note-off releases by key rather than by pitch.
+    voice_keys: [u8; MAX_POLYPHONY],
+
+    /// Host per-note parameter modulation for the note each voice is playing
+    voice_mods: [voice_mods::VoiceParamMods; MAX_POLYPHONY],
+
     /// Previous tempo sync modes for LFOs and effects (for phase reset detection)
     /// Order: [LFO1, LFO2, LFO3, Chorus, Phaser, Flanger, Tremolo, AutoPan]
     previous_sync_modes: [crate::params::TempoSync; 8],
+
+    /// Quality setting currently applied to oscillators and effects (rebuilt on change only)
+    applied_quality: crate::params::Quality,
+
+    /// Shared filter and amp envelope the summed voices run through in paraphonic mode
+    paraphonic: ParaphonicStage,
+
+    /// Keys held while note repeat is on, retriggered in `advance_note_repeat()`
+    note_repeat: NoteRepeat,
+
+    /// Effect whose wet contribution alone is heard (sound-design audition, see
+    /// `audition_effect()`). Transient UI state: not part of `SynthParams` or presets.
+    auditioned_effect: Option<EffectSlot>,
+
+    /// How the final output is folded for monitoring (see `set_monitor_mode()`).
+    /// Transient UI state: not part of `SynthParams` or presets.
+    monitor_mode: MonitorMode,
+
+    /// MIDI channel (0-15) the plugin wrapper accepts note events on, `None` = omni.
+    /// Follows `SynthParams::midi_channel` whenever a preset or the parameter changes it.
+    midi_channel_filter: Option<u8>,
+
+    /// Effect tail length in samples (reverb decay + delay repeats), recomputed whenever
+    /// effect parameters change
+    tail_length_samples: u32,
+
+    /// Processing latency in samples (the pitch shifter's delay and compressor lookahead
+    /// while they're enabled), recomputed whenever effect parameters change
+    latency_samples: u32,
+
+    /// Zero-crossing classifier on the mix entering the effects chain
+    /// (see `current_signal_type()`)
+    signal_classifier: ZcrDetector,
+    /// Signal type the adaptive compressor attack was last set for
+    compressor_signal_type: SignalType,
 }
 
 impl SynthEngine {
@@ -174,8 +341,10 @@ impl SynthEngine {
     /// - Sample counter at 0
     pub fn new(sample_rate: f32, params_consumer: Output<SynthParams>) -> Self {
         let mut voices = Vec::with_capacity(MAX_POLYPHONY);
-        for _ in 0..MAX_POLYPHONY {
-            voices.push(Voice::new(sample_rate));
+        for i in 0..MAX_POLYPHONY {
+            let mut voice = Voice::new(sample_rate);
+            voice.seed_drift(NOISE_SEED.wrapping_add(i as u32));
+            voices.push(voice);
         }
 
         // Polyphonic gain compensation smoothing.
@@ -202,18 +371,36 @@ impl SynthEngine {
             params_consumer,
             current_params: SynthParams::default(),
             note_stack: Vec::new(),
+            scale_lock_enabled: false,
+            scale_quantizer: PitchQuantizer::new(sample_rate),
+            played_notes: std::array::from_fn(|note| note as u8),
             sample_counter: 0,
             param_update_interval: 32, // Update every 32 samples (~0.7ms at 44.1kHz)
+            modulation_feed: None,
+            modulation_feed_interval: (sample_rate / MODULATION_FEED_HZ).max(1.0) as u32,
+            modulation_feed_counter: 0,
+            output_history: None,
+            last_note: None,
             poly_gain: 1.0,
             poly_gain_attack_coeff,
             poly_gain_release_coeff,
             lookahead_limiter,
             reverb: Reverb::new(sample_rate),
             delay: StereoDelay::new(sample_rate),
+            doubler: VocalDoubler::new(sample_rate),
+            doubler_analyzer: SignalAnalyzer::new(sample_rate),
+            choir: VocalChoir::new(sample_rate),
             chorus: Chorus::new(sample_rate),
             distortion: Distortion::new(sample_rate),
             multiband_distortion: MultibandDistortion::new(sample_rate),
             stereo_widener: StereoWidener::new(sample_rate),
+            mid_side_low_cut: {
+                let mut filter = BiquadFilter::new(sample_rate);
+                filter.set_filter_type(FilterType::Highpass);
+                filter.set_resonance(0.707);
+                filter.set_cutoff(MID_SIDE_LOW_CUT_OFF_HZ);
+                filter
+            },
 
             // Initialize new modulation/time-based effects
             phaser: Phaser::new(sample_rate, 6, 1000.0, 0.5),
@@ -226,6 +413,15 @@ impl SynthEngine {
             ring_modulator: RingModulator::new(sample_rate, 440.0),
 
             // Initialize new dynamics/distortion effects
+            gate: {
+                let mut gate = NoiseGate::new(sample_rate);
+                gate.set_ratio(10.0); // Full expansion: a gate, not a gentle expander
+                gate
+            },
+            smart_gate: SmartGate::new(sample_rate),
+            gate_analyzer: SignalAnalyzer::new(sample_rate),
+            transient_shaper: TransientShaper::new(sample_rate),
+            transient_analyzer: SignalAnalyzer::new_no_pitch(sample_rate),
             compressor: Compressor::new(sample_rate, -20.0, 4.0, 10.0, 100.0),
             bitcrusher: Bitcrusher::new(sample_rate, sample_rate, 16),
             waveshaper: Waveshaper::new(
@@ -233,11 +429,45 @@ impl SynthEngine {
                 1.0,
             ),
             exciter: Exciter::new(sample_rate),
+            intelligent_exciter: IntelligentExciter::new(sample_rate),
+            exciter_analyzer: SignalAnalyzer::new(sample_rate),
+            sibilance_eq: SibilanceEq::new(sample_rate),
+            formant_filter: FormantFilter::new(sample_rate),
+            pitch_shifter: StereoPitchShifter::new(sample_rate),
+
+            effect_fades: Self::effect_enable_flags(&EffectsParams::default()).map(|enabled| {
+                if enabled {
+                    1.0
+                } else {
+                    0.0
+                }
+            }),
+            effect_fade_step: 1.0 / (EFFECT_FADE_MS * 0.001 * sample_rate).max(1.0),
+            effect_peaks: [0.0; EFFECT_COUNT],
+            // -60 dB (0.001) over the release time
+            effect_meter_decay: 0.001_f32
+                .powf(1.0 / (EFFECT_METER_RELEASE_MS * 0.001 * sample_rate)),
 
             wavetable_library,
 
             current_tempo_bpm: 120.0, // Default tempo
+            tempo_changed: false,
+            sample_clock: 0,
+            song_position: None,
+            voice_release_times: [None; MAX_POLYPHONY],
+            voice_keys: [0; MAX_POLYPHONY],
+            voice_mods: [voice_mods::VoiceParamMods::default(); MAX_POLYPHONY],
             previous_sync_modes: [crate::params::TempoSync::Hz; 8], // All default to Hz mode
+            applied_quality: crate::params::Quality::Normal, // Matches the constructors above
+            paraphonic: ParaphonicStage::new(sample_rate),
+            note_repeat: NoteRepeat::new(),
+            auditioned_effect: None,
+            monitor_mode: MonitorMode::Stereo,
+            midi_channel_filter: None,
+            tail_length_samples: 0, // Reverb and delay are disabled by default
+            latency_samples: 0,     // Pitch shifter is disabled by default
+            signal_classifier: ZcrDetector::new(sample_rate),
+            compressor_signal_type: SignalType::Tonal,
         }
     }
 
@@ -308,12 +538,23 @@ impl SynthEngine {
         // Check for parameter updates from triple buffer
         let new_params = self.params_consumer.read();
 
-        // Only update if parameters actually changed
-        if *new_params == self.current_params {
+        // Only update if parameters (or the tempo behind synced rates) actually changed
+        if *new_params == self.current_params && !self.tempo_changed {
             return;
         }
 
+        let previous_midi_channel = self.current_params.midi_channel;
         self.current_params = *new_params;
+        self.tempo_changed = false;
+
+        // Parameter channels count from 1 (0 = omni)
+        if self.current_params.midi_channel != previous_midi_channel {
+            self.set_midi_channel_filter(self.current_params.midi_channel.checked_sub(1));
+        }
+
+        if self.current_params.quality != self.applied_quality {
+            self.apply_quality(self.current_params.quality);
+        }
 
         // Apply tempo-synced rates to LFOs before passing to voices
         let mut modified_lfos = self.current_params.lfos;
@@ -324,8 +565,12 @@ impl SynthEngine {
             let sync_mode_changed = self.previous_sync_modes[i] != lfo_params.tempo_sync;
             if sync_mode_changed {
                 self.previous_sync_modes[i] = lfo_params.tempo_sync;
-                // Phase reset will be handled when voices are updated
-                // (voices have direct access to LFO objects)
+                // While the host transport plays, jump straight to the timeline phase
+                if let Some(phase) = self.song_phase(lfo_params.tempo_sync) {
+                    for voice in &mut self.voices {
+                        voice.set_lfo_phase(i, phase);
+                    }
+                }
             }
 
             // Calculate effective rate
@@ -338,20 +583,73 @@ impl SynthEngine {
         // Update effects parameters
         self.update_effects_params();
 
+        self.paraphonic.update_parameters(
+            &self.current_params.filters[0],
+            &self.current_params.envelope,
+        );
+
         // Update all active voices with current parameters (using tempo-synced LFO rates)
-        for voice in &mut self.voices {
+        for idx in 0..self.voices.len() {
+            let voice = &mut self.voices[idx];
+            voice.set_filter_topology(self.current_params.filter_topology);
+            voice.set_analog_drift(self.current_params.analog_drift);
+            voice.set_tuning_cents(self.current_params.tuning_cents);
+            voice.set_max_note_seconds(self.current_params.max_note_seconds);
+            voice.set_paraphonic(self.current_params.paraphonic);
+            voice.set_click(
+                self.current_params.voice_click_enabled,
+                self.current_params.voice_click_level,
+                self.current_params.voice_click_length_ms,
+                self.current_params.voice_click_highpass_hz,
+            );
+            voice.set_velocity_envelope_sensitivity(
+                self.current_params.velocity.attack_sensitivity,
+                self.current_params.velocity.decay_sensitivity,
+            );
+            voice
+                .set_velocity_release_sensitivity(self.current_params.velocity.release_sensitivity);
             if voice.is_active() {
-                voice.update_parameters(
-                    &self.current_params.oscillators,
-                    &self.current_params.filters,
-                    &modified_lfos, // Use modified LFO params with tempo-synced rates
-                    &self.current_params.envelope,
-                    &self.wavetable_library,
-                );
+                // Use modified LFO params with tempo-synced rates
+                self.update_voice_parameters(idx, &modified_lfos);
             }
         }
     }
 
+    /// Push the current parameters to voice `idx`, with its note's host modulation on top
+    fn update_voice_parameters(&mut self, idx: usize, lfo_params: &[crate::params::LFOParams; 3]) {
+        if let Some(params) = self.voice_mods[idx].apply(&self.current_params) {
+            let lfo_params = self.tempo_synced_lfos(params.lfos);
+            self.voices[idx].update_parameters(
+                &params.oscillators,
+                &params.filters,
+                &lfo_params,
+                &params.envelope,
+                &self.wavetable_library,
+            );
+        } else {
+            self.voices[idx].update_parameters(
+                &self.current_params.oscillators,
+                &self.current_params.filters,
+                lfo_params,
+                &self.current_params.envelope,
+                &self.wavetable_library,
+            );
+        }
+    }
+
+    /// Apply a quality setting: oscillator decimation filter length, distortion
+    /// oversampling and delay interpolation. Only called when the setting changes,
+    /// since resizing the decimation filters recomputes their coefficients.
+    fn apply_quality(&mut self, quality: crate::params::Quality) {
+        for voice in &mut self.voices {
+            voice.set_downsampler_taps(quality.downsampler_taps());
+        }
+        self.distortion
+            .set_oversampling(quality.oversampled_distortion());
+        self.delay.set_cubic_interpolation(quality.cubic_delay());
+        self.applied_quality = quality;
+    }
+
     /// Update effects processors with current parameters
     fn update_effects_params(&mut self) {
         // Extract all effect params at once to avoid borrow issues with get_effective_rate
@@ -360,19 +658,27 @@ impl SynthEngine {
         let reverb_params = effects.reverb;
         let delay_params = effects.delay;
         let chorus_params = effects.chorus;
+        let doubler_params = effects.doubler;
+        let choir_params = effects.choir;
         let distortion_params = effects.distortion;
         let mb_dist = effects.multiband_distortion;
         let stereo_widener_params = effects.stereo_widener;
+        let mid_side_params = effects.mid_side;
         let phaser_params = effects.phaser;
         let flanger_params = effects.flanger;
         let tremolo_params = effects.tremolo;
         let autopan_params = effects.auto_pan;
         let comb_filter_params = effects.comb_filter;
         let ring_mod_params = effects.ring_mod;
+        let gate_params = effects.gate;
+        let transient_params = effects.transient_shaper;
         let compressor_params = effects.compressor;
         let bitcrusher_params = effects.bitcrusher;
         let waveshaper_params = effects.waveshaper;
         let exciter_params = effects.exciter;
+        let sibilance_eq_params = effects.sibilance_eq;
+        let formant_params = effects.formant_filter;
+        let pitch_shifter_params = effects.pitch_shifter;
 
         // Update reverb
         self.reverb.set_room_size(reverb_params.room_size);
@@ -380,13 +686,30 @@ impl SynthEngine {
         self.reverb.set_wet(reverb_params.wet);
         self.reverb.set_dry(reverb_params.dry);
         self.reverb.set_width(reverb_params.width);
+        self.reverb.set_algorithm(reverb_params.algorithm);
 
-        // Update delay
-        self.delay.set_time(delay_params.time_ms);
+        // Update delay with tempo sync (musical divisions map to a delay time in ms)
+        let delay_time_ms = if delay_params.tempo_sync == crate::params::TempoSync::Hz {
+            delay_params.time_ms
+        } else {
+            Self::tempo_division_to_ms(delay_params.tempo_sync, self.current_tempo_bpm)
+        };
+        self.delay.set_time(delay_time_ms);
+        self.delay.set_feedback_curve(delay_params.feedback_curve);
         self.delay.set_feedback(delay_params.feedback);
         self.delay.set_wet(delay_params.wet);
         self.delay.set_dry(delay_params.dry);
 
+        // Delay feeds the reverb, so their tails add up
+        let mut tail = 0u32;
+        if delay_params.enabled {
+            tail = tail.saturating_add(self.delay.tail_samples());
+        }
+        if reverb_params.enabled {
+            tail = tail.saturating_add(self.reverb.tail_samples());
+        }
+        self.tail_length_samples = tail;
+
         // Update chorus with tempo sync
         let chorus_rate = self.get_effective_rate(
             chorus_params.rate,
@@ -396,6 +719,16 @@ impl SynthEngine {
         self.chorus.set_rate(chorus_rate);
         self.chorus.set_depth(chorus_params.depth);
         self.chorus.set_mix(chorus_params.mix);
+        self.chorus.set_voices(chorus_params.voices);
+        self.chorus.set_spread(chorus_params.spread);
+
+        // Update doubler and choir
+        self.doubler.set_amount(doubler_params.mix);
+        self.doubler.set_stereo_width(doubler_params.spread);
+        self.choir.set_num_voices(choir_params.voices);
+        self.choir.set_detune_amount(choir_params.detune);
+        self.choir.set_stereo_spread(choir_params.spread);
+        self.choir.set_mix(choir_params.mix);
 
         // Update distortion
         self.distortion.set_drive(distortion_params.drive);
@@ -426,6 +759,10 @@ impl SynthEngine {
         self.stereo_widener
             .set_side_gain(stereo_widener_params.side_gain);
 
+        // Update mid/side insert (gains are applied per sample)
+        self.mid_side_low_cut
+            .set_cutoff(mid_side_params.side_low_cut);
+
         // Update phaser with tempo sync
         let phaser_rate = self.get_effective_rate(
             phaser_params.rate,
@@ -477,11 +814,25 @@ impl SynthEngine {
         self.ring_modulator.set_frequency(ring_mod_params.frequency);
         self.ring_modulator.set_depth(ring_mod_params.depth);
 
+        // Update noise gate (both variants, so switching modes keeps the settings)
+        self.gate.set_threshold(gate_params.threshold);
+        self.gate.set_release(gate_params.release);
+        self.smart_gate.set_threshold(gate_params.threshold);
+        self.smart_gate.set_release(gate_params.release);
+
+        // Update transient shaper (attack is passed per sample)
+        self.transient_shaper.set_sustain(transient_params.sustain);
+
         // Update compressor
         self.compressor.set_threshold(compressor_params.threshold);
         self.compressor.set_ratio(compressor_params.ratio);
-        self.compressor.set_attack(compressor_params.attack);
+        self.update_compressor_attack();
         self.compressor.set_release(compressor_params.release);
+        self.compressor
+            .set_stereo_link(compressor_params.stereo_link);
+        self.compressor
+            .set_lookahead_ms(compressor_params.lookahead);
+        self.compressor.set_knee(compressor_params.knee);
 
         // Update bitcrusher
         self.bitcrusher
@@ -496,6 +847,31 @@ impl SynthEngine {
         self.exciter.set_frequency(exciter_params.frequency);
         self.exciter.set_drive(exciter_params.drive);
         self.exciter.set_mix(exciter_params.mix);
+        self.intelligent_exciter.set_amount(exciter_params.drive);
+        self.intelligent_exciter.set_mix(exciter_params.mix);
+
+        // Update sibilance EQ
+        self.sibilance_eq
+            .set_threshold(sibilance_eq_params.threshold);
+        self.sibilance_eq.set_amount(sibilance_eq_params.amount);
+
+        // Update formant filter
+        self.formant_filter.set_vowel(formant_params.vowel);
+        self.formant_filter.set_morph(formant_params.morph);
+        self.formant_filter.set_mix(formant_params.mix);
+
+        // Update pitch shifter (its delay line and the compressor lookahead are the
+        // latency the chain reports)
+        self.pitch_shifter
+            .set_semitones(pitch_shifter_params.semitones);
+        self.pitch_shifter.set_mix(pitch_shifter_params.mix);
+        self.latency_samples = 0;
+        if pitch_shifter_params.enabled {
+            self.latency_samples += self.pitch_shifter.latency_samples();
+        }
+        if compressor_params.enabled {
+            self.latency_samples += self.compressor.latency_samples();
+        }
     }
 
     /// Process one stereo sample and return both left and right channels.
@@ -556,17 +932,75 @@ impl SynthEngine {
     /// assert!(left.abs() < 2.0 && right.abs() < 2.0);
     /// ```
     pub fn process(&mut self) -> (f32, f32) {
+        self.process_stereo_internal(None)
+    }
+
+    /// Process one stereo sample with an external audio input mixed into the bus.
+    ///
+    /// Used when the host provides an input port (Effect/Custom port configurations), so
+    /// DSynth's effects chain can process incoming audio alongside the voices. The bus
+    /// (input plus voices) is scaled by `SynthParams::input_gain_db` before the effects,
+    /// and the inverse gain is applied after them as output makeup. This lets you drive the nonlinear effects
+    /// (distortion, waveshaper, bitcrusher) harder without changing the overall level.
+    ///
+    /// Instrument configurations have no input and call `process()` instead, which ignores
+    /// the input gain entirely.
+    ///
+    /// # Example
+    /// ```
+    /// use dsynth::audio::engine::{SynthEngine, create_parameter_buffer};
+    /// let (_producer, consumer) = create_parameter_buffer();
+    /// let mut engine = SynthEngine::new(44100.0, consumer);
+    ///
+    /// let (left, right) = engine.process_with_input(0.25, -0.25);
+    /// assert!(left.is_finite() && right.is_finite());
+    /// ```
+    pub fn process_with_input(&mut self, input_left: f32, input_right: f32) -> (f32, f32) {
+        self.process_stereo_internal(Some((input_left, input_right)))
+    }
+
+    /// Shared per-sample processing for `process()` and `process_with_input()`.
+    fn process_stereo_internal(&mut self, input: Option<(f32, f32)>) -> (f32, f32) {
         self.maybe_update_params();
+        self.maybe_publish_modulation();
+        self.sample_clock += 1;
+        self.advance_note_repeat();
+
+        // Input drive (Effect/Custom configs only). It scales the whole pre-effects bus
+        // (input plus any voices) so the makeup after the effects leaves the synth level
+        // unchanged.
+        let input_drive = if input.is_some() {
+            10.0_f32.powf(self.current_params.input_gain_db / 20.0)
+        } else {
+            1.0
+        };
+
+        // Internal headroom: effects run this many dB below the mixed level and the loss
+        // is made up after the chain, so nonlinear effects aren't pushed by hot mixes.
+        // This sits on top of the polyphonic gain compensation below: poly gain evens out
+        // level differences from voice count, headroom sets where that level lands
+        // relative to the effects' operating range. 0 dB keeps the original staging.
+        let headroom_gain = if self.current_params.headroom_db > 0.0 {
+            10.0_f32.powf(-self.current_params.headroom_db / 20.0)
+        } else {
+            1.0
+        };
 
         // Mix all voices - stereo
         let mut output_left = 0.0;
         let mut output_right = 0.0;
         let mut active_count = 0;
-        for voice in &mut self.voices {
-            let (left, right) = voice.process(
+        for (voice, mods) in self.voices.iter_mut().zip(&self.voice_mods) {
+            // A note with host modulation renders with its own modulated copy
+            let (oscillators, filters, lfos) = mods.render_params().unwrap_or((
                 &self.current_params.oscillators,
                 &self.current_params.filters,
                 &self.current_params.lfos,
+            ));
+            let (left, right) = voice.process(
+                oscillators,
+                filters,
+                lfos,
                 &self.current_params.velocity,
                 self.current_params.hard_sync_enabled,
                 &self.current_params.voice_compressor,
@@ -579,12 +1013,28 @@ impl SynthEngine {
             }
         }
 
+        // Paraphonic mode: the voices' raw oscillators share one filter and amp envelope
+        if self.current_params.paraphonic {
+            if active_count > 0 {
+                (output_left, output_right) = self.paraphonic.process(
+                    output_left,
+                    output_right,
+                    &self.current_params.filters[0],
+                    &self.current_params.velocity,
+                );
+            } else {
+                self.paraphonic.reset();
+            }
+        }
+
         // Polyphonic gain compensation: prevent distortion when many keys are pressed.
         // IMPORTANT: smooth changes in this gain. A step change when active_count changes
         // (e.g., pressing a second key) can be audible as a click.
-        // Uses gentler exponent (0.35 vs 0.5) to maintain perceived loudness with limiter protection
+        // `poly_comp_amount` scales the exponent from 0 (plain sum) to 0.5 (equal power);
+        // the default 0.7 gives the gentler 0.35 that keeps chords loud with limiter protection
         let target_poly_gain = if active_count > 1 {
-            1.0 / (active_count as f32).powf(0.35)
+            let exponent = self.current_params.poly_comp_amount.clamp(0.0, 1.0) * 0.5;
+            1.0 / (active_count as f32).powf(exponent)
         } else {
             1.0
         };
@@ -607,72 +1057,292 @@ impl SynthEngine {
 
         // Effects chain (processed in series)
         // Order is intentional for sound quality:
-        // 1. Dynamics (compressor) - control peaks first
+        // 1. Dynamics (noise gate, transient shaper, compressor) - clean up, shape the
+        //    attack and control peaks first
         // 2. Distortion/saturation (distortion, waveshaper, bitcrusher) - add harmonics
         // 3. Multiband distortion - frequency-specific saturation
-        // 4. Harmonic enhancement (exciter) - frequency-specific harmonics after multiband
-        // 5. Filter effects (comb filter, phaser, flanger) - frequency/phase manipulation
+        // 4. Harmonic enhancement (exciter) - frequency-specific harmonics after multiband,
+        //    then the sibilance EQ to tame any hiss it brought up
+        // 5. Filter effects (formant, comb filter, phaser, flanger) - frequency/phase shaping
         // 6. Pitch modulation (ring modulator, tremolo) - amplitude/frequency effects
-        // 7. Chorus - adds width/detuning
-        // 8. Delay - rhythmic repeats
-        // 9. Spatial effects (auto-pan, stereo widener) - stereo field manipulation
-        // 10. Reverb last - final ambience/space
+        // 7. Ensemble (chorus, doubler, choir) - adds width/detuning
+        // 8. Pitch shifter - harmonizer-style thickening
+        // 9. Delay - rhythmic repeats
+        // 10. Spatial effects (auto-pan, mid/side, stereo widener) - stereo field manipulation
+        // 11. Reverb last - final ambience/space
         //
-        // Conditional processing: Skip disabled effects to save CPU
+        // Conditional processing: Skip disabled effects to save CPU. Enable/disable changes
+        // crossfade over EFFECT_FADE_MS so effects with tails (delay, reverb) don't click.
+        // The global bypass skips the whole chain without touching individual enables,
+        // so turning it back off restores the previous effect setup exactly.
         let mut out_l = output_left;
         let mut out_r = output_right;
 
-        if self.current_params.effects.compressor.enabled {
-            (out_l, out_r) = self.compressor.process(out_l, out_r);
-        }
-        if self.current_params.effects.distortion.enabled {
-            (out_l, out_r) = self.distortion.process_stereo(out_l, out_r);
-        }
-        if self.current_params.effects.waveshaper.enabled {
-            (out_l, out_r) = self.waveshaper.process(out_l, out_r);
-        }
-        if self.current_params.effects.bitcrusher.enabled {
-            (out_l, out_r) = self.bitcrusher.process(out_l, out_r);
+        if let Some((input_left, input_right)) = input {
+            out_l = (out_l + input_left) * input_drive;
+            out_r = (out_r + input_right) * input_drive;
         }
-        if self.current_params.effects.multiband_distortion.enabled {
-            (out_l, out_r) = self.multiband_distortion.process_stereo(out_l, out_r);
-        }
-        if self.current_params.effects.exciter.enabled {
-            (out_l, out_r) = self.exciter.process(out_l, out_r);
-        }
-        if self.current_params.effects.comb_filter.enabled {
-            (out_l, out_r) = self.comb_filter.process(out_l, out_r);
-        }
-        if self.current_params.effects.phaser.enabled {
-            (out_l, out_r) = self.phaser.process(out_l, out_r);
-        }
-        if self.current_params.effects.flanger.enabled {
-            (out_l, out_r) = self.flanger.process(out_l, out_r);
+
+        out_l *= headroom_gain;
+        out_r *= headroom_gain;
+
+        self.signal_classifier.process_stereo(out_l, out_r);
+
+        if !self.current_params.effects_bypass {
+            // Adaptive compressor: retune the attack whenever the material changes character
+            if self.current_params.effects.compressor.adaptive
+                && self.signal_classifier.classify_signal() != self.compressor_signal_type
+            {
+                self.update_compressor_attack();
+            }
+
+            let enabled = Self::effect_enable_flags(&self.current_params.effects);
+            let sends = Self::effect_send_flags(&self.current_params.effects);
+            let metering = self.modulation_feed.is_some();
+            let audition = self.auditioned_effect.map(EffectSlot::index);
+            let mut auditioned = (0.0, 0.0);
+
+            // Send effects are fed from the pre-effects bus and their wet returns are summed
+            // after the chain, so parallel sends never feed each other
+            let chain_input = (out_l, out_r);
+            let mut send_return = (0.0, 0.0);
+
+            // Run an effect while it is enabled or still fading out, blending dry → wet by
+            // its crossfade position. Fully bypassed effects are skipped to save CPU.
+            // With a GUI attached, each effect's output peak also feeds its meter.
+            macro_rules! crossfaded {
+                ($slot:expr, $effect:ident . $method:ident) => {
+                    crossfaded!($slot, |l, r| self.$effect.$method(l, r));
+                };
+                ($slot:expr, $method:ident) => {
+                    crossfaded!($slot, |l, r| self.$method(l, r));
+                };
+                ($slot:expr, |$l:ident, $r:ident| $process:expr) => {
+                    let fade = &mut self.effect_fades[$slot];
+                    *fade = if enabled[$slot] {
+                        (*fade + self.effect_fade_step).min(1.0)
+                    } else {
+                        (*fade - self.effect_fade_step).max(0.0)
+                    };
+                    let fade = *fade;
+                    let mut level = 0.0_f32;
+                    if fade > 0.0 {
+                        let ($l, $r) = if sends[$slot] {
+                            chain_input
+                        } else {
+                            (out_l, out_r)
+                        };
+                        let (wet_l, wet_r) = $process;
+                        if sends[$slot] {
+                            let dry = self.effect_dry_level($slot);
+                            let ret = ((wet_l - $l * dry) * fade, (wet_r - $r * dry) * fade);
+                            send_return.0 += ret.0;
+                            send_return.1 += ret.1;
+                            if audition == Some($slot) {
+                                auditioned = ret;
+                            }
+                            level = ret.0.abs().max(ret.1.abs());
+                        } else {
+                            if fade >= 1.0 {
+                                (out_l, out_r) = (wet_l, wet_r);
+                            } else {
+                                out_l += (wet_l - out_l) * fade;
+                                out_r += (wet_r - out_r) * fade;
+                            }
+                            if audition == Some($slot) {
+                                let dry = self.effect_dry_level($slot);
+                                auditioned = (out_l - $l * dry, out_r - $r * dry);
+                            }
+                            level = out_l.abs().max(out_r.abs());
+                        }
+                    }
+                    if metering {
+                        let peak = &mut self.effect_peaks[$slot];
+                        *peak = if fade > 0.0 {
+                            (*peak * self.effect_meter_decay).max(level)
+                        } else {
+                            0.0
+                        };
+                    }
+                };
+            }
+
+            crossfaded!(0, process_gate);
+            crossfaded!(1, process_transient_shaper);
+            crossfaded!(2, compressor.process);
+            crossfaded!(3, distortion.process_stereo);
+            crossfaded!(4, waveshaper.process);
+            crossfaded!(5, bitcrusher.process);
+            crossfaded!(6, multiband_distortion.process_stereo);
+            crossfaded!(7, process_exciter);
+            crossfaded!(8, sibilance_eq.process);
+            crossfaded!(9, formant_filter.process);
+            crossfaded!(10, comb_filter.process);
+            crossfaded!(11, phaser.process);
+            crossfaded!(12, flanger.process);
+            crossfaded!(13, ring_modulator.process);
+            crossfaded!(14, tremolo.process);
+            crossfaded!(15, chorus.process);
+            crossfaded!(16, process_doubler);
+            crossfaded!(17, choir.process);
+            crossfaded!(18, pitch_shifter.process);
+            crossfaded!(19, delay.process);
+            crossfaded!(20, auto_pan.process);
+            crossfaded!(21, process_mid_side);
+            crossfaded!(22, stereo_widener.process);
+            crossfaded!(23, reverb.process);
+
+            out_l += send_return.0;
+            out_r += send_return.1;
+
+            // Auditioning: only the chosen effect's contribution reaches the output. The
+            // rest of the chain keeps running so nothing jumps when the audition ends.
+            if audition.is_some() {
+                (out_l, out_r) = auditioned;
+            }
         }
-        if self.current_params.effects.ring_mod.enabled {
-            (out_l, out_r) = self.ring_modulator.process(out_l, out_r);
+
+        // Output makeup for the input drive keeps the processed level comparable
+        if input_drive != 1.0 {
+            out_l /= input_drive;
+            out_r /= input_drive;
         }
-        if self.current_params.effects.tremolo.enabled {
-            (out_l, out_r) = self.tremolo.process(out_l, out_r);
+
+        // Headroom makeup restores the pre-effects level before the limiter
+        if headroom_gain != 1.0 {
+            out_l /= headroom_gain;
+            out_r /= headroom_gain;
         }
-        if self.current_params.effects.chorus.enabled {
-            (out_l, out_r) = self.chorus.process(out_l, out_r);
+
+        // Look-ahead limiter for transparent peak limiting with minimal artifacts
+        let (out_l, out_r) = self.lookahead_limiter.process(out_l, out_r);
+
+        // Analysis sees the real stereo output, not the monitoring fold below
+        if let Some(history) = self.output_history.as_mut() {
+            history.push(out_l, out_r);
         }
-        if self.current_params.effects.delay.enabled {
-            (out_l, out_r) = self.delay.process(out_l, out_r);
+
+        // Monitoring folds the finished output (mono check, mid/side solo)
+        self.monitor_mode.apply(out_l, out_r)
+    }
+
+    /// Dry level an effect mixes into its own output, removed when auditioning it or
+    /// taking its send return.
+    ///
+    /// Delay and reverb blend dry and wet internally, so their audition is the pure wet
+    /// signal. Insert effects replace their input, so their audition is what they change.
+    fn effect_dry_level(&self, slot: usize) -> f32 {
+        let effects = &self.current_params.effects;
+        match slot {
+            s if s == EffectSlot::Delay.index() => effects.delay.dry,
+            s if s == EffectSlot::Reverb.index() => effects.reverb.dry,
+            _ => 1.0,
         }
-        if self.current_params.effects.auto_pan.enabled {
-            (out_l, out_r) = self.auto_pan.process(out_l, out_r);
+    }
+
+    /// Apply the compressor attack. In adaptive mode the set attack is scaled by the signal
+    /// type: slower on tonal material so note attacks pass and low notes aren't distorted,
+    /// faster on noisy/percussive material to catch its spikes.
+    fn update_compressor_attack(&mut self) {
+        let compressor = self.current_params.effects.compressor;
+        let attack = if compressor.adaptive {
+            self.compressor_signal_type = self.signal_classifier.classify_signal();
+            let scale = match self.compressor_signal_type {
+                SignalType::Tonal => 2.0,
+                SignalType::Mixed => 1.0,
+                SignalType::Noisy => 0.5,
+                SignalType::VeryNoisy => 0.25,
+            };
+            compressor.attack * scale
+        } else {
+            compressor.attack
+        };
+        self.compressor.set_attack(attack);
+    }
+
+    /// Gate slot of the effects chain: the level-only noise gate, or the smart gate fed by
+    /// an analysis of its input.
+    fn process_gate(&mut self, left: f32, right: f32) -> (f32, f32) {
+        if self.current_params.effects.gate.smart {
+            let analysis = self.gate_analyzer.analyze(left, right);
+            self.smart_gate.process(left, right, &analysis)
+        } else {
+            self.gate.process(left, right)
         }
-        if self.current_params.effects.stereo_widener.enabled {
-            (out_l, out_r) = self.stereo_widener.process(out_l, out_r);
+    }
+
+    /// Transient shaper slot of the effects chain, fed by transient detection on its input.
+    fn process_transient_shaper(&mut self, left: f32, right: f32) -> (f32, f32) {
+        let analysis = self.transient_analyzer.analyze(left, right);
+        let attack = self.current_params.effects.transient_shaper.attack;
+        self.transient_shaper
+            .process(left, right, attack, &analysis)
+    }
+
+    /// Doubler slot of the effects chain, fed by content analysis of its input.
+    fn process_doubler(&mut self, left: f32, right: f32) -> (f32, f32) {
+        let analysis = self.doubler_analyzer.analyze(left, right);
+        self.doubler.process(left, right, &analysis)
+    }
+
+    /// Mid/side slot of the effects chain: independent mid and side gain, with the side
+    /// signal's low end optionally cut so the bass stays mono
+    fn process_mid_side(&mut self, left: f32, right: f32) -> (f32, f32) {
+        let params = self.current_params.effects.mid_side;
+        let (mid, mut side) = MidSideStereoProcessor::encode(left, right);
+        if params.side_low_cut > MID_SIDE_LOW_CUT_OFF_HZ {
+            side = self.mid_side_low_cut.process(side);
         }
-        if self.current_params.effects.reverb.enabled {
-            (out_l, out_r) = self.reverb.process(out_l, out_r);
+        MidSideStereoProcessor::decode(mid * params.mid_gain, side * params.side_gain)
+    }
+
+    /// Exciter slot of the effects chain: the fixed exciter, or the intelligent one fed by
+    /// an analysis of its input.
+    fn process_exciter(&mut self, left: f32, right: f32) -> (f32, f32) {
+        if self.current_params.effects.exciter.intelligent {
+            let analysis = self.exciter_analyzer.analyze(left, right);
+            self.intelligent_exciter.process(left, right, &analysis)
+        } else {
+            self.exciter.process(left, right)
         }
+    }
 
-        // Look-ahead limiter for transparent peak limiting with minimal artifacts
-        self.lookahead_limiter.process(out_l, out_r)
+    /// Enable flags for every effect, in chain order (indexes into `effect_fades`).
+    fn effect_enable_flags(effects: &EffectsParams) -> [bool; EFFECT_COUNT] {
+        [
+            effects.gate.enabled,
+            effects.transient_shaper.enabled,
+            effects.compressor.enabled,
+            effects.distortion.enabled,
+            effects.waveshaper.enabled,
+            effects.bitcrusher.enabled,
+            effects.multiband_distortion.enabled,
+            effects.exciter.enabled,
+            effects.sibilance_eq.enabled,
+            effects.formant_filter.enabled,
+            effects.comb_filter.enabled,
+            effects.phaser.enabled,
+            effects.flanger.enabled,
+            effects.ring_mod.enabled,
+            effects.tremolo.enabled,
+            effects.chorus.enabled,
+            effects.doubler.enabled,
+            effects.choir.enabled,
+            effects.pitch_shifter.enabled,
+            effects.delay.enabled,
+            effects.auto_pan.enabled,
+            effects.mid_side.enabled,
+            effects.stereo_widener.enabled,
+            effects.reverb.enabled,
+        ]
+    }
+
+    /// Effects routed as parallel sends, indexed like `effect_enable_flags()`
+    fn effect_send_flags(effects: &EffectsParams) -> [bool; EFFECT_COUNT] {
+        let mut sends = [false; EFFECT_COUNT];
+        sends[EffectSlot::Delay.index()] = effects.delay.routing == EffectRouting::Send;
+        sends[EffectSlot::Reverb.index()] = effects.reverb.routing == EffectRouting::Send;
+        sends
     }
 
     /// Trigger a note on (MIDI note event).
@@ -716,22 +1386,47 @@ impl SynthEngine {
     /// assert!(left.abs() < 2.0 && right.abs() < 2.0, "Output should be in valid range");
     /// ```
     pub fn note_on(&mut self, note: u8, velocity: f32) {
+        self.note_on_with_id(note, velocity, None);
+    }
+
+    /// Trigger a note tagged with the host's note ID (CLAP), so per-note parameter
+    /// modulation can find its voice later (see `modulate_voice_param()`).
+    ///
+    /// Otherwise identical to `note_on()`. The voice starts without modulation, even
+    /// when it takes over a note that had some.
+    pub fn note_on_with_id(&mut self, note: u8, velocity: f32, note_id: Option<i32>) {
         // MIDI semantics: NoteOn with velocity 0 is equivalent to NoteOff.
         // Avoid activating a voice that should be silent.
         if velocity <= 0.0 {
             return;
         }
+        if self.current_params.note_repeat.enabled {
+            self.note_repeat.press(note, velocity, note_id);
+        }
+        self.start_note(note, velocity, note_id);
+    }
+
+    /// Start a note on a voice (the body of `note_on_with_id()`, also used for note
+    /// repeat hits)
+    fn start_note(&mut self, key: u8, velocity: f32, note_id: Option<i32>) {
+        let key = key.min(127);
+        let note = self.lock_to_scale(key);
+        self.last_note = Some((note, velocity));
+        if self.current_params.paraphonic {
+            let first_key = !self.any_voice_held();
+            self.paraphonic.note_on(note, velocity, first_key);
+        }
         if self.current_params.monophonic {
             // Monophonic mode: last-note priority.
             // If at least one key was already held, switching notes should be legato
             // (no hard DSP reset), otherwise a fast note change can click/pop.
             let had_held_note = !self.note_stack.is_empty();
 
-            // Add/update note in stack.
-            if let Some(existing) = self.note_stack.iter_mut().find(|(n, _)| *n == note) {
+            // Add/update key in stack.
+            if let Some(existing) = self.note_stack.iter_mut().find(|(k, _)| *k == key) {
                 existing.1 = velocity;
             } else {
-                self.note_stack.push((note, velocity));
+                self.note_stack.push((key, velocity));
             }
 
             if had_held_note {
@@ -739,45 +1434,67 @@ impl SynthEngine {
             } else {
                 // No keys were held: treat this as a normal note-on (retrigger envelope).
                 self.voices[0].note_on(note, velocity);
+                self.voices[0].set_key_pan(self.current_params.key_pan_amount);
+                self.align_voice_lfos(0);
+                self.retrigger_modulation(0);
             }
+            self.voice_mods[0].start(note_id);
 
             // Apply parameter-dependent frequency/timbre immediately.
             let lfo_params = self.get_tempo_synced_lfo_params();
-            self.voices[0].update_parameters(
-                &self.current_params.oscillators,
-                &self.current_params.filters,
-                &lfo_params,
-                &self.current_params.envelope,
-                &self.wavetable_library,
-            );
+            self.update_voice_parameters(0, &lfo_params);
         } else {
             // Polyphonic mode: original behavior
             // Get tempo-synced LFO params before borrowing voices
             let lfo_params = self.get_tempo_synced_lfo_params();
 
+            // Solo note mode: release every sounding note first so only the newest note
+            // attacks, while the released voices keep their natural release tails.
+            if self.current_params.solo_note_mode {
+                for (voice, release_time) in
+                    self.voices.iter_mut().zip(&mut self.voice_release_times)
+                {
+                    if voice.is_active() && release_time.is_none() {
+                        voice.note_off();
+                        *release_time = Some(self.sample_clock);
+                    }
+                }
+            }
+
+            // Poly legato: take over a voice released within the legato window instead of
+            // starting a fresh one, so fast repeated notes/lines flow without re-attacking.
+            if let Some(idx) = self.find_legato_voice() {
+                self.voice_release_times[idx] = None;
+                self.voice_keys[idx] = key;
+                self.voices[idx].note_resume_legato(note, velocity);
+                self.voice_mods[idx].start(note_id);
+                self.update_voice_parameters(idx, &lfo_params);
+                return;
+            }
+
             // First, try to find an inactive voice
-            if let Some(voice) = self.voices.iter_mut().find(|v| !v.is_active()) {
-                voice.note_on(note, velocity);
-                voice.update_parameters(
-                    &self.current_params.oscillators,
-                    &self.current_params.filters,
-                    &lfo_params,
-                    &self.current_params.envelope,
-                    &self.wavetable_library,
-                );
+            if let Some(idx) = self.voices.iter().position(|v| !v.is_active()) {
+                self.voice_release_times[idx] = None;
+                self.voice_keys[idx] = key;
+                self.voices[idx].note_on(note, velocity);
+                self.voices[idx].set_key_pan(self.current_params.key_pan_amount);
+                self.voice_mods[idx].start(note_id);
+                self.update_voice_parameters(idx, &lfo_params);
+                self.align_voice_lfos(idx);
+                self.retrigger_modulation(idx);
                 return;
             }
 
             // All voices active - use quietest-voice stealing
             let quietest_idx = self.find_quietest_voice();
+            self.voice_release_times[quietest_idx] = None;
+            self.voice_keys[quietest_idx] = key;
             self.voices[quietest_idx].note_on(note, velocity);
-            self.voices[quietest_idx].update_parameters(
-                &self.current_params.oscillators,
-                &self.current_params.filters,
-                &lfo_params,
-                &self.current_params.envelope,
-                &self.wavetable_library,
-            );
+            self.voices[quietest_idx].set_key_pan(self.current_params.key_pan_amount);
+            self.voice_mods[quietest_idx].start(note_id);
+            self.update_voice_parameters(quietest_idx, &lfo_params);
+            self.align_voice_lfos(quietest_idx);
+            self.retrigger_modulation(quietest_idx);
         }
     }
 
@@ -818,38 +1535,135 @@ impl SynthEngine {
     /// assert!(left.is_finite() && right.is_finite(), "Output should be finite");
     /// ```
     pub fn note_off(&mut self, note: u8) {
+        self.note_off_with_velocity(note, 0.0);
+    }
+
+    /// Trigger a note off carrying the controller's release velocity (0.0 to 1.0).
+    ///
+    /// Same as `note_off()`, except the release velocity shortens the amp release by
+    /// `VelocityParams::release_sensitivity` (a fast key lift releases faster). With the
+    /// default sensitivity of 0.0 the release velocity is ignored.
+    pub fn note_off_with_velocity(&mut self, note: u8, release_velocity: f32) {
+        // A repeated key between hits has nothing sounding to release
+        if self.note_repeat.release(note) == Some(false) {
+            return;
+        }
+        self.release_note(note, release_velocity);
+    }
+
+    /// Release a note on its voice (the body of `note_off_with_velocity()`, also used
+    /// when a note repeat gate closes)
+    fn release_note(&mut self, key: u8, release_velocity: f32) {
+        let key = key.min(127);
         if self.current_params.monophonic {
-            // Monophonic mode: remove note from stack
-            if let Some(pos) = self.note_stack.iter().position(|(n, _)| *n == note) {
+            // Monophonic mode: remove key from stack
+            if let Some(pos) = self.note_stack.iter().position(|(k, _)| *k == key) {
                 self.note_stack.remove(pos);
             }
 
-            // If there are still notes in the stack, retrigger the most recent one
-            if let Some(&(last_note, last_vel)) = self.note_stack.last() {
+            // If there are still keys in the stack, retrigger the most recent one
+            if let Some(&(last_key, last_vel)) = self.note_stack.last() {
                 // Last-note priority, legato: switch pitch without hard-resetting DSP.
                 let lfo_params = self.get_tempo_synced_lfo_params();
+                let last_note = self.played_notes[last_key as usize];
                 self.voices[0].note_change_legato(last_note, last_vel);
-                self.voices[0].update_parameters(
-                    &self.current_params.oscillators,
-                    &self.current_params.filters,
-                    &lfo_params,
-                    &self.current_params.envelope,
-                    &self.wavetable_library,
-                );
+                self.update_voice_parameters(0, &lfo_params);
             } else {
                 // No more notes in stack, release the voice
-                self.voices[0].note_off();
+                self.voices[0].note_off_with_velocity(release_velocity);
             }
         } else {
-            // Polyphonic mode: release all voices playing this note
-            for voice in &mut self.voices {
-                if voice.is_active() && voice.note() == note {
-                    voice.note_off();
+            // Polyphonic mode: release all voices this key started
+            for ((voice, release_time), &voice_key) in self
+                .voices
+                .iter_mut()
+                .zip(&mut self.voice_release_times)
+                .zip(&self.voice_keys)
+            {
+                if voice.is_active() && voice_key == key {
+                    voice.note_off_with_velocity(release_velocity);
+                    release_time.get_or_insert(self.sample_clock);
+                }
+            }
+        }
+
+        if self.current_params.paraphonic && !self.any_voice_held() {
+            self.paraphonic.note_off();
+        }
+    }
+
+    /// Retrigger held keys for note repeat, one sample's worth of the step clock.
+    ///
+    /// Turning note repeat off forgets the held keys: a key caught mid-hit keeps sounding
+    /// until its note-off, one caught between hits stays silent.
+    fn advance_note_repeat(&mut self) {
+        if self.note_repeat.is_empty() {
+            return;
+        }
+        let repeat = self.current_params.note_repeat;
+        if !repeat.enabled {
+            self.note_repeat.clear();
+            return;
+        }
+
+        let beats_per_second = self.current_tempo_bpm / 60.0;
+        let beats_per_sample = beats_per_second / self.sample_rate as f64;
+        let step_beats = Self::tempo_division_beats(repeat.tempo_sync)
+            .unwrap_or(beats_per_second / repeat.rate.clamp(1.0, 32.0) as f64);
+        for index in 0..self.note_repeat.len() {
+            let event = self.note_repeat.advance(
+                index,
+                beats_per_sample,
+                step_beats,
+                repeat.gate,
+                repeat.velocity_ramp,
+            );
+            match event {
+                Some(RepeatEvent::Release { note }) => self.release_note(note, 0.0),
+                Some(RepeatEvent::Retrigger {
+                    note,
+                    velocity,
+                    note_id,
+                    release_first,
+                }) => {
+                    if release_first {
+                        self.release_note(note, 0.0);
+                    }
+                    self.start_note(note, velocity, note_id);
                 }
+                None => {}
             }
         }
     }
 
+    /// Whether any voice is sounding a key that is still down (not releasing)
+    fn any_voice_held(&self) -> bool {
+        self.voices
+            .iter()
+            .any(|voice| voice.is_active() && voice.envelope_stage() != EnvelopeStage::Release)
+    }
+
+    /// Find the most recently released voice that is still within the poly legato window.
+    ///
+    /// Returns `None` when poly legato is off or no releasing voice qualifies.
+    fn find_legato_voice(&self) -> Option<usize> {
+        if !self.current_params.poly_legato {
+            return None;
+        }
+        let window_samples =
+            (self.current_params.legato_window_ms.max(0.0) * 0.001 * self.sample_rate) as u64;
+
+        self.voice_release_times
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, released)| released.map(|at| (idx, at)))
+            .filter(|&(idx, at)| {
+                self.voices[idx].is_active() && self.sample_clock - at <= window_samples
+            })
+            .max_by_key(|&(_, at)| at)
+            .map(|(idx, _)| idx)
+    }
+
     /// Find the voice with the lowest RMS energy (quietest voice).
     ///
     /// This is used for voice stealing: when all 16 voices are busy and a new note arrives,
@@ -862,42 +1676,89 @@ impl SynthEngine {
     /// perceived loudness than peak amplitude because it accounts for the overall power of
     /// the waveform, not just the highest point.
     ///
+    /// With `steal_prefers_release`, any voice in its release phase is taken before a held
+    /// one, however loud, so long pad tails can't protect themselves by level alone.
+    ///
+    /// Ties go to the lowest voice index (and levels compare with `total_cmp`, so even a
+    /// NaN peak orders consistently), keeping voice allocation reproducible.
+    ///
     /// # Returns
     /// Index of the quietest active voice, or 0 if no voices are active (edge case)
     fn find_quietest_voice(&self) -> usize {
+        let prefer_release = self.current_params.steal_prefers_release;
+        let is_held =
+            |voice: &Voice| prefer_release && voice.envelope_stage() != EnvelopeStage::Release;
         self.voices
             .iter()
             .enumerate()
             .filter(|(_, v)| v.is_active())
-            .min_by(|(_, a), (_, b)| {
-                a.peak_amplitude()
-                    .partial_cmp(&b.peak_amplitude())
-                    .unwrap_or(std::cmp::Ordering::Equal)
+            .min_by(|(a_idx, a), (b_idx, b)| {
+                is_held(a)
+                    .cmp(&is_held(b))
+                    .then(a.peak_amplitude().total_cmp(&b.peak_amplitude()))
+                    .then(a_idx.cmp(b_idx))
             })
             .map(|(idx, _)| idx)
             .unwrap_or(0)
     }
 
-    /// Immediately release all notes and silence the synthesizer.
+    /// Release all notes with a fast fade and silence the synthesizer.
     ///
-    /// This is called by MIDI "All Notes Off" (CC #123) or when the synthesizer needs to
-    /// be silenced instantly (e.g., panic button, channel mute, safety shutdown).
+    /// This is called by MIDI "All Notes Off" (CC #123) or when sounding notes need to stop
+    /// at once (e.g., transport stop, channel mute, a stuck note).
     ///
     /// Behavior:
     /// - Clears the note stack (monophonic mode)
-    /// - Calls reset() on all voices, which:
-    ///   - Stops the envelope immediately (no release phase)
-    ///   - Marks the voice as inactive
-    ///   - Clears all internal state (oscillator phases, filter memory, etc.)
-    ///
-    /// Unlike note_off(), this doesn't play the release envelope. It's a hard stop.
-    /// This is necessary for safety (user hits panic button) and proper cleanup (stop a
-    /// stuck note from sustaining forever).\n    
+    /// - Calls kill() on all voices, which fades each one out over a few milliseconds
+    ///   regardless of the release setting, then frees it
+    ///
+    /// Unlike note_off(), this doesn't play the release envelope, but unlike `panic()` it
+    /// doesn't cut loud voices off mid-waveform, which would click.
     pub fn all_notes_off(&mut self) {
         self.note_stack.clear();
+        self.note_repeat.clear();
+        self.voice_release_times = [None; MAX_POLYPHONY];
+        for voice in &mut self.voices {
+            voice.kill();
+        }
+        self.paraphonic.note_off();
+    }
+
+    /// Immediately stop every voice (hard reset, no fade).
+    ///
+    /// The emergency stop for a panic button or safety shutdown: voices are reset on the
+    /// spot, clearing oscillator phases and filter memory. Loud voices may click; use
+    /// `all_notes_off()` for a clean stop.
+    pub fn panic(&mut self) {
+        self.note_stack.clear();
+        self.note_repeat.clear();
+        self.voice_release_times = [None; MAX_POLYPHONY];
         for voice in &mut self.voices {
             voice.reset();
         }
+        self.paraphonic.reset();
+    }
+
+    /// Select a program (MIDI program change) from a preset bank.
+    ///
+    /// The preset's parameters are written through `producer` — the same triple-buffer path
+    /// every other parameter change takes — and picked up at the very next sample instead of
+    /// waiting out the rest of the update interval. Sounding notes keep playing with the new
+    /// parameters. Out-of-range program numbers are ignored.
+    ///
+    /// # Returns
+    /// The newly selected parameters (so the caller can sync its own copy), or `None` if
+    /// `program` is beyond the end of the bank
+    pub fn select_program(
+        &mut self,
+        program: u8,
+        bank: &crate::plugin::state::PresetBank,
+        producer: &mut Input<SynthParams>,
+    ) -> Option<SynthParams> {
+        let params = *bank.get(program)?.params();
+        producer.write(params);
+        self.sample_counter = self.param_update_interval;
+        Some(params)
     }
 
     /// Get the count of currently active voices.
@@ -919,11 +1780,10 @@ impl SynthEngine {
 
     /// Get the configured sample rate of this engine.
     ///
-    /// This returns the sample rate that was passed to new(). It's constant and never changes
-    /// after engine creation. Used primarily for:
+    /// This returns the sample rate passed to new() or the last `set_sample_rate()` call.
+    /// Used primarily for:
     /// - Debugging/monitoring (verify the engine is at the expected rate)
     /// - Reporting to the user or UI
-    /// - Re-creating the engine at a different rate (not hot-swappable)
     ///
     /// # Returns
     /// Sample rate in Hz (e.g., 44100.0 for CD quality)
@@ -931,11 +1791,348 @@ impl SynthEngine {
         self.sample_rate
     }
 
-    /// Set the current tempo from DAW transport (CLAP plugin only)
+    /// Switch the engine to a new sample rate (e.g., the output device changed).
+    ///
+    /// Every voice, filter and effect derives coefficients and buffer sizes from the rate,
+    /// so this rebuilds the engine in place. The parameter buffer, modulation feed and tempo
+    /// carry over; the current parameters are re-applied on the next update. Sounding notes
+    /// are cut. Allocates - call it while the audio stream is stopped, never from the callback.
+    ///
+    /// # Arguments
+    /// * `sample_rate` - New sample rate in Hz
+    pub fn set_sample_rate(&mut self, sample_rate: f32) {
+        if sample_rate == self.sample_rate {
+            return;
+        }
+
+        let (_, placeholder) = TripleBuffer::new(&SynthParams::default()).split();
+        let params_consumer = std::mem::replace(&mut self.params_consumer, placeholder);
+        let modulation_feed = self.modulation_feed.take();
+        let output_history = self.output_history.take().map(|tap| tap.shared().clone());
+        let tempo = self.current_tempo_bpm;
+        let auditioned_effect = self.auditioned_effect;
+        let midi_channel_filter = self.midi_channel_filter;
+        let scale_lock_enabled = self.scale_lock_enabled;
+        let (scale, root) = (
+            self.scale_quantizer.scale_type(),
+            self.scale_quantizer.root_note(),
+        );
+
+        *self = Self::new(sample_rate, params_consumer);
+        self.modulation_feed = modulation_feed;
+        if let Some(history) = output_history {
+            self.set_output_history(history);
+        }
+        self.set_tempo(tempo);
+        self.set_scale_lock(scale, root, scale_lock_enabled);
+        self.auditioned_effect = auditioned_effect;
+        self.midi_channel_filter = midi_channel_filter;
+        // Apply the live parameters on the very first sample
+        self.sample_counter = self.param_update_interval;
+    }
+
+    /// Start publishing effective (post-modulation) parameter values for the GUI.
+    ///
+    /// Returns the consumer end of a triple-buffer that the engine refreshes at
+    /// `MODULATION_FEED_HZ` with a `ModulationSnapshot` taken from the loudest voice.
+    /// Calling this again replaces the previous feed.
+    ///
+    /// # Returns
+    /// Lock-free consumer for the GUI thread
+    pub fn modulation_feed(&mut self) -> Output<ModulationSnapshot> {
+        let (producer, consumer) = TripleBuffer::new(&ModulationSnapshot::default()).split();
+        self.modulation_feed = Some(producer);
+        self.modulation_feed_counter = self.modulation_feed_interval;
+        consumer
+    }
+
+    /// Keep the last `OUTPUT_HISTORY_SECONDS` of output in `history` for off-thread
+    /// analysis such as `mono_compatibility_report()`.
+    ///
+    /// The history is cleared and resized for this engine's sample rate, so attach it
+    /// before the engine moves to the audio thread. The audio thread only ever `try_lock`s
+    /// it, so readers can't stall the output.
+    pub fn set_output_history(&mut self, history: SharedOutputHistory) {
+        *history.lock() = OutputHistory::new(self.sample_rate);
+        self.output_history = Some(OutputHistoryTap::new(history));
+    }
+
+    /// Publish a modulation snapshot if a feed is attached and the interval has elapsed
+    #[inline]
+    fn maybe_publish_modulation(&mut self) {
+        if self.modulation_feed.is_none() {
+            return;
+        }
+        self.modulation_feed_counter += 1;
+        if self.modulation_feed_counter < self.modulation_feed_interval {
+            return;
+        }
+        self.modulation_feed_counter = 0;
+
+        let loudest = self
+            .voices
+            .iter()
+            .filter(|v| v.is_active())
+            .max_by(|a, b| a.peak_amplitude().total_cmp(&b.peak_amplitude()));
+
+        let mut snapshot = ModulationSnapshot::default();
+        if let Some(voice) = loudest {
+            for (slot, value) in snapshot.values.iter_mut().enumerate() {
+                *value = voice.effective_cutoff(slot);
+            }
+        }
+        snapshot.held_notes = Self::held_notes_of(&self.voices);
+        snapshot.last_note = self.last_note;
+        snapshot.effect_levels = self.effect_levels();
+        if let Some(producer) = self.modulation_feed.as_mut() {
+            producer.write(snapshot);
+        }
+    }
+
+    /// Notes whose voices are sounding and not yet released
+    ///
+    /// A note that stays here after its key is up is a stuck note.
+    pub fn held_notes(&self) -> HeldNotes {
+        Self::held_notes_of(&self.voices)
+    }
+
+    fn held_notes_of(voices: &[Voice]) -> HeldNotes {
+        let mut notes = HeldNotes::default();
+        for voice in voices {
+            if voice.is_active() && voice.envelope_stage() != EnvelopeStage::Release {
+                notes.insert(voice.note());
+            }
+        }
+        notes
+    }
+
+    /// Output activity of each effect, as shown by the GUI meters
+    ///
+    /// Peaks are only tracked while a modulation feed is attached (they read 0 otherwise).
+    pub fn effect_levels(&self) -> EffectLevels {
+        let compressing = !self.current_params.effects_bypass
+            && self.effect_fades[EffectSlot::Compressor.index()] > 0.0;
+        EffectLevels {
+            peaks: self.effect_peaks,
+            compressor_reduction_db: if compressing {
+                self.compressor.gain_reduction_db()
+            } else {
+                0.0
+            },
+        }
+    }
+
+    /// Hear only one effect's wet contribution while dialing it in (`None` ends it).
+    ///
+    /// The dry signal and every other effect are muted at the output; delay and reverb
+    /// play their pure wet signal, insert effects the difference they make to their input.
+    /// A disabled effect auditions as silence, and the global effects bypass overrides the
+    /// audition. This is a transient sound-design aid: it isn't a parameter and is never
+    /// saved with presets.
+    pub fn audition_effect(&mut self, slot: Option<EffectSlot>) {
+        self.auditioned_effect = slot;
+    }
+
+    /// Effect currently being auditioned, if any
+    pub fn auditioned_effect(&self) -> Option<EffectSlot> {
+        self.auditioned_effect
+    }
+
+    /// Listen to the master output in mono, one channel only, or mid/side solo.
+    ///
+    /// Applied after the limiter to the final output, for checking mono compatibility
+    /// and stereo content. Like `audition_effect()` this is a monitoring aid that isn't a
+    /// parameter and is never saved with presets.
+    pub fn set_monitor_mode(&mut self, mode: MonitorMode) {
+        self.monitor_mode = mode;
+    }
+
+    /// Current monitoring mode
+    pub fn monitor_mode(&self) -> MonitorMode {
+        self.monitor_mode
+    }
+
+    /// Respond only to note events on one MIDI channel (0-15), or to all with `None` (omni).
+    ///
+    /// The engine's note methods carry no channel, so the plugin wrapper does the dropping
+    /// by checking `accepts_midi_channel()`. Lets several instances share one MIDI track,
+    /// each playing its own channel. The preset's `midi_channel` sets this when loaded.
+    pub fn set_midi_channel_filter(&mut self, channel: Option<u8>) {
+        self.midi_channel_filter = channel.map(|c| c.min(15));
+    }
+
+    /// MIDI channel note events are accepted on, `None` = omni
+    pub fn midi_channel_filter(&self) -> Option<u8> {
+        self.midi_channel_filter
+    }
+
+    /// Whether an event on `channel` (0-15) passes the MIDI channel filter
+    pub fn accepts_midi_channel(&self, channel: u8) -> bool {
+        self.midi_channel_filter.is_none_or(|c| c == channel)
+    }
+
+    /// Modulate a parameter for a single sounding note (CLAP polyphonic modulation).
+    ///
+    /// The note is found by the ID it was started with (`note_on_with_id()`), or by key
+    /// when `note_id` is `None`. `amount` is an offset in normalized units added to the
+    /// parameter's current value for that voice only, replacing any earlier offset for
+    /// the same parameter (0.0 removes it). Only voice-level parameters (oscillators,
+    /// voice filters, LFOs, amp envelope) have an effect.
+    ///
+    /// # Returns
+    /// Whether a sounding voice matched
+    pub fn modulate_voice_param(
+        &mut self,
+        note_id: Option<i32>,
+        key: Option<u8>,
+        param_id: ParamId,
+        amount: f32,
+    ) -> bool {
+        let played_key = key.map(|k| self.played_notes[k.min(127) as usize]);
+        let lfo_params = self.get_tempo_synced_lfo_params();
+        let mut matched = false;
+        for idx in 0..self.voices.len() {
+            if !self.voices[idx].is_active() {
+                continue;
+            }
+            let targeted = match note_id {
+                Some(id) => self.voice_mods[idx].note_id() == Some(id),
+                None => played_key == Some(self.voices[idx].note()),
+            };
+            if targeted {
+                self.voice_mods[idx].set(param_id, amount);
+                self.update_voice_parameters(idx, &lfo_params);
+                matched = true;
+            }
+        }
+        matched
+    }
+
+    /// Last note-on received: (note, velocity 0.0-1.0)
+    pub fn last_note(&self) -> Option<(u8, f32)> {
+        self.last_note
+    }
+
+    /// Get how long the effects keep ringing after the input goes silent, in samples.
+    ///
+    /// Derived from the reverb decay and the delay time × feedback of the enabled effects,
+    /// and recomputed whenever effect parameters change. Plugin hosts use this to keep
+    /// processing (and recording) after the last note so tails aren't truncated on bounce.
+    ///
+    /// # Returns
+    /// Tail length in samples (0 when no time-based effect is enabled)
+    pub fn tail_length_samples(&self) -> u32 {
+        self.tail_length_samples
+    }
+
+    /// Get the processing latency the plugin reports to the host, in samples.
+    ///
+    /// The pitch shifter reads its shifted signal from a delay line and the compressor
+    /// delays its audio by its lookahead, so their output trails the input; while they're
+    /// enabled, hosts compensate by that delay. Recomputed whenever effect parameters change.
+    pub fn latency_samples(&self) -> u32 {
+        self.latency_samples
+    }
+
+    /// Get the character of the mix entering the effects chain, from its zero-crossing rate.
+    ///
+    /// Tracked every sample (over a 20 ms window), so effects and the GUI can adapt to
+    /// what's playing: `Tonal` for pitched notes, up to `VeryNoisy` for noise and hiss.
+    /// The adaptive compressor uses it to pick its attack.
+    pub fn current_signal_type(&self) -> SignalType {
+        self.signal_classifier.classify_signal()
+    }
+
+    /// Report how much of the recent output is lost when summed to mono.
+    ///
+    /// Analyses the output an engine kept in `history` (see `set_output_history()`). Each
+    /// band's mono-sum loss is measured (see `MonoCompatibilityReport`); when any band
+    /// cancels worse than uncorrelated stereo, the enabled width settings in `params` that
+    /// could be responsible are listed in `flagged_settings`. The history is copied under
+    /// a brief lock and analysed on the calling thread, so call it from a worker thread,
+    /// never the audio callback.
+    ///
+    /// # Returns
+    /// The report, or `None` until the engine has played anything into the history
+    ///
+    /// # Example
+    /// ```
+    /// use dsynth::audio::engine::{create_parameter_buffer, OutputHistory, SynthEngine};
+    /// use dsynth::params::SynthParams;
+    /// let (_producer, consumer) = create_parameter_buffer();
+    /// let mut engine = SynthEngine::new(44100.0, consumer);
+    /// let history = OutputHistory::shared(44100.0);
+    /// engine.set_output_history(history.clone());
+    ///
+    /// engine.note_on(60, 0.8);
+    /// for _ in 0..11025 {
+    ///     engine.process();
+    /// }
+    /// let report = SynthEngine::mono_compatibility_report(&history, &SynthParams::default())
+    ///     .expect("output was recorded");
+    /// assert!(!report.is_problematic(), "a centred note sums to mono cleanly");
+    /// ```
+    pub fn mono_compatibility_report(
+        history: &SharedOutputHistory,
+        params: &SynthParams,
+    ) -> Option<MonoCompatibilityReport> {
+        let ((left, right), sample_rate) = {
+            let history = history.lock();
+            if history.is_empty() {
+                return None;
+            }
+            (history.snapshot(), history.sample_rate())
+        };
+
+        let mut report = MonoCompatibilityReport::analyze(&left, &right, sample_rate);
+        if report.is_problematic() {
+            report.flagged_settings = Self::width_settings(params);
+        }
+        Some(report)
+    }
+
+    /// Enabled settings that widen (and can decorrelate) the stereo image
+    fn width_settings(params: &SynthParams) -> Vec<&'static str> {
+        if params.effects_bypass {
+            return Vec::new();
+        }
+        let effects = &params.effects;
+        let widener = &effects.stereo_widener;
+        [
+            (
+                widener.enabled
+                    && (widener.haas_mix > 0.0 || widener.width > 1.0 || widener.side_gain > 1.0),
+                "Stereo Widener",
+            ),
+            (
+                effects.mid_side.enabled && effects.mid_side.side_gain > 1.0,
+                "Mid/Side",
+            ),
+            (
+                effects.chorus.enabled && effects.chorus.spread > 0.0,
+                "Chorus",
+            ),
+            (
+                effects.doubler.enabled && effects.doubler.spread > 0.0,
+                "Doubler",
+            ),
+            (effects.choir.enabled && effects.choir.spread > 0.0, "Choir"),
+            (
+                effects.reverb.enabled && effects.reverb.width > 0.0,
+                "Reverb",
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(widening, name)| widening.then_some(name))
+        .collect()
+    }
+
+    /// Set the current tempo from DAW transport (CLAP plugin) or MIDI clock (standalone)
     ///
     /// This updates the internal tempo used for tempo-synced LFO and effect rates.
     /// When tempo_sync mode is not Hz, the rate parameter is converted to Hz based
-    /// on the current tempo using musical divisions (1/4, 1/8T, etc.).
+    /// on the current tempo using musical divisions (1/4, 1/8T, etc.). A changed tempo
+    /// is applied at the next parameter update.
     ///
     /// # Arguments
     /// * `bpm` - Tempo in beats per minute (clamped to 20.0-999.0 for sanity)
@@ -949,7 +2146,161 @@ impl SynthEngine {
     /// engine.set_tempo(140.0); // Set tempo to 140 BPM
     /// ```
     pub fn set_tempo(&mut self, bpm: f64) {
-        self.current_tempo_bpm = bpm.clamp(20.0, 999.0);
+        let bpm = bpm.clamp(20.0, 999.0);
+        if bpm != self.current_tempo_bpm {
+            self.current_tempo_bpm = bpm;
+            self.tempo_changed = true;
+        }
+    }
+
+    /// Lock incoming notes to a scale
+    ///
+    /// While enabled, every note-on is snapped to the nearest note of `scale` (rooted at
+    /// `root`) before a voice is allocated, so off-scale keys can't sound. Notes already
+    /// held keep their pitch and are released normally.
+    ///
+    /// # Example
+    /// ```
+    /// use dsynth::audio::engine::{SynthEngine, create_parameter_buffer};
+    /// use dsynth::dsp::{RootNote, ScaleType};
+    /// let (_producer, consumer) = create_parameter_buffer();
+    /// let mut engine = SynthEngine::new(44100.0, consumer);
+    ///
+    /// engine.set_scale_lock(ScaleType::Minor, RootNote::A, true); // A natural minor
+    /// engine.note_on(61, 0.8); // C# is not in A minor: plays C
+    /// ```
+    pub fn set_scale_lock(&mut self, scale: ScaleType, root: RootNote, enabled: bool) {
+        self.scale_quantizer.set_scale_type(scale);
+        self.scale_quantizer.set_root_note(root);
+        self.scale_lock_enabled = enabled;
+    }
+
+    /// Note to play for an incoming note, remembering it for the matching note-off
+    fn lock_to_scale(&mut self, note: u8) -> u8 {
+        let note = note.min(127);
+        let played = if self.scale_lock_enabled {
+            self.scale_quantizer.quantize_note(note)
+        } else {
+            note
+        };
+        self.played_notes[note as usize] = played;
+        played
+    }
+
+    /// Restart every voice's LFOs from phase zero
+    ///
+    /// Used on transport start (MIDI Start/Stop in standalone) so synced LFOs line up
+    /// with the external sequencer's downbeat.
+    pub fn reset_lfo_phases(&mut self) {
+        for voice in &mut self.voices {
+            voice.reset_lfo_phases();
+        }
+    }
+
+    /// Set the host song position (in beats) at the start of a block
+    ///
+    /// Tempo-synced LFOs and modulation effects are locked to the timeline: when the position
+    /// doesn't follow on from the previous report (playback start, seek, loop), every synced
+    /// LFO jumps to the phase it would have if it had been running since beat 0, so synced
+    /// modulation lands the same way on every playback. Voices starting a note also pick up
+    /// the timeline phase. Call `clear_song_position` when the transport stops.
+    ///
+    /// # Example
+    /// ```
+    /// use dsynth::audio::engine::{SynthEngine, create_parameter_buffer};
+    /// let (_producer, consumer) = create_parameter_buffer();
+    /// let mut engine = SynthEngine::new(44100.0, consumer);
+    ///
+    /// engine.set_tempo(120.0);
+    /// engine.set_song_position(16.0); // Playback starts at bar 5 (4/4)
+    /// ```
+    pub fn set_song_position(&mut self, beats: f64) {
+        let continuous = self
+            .song_position_beats()
+            .is_some_and(|expected| (beats - expected).abs() <= SONG_POSITION_JUMP_BEATS);
+        self.song_position = Some((beats, self.sample_clock));
+        if !continuous {
+            self.align_synced_lfos();
+        }
+    }
+
+    /// Forget the song position (transport stopped); synced LFOs run freely again
+    pub fn clear_song_position(&mut self) {
+        self.song_position = None;
+    }
+
+    /// Song position now, extrapolated from the last report at the current tempo
+    fn song_position_beats(&self) -> Option<f64> {
+        let (beats, reported_at) = self.song_position?;
+        let elapsed = self.sample_clock.saturating_sub(reported_at) as f64;
+        Some(beats + elapsed * self.current_tempo_bpm / 60.0 / self.sample_rate as f64)
+    }
+
+    /// Timeline phase (0.0-1.0) of an LFO synced to `tempo_sync`
+    ///
+    /// `None` in Hz mode or when no song position is known.
+    fn song_phase(&self, tempo_sync: crate::params::TempoSync) -> Option<f32> {
+        let beats_per_cycle = Self::tempo_division_beats(tempo_sync)?;
+        let position = self.song_position_beats()?;
+        Some((position / beats_per_cycle).rem_euclid(1.0) as f32)
+    }
+
+    /// Move every tempo-synced LFO (voices and modulation effects) to its timeline phase
+    fn align_synced_lfos(&mut self) {
+        for idx in 0..self.voices.len() {
+            self.align_voice_lfos(idx);
+        }
+
+        let effects = &self.current_params.effects;
+        let syncs = [
+            effects.chorus.tempo_sync,
+            effects.phaser.tempo_sync,
+            effects.flanger.tempo_sync,
+            effects.tremolo.tempo_sync,
+            effects.auto_pan.tempo_sync,
+        ];
+        let phases = syncs.map(|sync| self.song_phase(sync));
+        if let Some(phase) = phases[0] {
+            self.chorus.set_phase(phase);
+        }
+        if let Some(phase) = phases[1] {
+            self.phaser.set_phase(phase);
+        }
+        if let Some(phase) = phases[2] {
+            self.flanger.set_phase(phase);
+        }
+        if let Some(phase) = phases[3] {
+            self.tremolo.set_phase(phase);
+        }
+        if let Some(phase) = phases[4] {
+            self.auto_pan.set_phase(phase);
+        }
+    }
+
+    /// Move one voice's tempo-synced LFOs to their timeline phase
+    fn align_voice_lfos(&mut self, idx: usize) {
+        let lfos = self.current_params.lfos;
+        for (i, lfo) in lfos.iter().enumerate() {
+            if let Some(phase) = self.song_phase(lfo.tempo_sync) {
+                self.voices[idx].set_lfo_phase(i, phase);
+            }
+        }
+    }
+
+    /// Restart a freshly triggered voice's LFOs and the modulation effects' LFOs from
+    /// phase 0 when `retrigger_mod_on_note` is set, so every note hears the same sweep.
+    ///
+    /// Legato note changes keep the running phases.
+    fn retrigger_modulation(&mut self, idx: usize) {
+        if !self.current_params.retrigger_mod_on_note {
+            return;
+        }
+        self.voices[idx].reset_lfo_phases();
+        self.chorus.reset_phase();
+        self.phaser.reset_phase();
+        self.flanger.reset_phase();
+        self.tremolo.reset_phase();
+        self.auto_pan.reset_phase();
     }
 
     /// Convert tempo sync mode to Hz based on current tempo
@@ -974,15 +2325,54 @@ impl SynthEngine {
     /// Frequency in Hz, clamped to 0.01-20.0 Hz
     #[inline]
     fn tempo_division_to_hz(sync_mode: crate::params::TempoSync, bpm: f64) -> f32 {
-        use crate::params::TempoSync;
-
-        if sync_mode == TempoSync::Hz {
+        // Calculate beats per cycle based on musical division
+        let Some(beats_per_cycle) = Self::tempo_division_beats(sync_mode) else {
             return 0.0; // Signal to use raw Hz value
-        }
+        };
+
+        // Convert BPM to cycles per second
+        let beats_per_second = bpm / 60.0;
+        let cycles_per_second = beats_per_second / beats_per_cycle;
+
+        // Clamp to valid LFO/effect rate range (0.01 to 20 Hz)
+        (cycles_per_second as f32).clamp(0.01, 20.0)
+    }
+
+    /// Convert tempo sync mode to a delay time in milliseconds based on current tempo
+    ///
+    /// This is the time-domain counterpart of `tempo_division_to_hz`, used by effects whose
+    /// main control is a duration rather than a rate (the delay). One cycle of the musical
+    /// division at the given tempo is returned as milliseconds:
+    /// - Quarter (1/4) at 120 BPM = 500 ms
+    /// - Dotted eighth (1/8D) at 120 BPM = 375 ms
+    /// - Eighth triplet (1/8T) at 120 BPM = 166.7 ms
+    ///
+    /// # Arguments
+    /// * `sync_mode` - The tempo sync mode (Hz, Quarter, EighthT, etc.)
+    /// * `bpm` - Tempo in beats per minute
+    ///
+    /// # Returns
+    /// Delay time in ms, clamped to the delay's buffer length (1.0 to `MAX_DELAY_MS`).
+    /// Returns 0.0 for `TempoSync::Hz` to signal that the raw time value should be used.
+    #[inline]
+    fn tempo_division_to_ms(sync_mode: crate::params::TempoSync, bpm: f64) -> f32 {
+        use crate::dsp::effects::time_based::delay::MAX_DELAY_MS;
+
+        let Some(beats_per_cycle) = Self::tempo_division_beats(sync_mode) else {
+            return 0.0; // Signal to use raw ms value
+        };
+
+        let ms_per_beat = 60_000.0 / bpm;
+        ((beats_per_cycle * ms_per_beat) as f32).clamp(1.0, MAX_DELAY_MS)
+    }
+
+    /// Number of beats in one cycle of a musical division, or `None` for `TempoSync::Hz`.
+    #[inline]
+    fn tempo_division_beats(sync_mode: crate::params::TempoSync) -> Option<f64> {
+        use crate::params::TempoSync;
 
-        // Calculate beats per cycle based on musical division
         let beats_per_cycle = match sync_mode {
-            TempoSync::Hz => return 0.0,
+            TempoSync::Hz => return None,
             TempoSync::Whole => 4.0,
             TempoSync::Half => 2.0,
             TempoSync::Quarter => 1.0,
@@ -996,13 +2386,7 @@ impl SynthEngine {
             TempoSync::EighthD => 0.75,
             TempoSync::SixteenthD => 0.375,
         };
-
-        // Convert BPM to cycles per second
-        let beats_per_second = bpm / 60.0;
-        let cycles_per_second = beats_per_second / beats_per_cycle;
-
-        // Clamp to valid LFO/effect rate range (0.01 to 20 Hz)
-        (cycles_per_second as f32).clamp(0.01, 20.0)
+        Some(beats_per_cycle)
     }
 
     /// Get the effective rate for an LFO or effect, applying tempo sync if needed
@@ -1033,14 +2417,16 @@ impl SynthEngine {
             self.previous_sync_modes[sync_index] = tempo_sync;
 
             // Reset phase when sync mode changes
-            // This ensures predictable timing when switching sync modes
+            // This ensures predictable timing when switching sync modes; while the host
+            // transport plays, the phase follows the timeline instead of restarting at 0
+            let phase = self.song_phase(tempo_sync).unwrap_or(0.0);
             match sync_index {
-                3 => self.chorus.reset_phase(),   // Chorus
-                4 => self.phaser.reset_phase(),   // Phaser
-                5 => self.flanger.reset_phase(),  // Flanger
-                6 => self.tremolo.reset_phase(),  // Tremolo
-                7 => self.auto_pan.reset_phase(), // AutoPan
-                _ => {}                           // LFOs handled separately in voice update
+                3 => self.chorus.set_phase(phase),   // Chorus
+                4 => self.phaser.set_phase(phase),   // Phaser
+                5 => self.flanger.set_phase(phase),  // Flanger
+                6 => self.tremolo.set_phase(phase),  // Tremolo
+                7 => self.auto_pan.set_phase(phase), // AutoPan
+                _ => {}                              // LFOs handled separately in voice update
             }
         }
 
@@ -1061,9 +2447,17 @@ impl SynthEngine {
     /// Array of 3 LFO params with tempo-synced rates applied
     #[inline]
     fn get_tempo_synced_lfo_params(&self) -> [crate::params::LFOParams; 3] {
+        self.tempo_synced_lfos(self.current_params.lfos)
+    }
+
+    /// `lfos` with tempo-synced rates converted to Hz at the current tempo
+    fn tempo_synced_lfos(
+        &self,
+        lfos: [crate::params::LFOParams; 3],
+    ) -> [crate::params::LFOParams; 3] {
         use crate::params::TempoSync;
 
-        let mut modified_lfos = self.current_params.lfos;
+        let mut modified_lfos = lfos;
         for lfo_params in modified_lfos.iter_mut() {
             if lfo_params.tempo_sync != TempoSync::Hz {
                 lfo_params.rate =
@@ -1123,6 +2517,10 @@ impl SynthEngine {
     /// True stereo output is preserved: left and right channels are generated independently
     /// by the synthesizer's panning, stereo effects, and spatial processing.
     ///
+    /// The output doesn't depend on how a render is split into blocks: the parameter
+    /// throttle, envelopes and LFOs all count samples, not calls. Callers handling timed
+    /// events should split the block at each event's offset (as the CLAP plugin does).
+    ///
     /// # Arguments
     /// * `left` - Output buffer for left channel (will be filled with samples)
     /// * `right` - Output buffer for right channel (will be filled with samples)
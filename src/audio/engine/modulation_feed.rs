@@ -0,0 +1,133 @@
+//! Engine → GUI feed of effective (post-modulation) parameter values, held notes and
+//! effect activity.
+//!
+//! Knobs whose parameter is being moved by LFOs, envelopes, velocity or key tracking can
+//! draw a secondary ring at the value the audio is actually using, the note display
+//! highlights the keys the engine thinks are down, and each effect section shows a level
+//! meter. The engine publishes a small snapshot
+//! through a lock-free triple-buffer at `MODULATION_FEED_HZ`, so the audio thread never
+//! blocks and the GUI never sees more updates than it can draw.
+
+use super::EFFECT_COUNT;
+use crate::plugin::param_descriptor::{
+    ParamId, PARAM_FILTER1_CUTOFF, PARAM_FILTER2_CUTOFF, PARAM_FILTER3_CUTOFF,
+};
+use parking_lot::Mutex;
+use std::sync::Arc;
+use triple_buffer::Output;
+
+/// Feed consumer shared with the GUI. The slot is refilled whenever the engine is rebuilt
+/// (e.g. plugin re-activation) so an open editor keeps receiving snapshots.
+pub type SharedModulationFeed = Arc<Mutex<Option<Output<ModulationSnapshot>>>>;
+
+/// How often the engine publishes a snapshot (GUI frame rate is plenty)
+pub const MODULATION_FEED_HZ: f32 = 30.0;
+
+/// Parameters the feed reports, in snapshot slot order
+pub const MODULATION_TARGETS: [ParamId; 3] = [
+    PARAM_FILTER1_CUTOFF,
+    PARAM_FILTER2_CUTOFF,
+    PARAM_FILTER3_CUTOFF,
+];
+
+/// Set of MIDI notes (0-127), stored as a bit mask so it copies through the feed for free
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HeldNotes(u128);
+
+impl HeldNotes {
+    pub fn insert(&mut self, note: u8) {
+        if note < 128 {
+            self.0 |= 1 << note;
+        }
+    }
+
+    pub fn contains(&self, note: u8) -> bool {
+        note < 128 && self.0 & (1 << note) != 0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// Notes in ascending order
+    pub fn iter(&self) -> impl Iterator<Item = u8> + '_ {
+        (0..128u8).filter(move |&note| self.contains(note))
+    }
+}
+
+/// Effects of the master chain, in chain order (the index into `EffectLevels::peaks`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EffectSlot {
+    Gate,
+    TransientShaper,
+    Compressor,
+    Distortion,
+    Waveshaper,
+    Bitcrusher,
+    MultibandDistortion,
+    Exciter,
+    SibilanceEq,
+    FormantFilter,
+    CombFilter,
+    Phaser,
+    Flanger,
+    RingMod,
+    Tremolo,
+    Chorus,
+    Doubler,
+    Choir,
+    PitchShifter,
+    Delay,
+    AutoPan,
+    MidSide,
+    StereoWidener,
+    Reverb,
+}
+
+impl EffectSlot {
+    pub fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// Output activity of every effect in the chain
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct EffectLevels {
+    /// Decaying output peak per effect (linear, 0 while the effect is bypassed)
+    pub peaks: [f32; EFFECT_COUNT],
+    /// Current compressor gain reduction in dB (positive, 0 when not compressing)
+    pub compressor_reduction_db: f32,
+}
+
+impl EffectLevels {
+    pub fn peak(&self, slot: EffectSlot) -> f32 {
+        self.peaks[slot.index()]
+    }
+}
+
+/// Effective values of the modulation targets, in parameter units (denormalized)
+///
+/// Values come from the loudest sounding voice; a slot is `None` while nothing is playing.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ModulationSnapshot {
+    pub values: [Option<f32>; MODULATION_TARGETS.len()],
+    /// Notes whose voices are still held (not yet released)
+    pub held_notes: HeldNotes,
+    /// Last note-on received: (note, velocity 0.0-1.0)
+    pub last_note: Option<(u8, f32)>,
+    pub effect_levels: EffectLevels,
+}
+
+impl ModulationSnapshot {
+    /// Effective value for a parameter, if it's a modulation target and a voice is sounding
+    pub fn get(&self, param_id: ParamId) -> Option<f32> {
+        MODULATION_TARGETS
+            .iter()
+            .position(|&id| id == param_id)
+            .and_then(|slot| self.values[slot])
+    }
+}
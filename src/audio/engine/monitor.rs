@@ -0,0 +1,43 @@
+//! Master output monitoring modes for checking a mix.
+//!
+//! Applied after the limiter, so what's heard is exactly the final output folded or
+//! split. Like effect audition this is a listening aid: it is engine state set by the
+//! GUI or host wrapper, not a parameter, and never saved with presets.
+
+/// How the final stereo output is sent to the speakers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MonitorMode {
+    /// Normal stereo output
+    #[default]
+    Stereo,
+    /// Both channels summed, (L + R) / 2 on each side
+    Mono,
+    /// Left channel on both sides
+    Left,
+    /// Right channel on both sides
+    Right,
+    /// Mid signal (L + R) / 2 on both sides (the mono fold of the mid/side split)
+    Mid,
+    /// Side signal (L - R) / 2, inverted on the right so it stays audible on speakers
+    Side,
+}
+
+impl MonitorMode {
+    /// Fold one stereo output sample for this mode
+    #[inline]
+    pub fn apply(self, left: f32, right: f32) -> (f32, f32) {
+        match self {
+            MonitorMode::Stereo => (left, right),
+            MonitorMode::Mono | MonitorMode::Mid => {
+                let mid = (left + right) * 0.5;
+                (mid, mid)
+            }
+            MonitorMode::Left => (left, left),
+            MonitorMode::Right => (right, right),
+            MonitorMode::Side => {
+                let side = (left - right) * 0.5;
+                (side, -side)
+            }
+        }
+    }
+}
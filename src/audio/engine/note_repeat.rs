@@ -0,0 +1,125 @@
+//! Note repeat (roll) for held keys.
+//!
+//! While note repeat is on, every held key is retriggered once per step of the chosen
+//! division, for hi-hat rolls and stutters. Each hit is held for the gate portion of
+//! the step and then released; its velocity starts at the key's velocity and ramps by
+//! `velocity_ramp` per beat for the whole time the key is down. Repeats follow the
+//! engine tempo, so changing the tempo or rate mid-roll bends the step length without
+//! restarting it.
+
+/// Quietest velocity a downward ramp can reach, so a long roll never goes fully silent
+const MIN_REPEAT_VELOCITY: f32 = 0.05;
+
+/// A held key being repeated
+#[derive(Debug, Clone, Copy)]
+struct HeldNote {
+    note: u8,
+    velocity: f32,
+    note_id: Option<i32>,
+    /// Position within the current step (0.0-1.0)
+    step_phase: f64,
+    /// Beats since the key went down, for the velocity ramp
+    held_beats: f64,
+    /// The current hit hasn't been released by the gate yet
+    gate_open: bool,
+}
+
+/// What a held key does on this sample
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) enum RepeatEvent {
+    /// The gate closed: release the note until the next step
+    Release { note: u8 },
+    /// A new step: retrigger the note (releasing the previous hit first if still gated)
+    Retrigger {
+        note: u8,
+        velocity: f32,
+        note_id: Option<i32>,
+        release_first: bool,
+    },
+}
+
+/// Held keys and their step clocks
+pub(super) struct NoteRepeat {
+    held: Vec<HeldNote>,
+}
+
+impl NoteRepeat {
+    pub(super) fn new() -> Self {
+        Self {
+            held: Vec::with_capacity(128),
+        }
+    }
+
+    pub(super) fn len(&self) -> usize {
+        self.held.len()
+    }
+
+    pub(super) fn is_empty(&self) -> bool {
+        self.held.is_empty()
+    }
+
+    /// A key went down: its first hit plays now and the step clock starts
+    pub(super) fn press(&mut self, note: u8, velocity: f32, note_id: Option<i32>) {
+        self.held.retain(|held| held.note != note);
+        self.held.push(HeldNote {
+            note,
+            velocity,
+            note_id,
+            step_phase: 0.0,
+            held_beats: 0.0,
+            gate_open: true,
+        });
+    }
+
+    /// A key came up. Returns `Some(sounding)` if the key was being repeated, where
+    /// `sounding` says whether the current hit still needs a note-off.
+    pub(super) fn release(&mut self, note: u8) -> Option<bool> {
+        let index = self.held.iter().position(|held| held.note == note)?;
+        Some(self.held.swap_remove(index).gate_open)
+    }
+
+    /// Forget every held key (the notes themselves are left to the caller)
+    pub(super) fn clear(&mut self) {
+        self.held.clear();
+    }
+
+    /// Advance held key `index` by one sample
+    ///
+    /// # Arguments
+    /// * `beats_per_sample` - Tempo as beats per sample
+    /// * `step_beats` - Length of one repeat step in beats
+    /// * `gate` - Portion of the step each hit is held (0.05 to 1.0)
+    /// * `velocity_ramp` - Velocity change per beat held
+    pub(super) fn advance(
+        &mut self,
+        index: usize,
+        beats_per_sample: f64,
+        step_beats: f64,
+        gate: f32,
+        velocity_ramp: f32,
+    ) -> Option<RepeatEvent> {
+        let held = &mut self.held[index];
+        held.held_beats += beats_per_sample;
+        held.step_phase += beats_per_sample / step_beats.max(1e-6);
+
+        if held.step_phase >= 1.0 {
+            held.step_phase = held.step_phase.fract();
+            let release_first = held.gate_open;
+            held.gate_open = true;
+            let velocity = (held.velocity + velocity_ramp * held.held_beats as f32)
+                .clamp(MIN_REPEAT_VELOCITY, 1.0);
+            return Some(RepeatEvent::Retrigger {
+                note: held.note,
+                velocity,
+                note_id: held.note_id,
+                release_first,
+            });
+        }
+
+        if held.gate_open && held.step_phase >= gate.clamp(0.05, 1.0) as f64 {
+            held.gate_open = false;
+            return Some(RepeatEvent::Release { note: held.note });
+        }
+        None
+    }
+}
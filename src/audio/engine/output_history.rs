@@ -0,0 +1,132 @@
+//! Engine → analysis feed of the most recent output.
+//!
+//! Offline checks such as the mono compatibility report need the last second or so of
+//! what the engine actually played. The audio thread stages frames in a small fixed chunk
+//! and copies each full chunk into a shared ring with `try_lock`, dropping the chunk if a
+//! reader holds the lock, so it never blocks or allocates. Readers copy the ring out with
+//! `snapshot()` and do their analysis on their own thread.
+
+use parking_lot::Mutex;
+use std::sync::Arc;
+
+/// Seconds of output the history keeps
+pub const OUTPUT_HISTORY_SECONDS: f32 = 1.0;
+
+/// Frames staged on the audio thread between copies into the shared ring
+const HISTORY_CHUNK: usize = 256;
+
+/// History shared between the engine and its readers. It outlives the engine (the
+/// plugin rebuilds its engine on re-activation), which just gets handed the same one.
+pub type SharedOutputHistory = Arc<Mutex<OutputHistory>>;
+
+/// Ring of the last `OUTPUT_HISTORY_SECONDS` of stereo output
+#[derive(Debug, Clone, Default)]
+pub struct OutputHistory {
+    left: Vec<f32>,
+    right: Vec<f32>,
+    /// Next slot to write
+    write: usize,
+    /// Slots holding output (grows to the capacity, then stays there)
+    filled: usize,
+    sample_rate: f32,
+}
+
+impl OutputHistory {
+    /// Empty history sized for `sample_rate`
+    pub fn new(sample_rate: f32) -> Self {
+        let capacity = (OUTPUT_HISTORY_SECONDS * sample_rate).max(1.0) as usize;
+        Self {
+            left: vec![0.0; capacity],
+            right: vec![0.0; capacity],
+            write: 0,
+            filled: 0,
+            sample_rate,
+        }
+    }
+
+    /// Empty shared history sized for `sample_rate`
+    pub fn shared(sample_rate: f32) -> SharedOutputHistory {
+        Arc::new(Mutex::new(Self::new(sample_rate)))
+    }
+
+    /// Sample rate of the stored output
+    pub fn sample_rate(&self) -> f32 {
+        self.sample_rate
+    }
+
+    /// Stereo frames stored
+    pub fn len(&self) -> usize {
+        self.filled
+    }
+
+    /// Whether nothing has been played yet
+    pub fn is_empty(&self) -> bool {
+        self.filled == 0
+    }
+
+    /// Copy of the stored output, oldest frame first
+    pub fn snapshot(&self) -> (Vec<f32>, Vec<f32>) {
+        let start = (self.write + self.left.len() - self.filled) % self.left.len().max(1);
+        let ordered = |channel: &[f32]| {
+            channel
+                .iter()
+                .cycle()
+                .skip(start)
+                .take(self.filled)
+                .copied()
+                .collect()
+        };
+        (ordered(&self.left), ordered(&self.right))
+    }
+
+    fn push(&mut self, left: &[f32], right: &[f32]) {
+        let capacity = self.left.len();
+        if capacity == 0 {
+            return;
+        }
+        for (&l, &r) in left.iter().zip(right) {
+            self.left[self.write] = l;
+            self.right[self.write] = r;
+            self.write = (self.write + 1) % capacity;
+        }
+        self.filled = (self.filled + left.len().min(right.len())).min(capacity);
+    }
+}
+
+/// Audio-thread end of an output history
+pub(super) struct OutputHistoryTap {
+    shared: SharedOutputHistory,
+    left: [f32; HISTORY_CHUNK],
+    right: [f32; HISTORY_CHUNK],
+    len: usize,
+}
+
+impl OutputHistoryTap {
+    pub(super) fn new(shared: SharedOutputHistory) -> Self {
+        Self {
+            shared,
+            left: [0.0; HISTORY_CHUNK],
+            right: [0.0; HISTORY_CHUNK],
+            len: 0,
+        }
+    }
+
+    pub(super) fn shared(&self) -> &SharedOutputHistory {
+        &self.shared
+    }
+
+    /// Stage one frame, copying the chunk into the ring once it's full
+    #[inline]
+    pub(super) fn push(&mut self, left: f32, right: f32) {
+        self.left[self.len] = left;
+        self.right[self.len] = right;
+        self.len += 1;
+        if self.len == HISTORY_CHUNK {
+            // A reader is copying the ring: skip this chunk rather than wait
+            if let Some(mut history) = self.shared.try_lock() {
+                history.push(&self.left, &self.right);
+            }
+            self.len = 0;
+        }
+    }
+}
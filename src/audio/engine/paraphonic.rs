@@ -0,0 +1,133 @@
+//! Shared filter and amp envelope for paraphonic mode.
+//!
+//! In paraphonic mode the voices only generate oscillators (each gated by its own key,
+//! with the amp release as its fade-out) and the engine runs their sum through one
+//! filter and one amp envelope, like a classic string machine. Both envelopes start
+//! with the first key of a phrase and are not retriggered by notes played over held
+//! keys; the filter envelope releases once the last key is up, while the amp level
+//! fades out through the per-note releases.
+
+use crate::dsp::filters::BiquadFilter;
+use crate::dsp::modulation::envelope::Envelope;
+use crate::params::{EnvelopeParams, FilterParams, VelocityParams};
+
+/// Shared stereo filter (filter 1's settings) and envelopes for the summed voices
+pub(super) struct ParaphonicStage {
+    filters: [BiquadFilter; 2],
+    amp_envelope: Envelope,
+    filter_envelope: Envelope,
+
+    /// Most recent note and velocity, for key tracking and velocity → cutoff
+    note: u8,
+    velocity: f32,
+
+    /// Something has been played since the last `reset()`
+    active: bool,
+}
+
+impl ParaphonicStage {
+    pub(super) fn new(sample_rate: f32) -> Self {
+        Self {
+            filters: [
+                BiquadFilter::new(sample_rate),
+                BiquadFilter::new(sample_rate),
+            ],
+            amp_envelope: Envelope::new(sample_rate),
+            filter_envelope: Envelope::new(sample_rate),
+            note: 60,
+            velocity: 0.0,
+            active: false,
+        }
+    }
+
+    /// Apply filter 1's type/resonance/envelope and the amp envelope times
+    pub(super) fn update_parameters(&mut self, filter: &FilterParams, envelope: &EnvelopeParams) {
+        for f in &mut self.filters {
+            f.set_filter_type(filter.filter_type);
+            f.set_resonance(filter.resonance);
+            f.set_bandwidth(filter.bandwidth);
+        }
+
+        self.filter_envelope.set_attack(filter.envelope.attack);
+        self.filter_envelope.set_decay(filter.envelope.decay);
+        self.filter_envelope.set_sustain(filter.envelope.sustain);
+        self.filter_envelope.set_release(filter.envelope.release);
+
+        self.amp_envelope.set_attack(envelope.attack);
+        self.amp_envelope.set_decay(envelope.decay);
+        self.amp_envelope.set_sustain(envelope.sustain);
+        self.amp_envelope.set_attack_curve(envelope.attack_curve);
+        self.amp_envelope.set_decay_curve(envelope.decay_curve);
+    }
+
+    /// A key went down. `first_key` starts the shared envelopes (no other key was held).
+    pub(super) fn note_on(&mut self, note: u8, velocity: f32, first_key: bool) {
+        self.note = note;
+        self.velocity = velocity;
+        self.active = true;
+        if first_key {
+            self.amp_envelope.note_on_legato();
+            self.filter_envelope.note_on_legato();
+        }
+    }
+
+    /// The last held key went up
+    pub(super) fn note_off(&mut self) {
+        self.filter_envelope.note_off();
+    }
+
+    /// Return to silence once every voice has finished (next phrase starts from zero)
+    pub(super) fn reset(&mut self) {
+        if !self.active {
+            return;
+        }
+        self.active = false;
+        self.amp_envelope.reset();
+        self.amp_envelope.reset_level();
+        self.filter_envelope.reset();
+        self.filter_envelope.reset_level();
+        for f in &mut self.filters {
+            f.reset();
+        }
+    }
+
+    /// Filter and shape the summed voices
+    pub(super) fn process(
+        &mut self,
+        left: f32,
+        right: f32,
+        filter: &FilterParams,
+        velocity_params: &VelocityParams,
+    ) -> (f32, f32) {
+        let amp = self.amp_envelope.process();
+        let filter_env = self.filter_envelope.process();
+
+        // Same cutoff modulation as a voice filter: key tracking around C4, velocity
+        // around 0.5 and the filter envelope amount in Hz
+        let key_tracking = filter.key_tracking.clamp(0.0, 1.0);
+        let key_mult = 2.0_f32.powf((self.note as f32 - 60.0) * key_tracking / 12.0);
+        let cutoff = (filter.cutoff * key_mult
+            + filter.cutoff * velocity_params.filter_sensitivity * (self.velocity - 0.5)
+            + filter_env * filter.envelope.amount)
+            .clamp(20.0, 20000.0);
+
+        let mut out = [left, right];
+        for (sample, f) in out.iter_mut().zip(&mut self.filters) {
+            f.set_cutoff(cutoff);
+            let filtered = f.process(saturate(*sample, filter.drive));
+            *sample = saturate(filtered, filter.post_drive) * amp;
+        }
+
+        (out[0], out[1])
+    }
+}
+
+/// Filter drive: tanh saturation with loudness compensation (0 = bypass)
+fn saturate(input: f32, drive: f32) -> f32 {
+    if drive > 0.001 {
+        let drive_gain = 1.0 + drive * 2.0;
+        (input * drive_gain).tanh() / drive_gain.sqrt()
+    } else {
+        input
+    }
+}
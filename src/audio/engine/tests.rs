@@ -117,14 +117,167 @@ fn test_voice_stealing_quietest() {
     assert_eq!(engine.active_voice_count(), MAX_POLYPHONY);
 }
 
-/// Test that all_notes_off() immediately silences all voices.
+/// Test paraphonic mode's shared amp envelope.
 /// Verifies:
-/// - Before all_notes_off(): multiple voices are active
-/// - After all_notes_off(): zero active voices
+/// - The first key of a phrase still rises through the (shared) attack
+/// - A note played over a held key joins at full level instead of restarting its own attack
+#[test]
+fn test_paraphonic_shares_amp_envelope() {
+    fn render(paraphonic: bool) -> (f32, f32, f32) {
+        let (mut producer, consumer) = create_parameter_buffer();
+        let mut engine = SynthEngine::new(44100.0, consumer);
+        let mut params = SynthParams {
+            paraphonic,
+            ..Default::default()
+        };
+        params.envelope.attack = 1.0;
+        params.envelope.sustain = 1.0;
+        producer.write(params);
+        for _ in 0..64 {
+            engine.process_mono();
+        }
+
+        let peak = |engine: &mut SynthEngine, samples: usize| {
+            (0..samples).fold(0.0_f32, |p, _| p.max(engine.process_mono().abs()))
+        };
+        engine.note_on(60, 1.0);
+        let attack_peak = peak(&mut engine, 2000);
+        peak(&mut engine, 44100);
+        let full_peak = peak(&mut engine, 2000);
+
+        engine.note_on(67, 1.0);
+        peak(&mut engine, 2000);
+        let second_note_peak = engine
+            .voices
+            .iter()
+            .find(|v| v.is_active() && v.note() == 67)
+            .map(|v| v.peak_amplitude())
+            .unwrap_or(0.0);
+        (attack_peak, full_peak, second_note_peak)
+    }
+
+    let (attack_peak, full_peak, paraphonic_second) = render(true);
+    assert!(
+        attack_peak < full_peak * 0.2,
+        "The first key should rise through the shared attack"
+    );
+
+    let (_, _, poly_second) = render(false);
+    assert!(
+        paraphonic_second > poly_second * 5.0,
+        "A paraphonic note over a held key should not restart the attack \
+         ({paraphonic_second} vs {poly_second})"
+    );
+}
+
+/// Test that with `steal_prefers_release` a quiet held note survives a steal while a
+/// louder releasing note is taken instead (and that the default still steals by level).
+#[test]
+fn test_steal_prefers_releasing_voice() {
+    fn steal(steal_prefers_release: bool) -> (bool, bool) {
+        let (mut producer, consumer) = create_parameter_buffer();
+        let mut engine = SynthEngine::new(44100.0, consumer);
+        let mut params = SynthParams {
+            steal_prefers_release,
+            ..Default::default()
+        };
+        params.envelope.release = 2.0;
+        producer.write(params);
+        for _ in 0..64 {
+            engine.process_mono();
+        }
+
+        // A quiet held note, then loud notes in every other slot
+        engine.note_on(60, 0.1);
+        for i in 1..MAX_POLYPHONY {
+            engine.note_on(60 + i as u8, 1.0);
+        }
+        for _ in 0..2000 {
+            engine.process_mono();
+        }
+
+        // One loud note starts its long release tail
+        engine.note_off(61);
+        for _ in 0..100 {
+            engine.process_mono();
+        }
+        let peak_of = |engine: &SynthEngine, note: u8| {
+            engine
+                .voices
+                .iter()
+                .find(|v| v.is_active() && v.note() == note)
+                .map(|v| v.peak_amplitude())
+                .unwrap_or(0.0)
+        };
+        assert!(
+            peak_of(&engine, 60) < peak_of(&engine, 61),
+            "the held note should be the quieter one"
+        );
+
+        engine.note_on(100, 1.0);
+        let sounding = |note: u8| {
+            engine
+                .voices
+                .iter()
+                .any(|v| v.is_active() && v.note() == note)
+        };
+        (sounding(60), sounding(61))
+    }
+
+    assert_eq!(
+        steal(false),
+        (false, true),
+        "default steals the quietest voice"
+    );
+    assert_eq!(
+        steal(true),
+        (true, false),
+        "releasing voice is stolen first"
+    );
+}
+
+/// Test that voice allocation is deterministic: the same note sequence, including steals
+/// between equally loud voices, lands on the same voices every run.
+#[test]
+fn test_voice_allocation_is_reproducible() {
+    fn assignments() -> Vec<Vec<Option<u8>>> {
+        let (_producer, consumer) = create_parameter_buffer();
+        let mut engine = SynthEngine::new(44100.0, consumer);
+        let mut snapshots = Vec::new();
+        for step in 0..(MAX_POLYPHONY * 3) {
+            // Identical velocities make equal peaks, so steals hit ties
+            engine.note_on(36 + (step * 7 % 48) as u8, 0.8);
+            if step % 5 == 0 {
+                engine.note_off(36 + ((step + 3) * 7 % 48) as u8);
+            }
+            for _ in 0..64 {
+                engine.process();
+            }
+            snapshots.push(
+                engine
+                    .voices
+                    .iter()
+                    .map(|v| v.is_active().then_some(v.note()))
+                    .collect(),
+            );
+        }
+        snapshots
+    }
+
+    let first = assignments();
+    for _ in 0..3 {
+        assert_eq!(assignments(), first);
+    }
+}
+
+/// Test that panic() immediately silences all voices.
+/// Verifies:
+/// - Before panic(): multiple voices are active
+/// - After panic(): zero active voices
 /// - This is different from note_off() which releases each voice (plays release envelope)
-/// - all_notes_off() is a hard stop for panic/emergency silence
+/// - panic() is a hard stop for emergency silence
 #[test]
-fn test_all_notes_off() {
+fn test_panic() {
     let (_producer, consumer) = create_parameter_buffer();
     let mut engine = SynthEngine::new(44100.0, consumer);
 
@@ -135,10 +288,48 @@ fn test_all_notes_off() {
 
     assert!(engine.active_voice_count() > 0);
 
-    engine.all_notes_off();
+    engine.panic();
     assert_eq!(engine.active_voice_count(), 0);
 }
 
+/// Test that all_notes_off() fades loud notes out quickly instead of cutting them.
+/// Verifies:
+/// - Every voice is silent and free within a few milliseconds, despite a long release
+/// - No sample-to-sample jump exceeds the steady sine's own largest step (no click)
+#[test]
+fn test_all_notes_off_fades_without_click() {
+    let (mut producer, consumer) = create_parameter_buffer();
+    let mut engine = SynthEngine::new(44100.0, consumer);
+    let mut params = SynthParams::default();
+    params.envelope.release = 2.0;
+    producer.write(params);
+
+    engine.note_on(60, 1.0);
+    let mut previous = 0.0;
+    let mut steady_step = 0.0_f32;
+    for i in 0..8192 {
+        let (left, _) = engine.process();
+        if i >= 4096 {
+            steady_step = steady_step.max((left - previous).abs());
+        }
+        previous = left;
+    }
+    assert!(steady_step > 0.005, "Note should be loud");
+
+    engine.all_notes_off();
+    let mut fade_step = 0.0_f32;
+    for _ in 0..1024 {
+        let (left, _) = engine.process();
+        fade_step = fade_step.max((left - previous).abs());
+        previous = left;
+    }
+    assert_eq!(engine.active_voice_count(), 0, "Fade should take a few ms");
+    assert!(
+        fade_step <= steady_step * 1.05,
+        "Fade should not click: step {fade_step} vs steady {steady_step}"
+    );
+}
+
 /// Test that process() generates audible output when notes are playing.
 /// Verifies:
 /// - Triggering a note produces audio samples (not silent)
@@ -517,6 +708,30 @@ fn test_set_tempo() {
     assert_eq!(engine.current_tempo_bpm, 85.0);
 }
 
+/// Test that a tempo change re-applies synced rates even when no parameter changed
+/// (MIDI clock in standalone only ever changes the tempo)
+#[test]
+fn test_tempo_change_applies_without_param_change() {
+    let (_producer, consumer) = create_parameter_buffer();
+    let mut engine = SynthEngine::new(44100.0, consumer);
+    for _ in 0..64 {
+        engine.process();
+    }
+
+    engine.set_tempo(120.0);
+    assert!(!engine.tempo_changed, "Same tempo is not a change");
+
+    engine.set_tempo(90.0);
+    assert!(engine.tempo_changed);
+    for _ in 0..engine.param_update_interval {
+        engine.process();
+    }
+    assert!(
+        !engine.tempo_changed,
+        "Next parameter update should apply it"
+    );
+}
+
 /// Test that get_effective_rate() returns raw Hz when tempo_sync = Hz
 #[test]
 fn test_get_effective_rate_hz_mode() {
@@ -705,3 +920,1501 @@ fn test_dotted_formulas() {
         epsilon = 0.001
     );
 }
+
+/// Test tempo division to delay time conversion
+/// Verifies:
+/// - Straight, dotted, and triplet divisions produce the expected ms at 120 BPM
+/// - Long divisions are clamped to the delay buffer length
+/// - Hz mode returns 0.0 (signal to use the raw time parameter)
+#[test]
+fn test_tempo_division_to_ms() {
+    use crate::params::TempoSync;
+    use approx::assert_relative_eq;
+
+    // At 120 BPM one beat is 500 ms
+    assert_relative_eq!(
+        SynthEngine::tempo_division_to_ms(TempoSync::Quarter, 120.0),
+        500.0,
+        epsilon = 0.01
+    );
+    assert_relative_eq!(
+        SynthEngine::tempo_division_to_ms(TempoSync::EighthD, 120.0),
+        375.0,
+        epsilon = 0.01
+    );
+    assert_relative_eq!(
+        SynthEngine::tempo_division_to_ms(TempoSync::EighthT, 120.0),
+        166.667,
+        epsilon = 0.01
+    );
+
+    // A whole note at 60 BPM is 4 s, longer than the 2 s delay buffer
+    assert_relative_eq!(
+        SynthEngine::tempo_division_to_ms(TempoSync::Whole, 60.0),
+        2000.0,
+        epsilon = 0.01
+    );
+
+    assert_eq!(SynthEngine::tempo_division_to_ms(TempoSync::Hz, 120.0), 0.0);
+}
+
+/// Test that the global effects bypass skips the chain without clearing enables.
+/// Verifies:
+/// - With bypass on, an enabled effect has no influence on the output
+/// - The effect's own `enabled` flag is left untouched, so turning bypass off
+///   brings the effect back
+#[test]
+fn test_effects_bypass_skips_chain() {
+    fn render(params: SynthParams) -> Vec<(f32, f32)> {
+        let (mut producer, consumer) = create_parameter_buffer();
+        let mut engine = SynthEngine::new(44100.0, consumer);
+        producer.write(params);
+        engine.note_on(60, 1.0);
+        (0..4096).map(|_| engine.process()).collect()
+    }
+
+    let mut with_distortion = SynthParams::default();
+    with_distortion.effects.distortion.enabled = true;
+    with_distortion.effects.distortion.drive = 1.0;
+    with_distortion.effects.distortion.mix = 1.0;
+
+    let mut bypassed = with_distortion;
+    bypassed.effects_bypass = true;
+
+    let mut bypassed_clean = bypassed;
+    bypassed_clean.effects.distortion.enabled = false;
+
+    let bypassed_out = render(bypassed);
+    assert_eq!(
+        bypassed_out,
+        render(bypassed_clean),
+        "Bypass should ignore individually enabled effects"
+    );
+    assert!(bypassed.effects.distortion.enabled);
+    assert_ne!(
+        bypassed_out,
+        render(with_distortion),
+        "Disabling bypass should restore the enabled effect"
+    );
+}
+
+/// Test that auditioning an effect outputs only its wet contribution.
+/// Verifies:
+/// - A reverb with no wet level auditions as silence although the dry note is loud
+/// - Auditioning a disabled effect is silent
+/// - Ending the audition restores the normal output
+#[test]
+fn test_audition_effect_outputs_only_wet() {
+    fn render(params: SynthParams, audition: Option<EffectSlot>) -> Vec<(f32, f32)> {
+        let (mut producer, consumer) = create_parameter_buffer();
+        let mut engine = SynthEngine::new(44100.0, consumer);
+        producer.write(params);
+        engine.audition_effect(audition);
+        engine.note_on(60, 1.0);
+        let mut out: Vec<(f32, f32)> = (0..4096).map(|_| engine.process()).collect();
+        engine.audition_effect(None);
+        out.extend((0..1024).map(|_| engine.process()));
+        out
+    }
+    fn peak(samples: &[(f32, f32)]) -> f32 {
+        samples
+            .iter()
+            .fold(0.0, |p, (l, r)| p.max(l.abs()).max(r.abs()))
+    }
+
+    let mut params = SynthParams::default();
+    params.effects.reverb.enabled = true;
+    params.effects.reverb.wet = 0.0;
+    params.effects.reverb.dry = 1.0;
+
+    let normal = render(params, None);
+    assert!(
+        peak(&normal[2048..4096]) > 0.05,
+        "Dry note should be audible"
+    );
+
+    let reverb = render(params, Some(EffectSlot::Reverb));
+    assert!(
+        peak(&reverb[2048..4096]) < 1e-3,
+        "A reverb with no wet level should audition silently"
+    );
+    // Once the limiter's look-ahead has flushed, the chain is back in step
+    for (a, b) in reverb[4608..].iter().zip(&normal[4608..]) {
+        assert!(
+            (a.0 - b.0).abs() < 1e-5 && (a.1 - b.1).abs() < 1e-5,
+            "Ending the audition should restore the normal output"
+        );
+    }
+
+    let chorus = render(params, Some(EffectSlot::Chorus));
+    assert!(
+        peak(&chorus[2048..4096]) < 1e-6,
+        "A disabled effect should audition silently"
+    );
+}
+
+/// Test that delay and reverb can run as parallel sends off the pre-effects bus.
+/// Verifies:
+/// - Two sends sum independently: their combined output is the dry signal plus each return
+/// - Series routing (delay feeding reverb) sounds different from the parallel sends
+#[test]
+fn test_send_effects_run_in_parallel() {
+    fn render(params: SynthParams) -> Vec<(f32, f32)> {
+        let (mut producer, consumer) = create_parameter_buffer();
+        let mut engine = SynthEngine::new(44100.0, consumer);
+        producer.write(params);
+        (0..8192)
+            .map(|i| {
+                // Short burst so the delay repeats and reverb tail are exposed
+                let x = if i < 2048 {
+                    0.1 * (i as f32 * 0.05).sin()
+                } else {
+                    0.0
+                };
+                engine.process_with_input(x, x)
+            })
+            .collect()
+    }
+
+    let mut params = SynthParams::default();
+    params.effects.delay.time_ms = 50.0;
+    params.effects.delay.wet = 0.5;
+    params.effects.delay.dry = 1.0;
+    params.effects.reverb.wet = 0.5;
+    params.effects.reverb.dry = 1.0;
+    params.effects.delay.routing = EffectRouting::Send;
+    params.effects.reverb.routing = EffectRouting::Send;
+
+    let dry = render(params);
+    let mut delay_only = params;
+    delay_only.effects.delay.enabled = true;
+    let mut reverb_only = params;
+    reverb_only.effects.reverb.enabled = true;
+    let mut both = delay_only;
+    both.effects.reverb.enabled = true;
+
+    let delay_out = render(delay_only);
+    let reverb_out = render(reverb_only);
+    let both_out = render(both);
+    for i in 0..both_out.len() {
+        let expected = delay_out[i].0 + reverb_out[i].0 - dry[i].0;
+        assert!(
+            (both_out[i].0 - expected).abs() < 1e-4,
+            "Parallel sends should not feed each other (sample {i})"
+        );
+    }
+
+    let mut series = both;
+    series.effects.delay.routing = EffectRouting::Series;
+    series.effects.reverb.routing = EffectRouting::Series;
+    let series_out = render(series);
+    let difference = series_out
+        .iter()
+        .zip(&both_out)
+        .fold(0.0_f32, |d, (a, b)| d.max((a.0 - b.0).abs()));
+    assert!(
+        difference > 1e-3,
+        "Series routing should feed the delay into the reverb"
+    );
+}
+
+/// Test that input gain drives the effects chain with matching output makeup.
+/// Verifies:
+/// - With no nonlinear effects, input gain is cancelled by the makeup gain
+/// - With distortion enabled, raising input gain changes the processed signal
+/// - `process()` (instrument path) ignores input gain entirely
+#[test]
+fn test_input_gain_drives_effects_with_makeup() {
+    fn render(params: SynthParams, input_level: f32, note: bool) -> Vec<(f32, f32)> {
+        let (mut producer, consumer) = create_parameter_buffer();
+        let mut engine = SynthEngine::new(44100.0, consumer);
+        producer.write(params);
+        if note {
+            engine.note_on(60, 1.0);
+        }
+        (0..2048)
+            .map(|i| {
+                let x = input_level * (i as f32 * 0.05).sin();
+                engine.process_with_input(x, x)
+            })
+            .collect()
+    }
+    let assert_same_level = |a: &[(f32, f32)], b: &[(f32, f32)]| {
+        for (a, b) in a.iter().zip(b.iter()) {
+            assert!((a.0 - b.0).abs() < 1e-4 && (a.1 - b.1).abs() < 1e-4);
+        }
+    };
+
+    let unity = SynthParams::default();
+    let mut driven = unity;
+    driven.input_gain_db = 12.0;
+
+    let unity_out = render(unity, 0.3, false);
+    assert_same_level(&unity_out, &render(driven, 0.3, false));
+    assert!(unity_out.iter().any(|(l, _)| l.abs() > 0.1));
+
+    // A note held while processing input (Custom config) keeps its level
+    let unity_synth = render(unity, 0.0, true);
+    assert_same_level(&unity_synth, &render(driven, 0.0, true));
+    assert!(unity_synth.iter().any(|(l, _)| l.abs() > 0.05));
+
+    let mut distorted = unity;
+    distorted.effects.distortion.enabled = true;
+    distorted.effects.distortion.mix = 1.0;
+    let mut distorted_driven = distorted;
+    distorted_driven.input_gain_db = 12.0;
+    assert_ne!(
+        render(distorted, 0.3, false),
+        render(distorted_driven, 0.3, false)
+    );
+
+    let (mut producer, consumer) = create_parameter_buffer();
+    let mut engine = SynthEngine::new(44100.0, consumer);
+    producer.write(driven);
+    engine.note_on(60, 1.0);
+    let driven_synth: Vec<_> = (0..1024).map(|_| engine.process()).collect();
+    let (mut producer, consumer) = create_parameter_buffer();
+    let mut engine = SynthEngine::new(44100.0, consumer);
+    producer.write(unity);
+    engine.note_on(60, 1.0);
+    let unity_synth: Vec<_> = (0..1024).map(|_| engine.process()).collect();
+    assert_eq!(driven_synth, unity_synth);
+}
+
+/// Test that keyboard panning places low and high notes on opposite sides.
+/// Verifies:
+/// - With a positive amount, note 36 leans left and note 96 leans right
+/// - With amount 0, a note stays balanced
+#[test]
+fn test_key_pan_opposite_sides() {
+    fn channel_energy(amount: f32, note: u8) -> (f32, f32) {
+        let (mut producer, consumer) = create_parameter_buffer();
+        let mut engine = SynthEngine::new(44100.0, consumer);
+        producer.write(SynthParams {
+            key_pan_amount: amount,
+            ..Default::default()
+        });
+        // Let the engine pick up the new params (throttled) before the note starts
+        for _ in 0..64 {
+            engine.process();
+        }
+        engine.note_on(note, 1.0);
+        (0..4096).fold((0.0, 0.0), |(l, r), _| {
+            let (left, right) = engine.process();
+            (l + left * left, r + right * right)
+        })
+    }
+
+    let (low_l, low_r) = channel_energy(1.0, 36);
+    let (high_l, high_r) = channel_energy(1.0, 96);
+    assert!(
+        low_l > low_r * 2.0,
+        "Note 36 should pan left: L={low_l}, R={low_r}"
+    );
+    assert!(
+        high_r > high_l * 2.0,
+        "Note 96 should pan right: L={high_l}, R={high_r}"
+    );
+
+    let (center_l, center_r) = channel_energy(0.0, 36);
+    assert!(
+        (center_l - center_r).abs() < center_l * 0.01,
+        "Amount 0 should not pan: L={center_l}, R={center_r}"
+    );
+}
+
+/// Test that toggling an individual effect crossfades instead of switching abruptly.
+/// Verifies:
+/// - Enabling heavy distortion mid-note produces no per-sample jump larger than
+///   the steady-state signal already has
+#[test]
+fn test_effect_toggle_crossfades() {
+    let (mut producer, consumer) = create_parameter_buffer();
+    let mut engine = SynthEngine::new(44100.0, consumer);
+
+    let mut params = SynthParams::default();
+    params.effects.distortion.drive = 1.0;
+    params.effects.distortion.mix = 1.0;
+    producer.write(params);
+
+    engine.note_on(48, 1.0);
+
+    fn max_step(samples: &[(f32, f32)]) -> f32 {
+        samples
+            .windows(2)
+            .map(|w| (w[1].0 - w[0].0).abs().max((w[1].1 - w[0].1).abs()))
+            .fold(0.0, f32::max)
+    }
+
+    // Steady-state clean and distorted signal
+    let clean: Vec<_> = (0..4410).map(|_| engine.process()).collect();
+    params.effects.distortion.enabled = true;
+    producer.write(params);
+    let toggled: Vec<_> = (0..4410).map(|_| engine.process()).collect();
+    let steady = max_step(&clean[2205..]).max(max_step(&toggled[2205..]));
+
+    let mut transition = vec![*clean.last().unwrap()];
+    transition.extend_from_slice(&toggled[..2205]);
+    let transition_step = max_step(&transition);
+
+    assert!(
+        transition_step <= steady * 1.5,
+        "Enabling distortion should not click: transition step {transition_step}, steady {steady}"
+    );
+}
+
+/// Test poly legato voice reuse timing.
+/// Verifies:
+/// - A note arriving inside the legato window takes over the released voice
+/// - A note arriving after the window allocates a fresh voice (old one still releasing)
+/// - With poly legato off, fast repeats always allocate a fresh voice
+#[test]
+fn test_poly_legato_window_reuses_released_voice() {
+    fn voices_after_gap(poly_legato: bool, gap_ms: f32) -> usize {
+        let (mut producer, consumer) = create_parameter_buffer();
+        let mut engine = SynthEngine::new(44100.0, consumer);
+        producer.write(SynthParams {
+            poly_legato,
+            legato_window_ms: 30.0,
+            envelope: crate::params::EnvelopeParams {
+                release: 1.0, // Long release keeps the old voice active
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+
+        engine.note_on(60, 0.8);
+        for _ in 0..2205 {
+            engine.process();
+        }
+        engine.note_off(60);
+        for _ in 0..(gap_ms * 44.1) as usize {
+            engine.process();
+        }
+        engine.note_on(62, 0.8);
+        engine.process();
+        engine.active_voice_count()
+    }
+
+    assert_eq!(
+        voices_after_gap(true, 10.0),
+        1,
+        "Inside window: reuse voice"
+    );
+    assert_eq!(voices_after_gap(true, 60.0), 2, "Outside window: new voice");
+    assert_eq!(voices_after_gap(false, 10.0), 2, "Disabled: new voice");
+}
+
+#[test]
+fn test_solo_note_mode_releases_previous_notes() {
+    use crate::dsp::modulation::envelope::EnvelopeStage;
+
+    let (mut producer, consumer) = create_parameter_buffer();
+    let mut engine = SynthEngine::new(44100.0, consumer);
+    producer.write(SynthParams {
+        solo_note_mode: true,
+        envelope: crate::params::EnvelopeParams {
+            attack: 0.5,  // Long attack: every note is still attacking when the next arrives
+            release: 1.0, // Long release keeps the old voices active
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+    for _ in 0..64 {
+        engine.process();
+    }
+
+    // Rapid triggers, all keys still held
+    for note in [60, 64, 67, 72] {
+        engine.note_on(note, 0.8);
+        for _ in 0..100 {
+            engine.process();
+        }
+    }
+
+    let attacking: Vec<u8> = engine
+        .voices
+        .iter()
+        .filter(|v| v.is_active() && v.envelope_stage() == EnvelopeStage::Attack)
+        .map(|v| v.note())
+        .collect();
+    assert_eq!(
+        attacking,
+        vec![72],
+        "Only the newest note should be attacking"
+    );
+    assert_eq!(
+        engine.active_voice_count(),
+        4,
+        "Previous notes keep their release tails"
+    );
+}
+
+/// Test that internal headroom is transparent for linear processing but keeps
+/// nonlinear effects out of saturation.
+#[test]
+fn test_headroom_gain_staging() {
+    fn render(headroom_db: f32, effects: crate::params::EffectsParams) -> Vec<f32> {
+        let (mut producer, consumer) = create_parameter_buffer();
+        let mut engine = SynthEngine::new(44100.0, consumer);
+        producer.write(SynthParams {
+            headroom_db,
+            effects,
+            ..Default::default()
+        });
+        for _ in 0..64 {
+            engine.process();
+        }
+        engine.note_on(60, 1.0);
+        engine.note_on(64, 1.0);
+        engine.note_on(67, 1.0);
+        (0..4096).map(|_| engine.process().0).collect()
+    }
+
+    // Every effect off: headroom attenuation and makeup cancel exactly
+    let dry_effects = crate::params::EffectsParams {
+        reverb: crate::params::ReverbParams {
+            enabled: false,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let plain = render(0.0, dry_effects);
+    let staged = render(12.0, dry_effects);
+    for (a, b) in plain.iter().zip(&staged) {
+        assert!(
+            (a - b).abs() < 1e-4,
+            "Headroom changed a linear path: {a} vs {b}"
+        );
+    }
+
+    // Heavy distortion: headroom lowers the level it sees, so its output changes
+    let distorted_effects = crate::params::EffectsParams {
+        distortion: crate::params::DistortionParams {
+            enabled: true,
+            drive: 1.0,
+            mix: 1.0,
+            ..Default::default()
+        },
+        ..dry_effects
+    };
+    let hot = render(0.0, distorted_effects);
+    let cool = render(12.0, distorted_effects);
+    let diff: f32 = hot.iter().zip(&cool).map(|(a, b)| (a - b).abs()).sum();
+    assert!(
+        diff > 1.0,
+        "Headroom should change the distortion's drive: {diff}"
+    );
+}
+
+/// Test that the reported effect tail follows the reverb and delay settings.
+#[test]
+fn test_tail_length_tracks_effects() {
+    let (mut producer, consumer) = create_parameter_buffer();
+    let mut engine = SynthEngine::new(44100.0, consumer);
+    assert_eq!(engine.tail_length_samples(), 0, "No tail with effects off");
+
+    let mut tail_for = |effects: crate::params::EffectsParams| {
+        producer.write(SynthParams {
+            effects,
+            ..Default::default()
+        });
+        for _ in 0..64 {
+            engine.process();
+        }
+        engine.tail_length_samples()
+    };
+
+    let short_delay = tail_for(crate::params::EffectsParams {
+        delay: crate::params::DelayParams {
+            enabled: true,
+            feedback: 0.3,
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+    let long_delay = tail_for(crate::params::EffectsParams {
+        delay: crate::params::DelayParams {
+            enabled: true,
+            feedback: 0.9,
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+    assert!(
+        short_delay > 22050 && long_delay > short_delay,
+        "More feedback should lengthen the tail: {short_delay} vs {long_delay}"
+    );
+
+    let small_room = tail_for(crate::params::EffectsParams {
+        reverb: crate::params::ReverbParams {
+            enabled: true,
+            room_size: 0.1,
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+    let big_room = tail_for(crate::params::EffectsParams {
+        reverb: crate::params::ReverbParams {
+            enabled: true,
+            room_size: 1.0,
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+    assert!(
+        small_room > 0 && big_room > small_room,
+        "Bigger rooms should ring longer: {small_room} vs {big_room}"
+    );
+}
+
+/// Test that the signal type follows the input's character and drives the adaptive
+/// compressor attack.
+#[test]
+fn test_signal_type_classifies_sine_and_noise() {
+    use crate::dsp::SignalType;
+    use approx::assert_relative_eq;
+    use std::f32::consts::PI;
+
+    let (mut producer, consumer) = create_parameter_buffer();
+    let mut engine = SynthEngine::new(44100.0, consumer);
+    let mut params = SynthParams::default();
+    params.effects.compressor.enabled = true;
+    params.effects.compressor.adaptive = true;
+    params.effects.compressor.attack = 10.0;
+    producer.write(params);
+
+    for i in 0..4410 {
+        let sine = 0.5 * (2.0 * PI * 220.0 * i as f32 / 44100.0).sin();
+        engine.process_with_input(sine, sine);
+    }
+    assert_eq!(engine.current_signal_type(), SignalType::Tonal);
+    assert_relative_eq!(engine.compressor.attack(), 20.0, epsilon = 1e-3);
+
+    let mut noise_state = 0x12345678_u32;
+    for _ in 0..4410 {
+        noise_state ^= noise_state << 13;
+        noise_state ^= noise_state >> 17;
+        noise_state ^= noise_state << 5;
+        let noise = 0.5 * (noise_state as f32 / u32::MAX as f32 * 2.0 - 1.0);
+        engine.process_with_input(noise, noise);
+    }
+    assert_eq!(engine.current_signal_type(), SignalType::VeryNoisy);
+    assert_relative_eq!(engine.compressor.attack(), 2.5, epsilon = 1e-3);
+
+    // Without the adaptive flag the attack stays as set
+    params.effects.compressor.adaptive = false;
+    producer.write(params);
+    for _ in 0..64 {
+        engine.process_with_input(0.0, 0.0);
+    }
+    assert_relative_eq!(engine.compressor.attack(), 10.0, epsilon = 1e-3);
+}
+
+/// Test that the master transient shaper's attack control emphasizes the onset of a
+/// plucked patch, and that its neutral default leaves the mix untouched.
+#[test]
+fn test_master_transient_shaper_boosts_pluck_onset() {
+    let onset_peak = |enabled: bool, attack: f32| {
+        let (mut producer, consumer) = create_parameter_buffer();
+        let mut engine = SynthEngine::new(44100.0, consumer);
+        let mut params = SynthParams::default();
+        params.envelope.attack = 0.001;
+        params.envelope.decay = 0.15;
+        params.envelope.sustain = 0.0;
+        params.effects.transient_shaper.enabled = enabled;
+        params.effects.transient_shaper.attack = attack;
+        producer.write(params);
+
+        // Let the parameters land before the pluck
+        for _ in 0..64 {
+            engine.process();
+        }
+        engine.note_on(48, 1.0);
+        (0..2205)
+            .map(|_| engine.process().0.abs())
+            .fold(0.0_f32, f32::max)
+    };
+
+    let bypassed = onset_peak(false, 0.0);
+    let neutral = onset_peak(true, 0.0);
+    let boosted = onset_peak(true, 1.0);
+    assert!(bypassed > 0.01, "pluck should be audible: {}", bypassed);
+    assert!(
+        (neutral - bypassed).abs() < 1e-6,
+        "neutral shaper changed the onset: {} vs {}",
+        neutral,
+        bypassed
+    );
+    assert!(
+        boosted > bypassed * 1.2,
+        "attack boost should raise the onset peak: {} vs {}",
+        boosted,
+        bypassed
+    );
+}
+
+/// Test that scale lock snaps off-scale notes to the nearest in-scale note, and that
+/// note-off releases the note that was actually played.
+#[test]
+fn test_scale_lock_snaps_to_nearest_scale_note() {
+    use crate::dsp::{RootNote, ScaleType};
+
+    let (_producer, consumer) = create_parameter_buffer();
+    let mut engine = SynthEngine::new(44100.0, consumer);
+
+    // Disabled: notes pass through untouched
+    engine.set_scale_lock(ScaleType::Major, RootNote::C, false);
+    engine.note_on(61, 0.8);
+    assert_eq!(engine.last_note(), Some((61, 0.8)));
+    engine.note_off(61);
+
+    // C major: F# -> F (tie resolves down), B stays, A# -> A (tie), D# -> D (tie)
+    engine.set_scale_lock(ScaleType::Major, RootNote::C, true);
+    for (incoming, expected) in [(66, 65), (71, 71), (70, 69), (63, 62), (60, 60)] {
+        engine.note_on(incoming, 0.8);
+        assert_eq!(
+            engine.last_note(),
+            Some((expected, 0.8)),
+            "note {}",
+            incoming
+        );
+        engine.note_off(incoming);
+    }
+
+    // A minor pentatonic (A C D E G): B -> C, F -> E, G# -> G (tie), A# -> A
+    engine.set_scale_lock(ScaleType::MinorPentatonic, RootNote::A, true);
+    for (incoming, expected) in [(71, 72), (65, 64), (68, 67), (58, 57)] {
+        engine.note_on(incoming, 0.8);
+        assert_eq!(
+            engine.last_note(),
+            Some((expected, 0.8)),
+            "note {}",
+            incoming
+        );
+    }
+
+    // Turning the lock off releases the held notes by their incoming numbers
+    engine.set_scale_lock(ScaleType::Chromatic, RootNote::C, false);
+    for incoming in [71, 65, 68, 58] {
+        engine.note_off(incoming);
+    }
+    let held = engine
+        .voices
+        .iter()
+        .zip(&engine.voice_release_times)
+        .filter(|(voice, release_time)| voice.is_active() && release_time.is_none())
+        .count();
+    assert_eq!(held, 0, "every scale-locked note should be released");
+}
+
+/// Test that two keys snapped to the same scale note are released independently.
+/// Verifies:
+/// - In A minor, 60 and 61 both play C; lifting 61 leaves 60's voice sounding
+/// - Lifting 60 as well releases it
+/// - The same holds for the single voice in mono mode
+#[test]
+fn test_scale_lock_shared_note_released_per_key() {
+    use crate::dsp::{RootNote, ScaleType};
+
+    let held_voices = |engine: &SynthEngine| {
+        engine
+            .voices
+            .iter()
+            .filter(|voice| voice.is_active() && voice.envelope_stage() != EnvelopeStage::Release)
+            .count()
+    };
+
+    for monophonic in [false, true] {
+        let (mut producer, consumer) = create_parameter_buffer();
+        let mut engine = SynthEngine::new(44100.0, consumer);
+        producer.write(SynthParams {
+            monophonic,
+            ..Default::default()
+        });
+        for _ in 0..64 {
+            engine.process();
+        }
+        engine.set_scale_lock(ScaleType::Minor, RootNote::A, true);
+
+        engine.note_on(60, 0.8);
+        engine.note_on(61, 0.8);
+        assert_eq!(engine.last_note(), Some((60, 0.8)), "C# snaps to C");
+        engine.note_off(61);
+        engine.process();
+        assert!(
+            held_voices(&engine) > 0,
+            "key 60 is still down (mono: {})",
+            monophonic
+        );
+
+        engine.note_off(60);
+        engine.process();
+        assert_eq!(held_voices(&engine), 0, "mono: {}", monophonic);
+    }
+}
+
+/// Test that the mid/side insert's side gain widens a stereo input and leaves a mono
+/// input (which has no side content) unchanged.
+#[test]
+fn test_mid_side_side_gain_widens_stereo_only() {
+    use std::f32::consts::PI;
+
+    // Peak L-R difference and peak L+R sum of the input run through the insert
+    let run = |side_gain: f32, stereo: bool| {
+        let (mut producer, consumer) = create_parameter_buffer();
+        let mut engine = SynthEngine::new(44100.0, consumer);
+        let mut params = SynthParams::default();
+        params.effects.mid_side.enabled = true;
+        params.effects.mid_side.side_gain = side_gain;
+        producer.write(params);
+
+        let (mut side_peak, mut mid_peak) = (0.0_f32, 0.0_f32);
+        for i in 0..4410 {
+            let left = 0.3 * (2.0 * PI * 220.0 * i as f32 / 44100.0).sin();
+            let right = if stereo { 0.5 * left } else { left };
+            let (out_l, out_r) = engine.process_with_input(left, right);
+            if i >= 2205 {
+                side_peak = side_peak.max((out_l - out_r).abs());
+                mid_peak = mid_peak.max((out_l + out_r).abs());
+            }
+        }
+        (side_peak, mid_peak)
+    };
+
+    let (stereo_side, stereo_mid) = run(1.0, true);
+    let (boosted_side, boosted_mid) = run(2.0, true);
+    assert!(
+        boosted_side > stereo_side * 1.8,
+        "side boost should widen: {} vs {}",
+        boosted_side,
+        stereo_side
+    );
+    assert!((boosted_mid - stereo_mid).abs() < stereo_mid * 0.02);
+
+    let (mono_side, mono_mid) = run(1.0, false);
+    let (mono_boosted_side, mono_boosted_mid) = run(2.0, false);
+    assert!(mono_side < 1e-6 && mono_boosted_side < 1e-6);
+    assert!((mono_boosted_mid - mono_mid).abs() < 1e-6);
+}
+
+/// Test that a centred note sums to mono cleanly, while a Haas delay of half a cycle
+/// cancels the note in mono and names the stereo widener. The report reads the engine's
+/// recent output and leaves the engine itself alone.
+#[test]
+fn test_mono_compatibility_report_flags_haas_cancellation() {
+    fn report(widen: bool) -> crate::dsp::MonoCompatibilityReport {
+        let (mut producer, consumer) = create_parameter_buffer();
+        let mut engine = SynthEngine::new(44100.0, consumer);
+        let history = OutputHistory::shared(44100.0);
+        engine.set_output_history(history.clone());
+        let mut params = SynthParams::default();
+        if widen {
+            // 50 samples ≈ half a period of A4 (440 Hz), fully replacing the right channel
+            params.effects.stereo_widener.enabled = true;
+            params.effects.stereo_widener.haas_delay_ms = 50.5 / 44.1;
+            params.effects.stereo_widener.haas_mix = 1.0;
+            params.effects.stereo_widener.width = 1.0;
+        }
+        producer.write(params);
+        for _ in 0..64 {
+            engine.process();
+        }
+        engine.note_on(69, 0.8);
+        for _ in 0..44100 {
+            engine.process();
+        }
+
+        let clock = engine.sample_clock;
+        let report = SynthEngine::mono_compatibility_report(&history, &params).unwrap();
+        assert_eq!(
+            engine.sample_clock, clock,
+            "nothing is rendered for the report"
+        );
+        report
+    }
+
+    let centred = report(false);
+    assert!(!centred.is_problematic());
+    assert!(centred.overall_loss_db > -0.5, "{:?}", centred);
+    assert!(centred.flagged_settings.is_empty());
+
+    let wide = report(true);
+    assert!(wide.is_problematic(), "{:?}", wide);
+    assert!(wide.overall_loss_db < -10.0, "{:?}", wide);
+    assert_eq!(wide.flagged_settings, vec!["Stereo Widener"]);
+}
+
+/// Test that the output history keeps the last second of output in order.
+/// Verifies:
+/// - A fresh history is empty and gives no mono report
+/// - After more than a second, exactly `OUTPUT_HISTORY_SECONDS` is kept
+/// - The snapshot ends with the most recent frames, oldest first
+#[test]
+fn test_output_history_keeps_recent_output() {
+    let (_producer, consumer) = create_parameter_buffer();
+    let mut engine = SynthEngine::new(44100.0, consumer);
+    let history = OutputHistory::shared(48000.0);
+    engine.set_output_history(history.clone());
+    assert!(history.lock().is_empty());
+    assert_eq!(
+        history.lock().sample_rate(),
+        44100.0,
+        "sized for the engine"
+    );
+    assert!(SynthEngine::mono_compatibility_report(&history, &SynthParams::default()).is_none());
+
+    engine.note_on(60, 0.8);
+    let output: Vec<(f32, f32)> = (0..66150).map(|_| engine.process()).collect();
+
+    let history = history.lock();
+    let capacity = (OUTPUT_HISTORY_SECONDS * 44100.0) as usize;
+    assert_eq!(history.len(), capacity);
+    let (left, right) = history.snapshot();
+    // Frames reach the history in whole chunks, so the newest few may still be staged
+    let staged = output.len() % 256;
+    let expected = &output[output.len() - staged - capacity..output.len() - staged];
+    assert!(left.iter().zip(expected).all(|(l, e)| *l == e.0));
+    assert!(right.iter().zip(expected).all(|(r, e)| *r == e.1));
+}
+
+/// Test that the reported latency follows the pitch shifter's and compressor lookahead's
+/// enable state.
+#[test]
+fn test_latency_tracks_pitch_shifter() {
+    let (mut producer, consumer) = create_parameter_buffer();
+    let mut engine = SynthEngine::new(44100.0, consumer);
+    assert_eq!(engine.latency_samples(), 0, "No latency with effects off");
+
+    let mut params = SynthParams::default();
+    params.effects.pitch_shifter.enabled = true;
+    producer.write(params);
+    for _ in 0..64 {
+        engine.process();
+    }
+    assert_eq!(
+        engine.latency_samples(),
+        882,
+        "Half the shifter's 40 ms sweep"
+    );
+
+    params.effects.pitch_shifter.enabled = false;
+    producer.write(params);
+    for _ in 0..64 {
+        engine.process();
+    }
+    assert_eq!(engine.latency_samples(), 0);
+
+    // Compressor lookahead adds its delay while the compressor is on
+    params.effects.compressor.enabled = true;
+    params.effects.compressor.lookahead = 2.0;
+    producer.write(params);
+    for _ in 0..64 {
+        engine.process();
+    }
+    assert_eq!(engine.latency_samples(), 88, "2 ms at 44.1 kHz");
+}
+
+/// Test that analog drift is off by default and changes the sound when enabled.
+#[test]
+fn test_analog_drift_zero_is_bit_identical() {
+    fn render(analog_drift: f32) -> Vec<(f32, f32)> {
+        let (mut producer, consumer) = create_parameter_buffer();
+        let mut engine = SynthEngine::new(44100.0, consumer);
+        producer.write(SynthParams {
+            analog_drift,
+            ..Default::default()
+        });
+        for _ in 0..64 {
+            engine.process();
+        }
+        engine.note_on(60, 1.0);
+        engine.note_on(67, 0.8);
+        (0..44100).map(|_| engine.process()).collect()
+    }
+
+    let first = render(0.0);
+    let second = render(0.0);
+    assert!(
+        first == second,
+        "Renders without drift must be bit-identical"
+    );
+
+    // Drift is seeded, so it is reproducible too, but differs from the stable render
+    let drifting = render(1.0);
+    assert!(drifting == render(1.0), "Drift must be reproducible");
+    assert!(drifting != first, "Drift should change the output");
+}
+
+/// Test that program changes load presets from the bank and ignore unknown programs.
+#[test]
+fn test_select_program_from_bank() {
+    use crate::plugin::state::{PluginState, PresetBank};
+
+    let (mut producer, consumer) = create_parameter_buffer();
+    let mut engine = SynthEngine::new(44100.0, consumer);
+
+    let quiet = SynthParams {
+        master_gain: 0.25,
+        ..Default::default()
+    };
+    let bank = PresetBank::from_presets(vec![
+        PluginState::from_params(SynthParams::default(), Some("Init".to_string())),
+        PluginState::from_params(quiet, Some("Quiet".to_string())),
+    ]);
+
+    assert!(engine.select_program(2, &bank, &mut producer).is_none());
+    engine.process();
+    assert_eq!(engine.current_params().master_gain, 1.0);
+
+    let selected = engine.select_program(1, &bank, &mut producer);
+    assert_eq!(selected, Some(quiet));
+
+    // Applied on the next sample, not after the rest of the update interval
+    engine.process();
+    assert_eq!(engine.current_params().master_gain, 0.25);
+}
+
+#[test]
+fn test_modulation_feed_reports_lfo_moved_cutoff() {
+    let (mut producer, consumer) = create_parameter_buffer();
+    let mut engine = SynthEngine::new(44100.0, consumer);
+    let mut feed = engine.modulation_feed();
+
+    let mut params = SynthParams::default();
+    params.lfos[0].rate = 2.0;
+    params.lfos[0].depth = 1.0;
+    params.lfos[0].filter_amount = 2000.0;
+    producer.write(params);
+
+    for _ in 0..4410 {
+        engine.process();
+    }
+    assert_eq!(
+        feed.read()
+            .get(crate::plugin::param_descriptor::PARAM_FILTER1_CUTOFF),
+        None,
+        "Nothing sounding, nothing to report"
+    );
+
+    engine.note_on(60, 1.0);
+    let mut cutoffs = Vec::new();
+    for _ in 0..20 {
+        for _ in 0..2205 {
+            engine.process();
+        }
+        let cutoff = feed
+            .read()
+            .get(crate::plugin::param_descriptor::PARAM_FILTER1_CUTOFF)
+            .expect("Sounding voice should report its cutoff");
+        assert!((20.0..=20000.0).contains(&cutoff));
+        cutoffs.push(cutoff);
+    }
+
+    let min = cutoffs.iter().cloned().fold(f32::MAX, f32::min);
+    let max = cutoffs.iter().cloned().fold(f32::MIN, f32::max);
+    assert!(
+        max - min > 1000.0,
+        "LFO should visibly move the reported cutoff: {min}..{max}"
+    );
+}
+
+#[test]
+fn test_set_sample_rate_keeps_params_and_feed() {
+    let (mut producer, consumer) = create_parameter_buffer();
+    let mut engine = SynthEngine::new(44100.0, consumer);
+    let mut feed = engine.modulation_feed();
+
+    producer.write(SynthParams {
+        master_gain: 0.3,
+        ..Default::default()
+    });
+    engine.set_tempo(140.0);
+
+    engine.set_sample_rate(96000.0);
+    assert_eq!(engine.sample_rate(), 96000.0);
+
+    // Params written before the switch still reach the rebuilt engine
+    engine.note_on(60, 1.0);
+    let mut peak: f32 = 0.0;
+    for _ in 0..9600 {
+        let (left, right) = engine.process();
+        peak = peak.max(left.abs()).max(right.abs());
+    }
+    assert_eq!(engine.current_params.master_gain, 0.3);
+    assert!(
+        peak > 0.0,
+        "Engine should still produce sound after the switch"
+    );
+    assert_eq!(engine.current_tempo_bpm, 140.0);
+
+    // The GUI's feed survives the rebuild
+    assert!(feed
+        .read()
+        .get(crate::plugin::param_descriptor::PARAM_FILTER1_CUTOFF)
+        .is_some());
+}
+
+#[test]
+fn test_held_notes_track_keys_until_release() {
+    let (_producer, consumer) = create_parameter_buffer();
+    let mut engine = SynthEngine::new(44100.0, consumer);
+    let mut feed = engine.modulation_feed();
+
+    engine.note_on(60, 0.5);
+    engine.note_on(64, 0.8);
+    for _ in 0..2205 {
+        engine.process();
+    }
+    let held = engine.held_notes();
+    assert_eq!(held.iter().collect::<Vec<_>>(), vec![60, 64]);
+    assert_eq!(engine.last_note(), Some((64, 0.8)));
+
+    let snapshot = *feed.read();
+    assert_eq!(snapshot.held_notes, held);
+    assert_eq!(snapshot.last_note, Some((64, 0.8)));
+
+    // A released note leaves the set even while its release tail still sounds
+    engine.note_off(60);
+    engine.process();
+    assert!(!engine.held_notes().contains(60));
+    assert!(engine.held_notes().contains(64));
+}
+
+#[test]
+fn test_effect_levels_follow_enabled_effects() {
+    let (mut producer, consumer) = create_parameter_buffer();
+    let mut engine = SynthEngine::new(44100.0, consumer);
+    let mut feed = engine.modulation_feed();
+
+    let mut params = SynthParams::default();
+    params.effects.compressor.enabled = true;
+    params.effects.compressor.threshold = -40.0;
+    producer.write(params);
+
+    engine.note_on(60, 1.0);
+    for _ in 0..4410 {
+        engine.process();
+    }
+    let levels = feed.read().effect_levels;
+    assert!(levels.peak(EffectSlot::Compressor) > 0.0);
+    assert!(levels.compressor_reduction_db > 0.0);
+    assert_eq!(
+        levels.peak(EffectSlot::Reverb),
+        0.0,
+        "Disabled effect stays silent"
+    );
+
+    // Without a feed nothing is tracked
+    let (_producer, consumer) = create_parameter_buffer();
+    let mut unmetered = SynthEngine::new(44100.0, consumer);
+    unmetered.note_on(60, 1.0);
+    for _ in 0..441 {
+        unmetered.process();
+    }
+    assert_eq!(unmetered.effect_levels().peaks, [0.0; EFFECT_COUNT]);
+}
+
+#[test]
+fn test_song_position_phase_follows_timeline() {
+    use crate::params::TempoSync;
+
+    let (_producer, consumer) = create_parameter_buffer();
+    let mut engine = SynthEngine::new(44100.0, consumer);
+    engine.set_tempo(120.0);
+    assert_eq!(engine.song_phase(TempoSync::Quarter), None);
+
+    engine.set_song_position(2.25);
+    assert!((engine.song_phase(TempoSync::Quarter).unwrap() - 0.25).abs() < 1e-6);
+    assert!((engine.song_phase(TempoSync::Whole).unwrap() - 0.5625).abs() < 1e-6);
+    assert_eq!(engine.song_phase(TempoSync::Hz), None);
+
+    // Half a second at 120 BPM is one beat: the position is extrapolated between reports
+    for _ in 0..22050 {
+        engine.process();
+    }
+    assert!((engine.song_position_beats().unwrap() - 3.25).abs() < 1e-6);
+    assert!((engine.song_phase(TempoSync::Half).unwrap() - 0.625).abs() < 1e-6);
+
+    engine.clear_song_position();
+    assert_eq!(engine.song_phase(TempoSync::Quarter), None);
+}
+
+#[test]
+fn test_synced_tremolo_is_reproducible_across_playbacks() {
+    use crate::params::TempoSync;
+
+    let mut params = SynthParams::default();
+    params.effects.tremolo.enabled = true;
+    params.effects.tremolo.tempo_sync = TempoSync::Quarter;
+    params.effects.tremolo.depth = 1.0;
+
+    // Two "playbacks" started from the same bar after different amounts of idle time
+    let render = |idle_samples: usize| {
+        let (mut producer, consumer) = create_parameter_buffer();
+        let mut engine = SynthEngine::new(44100.0, consumer);
+        producer.write(params);
+        engine.set_tempo(120.0);
+        for _ in 0..idle_samples {
+            engine.process_with_input(0.5, 0.5);
+        }
+        engine.set_song_position(8.0);
+        // Skip the first half second while the output stages settle from the idle period
+        (0..44100)
+            .map(|_| engine.process_with_input(0.5, 0.5).0)
+            .skip(22050)
+            .collect::<Vec<f32>>()
+    };
+
+    let first = render(4410);
+    let second = render(7777);
+    for (a, b) in first.iter().zip(&second) {
+        assert!((a - b).abs() < 1e-4, "{} vs {}", a, b);
+    }
+}
+
+/// Test that `retrigger_mod_on_note` restarts modulation at every note.
+/// Verifies:
+/// - With it on, every note-on puts the tremolo LFO back at phase 0, so two notes started
+///   at unrelated times run through the same sweep
+/// - With it off, the tremolo free-runs and the two onsets land at different phases
+#[test]
+fn test_retrigger_mod_on_note_locks_modulation_phase() {
+    use approx::assert_relative_eq;
+
+    let mut params = SynthParams::default();
+    params.effects.tremolo.enabled = true;
+    params.effects.tremolo.rate = 3.0;
+    params.effects.tremolo.depth = 1.0;
+
+    // Tremolo phase right at each note-on and 1000 samples into the note
+    let onset_phases = |retrigger: bool| {
+        let (mut producer, consumer) = create_parameter_buffer();
+        let mut engine = SynthEngine::new(44100.0, consumer);
+        producer.write(SynthParams {
+            retrigger_mod_on_note: retrigger,
+            ..params
+        });
+        for _ in 0..22050 {
+            engine.process();
+        }
+        let mut phases = Vec::new();
+        for gap in [0, 5123] {
+            for _ in 0..gap {
+                engine.process();
+            }
+            engine.note_on(60, 1.0);
+            let at_onset = engine.tremolo.phase();
+            for _ in 0..1000 {
+                engine.process();
+            }
+            engine.note_off(60);
+            phases.push((at_onset, engine.tremolo.phase()));
+        }
+        phases
+    };
+
+    let locked = onset_phases(true);
+    for &(at_onset, into_note) in &locked {
+        assert_eq!(
+            at_onset, 0.0,
+            "Note-on should restart the tremolo: {:?}",
+            locked
+        );
+        assert_relative_eq!(into_note, 1000.0 * 3.0 / 44100.0, epsilon = 1e-4);
+    }
+
+    let free = onset_phases(false);
+    assert!(
+        (free[0].0 - free[1].0).abs() > 0.05,
+        "Free-running tremolo should drift between notes: {:?}",
+        free
+    );
+}
+
+/// Test that the rendered audio doesn't depend on how the host slices it into buffers.
+/// Verifies:
+/// - A short envelope and a fast LFO render the same at buffer sizes 1, 64 and 512
+/// - A note-off landing mid-buffer takes effect at its sample, not at a block boundary
+#[test]
+fn test_output_is_independent_of_buffer_size() {
+    let mut params = SynthParams::default();
+    params.envelope.attack = 0.002;
+    params.envelope.decay = 0.005;
+    params.envelope.sustain = 0.4;
+    params.envelope.release = 0.003;
+    params.lfos[0].rate = 20.0;
+    params.lfos[0].depth = 1.0;
+    params.lfos[0].gain_amount = 1.0;
+    params.lfos[0].pitch_amount = 50.0;
+
+    const LENGTH: usize = 8192;
+    const NOTE_OFF_AT: usize = 3001;
+
+    let render = |buffer_size: usize| {
+        let (mut producer, consumer) = create_parameter_buffer();
+        let mut engine = SynthEngine::new(44100.0, consumer);
+        producer.write(params);
+        engine.note_on(60, 0.8);
+
+        let mut left = vec![0.0; LENGTH];
+        let mut right = vec![0.0; LENGTH];
+        let mut start = 0;
+        while start < LENGTH {
+            let end = (start + buffer_size).min(LENGTH);
+            // Split the buffer at the note-off, the way the plugin dispatches timed events
+            if (start..end).contains(&NOTE_OFF_AT) {
+                engine.process_block(
+                    &mut left[start..NOTE_OFF_AT],
+                    &mut right[start..NOTE_OFF_AT],
+                );
+                engine.note_off(60);
+                engine.process_block(&mut left[NOTE_OFF_AT..end], &mut right[NOTE_OFF_AT..end]);
+            } else {
+                engine.process_block(&mut left[start..end], &mut right[start..end]);
+            }
+            start = end;
+        }
+        left
+    };
+
+    let reference = render(1);
+    assert!(
+        reference.iter().any(|s| s.abs() > 0.01),
+        "Note should sound"
+    );
+    for buffer_size in [64, 512] {
+        let output = render(buffer_size);
+        for (i, (a, b)) in reference.iter().zip(&output).enumerate() {
+            assert!(
+                (a - b).abs() < 1e-6,
+                "Buffer size {} differs at sample {}: {} vs {}",
+                buffer_size,
+                i,
+                a,
+                b
+            );
+        }
+    }
+}
+
+/// Test that the MIDI channel filter follows the preset and rejects other channels.
+/// Verifies:
+/// - Omni (the default) accepts every channel
+/// - A preset channel (1-16) accepts only that channel (0-15 on the wire)
+/// - `set_midi_channel_filter()` overrides it directly
+#[test]
+fn test_midi_channel_filter_ignores_other_channels() {
+    let (mut producer, consumer) = create_parameter_buffer();
+    let mut engine = SynthEngine::new(44100.0, consumer);
+    assert_eq!(engine.midi_channel_filter(), None);
+    assert!((0..16).all(|c| engine.accepts_midi_channel(c)));
+
+    let mut params = SynthParams {
+        midi_channel: 3,
+        ..Default::default()
+    };
+    producer.write(params);
+    for _ in 0..64 {
+        engine.process();
+    }
+    assert_eq!(engine.midi_channel_filter(), Some(2));
+    assert!(engine.accepts_midi_channel(2));
+    assert!(!engine.accepts_midi_channel(0), "Wrong channel is ignored");
+    assert!(!engine.accepts_midi_channel(3), "Wrong channel is ignored");
+
+    engine.set_midi_channel_filter(Some(9));
+    assert!(engine.accepts_midi_channel(9));
+    assert!(!engine.accepts_midi_channel(2));
+
+    params.midi_channel = 0;
+    producer.write(params);
+    for _ in 0..64 {
+        engine.process();
+    }
+    assert_eq!(engine.midi_channel_filter(), None, "Back to omni");
+}
+
+#[test]
+fn test_per_note_param_modulation_targets_one_voice() {
+    use crate::plugin::param_descriptor::PARAM_OSC1_GAIN;
+
+    let (_producer, consumer) = create_parameter_buffer();
+    let mut engine = SynthEngine::new(44100.0, consumer);
+    let peak = |engine: &mut SynthEngine| {
+        for _ in 0..2000 {
+            engine.process();
+        }
+        (0..1000).fold(0.0_f32, |max, _| {
+            let (l, r) = engine.process();
+            max.max(l.abs()).max(r.abs())
+        })
+    };
+
+    engine.note_on_with_id(60, 0.8, Some(1));
+    engine.note_on_with_id(64, 0.8, Some(2));
+    assert!(peak(&mut engine) > 0.01);
+
+    // Only oscillator 1 is on by default: muting it silences just note 1
+    assert!(!engine.modulate_voice_param(Some(9), None, PARAM_OSC1_GAIN, -1.0));
+    assert!(engine.modulate_voice_param(Some(1), None, PARAM_OSC1_GAIN, -1.0));
+    assert!(peak(&mut engine) > 0.01, "Note 2 keeps playing");
+
+    // Key addressing reaches the other note
+    assert!(engine.modulate_voice_param(None, Some(64), PARAM_OSC1_GAIN, -1.0));
+    assert!(peak(&mut engine) < 1e-3, "Both notes muted");
+
+    // A new note on a fresh voice starts unmodulated
+    engine.note_on_with_id(67, 0.8, Some(3));
+    assert!(
+        peak(&mut engine) > 0.01,
+        "Modulation doesn't leak to new notes"
+    );
+
+    // An offset of 0.0 clears it
+    assert!(engine.modulate_voice_param(Some(1), None, PARAM_OSC1_GAIN, 0.0));
+    assert_eq!(engine.active_voice_count(), 3);
+}
+
+/// Test that note repeat retriggers a held key once per step.
+/// Verifies:
+/// - A key held for a second at 120 BPM with 1/16 repeats attacks every 5512.5 samples
+/// - Releasing the key stops the repeats
+#[test]
+fn test_note_repeat_retriggers_held_note() {
+    use crate::dsp::modulation::envelope::EnvelopeStage;
+
+    let (mut producer, consumer) = create_parameter_buffer();
+    let mut engine = SynthEngine::new(44100.0, consumer);
+    let mut params = SynthParams::default();
+    params.envelope.attack = 0.001;
+    params.envelope.sustain = 1.0;
+    params.envelope.release = 0.002;
+    params.note_repeat.enabled = true;
+    params.note_repeat.tempo_sync = crate::params::TempoSync::Sixteenth;
+    params.note_repeat.gate = 0.5;
+    producer.write(params);
+    engine.set_tempo(120.0);
+    for _ in 0..1000 {
+        engine.process();
+    }
+
+    // Attacks = samples where some voice's envelope newly enters its attack stage
+    let onsets = |engine: &mut SynthEngine, samples: usize| {
+        let mut attacking = vec![false; engine.voices.len()];
+        let mut found = Vec::new();
+        for sample in 0..samples {
+            engine.process();
+            let mut onset = false;
+            for (voice, was_attacking) in engine.voices.iter().zip(attacking.iter_mut()) {
+                let now = voice.is_active() && voice.envelope_stage() == EnvelopeStage::Attack;
+                onset |= now && !*was_attacking;
+                *was_attacking = now;
+            }
+            if onset {
+                found.push(sample);
+            }
+        }
+        found
+    };
+
+    engine.note_on(60, 1.0);
+    let held = onsets(&mut engine, 44100);
+    assert_eq!(held.len(), 8, "Eight 1/16 steps in a second: {:?}", held);
+    for pair in held.windows(2) {
+        let interval = (pair[1] - pair[0]) as f32;
+        assert!(
+            (interval - 5512.5).abs() <= 1.0,
+            "Repeats every 1/16 at 120 BPM: {:?}",
+            held
+        );
+    }
+
+    engine.note_off(60);
+    let released = onsets(&mut engine, 22050);
+    assert!(
+        released.is_empty(),
+        "No repeats after release: {:?}",
+        released
+    );
+}
+
+/// Test the master monitoring modes on a panned note.
+/// Verifies:
+/// - Mid-only sends the same (L + R) / 2 signal to both channels
+/// - Side-only sends (L - R) / 2 to the left and its inverse to the right
+/// - Left-only duplicates the left channel
+#[test]
+fn test_monitor_mode_folds_final_output() {
+    // A low note hard-panned by key pan, so the channels clearly differ
+    let render = |mode: MonitorMode| {
+        let (mut producer, consumer) = create_parameter_buffer();
+        let mut engine = SynthEngine::new(44100.0, consumer);
+        producer.write(SynthParams {
+            key_pan_amount: 1.0,
+            ..SynthParams::default()
+        });
+        engine.set_monitor_mode(mode);
+        assert_eq!(engine.monitor_mode(), mode);
+        for _ in 0..64 {
+            engine.process();
+        }
+        engine.note_on(36, 1.0);
+        (0..4096).map(|_| engine.process()).collect::<Vec<_>>()
+    };
+
+    let stereo = render(MonitorMode::Stereo);
+    let side_peak = stereo
+        .iter()
+        .fold(0.0_f32, |peak, (l, r)| peak.max((l - r).abs()));
+    assert!(side_peak > 0.01, "The source should be panned");
+
+    let mid = render(MonitorMode::Mid);
+    let side = render(MonitorMode::Side);
+    let left = render(MonitorMode::Left);
+    for (i, &(l, r)) in stereo.iter().enumerate() {
+        assert_eq!(mid[i].0, mid[i].1);
+        assert!((mid[i].0 - (l + r) * 0.5).abs() < 1e-6);
+        assert_eq!(side[i].0, -side[i].1);
+        assert!((side[i].0 - (l - r) * 0.5).abs() < 1e-6);
+        assert_eq!(left[i], (l, l));
+    }
+}
+
+/// Test that the poly compensation amount sets how much stacked voices are turned down.
+/// Verifies:
+/// - Amount 0.0 leaves the voice sum untouched
+/// - The default 0.7 reproduces the original 1/N^0.35 curve
+/// - Amount 1.0 gives equal-power compensation (1/√N)
+/// - A single voice is never compensated
+#[test]
+fn test_poly_comp_amount_curve() {
+    use approx::assert_relative_eq;
+
+    fn settled_poly_gain(amount: f32, notes: &[u8]) -> f32 {
+        let (mut producer, consumer) = create_parameter_buffer();
+        let mut engine = SynthEngine::new(44100.0, consumer);
+        producer.write(SynthParams {
+            poly_comp_amount: amount,
+            ..Default::default()
+        });
+        for &note in notes {
+            engine.note_on(note, 1.0);
+        }
+        // Well past the 10 ms release smoothing
+        for _ in 0..4410 {
+            engine.process();
+        }
+        engine.poly_gain
+    }
+
+    let chord = [60, 64, 67, 71];
+    assert_relative_eq!(settled_poly_gain(0.0, &chord), 1.0, epsilon = 1e-3);
+    assert_relative_eq!(
+        settled_poly_gain(SynthParams::default().poly_comp_amount, &chord),
+        1.0 / 4.0_f32.powf(0.35),
+        epsilon = 1e-3
+    );
+    assert_relative_eq!(settled_poly_gain(1.0, &chord), 0.5, epsilon = 1e-3);
+    assert_relative_eq!(settled_poly_gain(1.0, &[60]), 1.0, epsilon = 1e-3);
+}
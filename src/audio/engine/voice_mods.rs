@@ -0,0 +1,110 @@
+//! Per-note parameter modulation from the host (CLAP polyphonic modulation).
+//!
+//! Hosts like Bitwig can modulate a parameter for a single note, addressed by the note
+//! ID it was started with. Each voice keeps the offsets aimed at its note and renders
+//! with a copy of the shared parameters that has them applied, so one note's filter
+//! can open while the others stay put. The oscillator, filter and LFO parameters the
+//! voice reads every sample are kept with the offsets, so they apply between updates
+//! too. Offsets are in normalized (0-1) units, the same space the parameters are
+//! exposed to the host in.
+
+use crate::params::{FilterParams, LFOParams, OscillatorParams, SynthParams};
+use crate::plugin::param_descriptor::ParamId;
+use crate::plugin::param_registry;
+use crate::plugin::param_update::{param_apply, param_get};
+
+/// Most parameters one note can have modulated at once (further ones are ignored)
+pub const MAX_VOICE_PARAM_MODS: usize = 16;
+
+/// The host's modulation offsets for the note a voice is playing
+#[derive(Debug, Clone, Copy)]
+pub(super) struct VoiceParamMods {
+    /// Host note ID the voice was started with (`None` for MIDI/untagged notes)
+    note_id: Option<i32>,
+    mods: [(ParamId, f32); MAX_VOICE_PARAM_MODS],
+    count: usize,
+    /// Modulated per-sample parameters from the last `apply()` (unused while empty)
+    oscillators: [OscillatorParams; 3],
+    filters: [FilterParams; 3],
+    lfos: [LFOParams; 3],
+}
+
+impl Default for VoiceParamMods {
+    fn default() -> Self {
+        Self {
+            note_id: None,
+            mods: [(0, 0.0); MAX_VOICE_PARAM_MODS],
+            count: 0,
+            oscillators: [OscillatorParams::default(); 3],
+            filters: [FilterParams::default(); 3],
+            lfos: [LFOParams::default(); 3],
+        }
+    }
+}
+
+impl VoiceParamMods {
+    /// A new note started on the voice: drop the previous note's modulation
+    pub(super) fn start(&mut self, note_id: Option<i32>) {
+        self.note_id = note_id;
+        self.count = 0;
+    }
+
+    pub(super) fn note_id(&self) -> Option<i32> {
+        self.note_id
+    }
+
+    pub(super) fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Set the offset for `param_id` (0.0 removes it)
+    pub(super) fn set(&mut self, param_id: ParamId, amount: f32) {
+        let existing = self.mods[..self.count]
+            .iter()
+            .position(|&(id, _)| id == param_id);
+        match existing {
+            Some(index) if amount == 0.0 => {
+                self.mods.copy_within(index + 1..self.count, index);
+                self.count -= 1;
+            }
+            Some(index) => self.mods[index].1 = amount,
+            None if amount != 0.0 && self.count < MAX_VOICE_PARAM_MODS => {
+                self.mods[self.count] = (param_id, amount);
+                self.count += 1;
+            }
+            None => {}
+        }
+    }
+
+    /// Copy of `base` with this note's offsets added to the normalized values, or `None`
+    /// when the note has no modulation (the voice renders with `base` directly)
+    ///
+    /// The modulated oscillator, filter and LFO parameters are kept for
+    /// `render_params()`.
+    pub(super) fn apply(&mut self, base: &SynthParams) -> Option<SynthParams> {
+        if self.is_empty() {
+            return None;
+        }
+        let registry = param_registry::get_registry();
+        let mut params = *base;
+        for &(param_id, amount) in &self.mods[..self.count] {
+            let Some(desc) = registry.get(param_id) else {
+                continue;
+            };
+            let normalized = desc.normalize_value(param_get::get_param(base, param_id));
+            param_apply::apply_param(&mut params, param_id, (normalized + amount).clamp(0.0, 1.0));
+        }
+        self.oscillators = params.oscillators;
+        self.filters = params.filters;
+        self.lfos = params.lfos;
+        Some(params)
+    }
+
+    /// Modulated oscillator, filter and LFO parameters to render with, or `None` to use
+    /// the shared ones
+    pub(super) fn render_params(
+        &self,
+    ) -> Option<(&[OscillatorParams; 3], &[FilterParams; 3], &[LFOParams; 3])> {
+        (!self.is_empty()).then_some((&self.oscillators, &self.filters, &self.lfos))
+    }
+}
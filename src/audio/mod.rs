@@ -91,6 +91,13 @@ pub mod engine;
 #[cfg(feature = "standalone")]
 pub mod output;
 
+/// The **recorder** module captures the engine output to a WAV file.
+///
+/// The standalone audio callback pushes every frame through a lock-free tap into a bounded
+/// channel; a writer thread drains it to disk. Recording never blocks the audio thread -
+/// if the writer falls seconds behind, frames are counted as dropped instead.
+pub mod recorder;
+
 /// The **voice** module implements a single polyphonic voice.
 ///
 /// Each voice is an independent synthesizer that can play one note at a time. A voice includes:
@@ -1,52 +1,193 @@
 use crate::audio::engine::SynthEngine;
+use crate::audio::recorder::Recorder;
 use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
 use cpal::{Device, Stream, StreamConfig};
-use crossbeam_channel::Receiver;
+use crossbeam_channel::{bounded, unbounded, Receiver, Sender};
+use parking_lot::Mutex;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::Instant;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum EngineEvent {
-    NoteOn { note: u8, velocity: f32 },
-    NoteOff { note: u8 },
+    NoteOn {
+        note: u8,
+        velocity: f32,
+    },
+    NoteOff {
+        note: u8,
+    },
     AllNotesOff,
+    /// Tempo from an external MIDI clock
+    SetTempo {
+        bpm: f64,
+    },
+    /// MIDI Start/Stop: realign LFOs to the sequencer
+    TransportStart,
+    TransportStop,
+}
+
+/// Requested output configuration; `None` fields use the device's default
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AudioSettings {
+    /// Output device name (as listed by `list_output_devices`)
+    pub device: Option<String>,
+    /// Sample rate in Hz
+    pub sample_rate: Option<u32>,
+    /// Buffer size in frames
+    pub buffer_size: Option<u32>,
+}
+
+/// Sample rates offered for selection (filtered per device by `supported_sample_rates`)
+pub const SAMPLE_RATE_CHOICES: [u32; 4] = [44100, 48000, 88200, 96000];
+
+/// Buffer sizes offered for selection, in frames
+pub const BUFFER_SIZE_CHOICES: [u32; 5] = [64, 128, 256, 512, 1024];
+
+/// Per-callback smoothing of the CPU load estimate (higher = faster response)
+const CPU_LOAD_SMOOTHING: f32 = 0.05;
+
+/// Load figures published by the audio callback
+///
+/// Plain atomics: the callback stores, the GUI loads, neither ever waits.
+#[derive(Debug, Default)]
+pub struct AudioStats {
+    active_voices: AtomicU32,
+    /// `f32` bits of the smoothed load
+    cpu_load: AtomicU32,
+}
+
+impl AudioStats {
+    /// Voices sounding at the end of the last callback
+    pub fn active_voices(&self) -> u32 {
+        self.active_voices.load(Ordering::Relaxed)
+    }
+
+    /// Smoothed fraction of the buffer period spent rendering (1.0 = no headroom left)
+    pub fn cpu_load(&self) -> f32 {
+        f32::from_bits(self.cpu_load.load(Ordering::Relaxed))
+    }
+
+    fn publish(&self, active_voices: u32, cpu_load: f32) {
+        self.active_voices.store(active_voices, Ordering::Relaxed);
+        self.cpu_load.store(cpu_load.to_bits(), Ordering::Relaxed);
+    }
 }
 
 pub struct AudioOutput {
-    _stream: Stream,
+    stream: Option<Stream>,
+    /// Shared with the stream callback so it survives stream rebuilds
+    engine: Arc<Mutex<SynthEngine>>,
+    event_rx: Receiver<EngineEvent>,
+    /// Settings the running stream actually uses (device default values filled in)
+    settings: AudioSettings,
     sample_rate: f32,
+    /// WAV capture of everything the stream plays
+    recorder: Recorder,
+    /// Voice count and CPU load, shared with every stream this output builds
+    stats: Arc<AudioStats>,
 }
 
 impl AudioOutput {
-    /// Create and start audio output
+    /// Create and start audio output on the default device
     ///
     /// # Arguments
-    /// * `engine` - Synth engine instance (owned by the audio callback thread)
+    /// * `engine` - Synth engine instance (run by the audio callback thread)
     /// * `event_rx` - Non-blocking event receiver for note/transport events
     ///
     /// # Returns
     /// Result containing AudioOutput or error message
     pub fn new(engine: SynthEngine, event_rx: Receiver<EngineEvent>) -> Result<Self, String> {
+        Self::with_settings(engine, event_rx, &AudioSettings::default())
+    }
+
+    /// Create and start audio output with the requested settings
+    ///
+    /// Falls back to the default device if the requested settings can't be opened.
+    pub fn with_settings(
+        engine: SynthEngine,
+        event_rx: Receiver<EngineEvent>,
+        settings: &AudioSettings,
+    ) -> Result<Self, String> {
+        let mut output = Self {
+            stream: None,
+            sample_rate: engine.sample_rate(),
+            engine: Arc::new(Mutex::new(engine)),
+            event_rx,
+            settings: AudioSettings::default(),
+            recorder: Recorder::new(),
+            stats: Arc::new(AudioStats::default()),
+        };
+
+        if let Err(e) = output.apply_settings(settings) {
+            if output.stream.is_none() {
+                return Err(e);
+            }
+            eprintln!("{}; using the default output device", e);
+        }
+
+        Ok(output)
+    }
+
+    /// Recreate the stream with new settings and retune the engine to the new rate
+    ///
+    /// If the requested settings fail, the default device is opened instead and the
+    /// original error is returned; `settings()` reports what is actually running.
+    pub fn apply_settings(&mut self, requested: &AudioSettings) -> Result<(), String> {
+        // A take can't change sample rate midway; finish it at the old one
+        if let Err(e) = self.recorder.stop() {
+            eprintln!("{}", e);
+        }
+
+        // Stop the old callback before touching the engine
+        self.stream = None;
+
+        match self.start(requested) {
+            Ok(()) => Ok(()),
+            Err(e) if *requested != AudioSettings::default() => {
+                self.start(&AudioSettings::default()).map_err(|fallback| {
+                    format!("{} (default device also failed: {})", e, fallback)
+                })?;
+                Err(e)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Open the device and start a stream for `requested`
+    fn start(&mut self, requested: &AudioSettings) -> Result<(), String> {
         let host = cpal::default_host();
 
-        let device = host
-            .default_output_device()
-            .ok_or_else(|| "No output device available".to_string())?;
+        let device = match requested.device.as_deref() {
+            Some(name) => find_output_device(&host, name)?,
+            None => host
+                .default_output_device()
+                .ok_or_else(|| "No output device available".to_string())?,
+        };
 
-        let config = device
+        let default_config = device
             .default_output_config()
             .map_err(|e| format!("Failed to get default output config: {}", e))?;
 
-        let sample_rate = config.sample_rate().0 as f32;
-
-        let stream = match config.sample_format() {
-            cpal::SampleFormat::F32 => {
-                Self::build_stream::<f32>(&device, &config.into(), engine, event_rx)?
-            }
-            cpal::SampleFormat::I16 => {
-                Self::build_stream::<i16>(&device, &config.into(), engine, event_rx)?
-            }
-            cpal::SampleFormat::U16 => {
-                Self::build_stream::<u16>(&device, &config.into(), engine, event_rx)?
+        let mut config: StreamConfig = default_config.config();
+        if let Some(rate) = requested.sample_rate {
+            if !device_supports_rate(&device, rate) {
+                return Err(format!("{} Hz is not supported by this device", rate));
             }
+            config.sample_rate = cpal::SampleRate(rate);
+        }
+        if let Some(frames) = requested.buffer_size {
+            config.buffer_size = cpal::BufferSize::Fixed(frames);
+        }
+
+        let sample_rate = config.sample_rate.0;
+        self.engine.lock().set_sample_rate(sample_rate as f32);
+
+        let stream = match default_config.sample_format() {
+            cpal::SampleFormat::F32 => self.build_stream::<f32>(&device, &config)?,
+            cpal::SampleFormat::I16 => self.build_stream::<i16>(&device, &config)?,
+            cpal::SampleFormat::U16 => self.build_stream::<u16>(&device, &config)?,
             _ => return Err("Unsupported sample format".to_string()),
         };
 
@@ -54,23 +195,28 @@ impl AudioOutput {
             .play()
             .map_err(|e| format!("Failed to play stream: {}", e))?;
 
-        Ok(Self {
-            _stream: stream,
-            sample_rate,
-        })
+        self.stream = Some(stream);
+        self.sample_rate = sample_rate as f32;
+        self.settings = AudioSettings {
+            device: device.name().ok(),
+            sample_rate: Some(sample_rate),
+            buffer_size: requested.buffer_size,
+        };
+        Ok(())
     }
 
     /// Build audio stream for specific sample type
-    fn build_stream<T>(
-        device: &Device,
-        config: &StreamConfig,
-        mut engine: SynthEngine,
-        event_rx: Receiver<EngineEvent>,
-    ) -> Result<Stream, String>
+    fn build_stream<T>(&self, device: &Device, config: &StreamConfig) -> Result<Stream, String>
     where
         T: cpal::Sample + cpal::SizedSample + cpal::FromSample<f32>,
     {
         let channels = config.channels as usize;
+        let engine = self.engine.clone();
+        let event_rx = self.event_rx.clone();
+        let recorder_tap = self.recorder.tap();
+        let stats = self.stats.clone();
+        let sample_rate = config.sample_rate.0 as f32;
+        let mut smoothed_load = 0.0f32;
 
         let err_fn = |err| eprintln!("Audio stream error: {}", err);
 
@@ -78,6 +224,14 @@ impl AudioOutput {
             .build_output_stream(
                 config,
                 move |data: &mut [T], _: &cpal::OutputCallbackInfo| {
+                    let started = Instant::now();
+
+                    // Only contended while the stream is being rebuilt; output silence then
+                    let Some(mut engine) = engine.try_lock() else {
+                        data.fill(cpal::Sample::EQUILIBRIUM);
+                        return;
+                    };
+
                     // Drain pending events without blocking.
                     for event in event_rx.try_iter() {
                         match event {
@@ -86,6 +240,10 @@ impl AudioOutput {
                             }
                             EngineEvent::NoteOff { note } => engine.note_off(note),
                             EngineEvent::AllNotesOff => engine.all_notes_off(),
+                            EngineEvent::SetTempo { bpm } => engine.set_tempo(bpm),
+                            EngineEvent::TransportStart | EngineEvent::TransportStop => {
+                                engine.reset_lfo_phases()
+                            }
                         }
                     }
 
@@ -93,6 +251,7 @@ impl AudioOutput {
                         if channels >= 2 {
                             // Stereo output - use process() which now returns stereo by default
                             let (sample_left, sample_right) = engine.process();
+                            recorder_tap.push(sample_left, sample_right);
                             frame[0] = cpal::Sample::from_sample(sample_left);
                             frame[1] = cpal::Sample::from_sample(sample_right);
                             // Fill any additional channels with right channel
@@ -102,12 +261,21 @@ impl AudioOutput {
                         } else {
                             // Mono output fallback - explicitly use process_mono()
                             let sample = engine.process_mono();
+                            recorder_tap.push(sample, sample);
                             let sample_t = cpal::Sample::from_sample(sample);
                             for channel_sample in frame.iter_mut() {
                                 *channel_sample = sample_t;
                             }
                         }
                     }
+
+                    // Time spent vs the real time this buffer covers
+                    let period = (data.len() / channels) as f32 / sample_rate;
+                    if period > 0.0 {
+                        let load = started.elapsed().as_secs_f32() / period;
+                        smoothed_load += (load - smoothed_load) * CPU_LOAD_SMOOTHING;
+                        stats.publish(engine.active_voice_count() as u32, smoothed_load);
+                    }
                 },
                 err_fn,
                 None,
@@ -121,6 +289,120 @@ impl AudioOutput {
     pub fn sample_rate(&self) -> f32 {
         self.sample_rate
     }
+
+    /// Settings of the running stream
+    pub fn settings(&self) -> &AudioSettings {
+        &self.settings
+    }
+
+    /// Recorder fed by this output's stream
+    pub fn recorder(&self) -> Recorder {
+        self.recorder.clone()
+    }
+
+    /// Voice count and CPU load of the running stream
+    pub fn stats(&self) -> Arc<AudioStats> {
+        self.stats.clone()
+    }
+}
+
+/// Request sent to the audio control thread: new settings plus a reply channel that
+/// receives the outcome and the settings now running
+type SettingsRequest = (AudioSettings, Sender<(Result<(), String>, AudioSettings)>);
+
+/// Cloneable handle to an `AudioOutput` running on its own control thread
+///
+/// cpal streams can't move between threads, so the output stays on the thread that
+/// created it and the GUI sends it new settings instead. The stream stops once every
+/// handle is dropped.
+#[derive(Clone)]
+pub struct AudioControl {
+    requests: Sender<SettingsRequest>,
+    /// Settings of the running stream, updated after every change
+    current: Arc<Mutex<AudioSettings>>,
+    recorder: Recorder,
+    stats: Arc<AudioStats>,
+}
+
+impl AudioControl {
+    /// Start audio output on a dedicated thread
+    ///
+    /// Blocks until the stream is running (or has failed to start).
+    pub fn spawn(
+        engine: SynthEngine,
+        event_rx: Receiver<EngineEvent>,
+        settings: AudioSettings,
+    ) -> Result<Self, String> {
+        let (requests, request_rx) = unbounded::<SettingsRequest>();
+        let (ready_tx, ready_rx) = bounded(1);
+
+        thread::Builder::new()
+            .name("audio-control".to_string())
+            .spawn(move || {
+                let mut output = match AudioOutput::with_settings(engine, event_rx, &settings) {
+                    Ok(output) => output,
+                    Err(e) => {
+                        let _ = ready_tx.send(Err(e));
+                        return;
+                    }
+                };
+                let _ = ready_tx.send(Ok((
+                    output.settings().clone(),
+                    output.recorder(),
+                    output.stats(),
+                )));
+
+                // Runs until every AudioControl handle is dropped
+                for (requested, reply) in request_rx {
+                    let result = output.apply_settings(&requested);
+                    let _ = reply.send((result, output.settings().clone()));
+                }
+            })
+            .map_err(|e| format!("Failed to spawn audio thread: {}", e))?;
+
+        let (started, recorder, stats) = ready_rx
+            .recv()
+            .map_err(|_| "Audio thread exited during startup".to_string())??;
+        Ok(Self {
+            requests,
+            current: Arc::new(Mutex::new(started)),
+            recorder,
+            stats,
+        })
+    }
+
+    /// Switch to new settings, blocking until the stream has been rebuilt
+    ///
+    /// # Returns
+    /// The settings now running, or the error (the default device is then running instead)
+    pub fn apply(&self, settings: AudioSettings) -> Result<AudioSettings, String> {
+        let (reply_tx, reply_rx) = bounded(1);
+        self.requests
+            .send((settings, reply_tx))
+            .map_err(|_| "Audio thread is not running".to_string())?;
+        let (result, running) = reply_rx
+            .recv()
+            .map_err(|_| "Audio thread is not running".to_string())?;
+
+        // Even on failure something (the default device) may now be running
+        *self.current.lock() = running.clone();
+        result.map(|()| running)
+    }
+
+    /// Settings of the running stream
+    pub fn settings(&self) -> AudioSettings {
+        self.current.lock().clone()
+    }
+
+    /// Recorder fed by the output stream
+    pub fn recorder(&self) -> &Recorder {
+        &self.recorder
+    }
+
+    /// Voice count and CPU load published by the audio callback
+    pub fn stats(&self) -> &AudioStats {
+        &self.stats
+    }
 }
 
 /// List available audio output devices
@@ -140,11 +422,39 @@ pub fn list_output_devices() -> Result<Vec<String>, String> {
     Ok(device_names)
 }
 
+/// Entries of `SAMPLE_RATE_CHOICES` the device can run at (`None` = default device)
+pub fn supported_sample_rates(device: Option<&str>) -> Result<Vec<u32>, String> {
+    let host = cpal::default_host();
+    let device = match device {
+        Some(name) => find_output_device(&host, name)?,
+        None => host
+            .default_output_device()
+            .ok_or_else(|| "No output device available".to_string())?,
+    };
+
+    Ok(SAMPLE_RATE_CHOICES
+        .into_iter()
+        .filter(|&rate| device_supports_rate(&device, rate))
+        .collect())
+}
+
+fn find_output_device(host: &cpal::Host, name: &str) -> Result<Device, String> {
+    host.output_devices()
+        .map_err(|e| format!("Failed to enumerate devices: {}", e))?
+        .find(|device| device.name().is_ok_and(|n| n == name))
+        .ok_or_else(|| format!("Output device '{}' not found", name))
+}
+
+fn device_supports_rate(device: &Device, rate: u32) -> bool {
+    device.supported_output_configs().is_ok_and(|mut configs| {
+        configs.any(|range| (range.min_sample_rate().0..=range.max_sample_rate().0).contains(&rate))
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::audio::engine::create_parameter_buffer;
-    use crossbeam_channel::bounded;
 
     #[test]
     fn test_list_output_devices() {
@@ -157,6 +467,14 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_supported_sample_rates() {
+        // May fail without an audio device; any rates reported must be offered choices
+        if let Ok(rates) = supported_sample_rates(None) {
+            assert!(rates.iter().all(|rate| SAMPLE_RATE_CHOICES.contains(rate)));
+        }
+    }
+
     #[test]
     fn test_audio_output_creation() {
         let (_producer, consumer) = create_parameter_buffer();
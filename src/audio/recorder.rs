@@ -0,0 +1,217 @@
+//! Recording of the engine output to a WAV file.
+//!
+//! The audio callback pushes frames into a bounded lock-free channel through a
+//! `RecorderTap` (a `try_send` per frame, never blocking); a writer thread drains the
+//! channel into a 32-bit float stereo WAV. The channel holds `RECORDER_BUFFER_SECONDS` of
+//! audio, so the writer can stall on disk for seconds before anything is dropped.
+
+use crossbeam_channel::{bounded, Receiver, Sender};
+use parking_lot::Mutex;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+/// Seconds of audio the tap can buffer ahead of the writer thread
+pub const RECORDER_BUFFER_SECONDS: usize = 4;
+
+/// Highest sample rate the buffer is sized for (lower rates just get more headroom)
+const MAX_SAMPLE_RATE: usize = 96_000;
+
+/// How often the writer thread drains the buffer
+const WRITER_POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+/// Result of a finished recording
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordingSummary {
+    pub path: PathBuf,
+    /// Stereo frames written
+    pub frames: u64,
+    /// Frames lost because the writer fell behind (0 under normal load)
+    pub dropped_frames: u64,
+}
+
+struct Shared {
+    sender: Sender<(f32, f32)>,
+    receiver: Receiver<(f32, f32)>,
+    recording: AtomicBool,
+    dropped_frames: AtomicU64,
+}
+
+/// Audio-thread end of the recorder
+#[derive(Clone)]
+pub struct RecorderTap {
+    shared: Arc<Shared>,
+}
+
+impl RecorderTap {
+    /// Offer one stereo frame; a no-op unless a recording is running
+    #[inline]
+    pub fn push(&self, left: f32, right: f32) {
+        if !self.shared.recording.load(Ordering::Relaxed) {
+            return;
+        }
+        if self.shared.sender.try_send((left, right)).is_err() {
+            self.shared.dropped_frames.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+}
+
+/// Running take: destination and the writer thread (returns frames written)
+type ActiveWriter = (PathBuf, JoinHandle<Result<u64, String>>);
+
+/// Control end of the recorder: starts and stops WAV files
+#[derive(Clone)]
+pub struct Recorder {
+    shared: Arc<Shared>,
+    writer: Arc<Mutex<Option<ActiveWriter>>>,
+}
+
+impl Default for Recorder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Recorder {
+    pub fn new() -> Self {
+        let (sender, receiver) = bounded(RECORDER_BUFFER_SECONDS * MAX_SAMPLE_RATE);
+        Self {
+            shared: Arc::new(Shared {
+                sender,
+                receiver,
+                recording: AtomicBool::new(false),
+                dropped_frames: AtomicU64::new(0),
+            }),
+            writer: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Handle for the audio callback
+    pub fn tap(&self) -> RecorderTap {
+        RecorderTap {
+            shared: self.shared.clone(),
+        }
+    }
+
+    pub fn is_recording(&self) -> bool {
+        self.writer.lock().is_some()
+    }
+
+    /// Start writing to `path` (created or overwritten)
+    ///
+    /// # Arguments
+    /// * `path` - Destination WAV file
+    /// * `sample_rate` - Rate of the running stream, written to the WAV header
+    pub fn start(&self, path: &Path, sample_rate: u32) -> Result<(), String> {
+        let mut writer_slot = self.writer.lock();
+        if writer_slot.is_some() {
+            return Err("Already recording".to_string());
+        }
+
+        let spec = hound::WavSpec {
+            channels: 2,
+            sample_rate,
+            bits_per_sample: 32,
+            sample_format: hound::SampleFormat::Float,
+        };
+        let mut writer = hound::WavWriter::create(path, spec)
+            .map_err(|e| format!("Failed to create {}: {}", path.display(), e))?;
+
+        // Discard anything left over from a previous take
+        for _ in self.shared.receiver.try_iter() {}
+        self.shared.dropped_frames.store(0, Ordering::Relaxed);
+        self.shared.recording.store(true, Ordering::Release);
+
+        let shared = self.shared.clone();
+        let handle = thread::Builder::new()
+            .name("wav-recorder".to_string())
+            .spawn(move || {
+                let mut frames = 0u64;
+                let mut write_error = None;
+                loop {
+                    // Read the flag before draining so the final frames are always written
+                    let stopping = !shared.recording.load(Ordering::Acquire);
+                    for (left, right) in shared.receiver.try_iter() {
+                        if write_error.is_none() {
+                            write_error = writer
+                                .write_sample(left)
+                                .and_then(|()| writer.write_sample(right))
+                                .err();
+                            frames += 1;
+                        }
+                    }
+                    if stopping {
+                        break;
+                    }
+                    thread::sleep(WRITER_POLL_INTERVAL);
+                }
+                if let Some(e) = write_error {
+                    return Err(format!("Failed to write recording: {}", e));
+                }
+                writer
+                    .finalize()
+                    .map_err(|e| format!("Failed to finish recording: {}", e))?;
+                Ok(frames)
+            })
+            .map_err(|e| {
+                self.shared.recording.store(false, Ordering::Release);
+                format!("Failed to spawn recorder thread: {}", e)
+            })?;
+
+        *writer_slot = Some((path.to_path_buf(), handle));
+        Ok(())
+    }
+
+    /// Stop recording and finish the file; `Ok(None)` if nothing was recording
+    pub fn stop(&self) -> Result<Option<RecordingSummary>, String> {
+        let Some((path, handle)) = self.writer.lock().take() else {
+            return Ok(None);
+        };
+        self.shared.recording.store(false, Ordering::Release);
+
+        let frames = handle
+            .join()
+            .map_err(|_| "Recorder thread panicked".to_string())??;
+        Ok(Some(RecordingSummary {
+            path,
+            frames,
+            dropped_frames: self.shared.dropped_frames.load(Ordering::Relaxed),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recording_writes_pushed_frames() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("take.wav");
+        let recorder = Recorder::new();
+        let tap = recorder.tap();
+
+        tap.push(1.0, 1.0); // Not recording yet: ignored
+        recorder.start(&path, 48000).unwrap();
+        assert!(recorder.is_recording());
+        for i in 0..1000 {
+            let sample = i as f32 / 1000.0;
+            tap.push(sample, -sample);
+        }
+        let summary = recorder.stop().unwrap().unwrap();
+        assert_eq!(summary.frames, 1000);
+        assert_eq!(summary.dropped_frames, 0);
+        assert!(!recorder.is_recording());
+        assert_eq!(recorder.stop().unwrap(), None);
+
+        let mut reader = hound::WavReader::open(&path).unwrap();
+        assert_eq!(reader.spec().sample_rate, 48000);
+        assert_eq!(reader.spec().channels, 2);
+        let samples: Vec<f32> = reader.samples::<f32>().map(Result::unwrap).collect();
+        assert_eq!(samples.len(), 2000);
+        assert_eq!(samples[2 * 500], 0.5);
+        assert_eq!(samples[2 * 500 + 1], -0.5);
+    }
+}
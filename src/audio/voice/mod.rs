@@ -46,12 +46,16 @@
 
 use crate::dsp::effects::dynamics::compressor::Compressor;
 use crate::dsp::{
-    filters::filter::BiquadFilter, modulation::envelope::Envelope, modulation::lfo::LFO,
+    filters::filter::BiquadFilter,
+    modulation::drift::Drift,
+    modulation::envelope::{Envelope, EnvelopeStage},
+    modulation::lfo::LFO,
+    synthesis::noise_burst::NoiseBurst,
     synthesis::oscillator::Oscillator,
 };
 use crate::params::{
-    EnvelopeParams, FilterParams, LFOParams, OscillatorParams, TransientShaperParams,
-    VelocityParams, VoiceCompressorParams,
+    DrivePosition, EnvelopeParams, FilterParams, FilterTopology, LFOParams, OscillatorParams,
+    TransientShaperParams, VelocityParams, VoiceCompressorParams,
 };
 
 /// Maximum number of unison voices per oscillator slot.
@@ -62,6 +66,23 @@ use crate::params::{
 /// is always allocated per slot.
 const MAX_UNISON_VOICES: usize = 7;
 
+/// Pitch drift at `analog_drift = 1.0`, in cents (each oscillator drifts independently)
+const DRIFT_MAX_CENTS: f32 = 6.0;
+
+/// Filter cutoff drift at `analog_drift = 1.0`, in octaves
+const DRIFT_MAX_CUTOFF_OCTAVES: f32 = 0.15;
+
+/// Largest fraction of the amp envelope attack/decay time that velocity can remove
+/// (at sensitivity 1.0 and velocity 1.0), so times never collapse to zero
+const VELOCITY_TIME_MAX_REDUCTION: f32 = 0.9;
+
+/// Amp release time used by `kill()`, in seconds: short enough to silence at once, long
+/// enough not to click
+const KILL_FADE_SECONDS: f32 = 0.005;
+
+/// Drift generator rates in Hz; slightly different so the sources never move in lockstep
+const DRIFT_RATES_HZ: [f32; 4] = [0.31, 0.37, 0.43, 0.23];
+
 /// A single polyphonic voice combining oscillators, filters, envelopes, and LFOs.
 ///
 /// This struct represents one "note" in a polyphonic synthesizer. The engine pre-allocates
@@ -206,6 +227,10 @@ pub struct Voice {
     /// oscillators modulating earlier ones via 1-sample delayed feedback).
     osc_outputs_prev: [f32; 3],
 
+    /// Per-oscillator sample-rate reduction: hold-clock phase (0.0-1.0) and held sample
+    sample_reduce_phase: [f32; 3],
+    sample_reduce_hold: [f32; 3],
+
     /// Anti-click fade-in sample counter.
     ///
     /// Counts samples since note-on to apply a short exponential fade-in (1-2ms)
@@ -251,12 +276,46 @@ pub struct Voice {
     /// Per-filter smoothed cutoff frequency (Hz) used during mono legato transitions.
     mono_smoothed_cutoff_hz: [f32; 3],
 
+    /// Per-filter one-pole coefficient for smoothing the base cutoff parameter.
+    ///
+    /// Cutoff is the most heavily automated parameter, and host automation arrives in
+    /// 32-sample steps (see the engine's parameter throttle). Applying those steps directly
+    /// to the biquad produces audible zipper noise, so each filter's base cutoff is slewed
+    /// per-sample with a time constant of `FilterParams::cutoff_slew_ms`. Modulation sources
+    /// (envelope, LFO, key tracking, velocity) are added after smoothing so they stay snappy.
+    cutoff_slew_coeff: [f32; 3],
+
+    /// Per-filter smoothed base cutoff (Hz). A value of 0.0 means "snap to the target".
+    smoothed_base_cutoff_hz: [f32; 3],
+
+    /// Per-oscillator one-pole coefficient for smoothing the oscillator gain.
+    ///
+    /// Oscillator levels are automated heavily when layering, and a gain step (above all a
+    /// mute or unmute) lands on the waveform as a click. Each gain is slewed per-sample
+    /// with a time constant of `OscillatorParams::gain_slew_ms`.
+    osc_gain_slew_coeff: [f32; 3],
+
+    /// Per-oscillator smoothed gain
+    smoothed_osc_gain: [f32; 3],
+
+    /// Set on note-on so the smoothed gains snap to their targets at the next sample
+    snap_osc_gain: bool,
+
+    /// Per-filter resonance compensation gain (1.0 when `FilterParams::res_comp` is off).
+    ///
+    /// Cached in `update_parameters()` since it only depends on the resonance setting.
+    /// See `resonance_compensation_gain()` for the curve.
+    filter_res_comp_gain: [f32; 3],
+
     /// Last MIDI note that oscillator-dependent parameters were applied for.
     ///
     /// Used to avoid recomputing oscillator frequency/unison state when only non-osc
     /// parameters (e.g., filter cutoff) change.
     last_applied_note: u8,
 
+    /// Global tuning offset (cents) that oscillator frequencies were last computed with
+    last_applied_tuning_cents: f32,
+
     /// Cached last-applied oscillator parameters.
     last_applied_osc_params: [OscillatorParams; 3],
 
@@ -299,6 +358,55 @@ pub struct Voice {
     /// Cached equal-power panning gains (only used when `pan_mod_active` is false).
     cached_pan_left_gain: [f32; 3],
     cached_pan_right_gain: [f32; 3],
+
+    /// Keyboard-panning balance gains for the whole voice, set at note-on.
+    ///
+    /// Both are 1.0 when keyboard panning is off, so the voice output is untouched.
+    key_pan_left_gain: f32,
+    key_pan_right_gain: f32,
+
+    /// Parallel (per-routing buses) or serial (Filter 1 → 2 → 3) filter combination
+    filter_topology: FilterTopology,
+
+    /// Velocity → amp envelope attack/decay time amounts (see `VelocityParams`)
+    velocity_attack_sensitivity: f32,
+    velocity_decay_sensitivity: f32,
+
+    /// Release velocity → amp release time amount, and this note's release velocity
+    /// (0.0 until a note-off carrying velocity arrives)
+    velocity_release_sensitivity: f32,
+    release_velocity: f32,
+
+    /// Released by `kill()`: the amp envelope fades out over `KILL_FADE_SECONDS`
+    killed: bool,
+
+    /// Whether the amp envelope currently holds velocity- or key-scaled times
+    envelope_times_scaled: bool,
+
+    /// Analog drift amount (0.0 = stable digital, 1.0 = maximum wander)
+    analog_drift: f32,
+
+    /// Slow random sources: one per oscillator slot (pitch), then one for filter cutoff
+    drift: [Drift; 4],
+
+    /// Global tuning offset in cents, applied to every oscillator's base frequency
+    tuning_cents: f32,
+
+    /// Highpassed noise burst mixed in at note-on (the voice click)
+    click: NoiseBurst,
+
+    /// Click level (0.0 = off)
+    click_level: f32,
+
+    /// Samples the current note has been held (counted only while a limit is set)
+    held_samples: u64,
+
+    /// Held notes auto-release after this many samples (0 = unlimited)
+    max_held_samples: u64,
+
+    /// Paraphonic mode: skip the voice filters and use the amp envelope only as a key gate
+    /// (the engine filters and shapes the summed voices)
+    paraphonic: bool,
 }
 
 impl Voice {
@@ -376,6 +484,8 @@ impl Voice {
             last_output_right: 0.0,
             needs_dsp_reset_on_update: false,
             osc_outputs_prev: [0.0; 3],
+            sample_reduce_phase: [0.0; 3],
+            sample_reduce_hold: [0.0; 3],
             osc1_phase_prev: 0.0, // Initialize hard sync phase tracking
             osc2_phase_prev: 0.0, // Initialize hard sync chain tracking
             anti_click_samples: 0,
@@ -392,8 +502,23 @@ impl Voice {
             mono_declick_cutoff_coeff: (-1.0 / (0.0005 * sample_rate)).exp(), // 0.5ms time constant
             mono_smoothed_cutoff_hz: [0.0; 3],
 
+            // Base cutoff slew (per-sample one-pole smoothing of automated cutoff).
+            cutoff_slew_coeff: [Self::slew_coeff_from_ms(
+                FilterParams::default().cutoff_slew_ms,
+                sample_rate,
+            ); 3],
+            smoothed_base_cutoff_hz: [0.0; 3],
+            osc_gain_slew_coeff: [Self::slew_coeff_from_ms(
+                OscillatorParams::default().gain_slew_ms,
+                sample_rate,
+            ); 3],
+            smoothed_osc_gain: [0.0; 3],
+            snap_osc_gain: true,
+            filter_res_comp_gain: [1.0; 3],
+
             // Parameter caching for incremental updates.
             last_applied_note: 0,
+            last_applied_tuning_cents: 0.0,
             last_applied_osc_params: [OscillatorParams::default(); 3],
             last_applied_filter_params: [FilterParams::default(); 3],
             last_applied_lfo_params: [LFOParams::default(); 3],
@@ -408,6 +533,28 @@ impl Voice {
             pan_mod_active: false,
             cached_pan_left_gain: [std::f32::consts::FRAC_1_SQRT_2; 3],
             cached_pan_right_gain: [std::f32::consts::FRAC_1_SQRT_2; 3],
+
+            key_pan_left_gain: 1.0,
+            key_pan_right_gain: 1.0,
+            filter_topology: FilterTopology::Parallel,
+
+            velocity_attack_sensitivity: 0.0,
+            velocity_decay_sensitivity: 0.0,
+            velocity_release_sensitivity: 0.0,
+            release_velocity: 0.0,
+            killed: false,
+            envelope_times_scaled: false,
+
+            analog_drift: 0.0,
+            drift: std::array::from_fn(|i| {
+                Drift::new(sample_rate, DRIFT_RATES_HZ[i], i as u32 + 1)
+            }),
+            tuning_cents: 0.0,
+            click: NoiseBurst::new(sample_rate, 1),
+            click_level: 0.0,
+            held_samples: 0,
+            max_held_samples: 0,
+            paraphonic: false,
         }
     }
 
@@ -457,6 +604,7 @@ impl Voice {
         }
 
         self.note = note;
+        self.held_samples = 0;
         // Clamp velocity to valid range [0.0, 1.0] to handle edge cases
         // (e.g., MIDI controller sending out-of-spec values)
         self.velocity = velocity.clamp(0.0, 1.0);
@@ -468,6 +616,19 @@ impl Voice {
         self.peak_amplitude = 0.0;
         self.last_output = 0.0;
 
+        // Velocity- and key-scaled envelope times depend on this note
+        // (also rescaled when the previous note's scaling needs undoing)
+        self.release_velocity = 0.0;
+        self.killed = false;
+        if self.velocity_attack_sensitivity > 0.0
+            || self.velocity_decay_sensitivity > 0.0
+            || self.last_applied_envelope_params.key_scaling > 0.0
+            || self.envelope_times_scaled
+        {
+            let envelope_params = self.last_applied_envelope_params;
+            self.apply_envelope_times(&envelope_params);
+        }
+
         // Trigger the ADSR envelope's attack phase
         // The envelope will fade in from 0.0 to 1.0 over the attack time (typically 10-100ms)
         self.envelope.note_on();
@@ -513,6 +674,15 @@ impl Voice {
         // Old values would cause discontinuities in FM modulation.
         self.osc_outputs_prev = [0.0; 3];
 
+        // Don't let a sample held from the previous note leak into this one
+        self.sample_reduce_hold = [0.0; 3];
+
+        // The click is the audible attack, so it starts where the anti-click fade would
+        // otherwise leave the note silent
+        if self.click_level > 0.0 {
+            self.click.trigger();
+        }
+
         // Reset anti-click fade counter to trigger fade-in for this note.
         // This ensures a smooth 2ms fade-in at the start of every note,
         // preventing clicks when voices are stolen while still producing sound.
@@ -521,6 +691,10 @@ impl Voice {
         // Any fresh note-on should start from a clean cutoff state.
         self.mono_declick_samples_remaining = 0;
         self.mono_smoothed_cutoff_hz = [0.0; 3];
+        self.smoothed_base_cutoff_hz = [0.0; 3];
+
+        // The note's fade-in covers the start, so oscillator levels don't glide in
+        self.snap_osc_gain = true;
 
         // Reset all DSP components IMMEDIATELY to prevent clicks from stale state.
         // This must happen in note_on() rather than being deferred to update_parameters()
@@ -542,6 +716,7 @@ impl Voice {
         for filter in &mut self.filters {
             filter.reset();
         }
+        self.smoothed_base_cutoff_hz = [0.0; 3];
 
         // Reset per-voice compressor (clears envelope follower state)
         self.voice_compressor.reset();
@@ -561,6 +736,7 @@ impl Voice {
     /// occur when switching notes while the voice is already producing sound.
     pub fn note_change_legato(&mut self, note: u8, velocity: f32) {
         self.note = note;
+        self.held_samples = 0;
         self.velocity = velocity.clamp(0.0, 1.0);
         self.is_active = true;
         self.mono_declick_samples_remaining = self.mono_declick_total_samples;
@@ -568,6 +744,172 @@ impl Voice {
         // Frequency changes are applied immediately by the caller via update_parameters().
     }
 
+    /// Take over a releasing voice for a new note without restarting it (poly legato).
+    ///
+    /// Used by the engine when a note arrives within the legato window of this voice's
+    /// release. Pitch changes like `note_change_legato()`, and the amp/filter envelopes
+    /// climb back from their current release level instead of restarting from silence.
+    pub fn note_resume_legato(&mut self, note: u8, velocity: f32) {
+        self.note_change_legato(note, velocity);
+        self.envelope.note_on_legato();
+        for env in &mut self.filter_envelopes {
+            env.note_on_legato();
+        }
+    }
+
+    /// Set the voice's keyboard pan from the current note.
+    ///
+    /// The note's distance from middle C (60) is mapped to a pan position, reaching full
+    /// left/right four octaves away (notes 12 and 108), then scaled by `amount`. A positive
+    /// amount places low notes on the left and high notes on the right; negative flips it.
+    ///
+    /// Uses a balance law rather than equal-power panning: the near channel stays at unity
+    /// and only the far channel is attenuated, so `amount == 0.0` leaves the voice unchanged.
+    pub fn set_key_pan(&mut self, amount: f32) {
+        let offset = ((self.note as f32 - 60.0) / 48.0).clamp(-1.0, 1.0);
+        let pan = (offset * amount.clamp(-1.0, 1.0)).clamp(-1.0, 1.0);
+        self.key_pan_left_gain = (1.0 - pan).min(1.0);
+        self.key_pan_right_gain = (1.0 + pan).min(1.0);
+    }
+
+    /// Set the decimation filter length of every oscillator (see `Quality`).
+    pub fn set_downsampler_taps(&mut self, taps: usize) {
+        for osc in self.oscillators.iter_mut().flatten().flatten() {
+            osc.set_downsampler_taps(taps);
+        }
+    }
+
+    /// Set how the three filters combine (see `FilterTopology`).
+    ///
+    /// In serial mode every non-bypassed oscillator is summed into one signal that runs
+    /// through Filter 1 → Filter 2 → Filter 3; per-oscillator filter routing only decides
+    /// bypass. Parallel mode is the routed-bus behavior.
+    pub fn set_filter_topology(&mut self, topology: FilterTopology) {
+        self.filter_topology = topology;
+    }
+
+    /// Set how much velocity shortens the amp envelope attack and decay (0.0 to 1.0 each).
+    ///
+    /// Takes effect at the next note-on, like a struck instrument whose timing is set
+    /// by how hard it was hit.
+    pub fn set_velocity_envelope_sensitivity(&mut self, attack: f32, decay: f32) {
+        self.velocity_attack_sensitivity = attack.clamp(0.0, 1.0);
+        self.velocity_decay_sensitivity = decay.clamp(0.0, 1.0);
+    }
+
+    /// Set how much note-off velocity shortens the amp envelope release (0.0 to 1.0).
+    ///
+    /// Applies to releases started by `note_off_with_velocity()`.
+    pub fn set_velocity_release_sensitivity(&mut self, amount: f32) {
+        self.velocity_release_sensitivity = amount.clamp(0.0, 1.0);
+    }
+
+    /// Set the amp envelope attack, decay and release times for this note.
+    ///
+    /// Velocity shortens attack/decay and release velocity shortens release (see
+    /// `VelocityParams`), and key scaling shortens decay/release on notes above C4
+    /// (lengthening them below), halving per octave at 1.0.
+    fn apply_envelope_times(&mut self, envelope_params: &EnvelopeParams) {
+        let attack_scale =
+            1.0 - VELOCITY_TIME_MAX_REDUCTION * self.velocity_attack_sensitivity * self.velocity;
+        let velocity_decay_scale =
+            1.0 - VELOCITY_TIME_MAX_REDUCTION * self.velocity_decay_sensitivity * self.velocity;
+        let key_scale = if envelope_params.key_scaling > 0.0 {
+            let octaves_from_c4 = (self.note as f32 - 60.0) / 12.0;
+            2.0_f32.powf(-envelope_params.key_scaling * octaves_from_c4)
+        } else {
+            1.0
+        };
+        let decay_scale = velocity_decay_scale * key_scale;
+        let release_scale = (1.0
+            - VELOCITY_TIME_MAX_REDUCTION
+                * self.velocity_release_sensitivity
+                * self.release_velocity)
+            * key_scale;
+        let release = if self.killed {
+            KILL_FADE_SECONDS
+        } else {
+            envelope_params.release * release_scale
+        };
+
+        self.envelope
+            .set_attack(envelope_params.attack * attack_scale);
+        self.envelope.set_decay(envelope_params.decay * decay_scale);
+        self.envelope.set_release(release);
+        self.envelope_times_scaled =
+            attack_scale != 1.0 || decay_scale != 1.0 || release_scale != 1.0 || self.killed;
+    }
+
+    /// Set the analog drift amount (0.0 to 1.0).
+    ///
+    /// Drift slowly wanders each oscillator's pitch (up to ±`DRIFT_MAX_CENTS`) and the
+    /// filter cutoffs (up to ±`DRIFT_MAX_CUTOFF_OCTAVES`). At 0.0 the drift sources are not
+    /// run at all, so output is exactly the stable digital sound.
+    pub fn set_analog_drift(&mut self, amount: f32) {
+        self.analog_drift = amount.clamp(0.0, 1.0);
+    }
+
+    /// Set the global tuning offset in cents (e.g. -31.77 for A4 = 432 Hz).
+    ///
+    /// The offset scales the note's frequency before oscillator pitch, detune and LFO
+    /// modulation, so it stacks with them as a plain sum of cents. Takes effect on the
+    /// next `update_parameters()` call.
+    pub fn set_tuning_cents(&mut self, cents: f32) {
+        self.tuning_cents = cents.clamp(-100.0, 100.0);
+    }
+
+    /// Set the longest a note may be held before it releases on its own (0.0 = unlimited).
+    ///
+    /// A safety net for missed note-offs (flaky controllers, stuck MIDI): once a held note
+    /// reaches the limit, `process()` calls `note_off()` and the normal release plays.
+    pub fn set_max_note_seconds(&mut self, seconds: f32) {
+        self.max_held_samples = (seconds.max(0.0) * self.sample_rate) as u64;
+    }
+
+    /// Set the voice click: a highpassed noise burst at each note-on for attack "air".
+    ///
+    /// `enabled = false` or a zero level turns it off. Length and highpass take effect
+    /// from the next note.
+    pub fn set_click(&mut self, enabled: bool, level: f32, length_ms: f32, highpass_hz: f32) {
+        self.click_level = if enabled { level.clamp(0.0, 1.0) } else { 0.0 };
+        self.click.set_length_ms(length_ms);
+        self.click.set_highpass(highpass_hz);
+    }
+
+    /// Switch paraphonic mode on or off.
+    ///
+    /// A paraphonic voice outputs its oscillators unfiltered, at full level while the key
+    /// is held and fading out over the amp release afterwards. The engine runs the sum of
+    /// all voices through one shared filter and amp envelope.
+    pub fn set_paraphonic(&mut self, paraphonic: bool) {
+        self.paraphonic = paraphonic;
+    }
+
+    /// Restart the LFOs from phase zero (transport start, not note-on - see `note_on`)
+    pub fn reset_lfo_phases(&mut self) {
+        for lfo in &mut self.lfos {
+            lfo.reset();
+        }
+    }
+
+    /// Move one LFO to a position in its cycle (0.0-1.0), for timeline-locked sync
+    pub fn set_lfo_phase(&mut self, index: usize, phase: f32) {
+        if let Some(lfo) = self.lfos.get_mut(index) {
+            lfo.set_phase(phase);
+        }
+    }
+
+    /// Restart this voice's drift sources from a seed, for reproducible renders.
+    ///
+    /// The click noise is reseeded too, so the voices of a chord click with different noise.
+    pub fn seed_drift(&mut self, seed: u32) {
+        for (i, drift) in self.drift.iter_mut().enumerate() {
+            // Distinct, non-zero seed per source (golden-ratio increment)
+            drift.seed(seed.wrapping_add((i as u32 + 1).wrapping_mul(0x9E37_79B9)));
+        }
+        self.click.seed(!seed.wrapping_mul(0x9E37_79B9));
+    }
+
     /// Trigger a note-off event, starting this voice's release phase.
     ///
     /// This method does NOT immediately silence the voice. Instead, it triggers the ADSR
@@ -598,6 +940,34 @@ impl Voice {
         }
     }
 
+    /// Release the note with a note-off velocity (0.0 to 1.0).
+    ///
+    /// A fast key lift (high release velocity) shortens the amp release by the release
+    /// velocity sensitivity; with sensitivity 0.0 this is the same as `note_off()`.
+    pub fn note_off_with_velocity(&mut self, release_velocity: f32) {
+        self.release_velocity = release_velocity.clamp(0.0, 1.0);
+        if self.velocity_release_sensitivity > 0.0 && self.release_velocity > 0.0 {
+            let envelope_params = self.last_applied_envelope_params;
+            self.apply_envelope_times(&envelope_params);
+        }
+        self.note_off();
+    }
+
+    /// Force a fast fade-out (`KILL_FADE_SECONDS`) regardless of the release setting.
+    ///
+    /// Used by "All Notes Off" to silence everything almost at once without the click of
+    /// `reset()`, which stops the voice mid-waveform. The fade holds even if parameters
+    /// change during it; the next `note_on()` restores the normal release.
+    pub fn kill(&mut self) {
+        if !self.is_active {
+            return;
+        }
+        self.killed = true;
+        let envelope_params = self.last_applied_envelope_params;
+        self.apply_envelope_times(&envelope_params);
+        self.note_off();
+    }
+
     /// Update all oscillator, filter, and LFO parameters for this voice.
     ///
     /// This method is called by the engine when parameters change (via the GUI or DAW automation).
@@ -626,12 +996,16 @@ impl Voice {
     /// The final oscillator frequency is calculated as:
     /// ```ignore
     /// base_freq = 440 * 2^((note - 69) / 12)  // MIDI note to Hz
+    /// tuning_mult = 2^(tuning_cents / 1200)   // Global tuning offset in cents
     /// pitch_mult = 2^(pitch / 12)             // Pitch shift in semitones
     /// detune_mult = 2^(detune / 1200)         // Fine detune in cents
     /// unison_detune = 2^(spread * offset / 12) // Per-voice unison spread
-    /// final_freq = base_freq * pitch_mult * detune_mult * unison_detune
+    /// final_freq = base_freq * tuning_mult * pitch_mult * detune_mult * unison_detune
     /// ```
     ///
+    /// An oscillator with a `fixed_freq` replaces `base_freq * tuning_mult` with that
+    /// frequency, so it ignores the played note; pitch, detune and unison still apply.
+    ///
     /// # Why Not Update Filter Cutoff Here?
     ///
     /// We set the **base cutoff** here, but the actual cutoff is modulated per-sample in
@@ -650,10 +1024,12 @@ impl Voice {
         let lfo_params_changed = *lfo_params != self.last_applied_lfo_params;
         let envelope_params_changed = *envelope_params != self.last_applied_envelope_params;
         let note_changed = self.note != self.last_applied_note;
+        let tuning_changed = self.tuning_cents != self.last_applied_tuning_cents;
 
         // If note_on() reset buffers, we must re-apply oscillator state at least once,
         // even if note/params match the previous note.
-        let needs_osc_update = self.needs_dsp_reset_on_update || note_changed || osc_params_changed;
+        let needs_osc_update =
+            self.needs_dsp_reset_on_update || note_changed || tuning_changed || osc_params_changed;
         let needs_filter_update = filter_params_changed;
         let needs_lfo_update = lfo_params_changed;
         let needs_envelope_update = envelope_params_changed;
@@ -663,7 +1039,7 @@ impl Voice {
         }
 
         let base_freq = if needs_osc_update {
-            Self::midi_note_to_freq(self.note)
+            Self::midi_note_to_freq(self.note) * 2.0_f32.powf(self.tuning_cents / 1200.0)
         } else {
             0.0
         };
@@ -692,10 +1068,13 @@ impl Voice {
 
                 let target_unison = param.unison.clamp(1, MAX_UNISON_VOICES);
                 self.active_unison[i] = target_unison;
+                self.osc_gain_slew_coeff[i] =
+                    Self::slew_coeff_from_ms(param.gain_slew_ms, self.sample_rate);
 
                 let pitch_mult = 2.0_f32.powf(param.pitch / 12.0);
                 let detune_mult = 2.0_f32.powf(param.detune / 1200.0);
-                let base_osc_freq = base_freq * pitch_mult * detune_mult;
+                let base_osc_freq =
+                    param.fixed_freq.unwrap_or(base_freq) * pitch_mult * detune_mult;
                 self.osc_base_freq_hz[i] = base_osc_freq;
 
                 for unison_idx in 0..target_unison {
@@ -715,9 +1094,15 @@ impl Voice {
                         osc.set_frequency(freq);
                         osc.set_waveform(param.waveform);
                         osc.set_shape(param.shape);
+                        osc.set_fm_feedback(param.fm_feedback);
 
                         if param.waveform == crate::params::Waveform::Additive {
-                            osc.set_additive_harmonics(param.additive_harmonics);
+                            osc.set_additive_level(param.additive_normalize, param.additive_gain);
+                            osc.set_additive_harmonics(&param.additive_harmonic_amplitudes());
+                        }
+
+                        if param.waveform == crate::params::Waveform::Pluck {
+                            osc.set_pluck(param.pluck_damping, param.pluck_brightness);
                         }
 
                         if param.waveform == crate::params::Waveform::Wavetable {
@@ -741,6 +1126,13 @@ impl Voice {
                 self.filters[i].set_filter_type(filter_param.filter_type);
                 self.filters[i].set_resonance(filter_param.resonance);
                 self.filters[i].set_bandwidth(filter_param.bandwidth);
+                self.cutoff_slew_coeff[i] =
+                    Self::slew_coeff_from_ms(filter_param.cutoff_slew_ms, self.sample_rate);
+                self.filter_res_comp_gain[i] = if filter_param.res_comp {
+                    Self::resonance_compensation_gain(filter_param.resonance)
+                } else {
+                    1.0
+                };
 
                 self.filter_envelopes[i].set_attack(filter_param.envelope.attack);
                 self.filter_envelopes[i].set_decay(filter_param.envelope.decay);
@@ -751,6 +1143,8 @@ impl Voice {
             if needs_lfo_update {
                 self.lfos[i].set_rate(lfo_param.rate);
                 self.lfos[i].set_waveform(lfo_param.waveform);
+                self.lfos[i].set_pulse_width(lfo_param.pulse_width);
+                self.lfos[i].set_unipolar(lfo_param.unipolar);
             }
         }
 
@@ -775,10 +1169,8 @@ impl Voice {
         }
 
         if needs_envelope_update {
-            self.envelope.set_attack(envelope_params.attack);
-            self.envelope.set_decay(envelope_params.decay);
+            self.apply_envelope_times(envelope_params);
             self.envelope.set_sustain(envelope_params.sustain);
-            self.envelope.set_release(envelope_params.release);
             self.envelope.set_attack_curve(envelope_params.attack_curve);
             self.envelope.set_decay_curve(envelope_params.decay_curve);
             self.envelope
@@ -799,6 +1191,7 @@ impl Voice {
         }
         if needs_osc_update {
             self.last_applied_note = self.note;
+            self.last_applied_tuning_cents = self.tuning_cents;
         }
 
         if self.needs_dsp_reset_on_update {
@@ -806,6 +1199,55 @@ impl Voice {
         }
     }
 
+    /// Velocity crossfade gain for oscillator `index` (1.0 when the crossfade is off).
+    ///
+    /// Oscillator 1 fades out as velocity rises and oscillator 2 fades in, by `amount`;
+    /// oscillator 3 is unaffected. The velocity is the note-on velocity, so the balance
+    /// is set when the note starts.
+    fn velocity_crossfade_gain(index: usize, velocity: f32, amount: f32) -> f32 {
+        let amount = amount.clamp(0.0, 1.0);
+        match index {
+            0 => 1.0 - amount * velocity,
+            1 => 1.0 - amount * (1.0 - velocity),
+            _ => 1.0,
+        }
+    }
+
+    /// Compute the one-pole coefficient for a slew time constant (cutoff or oscillator gain).
+    ///
+    /// A slew of 0 ms (or less) disables smoothing and returns 0.0, so the smoothed value
+    /// jumps straight to the target.
+    fn slew_coeff_from_ms(slew_ms: f32, sample_rate: f32) -> f32 {
+        if slew_ms <= 0.0 {
+            0.0
+        } else {
+            (-1.0 / (slew_ms * 0.001 * sample_rate)).exp()
+        }
+    }
+
+    /// Output gain that offsets the level build-up of a resonant filter.
+    ///
+    /// Raising Q boosts a narrow band around the cutoff. On a harmonically rich source the
+    /// overall level creeps up by roughly 1 dB per doubling of Q (and the resonant band can
+    /// ring toward self-oscillation), so the compensation curve is
+    ///
+    /// ```text
+    /// gain = (0.707 / Q)^(1/6)   for Q > 0.707 (Butterworth)
+    /// gain = 1.0                 otherwise
+    /// ```
+    ///
+    /// i.e. -1 dB per doubling of Q: Q=2.8 → -2 dB, Q=11.3 → -4 dB, Q=50 → -6.2 dB. The
+    /// resonant peak itself still grows, so resonance stays audible, but the perceived level
+    /// stays close to the unresonant sound.
+    fn resonance_compensation_gain(resonance: f32) -> f32 {
+        const BUTTERWORTH_Q: f32 = std::f32::consts::FRAC_1_SQRT_2;
+        if resonance <= BUTTERWORTH_Q {
+            1.0
+        } else {
+            (BUTTERWORTH_Q / resonance).powf(1.0 / 6.0)
+        }
+    }
+
     /// Convert MIDI note number to frequency in Hz using equal temperament tuning.
     ///
     /// The standard MIDI-to-frequency formula is:
@@ -904,6 +1346,14 @@ impl Voice {
             return (0.0, 0.0);
         }
 
+        // Auto-release notes held past the maximum note length (missed note-off safety)
+        if self.max_held_samples > 0 && self.envelope.stage() != EnvelopeStage::Release {
+            self.held_samples += 1;
+            if self.held_samples >= self.max_held_samples {
+                self.note_off();
+            }
+        }
+
         // === STEP 3: Calculate velocity-sensitive amplitude ===
         // Standardized formula: output = 1.0 + sensitivity * (velocity - 0.5)
         // This maps velocity to amplitude scaling:
@@ -932,7 +1382,7 @@ impl Voice {
         let mut lfo_values = [0.0; 3];
         let mut filter_env_values = [0.0; 3];
         for i in 0..3 {
-            lfo_values[i] = self.lfos[i].process(); // Returns -1.0 to 1.0
+            lfo_values[i] = self.lfos[i].process(); // -1.0 to 1.0 (0.0 to 1.0 unipolar)
             filter_env_values[i] = self.filter_envelopes[i].process();
         }
 
@@ -989,6 +1439,21 @@ impl Voice {
             }
         }
 
+        // === STEP 4.6: Apply analog drift ===
+        // Slow random wander added on top of the LFO modulation: per-oscillator pitch in
+        // cents, and one shared cutoff offset (in octaves, converted to Hz per filter).
+        if self.analog_drift > 0.0 {
+            for (cents, drift) in pitch_mod_cents.iter_mut().zip(&mut self.drift[..3]) {
+                *cents += drift.process() * DRIFT_MAX_CENTS * self.analog_drift;
+            }
+            let cutoff_octaves =
+                self.drift[3].process() * DRIFT_MAX_CUTOFF_OCTAVES * self.analog_drift;
+            let cutoff_ratio = 2.0_f32.powf(cutoff_octaves) - 1.0;
+            for i in 0..3 {
+                filter_mod_hz[i] += filter_params[i].cutoff * cutoff_ratio;
+            }
+        }
+
         // === STEP 5: Generate all oscillator outputs (with feedback FM support) ===
         // We process oscillators in order: 0 → 1 → 2
         // Any oscillator can be modulated by any other oscillator, including "feedback"
@@ -1112,6 +1577,22 @@ impl Voice {
                 osc_out
             };
 
+            // === STEP 5b.2: Apply per-oscillator sample-rate reduction ===
+            // Sample-and-hold at the target rate before the filter, so only this layer
+            // picks up the lo-fi aliasing (the master bitcrusher crushes everything)
+            let target_rate = osc_params[i].sample_reduce;
+            let osc_out = if target_rate > 0.0 && target_rate < self.sample_rate {
+                let step = target_rate.max(100.0) / self.sample_rate;
+                self.sample_reduce_phase[i] += step;
+                if self.sample_reduce_phase[i] >= 1.0 {
+                    self.sample_reduce_phase[i] -= 1.0;
+                    self.sample_reduce_hold[i] = osc_out;
+                }
+                self.sample_reduce_hold[i]
+            } else {
+                osc_out
+            };
+
             // Store the raw oscillator output (needed for FM routing)
             osc_outputs[i] = osc_out;
 
@@ -1171,9 +1652,27 @@ impl Voice {
         // Store outputs for next sample (enables feedback FM)
         self.osc_outputs_prev = osc_outputs;
 
-        // === STEP 6: Apply filters and generate stereo output ===
+        // === STEP 6: Route oscillators to filters and generate stereo output ===
         // Now that all oscillators have been processed (and their outputs stored),
-        // we can apply filtering and panning to generate the final stereo mix.
+        // each one is sent to its routed filter's input bus (or straight to the output
+        // when bypassed). A bus sums its oscillators weighted by their gain, normalized
+        // back to unity so drive behaves as it does for a single oscillator, then applies
+        // the summed gain after filtering. With the default Paired routing each filter
+        // sees exactly one oscillator, which reproduces the original per-oscillator chain.
+        let mut bus_input = [0.0f32; 3]; // Gain-weighted oscillator sum
+        let mut bus_raw = [0.0f32; 3]; // Unweighted sum (used when all gains are zero)
+        let mut bus_gain = [0.0f32; 3];
+        let mut bus_left = [0.0f32; 3]; // Gain-weighted pan gains
+        let mut bus_right = [0.0f32; 3];
+        let mut bus_used = [false; 3];
+
+        if self.snap_osc_gain {
+            self.snap_osc_gain = false;
+            for (smoothed, param) in self.smoothed_osc_gain.iter_mut().zip(osc_params) {
+                *smoothed = param.gain;
+            }
+        }
+
         for i in 0..3 {
             // Skip if this oscillator is inactive
             if any_soloed && !osc_params[i].solo {
@@ -1192,92 +1691,7 @@ impl Voice {
                 osc_out *= gain_mult;
             }
 
-            // === STEP 6b: Process LFO for filter modulation ===
-            // LFOs generate slow-moving waveforms (typically <20 Hz) that modulate parameters.
-            // We already processed LFOs earlier; reuse the stored value.
-            // (Note: lfo_values[i] is available but filter_mod_hz[i] already contains the computed modulation)
-
-            // === STEP 6c: Calculate modulated filter cutoff ===
-            // The cutoff frequency is determined by multiple factors:
-            // 1. Base cutoff (set by user or automation)
-            // 2. Key tracking (higher notes → higher cutoff, follows keyboard)
-            // 3. Velocity sensitivity (harder key press → higher cutoff)
-            // 4. LFO modulation (time-varying cutoff for wah/vibrato effects)
-
-            let base_cutoff = filter_params[i].cutoff;
-
-            // **Key tracking**: Scale filter cutoff with MIDI note number
-            // If key_tracking = 1.0, the filter tracks the keyboard 1:1 (cutoff doubles per octave).
-            // If key_tracking = 0.0, the multiplier is 1.0 and this offset becomes 0.0.
-            let key_tracking_offset = base_cutoff * (self.filter_key_tracking_mul[i] - 1.0);
-
-            // **Velocity to filter cutoff**: Harder key press opens the filter more
-            // Uses the same standardized formula as amplitude (centered at velocity 0.5).
-            // At velocity 0.0: cutoff reduced, at velocity 1.0: cutoff raised.
-            let velocity_cutoff_offset =
-                base_cutoff * velocity_params.filter_sensitivity * (self.velocity - 0.5);
-
-            // **Combine all modulations and clamp to audible range [20 Hz, 20 kHz]**
-            // Use per-oscillator filter_mod_hz instead of per-LFO filter modulation
-            let modulated_cutoff = (base_cutoff
-                + key_tracking_offset
-                + velocity_cutoff_offset
-                + filter_mod_hz[i]
-                + filter_env_values[i] * filter_params[i].envelope.amount)
-                .clamp(20.0, 20000.0);
-
-            // === STEP 6d: Apply pre-filter drive (saturation) ===
-            // Pre-filter saturation adds warmth and presence by generating harmonics
-            // BEFORE filtering. This is key for "analog" sound and punch.
-            let driven_signal = if filter_params[i].drive > 0.001 {
-                // Map drive 0-1 to gain multiplier 1x-3x
-                let drive_gain = 1.0 + filter_params[i].drive * 2.0;
-                let amplified = osc_out * drive_gain;
-                // Soft saturation using tanh (tube-like warmth)
-                let saturated = amplified.tanh();
-                // Compensate for gain to maintain perceived level
-                saturated / drive_gain.sqrt()
-            } else {
-                osc_out
-            };
-
-            // === STEP 6e: Update filter and apply to driven signal ===
-            // During mono legato note changes, smooth cutoff briefly to avoid a small
-            // click from instantaneous coefficient updates (key tracking / velocity).
-            let cutoff_to_set = if self.mono_declick_samples_remaining > 0 {
-                let prev = self.mono_smoothed_cutoff_hz[i];
-                let coeff = self.mono_declick_cutoff_coeff;
-                let smoothed = if prev <= 0.0 {
-                    modulated_cutoff
-                } else {
-                    coeff * prev + (1.0 - coeff) * modulated_cutoff
-                };
-                self.mono_smoothed_cutoff_hz[i] = smoothed;
-                smoothed
-            } else {
-                self.mono_smoothed_cutoff_hz[i] = modulated_cutoff;
-                modulated_cutoff
-            };
-
-            self.filters[i].set_cutoff(cutoff_to_set);
-            let filtered = self.filters[i].process(driven_signal);
-
-            // === STEP 6e.1: Apply post-filter drive (saturation after filtering) ===
-            // Post-filter saturation adds harmonics to the filtered signal
-            // Creates different tonal character than pre-filter drive (presence & edge)
-            let post_filtered = if filter_params[i].post_drive > 0.001 {
-                // Map drive 0-1 to gain multiplier 1x-3x (same range as pre-filter drive)
-                let drive_gain = 1.0 + filter_params[i].post_drive * 2.0;
-                let amplified = filtered * drive_gain;
-                // Soft saturation using tanh
-                let saturated = amplified.tanh();
-                // Gain compensation (perceptual loudness)
-                saturated / drive_gain.sqrt()
-            } else {
-                filtered
-            };
-
-            // === STEP 6f: Apply LFO pan modulation and stereo panning ===
+            // === STEP 6b: Apply LFO pan modulation and stereo panning ===
             // Pan: -1.0 (full left) to 1.0 (full right), 0.0 = center
             //
             // Apply LFO pan modulation first (per-oscillator)
@@ -1298,10 +1712,97 @@ impl Voice {
                 (self.cached_pan_left_gain[i], self.cached_pan_right_gain[i])
             };
 
-            // Apply gain and panning, then accumulate into output channels
-            let scaled = post_filtered * osc_params[i].gain;
-            output_left += scaled * left_gain;
-            output_right += scaled * right_gain;
+            // === STEP 6c: Send to the routed filter bus, or bypass filtering ===
+            // Gain slew: one-pole smoothing so mutes and stepped automation don't click
+            let coeff = self.osc_gain_slew_coeff[i];
+            self.smoothed_osc_gain[i] =
+                coeff * self.smoothed_osc_gain[i] + (1.0 - coeff) * osc_params[i].gain;
+            let gain = self.smoothed_osc_gain[i]
+                * Self::velocity_crossfade_gain(i, self.velocity, velocity_params.osc_crossfade);
+            let filter_index = if self.paraphonic {
+                None // The engine's shared filter takes over
+            } else {
+                osc_params[i].filter_routing.filter_index(i)
+            };
+            match filter_index {
+                Some(f) => {
+                    bus_input[f] += osc_out * gain;
+                    bus_raw[f] += osc_out;
+                    bus_gain[f] += gain;
+                    bus_left[f] += gain * left_gain;
+                    bus_right[f] += gain * right_gain;
+                    bus_used[f] = true;
+                }
+                None => {
+                    let scaled = osc_out * gain;
+                    output_left += scaled * left_gain;
+                    output_right += scaled * right_gain;
+                }
+            }
+        }
+
+        // === STEP 6d-6f: Filter stages (see process_filter_stage) ===
+        if self.filter_topology == FilterTopology::Serial {
+            // Serial: merge every bus into one signal and run it through all three filters
+            if bus_used.iter().any(|&used| used) {
+                let total_gain: f32 = bus_gain.iter().sum();
+                let (mut signal, left_gain, right_gain) = if total_gain > 1e-6 {
+                    (
+                        bus_input.iter().sum::<f32>() / total_gain,
+                        bus_left.iter().sum::<f32>() / total_gain,
+                        bus_right.iter().sum::<f32>() / total_gain,
+                    )
+                } else {
+                    (bus_raw.iter().sum(), 0.0, 0.0)
+                };
+
+                for i in 0..3 {
+                    signal = self.process_filter_stage(
+                        i,
+                        signal,
+                        &filter_params[i],
+                        velocity_params,
+                        filter_mod_hz[i],
+                        filter_env_values[i],
+                    );
+                }
+
+                let scaled = signal * total_gain;
+                output_left += scaled * left_gain;
+                output_right += scaled * right_gain;
+            }
+        } else {
+            for i in 0..3 {
+                // Skip filters with no oscillator routed to them
+                if !bus_used[i] {
+                    continue;
+                }
+
+                // Gain-weighted mix at unity level; the summed gain is applied after filtering
+                let (bus_signal, left_gain, right_gain) = if bus_gain[i] > 1e-6 {
+                    (
+                        bus_input[i] / bus_gain[i],
+                        bus_left[i] / bus_gain[i],
+                        bus_right[i] / bus_gain[i],
+                    )
+                } else {
+                    (bus_raw[i], 0.0, 0.0)
+                };
+
+                let post_filtered = self.process_filter_stage(
+                    i,
+                    bus_signal,
+                    &filter_params[i],
+                    velocity_params,
+                    filter_mod_hz[i],
+                    filter_env_values[i],
+                );
+
+                // Apply summed gain and bus panning, then accumulate into output channels
+                let scaled = post_filtered * bus_gain[i];
+                output_left += scaled * left_gain;
+                output_right += scaled * right_gain;
+            }
         }
 
         // === STEP 7: Normalize for multiple active oscillators ===
@@ -1351,8 +1852,22 @@ impl Voice {
         // === STEP 8: Apply envelope and velocity-sensitive amplitude ===
         // Multiply the final mixed output by the envelope (0.0-1.0) and velocity factor.
         // This shapes the amplitude over time (ADSR) and scales by key velocity.
-        output_left = output_left * env_value * velocity_factor;
-        output_right = output_right * env_value * velocity_factor;
+        // In paraphonic mode the envelope is only a key gate: full level while held, then
+        // its release curve (scaled back to 1.0) fades the note out.
+        let amplitude = if !self.paraphonic {
+            env_value
+        } else if self.envelope.stage() == EnvelopeStage::Release {
+            let start = self.envelope.release_start_level();
+            if start > 0.0 {
+                (env_value / start).min(1.0)
+            } else {
+                0.0
+            }
+        } else {
+            1.0
+        };
+        output_left = output_left * amplitude * velocity_factor;
+        output_right = output_right * amplitude * velocity_factor;
 
         // === STEP 9: Track peak amplitude for voice stealing ===
         // The engine uses peak amplitude to identify the quietest voice when all 16 voices
@@ -1398,8 +1913,6 @@ impl Voice {
         // Emphasizes attack transients and reduces sustain for punchier sounds
         // Works by multiplying gain during specific envelope stages
         if transient_params.enabled {
-            use crate::dsp::modulation::envelope::EnvelopeStage;
-
             let gain_mult = match self.envelope.stage() {
                 EnvelopeStage::Attack => {
                     // Boost transients during attack (0.0-1.0 → 1x to 2x gain)
@@ -1416,6 +1929,10 @@ impl Voice {
             output_right *= gain_mult;
         }
 
+        // === STEP 10.2: Apply keyboard panning (per-voice balance set at note-on) ===
+        output_left *= self.key_pan_left_gain;
+        output_right *= self.key_pan_right_gain;
+
         // === STEP 11: Apply anti-click fade-in (FINAL STAGE) ===
         // Apply a 5ms exponential fade-in at the start of every note.
         // This MUST be the very last processing step to ensure nothing can bypass it.
@@ -1434,6 +1951,15 @@ impl Voice {
             self.anti_click_samples += 1;
         }
 
+        // === STEP 11.1: Voice click (noise burst at note-on) ===
+        // Mixed after the anti-click fade: the burst is the intended transient, and it
+        // starts at full level on purpose. Velocity scales it like the rest of the note.
+        if self.click.is_active() {
+            let click = self.click.process() * self.click_level * velocity_factor;
+            output_left += click * self.key_pan_left_gain;
+            output_right += click * self.key_pan_right_gain;
+        }
+
         // Very short crossfade on retrigger to avoid a step from release tail → restarted fade.
         if self.retrigger_xfade_samples_remaining > 0 {
             let total = self.retrigger_xfade_total_samples.max(1) as f32;
@@ -1459,6 +1985,124 @@ impl Voice {
         (output_left, output_right)
     }
 
+    /// Run one filter slot: cutoff modulation, pre-drive, filter, post-drive.
+    ///
+    /// Shared by the parallel and serial topologies. `filter_mod_hz` and
+    /// `filter_env_value` are this slot's LFO and filter-envelope modulation.
+    #[inline]
+    fn process_filter_stage(
+        &mut self,
+        i: usize,
+        input: f32,
+        filter_params: &FilterParams,
+        velocity_params: &VelocityParams,
+        filter_mod_hz: f32,
+        filter_env_value: f32,
+    ) -> f32 {
+        // === STEP 6d: Calculate modulated filter cutoff ===
+        // The cutoff frequency is determined by multiple factors:
+        // 1. Base cutoff (set by user or automation)
+        // 2. Key tracking (higher notes → higher cutoff, follows keyboard)
+        // 3. Velocity sensitivity (harder key press → higher cutoff)
+        // 4. LFO modulation (time-varying cutoff for wah/vibrato effects)
+
+        // **Cutoff slew**: one-pole smoothing of the base cutoff so stepped automation
+        // doesn't reach the biquad as discrete jumps. A fresh note snaps to the target.
+        let target_cutoff = filter_params.cutoff;
+        let prev_cutoff = self.smoothed_base_cutoff_hz[i];
+        let base_cutoff = if prev_cutoff <= 0.0 {
+            target_cutoff
+        } else {
+            let coeff = self.cutoff_slew_coeff[i];
+            coeff * prev_cutoff + (1.0 - coeff) * target_cutoff
+        };
+        self.smoothed_base_cutoff_hz[i] = base_cutoff;
+
+        // **Key tracking**: Scale filter cutoff with MIDI note number
+        // If key_tracking = 1.0, the filter tracks the keyboard 1:1 (cutoff doubles per octave).
+        // If key_tracking = 0.0, the multiplier is 1.0 and this offset becomes 0.0.
+        let key_tracking_offset = base_cutoff * (self.filter_key_tracking_mul[i] - 1.0);
+
+        // **Velocity to filter cutoff**: Harder key press opens the filter more
+        // Uses the same standardized formula as amplitude (centered at velocity 0.5).
+        // At velocity 0.0: cutoff reduced, at velocity 1.0: cutoff raised.
+        let velocity_cutoff_offset =
+            base_cutoff * velocity_params.filter_sensitivity * (self.velocity - 0.5);
+
+        // **Combine all modulations and clamp to audible range [20 Hz, 20 kHz]**
+        // (LFO filter modulation was computed per slot earlier in process())
+        let modulated_cutoff = (base_cutoff
+            + key_tracking_offset
+            + velocity_cutoff_offset
+            + filter_mod_hz
+            + filter_env_value * filter_params.envelope.amount)
+            .clamp(20.0, 20000.0);
+
+        // === STEP 6e: Apply pre-filter drive (saturation) ===
+        // Pre-filter saturation adds warmth and presence by generating harmonics
+        // BEFORE filtering. This is key for "analog" sound and punch.
+        // With drive_position = Post, Drive moves after the filter instead (STEP 6f.1).
+        let drive_pre = filter_params.drive_position == DrivePosition::Pre;
+        let driven_signal = if drive_pre {
+            Self::saturate(input, filter_params.drive)
+        } else {
+            input
+        };
+
+        // === STEP 6f: Update filter and apply to driven signal ===
+        // During mono legato note changes, smooth cutoff briefly to avoid a small
+        // click from instantaneous coefficient updates (key tracking / velocity).
+        let cutoff_to_set = if self.mono_declick_samples_remaining > 0 {
+            let prev = self.mono_smoothed_cutoff_hz[i];
+            let coeff = self.mono_declick_cutoff_coeff;
+            let smoothed = if prev <= 0.0 {
+                modulated_cutoff
+            } else {
+                coeff * prev + (1.0 - coeff) * modulated_cutoff
+            };
+            self.mono_smoothed_cutoff_hz[i] = smoothed;
+            smoothed
+        } else {
+            self.mono_smoothed_cutoff_hz[i] = modulated_cutoff;
+            modulated_cutoff
+        };
+
+        self.filters[i].set_cutoff(cutoff_to_set);
+        let filtered = self.filters[i].process(driven_signal) * self.filter_res_comp_gain[i];
+
+        // === STEP 6f.1: Apply post-filter drive (saturation after filtering) ===
+        // Post-filter saturation adds harmonics to the filtered signal
+        // Creates different tonal character than pre-filter drive (presence & edge)
+        let filtered = if drive_pre {
+            filtered
+        } else {
+            Self::saturate(filtered, filter_params.drive)
+        };
+        Self::saturate(filtered, filter_params.post_drive)
+    }
+
+    /// Soft tanh saturation for the filter drive stages
+    ///
+    /// Maps drive 0-1 to a 1x-3x gain into tanh (tube-like warmth), then compensates
+    /// by the square root of the gain to keep perceived level. Drive at or near zero
+    /// passes the signal through untouched.
+    #[inline]
+    fn saturate(signal: f32, drive: f32) -> f32 {
+        if drive > 0.001 {
+            let drive_gain = 1.0 + drive * 2.0;
+            (signal * drive_gain).tanh() / drive_gain.sqrt()
+        } else {
+            signal
+        }
+    }
+
+    /// Cutoff (Hz) filter `index` is currently running at, after key tracking, velocity,
+    /// LFO, envelope and drift modulation. `None` before the filter has processed a sample.
+    pub fn effective_cutoff(&self, index: usize) -> Option<f32> {
+        let cutoff = *self.mono_smoothed_cutoff_hz.get(index)?;
+        (cutoff > 0.0).then_some(cutoff)
+    }
+
     /// Get current amplitude level for voice stealing decisions.
     ///
     /// This method returns the **peak amplitude** seen since the last `note_on()` call.
@@ -1513,6 +2157,15 @@ impl Voice {
         self.is_active
     }
 
+    /// Get the current stage of the amplitude envelope.
+    ///
+    /// Lets the engine (and tests) distinguish held notes from notes in their release tail.
+    #[inline]
+    #[must_use]
+    pub fn envelope_stage(&self) -> EnvelopeStage {
+        self.envelope.stage()
+    }
+
     /// Get the MIDI note number currently assigned to this voice.
     ///
     /// Returns the note number (0-127) set by the most recent `note_on()` call.
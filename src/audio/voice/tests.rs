@@ -147,6 +147,111 @@ fn test_midi_note_to_freq() {
     assert_relative_eq!(Voice::midi_note_to_freq(81), 880.0, epsilon = 0.01);
 }
 
+/// Test that a +100 cent tuning offset plays exactly one semitone up, on top of the
+/// oscillator's own pitch offset.
+#[test]
+fn test_tuning_cents_offsets_frequency() {
+    let mut osc_params = default_osc_params();
+    osc_params[0].pitch = 12.0;
+
+    let base_freq = |note: u8, tuning_cents: f32| {
+        let mut voice = Voice::new(44100.0);
+        voice.set_tuning_cents(tuning_cents);
+        voice.note_on(note, 1.0);
+        voice.update_parameters(
+            &osc_params,
+            &default_filter_params(),
+            &default_lfo_params(),
+            &default_envelope_params(),
+            &default_wavetable_library(),
+        );
+        voice.osc_base_freq_hz[0]
+    };
+
+    assert_relative_eq!(base_freq(60, 100.0), base_freq(61, 0.0), epsilon = 0.01);
+    assert_relative_eq!(base_freq(69, 0.0), 880.0, epsilon = 0.01);
+}
+
+/// Test that a fixed-frequency oscillator ignores the played note while the others track it.
+#[test]
+fn test_fixed_freq_oscillator_ignores_note() {
+    let mut osc_params = default_osc_params();
+    osc_params[0].fixed_freq = Some(100.0);
+
+    let base_freqs = |note: u8| {
+        let mut voice = Voice::new(44100.0);
+        voice.note_on(note, 1.0);
+        voice.update_parameters(
+            &osc_params,
+            &default_filter_params(),
+            &default_lfo_params(),
+            &default_envelope_params(),
+            &default_wavetable_library(),
+        );
+        voice.osc_base_freq_hz
+    };
+
+    let (low, high) = (base_freqs(48), base_freqs(72));
+    assert_relative_eq!(low[0], 100.0, epsilon = 0.01);
+    assert_relative_eq!(high[0], 100.0, epsilon = 0.01);
+    assert_relative_eq!(high[1], low[1] * 4.0, epsilon = 0.01);
+}
+
+/// Test that per-oscillator sample-rate reduction folds a sine into aliasing images.
+///
+/// An 880 Hz sine held at 4 kHz gains an image at 4000 - 880 = 3120 Hz; at full rate
+/// that frequency stays empty. Measured with a Goertzel filter on the unfiltered voice.
+#[test]
+fn test_sample_reduce_creates_aliasing_images() {
+    use crate::params::FilterRouting;
+    use std::f32::consts::PI;
+
+    let image_level = |sample_reduce: f32| {
+        let mut voice = Voice::new(44100.0);
+        let mut osc_params = default_osc_params();
+        osc_params[0].gain = 0.5;
+        osc_params[0].filter_routing = FilterRouting::Bypass;
+        osc_params[0].sample_reduce = sample_reduce;
+        let filter_params = default_filter_params();
+        let lfo_params = default_lfo_params();
+        voice.note_on(81, 1.0); // 880 Hz
+        voice.update_parameters(
+            &osc_params,
+            &filter_params,
+            &lfo_params,
+            &default_envelope_params(),
+            &default_wavetable_library(),
+        );
+
+        let coeff = 2.0 * (2.0 * PI * 3120.0 / 44100.0).cos();
+        let (mut s1, mut s2) = (0.0f32, 0.0f32);
+        for n in 0..44100 {
+            let (left, _) = voice.process(
+                &osc_params,
+                &filter_params,
+                &lfo_params,
+                &default_velocity_params(),
+                false,
+                &default_voice_comp_params(),
+                &default_transient_params(),
+            );
+            // Skip the attack so only the steady tone is measured
+            let x = if n < 4410 { 0.0 } else { left };
+            let s0 = x + coeff * s1 - s2;
+            s2 = s1;
+            s1 = s0;
+        }
+        (s1 * s1 + s2 * s2 - coeff * s1 * s2).sqrt()
+    };
+
+    let full_rate = image_level(0.0);
+    let reduced = image_level(4000.0);
+    assert!(
+        reduced > full_rate * 20.0,
+        "Holding at 4 kHz should create a 3120 Hz image: {reduced} vs {full_rate}"
+    );
+}
+
 /// Test that an active voice produces non-zero audio output.
 ///
 /// Verifies:
@@ -270,6 +375,52 @@ fn test_voice_stops_after_release() {
     );
 }
 
+/// Test that a held note auto-releases once it reaches the maximum note length, with no
+/// note-off, and that the limit counts from the note-on.
+#[test]
+fn test_voice_auto_releases_after_max_note_length() {
+    let mut voice = Voice::new(44100.0);
+    let osc_params = default_osc_params();
+    let filter_params = default_filter_params();
+    let lfo_params = default_lfo_params();
+    let velocity_params = default_velocity_params();
+
+    voice.set_max_note_seconds(0.1); // 4410 samples
+    voice.note_on(60, 0.8);
+    voice.update_parameters(
+        &osc_params,
+        &filter_params,
+        &lfo_params,
+        &default_envelope_params(),
+        &default_wavetable_library(),
+    );
+
+    let process = |voice: &mut Voice, samples: usize| {
+        for _ in 0..samples {
+            let _ = voice.process(
+                &osc_params,
+                &filter_params,
+                &lfo_params,
+                &velocity_params,
+                false,
+                &default_voice_comp_params(),
+                &default_transient_params(),
+            );
+        }
+    };
+
+    process(&mut voice, 4000);
+    assert_ne!(voice.envelope_stage(), EnvelopeStage::Release);
+
+    process(&mut voice, 500);
+    assert_eq!(
+        voice.envelope_stage(),
+        EnvelopeStage::Release,
+        "note should release itself after 0.1 s"
+    );
+    assert!(voice.is_active(), "the release tail still plays");
+}
+
 /// Test that an inactive voice produces (0.0, 0.0) output.
 ///
 /// Verifies:
@@ -1097,3 +1248,717 @@ fn test_lfo_destination_mixed_routing() {
         .any(|(l, r)| l.abs() > 0.01 || r.abs() > 0.01);
     assert!(has_output, "Mixed LFO routing should produce audio");
 }
+
+/// Test that a step change in filter cutoff is slewed rather than applied instantly.
+///
+/// Verifies:
+/// - The first sample after the step does not jump to the new cutoff
+/// - Every per-sample cutoff delta is bounded by the one-pole slew coefficient
+/// - The smoothed cutoff converges on the target within a few time constants
+///
+/// Cutoff is the most automated parameter; without slew, 32-sample parameter
+/// updates reach the biquad as discrete jumps and produce zipper noise.
+#[test]
+fn test_cutoff_slew_smooths_step_change() {
+    let sample_rate = 44100.0;
+    let mut voice = Voice::new(sample_rate);
+    let mut osc_params = default_osc_params();
+    osc_params[0].gain = 0.5;
+    let mut filter_params = default_filter_params();
+    filter_params[0].cutoff = 200.0;
+    filter_params[0].cutoff_slew_ms = 5.0;
+    let lfo_params = default_lfo_params();
+    let envelope_params = default_envelope_params();
+    let velocity_params = default_velocity_params();
+    let wavetable_library = default_wavetable_library();
+
+    voice.note_on(60, 0.5);
+    voice.update_parameters(
+        &osc_params,
+        &filter_params,
+        &lfo_params,
+        &envelope_params,
+        &wavetable_library,
+    );
+
+    for _ in 0..256 {
+        voice.process(
+            &osc_params,
+            &filter_params,
+            &lfo_params,
+            &velocity_params,
+            false,
+            &default_voice_comp_params(),
+            &default_transient_params(),
+        );
+    }
+    assert_relative_eq!(voice.smoothed_base_cutoff_hz[0], 200.0, epsilon = 0.01);
+
+    // Step the cutoff up by almost 10 kHz.
+    filter_params[0].cutoff = 10000.0;
+    voice.update_parameters(
+        &osc_params,
+        &filter_params,
+        &lfo_params,
+        &envelope_params,
+        &wavetable_library,
+    );
+
+    let coeff = (-1.0 / (0.005 * sample_rate)).exp();
+    let max_delta = (1.0 - coeff) * (10000.0 - 200.0) + 0.01;
+    let mut prev = voice.smoothed_base_cutoff_hz[0];
+    for _ in 0..(0.05 * sample_rate) as usize {
+        voice.process(
+            &osc_params,
+            &filter_params,
+            &lfo_params,
+            &velocity_params,
+            false,
+            &default_voice_comp_params(),
+            &default_transient_params(),
+        );
+        let current = voice.smoothed_base_cutoff_hz[0];
+        assert!(
+            (current - prev).abs() <= max_delta,
+            "Cutoff jumped {} Hz in one sample (max {})",
+            current - prev,
+            max_delta
+        );
+        prev = current;
+    }
+
+    assert!(
+        (prev - 10000.0).abs() < 10.0,
+        "Cutoff should settle on target after 10 time constants, got {}",
+        prev
+    );
+}
+
+/// Test that resonance compensation keeps the output level consistent across Q values.
+/// Verifies:
+/// - Without compensation, level climbs noticeably as Q rises
+/// - With compensation, the level spread across Q is much smaller (within 2 dB)
+/// - At the default Butterworth Q the compensation is a no-op
+#[test]
+fn test_resonance_compensation_level_consistency() {
+    fn render_rms(resonance: f32, res_comp: bool) -> f32 {
+        let mut voice = Voice::new(44100.0);
+        let mut osc_params = default_osc_params();
+        osc_params[0].waveform = Waveform::Saw;
+        osc_params[0].gain = 0.5;
+        let mut filter_params = default_filter_params();
+        filter_params[0].cutoff = 1000.0;
+        filter_params[0].resonance = resonance;
+        filter_params[0].res_comp = res_comp;
+        let lfo_params = default_lfo_params();
+        let velocity_params = default_velocity_params();
+
+        voice.note_on(48, 1.0);
+        voice.update_parameters(
+            &osc_params,
+            &filter_params,
+            &lfo_params,
+            &default_envelope_params(),
+            &default_wavetable_library(),
+        );
+
+        let mut sum_sq = 0.0;
+        let samples = 8820;
+        for n in 0..(samples + 4410) {
+            let (left, right) = voice.process(
+                &osc_params,
+                &filter_params,
+                &lfo_params,
+                &velocity_params,
+                false,
+                &default_voice_comp_params(),
+                &default_transient_params(),
+            );
+            // Skip the attack so only the steady state is measured
+            if n >= 4410 {
+                sum_sq += left * left + right * right;
+            }
+        }
+        (sum_sq / (2.0 * samples as f32)).sqrt()
+    }
+
+    let resonances = [0.707, 2.0, 5.0, 10.0, 20.0];
+    let spread_db = |res_comp: bool| {
+        let levels: Vec<f32> = resonances
+            .iter()
+            .map(|&q| render_rms(q, res_comp))
+            .collect();
+        let max = levels.iter().cloned().fold(f32::MIN, f32::max);
+        let min = levels.iter().cloned().fold(f32::MAX, f32::min);
+        20.0 * (max / min).log10()
+    };
+
+    let raw_spread = spread_db(false);
+    let comp_spread = spread_db(true);
+    assert!(
+        comp_spread < raw_spread,
+        "Compensation should reduce the level spread: {comp_spread:.2} dB vs {raw_spread:.2} dB"
+    );
+    assert!(
+        comp_spread < 2.0,
+        "Compensated level should stay within 2 dB across Q, got {comp_spread:.2} dB"
+    );
+    assert_relative_eq!(render_rms(0.707, true), render_rms(0.707, false));
+}
+
+/// Test oscillator → filter routing: a bypassed oscillator ignores every filter, and a
+/// re-routed oscillator follows its target filter instead of its paired one.
+#[test]
+fn test_filter_routing_bypass_and_reroute() {
+    use crate::params::FilterRouting;
+
+    fn render(routing: FilterRouting, filter1_cutoff: f32, filter2_cutoff: f32) -> Vec<f32> {
+        let mut voice = Voice::new(44100.0);
+        let mut osc_params = default_osc_params();
+        osc_params[0].waveform = Waveform::Saw;
+        osc_params[0].gain = 0.5;
+        osc_params[0].filter_routing = routing;
+        let mut filter_params = default_filter_params();
+        filter_params[0].cutoff = filter1_cutoff;
+        filter_params[1].cutoff = filter2_cutoff;
+        let lfo_params = default_lfo_params();
+        let velocity_params = default_velocity_params();
+
+        voice.note_on(60, 1.0);
+        voice.update_parameters(
+            &osc_params,
+            &filter_params,
+            &lfo_params,
+            &default_envelope_params(),
+            &default_wavetable_library(),
+        );
+
+        (0..4410)
+            .map(|_| {
+                voice
+                    .process(
+                        &osc_params,
+                        &filter_params,
+                        &lfo_params,
+                        &velocity_params,
+                        false,
+                        &default_voice_comp_params(),
+                        &default_transient_params(),
+                    )
+                    .0
+            })
+            .collect()
+    }
+
+    let energy = |samples: &[f32]| samples.iter().map(|s| s * s).sum::<f32>();
+    let max_diff = |a: &[f32], b: &[f32]| {
+        a.iter()
+            .zip(b)
+            .map(|(x, y)| (x - y).abs())
+            .fold(0.0f32, f32::max)
+    };
+
+    // Bypass: filter cutoffs make no difference
+    let bypass_dark = render(FilterRouting::Bypass, 100.0, 100.0);
+    let bypass_bright = render(FilterRouting::Bypass, 20000.0, 20000.0);
+    assert!(
+        max_diff(&bypass_dark, &bypass_bright) < 1e-6,
+        "Bypassed oscillator should ignore filter cutoff"
+    );
+
+    // Paired (default): filter 1's cutoff darkens the oscillator
+    let paired_dark = render(FilterRouting::Paired, 100.0, 20000.0);
+    assert!(energy(&paired_dark) < energy(&bypass_dark) * 0.5);
+
+    // Routed to filter 2: filter 1's cutoff no longer matters, filter 2's does
+    let routed_open = render(FilterRouting::Filter2, 100.0, 20000.0);
+    let routed_closed = render(FilterRouting::Filter2, 20000.0, 100.0);
+    assert!(energy(&routed_closed) < energy(&routed_open) * 0.5);
+    assert!(max_diff(&routed_closed, &paired_dark) < 1e-6);
+}
+
+/// Test that serial filter topology cascades the filters: with identical lowpass
+/// cutoffs, serial attenuates high frequencies much more steeply than parallel.
+#[test]
+fn test_serial_topology_steeper_rolloff() {
+    use crate::params::FilterTopology;
+
+    fn high_band_ratio(topology: FilterTopology) -> f32 {
+        // Sine two octaves above the shared cutoff: 12 dB/oct per lowpass stage
+        let render = |cutoff: f32| {
+            let mut voice = Voice::new(44100.0);
+            voice.set_filter_topology(topology);
+            let mut osc_params = default_osc_params();
+            osc_params[0].gain = 0.5;
+            let mut filter_params = default_filter_params();
+            for filter in &mut filter_params {
+                filter.cutoff = cutoff;
+            }
+            let lfo_params = default_lfo_params();
+            let velocity_params = default_velocity_params();
+
+            voice.note_on(84, 1.0); // ~1047 Hz
+            voice.update_parameters(
+                &osc_params,
+                &filter_params,
+                &lfo_params,
+                &default_envelope_params(),
+                &default_wavetable_library(),
+            );
+
+            let mut sum_sq = 0.0;
+            for n in 0..8820 {
+                let (left, _) = voice.process(
+                    &osc_params,
+                    &filter_params,
+                    &lfo_params,
+                    &velocity_params,
+                    false,
+                    &default_voice_comp_params(),
+                    &default_transient_params(),
+                );
+                if n >= 4410 {
+                    sum_sq += left * left;
+                }
+            }
+            sum_sq
+        };
+        render(262.0) / render(20000.0)
+    }
+
+    let parallel = high_band_ratio(FilterTopology::Parallel);
+    let serial = high_band_ratio(FilterTopology::Serial);
+    assert!(
+        serial < parallel * 0.01,
+        "Serial should roll off more steeply: serial {serial:.4}, parallel {parallel:.4}"
+    );
+}
+
+/// Test that velocity → attack makes hard hits reach the envelope peak sooner.
+#[test]
+fn test_velocity_shortens_attack() {
+    fn samples_to_peak(velocity: f32) -> usize {
+        let mut voice = Voice::new(44100.0);
+        voice.set_velocity_envelope_sensitivity(1.0, 0.0);
+        let osc_params = default_osc_params();
+        let filter_params = default_filter_params();
+        let lfo_params = default_lfo_params();
+        let envelope_params = EnvelopeParams {
+            attack: 0.2,
+            ..default_envelope_params()
+        };
+
+        voice.note_on(60, velocity);
+        voice.update_parameters(
+            &osc_params,
+            &filter_params,
+            &lfo_params,
+            &envelope_params,
+            &default_wavetable_library(),
+        );
+
+        let mut samples = 0;
+        while voice.envelope_stage() == EnvelopeStage::Attack && samples < 44100 {
+            voice.process(
+                &osc_params,
+                &filter_params,
+                &lfo_params,
+                &default_velocity_params(),
+                false,
+                &default_voice_comp_params(),
+                &default_transient_params(),
+            );
+            samples += 1;
+        }
+        samples
+    }
+
+    let hard = samples_to_peak(1.0);
+    let soft = samples_to_peak(0.2);
+    assert!(
+        hard * 3 < soft,
+        "Hard hits should attack much faster: {hard} vs {soft} samples"
+    );
+}
+
+/// Test that release velocity shortens the release, and is ignored at zero sensitivity.
+#[test]
+fn test_release_velocity_shortens_release() {
+    fn release_samples(sensitivity: f32, release_velocity: f32) -> usize {
+        let mut voice = Voice::new(44100.0);
+        voice.set_velocity_release_sensitivity(sensitivity);
+        let osc_params = default_osc_params();
+        let filter_params = default_filter_params();
+        let lfo_params = default_lfo_params();
+        let envelope_params = EnvelopeParams {
+            attack: 0.001,
+            release: 0.5,
+            ..default_envelope_params()
+        };
+        let process = |voice: &mut Voice| {
+            voice.process(
+                &osc_params,
+                &filter_params,
+                &lfo_params,
+                &default_velocity_params(),
+                false,
+                &default_voice_comp_params(),
+                &default_transient_params(),
+            );
+        };
+
+        voice.note_on(60, 0.8);
+        voice.update_parameters(
+            &osc_params,
+            &filter_params,
+            &lfo_params,
+            &envelope_params,
+            &default_wavetable_library(),
+        );
+        for _ in 0..2048 {
+            process(&mut voice);
+        }
+
+        voice.note_off_with_velocity(release_velocity);
+        let mut samples = 0;
+        while voice.is_active() && samples < 88200 {
+            process(&mut voice);
+            samples += 1;
+        }
+        samples
+    }
+
+    let fast_lift = release_samples(1.0, 1.0);
+    let slow_lift = release_samples(1.0, 0.1);
+    assert!(
+        fast_lift * 3 < slow_lift,
+        "A fast key lift should release much sooner: {fast_lift} vs {slow_lift} samples"
+    );
+
+    let ignored = release_samples(0.0, 1.0);
+    let plain = release_samples(0.0, 0.0);
+    assert_eq!(ignored, plain, "Zero sensitivity ignores release velocity");
+}
+
+/// Test that the velocity crossfade favors oscillator 1 on soft hits and 2 on hard hits.
+///
+/// Oscillator 1 plays 440 Hz and oscillator 2 an octave up; each one's level is read with
+/// a Goertzel filter at its frequency.
+#[test]
+fn test_velocity_crossfade_between_oscillators() {
+    use crate::params::FilterRouting;
+    use std::f32::consts::PI;
+
+    fn levels(velocity: f32) -> (f32, f32) {
+        let mut voice = Voice::new(44100.0);
+        let mut osc_params = default_osc_params();
+        for osc in &mut osc_params[..2] {
+            osc.gain = 0.5;
+            osc.filter_routing = FilterRouting::Bypass;
+        }
+        osc_params[1].pitch = 12.0;
+        let filter_params = default_filter_params();
+        let lfo_params = default_lfo_params();
+        let velocity_params = VelocityParams {
+            osc_crossfade: 1.0,
+            ..default_velocity_params()
+        };
+
+        voice.note_on(69, velocity);
+        voice.update_parameters(
+            &osc_params,
+            &filter_params,
+            &lfo_params,
+            &default_envelope_params(),
+            &default_wavetable_library(),
+        );
+
+        let output: Vec<f32> = (0..8820)
+            .map(|_| {
+                voice
+                    .process(
+                        &osc_params,
+                        &filter_params,
+                        &lfo_params,
+                        &velocity_params,
+                        false,
+                        &default_voice_comp_params(),
+                        &default_transient_params(),
+                    )
+                    .0
+            })
+            .collect();
+        let magnitude = |freq: f32| {
+            let coeff = 2.0 * (2.0 * PI * freq / 44100.0).cos();
+            let (mut s1, mut s2) = (0.0f32, 0.0f32);
+            for &x in &output[4410..] {
+                let s0 = x + coeff * s1 - s2;
+                s2 = s1;
+                s1 = s0;
+            }
+            (s1 * s1 + s2 * s2 - coeff * s1 * s2).sqrt()
+        };
+        (magnitude(440.0), magnitude(880.0))
+    }
+
+    let (soft_osc1, soft_osc2) = levels(0.1);
+    assert!(
+        soft_osc1 > soft_osc2 * 4.0,
+        "Oscillator 1 should dominate soft hits: {soft_osc1} vs {soft_osc2}"
+    );
+    let (hard_osc1, hard_osc2) = levels(1.0);
+    assert!(
+        hard_osc2 > hard_osc1 * 4.0,
+        "Oscillator 2 should dominate hard hits: {hard_osc2} vs {hard_osc1}"
+    );
+}
+
+/// Test that envelope key scaling shortens decay on high notes.
+#[test]
+fn test_envelope_key_scaling_shortens_high_notes() {
+    fn decay_samples(note: u8, key_scaling: f32) -> usize {
+        let mut voice = Voice::new(44100.0);
+        let osc_params = default_osc_params();
+        let filter_params = default_filter_params();
+        let lfo_params = default_lfo_params();
+        let envelope_params = EnvelopeParams {
+            decay: 0.2,
+            sustain: 0.5,
+            key_scaling,
+            ..default_envelope_params()
+        };
+
+        voice.note_on(note, 0.8);
+        voice.update_parameters(
+            &osc_params,
+            &filter_params,
+            &lfo_params,
+            &envelope_params,
+            &default_wavetable_library(),
+        );
+
+        let mut samples = 0;
+        for _ in 0..88200 {
+            voice.process(
+                &osc_params,
+                &filter_params,
+                &lfo_params,
+                &default_velocity_params(),
+                false,
+                &default_voice_comp_params(),
+                &default_transient_params(),
+            );
+            match voice.envelope_stage() {
+                EnvelopeStage::Decay => samples += 1,
+                EnvelopeStage::Sustain => break,
+                _ => {}
+            }
+        }
+        samples
+    }
+
+    // Without scaling every note decays the same
+    assert_eq!(decay_samples(40, 0.0), decay_samples(90, 0.0));
+
+    let low = decay_samples(40, 1.0);
+    let high = decay_samples(90, 1.0);
+    assert!(
+        high * 10 < low,
+        "High notes should decay much faster: note 40 {low}, note 90 {high} samples"
+    );
+}
+
+#[test]
+fn test_osc_gain_step_is_smoothed() {
+    let mut voice = Voice::new(44100.0);
+    let mut osc_params = default_osc_params();
+    osc_params[0].gain = 0.7;
+    let filter_params = default_filter_params();
+    let lfo_params = default_lfo_params();
+
+    // Largest sample-to-sample change and largest level over `samples`
+    let render = |voice: &mut Voice, osc_params: &[OscillatorParams; 3], samples| {
+        let mut previous: Option<f32> = None;
+        let (mut max_delta, mut peak) = (0.0_f32, 0.0_f32);
+        for _ in 0..samples {
+            let (left, _) = voice.process(
+                osc_params,
+                &filter_params,
+                &lfo_params,
+                &default_velocity_params(),
+                false,
+                &default_voice_comp_params(),
+                &default_transient_params(),
+            );
+            if let Some(previous) = previous {
+                max_delta = max_delta.max((left - previous).abs());
+            }
+            previous = Some(left);
+            peak = peak.max(left.abs());
+        }
+        (max_delta, peak)
+    };
+
+    voice.note_on(60, 0.8);
+    voice.update_parameters(
+        &osc_params,
+        &filter_params,
+        &lfo_params,
+        &default_envelope_params(),
+        &default_wavetable_library(),
+    );
+    render(&mut voice, &osc_params, 4410);
+    let (steady_delta, steady_peak) = render(&mut voice, &osc_params, 441);
+    assert!(steady_peak > 0.01, "The note should be sounding");
+
+    // Mute oscillator 1 in one step
+    osc_params[0].gain = 0.0;
+    voice.update_parameters(
+        &osc_params,
+        &filter_params,
+        &lfo_params,
+        &default_envelope_params(),
+        &default_wavetable_library(),
+    );
+    let (step_delta, _) = render(&mut voice, &osc_params, 441);
+    assert!(
+        step_delta <= steady_delta * 1.1,
+        "Muting moved the output by {} in one sample; the waveform's own slope is {}",
+        step_delta,
+        steady_delta
+    );
+
+    // The slew still arrives: oscillator 1 is the only one sounding (the others default
+    // to gain 0), so the voice settles to silence once the 5 ms slew has run its course
+    render(&mut voice, &osc_params, 2205);
+    let (_, tail_peak) = render(&mut voice, &osc_params, 441);
+    assert!(tail_peak < steady_peak * 1e-3, "Tail level {}", tail_peak);
+}
+
+/// Test that the voice click is a burst at the very start of the note only.
+///
+/// With every oscillator silent, anything the voice outputs is the click: it should be
+/// audible in the first millisecond and gone once its length has passed.
+#[test]
+fn test_voice_click_only_at_note_start() {
+    let mut voice = Voice::new(44100.0);
+    let mut osc_params = default_osc_params();
+    for osc in &mut osc_params {
+        osc.gain = 0.0;
+    }
+    let filter_params = default_filter_params();
+    let lfo_params = default_lfo_params();
+    voice.update_parameters(
+        &osc_params,
+        &filter_params,
+        &lfo_params,
+        &default_envelope_params(),
+        &default_wavetable_library(),
+    );
+
+    let render = |voice: &mut Voice| -> Vec<f32> {
+        (0..4410)
+            .map(|_| {
+                let (left, right) = voice.process(
+                    &osc_params,
+                    &filter_params,
+                    &lfo_params,
+                    &default_velocity_params(),
+                    false,
+                    &default_voice_comp_params(),
+                    &default_transient_params(),
+                );
+                left.abs().max(right.abs())
+            })
+            .collect()
+    };
+
+    // 5 ms click = 220 samples
+    voice.set_click(true, 1.0, 5.0, 2000.0);
+    voice.note_on(60, 1.0);
+    let levels = render(&mut voice);
+    let first_ms = levels[..44].iter().cloned().fold(0.0, f32::max);
+    let after_click = levels[240..].iter().cloned().fold(0.0, f32::max);
+    assert!(
+        first_ms > 0.05,
+        "Click should be audible at the onset: {}",
+        first_ms
+    );
+    assert!(
+        after_click < 1e-6,
+        "Nothing after the burst: {}",
+        after_click
+    );
+
+    // Disabled: the silent note stays silent
+    voice.set_click(false, 1.0, 5.0, 2000.0);
+    voice.note_off();
+    voice.note_on(60, 1.0);
+    let levels = render(&mut voice);
+    assert!(levels.iter().all(|&level| level < 1e-6));
+}
+
+/// Test that drive position changes the harmonic content of a driven filter.
+/// Verifies:
+/// - Pre drive: harmonics added by the saturation are rolled off by the lowpass
+/// - Post drive: harmonics are added after the lowpass and pass through untouched
+#[test]
+fn test_drive_position_pre_vs_post_harmonics() {
+    // Level of the 3rd and 5th harmonics relative to the fundamental
+    fn harmonic_ratio(position: DrivePosition) -> f32 {
+        let mut voice = Voice::new(44100.0);
+        let mut osc_params = default_osc_params();
+        osc_params[0].gain = 0.8;
+        let mut filter_params = default_filter_params();
+        filter_params[0].cutoff = 150.0;
+        filter_params[0].drive = 1.0;
+        filter_params[0].drive_position = position;
+        let lfo_params = default_lfo_params();
+
+        // A2 = 110 Hz: 8820 samples hold exactly 22 cycles
+        voice.note_on(45, 1.0);
+        voice.update_parameters(
+            &osc_params,
+            &filter_params,
+            &lfo_params,
+            &default_envelope_params(),
+            &default_wavetable_library(),
+        );
+
+        let samples: Vec<f32> = (0..(4410 + 8820))
+            .map(|_| {
+                voice
+                    .process(
+                        &osc_params,
+                        &filter_params,
+                        &lfo_params,
+                        &default_velocity_params(),
+                        false,
+                        &default_voice_comp_params(),
+                        &default_transient_params(),
+                    )
+                    .0
+            })
+            .skip(4410)
+            .collect();
+
+        let magnitude = |freq: f32| {
+            let (mut re, mut im) = (0.0_f32, 0.0_f32);
+            for (n, sample) in samples.iter().enumerate() {
+                let phase = 2.0 * std::f32::consts::PI * freq * n as f32 / 44100.0;
+                re += sample * phase.cos();
+                im -= sample * phase.sin();
+            }
+            (re * re + im * im).sqrt()
+        };
+
+        (magnitude(330.0) + magnitude(550.0)) / magnitude(110.0)
+    }
+
+    let pre = harmonic_ratio(DrivePosition::Pre);
+    let post = harmonic_ratio(DrivePosition::Post);
+    assert!(
+        post > pre * 2.0,
+        "Post drive should keep far more upper harmonics: pre {pre:.4}, post {post:.4}"
+    );
+}
@@ -1,6 +1,7 @@
 // Analysis Components - Pitch detection, formant analysis, and signal classification
 
 pub mod formant_detector;
+pub mod mono_compatibility;
 pub mod pitch_detector;
 pub mod pitch_quantizer;
 pub mod sibilance_detector;
@@ -9,6 +10,7 @@ pub mod transient_detector;
 pub mod zcr_detector;
 
 pub use formant_detector::{FormantDetector, VowelEstimate};
+pub use mono_compatibility::{MonoBandReport, MonoCompatibilityReport};
 pub use pitch_detector::{PitchDetectionResult, PitchDetector, PITCH_BUFFER_SIZE};
 pub use pitch_quantizer::{PitchQuantizer, RootNote, ScaleType};
 pub use sibilance_detector::SibilanceDetector;
@@ -0,0 +1,202 @@
+/// Mono Compatibility Analysis - How much a stereo signal loses when summed to mono
+///
+/// Wide stereo (Haas delays, chorus, detuned unison spread across L/R) can sound great in
+/// stereo but partially cancel when a phone, club PA or broadcast chain sums it to mono.
+/// This compares each frequency band's mono-sum power with its stereo power:
+///
+/// - **0 dB**: L and R are identical (perfectly mono compatible)
+/// - **-3 dB**: L and R are uncorrelated (normal for wide but healthy material)
+/// - **below -3 dB**: L and R are partly out of phase - the band audibly thins out in mono
+///
+/// Unlike the real-time detectors in this module, this analyses a whole recorded buffer at
+/// once, so run it off the audio thread.
+use crate::dsp::filters::crossovers::MultibandCrossover;
+
+/// Loss (dB) beyond which a band is flagged: worse than uncorrelated L/R
+pub const MONO_LOSS_THRESHOLD_DB: f32 = -3.0;
+
+/// Floor for reported losses (a fully cancelling band reads this instead of -inf)
+const MIN_LOSS_DB: f32 = -60.0;
+
+/// Names of the analysed bands, matching `MultibandCrossover`'s split points
+pub const MONO_BAND_NAMES: [&str; 4] = ["Bass", "Mid", "Presence", "Air"];
+
+/// Mono-sum result for one frequency band
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MonoBandReport {
+    pub name: &'static str,
+    /// Mono-sum power relative to stereo power in dB (0 = no loss)
+    pub loss_db: f32,
+    /// L/R correlation (-1.0 = out of phase, 0.0 = unrelated, 1.0 = identical)
+    pub correlation: f32,
+}
+
+impl MonoBandReport {
+    /// Whether this band loses more than an uncorrelated signal would in mono
+    pub fn is_problematic(&self) -> bool {
+        self.loss_db < MONO_LOSS_THRESHOLD_DB
+    }
+}
+
+/// Mono compatibility of a stereo buffer, per band and overall
+#[derive(Debug, Clone, PartialEq)]
+pub struct MonoCompatibilityReport {
+    /// Bass (<200 Hz), mid (200 Hz-1 kHz), presence (1-8 kHz) and air (>8 kHz)
+    pub bands: [MonoBandReport; 4],
+    /// Full-band mono-sum loss in dB
+    pub overall_loss_db: f32,
+    /// Full-band L/R correlation
+    pub correlation: f32,
+    /// Enabled settings that widen the image, listed when any band is problematic
+    pub flagged_settings: Vec<&'static str>,
+}
+
+impl MonoCompatibilityReport {
+    /// Analyse a stereo buffer (the shorter channel's length is used)
+    pub fn analyze(left: &[f32], right: &[f32], sample_rate: f32) -> Self {
+        let mut left_bands = MultibandCrossover::new(sample_rate);
+        let mut right_bands = MultibandCrossover::new(sample_rate);
+
+        // Per band (then full band last): sum of L², R², L·R
+        let mut sums = [[0.0_f64; 3]; 5];
+        for (&l, &r) in left.iter().zip(right) {
+            let (lb, lm, lp, la) = left_bands.process(l);
+            let (rb, rm, rp, ra) = right_bands.process(r);
+            for (sum, (l, r)) in
+                sums.iter_mut()
+                    .zip([(lb, rb), (lm, rm), (lp, rp), (la, ra), (l, r)])
+            {
+                let (l, r) = (l as f64, r as f64);
+                sum[0] += l * l;
+                sum[1] += r * r;
+                sum[2] += l * r;
+            }
+        }
+
+        let bands = std::array::from_fn(|i| {
+            let (loss_db, correlation) = loss_and_correlation(sums[i]);
+            MonoBandReport {
+                name: MONO_BAND_NAMES[i],
+                loss_db,
+                correlation,
+            }
+        });
+        let (overall_loss_db, correlation) = loss_and_correlation(sums[4]);
+
+        Self {
+            bands,
+            overall_loss_db,
+            correlation,
+            flagged_settings: Vec::new(),
+        }
+    }
+
+    /// Whether any band thins out in mono more than an uncorrelated signal would
+    pub fn is_problematic(&self) -> bool {
+        self.bands.iter().any(MonoBandReport::is_problematic)
+    }
+
+    /// One-line readout: overall loss, then the bands that thin out and the likely causes
+    pub fn summary(&self) -> String {
+        let mut text = format!("Mono sum {:+.1} dB", self.overall_loss_db);
+        let thin: Vec<String> = self
+            .bands
+            .iter()
+            .filter(|band| band.is_problematic())
+            .map(|band| format!("{} {:+.1} dB", band.name, band.loss_db))
+            .collect();
+        if thin.is_empty() {
+            text.push_str(", mono compatible");
+        } else {
+            text.push_str(&format!(", thins out in mono: {}", thin.join(", ")));
+            if !self.flagged_settings.is_empty() {
+                text.push_str(&format!(" (check {})", self.flagged_settings.join(", ")));
+            }
+        }
+        text
+    }
+}
+
+/// Mono-sum loss (dB) and correlation from summed L², R² and L·R
+///
+/// Stereo power is (L² + R²) / 2 and mono power is ((L + R) / 2)², so identical channels
+/// lose nothing and uncorrelated ones lose 3 dB. Silence reports no loss.
+fn loss_and_correlation([ll, rr, lr]: [f64; 3]) -> (f32, f32) {
+    let stereo_power = (ll + rr) / 2.0;
+    if stereo_power <= 1e-12 {
+        return (0.0, 1.0);
+    }
+    let mono_power = (ll + rr + 2.0 * lr) / 4.0;
+    let loss_db = if mono_power > 0.0 {
+        (10.0 * (mono_power / stereo_power).log10()).max(MIN_LOSS_DB as f64)
+    } else {
+        MIN_LOSS_DB as f64
+    };
+    let correlation = if ll > 0.0 && rr > 0.0 {
+        (lr / (ll * rr).sqrt()).clamp(-1.0, 1.0)
+    } else {
+        0.0
+    };
+    (loss_db as f32, correlation as f32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::PI;
+
+    fn sine(freq: f32, phase: f32, len: usize) -> Vec<f32> {
+        (0..len)
+            .map(|i| (2.0 * PI * freq * i as f32 / 44100.0 + phase).sin() * 0.5)
+            .collect()
+    }
+
+    #[test]
+    fn test_identical_channels_lose_nothing() {
+        let signal = sine(440.0, 0.0, 44100);
+        let report = MonoCompatibilityReport::analyze(&signal, &signal, 44100.0);
+
+        assert!(report.overall_loss_db.abs() < 0.01);
+        assert!(report.correlation > 0.99);
+        assert!(!report.is_problematic());
+    }
+
+    #[test]
+    fn test_inverted_channels_cancel() {
+        let left = sine(440.0, 0.0, 44100);
+        let right: Vec<f32> = left.iter().map(|s| -s).collect();
+        let report = MonoCompatibilityReport::analyze(&left, &right, 44100.0);
+
+        assert_eq!(report.overall_loss_db, MIN_LOSS_DB);
+        assert!(report.correlation < -0.99);
+        // The mid band (where 440 Hz sits) is the one flagged
+        assert!(report.bands[1].is_problematic());
+        assert!(report.is_problematic());
+    }
+
+    #[test]
+    fn test_quadrature_channels_lose_three_db() {
+        let left = sine(440.0, 0.0, 44100);
+        let right = sine(440.0, PI / 2.0, 44100);
+        let report = MonoCompatibilityReport::analyze(&left, &right, 44100.0);
+
+        assert!((report.overall_loss_db - MONO_LOSS_THRESHOLD_DB).abs() < 0.1);
+        assert!(report.correlation.abs() < 0.01);
+    }
+
+    #[test]
+    fn test_summary_names_thin_bands_and_settings() {
+        let left = sine(440.0, 0.0, 44100);
+        let right: Vec<f32> = left.iter().map(|s| -s).collect();
+        let mut report = MonoCompatibilityReport::analyze(&left, &right, 44100.0);
+        report.flagged_settings = vec!["Stereo Widener"];
+
+        let summary = report.summary();
+        assert!(summary.starts_with("Mono sum -60.0 dB"));
+        assert!(summary.contains("Mid -"));
+        assert!(summary.contains("(check Stereo Widener)"));
+
+        let healthy = MonoCompatibilityReport::analyze(&left, &left, 44100.0);
+        assert!(healthy.summary().ends_with("mono compatible"));
+    }
+}
@@ -89,6 +89,16 @@ impl PitchQuantizer {
         self.root_note = root_note;
     }
 
+    /// Current scale type
+    pub fn scale_type(&self) -> ScaleType {
+        self.scale_type
+    }
+
+    /// Current root note
+    pub fn root_note(&self) -> RootNote {
+        self.root_note
+    }
+
     /// Set retune speed (0.0 = instant/robotic, 1.0 = very slow/natural)
     /// Typical values:
     /// - 0.0: T-Pain/Cher effect (instant snap)
@@ -163,30 +173,30 @@ impl PitchQuantizer {
         // Adjust for root note
         let adjusted_note = (note_in_octave - self.root_note.0 as f32 + 12.0) % 12.0;
 
-        // Find nearest scale degree
-        let mut nearest_interval = intervals[0] as f32;
-        let mut min_distance = (adjusted_note - nearest_interval).abs();
-
-        for &interval in intervals.iter() {
-            let distance = (adjusted_note - interval as f32).abs();
-            if distance < min_distance {
-                min_distance = distance;
-                nearest_interval = interval as f32;
-            }
-
-            // Also check wrapped distance (e.g., 11 semitones vs. -1 semitone)
-            let wrapped_distance = (adjusted_note - (interval as f32 + 12.0)).abs();
-            if wrapped_distance < min_distance {
-                min_distance = wrapped_distance;
-                nearest_interval = interval as f32 + 12.0;
-            }
-        }
-
-        // Convert back to absolute MIDI note
-        let quantized_note_in_octave = (nearest_interval + self.root_note.0 as f32) % 12.0;
-        let quantized_midi_note = octave * 12.0 + quantized_note_in_octave;
+        // Find nearest scale degree, also checking each degree an octave up (e.g.,
+        // 11 semitones vs. -1 semitone); equidistant degrees resolve to the lower one
+        let nearest_interval = intervals
+            .iter()
+            .flat_map(|&interval| [interval as f32, interval as f32 + 12.0])
+            .min_by(|a, b| {
+                let distance_a = (adjusted_note - a).abs();
+                let distance_b = (adjusted_note - b).abs();
+                distance_a.total_cmp(&distance_b).then(a.total_cmp(b))
+            })
+            .unwrap_or(adjusted_note);
+
+        // Convert back to absolute MIDI note (moving by the same offset keeps the octave
+        // right when the nearest degree wraps past the root)
+        raw_midi_note + (nearest_interval - adjusted_note)
+    }
 
-        quantized_midi_note
+    /// Snap a MIDI note to the nearest note of the scale (equidistant notes resolve downward)
+    ///
+    /// Used for scale lock on incoming notes; ignores the retune speed and correction amount.
+    pub fn quantize_note(&self, note: u8) -> u8 {
+        self.quantize_to_scale(note as f32)
+            .round()
+            .clamp(0.0, 127.0) as u8
     }
 
     /// Convert frequency (Hz) to MIDI note number (A4 = 440Hz = MIDI 69)
@@ -276,6 +286,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_quantize_note_keeps_octave_across_wrap() {
+        let mut quantizer = PitchQuantizer::new(44100.0);
+        quantizer.set_scale_type(ScaleType::Pentatonic);
+        quantizer.set_root_note(RootNote::C);
+
+        // B4 is nearer C5 (wrapping past the root) than A4
+        assert_eq!(quantizer.quantize_note(71), 72);
+        assert_eq!(quantizer.quantize_note(69), 69);
+        // C#4 sits between C4 and D4: ties resolve downward
+        assert_eq!(quantizer.quantize_note(61), 60);
+    }
+
     #[test]
     fn test_correction_amount_blending() {
         let mut quantizer = PitchQuantizer::new(44100.0);
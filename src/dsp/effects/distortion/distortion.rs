@@ -15,6 +15,12 @@
 /// - **mix**: Wet/dry balance (0.0 = dry, 1.0 = full wet)
 /// - **algorithm**: Choice of distortion curve (tanh, soft clip, hard clip, cubic)
 ///
+/// # Oversampling (optional)
+/// `set_oversampling(true)` runs the waveshaper at 2× rate: a midpoint is linearly
+/// interpolated between samples, both points are shaped, and the pair is averaged back
+/// down. The averaging attenuates harmonics folding back from above Nyquist at the cost
+/// of a second waveshaper call and a half-sample delay on the wet path.
+///
 /// # Distortion Types
 /// - **Tanh**: Smooth, tube-like saturation (most musical)
 /// - **Soft Clip**: Gentle compression then hard limit
@@ -27,6 +33,9 @@
 /// - **Diode**: Models diode clipper circuit (guitar pedal style)
 use std::f32::consts::PI;
 
+/// Time constant of the per-sample mix smoothing (keeps automated mix changes click-free)
+const MIX_SMOOTH_MS: f32 = 10.0;
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum DistortionType {
     Tanh,
@@ -53,6 +62,18 @@ pub struct Distortion {
     drive: f32,
     mix: f32,
     dist_type: DistortionType,
+
+    // Mix actually applied, following `mix` per sample
+    current_mix: f32,
+    mix_smooth_coeff: f32,
+    // Set once audio has been processed since construction or `clear`; until then mix
+    // changes apply immediately (there's no output yet to click against)
+    mix_active: bool,
+
+    // 2× oversampling (previous input per channel for midpoint interpolation)
+    oversample: bool,
+    prev_input_l: f32,
+    prev_input_r: f32,
 }
 
 impl Distortion {
@@ -76,9 +97,20 @@ impl Distortion {
             drive: 0.0,
             mix: 0.5,
             dist_type: DistortionType::Tanh,
+            current_mix: 0.5,
+            mix_active: false,
+            mix_smooth_coeff: 1.0 - (-1.0 / (MIX_SMOOTH_MS * 0.001 * sample_rate)).exp(),
+            oversample: false,
+            prev_input_l: 0.0,
+            prev_input_r: 0.0,
         }
     }
 
+    /// Enable 2× oversampled waveshaping (reduces aliasing, doubles shaping cost)
+    pub fn set_oversampling(&mut self, enabled: bool) {
+        self.oversample = enabled;
+    }
+
     /// Set drive amount (0.0 to 1.0)
     /// Maps to 1x to 100x gain internally
     pub fn set_drive(&mut self, drive: f32) {
@@ -86,8 +118,14 @@ impl Distortion {
     }
 
     /// Set wet/dry mix (0.0 = dry, 1.0 = full wet)
+    ///
+    /// The change is smoothed over ~`MIX_SMOOTH_MS` while processing, so stepped
+    /// (throttled) automation doesn't click.
     pub fn set_mix(&mut self, mix: f32) {
         self.mix = mix.clamp(0.0, 1.0);
+        if !self.mix_active {
+            self.current_mix = self.mix;
+        }
     }
 
     /// Set distortion type
@@ -95,6 +133,27 @@ impl Distortion {
         self.dist_type = dist_type;
     }
 
+    /// Advance the mix smoothing by one sample and return the mix to apply
+    #[inline]
+    fn next_mix(&mut self) -> f32 {
+        self.mix_active = true;
+        self.current_mix += (self.mix - self.current_mix) * self.mix_smooth_coeff;
+        self.current_mix
+    }
+
+    /// Waveshape `input`, at 2× rate when oversampling is enabled.
+    ///
+    /// `prev` is the channel's previous input, used to interpolate the midpoint sample.
+    #[inline]
+    fn shape(&self, prev: f32, input: f32, gain: f32) -> f32 {
+        if self.oversample {
+            let midpoint = 0.5 * (prev + input);
+            0.5 * (self.apply_distortion(midpoint, gain) + self.apply_distortion(input, gain))
+        } else {
+            self.apply_distortion(input, gain)
+        }
+    }
+
     /// Apply waveshaping based on selected algorithm
     fn apply_distortion(&self, input: f32, gain: f32) -> f32 {
         let x = input * gain;
@@ -218,7 +277,8 @@ impl Distortion {
         let gain = 1.0 + self.drive * 49.0;
 
         // Apply distortion
-        let distorted = self.apply_distortion(input, gain);
+        let distorted = self.shape(self.prev_input_l, input, gain);
+        self.prev_input_l = input;
 
         // Less aggressive compensation to preserve distortion character
         let compensated = distorted / (1.0 + self.drive * 0.3);
@@ -232,7 +292,8 @@ impl Distortion {
         );
 
         // Mix wet and dry
-        input * (1.0 - self.mix) + blocked * self.mix
+        let mix = self.next_mix();
+        input * (1.0 - mix) + blocked * mix
     }
 
     /// Process a stereo sample pair with independent L/R DC blocking
@@ -251,7 +312,8 @@ impl Distortion {
         let gain = 1.0 + self.drive * 49.0;
 
         // Process left channel
-        let distorted_l = self.apply_distortion(input_l, gain);
+        let distorted_l = self.shape(self.prev_input_l, input_l, gain);
+        self.prev_input_l = input_l;
         let compensated_l = distorted_l / (1.0 + self.drive * 0.3);
         let blocked_l = Self::dc_block(
             self.dc_block_coeff,
@@ -259,10 +321,12 @@ impl Distortion {
             &mut self.dc_block_x1_l,
             &mut self.dc_block_y1_l,
         );
-        let out_l = input_l * (1.0 - self.mix) + blocked_l * self.mix;
+        let mix = self.next_mix();
+        let out_l = input_l * (1.0 - mix) + blocked_l * mix;
 
         // Process right channel
-        let distorted_r = self.apply_distortion(input_r, gain);
+        let distorted_r = self.shape(self.prev_input_r, input_r, gain);
+        self.prev_input_r = input_r;
         let compensated_r = distorted_r / (1.0 + self.drive * 0.3);
         let blocked_r = Self::dc_block(
             self.dc_block_coeff,
@@ -270,7 +334,7 @@ impl Distortion {
             &mut self.dc_block_x1_r,
             &mut self.dc_block_y1_r,
         );
-        let out_r = input_r * (1.0 - self.mix) + blocked_r * self.mix;
+        let out_r = input_r * (1.0 - mix) + blocked_r * mix;
 
         (out_l, out_r)
     }
@@ -281,6 +345,10 @@ impl Distortion {
         self.dc_block_y1_l = 0.0;
         self.dc_block_x1_r = 0.0;
         self.dc_block_y1_r = 0.0;
+        self.prev_input_l = 0.0;
+        self.prev_input_r = 0.0;
+        self.current_mix = self.mix;
+        self.mix_active = false;
     }
 }
 
@@ -585,4 +653,65 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_oversampling_reduces_aliasing() {
+        // Hard-clipped 9 kHz sine: the 5th harmonic (45 kHz) folds back to 900 Hz
+        let sample_rate = 44100.0;
+        let alias_level = |oversample: bool| {
+            let mut dist = Distortion::new(sample_rate);
+            dist.set_type(DistortionType::HardClip);
+            dist.set_drive(1.0);
+            dist.set_mix(1.0);
+            dist.set_oversampling(oversample);
+
+            // Goertzel magnitude at the alias frequency
+            let coeff = 2.0 * (2.0 * PI * 900.0 / sample_rate).cos();
+            let (mut s1, mut s2) = (0.0f32, 0.0f32);
+            for n in 0..44100 {
+                let x = 0.5 * (2.0 * PI * 9000.0 * n as f32 / sample_rate).sin();
+                let (y, _) = dist.process_stereo(x, x);
+                let s0 = y + coeff * s1 - s2;
+                s2 = s1;
+                s1 = s0;
+            }
+            (s1 * s1 + s2 * s2 - coeff * s1 * s2).sqrt()
+        };
+
+        let plain = alias_level(false);
+        let oversampled = alias_level(true);
+        assert!(
+            oversampled < plain * 0.5,
+            "2x oversampling should reduce the 900 Hz alias: {oversampled} vs {plain}"
+        );
+    }
+
+    #[test]
+    fn test_mix_automation_is_smoothed() {
+        // Largest sample-to-sample step of a driven 220 Hz sine, with `mix_at(n)` per sample
+        fn max_step(mix_at: impl Fn(usize) -> f32) -> f32 {
+            let mut dist = Distortion::new(44100.0);
+            dist.set_drive(0.2);
+            let mut prev = 0.0;
+            let mut max_step: f32 = 0.0;
+            for n in 0..44100 {
+                dist.set_mix(mix_at(n));
+                let input = 0.5 * (2.0 * PI * 220.0 * n as f32 / 44100.0).sin();
+                let output = dist.process(input);
+                if n > 0 {
+                    max_step = max_step.max((output - prev).abs());
+                }
+                prev = output;
+            }
+            max_step
+        }
+
+        let steady = max_step(|_| 0.0).max(max_step(|_| 1.0));
+        // Throttled automation: the mix jumps fully wet/dry every 64 samples
+        let automated = max_step(|n| if (n / 64) % 2 == 0 { 1.0 } else { 0.0 });
+        assert!(
+            automated < steady * 1.5,
+            "Mix jumps should be smoothed: max step {automated} vs steady {steady}"
+        );
+    }
 }
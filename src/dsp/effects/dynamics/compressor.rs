@@ -1,6 +1,9 @@
 //! Dynamic range compressor - reduces dynamic range by attenuating loud signals
 //! Uses envelope follower with attack/release for transparent dynamics control
 
+/// Longest lookahead the compressor buffers, in milliseconds
+pub const MAX_LOOKAHEAD_MS: f32 = 10.0;
+
 pub struct Compressor {
     /// Sample rate for time calculations
     sample_rate: f32,
@@ -40,6 +43,19 @@ pub struct Compressor {
 
     /// Cached envelope value for mono compression
     envelope_mono: f32,
+
+    /// Apply the louder channel's gain to both (true) or compress each channel on its own
+    stereo_link: bool,
+
+    /// Delay line for the audio path, so the detector sees peaks before they're output
+    /// (sized for `MAX_LOOKAHEAD_MS`)
+    lookahead_buffer: Vec<(f32, f32)>,
+
+    /// Lookahead delay in samples (0 = none)
+    lookahead_samples: usize,
+
+    /// Next write position in `lookahead_buffer`
+    lookahead_pos: usize,
 }
 
 impl Compressor {
@@ -72,6 +88,13 @@ impl Compressor {
             release_coeff: 0.0,
             sample_counter: 0,
             envelope_mono: 0.0,
+            stereo_link: false,
+            lookahead_buffer: vec![
+                (0.0, 0.0);
+                (MAX_LOOKAHEAD_MS / 1000.0 * sample_rate).ceil() as usize + 1
+            ],
+            lookahead_samples: 0,
+            lookahead_pos: 0,
         };
 
         compressor.update_coefficients();
@@ -104,6 +127,11 @@ impl Compressor {
         self.update_coefficients();
     }
 
+    /// Get current attack time in milliseconds
+    pub fn attack(&self) -> f32 {
+        self.attack_time * 1000.0
+    }
+
     /// Set release time in milliseconds
     pub fn set_release(&mut self, release_ms: f32) {
         self.release_time = (release_ms / 1000.0).clamp(0.001, 5.0);
@@ -120,6 +148,32 @@ impl Compressor {
         self.makeup_gain_db = gain_db.clamp(0.0, 30.0);
     }
 
+    /// Link the channels' gain reduction (preserves the stereo image) or run dual-mono
+    /// detection (each channel ducks only for its own peaks, punchier but the image shifts)
+    pub fn set_stereo_link(&mut self, linked: bool) {
+        self.stereo_link = linked;
+    }
+
+    /// Set the lookahead in milliseconds (0 to `MAX_LOOKAHEAD_MS`, 0 = none).
+    ///
+    /// The audio is delayed by this much while the detector reads the undelayed input, so
+    /// gain reduction is already in place when a transient arrives. The delay is reported
+    /// by `latency_samples()`. Only `process()` looks ahead.
+    pub fn set_lookahead_ms(&mut self, lookahead_ms: f32) {
+        let samples = (lookahead_ms.clamp(0.0, MAX_LOOKAHEAD_MS) / 1000.0 * self.sample_rate)
+            .round() as usize;
+        let samples = samples.min(self.lookahead_buffer.len() - 1);
+        if samples != self.lookahead_samples {
+            self.lookahead_samples = samples;
+            self.lookahead_buffer.fill((0.0, 0.0));
+        }
+    }
+
+    /// Delay added by the lookahead, in samples
+    pub fn latency_samples(&self) -> u32 {
+        self.lookahead_samples as u32
+    }
+
     /// Update attack/release coefficients
     fn update_coefficients(&mut self) {
         // Exponential smoothing coefficients
@@ -147,9 +201,27 @@ impl Compressor {
             self.release_coeff * self.envelope_right + (1.0 - self.release_coeff) * input_db_right
         };
 
-        // Calculate gain reduction for each channel
-        let gain_reduction_left = self.calculate_gain_reduction(self.envelope_left);
-        let gain_reduction_right = self.calculate_gain_reduction(self.envelope_right);
+        // Calculate gain reduction for each channel (linked: both follow the louder one)
+        let (gain_reduction_left, gain_reduction_right) = if self.stereo_link {
+            let gain = self.calculate_gain_reduction(self.envelope_left.max(self.envelope_right));
+            (gain, gain)
+        } else {
+            (
+                self.calculate_gain_reduction(self.envelope_left),
+                self.calculate_gain_reduction(self.envelope_right),
+            )
+        };
+
+        // With lookahead, the gain applies to the audio from `lookahead_samples` ago
+        let (left, right) = if self.lookahead_samples > 0 {
+            let len = self.lookahead_buffer.len();
+            self.lookahead_buffer[self.lookahead_pos] = (left, right);
+            let read_pos = (self.lookahead_pos + len - self.lookahead_samples) % len;
+            self.lookahead_pos = (self.lookahead_pos + 1) % len;
+            self.lookahead_buffer[read_pos]
+        } else {
+            (left, right)
+        };
 
         // Apply gain reduction and makeup gain
         let output_left = left * gain_reduction_left * Self::db_to_amp(self.makeup_gain_db);
@@ -158,6 +230,12 @@ impl Compressor {
         (output_left, output_right)
     }
 
+    /// Current gain reduction in dB (positive; the louder channel's), for metering
+    pub fn gain_reduction_db(&self) -> f32 {
+        let envelope = self.envelope_left.max(self.envelope_right);
+        -Self::amp_to_db(self.calculate_gain_reduction(envelope))
+    }
+
     /// Calculate gain reduction based on input level
     #[inline]
     fn calculate_gain_reduction(&self, input_db: f32) -> f32 {
@@ -198,6 +276,8 @@ impl Compressor {
         self.envelope_right = 0.0;
         self.envelope_mono = 0.0;
         self.sample_counter = 0;
+        self.lookahead_buffer.fill((0.0, 0.0));
+        self.lookahead_pos = 0;
     }
 
     /// Process a stereo sample with optimized mono compression (for per-voice use)
@@ -421,6 +501,51 @@ mod tests {
         assert!((soft_out - hard_out).abs() < input); // Should differ but both work
     }
 
+    #[test]
+    fn test_compressor_soft_knee_gain_curve_is_smooth() {
+        // Static curve: output level vs input level, stepped through -30..-10 dB
+        fn curve_slopes(knee_db: f32) -> Vec<f32> {
+            let mut comp = Compressor::new(44100.0, -20.0, 4.0, 1.0, 100.0);
+            comp.set_knee(knee_db);
+            let output_db = |input_db: f32| {
+                input_db + Compressor::amp_to_db(comp.calculate_gain_reduction(input_db))
+            };
+            (0..80)
+                .map(|i| {
+                    let input_db = -30.0 + i as f32 * 0.25;
+                    (output_db(input_db + 0.25) - output_db(input_db)) / 0.25
+                })
+                .collect()
+        }
+
+        let soft = curve_slopes(8.0);
+        assert_relative_eq!(soft[0], 1.0, epsilon = 0.001);
+        assert_relative_eq!(soft[soft.len() - 1], 0.25, epsilon = 0.001);
+        for pair in soft.windows(2) {
+            assert!(pair[1] <= pair[0] + 1e-3, "Slope only ever flattens");
+            assert!(
+                pair[0] - pair[1] < 0.05,
+                "The knee eases the slope from 1 to 1/ratio: {:?}",
+                pair
+            );
+        }
+
+        // The hard knee switches slope in one step
+        let hard = curve_slopes(0.0);
+        let biggest_change = hard
+            .windows(2)
+            .map(|pair| pair[0] - pair[1])
+            .fold(0.0_f32, f32::max);
+        assert!(biggest_change > 0.5);
+
+        // Half-way into the knee (at the threshold) the reduction is a quarter of the
+        // knee's full amount: (knee / 2)^2 / (2 * knee) * (1 - 1 / ratio)
+        let mut comp = Compressor::new(44100.0, -20.0, 4.0, 1.0, 100.0);
+        comp.set_knee(8.0);
+        let reduction_db = -Compressor::amp_to_db(comp.calculate_gain_reduction(-20.0));
+        assert_relative_eq!(reduction_db, 0.75, epsilon = 0.001);
+    }
+
     #[test]
     fn test_compressor_reset() {
         let mut comp = Compressor::new(44100.0, -20.0, 4.0, 10.0, 100.0);
@@ -438,6 +563,24 @@ mod tests {
         assert_eq!(comp.envelope_right, 0.0);
     }
 
+    #[test]
+    fn test_compressor_gain_reduction_meter() {
+        let mut comp = Compressor::new(44100.0, -20.0, 4.0, 1.0, 50.0);
+        for _ in 0..44100 {
+            comp.process(0.001, 0.001);
+        }
+        assert!(
+            comp.gain_reduction_db() < 0.01,
+            "Quiet input is not compressed"
+        );
+
+        // 0 dBFS into a -20 dB threshold at 4:1 settles near 15 dB of reduction
+        for _ in 0..44100 {
+            comp.process(1.0, 1.0);
+        }
+        assert!((comp.gain_reduction_db() - 15.0).abs() < 0.5);
+    }
+
     #[test]
     fn test_compressor_stability() {
         let mut comp = Compressor::new(44100.0, -20.0, 10.0, 1.0, 50.0);
@@ -471,4 +614,62 @@ mod tests {
         // Outputs should be different (independent compression)
         assert!((left_out - right_out).abs() > 0.1);
     }
+
+    #[test]
+    fn test_compressor_lookahead_catches_transient() {
+        fn transient_peak(lookahead_ms: f32) -> f32 {
+            let mut comp = Compressor::new(44100.0, -20.0, 10.0, 1.0, 100.0);
+            comp.set_lookahead_ms(lookahead_ms);
+
+            // Settle on a quiet signal, then hit a full-scale step
+            for _ in 0..4410 {
+                comp.process(0.01, 0.01);
+            }
+            (0..441).fold(0.0_f32, |peak, _| {
+                let (left, _) = comp.process(1.0, 1.0);
+                peak.max(left.abs())
+            })
+        }
+
+        let mut comp = Compressor::new(44100.0, -20.0, 4.0, 1.0, 100.0);
+        assert_eq!(comp.latency_samples(), 0);
+        comp.set_lookahead_ms(2.0);
+        assert_eq!(comp.latency_samples(), 88, "2 ms at 44.1 kHz");
+
+        let without = transient_peak(0.0);
+        let with = transient_peak(5.0);
+        assert!(without > 0.9, "The attack lets the step's onset through");
+        assert!(
+            with < without * 0.5,
+            "Lookahead should clamp the onset: {with} vs {without}"
+        );
+    }
+
+    #[test]
+    fn test_compressor_stereo_link() {
+        let mut dual_mono = Compressor::new(44100.0, -20.0, 4.0, 1.0, 100.0);
+        let mut linked = Compressor::new(44100.0, -20.0, 4.0, 1.0, 100.0);
+        linked.set_stereo_link(true);
+
+        // Loud signal on the left only, quiet signal on the right. The detectors start at
+        // 0 dB, so run for a second (10 release time constants) to leave only steady state.
+        let (left_input, right_input) = (0.8, 0.01);
+        for _ in 0..44100 {
+            dual_mono.process(left_input, right_input);
+            linked.process(left_input, right_input);
+        }
+
+        let (left_dual, right_dual) = dual_mono.process(left_input, right_input);
+        assert!(left_dual < left_input * 0.5, "Left channel is compressed");
+        assert_relative_eq!(right_dual, right_input, epsilon = 0.001);
+
+        // Linked: the right channel ducks with the left, keeping the level ratio
+        let (left_linked, right_linked) = linked.process(left_input, right_input);
+        assert!(right_linked < right_input * 0.5);
+        assert_relative_eq!(
+            left_linked / right_linked,
+            left_input / right_input,
+            epsilon = 0.01
+        );
+    }
 }
@@ -20,8 +20,26 @@
 ///
 /// Uses an efficient sliding window maximum algorithm with a monotonic deque to track
 /// peaks in O(1) amortized time instead of O(N) linear scans, dramatically reducing CPU usage.
+///
+/// ## True-Peak Detection (optional)
+///
+/// Sample peaks can stay under the threshold while the reconstructed analog waveform
+/// overshoots between samples (inter-sample peaks), which clips on D/A conversion or after
+/// lossy encoding. With `set_true_peak(true)` the detector upsamples 4x with a polyphase
+/// windowed-sinc FIR and limits on the largest interpolated value instead. The audio path
+/// itself is not oversampled; it is only delayed by the FIR's group delay
+/// (`TRUE_PEAK_LATENCY` samples) so the detector still sees peaks ahead of the output.
 use std::collections::VecDeque;
 
+/// Oversampling factor for true-peak detection
+const TRUE_PEAK_OVERSAMPLE: usize = 4;
+
+/// Taps per polyphase branch of the true-peak interpolation filter
+const TRUE_PEAK_TAPS: usize = 13;
+
+/// Group delay of the interpolation filter in input samples (half the prototype length)
+const TRUE_PEAK_LATENCY: usize = (TRUE_PEAK_TAPS - 1) / 2;
+
 /// Entry in the peak tracking deque (value and position)
 #[derive(Copy, Clone)]
 struct PeakEntry {
@@ -60,6 +78,17 @@ pub struct LookAheadLimiter {
 
     /// Current write position in the circular buffer (for peak tracking)
     write_pos: usize,
+
+    /// Whether peak detection uses the 4x oversampled true-peak detector
+    true_peak: bool,
+
+    /// Polyphase interpolation coefficients, one row per oversampled phase
+    true_peak_coeffs: [[f32; TRUE_PEAK_TAPS]; TRUE_PEAK_OVERSAMPLE],
+
+    /// Recent input history for the interpolation filter (circular, per channel)
+    true_peak_history_left: [f32; TRUE_PEAK_TAPS],
+    true_peak_history_right: [f32; TRUE_PEAK_TAPS],
+    true_peak_history_pos: usize,
 }
 
 impl LookAheadLimiter {
@@ -86,9 +115,9 @@ impl LookAheadLimiter {
         let attack_coeff = (-1.0 / (attack_ms * sample_rate / 1000.0)).exp();
         let release_coeff = (-1.0 / (release_ms * sample_rate / 1000.0)).exp();
 
-        // Pre-allocate delay buffers filled with zeros
-        let mut delay_buffer_left = VecDeque::with_capacity(lookahead_samples);
-        let mut delay_buffer_right = VecDeque::with_capacity(lookahead_samples);
+        // Pre-allocate delay buffers filled with zeros (with room for true-peak latency)
+        let mut delay_buffer_left = VecDeque::with_capacity(lookahead_samples + TRUE_PEAK_LATENCY);
+        let mut delay_buffer_right = VecDeque::with_capacity(lookahead_samples + TRUE_PEAK_LATENCY);
         for _ in 0..lookahead_samples {
             delay_buffer_left.push_back(0.0);
             delay_buffer_right.push_back(0.0);
@@ -105,7 +134,87 @@ impl LookAheadLimiter {
             release_coeff,
             peak_queue: VecDeque::with_capacity(lookahead_samples),
             write_pos: 0,
+            true_peak: false,
+            true_peak_coeffs: Self::true_peak_filter(),
+            true_peak_history_left: [0.0; TRUE_PEAK_TAPS],
+            true_peak_history_right: [0.0; TRUE_PEAK_TAPS],
+            true_peak_history_pos: 0,
+        }
+    }
+
+    /// Build the 4x polyphase interpolation filter.
+    ///
+    /// Prototype: Blackman-windowed sinc with cutoff at the original Nyquist, length
+    /// `4 * (TRUE_PEAK_TAPS - 1) + 1`. Branch `p` holds taps `p, p + 4, p + 8, ...` and is
+    /// normalized to unity DC gain, so branch 0 reproduces the original samples exactly.
+    fn true_peak_filter() -> [[f32; TRUE_PEAK_TAPS]; TRUE_PEAK_OVERSAMPLE] {
+        let len = TRUE_PEAK_OVERSAMPLE * (TRUE_PEAK_TAPS - 1) + 1;
+        let center = (len - 1) as f32 / 2.0;
+        let mut coeffs = [[0.0; TRUE_PEAK_TAPS]; TRUE_PEAK_OVERSAMPLE];
+
+        for (phase, branch) in coeffs.iter_mut().enumerate() {
+            for (k, coeff) in branch.iter_mut().enumerate() {
+                let n = k * TRUE_PEAK_OVERSAMPLE + phase;
+                if n >= len {
+                    continue;
+                }
+                let x = (n as f32 - center) / TRUE_PEAK_OVERSAMPLE as f32;
+                let sinc = if x.abs() < 1e-6 {
+                    1.0
+                } else {
+                    (std::f32::consts::PI * x).sin() / (std::f32::consts::PI * x)
+                };
+                let w = 2.0 * std::f32::consts::PI * n as f32 / (len - 1) as f32;
+                let blackman = 0.42 - 0.5 * w.cos() + 0.08 * (2.0 * w).cos();
+                *coeff = sinc * blackman;
+            }
+            let sum: f32 = branch.iter().sum();
+            if sum.abs() > 1e-6 {
+                branch.iter_mut().for_each(|c| *c /= sum);
+            }
         }
+
+        coeffs
+    }
+
+    /// Enable or disable 4x oversampled true-peak (inter-sample) detection.
+    ///
+    /// Off by default to save CPU. Enabling adds `TRUE_PEAK_LATENCY` samples of delay (see
+    /// `get_latency_samples()`), so toggle it while stopped (e.g. at plugin activation)
+    /// rather than mid-stream, and re-report latency to the host.
+    pub fn set_true_peak(&mut self, enabled: bool) {
+        if enabled == self.true_peak {
+            return;
+        }
+        self.true_peak = enabled;
+        self.reset();
+    }
+
+    /// Whether true-peak detection is enabled
+    pub fn is_true_peak(&self) -> bool {
+        self.true_peak
+    }
+
+    /// Largest absolute value of the 4x interpolated signal around the newest history sample.
+    #[inline]
+    fn true_peak_detect(&mut self, left: f32, right: f32) -> f32 {
+        self.true_peak_history_left[self.true_peak_history_pos] = left;
+        self.true_peak_history_right[self.true_peak_history_pos] = right;
+        self.true_peak_history_pos = (self.true_peak_history_pos + 1) % TRUE_PEAK_TAPS;
+
+        let mut peak = 0.0f32;
+        for branch in &self.true_peak_coeffs {
+            let mut sum_left = 0.0;
+            let mut sum_right = 0.0;
+            // Walk history newest → oldest alongside the branch taps
+            for (k, coeff) in branch.iter().enumerate() {
+                let idx = (self.true_peak_history_pos + TRUE_PEAK_TAPS - 1 - k) % TRUE_PEAK_TAPS;
+                sum_left += coeff * self.true_peak_history_left[idx];
+                sum_right += coeff * self.true_peak_history_right[idx];
+            }
+            peak = peak.max(sum_left.abs()).max(sum_right.abs());
+        }
+        peak
     }
 
     /// Process a stereo sample pair through the look-ahead limiter.
@@ -129,8 +238,13 @@ impl LookAheadLimiter {
         self.delay_buffer_left.push_back(left);
         self.delay_buffer_right.push_back(right);
 
-        // Calculate peak of incoming sample (stereo max)
-        let incoming_peak = left.abs().max(right.abs());
+        // Calculate peak of incoming sample (stereo max). The true-peak detector lags the
+        // input by TRUE_PEAK_LATENCY samples, which the longer delay buffer accounts for.
+        let incoming_peak = if self.true_peak {
+            self.true_peak_detect(left, right)
+        } else {
+            left.abs().max(right.abs())
+        };
 
         // Remove peaks that have exited the look-ahead window
         let oldest_valid_pos = self.write_pos.saturating_sub(self.lookahead_samples);
@@ -199,13 +313,16 @@ impl LookAheadLimiter {
     pub fn reset(&mut self) {
         self.delay_buffer_left.clear();
         self.delay_buffer_right.clear();
-        for _ in 0..self.lookahead_samples {
+        for _ in 0..self.get_latency_samples() {
             self.delay_buffer_left.push_back(0.0);
             self.delay_buffer_right.push_back(0.0);
         }
         self.current_gain = 1.0;
         self.peak_queue.clear();
         self.write_pos = 0;
+        self.true_peak_history_left = [0.0; TRUE_PEAK_TAPS];
+        self.true_peak_history_right = [0.0; TRUE_PEAK_TAPS];
+        self.true_peak_history_pos = 0;
     }
 
     /// Get the current gain reduction amount (for metering/visualization)
@@ -215,13 +332,18 @@ impl LookAheadLimiter {
     }
 
     /// Get the current latency in samples introduced by the look-ahead buffer
+    /// (plus the interpolation filter delay when true-peak detection is on)
     pub fn get_latency_samples(&self) -> usize {
-        self.lookahead_samples
+        if self.true_peak {
+            self.lookahead_samples + TRUE_PEAK_LATENCY
+        } else {
+            self.lookahead_samples
+        }
     }
 
     /// Get the current latency in milliseconds
     pub fn get_latency_ms(&self) -> f32 {
-        self.lookahead_samples as f32 * 1000.0 / self.sample_rate
+        self.get_latency_samples() as f32 * 1000.0 / self.sample_rate
     }
 }
 
@@ -321,4 +443,60 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_true_peak_catches_inter_sample_peak() {
+        // fs/4 sine at 45° phase: samples land at ±0.707 * amplitude, so a 1.3 amplitude
+        // sine has sample peaks of ~0.92 (under 0 dBFS) but a true peak of 1.3 (over 0 dBTP).
+        let amplitude = 1.3;
+        let signal = |n: usize| {
+            amplitude * (std::f32::consts::FRAC_PI_2 * n as f32 + std::f32::consts::FRAC_PI_4).sin()
+        };
+
+        let mut sample_peak = LookAheadLimiter::new(44100.0, 5.0, 0.99, 0.5, 50.0);
+        let mut true_peak = LookAheadLimiter::new(44100.0, 5.0, 0.99, 0.5, 50.0);
+        true_peak.set_true_peak(true);
+        assert!(true_peak.is_true_peak());
+        assert_eq!(true_peak.get_latency_samples(), 220 + TRUE_PEAK_LATENCY);
+
+        let mut max_sample_peak_out = 0.0f32;
+        let mut max_true_peak_out = 0.0f32;
+        for n in 0..8820 {
+            let x = signal(n);
+            let (a, _) = sample_peak.process(x, x);
+            let (b, _) = true_peak.process(x, x);
+            if n > 4410 {
+                max_sample_peak_out = max_sample_peak_out.max(a.abs());
+                max_true_peak_out = max_true_peak_out.max(b.abs());
+            }
+        }
+
+        // Sample-peak mode sees nothing to limit
+        assert_relative_eq!(
+            max_sample_peak_out,
+            amplitude * std::f32::consts::FRAC_1_SQRT_2,
+            epsilon = 0.01
+        );
+        assert_relative_eq!(sample_peak.get_gain_reduction(), 1.0, epsilon = 0.001);
+
+        // True-peak mode reduces gain so the reconstructed peak stays under the threshold
+        let reconstructed_peak = max_true_peak_out / std::f32::consts::FRAC_1_SQRT_2;
+        assert!(
+            reconstructed_peak <= 1.0,
+            "True peak should be limited below 0 dBTP, got {reconstructed_peak}"
+        );
+        assert!(true_peak.get_gain_reduction() < 0.8);
+    }
+
+    #[test]
+    fn test_true_peak_filter_preserves_samples() {
+        // Branch 0 of the interpolation filter must reproduce the input samples
+        let coeffs = LookAheadLimiter::true_peak_filter();
+        assert_relative_eq!(coeffs[0][TRUE_PEAK_LATENCY], 1.0, epsilon = 1e-4);
+        for (k, c) in coeffs[0].iter().enumerate() {
+            if k != TRUE_PEAK_LATENCY {
+                assert_relative_eq!(*c, 0.0, epsilon = 1e-4);
+            }
+        }
+    }
 }
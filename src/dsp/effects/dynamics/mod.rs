@@ -8,6 +8,7 @@ pub mod de_esser;
 pub mod lookahead_limiter;
 pub mod multiband_compressor;
 pub mod noise_gate;
+pub mod sibilance_eq;
 pub mod smart_gate;
 pub mod transient_shaper;
 
@@ -19,6 +20,7 @@ pub use de_esser::DeEsser;
 pub use lookahead_limiter::LookAheadLimiter;
 pub use multiband_compressor::MultibandCompressor;
 pub use noise_gate::NoiseGate;
+pub use sibilance_eq::SibilanceEq;
 pub use smart_gate::SmartGate;
 pub use transient_shaper::TransientShaper;
 
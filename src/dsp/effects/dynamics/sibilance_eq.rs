@@ -0,0 +1,194 @@
+//! Sibilance EQ - Dynamic high-shelf cut triggered by sibilance
+//!
+//! A gentler alternative to the `DeEsser`: the signal runs through a high shelf that sits
+//! flat (0 dB) until the `SibilanceDetector` reports a burst of hissy high-frequency energy,
+//! and only then dips the top end. Steady tones - even bright ones - leave the shelf flat,
+//! so the effect stays transparent on synth leads and only catches the 's'-like spikes.
+//!
+//! # Parameters
+//! - **threshold**: Sibilance strength (high-band / full-band ratio) above which the shelf
+//!   starts to cut (0.0 = very sensitive, 1.0 = only pure hiss)
+//! - **amount**: Maximum cut (0.0 = none, 1.0 = `MAX_CUT_DB`)
+
+use crate::dsp::analysis::SibilanceDetector;
+use crate::dsp::filters::BiquadFilter;
+use crate::params::FilterType;
+
+/// Shelf corner frequency, at the bottom of the sibilant range
+const SHELF_FREQUENCY: f32 = 5000.0;
+
+/// Deepest cut at full amount
+const MAX_CUT_DB: f32 = 12.0;
+
+/// How far above the threshold the strength must rise for the full cut
+const KNEE: f32 = 0.25;
+
+/// Cut attack/release: quick to catch the start of a burst, slow enough to not flutter
+const ATTACK_MS: f32 = 1.0;
+const RELEASE_MS: f32 = 80.0;
+
+pub struct SibilanceEq {
+    detector: SibilanceDetector,
+    shelf_left: BiquadFilter,
+    shelf_right: BiquadFilter,
+
+    threshold: f32, // 0.0 to 1.0
+    amount: f32,    // 0.0 to 1.0
+
+    /// Current (smoothed) shelf gain in dB, 0.0 or below
+    cut_db: f32,
+    attack_coeff: f32,
+    release_coeff: f32,
+}
+
+impl SibilanceEq {
+    /// Create a new sibilance EQ
+    ///
+    /// # Arguments
+    /// * `sample_rate` - Audio sample rate in Hz
+    pub fn new(sample_rate: f32) -> Self {
+        let shelf = || {
+            let mut filter = BiquadFilter::new(sample_rate);
+            filter.set_filter_type(FilterType::HighShelf);
+            // The gain moves every sample during a burst, so never defer an update
+            filter.set_cutoff_update_interval(1);
+            filter.set_cutoff(SHELF_FREQUENCY);
+            filter.set_resonance(0.707);
+            filter.set_gain_db(0.0);
+            filter
+        };
+        let coeff = |time_ms: f32| (-1.0 / (time_ms / 1000.0 * sample_rate)).exp();
+
+        Self {
+            detector: SibilanceDetector::new(sample_rate),
+            shelf_left: shelf(),
+            shelf_right: shelf(),
+            threshold: 0.5,
+            amount: 0.5,
+            cut_db: 0.0,
+            attack_coeff: coeff(ATTACK_MS),
+            release_coeff: coeff(RELEASE_MS),
+        }
+    }
+
+    /// Set the sibilance strength the cut starts at (0.0 to 1.0)
+    pub fn set_threshold(&mut self, threshold: f32) {
+        self.threshold = threshold.clamp(0.0, 1.0);
+    }
+
+    /// Set the maximum cut (0.0 = none, 1.0 = full)
+    pub fn set_amount(&mut self, amount: f32) {
+        self.amount = amount.clamp(0.0, 1.0);
+    }
+
+    /// Current shelf cut in dB (0.0 = flat, negative while sibilance is reduced)
+    pub fn cut_db(&self) -> f32 {
+        self.cut_db
+    }
+
+    /// Process stereo audio
+    ///
+    /// # Arguments
+    /// * `left` - Left channel input sample
+    /// * `right` - Right channel input sample
+    ///
+    /// # Returns
+    /// Tuple of (left_out, right_out)
+    pub fn process(&mut self, left: f32, right: f32) -> (f32, f32) {
+        // Stereo-linked detection so the image doesn't shift during a cut
+        let (_, strength) = self.detector.process((left + right) * 0.5);
+        let depth = ((strength - self.threshold) / KNEE).clamp(0.0, 1.0);
+        let target_db = -MAX_CUT_DB * self.amount * depth;
+
+        let coeff = if target_db < self.cut_db {
+            self.attack_coeff
+        } else {
+            self.release_coeff
+        };
+        self.cut_db = target_db + (self.cut_db - target_db) * coeff;
+
+        self.shelf_left.set_gain_db(self.cut_db);
+        self.shelf_right.set_gain_db(self.cut_db);
+        (
+            self.shelf_left.process(left),
+            self.shelf_right.process(right),
+        )
+    }
+
+    /// Reset filter and detector state
+    pub fn clear(&mut self) {
+        self.shelf_left.reset();
+        self.shelf_right.reset();
+        self.detector.reset();
+        self.cut_db = 0.0;
+        self.shelf_left.set_gain_db(0.0);
+        self.shelf_right.set_gain_db(0.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::PI;
+
+    const SAMPLE_RATE: f32 = 44100.0;
+
+    #[test]
+    fn test_steady_tone_untouched() {
+        let mut eq = SibilanceEq::new(SAMPLE_RATE);
+        eq.set_amount(1.0);
+
+        let (mut deepest_cut, mut max_error) = (0.0_f32, 0.0_f32);
+        for i in 0..66150 {
+            let input = 0.5 * (2.0 * PI * 440.0 * i as f32 / SAMPLE_RATE).sin();
+            let (left, right) = eq.process(input, input);
+            if i < 22050 {
+                continue; // Let the tone's onset (a click, as far as the detector is concerned) pass
+            }
+            deepest_cut = deepest_cut.min(eq.cut_db());
+            max_error = max_error
+                .max((left - input).abs())
+                .max((right - input).abs());
+        }
+        assert!(
+            deepest_cut > -0.01,
+            "Shelf cut {} dB on a steady tone",
+            deepest_cut
+        );
+        assert!(max_error < 1e-4, "Steady tone altered by {}", max_error);
+    }
+
+    #[test]
+    fn test_sibilant_burst_attenuated() {
+        let mut eq = SibilanceEq::new(SAMPLE_RATE);
+        eq.set_amount(1.0);
+
+        // A tone, then a 100 ms burst of hiss over it
+        let mut noise_state = 0x12345678_u32;
+        let (mut dry_energy, mut wet_energy) = (0.0, 0.0);
+        for i in 0..22050 {
+            let tone = 0.3 * (2.0 * PI * 220.0 * i as f32 / SAMPLE_RATE).sin();
+            let hiss = if (11025..15435).contains(&i) {
+                noise_state ^= noise_state << 13;
+                noise_state ^= noise_state >> 17;
+                noise_state ^= noise_state << 5;
+                0.5 * (noise_state as f32 / u32::MAX as f32 * 2.0 - 1.0)
+            } else {
+                0.0
+            };
+            let input = tone + hiss;
+            let (output, _) = eq.process(input, input);
+            if (11466..15435).contains(&i) {
+                // Past the cut's attack: compare the top end the burst leaves behind
+                dry_energy += hiss * hiss;
+                wet_energy += (output - tone) * (output - tone);
+            }
+        }
+        assert!(
+            wet_energy < dry_energy * 0.5,
+            "Sibilant burst should be cut: {} of {}",
+            wet_energy,
+            dry_energy
+        );
+    }
+}
@@ -7,6 +7,8 @@
 //! - **Preserves sibilance** - 's', 'sh', 't', 'f' sounds bypass gate
 //! - **Vocal-aware** - Lowers threshold for singing/speech (pitched content)
 //! - **Content classification** - Treats tonal vs noisy content differently
+//! - **No chatter on sustained tones** - Tonal content is gated on a slow peak level, so
+//!   a note fading through the threshold (or beating around it) closes the gate once
 //!
 //! # How It Works
 //! 1. Receives `SignalAnalysis` with pre-computed signal features
@@ -22,13 +24,17 @@
 //! if transient OR sibilance:
 //!     gate_open = TRUE  (always pass musical content)
 //! else if voiced/pitched:
-//!     gate_open = (level > threshold - 6dB)  (more permissive for vocals)
+//!     gate_open = (sustained level > threshold - 6dB)  (more permissive for vocals)
 //! else:
 //!     gate_open = (level > threshold)  (standard for noise)
 //! ```
 
 use crate::dsp::signal_analyzer::SignalAnalysis;
 
+/// Release of the peak level tonal content is gated on: slow enough that the level of a
+/// beating or tremolo-ing note falls less between swells than the swells themselves do
+const SUSTAIN_RELEASE_MS: f32 = 500.0;
+
 /// Smart noise gate with signal-aware intelligence
 pub struct SmartGate {
     sample_rate: f32,
@@ -53,6 +59,10 @@ pub struct SmartGate {
 
     /// Hysteresis to prevent chattering
     hysteresis_db: f32,
+
+    /// Slow-release peak of the RMS level, used for tonal content
+    sustain_level: f32,
+    sustain_release_coeff: f32,
 }
 
 impl SmartGate {
@@ -72,6 +82,8 @@ impl SmartGate {
             attack_coeff: 0.0,
             release_coeff: 0.0,
             hysteresis_db: 3.0, // 3dB hysteresis
+            sustain_level: 0.0,
+            sustain_release_coeff: 0.0,
         };
 
         gate.update_coefficients();
@@ -118,6 +130,7 @@ impl SmartGate {
     fn update_coefficients(&mut self) {
         self.attack_coeff = Self::ms_to_coeff(self.attack_ms, self.sample_rate);
         self.release_coeff = Self::ms_to_coeff(self.release_ms, self.sample_rate);
+        self.sustain_release_coeff = Self::ms_to_coeff(SUSTAIN_RELEASE_MS, self.sample_rate);
     }
 
     /// Convert dB to linear gain
@@ -169,9 +182,18 @@ impl SmartGate {
         };
 
         // === STEP 2: Determine Gate State ===
-        // Use RMS level from analysis (pre-computed)
-
-        let input_level_db = Self::linear_to_db(analysis.rms_level.sqrt());
+        // Use RMS level from analysis (pre-computed). Tonal content is judged on its
+        // sustained level instead: a fading or beating note dips below the threshold
+        // many times on its way out, and the fast level would reopen the gate each time.
+
+        let rms_level = analysis.rms_level.sqrt();
+        self.sustain_level = rms_level.max(self.sustain_level * self.sustain_release_coeff);
+        let tonal = analysis.is_voiced || (analysis.is_pitched && analysis.pitch_confidence > 0.5);
+        let input_level_db = if tonal {
+            Self::linear_to_db(self.sustain_level)
+        } else {
+            Self::linear_to_db(rms_level)
+        };
 
         let target_gain = if input_level_db > effective_threshold {
             // Signal above threshold - gate is open
@@ -182,7 +204,8 @@ impl SmartGate {
             || self.hold_counter == 0
         {
             // Signal below threshold (with hysteresis) and hold expired
-            // Gate closes
+            // Gate closes, ending any hold so only a new rise above threshold reopens it
+            self.hold_counter = 0;
             0.0 // Silence
         } else {
             // In hold phase - keep gate open
@@ -211,6 +234,7 @@ impl SmartGate {
     pub fn reset(&mut self) {
         self.gain = 1.0;
         self.hold_counter = 0;
+        self.sustain_level = 0.0;
     }
 }
 
@@ -387,4 +411,72 @@ mod tests {
         assert_eq!(gate.gain, 1.0);
         assert_eq!(gate.hold_counter, 0);
     }
+
+    /// Gate gain over a decaying tone that beats (two detuned partials) as it fades through
+    /// the threshold, sampled wherever the input is large enough to divide by
+    fn gain_over_decaying_tone(mut gate: impl FnMut(f32, &SignalAnalysis) -> f32) -> Vec<f32> {
+        use crate::dsp::signal_analyzer::SignalAnalyzer;
+        use std::f32::consts::PI;
+
+        let sample_rate = 44100.0;
+        let mut analyzer = SignalAnalyzer::new(sample_rate);
+        let mut gains = Vec::new();
+        for i in 0..(3.0 * sample_rate) as usize {
+            let t = i as f32 / sample_rate;
+            let amplitude = 10.0_f32.powf((-20.0 - 20.0 * t) / 20.0); // -20 dB, falling 20 dB/s
+            let tone = (2.0 * PI * 220.0 * t).sin() + 0.5 * (2.0 * PI * 224.0 * t).sin();
+            let input = amplitude * tone / 1.5;
+            let analysis = analyzer.analyze(input, input);
+            let output = gate(input, &analysis);
+            if input.abs() > 1e-5 {
+                gains.push(output / input);
+            }
+        }
+        gains
+    }
+
+    /// How often the gate reopens: its gain climbing 0.1 or more above where it had fallen
+    fn reopen_count(gains: &[f32]) -> usize {
+        let mut lowest = f32::MAX;
+        let mut reopens = 0;
+        for &gain in gains {
+            if gain > lowest + 0.1 {
+                reopens += 1;
+                lowest = f32::MAX;
+            } else if gain < 1.0 {
+                lowest = lowest.min(gain);
+            }
+        }
+        reopens
+    }
+
+    #[test]
+    fn test_closes_smoothly_on_decaying_tone() {
+        use crate::dsp::effects::dynamics::NoiseGate;
+
+        let mut smart = SmartGate::new(44100.0);
+        smart.set_threshold(-50.0);
+        let smart_gains =
+            gain_over_decaying_tone(|input, analysis| smart.process(input, input, analysis).0);
+
+        let mut simple = NoiseGate::new(44100.0);
+        simple.set_threshold(-50.0);
+        simple.set_ratio(10.0);
+        let simple_gains = gain_over_decaying_tone(|input, _| simple.process(input, input).0);
+
+        // Both gates end up closed...
+        assert!(*smart_gains.last().unwrap() < 0.01);
+        assert!(*simple_gains.last().unwrap() < 0.5);
+
+        // ...but only the level-based gate chatters on the beats on the way down
+        assert!(
+            reopen_count(&simple_gains) > 0,
+            "Expected the simple gate to chatter"
+        );
+        assert_eq!(
+            reopen_count(&smart_gains),
+            0,
+            "Smart gate should close once"
+        );
+    }
 }
@@ -17,10 +17,12 @@
 /// ```
 ///
 /// **Attack boost/cut**: When `analysis.is_transient == true`, apply attack gain
-/// **Pass-through**: When no transient detected, output = input (unity gain)
+/// **Pass-through**: When no transient detected, output = input (unity gain), unless a
+/// sustain adjustment is set with `set_sustain()`
 ///
-/// # Parameters (1 total)
+/// # Parameters (2 total)
 /// - `attack`: -1.0 to +1.0 (negative = soften transients, 0 = neutral, positive = punch)
+/// - `sustain`: -1.0 to +1.0 (gain between transients; 0 = neutral, the default)
 ///
 /// # Use Cases
 /// - Attack +0.5: Emphasize consonants/plosives for clarity
@@ -36,6 +38,9 @@ pub struct TransientShaper {
     /// Fixed transient sensitivity threshold (0.15 = low for imperfect vocals)
     sensitivity: f32,
 
+    /// Gain adjustment between transients (-1.0 to +1.0, 0.0 = unity)
+    sustain: f32,
+
     /// Fast envelope follower for attack detection (1ms)
     fast_env_left: f32,
     fast_env_right: f32,
@@ -71,6 +76,7 @@ impl TransientShaper {
         Self {
             sample_rate,
             sensitivity: 0.15, // Lowered from 0.4 for consistent triggering on imperfect vocals
+            sustain: 0.0,
             fast_env_left: 0.0,
             fast_env_right: 0.0,
             fast_coeff,
@@ -83,6 +89,13 @@ impl TransientShaper {
         }
     }
 
+    /// Set the gain adjustment between transients
+    ///
+    /// -1.0 → 0.1× (tighten the body), 0.0 → 1.0× (neutral), +1.0 → 2.0× (fuller body)
+    pub fn set_sustain(&mut self, sustain: f32) {
+        self.sustain = sustain.clamp(-1.0, 1.0);
+    }
+
     /// Process stereo sample with attack enhancement
     ///
     /// # Arguments
@@ -99,8 +112,8 @@ impl TransientShaper {
         attack: f32,
         analysis: &SignalAnalysis,
     ) -> (f32, f32) {
-        // Bypass if attack is neutral
-        if attack.abs() < 0.001 {
+        // Bypass if attack and sustain are neutral
+        if attack.abs() < 0.001 && self.sustain.abs() < 0.001 {
             return (left, right);
         }
 
@@ -136,8 +149,13 @@ impl TransientShaper {
 
             (final_gain, final_gain)
         } else {
-            // NON-TRANSIENT: Pass through at unity gain (no processing)
-            (1.0, 1.0)
+            // NON-TRANSIENT: Sustain gain (unity unless a sustain adjustment is set)
+            let sustain_gain = if self.sustain >= 0.0 {
+                1.0 + self.sustain // 0 to +1 → 1.0 to 2.0
+            } else {
+                1.0 + self.sustain * 0.9 // -1 to 0 → 0.1 to 1.0
+            };
+            (sustain_gain, sustain_gain)
         };
 
         // Smooth gain changes to prevent clicks
@@ -304,6 +322,21 @@ mod tests {
         assert!((non_transient_out - 0.5).abs() < 0.1);
     }
 
+    #[test]
+    fn test_sustain_adjusts_non_transient_gain() {
+        let mut shaper = TransientShaper::new(44100.0);
+        let analysis = create_test_analysis();
+
+        shaper.set_sustain(-1.0);
+        for _ in 0..1000 {
+            shaper.process(0.5, 0.5, 0.0, &analysis);
+        }
+        let (out, _) = shaper.process(0.5, 0.5, 0.0, &analysis);
+
+        // Sustain works on its own, with attack neutral
+        assert!((out - 0.05).abs() < 0.01);
+    }
+
     #[test]
     fn test_sensitivity_threshold() {
         let mut shaper = TransientShaper::new(44100.0);
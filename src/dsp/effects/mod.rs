@@ -32,6 +32,7 @@ pub mod vocal;
 // Dynamics
 pub use dynamics::{
     Clipper, Compressor, LookAheadLimiter, LookaheadLimiter, MultibandCompressor, NoiseGate,
+    SibilanceEq, SmartGate, TransientShaper,
 };
 
 // Distortion
@@ -51,11 +52,12 @@ pub use saturator::Saturator;
 
 // Spectral
 pub use spectral::{
-    Crossover, EQBand, EqBand, Exciter, LR2Crossover, ParametricEQ, ParametricEq, PitchShifter,
+    Crossover, EQBand, EqBand, Exciter, FormantFilter, IntelligentExciter, LR2Crossover,
+    ParametricEQ, ParametricEq, PitchShifter, StereoPitchShifter,
 };
 
 // Stereo
-pub use stereo::StereoWidener;
+pub use stereo::{MidSideStereoProcessor, StereoWidener};
 
 // Vocal
 pub use vocal::{VocalChoir, VocalDoubler};
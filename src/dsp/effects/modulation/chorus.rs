@@ -5,8 +5,10 @@
 /// note with slight timing and pitch variations (like a choir or string ensemble).
 ///
 /// # Architecture
-/// - 4 delay lines with LFO-modulated read positions
-/// - Each voice has slightly different LFO rate and phase for natural variation
+/// - 1 to 8 delay lines per channel with LFO-modulated read positions (default 4)
+/// - Voice LFO phases are spread evenly across the cycle, with slightly different rates
+///   for natural variation
+/// - The right channel's LFOs are offset from the left by the spread amount
 /// - Typical delay time: 10-30ms with ±5ms LFO modulation
 /// - Creates pitch variation (vibrato) and timing variation (chorusing)
 ///
@@ -14,16 +16,29 @@
 /// - **rate**: LFO speed in Hz (0.1 to 5.0)
 /// - **depth**: Modulation depth - how much pitch variation (0.0 to 1.0)
 /// - **mix**: Wet/dry balance (0.0 = dry, 1.0 = full wet)
+/// - **voices**: Number of chorus voices per channel (1 to 8); more = lusher ensemble
+/// - **spread**: Stereo spread (0.0 = same modulation on both sides, 1.0 = quadrature)
 ///
 /// # Real-Time Safety
-/// All delay buffers are pre-allocated (50ms per voice).
+/// All delay buffers are pre-allocated (50ms per voice, for all 8 voices).
 /// No allocations during `process()`.
 use std::f32::consts::PI;
 
-const NUM_VOICES: usize = 4;
+const MAX_VOICES: usize = 8;
+const DEFAULT_VOICES: usize = 4;
+const DEFAULT_SPREAD: f32 = 0.5;
 const MAX_DELAY_MS: f32 = 50.0; // Maximum delay time
 const BASE_DELAY_MS: f32 = 20.0; // Center delay time
 
+/// Time constant of the per-sample mix smoothing (keeps automated mix changes click-free)
+const MIX_SMOOTH_MS: f32 = 10.0;
+
+/// Right-channel LFO phase offset at spread = 1.0 (a quarter cycle)
+const MAX_SPREAD_PHASE: f32 = 0.25;
+
+/// Per-voice LFO rate variations in Hz (creates beating between voices)
+const RATE_VARIATIONS: [f32; MAX_VOICES] = [0.0, 0.1, -0.05, 0.15, 0.07, -0.08, 0.12, -0.03];
+
 /// Single chorus voice with LFO-modulated delay
 struct ChorusVoice {
     buffer: Vec<f32>,
@@ -104,8 +119,8 @@ impl ChorusVoice {
 
 /// Stereo chorus processor
 pub struct Chorus {
-    voices_l: [ChorusVoice; NUM_VOICES],
-    voices_r: [ChorusVoice; NUM_VOICES],
+    voices_l: [ChorusVoice; MAX_VOICES],
+    voices_r: [ChorusVoice; MAX_VOICES],
     #[allow(dead_code)]
     sample_rate: f32,
 
@@ -113,6 +128,15 @@ pub struct Chorus {
     rate: f32,
     depth: f32,
     mix: f32,
+    voice_count: usize,
+    spread: f32,
+
+    /// Mix actually applied, following `mix` per sample
+    current_mix: f32,
+    mix_smooth_coeff: f32,
+    /// Set once audio has been processed since construction or `clear`; until then mix
+    /// changes apply immediately (there's no output yet to click against)
+    mix_active: bool,
 }
 
 impl Chorus {
@@ -125,29 +149,26 @@ impl Chorus {
     /// Each voice has a slightly different phase offset and rate variation
     /// to create natural, non-periodic modulation.
     pub fn new(sample_rate: f32) -> Self {
-        // Phase offsets for 4 voices (spread evenly across LFO cycle)
-        let phase_offsets = [0.0, 0.25, 0.5, 0.75];
-
-        // Rate variations for each voice (creates beating effect)
-        let rate_variations = [0.0, 0.1, -0.05, 0.15];
-
-        let voices_l: [ChorusVoice; NUM_VOICES] = std::array::from_fn(|i| {
-            ChorusVoice::new(sample_rate, phase_offsets[i], rate_variations[i])
-        });
-
-        let voices_r: [ChorusVoice; NUM_VOICES] = std::array::from_fn(|i| {
-            // Right channel has slightly different phases for stereo width
-            ChorusVoice::new(sample_rate, phase_offsets[i] + 0.125, rate_variations[i])
-        });
+        let voices_l: [ChorusVoice; MAX_VOICES] =
+            std::array::from_fn(|i| ChorusVoice::new(sample_rate, 0.0, RATE_VARIATIONS[i]));
+        let voices_r: [ChorusVoice; MAX_VOICES] =
+            std::array::from_fn(|i| ChorusVoice::new(sample_rate, 0.0, RATE_VARIATIONS[i]));
 
-        Self {
+        let mut chorus = Self {
             voices_l,
             voices_r,
             sample_rate,
             rate: 0.5,
             depth: 0.5,
             mix: 0.5,
-        }
+            voice_count: DEFAULT_VOICES,
+            spread: DEFAULT_SPREAD,
+            current_mix: 0.5,
+            mix_active: false,
+            mix_smooth_coeff: 1.0 - (-1.0 / (MIX_SMOOTH_MS * 0.001 * sample_rate)).exp(),
+        };
+        chorus.reset_phase();
+        chorus
     }
 
     /// Set LFO rate in Hz (0.1 to 5.0)
@@ -155,12 +176,11 @@ impl Chorus {
         self.rate = rate.clamp(0.1, 5.0);
 
         // Update all voices with their rate offsets
-        let rate_variations = [0.0, 0.1, -0.05, 0.15];
         for ((voice_l, voice_r), rate_variation) in self
             .voices_l
             .iter_mut()
             .zip(self.voices_r.iter_mut())
-            .zip(rate_variations.iter())
+            .zip(RATE_VARIATIONS.iter())
         {
             voice_l.set_rate(self.rate, *rate_variation);
             voice_r.set_rate(self.rate, *rate_variation);
@@ -173,23 +193,80 @@ impl Chorus {
     }
 
     /// Set wet/dry mix (0.0 = dry, 1.0 = full wet)
+    ///
+    /// The change is smoothed over ~`MIX_SMOOTH_MS` inside `process`, so stepped
+    /// (throttled) automation doesn't click.
     pub fn set_mix(&mut self, mix: f32) {
         self.mix = mix.clamp(0.0, 1.0);
+        if !self.mix_active {
+            self.current_mix = self.mix;
+        }
+    }
+
+    /// Set the number of chorus voices per channel (1 to 8, default 4)
+    ///
+    /// Changing the count re-spreads the voice LFO phases evenly across the cycle and
+    /// clears the delay lines of newly enabled voices. Calling this with the current
+    /// count is a no-op, so it's safe to call on every parameter update.
+    pub fn set_voices(&mut self, voices: usize) {
+        let voices = voices.clamp(1, MAX_VOICES);
+        if voices == self.voice_count {
+            return;
+        }
+        for i in self.voice_count..voices {
+            self.voices_l[i].clear();
+            self.voices_r[i].clear();
+        }
+        self.voice_count = voices;
+        self.reset_phase();
+    }
+
+    /// Get the number of active chorus voices per channel
+    pub fn voices(&self) -> usize {
+        self.voice_count
+    }
+
+    /// Set stereo spread (0.0 = identical L/R modulation, 1.0 = quadrature L/R)
+    ///
+    /// Shifts the right channel's LFOs relative to the left without touching the left
+    /// phases, so sweeping the spread doesn't jump the left channel's delay times.
+    pub fn set_spread(&mut self, spread: f32) {
+        let spread = spread.clamp(0.0, 1.0);
+        let shift = (spread - self.spread) * MAX_SPREAD_PHASE;
+        if shift == 0.0 {
+            return;
+        }
+        for voice_r in &mut self.voices_r {
+            voice_r.lfo_phase = (voice_r.lfo_phase + shift).rem_euclid(1.0);
+        }
+        self.spread = spread;
     }
 
     /// Reset LFO phase to 0.0 for all voices
     ///
+    /// Voice phases are spread evenly across the cycle (voice `i` of `n` starts at `i / n`),
+    /// with the right channel offset by the spread.
     /// This is called when tempo sync mode changes to ensure predictable timing.
     pub fn reset_phase(&mut self) {
-        let phase_offsets = [0.0, 0.25, 0.5, 0.75];
-        for ((voice_l, voice_r), phase_offset) in self
+        self.set_phase(0.0);
+    }
+
+    /// Jump the LFOs to a position in the cycle (0.0-1.0), keeping the voice spread
+    ///
+    /// Used to lock a tempo-synced chorus to the host timeline.
+    pub fn set_phase(&mut self, phase: f32) {
+        let phase = phase.rem_euclid(1.0);
+        let count = self.voice_count as f32;
+        let right_offset = self.spread * MAX_SPREAD_PHASE;
+        for (i, (voice_l, voice_r)) in self
             .voices_l
             .iter_mut()
             .zip(self.voices_r.iter_mut())
-            .zip(phase_offsets.iter())
+            .enumerate()
         {
-            voice_l.lfo_phase = *phase_offset;
-            voice_r.lfo_phase = *phase_offset + 0.125;
+            let phase_offset = (phase + i as f32 / count).fract();
+            voice_l.lfo_phase = phase_offset;
+            voice_r.lfo_phase = (phase_offset + right_offset).fract();
         }
     }
 
@@ -202,32 +279,40 @@ impl Chorus {
     /// # Returns
     /// Tuple of (left_output, right_output)
     pub fn process(&mut self, input_l: f32, input_r: f32) -> (f32, f32) {
-        // Process through all chorus voices and sum
+        // Process through the active chorus voices and sum
         let mut chorus_l = 0.0;
         let mut chorus_r = 0.0;
 
-        for (voice_l, voice_r) in self.voices_l.iter_mut().zip(self.voices_r.iter_mut()) {
+        for (voice_l, voice_r) in self.voices_l[..self.voice_count]
+            .iter_mut()
+            .zip(self.voices_r[..self.voice_count].iter_mut())
+        {
             chorus_l += voice_l.process(input_l, self.depth);
             chorus_r += voice_r.process(input_r, self.depth);
         }
 
         // Normalize by number of voices
-        chorus_l /= NUM_VOICES as f32;
-        chorus_r /= NUM_VOICES as f32;
+        chorus_l /= self.voice_count as f32;
+        chorus_r /= self.voice_count as f32;
 
         // Mix wet and dry
-        let output_l = input_l * (1.0 - self.mix) + chorus_l * self.mix;
-        let output_r = input_r * (1.0 - self.mix) + chorus_r * self.mix;
+        self.mix_active = true;
+        self.current_mix += (self.mix - self.current_mix) * self.mix_smooth_coeff;
+        let mix = self.current_mix;
+        let output_l = input_l * (1.0 - mix) + chorus_l * mix;
+        let output_r = input_r * (1.0 - mix) + chorus_r * mix;
 
         (output_l, output_r)
     }
 
     /// Clear all delay buffers
     pub fn clear(&mut self) {
-        for i in 0..NUM_VOICES {
+        for i in 0..MAX_VOICES {
             self.voices_l[i].clear();
             self.voices_r[i].clear();
         }
+        self.current_mix = self.mix;
+        self.mix_active = false;
     }
 }
 
@@ -384,4 +469,45 @@ mod tests {
             different_count
         );
     }
+
+    /// Pearson correlation between the wet L/R outputs for a mono noise input
+    fn wet_correlation(voices: usize, spread: f32) -> f32 {
+        let mut chorus = Chorus::new(44100.0);
+        chorus.set_voices(voices);
+        chorus.set_spread(spread);
+        chorus.set_depth(1.0);
+        chorus.set_rate(1.0);
+        chorus.set_mix(1.0);
+
+        let mut state = 0x1234_5678u32;
+        let (mut sum_lr, mut sum_ll, mut sum_rr) = (0.0f64, 0.0f64, 0.0f64);
+        for n in 0..44100 {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            let input = (state as f32 / u32::MAX as f32) * 2.0 - 1.0;
+            let (l, r) = chorus.process(input, input);
+            if n >= 4410 {
+                sum_lr += (l * r) as f64;
+                sum_ll += (l * l) as f64;
+                sum_rr += (r * r) as f64;
+            }
+        }
+        (sum_lr / (sum_ll * sum_rr).sqrt()) as f32
+    }
+
+    #[test]
+    fn test_chorus_voices_and_spread_decorrelate() {
+        assert_eq!(Chorus::new(44100.0).voices(), 4);
+
+        let mono = wet_correlation(1, 0.0);
+        let wide = wet_correlation(1, 1.0);
+        let ensemble = wet_correlation(8, 1.0);
+        assert!(mono > 0.99, "No spread should keep L/R identical: {mono}");
+        assert!(wide < mono - 0.2, "Spread should decorrelate L/R: {wide}");
+        assert!(
+            ensemble < mono - 0.2,
+            "Ensemble should stay decorrelated: {ensemble}"
+        );
+    }
 }
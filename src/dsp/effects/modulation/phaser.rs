@@ -128,6 +128,11 @@ impl Phaser {
         self.lfo_phase = 0.0;
     }
 
+    /// Jump the LFO to a position in its cycle (0.0-1.0), e.g. to follow the host timeline
+    pub fn set_phase(&mut self, phase: f32) {
+        self.lfo_phase = phase.rem_euclid(1.0);
+    }
+
     /// Process a stereo sample through the phaser
     pub fn process(&mut self, left: f32, right: f32) -> (f32, f32) {
         // Generate LFO modulation (sine wave)
@@ -72,6 +72,16 @@ impl Tremolo {
         self.lfo_phase = 0.0;
     }
 
+    /// Current position of the LFO in its cycle (0.0-1.0)
+    pub fn phase(&self) -> f32 {
+        self.lfo_phase
+    }
+
+    /// Jump the LFO to a position in its cycle (0.0-1.0), e.g. to follow the host timeline
+    pub fn set_phase(&mut self, phase: f32) {
+        self.lfo_phase = phase.rem_euclid(1.0);
+    }
+
     /// Process a stereo sample through the tremolo
     pub fn process(&mut self, left: f32, right: f32) -> (f32, f32) {
         // Generate LFO values for left and right channels
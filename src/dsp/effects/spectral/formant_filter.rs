@@ -0,0 +1,288 @@
+/// Formant Filter - Imposes vowel resonances ("talk box" / vocal textures)
+///
+/// The vocal tract shapes a voice with a handful of resonances (formants) whose positions
+/// identify the vowel. This effect imitates that with three parallel bandpass filters per
+/// channel, tuned to the first three formants of the selected vowel and summed with
+/// per-formant gains. The frequencies are the same classic F1/F2/F3 values the
+/// `FormantDetector` uses to classify vowels (e.g. "ah" ≈ 650/1080/2650 Hz).
+///
+/// # Parameters
+/// - **vowel**: Base vowel (A, E, I, O, U)
+/// - **morph**: Glide from the base vowel toward the next one (0.0 = base, 1.0 = next;
+///   U morphs back to A), interpolating formant frequencies, bandwidths and gains
+/// - **mix**: Wet/dry balance (0.0 = dry, 1.0 = formants only)
+use crate::dsp::filters::BiquadFilter;
+use crate::params::{FilterType, Vowel};
+
+/// Formants per vowel
+const FORMANT_COUNT: usize = 3;
+
+/// Makeup gain for the filtered signal: narrow bands keep only a fraction of a
+/// broadband synth's energy
+const FORMANT_MAKEUP_GAIN: f32 = 2.0;
+
+/// One formant: centre frequency (Hz), bandwidth (Hz), gain (dB relative to F1)
+#[derive(Debug, Clone, Copy)]
+struct Formant {
+    frequency: f32,
+    bandwidth: f32,
+    gain_db: f32,
+}
+
+const fn formant(frequency: f32, bandwidth: f32, gain_db: f32) -> Formant {
+    Formant {
+        frequency,
+        bandwidth,
+        gain_db,
+    }
+}
+
+/// F1-F3 of each vowel (adult male voice), in `Vowel` order
+const VOWEL_FORMANTS: [[Formant; FORMANT_COUNT]; 5] = [
+    // A ("father")
+    [
+        formant(650.0, 80.0, 0.0),
+        formant(1080.0, 90.0, -6.0),
+        formant(2650.0, 120.0, -7.0),
+    ],
+    // E ("bed")
+    [
+        formant(400.0, 70.0, 0.0),
+        formant(1700.0, 80.0, -10.0),
+        formant(2600.0, 100.0, -12.0),
+    ],
+    // I ("see")
+    [
+        formant(290.0, 40.0, 0.0),
+        formant(1870.0, 90.0, -12.0),
+        formant(2800.0, 100.0, -15.0),
+    ],
+    // O ("law")
+    [
+        formant(400.0, 40.0, 0.0),
+        formant(800.0, 80.0, -8.0),
+        formant(2600.0, 100.0, -12.0),
+    ],
+    // U ("boot")
+    [
+        formant(350.0, 40.0, 0.0),
+        formant(600.0, 80.0, -10.0),
+        formant(2700.0, 100.0, -15.0),
+    ],
+];
+
+fn vowel_index(vowel: Vowel) -> usize {
+    match vowel {
+        Vowel::A => 0,
+        Vowel::E => 1,
+        Vowel::I => 2,
+        Vowel::O => 3,
+        Vowel::U => 4,
+    }
+}
+
+pub struct FormantFilter {
+    sample_rate: f32,
+    vowel: Vowel,
+    morph: f32, // 0.0 to 1.0
+    mix: f32,   // 0.0 to 1.0
+
+    // Parallel formant bandpasses per channel
+    left: [BiquadFilter; FORMANT_COUNT],
+    right: [BiquadFilter; FORMANT_COUNT],
+
+    /// Linear output gain per formant, including the bandpass peak normalization
+    gains: [f32; FORMANT_COUNT],
+}
+
+impl FormantFilter {
+    /// Create a new formant filter
+    ///
+    /// # Arguments
+    /// * `sample_rate` - Audio sample rate in Hz
+    pub fn new(sample_rate: f32) -> Self {
+        let bandpass = || {
+            let mut filter = BiquadFilter::new(sample_rate);
+            filter.set_filter_type(FilterType::Bandpass);
+            // Formants only move on parameter changes, so never defer a cutoff update
+            filter.set_cutoff_update_interval(1);
+            filter
+        };
+        let mut formant_filter = Self {
+            sample_rate,
+            vowel: Vowel::A,
+            morph: 0.0,
+            mix: 1.0,
+            left: std::array::from_fn(|_| bandpass()),
+            right: std::array::from_fn(|_| bandpass()),
+            gains: [0.0; FORMANT_COUNT],
+        };
+        formant_filter.update_formants();
+        formant_filter
+    }
+
+    /// Select the base vowel
+    pub fn set_vowel(&mut self, vowel: Vowel) {
+        if self.vowel != vowel {
+            self.vowel = vowel;
+            self.update_formants();
+        }
+    }
+
+    /// Set the morph toward the next vowel (0.0 to 1.0)
+    pub fn set_morph(&mut self, morph: f32) {
+        let morph = morph.clamp(0.0, 1.0);
+        if (self.morph - morph).abs() > 0.001 {
+            self.morph = morph;
+            self.update_formants();
+        }
+    }
+
+    /// Set wet/dry mix (0.0 = dry, 1.0 = formants only)
+    pub fn set_mix(&mut self, mix: f32) {
+        self.mix = mix.clamp(0.0, 1.0);
+    }
+
+    /// Retune the bandpasses to the current vowel and morph position
+    fn update_formants(&mut self) {
+        let from = vowel_index(self.vowel);
+        let to = (from + 1) % VOWEL_FORMANTS.len();
+        let t = self.morph;
+
+        let pairs = VOWEL_FORMANTS[from].iter().zip(&VOWEL_FORMANTS[to]);
+        for (i, (a, b)) in pairs.enumerate() {
+            // Frequencies glide geometrically so the sweep sounds even
+            let frequency = a.frequency * (b.frequency / a.frequency).powf(t);
+            let bandwidth = a.bandwidth + (b.bandwidth - a.bandwidth) * t;
+            let gain_db = a.gain_db + (b.gain_db - a.gain_db) * t;
+
+            // Bandwidth in octaves for a centre frequency / bandwidth ratio of Q
+            let q = frequency / bandwidth;
+            let octaves = 2.0 * (1.0 / (2.0 * q)).asinh() / std::f32::consts::LN_2;
+
+            for filter in [&mut self.left[i], &mut self.right[i]] {
+                filter.set_cutoff(frequency);
+                filter.set_bandwidth(octaves);
+            }
+
+            // The bandpass peaks at its effective Q; scale that back to unity
+            let omega = 2.0 * std::f32::consts::PI * frequency / self.sample_rate;
+            let effective_octaves = octaves.clamp(0.1, 4.0) * omega / omega.sin();
+            let peak_normalization =
+                2.0 * (std::f32::consts::LN_2 / 2.0 * effective_octaves).sinh();
+
+            self.gains[i] =
+                10.0_f32.powf(gain_db / 20.0) * peak_normalization * FORMANT_MAKEUP_GAIN;
+        }
+    }
+
+    #[inline]
+    fn formants(
+        filters: &mut [BiquadFilter; FORMANT_COUNT],
+        gains: &[f32; FORMANT_COUNT],
+        input: f32,
+    ) -> f32 {
+        filters
+            .iter_mut()
+            .zip(gains)
+            .map(|(filter, gain)| filter.process(input) * gain)
+            .sum()
+    }
+
+    /// Process stereo audio
+    ///
+    /// # Arguments
+    /// * `left` - Left channel input sample
+    /// * `right` - Right channel input sample
+    ///
+    /// # Returns
+    /// Tuple of (left_out, right_out)
+    pub fn process(&mut self, left: f32, right: f32) -> (f32, f32) {
+        let wet_left = Self::formants(&mut self.left, &self.gains, left);
+        let wet_right = Self::formants(&mut self.right, &self.gains, right);
+
+        (
+            left + (wet_left - left) * self.mix,
+            right + (wet_right - right) * self.mix,
+        )
+    }
+
+    /// Reset filter state
+    pub fn clear(&mut self) {
+        for filter in self.left.iter_mut().chain(self.right.iter_mut()) {
+            filter.reset();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::PI;
+
+    const SAMPLE_RATE: f32 = 44100.0;
+
+    /// RMS output of a steady sine once the filters have settled
+    fn response(filter: &mut FormantFilter, freq: f32) -> f32 {
+        filter.clear();
+        let mut sum = 0.0;
+        for i in 0..8192 {
+            let input = (2.0 * PI * freq * i as f32 / SAMPLE_RATE).sin();
+            let (left, _) = filter.process(input, input);
+            if i >= 4096 {
+                sum += left * left;
+            }
+        }
+        (sum / 4096.0).sqrt()
+    }
+
+    #[test]
+    fn test_formant_peaks_per_vowel() {
+        for vowel in [Vowel::A, Vowel::E, Vowel::I, Vowel::O, Vowel::U] {
+            let mut filter = FormantFilter::new(SAMPLE_RATE);
+            filter.set_vowel(vowel);
+            let formants = VOWEL_FORMANTS[vowel_index(vowel)];
+
+            // F1 and F2 should each be the loudest point of their neighbourhood
+            for target in formants.iter().take(2).map(|f| f.frequency) {
+                let (peak_freq, _) = (0..=48)
+                    .map(|step| target * 2.0_f32.powf((step as f32 - 24.0) / 72.0))
+                    .map(|freq| (freq, response(&mut filter, freq)))
+                    .fold((0.0, 0.0), |best, (freq, level)| {
+                        if level > best.1 {
+                            (freq, level)
+                        } else {
+                            best
+                        }
+                    });
+                assert!(
+                    (peak_freq / target - 1.0).abs() < 0.05,
+                    "{:?}: expected a formant peak at {} Hz, found {} Hz",
+                    vowel,
+                    target,
+                    peak_freq
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_morph_moves_formants_toward_next_vowel() {
+        let mut filter = FormantFilter::new(SAMPLE_RATE);
+        filter.set_vowel(Vowel::A);
+
+        // A's F2 (1080 Hz) gives way to E's F2 (1700 Hz) as the morph opens
+        let a_f2 = response(&mut filter, 1080.0);
+        filter.set_morph(1.0);
+        assert!(response(&mut filter, 1700.0) > response(&mut filter, 1080.0));
+        assert!(a_f2 > response(&mut filter, 1080.0) * 2.0);
+    }
+
+    #[test]
+    fn test_dry_mix_passes_input() {
+        let mut filter = FormantFilter::new(SAMPLE_RATE);
+        filter.set_mix(0.0);
+        let (left, right) = filter.process(0.5, -0.3);
+        assert_eq!((left, right), (0.5, -0.3));
+    }
+}
@@ -454,4 +454,43 @@ mod tests {
         let diff = (out_l - out_r).abs();
         assert!(diff < 0.2, "Stereo difference should be subtle");
     }
+
+    #[test]
+    fn test_adds_less_harshness_than_fixed_exciter_on_bright_input() {
+        use crate::dsp::effects::Exciter;
+        use crate::dsp::signal_analyzer::SignalAnalyzer;
+        use crate::dsp::synthesis::waveform::{u32_to_f32_bipolar, xorshift32};
+
+        let mut analyzer = SignalAnalyzer::new(44100.0);
+        let mut intelligent = IntelligentExciter::new(44100.0);
+        intelligent.set_amount(0.8);
+        intelligent.set_mix(1.0);
+        let mut fixed = Exciter::new(44100.0);
+        fixed.set_drive(0.8);
+        fixed.set_mix(1.0);
+
+        // White noise: already bright, so any added top end is just harshness
+        let mut noise_state = 0x12345678;
+        let mut added_intelligent = 0.0;
+        let mut added_fixed = 0.0;
+        for _ in 0..44100 {
+            let input = 0.5 * u32_to_f32_bipolar(xorshift32(&mut noise_state));
+            let analysis = analyzer.analyze(input, input);
+            let (intelligent_out, _) = intelligent.process(input, input, &analysis);
+            let (fixed_out, _) = fixed.process(input, input);
+            added_intelligent += (intelligent_out - input).powi(2);
+            added_fixed += (fixed_out - input).powi(2);
+        }
+
+        assert!(
+            added_fixed > 0.0,
+            "Fixed exciter should add harmonics to noise"
+        );
+        assert!(
+            added_intelligent < added_fixed * 0.1,
+            "Intelligent exciter added {} vs fixed {}",
+            added_intelligent,
+            added_fixed
+        );
+    }
 }
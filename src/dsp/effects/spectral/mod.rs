@@ -2,15 +2,17 @@
 
 pub mod crossover;
 pub mod exciter;
+pub mod formant_filter;
 pub mod intelligent_exciter;
 pub mod parametric_eq;
 pub mod pitch_shifter;
 
 pub use crossover::LR2Crossover;
 pub use exciter::Exciter;
+pub use formant_filter::FormantFilter;
 pub use intelligent_exciter::IntelligentExciter;
 pub use parametric_eq::{EQBand, ParametricEQ};
-pub use pitch_shifter::PitchShifter;
+pub use pitch_shifter::{PitchShifter, StereoPitchShifter};
 
 // Aliases for convenience
 pub use crossover::LR2Crossover as Crossover;
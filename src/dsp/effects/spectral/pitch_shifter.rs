@@ -1,7 +1,7 @@
 /// Real-Time Pitch Shifter
 ///
 /// Uses a simple but effective delay-based pitch shifting algorithm.
-/// This works by modulating read position in a delay buffer to create pitch shifts.
+/// This works by sweeping the read position of a delay buffer to create pitch shifts.
 ///
 /// For subtle pitch correction (auto-tune), this approach is sufficient and avoids
 /// the complexity and artifacts of granular/PSOLA methods. The key insight is that
@@ -9,12 +9,18 @@
 ///
 /// Algorithm:
 /// 1. Write input to circular delay buffer
-/// 2. Read from buffer at variable rate to create pitch shift
-/// 3. Use linear interpolation for fractional sample positions
-/// 4. Smooth read rate changes to avoid clicks
+/// 2. Read two taps whose delay sweeps at `1 - ratio` samples per sample (the Doppler
+///    shift gives the new pitch), half a window apart
+/// 3. When a tap's delay runs out of the window it jumps back; a Hann crossfade between
+///    the taps hides the jump
+/// 4. Use linear interpolation for fractional sample positions
+/// 5. Smooth read rate changes to avoid clicks
 
 const BUFFER_SIZE_MS: f32 = 50.0; // 50ms circular buffer
 
+/// Length of the tap sweep; the taps sit between 0 and this far behind the input
+const WINDOW_MS: f32 = 40.0;
+
 #[allow(dead_code)]
 pub struct PitchShifter {
     sample_rate: f32,
@@ -23,7 +29,11 @@ pub struct PitchShifter {
     buffer: Vec<f32>,
     buffer_size: usize,
     write_pos: f32,
-    read_pos: f32,
+
+    /// Sweep length in samples
+    window: f32,
+    /// Position of the first tap in its sweep (0.0 to 1.0); the second is half a sweep on
+    sweep_phase: f32,
 
     // Target read rate (how fast to advance through buffer)
     target_read_rate: f32,
@@ -36,20 +46,25 @@ pub struct PitchShifter {
 impl PitchShifter {
     pub fn new(sample_rate: f32) -> Self {
         let buffer_size = ((sample_rate * BUFFER_SIZE_MS / 1000.0) as usize).next_power_of_two();
-        let initial_delay = buffer_size as f32 / 2.0; // Start in middle of buffer
 
         Self {
             sample_rate,
             buffer: vec![0.0; buffer_size],
             buffer_size,
             write_pos: 0.0,
-            read_pos: initial_delay,
+            window: sample_rate * WINDOW_MS / 1000.0,
+            sweep_phase: 0.0,
             target_read_rate: 1.0,
             current_read_rate: 1.0,
             rate_smoothing: 0.999, // Very smooth rate changes
         }
     }
 
+    /// Average delay of the shifted signal in samples (half a sweep)
+    pub fn latency_samples(&self) -> u32 {
+        (self.window / 2.0).round() as u32
+    }
+
     /// Process a single sample with pitch shifting
     ///
     /// # Arguments
@@ -82,56 +97,50 @@ impl PitchShifter {
             return input;
         }
 
-        // Clamp to reasonable range (±1 octave)
-        let pitch_ratio = pitch_ratio.clamp(0.5, 2.0);
-
-        // Write input to buffer
-        let write_idx = self.write_pos as usize % self.buffer_size;
-        self.buffer[write_idx] = input;
-        self.write_pos = (self.write_pos + 1.0) % self.buffer_size as f32;
-
         // Update target read rate and smooth towards it
         self.target_read_rate = pitch_ratio;
         self.current_read_rate = self.rate_smoothing * self.current_read_rate
             + (1.0 - self.rate_smoothing) * self.target_read_rate;
 
-        // Read from buffer with linear interpolation
-        let output = self.read_interpolated();
+        let output = self.shift(input, self.current_read_rate);
+
+        // Mix with dry signal
+        input * (1.0 - mix) + output * mix
+    }
 
-        // Advance read position at current rate
-        self.read_pos += self.current_read_rate;
+    /// Shift one sample by a fixed ratio and return the wet signal only
+    ///
+    /// # Arguments
+    /// * `input` - Input audio sample
+    /// * `ratio` - Pitch ratio, clamped to ±1 octave (0.5 to 2.0)
+    pub fn shift(&mut self, input: f32, ratio: f32) -> f32 {
+        let ratio = ratio.clamp(0.5, 2.0);
 
-        // Keep read position within buffer bounds
-        while self.read_pos >= self.buffer_size as f32 {
-            self.read_pos -= self.buffer_size as f32;
-        }
-        while self.read_pos < 0.0 {
-            self.read_pos += self.buffer_size as f32;
-        }
+        // Write input to buffer
+        let write_idx = self.write_pos as usize % self.buffer_size;
+        self.buffer[write_idx] = input;
 
-        // Maintain safe distance from write position (prevent buffer underrun/overrun)
-        let distance =
-            (self.write_pos - self.read_pos + self.buffer_size as f32) % self.buffer_size as f32;
+        // Two taps half a sweep apart; each fades in and out with a Hann window
+        let phase_b = (self.sweep_phase + 0.5).fract();
+        let gain_a = (std::f32::consts::PI * self.sweep_phase).sin().powi(2);
+        let gain_b = 1.0 - gain_a;
+        let output = self.read_delayed(self.sweep_phase * self.window) * gain_a
+            + self.read_delayed(phase_b * self.window) * gain_b;
 
-        // If we're getting too close to write position, nudge read position back
-        let min_distance = self.buffer_size as f32 * 0.1; // 10% minimum
-        let max_distance = self.buffer_size as f32 * 0.9; // 90% maximum
+        self.write_pos = (self.write_pos + 1.0) % self.buffer_size as f32;
 
-        if distance < min_distance {
-            self.read_pos -= 1.0;
-        } else if distance > max_distance {
-            self.read_pos += 1.0;
-        }
+        // Reading faster than writing shrinks the delay (pitch up), slower grows it
+        self.sweep_phase = (self.sweep_phase + (1.0 - ratio) / self.window).rem_euclid(1.0);
 
-        // Mix with dry signal
-        input * (1.0 - mix) + output * mix
+        output
     }
 
-    /// Read from buffer with linear interpolation for smooth pitch shifting
-    fn read_interpolated(&self) -> f32 {
-        let idx0 = self.read_pos.floor() as usize % self.buffer_size;
+    /// Read `delay` samples behind the last written sample, with linear interpolation
+    fn read_delayed(&self, delay: f32) -> f32 {
+        let read_pos = (self.write_pos - delay).rem_euclid(self.buffer_size as f32);
+        let idx0 = read_pos.floor() as usize % self.buffer_size;
         let idx1 = (idx0 + 1) % self.buffer_size;
-        let frac = self.read_pos.fract();
+        let frac = read_pos.fract();
 
         // Linear interpolation
         self.buffer[idx0] * (1.0 - frac) + self.buffer[idx1] * frac
@@ -140,14 +149,73 @@ impl PitchShifter {
     /// Reset the pitch shifter state
     pub fn reset(&mut self) {
         self.buffer.fill(0.0);
-        let initial_delay = self.buffer_size as f32 / 2.0;
         self.write_pos = 0.0;
-        self.read_pos = initial_delay;
+        self.sweep_phase = 0.0;
         self.target_read_rate = 1.0;
         self.current_read_rate = 1.0;
     }
 }
 
+/// Stereo pitch shifter for the master chain (harmonizer-style thickening)
+///
+/// Shifts both channels by a fixed interval and blends the result with the dry signal.
+pub struct StereoPitchShifter {
+    left: PitchShifter,
+    right: PitchShifter,
+    ratio: f32,
+    mix: f32, // 0.0 to 1.0
+}
+
+impl StereoPitchShifter {
+    pub fn new(sample_rate: f32) -> Self {
+        Self {
+            left: PitchShifter::new(sample_rate),
+            right: PitchShifter::new(sample_rate),
+            ratio: 1.0,
+            mix: 0.5,
+        }
+    }
+
+    /// Set the shift interval (-12.0 to 12.0 semitones)
+    pub fn set_semitones(&mut self, semitones: f32) {
+        self.ratio = 2.0_f32.powf(semitones.clamp(-12.0, 12.0) / 12.0);
+    }
+
+    /// Set wet/dry mix (0.0 = dry, 1.0 = shifted only)
+    pub fn set_mix(&mut self, mix: f32) {
+        self.mix = mix.clamp(0.0, 1.0);
+    }
+
+    /// Delay of the shifted signal in samples
+    pub fn latency_samples(&self) -> u32 {
+        self.left.latency_samples()
+    }
+
+    /// Process stereo audio
+    ///
+    /// # Arguments
+    /// * `left` - Left channel input sample
+    /// * `right` - Right channel input sample
+    ///
+    /// # Returns
+    /// Tuple of (left_out, right_out)
+    pub fn process(&mut self, left: f32, right: f32) -> (f32, f32) {
+        let wet_left = self.left.shift(left, self.ratio);
+        let wet_right = self.right.shift(right, self.ratio);
+
+        (
+            left + (wet_left - left) * self.mix,
+            right + (wet_right - right) * self.mix,
+        )
+    }
+
+    /// Reset the delay buffers
+    pub fn clear(&mut self) {
+        self.left.reset();
+        self.right.reset();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -202,6 +270,48 @@ mod tests {
         assert!(shifter.buffer.iter().all(|&x| x == 0.0));
     }
 
+    #[test]
+    fn test_stereo_shifter_transposes_by_semitones() {
+        let sample_rate = 44100.0;
+        let mut shifter = StereoPitchShifter::new(sample_rate);
+        shifter.set_semitones(12.0);
+        shifter.set_mix(1.0);
+
+        let output: Vec<f32> = (0..(2 * sample_rate as usize))
+            .map(|i| {
+                let input = (2.0 * std::f32::consts::PI * 250.0 * i as f32 / sample_rate).sin();
+                let (left, right) = shifter.process(input, input);
+                assert_eq!(left, right);
+                left
+            })
+            .skip(sample_rate as usize)
+            .collect();
+
+        // Magnitude of one frequency component (single DFT bin)
+        let magnitude = |freq: f32| {
+            let (re, im) = output
+                .iter()
+                .enumerate()
+                .fold((0.0, 0.0), |(re, im), (i, x)| {
+                    let phase = 2.0 * std::f32::consts::PI * freq * i as f32 / sample_rate;
+                    (re + x * phase.cos(), im + x * phase.sin())
+                });
+            (re * re + im * im).sqrt() / output.len() as f32
+        };
+        // 250 Hz puts a whole number of cycles between the taps, so the handovers
+        // don't slip phase and the shifted tone is a clean 500 Hz
+        let octave_up = magnitude(500.0);
+        assert!(
+            octave_up > magnitude(250.0) * 4.0,
+            "Shifted signal should sit an octave up"
+        );
+        assert!(octave_up > magnitude(375.0) * 4.0);
+        assert_eq!(
+            shifter.latency_samples(),
+            (sample_rate * WINDOW_MS / 1000.0 / 2.0).round() as u32
+        );
+    }
+
     #[test]
     fn test_small_pitch_corrections() {
         let mut shifter = PitchShifter::new(44100.0);
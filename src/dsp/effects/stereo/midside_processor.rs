@@ -76,6 +76,18 @@ impl MidSideStereoProcessor {
         (left_out, right_out)
     }
 
+    /// Split a stereo pair into mid (centre) and side (difference)
+    #[inline]
+    pub fn encode(left: f32, right: f32) -> (f32, f32) {
+        ((left + right) * 0.5, (left - right) * 0.5)
+    }
+
+    /// Rebuild a stereo pair from mid and side
+    #[inline]
+    pub fn decode(mid: f32, side: f32) -> (f32, f32) {
+        (mid + side, mid - side)
+    }
+
     /// Process a single frequency band with mid/side stereo width adjustment
     ///
     /// # Arguments
@@ -88,8 +100,7 @@ impl MidSideStereoProcessor {
     #[inline]
     fn process_band_midside(left: f32, right: f32, width: f32) -> (f32, f32) {
         // Convert to mid/side
-        let mid = (left + right) * 0.5; // Mono-compatible center
-        let side = (left - right) * 0.5; // Stereo difference
+        let (mid, side) = Self::encode(left, right);
 
         // For wide settings and moderate stereo content, add spatial enhancement
         let enhanced_side = if width > 1.6 && side.abs() > 0.001 && side.abs() < 0.4 {
@@ -104,10 +115,7 @@ impl MidSideStereoProcessor {
         let side_processed = enhanced_side * width;
 
         // Convert back to L/R
-        let left_out = mid + side_processed;
-        let right_out = mid - side_processed;
-
-        (left_out, right_out)
+        Self::decode(mid, side_processed)
     }
 
     /// Derive per-band stereo widths from global stereo_width parameter
@@ -12,15 +12,50 @@
 //!
 //! # Parameters
 //! - **time_ms**: Delay time in milliseconds (1.0 to 2000.0)
-//! - **feedback**: Amount of repeats (0.0 to 0.95)
+//! - **feedback**: Amount of repeats (0.0 to 0.95), remapped by the `FeedbackCurve`
 //! - **wet**: Delay signal level (0.0 = dry, 1.0 = full wet)
 //! - **dry**: Direct signal level (0.0 = none, 1.0 = full dry)
 //!
+//! # Multi-Tap Mode
+//! `set_taps(&[(time_ms, gain, pan)])` replaces the single ping-pong echo with up to
+//! `MAX_TAPS` taps read from the same buffers. The taps' sum feeds a shared feedback path
+//! (scaled so the loop gain never exceeds `feedback`), enabling rhythmic patterns.
+//! Passing an empty slice returns to the default single-tap ping-pong path.
+//!
+//! # Interpolation
+//! By default the delay time is rounded down to whole samples. With
+//! `set_cubic_interpolation(true)` (High quality) the fractional part is read with 4-point
+//! Hermite interpolation, so tempo-synced and automated times land exactly.
+//!
+//! # Feedback Curve
+//! Repeat count grows like 1/(1 - feedback), so with a linear knob the last few percent
+//! jump from a handful of echoes to near-infinite. `FeedbackCurve::Perceptual` maps the
+//! knob to the number of repeats before the echoes decay by 60 dB, spaced exponentially
+//! from 1 repeat up to the maximum feedback's ~135, then converts back to a gain.
+//!
 //! # Real-Time Safety
 //! Delay buffer is pre-allocated to maximum size (2 seconds at sample rate).
 //! No allocations happen during `process()`.
 
-const MAX_DELAY_MS: f32 = 2000.0;
+use crate::params::FeedbackCurve;
+
+/// Maximum delay time (buffer length) in milliseconds
+pub const MAX_DELAY_MS: f32 = 2000.0;
+
+/// Maximum feedback gain (keeps the loop stable)
+const MAX_FEEDBACK: f32 = 0.95;
+
+/// Maximum number of taps in multi-tap mode
+pub const MAX_TAPS: usize = 8;
+
+/// A single read tap in multi-tap mode (time pre-converted to samples, pan to gains)
+#[derive(Debug, Clone, Copy, Default)]
+struct DelayTap {
+    delay_samples: usize,
+    gain_l: f32,
+    gain_r: f32,
+    feedback_gain: f32,
+}
 
 /// Stereo ping-pong delay processor
 pub struct StereoDelay {
@@ -33,9 +68,17 @@ pub struct StereoDelay {
     // Parameters
     time_ms: f32,
     delay_samples: usize,
-    feedback: f32,
+    delay_frac: f32, // Fractional part of the delay time (used by cubic interpolation)
+    cubic: bool,     // Read fractional delay times with cubic interpolation
+    feedback_amount: f32, // Knob value, before the curve
+    feedback_curve: FeedbackCurve,
+    feedback: f32, // Effective loop gain
     wet: f32,
     dry: f32,
+
+    // Multi-tap mode (tap_count == 0 means single-tap ping-pong)
+    taps: [DelayTap; MAX_TAPS],
+    tap_count: usize,
 }
 
 impl StereoDelay {
@@ -58,23 +101,125 @@ impl StereoDelay {
             max_samples,
             time_ms: 500.0,
             delay_samples: (sample_rate * 0.5) as usize, // 500ms default
+            delay_frac: (sample_rate * 0.5).fract(),
+            cubic: false,
+            feedback_amount: 0.3,
+            feedback_curve: FeedbackCurve::Linear,
             feedback: 0.3,
             wet: 0.3,
             dry: 0.7,
+            taps: [DelayTap::default(); MAX_TAPS],
+            tap_count: 0,
         }
     }
 
     /// Set delay time in milliseconds (1.0 to 2000.0)
     pub fn set_time(&mut self, time_ms: f32) {
         self.time_ms = time_ms.clamp(1.0, MAX_DELAY_MS);
-        self.delay_samples = (self.sample_rate * self.time_ms / 1000.0) as usize;
-        self.delay_samples = self.delay_samples.min(self.max_samples - 1);
+        let exact = self.sample_rate * self.time_ms / 1000.0;
+        self.delay_samples = exact as usize;
+        self.delay_frac = exact.fract();
+        if self.delay_samples >= self.max_samples - 1 {
+            self.delay_samples = self.max_samples - 1;
+            self.delay_frac = 0.0;
+        }
+    }
+
+    /// Read fractional delay times with cubic interpolation (default: whole samples)
+    pub fn set_cubic_interpolation(&mut self, enabled: bool) {
+        self.cubic = enabled;
     }
 
     /// Set feedback amount (0.0 to 0.95)
-    /// Higher values create more repeats but can become unstable above 0.95
+    /// Higher values create more repeats but can become unstable above 0.95.
+    /// The value is remapped by the current `FeedbackCurve`.
     pub fn set_feedback(&mut self, feedback: f32) {
-        self.feedback = feedback.clamp(0.0, 0.95);
+        self.feedback_amount = feedback.clamp(0.0, MAX_FEEDBACK);
+        self.feedback = Self::map_feedback(self.feedback_amount, self.feedback_curve);
+    }
+
+    /// Set how the feedback amount maps to the loop gain (default: linear)
+    pub fn set_feedback_curve(&mut self, curve: FeedbackCurve) {
+        self.feedback_curve = curve;
+        self.feedback = Self::map_feedback(self.feedback_amount, curve);
+    }
+
+    /// Map a feedback knob amount (0.0 to 0.95) to the loop gain for `curve`
+    fn map_feedback(amount: f32, curve: FeedbackCurve) -> f32 {
+        match curve {
+            FeedbackCurve::Linear => amount,
+            FeedbackCurve::Perceptual => {
+                if amount <= 0.0 {
+                    return 0.0;
+                }
+                // Repeats until -60 dB: n = ln(0.001) / ln(g), so g = 0.001^(1/n)
+                let max_repeats = 0.001f32.ln() / MAX_FEEDBACK.ln();
+                let repeats = max_repeats.powf(amount / MAX_FEEDBACK);
+                0.001f32.powf(1.0 / repeats)
+            }
+        }
+    }
+
+    /// Configure multi-tap mode
+    ///
+    /// Each tap is `(time_ms, gain, pan)`: time is clamped like `set_time`, gain to
+    /// 0.0..1.0 and pan to -1.0 (left) ..1.0 (right). Only the first `MAX_TAPS` entries
+    /// are used. An empty slice restores the single-tap ping-pong path.
+    ///
+    /// # Feedback Safety
+    /// All taps feed one shared feedback path. Tap gains are normalized by their sum
+    /// (when it exceeds 1.0) on that path, so the recirculating gain is at most
+    /// `feedback` (≤ 0.95) and the delay can never run away.
+    pub fn set_taps(&mut self, taps: &[(f32, f32, f32)]) {
+        let count = taps.len().min(MAX_TAPS);
+        let gain_sum: f32 = taps[..count]
+            .iter()
+            .map(|&(_, gain, _)| gain.clamp(0.0, 1.0))
+            .sum();
+        let feedback_norm = 1.0 / gain_sum.max(1.0);
+
+        for (slot, &(time_ms, gain, pan)) in self.taps.iter_mut().zip(&taps[..count]) {
+            let time_ms = time_ms.clamp(1.0, MAX_DELAY_MS);
+            let gain = gain.clamp(0.0, 1.0);
+            let pan = pan.clamp(-1.0, 1.0);
+            *slot = DelayTap {
+                delay_samples: ((self.sample_rate * time_ms / 1000.0) as usize)
+                    .min(self.max_samples - 1),
+                // Balance law: center is unity on both sides
+                gain_l: gain * (1.0 - pan).min(1.0),
+                gain_r: gain * (1.0 + pan).min(1.0),
+                feedback_gain: gain * feedback_norm,
+            };
+        }
+        self.tap_count = count;
+    }
+
+    /// Number of active taps (0 = single-tap ping-pong mode)
+    pub fn tap_count(&self) -> usize {
+        self.tap_count
+    }
+
+    /// Time until the repeats fall below -60 dB, in samples
+    ///
+    /// Used to report the effect tail to hosts. Without feedback this is a single echo;
+    /// in multi-tap mode the longest tap sets the repeat period.
+    pub fn tail_samples(&self) -> u32 {
+        let period = if self.tap_count > 0 {
+            self.taps[..self.tap_count]
+                .iter()
+                .map(|tap| tap.delay_samples)
+                .max()
+                .unwrap_or(0)
+        } else {
+            self.delay_samples
+        } as f32;
+
+        let repeats = if self.feedback > 0.0 {
+            0.001f32.ln() / self.feedback.ln()
+        } else {
+            0.0
+        };
+        (period * (1.0 + repeats)) as u32
     }
 
     /// Set wet level (0.0 to 1.0)
@@ -101,17 +246,24 @@ impl StereoDelay {
     /// Similarly, right input feeds right delay, which crosses to left delay.
     /// This creates the characteristic ping-pong bouncing effect.
     pub fn process(&mut self, input_l: f32, input_r: f32) -> (f32, f32) {
+        if self.tap_count > 0 {
+            return self.process_multi_tap(input_l, input_r);
+        }
+
         // Calculate read position (delay samples back from write position)
-        let read_index = if self.write_index >= self.delay_samples {
-            self.write_index - self.delay_samples
+        let read_index = self.read_index(self.delay_samples);
+
+        // Read delayed samples (cubic needs one newer and two older neighbours)
+        let (delayed_l, delayed_r) = if self.cubic
+            && self.delay_frac > 0.0
+            && self.delay_samples >= 2
+            && self.delay_samples + 2 < self.max_samples
+        {
+            self.read_cubic()
         } else {
-            self.max_samples - (self.delay_samples - self.write_index)
+            (self.buffer_l[read_index], self.buffer_r[read_index])
         };
 
-        // Read delayed samples
-        let delayed_l = self.buffer_l[read_index];
-        let delayed_r = self.buffer_r[read_index];
-
         // Ping-pong: L delay feeds R, R delay feeds L (cross-feedback)
         self.buffer_l[self.write_index] = input_l + delayed_r * self.feedback;
         self.buffer_r[self.write_index] = input_r + delayed_l * self.feedback;
@@ -126,6 +278,64 @@ impl StereoDelay {
         (output_l, output_r)
     }
 
+    /// Buffer index `delay_samples` behind the write position
+    #[inline]
+    fn read_index(&self, delay_samples: usize) -> usize {
+        if self.write_index >= delay_samples {
+            self.write_index - delay_samples
+        } else {
+            self.max_samples - (delay_samples - self.write_index)
+        }
+    }
+
+    /// 4-point Hermite read at `delay_samples + delay_frac` for both channels
+    #[inline]
+    fn read_cubic(&self) -> (f32, f32) {
+        let i0 = self.read_index(self.delay_samples - 1);
+        let i1 = self.read_index(self.delay_samples);
+        let i2 = self.read_index(self.delay_samples + 1);
+        let i3 = self.read_index(self.delay_samples + 2);
+        let t = self.delay_frac;
+
+        let hermite = |buffer: &[f32]| {
+            let (y0, y1, y2, y3) = (buffer[i0], buffer[i1], buffer[i2], buffer[i3]);
+            let c1 = 0.5 * (y2 - y0);
+            let c2 = y0 - 2.5 * y1 + 2.0 * y2 - 0.5 * y3;
+            let c3 = 0.5 * (y3 - y0) + 1.5 * (y1 - y2);
+            ((c3 * t + c2) * t + c1) * t + y1
+        };
+
+        (hermite(&self.buffer_l), hermite(&self.buffer_r))
+    }
+
+    /// Multi-tap processing: sum all panned taps, feed their normalized sum back
+    fn process_multi_tap(&mut self, input_l: f32, input_r: f32) -> (f32, f32) {
+        let mut wet_l = 0.0;
+        let mut wet_r = 0.0;
+        let mut feedback_l = 0.0;
+        let mut feedback_r = 0.0;
+
+        for tap in &self.taps[..self.tap_count] {
+            let read_index = self.read_index(tap.delay_samples);
+            let delayed_l = self.buffer_l[read_index];
+            let delayed_r = self.buffer_r[read_index];
+
+            wet_l += delayed_l * tap.gain_l;
+            wet_r += delayed_r * tap.gain_r;
+            feedback_l += delayed_l * tap.feedback_gain;
+            feedback_r += delayed_r * tap.feedback_gain;
+        }
+
+        self.buffer_l[self.write_index] = input_l + feedback_l * self.feedback;
+        self.buffer_r[self.write_index] = input_r + feedback_r * self.feedback;
+        self.write_index = (self.write_index + 1) % self.max_samples;
+
+        (
+            input_l * self.dry + wet_l * self.wet,
+            input_r * self.dry + wet_r * self.wet,
+        )
+    }
+
     /// Clear delay buffers
     pub fn clear(&mut self) {
         self.buffer_l.fill(0.0);
@@ -138,6 +348,7 @@ impl StereoDelay {
 mod tests {
     use super::*;
     use approx::assert_relative_eq;
+    use std::f32::consts::PI;
 
     #[test]
     fn test_delay_creation() {
@@ -322,4 +533,131 @@ mod tests {
             assert!(out_r.is_finite(), "Delay produced NaN/inf (right)");
         }
     }
+
+    #[test]
+    fn test_multi_tap_impulse_response() {
+        let sample_rate = 44100.0;
+        let mut delay = StereoDelay::new(sample_rate);
+        delay.set_wet(1.0);
+        delay.set_dry(0.0);
+        delay.set_feedback(0.0);
+        delay.set_taps(&[(10.0, 1.0, -1.0), (25.0, 0.5, 0.0), (40.0, 0.25, 1.0)]);
+        assert_eq!(delay.tap_count(), 3);
+
+        let mut out = Vec::new();
+        out.push(delay.process(1.0, 1.0));
+        for _ in 0..2000 {
+            out.push(delay.process(0.0, 0.0));
+        }
+
+        let at = |ms: f32| (sample_rate * ms / 1000.0) as usize;
+
+        // Hard-left tap: left only
+        assert_relative_eq!(out[at(10.0)].0, 1.0, epsilon = 0.001);
+        assert_relative_eq!(out[at(10.0)].1, 0.0, epsilon = 0.001);
+        // Centered tap: both sides at tap gain
+        assert_relative_eq!(out[at(25.0)].0, 0.5, epsilon = 0.001);
+        assert_relative_eq!(out[at(25.0)].1, 0.5, epsilon = 0.001);
+        // Hard-right tap: right only
+        assert_relative_eq!(out[at(40.0)].0, 0.0, epsilon = 0.001);
+        assert_relative_eq!(out[at(40.0)].1, 0.25, epsilon = 0.001);
+
+        // Nothing else in the impulse response
+        let nonzero = out
+            .iter()
+            .filter(|(l, r)| l.abs() > 0.001 || r.abs() > 0.001)
+            .count();
+        assert_eq!(nonzero, 3);
+
+        // Empty tap list returns to single-tap mode
+        delay.set_taps(&[]);
+        assert_eq!(delay.tap_count(), 0);
+    }
+
+    #[test]
+    fn test_multi_tap_feedback_stays_stable() {
+        let mut delay = StereoDelay::new(44100.0);
+        delay.set_feedback(0.95);
+        delay.set_wet(1.0);
+        delay.set_dry(0.0);
+        // Gains sum well above unity; the shared feedback must still decay
+        delay.set_taps(&[(5.0, 1.0, 0.0), (7.0, 1.0, 0.0), (11.0, 1.0, 0.0)]);
+
+        delay.process(1.0, 1.0);
+        let mut late_peak = 0.0f32;
+        for n in 0..88200 {
+            let (l, r) = delay.process(0.0, 0.0);
+            assert!(l.is_finite() && r.is_finite());
+            if n > 80000 {
+                late_peak = late_peak.max(l.abs()).max(r.abs());
+            }
+        }
+        assert!(late_peak < 0.01, "Multi-tap feedback ran away: {late_peak}");
+    }
+
+    #[test]
+    fn test_perceptual_feedback_curve() {
+        let mut delay = StereoDelay::new(44100.0);
+        delay.set_feedback_curve(FeedbackCurve::Perceptual);
+
+        // Endpoints match the linear range
+        delay.set_feedback(0.0);
+        assert_eq!(delay.feedback, 0.0);
+        delay.set_feedback(0.95);
+        assert_relative_eq!(delay.feedback, 0.95, epsilon = 0.0001);
+
+        // Equal knob steps give equal ratios of repeat count (-60 dB decay length)
+        let repeats = |delay: &mut StereoDelay, amount: f32| {
+            delay.set_feedback(amount);
+            0.001f32.ln() / delay.feedback.ln()
+        };
+        let r1 = repeats(&mut delay, 0.475);
+        let r2 = repeats(&mut delay, 0.7125);
+        let r3 = repeats(&mut delay, 0.95);
+        assert_relative_eq!(r2 / r1, r3 / r2, epsilon = 0.01);
+
+        // Switching back to linear restores the raw knob value
+        delay.set_feedback(0.5);
+        delay.set_feedback_curve(FeedbackCurve::Linear);
+        assert_eq!(delay.feedback, 0.5);
+    }
+
+    #[test]
+    fn test_cubic_interpolation_tracks_fractional_delay() {
+        // 10.01 ms at 44.1 kHz = 441.441 samples; a 2 kHz sine shows the fractional error
+        let sample_rate = 44100.0;
+        let freq = 2000.0;
+        let exact_delay = sample_rate * 10.01 / 1000.0;
+
+        let error = |cubic: bool| {
+            let mut delay = StereoDelay::new(sample_rate);
+            delay.set_time(10.01);
+            delay.set_feedback(0.0);
+            delay.set_wet(1.0);
+            delay.set_dry(0.0);
+            delay.set_cubic_interpolation(cubic);
+
+            let omega = 2.0 * PI * freq / sample_rate;
+            let mut max_err = 0.0f32;
+            for n in 0..4410 {
+                let (out, _) = delay.process((omega * n as f32).sin(), 0.0);
+                if n > 1000 {
+                    let expected = (omega * (n as f32 - exact_delay)).sin();
+                    max_err = max_err.max((out - expected).abs());
+                }
+            }
+            max_err
+        };
+
+        let whole_samples = error(false);
+        let cubic = error(true);
+        assert!(
+            whole_samples > 0.1,
+            "Rounded delay should be off by ~0.44 samples"
+        );
+        assert!(
+            cubic < 0.01,
+            "Cubic read should land on the exact time, error {cubic}"
+        );
+    }
 }
@@ -16,10 +16,23 @@
 //! - **wet**: Reverb signal level (0.0 = dry, 1.0 = full wet)
 //! - **dry**: Direct signal level (0.0 = none, 1.0 = full dry)
 //! - **width**: Stereo width (0.0 = mono, 1.0 = full stereo)
+//! - **algorithm**: Room (original Freeverb tuning), Hall or Plate
+//!
+//! # Algorithms
+//! Each algorithm has its own comb/allpass tunings and decay profile:
+//! - **Room**: The classic Freeverb tuning
+//! - **Hall**: ~1.5x longer comb delays, higher feedback floor, darker damping
+//! - **Plate**: Short, dense delays with stronger allpass diffusion and little damping
+//!
+//! Switching algorithms fades the wet signal out, clears the delay lines, retunes,
+//! and fades back in (~10 ms each way) so the old tail never plays through new tunings.
 //!
 //! # Real-Time Safety
-//! All delay buffers are pre-allocated in `new()` with maximum possible size.
-//! No allocations happen during `process()`, making it safe for audio threads.
+//! All delay buffers are pre-allocated in `new()` with maximum possible size
+//! (the longest tuning across all algorithms). No allocations happen during `process()`
+//! or when switching algorithms, making it safe for audio threads.
+
+use crate::params::ReverbAlgorithm;
 
 /// Comb filter delays (in samples at 44.1kHz)
 /// These are tuned to prime numbers to avoid modal resonances
@@ -41,9 +54,82 @@ const FIXED_GAIN: f32 = 0.015;
 /// Stereo spread - slightly different tunings for L/R
 const STEREO_SPREAD: usize = 23;
 
+/// Wet fade time when switching algorithms (each direction)
+const ALGORITHM_FADE_MS: f32 = 10.0;
+
+/// Time constant of the per-sample wet/dry smoothing (keeps automated level changes click-free)
+const LEVEL_SMOOTH_MS: f32 = 10.0;
+
+/// Delay tunings and decay profile for one reverb algorithm
+struct AlgorithmTuning {
+    /// Comb filter delays (in samples at 44.1kHz)
+    comb: [usize; 8],
+    /// Allpass filter delays (in samples at 44.1kHz)
+    allpass: [usize; 4],
+    /// Comb feedback at room_size = 0.0
+    feedback_offset: f32,
+    /// Comb feedback added at room_size = 1.0
+    feedback_scale: f32,
+    /// Scale applied to the damping parameter
+    damping_scale: f32,
+    /// Allpass feedback (higher = denser, more diffuse)
+    allpass_feedback: f32,
+}
+
+const ROOM_TUNING: AlgorithmTuning = AlgorithmTuning {
+    comb: COMB_TUNINGS,
+    allpass: ALLPASS_TUNINGS,
+    feedback_offset: ROOM_SIZE_OFFSET,
+    feedback_scale: ROOM_SIZE_SCALE,
+    damping_scale: DAMPING_SCALE,
+    allpass_feedback: 0.5,
+};
+
+const HALL_TUNING: AlgorithmTuning = AlgorithmTuning {
+    comb: [1693, 1777, 1867, 1951, 2039, 2113, 2207, 2297],
+    allpass: [829, 661, 509, 337],
+    feedback_offset: 0.8,
+    feedback_scale: 0.18,
+    damping_scale: 0.5,
+    allpass_feedback: 0.5,
+};
+
+const PLATE_TUNING: AlgorithmTuning = AlgorithmTuning {
+    comb: [761, 823, 887, 953, 1013, 1069, 1129, 1187],
+    allpass: [347, 281, 199, 131],
+    feedback_offset: 0.72,
+    feedback_scale: 0.26,
+    damping_scale: 0.2,
+    allpass_feedback: 0.7,
+};
+
+fn tuning(algorithm: ReverbAlgorithm) -> &'static AlgorithmTuning {
+    match algorithm {
+        ReverbAlgorithm::Room => &ROOM_TUNING,
+        ReverbAlgorithm::Hall => &HALL_TUNING,
+        ReverbAlgorithm::Plate => &PLATE_TUNING,
+    }
+}
+
+/// Delay length in samples for a 44.1kHz tuning at the given sample rate
+fn scaled_len(tuning_samples: usize, scale_factor: f32) -> usize {
+    ((tuning_samples as f32 * scale_factor) as usize).max(1)
+}
+
+/// Buffer capacity for a comb/allpass slot: the longest tuning across all algorithms
+fn max_len(slot_tunings: impl Iterator<Item = usize>, scale_factor: f32) -> usize {
+    slot_tunings
+        .map(|t| scaled_len(t + STEREO_SPREAD, scale_factor))
+        .max()
+        .unwrap_or(1)
+}
+
+const ALL_TUNINGS: [&AlgorithmTuning; 3] = [&ROOM_TUNING, &HALL_TUNING, &PLATE_TUNING];
+
 /// Single comb filter with lowpass-damped feedback
 struct CombFilter {
     buffer: Vec<f32>,
+    len: usize, // Active delay length (<= buffer.len())
     index: usize,
     feedback: f32,
     damping: f32,
@@ -54,6 +140,7 @@ impl CombFilter {
     fn new(size: usize) -> Self {
         Self {
             buffer: vec![0.0; size],
+            len: size,
             index: 0,
             feedback: 0.5,
             damping: 0.5,
@@ -69,6 +156,11 @@ impl CombFilter {
         self.damping = damping;
     }
 
+    fn set_len(&mut self, len: usize) {
+        self.len = len.clamp(1, self.buffer.len());
+        self.index = 0;
+    }
+
     fn process(&mut self, input: f32) -> f32 {
         let output = self.buffer[self.index];
 
@@ -79,7 +171,7 @@ impl CombFilter {
         self.buffer[self.index] = input + self.filter_state * self.feedback;
 
         // Advance circular buffer index
-        self.index = (self.index + 1) % self.buffer.len();
+        self.index = (self.index + 1) % self.len;
 
         output
     }
@@ -94,23 +186,36 @@ impl CombFilter {
 /// Allpass filter for echo density
 struct AllpassFilter {
     buffer: Vec<f32>,
+    len: usize, // Active delay length (<= buffer.len())
     index: usize,
+    feedback: f32,
 }
 
 impl AllpassFilter {
     fn new(size: usize) -> Self {
         Self {
             buffer: vec![0.0; size],
+            len: size,
             index: 0,
+            feedback: 0.5,
         }
     }
 
+    fn set_len(&mut self, len: usize) {
+        self.len = len.clamp(1, self.buffer.len());
+        self.index = 0;
+    }
+
+    fn set_feedback(&mut self, feedback: f32) {
+        self.feedback = feedback;
+    }
+
     fn process(&mut self, input: f32) -> f32 {
         let delayed = self.buffer[self.index];
         let output = -input + delayed;
 
-        self.buffer[self.index] = input + delayed * 0.5;
-        self.index = (self.index + 1) % self.buffer.len();
+        self.buffer[self.index] = input + delayed * self.feedback;
+        self.index = (self.index + 1) % self.len;
 
         output
     }
@@ -123,7 +228,6 @@ impl AllpassFilter {
 
 /// Stereo reverb processor
 pub struct Reverb {
-    #[allow(dead_code)]
     sample_rate: f32,
 
     // Left channel processing
@@ -140,6 +244,20 @@ pub struct Reverb {
     wet: f32,
     dry: f32,
     width: f32,
+
+    // Wet/dry levels actually applied, following `wet`/`dry` per sample
+    current_wet: f32,
+    current_dry: f32,
+    level_smooth_coeff: f32,
+    // Set once audio has been processed since construction or `clear`; until then level
+    // changes apply immediately (there's no output yet to click against)
+    levels_active: bool,
+
+    // Algorithm selection
+    algorithm: ReverbAlgorithm,
+    pending_algorithm: Option<ReverbAlgorithm>, // Applied once the wet fade-out completes
+    switch_gain: f32,                           // Wet gain during algorithm switches (0.0-1.0)
+    switch_step: f32,                           // Per-sample switch fade increment
 }
 
 impl Reverb {
@@ -149,33 +267,30 @@ impl Reverb {
     /// * `sample_rate` - Sample rate in Hz (e.g., 44100.0)
     ///
     /// # Pre-allocation
-    /// All delay buffers are allocated here based on sample rate.
-    /// For 44.1kHz, this allocates ~60KB per channel (120KB total).
+    /// All delay buffers are allocated here based on sample rate, sized for the longest
+    /// algorithm (Hall). For 44.1kHz, this allocates ~75KB per channel (150KB total).
     pub fn new(sample_rate: f32) -> Self {
         let scale_factor = sample_rate / 44100.0;
 
-        // Create left channel comb filters
+        // Comb and allpass buffers are sized for the longest tuning of any algorithm,
+        // so switching algorithms only changes the active length.
         let comb_l: [CombFilter; 8] = std::array::from_fn(|i| {
-            let size = (COMB_TUNINGS[i] as f32 * scale_factor) as usize;
-            CombFilter::new(size)
+            CombFilter::new(max_len(ALL_TUNINGS.iter().map(|t| t.comb[i]), scale_factor))
         });
-
-        // Create right channel comb filters (with stereo spread)
         let comb_r: [CombFilter; 8] = std::array::from_fn(|i| {
-            let size = ((COMB_TUNINGS[i] + STEREO_SPREAD) as f32 * scale_factor) as usize;
-            CombFilter::new(size)
+            CombFilter::new(max_len(ALL_TUNINGS.iter().map(|t| t.comb[i]), scale_factor))
         });
-
-        // Create left channel allpass filters
         let allpass_l: [AllpassFilter; 4] = std::array::from_fn(|i| {
-            let size = (ALLPASS_TUNINGS[i] as f32 * scale_factor) as usize;
-            AllpassFilter::new(size)
+            AllpassFilter::new(max_len(
+                ALL_TUNINGS.iter().map(|t| t.allpass[i]),
+                scale_factor,
+            ))
         });
-
-        // Create right channel allpass filters (with stereo spread)
         let allpass_r: [AllpassFilter; 4] = std::array::from_fn(|i| {
-            let size = ((ALLPASS_TUNINGS[i] + STEREO_SPREAD) as f32 * scale_factor) as usize;
-            AllpassFilter::new(size)
+            AllpassFilter::new(max_len(
+                ALL_TUNINGS.iter().map(|t| t.allpass[i]),
+                scale_factor,
+            ))
         });
 
         let mut reverb = Self {
@@ -189,12 +304,84 @@ impl Reverb {
             wet: 0.33,
             dry: 0.67,
             width: 1.0,
+            current_wet: 0.33,
+            current_dry: 0.67,
+            levels_active: false,
+            level_smooth_coeff: 1.0 - (-1.0 / (LEVEL_SMOOTH_MS * 0.001 * sample_rate)).exp(),
+            algorithm: ReverbAlgorithm::Room,
+            pending_algorithm: None,
+            switch_gain: 1.0,
+            switch_step: 1.0 / (ALGORITHM_FADE_MS * 0.001 * sample_rate).max(1.0),
         };
 
-        reverb.update();
+        reverb.apply_algorithm(ReverbAlgorithm::Room);
         reverb
     }
 
+    /// Select the reverb algorithm (Room, Hall, Plate)
+    ///
+    /// The switch is not instant: the wet signal fades out, the delay lines are cleared
+    /// and retuned, then the wet signal fades back in. Calling this repeatedly with the
+    /// current algorithm is a no-op, so it's safe to call on every parameter update.
+    pub fn set_algorithm(&mut self, algorithm: ReverbAlgorithm) {
+        if algorithm == self.algorithm {
+            // Switching back before the fade-out finished: just fade back in
+            self.pending_algorithm = None;
+        } else {
+            self.pending_algorithm = Some(algorithm);
+        }
+    }
+
+    /// Get the active reverb algorithm
+    pub fn algorithm(&self) -> ReverbAlgorithm {
+        self.algorithm
+    }
+
+    /// Retune delay lengths for an algorithm and clear the old tail
+    fn apply_algorithm(&mut self, algorithm: ReverbAlgorithm) {
+        let scale_factor = self.sample_rate / 44100.0;
+        let t = tuning(algorithm);
+
+        for i in 0..8 {
+            self.comb_l[i].set_len(scaled_len(t.comb[i], scale_factor));
+            self.comb_r[i].set_len(scaled_len(t.comb[i] + STEREO_SPREAD, scale_factor));
+        }
+        for i in 0..4 {
+            self.allpass_l[i].set_len(scaled_len(t.allpass[i], scale_factor));
+            self.allpass_r[i].set_len(scaled_len(t.allpass[i] + STEREO_SPREAD, scale_factor));
+            self.allpass_l[i].set_feedback(t.allpass_feedback);
+            self.allpass_r[i].set_feedback(t.allpass_feedback);
+        }
+
+        self.algorithm = algorithm;
+        self.clear();
+        self.update();
+    }
+
+    /// Estimated decay time to -60 dB in samples (RT60 of the longest comb plus the
+    /// allpass chain), used to report the effect tail to hosts
+    pub fn tail_samples(&self) -> u32 {
+        let scale_factor = self.sample_rate / 44100.0;
+        let t = tuning(self.pending_algorithm.unwrap_or(self.algorithm));
+        let feedback = t.feedback_offset + self.room_size * t.feedback_scale;
+
+        let longest_comb = t
+            .comb
+            .iter()
+            .map(|&len| scaled_len(len + STEREO_SPREAD, scale_factor))
+            .max()
+            .unwrap_or(0) as f32;
+        let allpass_total: usize = t
+            .allpass
+            .iter()
+            .map(|&len| scaled_len(len + STEREO_SPREAD, scale_factor))
+            .sum();
+
+        // Each pass through the comb scales the tail by `feedback`
+        let passes = 0.001f32.ln() / feedback.ln();
+        (longest_comb * passes) as u32 + allpass_total as u32
+    }
+
     /// Set room size (0.0 to 1.0)
     pub fn set_room_size(&mut self, room_size: f32) {
         self.room_size = room_size.clamp(0.0, 1.0);
@@ -208,13 +395,22 @@ impl Reverb {
     }
 
     /// Set wet level (0.0 to 1.0)
+    ///
+    /// Wet and dry changes are smoothed over ~`LEVEL_SMOOTH_MS` inside `process`, so
+    /// stepped (throttled) automation doesn't click.
     pub fn set_wet(&mut self, wet: f32) {
         self.wet = wet.clamp(0.0, 1.0);
+        if !self.levels_active {
+            self.current_wet = self.wet;
+        }
     }
 
     /// Set dry level (0.0 to 1.0)
     pub fn set_dry(&mut self, dry: f32) {
         self.dry = dry.clamp(0.0, 1.0);
+        if !self.levels_active {
+            self.current_dry = self.dry;
+        }
     }
 
     /// Set stereo width (0.0 = mono, 1.0 = full stereo)
@@ -224,8 +420,9 @@ impl Reverb {
 
     /// Update internal filter coefficients
     fn update(&mut self) {
-        let feedback = ROOM_SIZE_OFFSET + self.room_size * ROOM_SIZE_SCALE;
-        let damp = self.damping * DAMPING_SCALE;
+        let t = tuning(self.algorithm);
+        let feedback = t.feedback_offset + self.room_size * t.feedback_scale;
+        let damp = self.damping * t.damping_scale;
 
         for i in 0..8 {
             self.comb_l[i].set_feedback(feedback);
@@ -247,6 +444,18 @@ impl Reverb {
     /// # Real-Time Safety
     /// This method performs no allocations and has bounded execution time.
     pub fn process(&mut self, input_l: f32, input_r: f32) -> (f32, f32) {
+        // Algorithm switch: fade wet out, retune + clear at silence, fade back in
+        if let Some(next) = self.pending_algorithm {
+            self.switch_gain -= self.switch_step;
+            if self.switch_gain <= 0.0 {
+                self.switch_gain = 0.0;
+                self.pending_algorithm = None;
+                self.apply_algorithm(next);
+            }
+        } else if self.switch_gain < 1.0 {
+            self.switch_gain = (self.switch_gain + self.switch_step).min(1.0);
+        }
+
         // Mix input to mono for feeding to reverb
         let input = (input_l + input_r) * FIXED_GAIN;
 
@@ -265,13 +474,19 @@ impl Reverb {
             out_r = self.allpass_r[i].process(out_r);
         }
 
+        // Smooth wet/dry level changes
+        self.levels_active = true;
+        self.current_wet += (self.wet - self.current_wet) * self.level_smooth_coeff;
+        self.current_dry += (self.dry - self.current_dry) * self.level_smooth_coeff;
+
         // Apply stereo width
-        let wet1 = self.wet * (self.width / 2.0 + 0.5);
-        let wet2 = self.wet * ((1.0 - self.width) / 2.0);
+        let wet = self.current_wet * self.switch_gain;
+        let wet1 = wet * (self.width / 2.0 + 0.5);
+        let wet2 = wet * ((1.0 - self.width) / 2.0);
 
         // Mix wet and dry signals
-        let output_l = out_l * wet1 + out_r * wet2 + input_l * self.dry;
-        let output_r = out_r * wet1 + out_l * wet2 + input_r * self.dry;
+        let output_l = out_l * wet1 + out_r * wet2 + input_l * self.current_dry;
+        let output_r = out_r * wet1 + out_l * wet2 + input_r * self.current_dry;
 
         (output_l, output_r)
     }
@@ -286,6 +501,9 @@ impl Reverb {
             self.allpass_l[i].clear();
             self.allpass_r[i].clear();
         }
+        self.current_wet = self.wet;
+        self.current_dry = self.dry;
+        self.levels_active = false;
     }
 }
 
@@ -469,4 +687,114 @@ mod tests {
             different_count
         );
     }
+
+    /// Render an impulse response tail and return its energy in the given sample window
+    fn tail_energy(algorithm: ReverbAlgorithm, from: usize, to: usize) -> f32 {
+        let mut reverb = Reverb::new(44100.0);
+        reverb.set_wet(1.0);
+        reverb.set_dry(0.0);
+        reverb.set_algorithm(algorithm);
+        // Let any algorithm switch complete before the impulse
+        for _ in 0..1000 {
+            reverb.process(0.0, 0.0);
+        }
+        assert_eq!(reverb.algorithm(), algorithm);
+
+        reverb.process(1.0, 1.0);
+        (1..to)
+            .map(|_| reverb.process(0.0, 0.0))
+            .skip(from.saturating_sub(1))
+            .map(|(l, r)| l * l + r * r)
+            .sum()
+    }
+
+    #[test]
+    fn test_reverb_algorithms_differ() {
+        // Hall decays slower than Room: more energy late in the tail
+        let room_late = tail_energy(ReverbAlgorithm::Room, 44100, 88200);
+        let hall_late = tail_energy(ReverbAlgorithm::Hall, 44100, 88200);
+        assert!(
+            hall_late > room_late * 2.0,
+            "Hall tail should outlast Room: hall={hall_late}, room={room_late}"
+        );
+
+        // Plate has shorter delays: reflections start earlier than Room's
+        let room_early = tail_energy(ReverbAlgorithm::Room, 0, 1000);
+        let plate_early = tail_energy(ReverbAlgorithm::Plate, 0, 1000);
+        assert!(
+            room_early < 1e-9,
+            "Room's first comb echo arrives after 1000 samples"
+        );
+        assert!(
+            plate_early > 1e-6,
+            "Plate should reflect within 1000 samples"
+        );
+    }
+
+    #[test]
+    fn test_reverb_algorithm_switch_fades_and_clears() {
+        let mut reverb = Reverb::new(44100.0);
+        reverb.set_room_size(0.9);
+        reverb.set_wet(1.0);
+        reverb.set_dry(0.0);
+
+        // Build up a tail with noise-like input
+        for i in 0..22050 {
+            let x = ((i * 7919) % 101) as f32 / 101.0 - 0.5;
+            reverb.process(x, x);
+        }
+
+        reverb.set_algorithm(ReverbAlgorithm::Plate);
+        assert_eq!(
+            reverb.algorithm(),
+            ReverbAlgorithm::Room,
+            "Switch waits for fade-out"
+        );
+
+        // While switching, the tail fades out instead of being cut off
+        let faded: Vec<f32> = (0..441)
+            .map(|_| {
+                let (l, r) = reverb.process(0.0, 0.0);
+                l.abs().max(r.abs())
+            })
+            .collect();
+        assert_eq!(reverb.algorithm(), ReverbAlgorithm::Plate);
+        let start_peak = faded[..40].iter().cloned().fold(0.0, f32::max);
+        let end_peak = faded[421..].iter().cloned().fold(0.0, f32::max);
+        assert!(
+            start_peak > 0.01,
+            "Tail should be audible when the switch starts"
+        );
+        assert!(
+            end_peak < start_peak * 0.1,
+            "Tail should fade out before retuning: start={start_peak}, end={end_peak}"
+        );
+
+        // The old tail was cleared, and only silence went in afterwards
+        for _ in 0..1000 {
+            let (out_l, out_r) = reverb.process(0.0, 0.0);
+            assert_relative_eq!(out_l, 0.0, epsilon = 0.0001);
+            assert_relative_eq!(out_r, 0.0, epsilon = 0.0001);
+        }
+    }
+
+    #[test]
+    fn test_reverb_algorithms_stable() {
+        for algorithm in [ReverbAlgorithm::Hall, ReverbAlgorithm::Plate] {
+            let mut reverb = Reverb::new(48000.0);
+            reverb.set_algorithm(algorithm);
+            reverb.set_room_size(1.0);
+            for _ in 0..48000 {
+                let (out_l, out_r) = reverb.process(0.1, 0.1);
+                assert!(
+                    out_l.is_finite() && out_l.abs() < 10.0,
+                    "{algorithm} unstable (left)"
+                );
+                assert!(
+                    out_r.is_finite() && out_r.abs() < 10.0,
+                    "{algorithm} unstable (right)"
+                );
+            }
+        }
+    }
 }
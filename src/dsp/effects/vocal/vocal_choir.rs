@@ -138,20 +138,29 @@ impl VocalChoir {
     /// Set detune amount in cents (0-30 typical)
     /// This is the total spread, so voices will be ±(amount/2)
     pub fn set_detune_amount(&mut self, cents: f32) {
-        self.detune_amount = cents.clamp(0.0, 50.0);
-        self.rebuild_voices();
+        let cents = cents.clamp(0.0, 50.0);
+        if cents != self.detune_amount {
+            self.detune_amount = cents;
+            self.rebuild_voices();
+        }
     }
 
     /// Set delay spread in milliseconds (10-40 typical)
     pub fn set_delay_spread(&mut self, ms: f32) {
-        self.delay_spread = ms.clamp(5.0, MAX_DELAY_MS);
-        self.rebuild_voices();
+        let ms = ms.clamp(5.0, MAX_DELAY_MS);
+        if ms != self.delay_spread {
+            self.delay_spread = ms;
+            self.rebuild_voices();
+        }
     }
 
     /// Set stereo spread (0.0 = mono, 1.0 = full stereo)
     pub fn set_stereo_spread(&mut self, spread: f32) {
-        self.stereo_spread = spread.clamp(0.0, 1.0);
-        self.rebuild_voices();
+        let spread = spread.clamp(0.0, 1.0);
+        if spread != self.stereo_spread {
+            self.stereo_spread = spread;
+            self.rebuild_voices();
+        }
     }
 
     /// Set dry/wet mix (0.0 = dry, 1.0 = wet)
@@ -160,8 +169,11 @@ impl VocalChoir {
     }
 
     /// Rebuild voice array with new parameters
+    ///
+    /// Existing voices are retuned in place, keeping their delay lines, so parameter
+    /// changes on a running choir don't allocate or drop audio.
     fn rebuild_voices(&mut self) {
-        self.voices.clear();
+        self.voices.truncate(self.num_voices);
 
         for i in 0..self.num_voices {
             // Distribute detuning evenly across voices
@@ -186,8 +198,14 @@ impl VocalChoir {
                 0.0 // Center for single voice
             };
 
-            let voice = ChoirVoice::new(self.max_delay_samples, delay_ms, detune_cents, pan);
-            self.voices.push(voice);
+            if let Some(voice) = self.voices.get_mut(i) {
+                voice.delay_ms = delay_ms;
+                voice.detune_cents = detune_cents;
+                voice.pan = pan;
+            } else {
+                let voice = ChoirVoice::new(self.max_delay_samples, delay_ms, detune_cents, pan);
+                self.voices.push(voice);
+            }
         }
     }
 
@@ -375,6 +393,53 @@ mod tests {
         assert_ne!(left, right, "Mono input should expand to stereo");
     }
 
+    #[test]
+    fn test_choir_decorrelates_channels() {
+        let mut choir = VocalChoir::new(44100.0);
+        choir.set_num_voices(6);
+        choir.set_stereo_spread(1.0);
+        choir.set_mix(1.0);
+
+        // Broadband mono input: identical channels in, correlation 1.0
+        let mut noise_state = 0x12345678_u32;
+        let (mut sum_lr, mut sum_ll, mut sum_rr) = (0.0_f64, 0.0_f64, 0.0_f64);
+        for i in 0..44100 {
+            noise_state ^= noise_state << 13;
+            noise_state ^= noise_state >> 17;
+            noise_state ^= noise_state << 5;
+            let input = noise_state as f32 / u32::MAX as f32 * 2.0 - 1.0;
+            let (left, right) = choir.process(input, input);
+            if i >= 4410 {
+                sum_lr += (left * right) as f64;
+                sum_ll += (left * left) as f64;
+                sum_rr += (right * right) as f64;
+            }
+        }
+
+        let correlation = sum_lr / (sum_ll * sum_rr).sqrt();
+        assert!(
+            correlation < 0.8,
+            "Choir should decorrelate a mono input, correlation {}",
+            correlation
+        );
+    }
+
+    #[test]
+    fn test_parameter_change_keeps_delay_lines() {
+        let mut choir = VocalChoir::new(44100.0);
+        choir.set_mix(1.0);
+        for _ in 0..4410 {
+            choir.process(0.5, 0.5);
+        }
+
+        // Retuning a running choir shouldn't drop the signal already in its delay lines:
+        // the input has stopped, but the voices (10 ms and longer) are still sounding
+        choir.set_detune_amount(25.0);
+        choir.set_stereo_spread(0.5);
+        let (left, right) = choir.process(0.0, 0.0);
+        assert!(left.abs() > 0.1 && right.abs() > 0.1);
+    }
+
     #[test]
     fn test_detune_distribution() {
         let mut choir = VocalChoir::new(44100.0);
@@ -31,9 +31,9 @@ pub use filters::BiquadFilter;
 
 // Analysis
 pub use analysis::{
-    FormantDetector, PitchDetectionResult, PitchDetector, PitchQuantizer, RootNote, ScaleType,
-    SibilanceDetector, SignalType, SpectralCentroid, TransientDetector, VowelEstimate, ZcrDetector,
-    PITCH_BUFFER_SIZE,
+    FormantDetector, MonoBandReport, MonoCompatibilityReport, PitchDetectionResult, PitchDetector,
+    PitchQuantizer, RootNote, ScaleType, SibilanceDetector, SignalType, SpectralCentroid,
+    TransientDetector, VowelEstimate, ZcrDetector, PITCH_BUFFER_SIZE,
 };
 
 // Signal Analyzer
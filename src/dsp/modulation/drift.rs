@@ -0,0 +1,109 @@
+/// Analog-style drift: a very slow, smooth random modulation source
+///
+/// Analog oscillators and filters never sit exactly on their set value; temperature
+/// and component tolerances make them wander slowly. This generator models that with
+/// smoothly interpolated random targets at a sub-audio rate.
+///
+/// # Algorithm
+/// A new random target in -1.0..1.0 is drawn once per cycle (`rate_hz`), and the output
+/// glides from the previous target to the next with a smoothstep curve, so there are no
+/// corners that could be heard as steps. Random numbers come from a xorshift32 PRNG,
+/// so a given seed always produces the same drift.
+pub struct Drift {
+    sample_rate: f32,
+    rate_hz: f32,
+    phase: f32,
+    from: f32,
+    to: f32,
+    rng_state: u32,
+}
+
+impl Drift {
+    /// Create a new drift generator
+    ///
+    /// # Arguments
+    /// * `sample_rate` - Sample rate in Hz
+    /// * `rate_hz` - How often a new random target is chosen (typically 0.1-1.0 Hz)
+    /// * `seed` - PRNG seed (0 is replaced by a fixed non-zero value)
+    pub fn new(sample_rate: f32, rate_hz: f32, seed: u32) -> Self {
+        let mut drift = Self {
+            sample_rate,
+            rate_hz: rate_hz.clamp(0.01, 20.0),
+            phase: 0.0,
+            from: 0.0,
+            to: 0.0,
+            rng_state: 1,
+        };
+        drift.seed(seed);
+        drift
+    }
+
+    /// Restart the drift from a seed (same seed = same drift sequence)
+    pub fn seed(&mut self, seed: u32) {
+        self.rng_state = if seed == 0 { 0x12345678 } else { seed };
+        self.phase = 0.0;
+        self.from = self.next_random();
+        self.to = self.next_random();
+    }
+
+    /// xorshift32 mapped to -1.0..1.0
+    fn next_random(&mut self) -> f32 {
+        let mut x = self.rng_state;
+        x ^= x << 13;
+        x ^= x >> 17;
+        x ^= x << 5;
+        self.rng_state = x;
+        (x as f32 / u32::MAX as f32) * 2.0 - 1.0
+    }
+
+    /// Generate the next drift sample
+    /// Returns a value between -1.0 and 1.0
+    pub fn process(&mut self) -> f32 {
+        let t = self.phase * self.phase * (3.0 - 2.0 * self.phase); // smoothstep
+        let output = self.from + (self.to - self.from) * t;
+
+        self.phase += self.rate_hz / self.sample_rate;
+        if self.phase >= 1.0 {
+            self.phase -= 1.0;
+            self.from = self.to;
+            self.to = self.next_random();
+        }
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_drift_is_bounded_and_smooth() {
+        let mut drift = Drift::new(44100.0, 0.5, 42);
+        let mut prev = drift.process();
+        for _ in 0..441000 {
+            let value = drift.process();
+            assert!((-1.0..=1.0).contains(&value), "Drift out of range: {value}");
+            assert!(
+                (value - prev).abs() < 0.001,
+                "Drift should move slowly, jumped {}",
+                (value - prev).abs()
+            );
+            prev = value;
+        }
+    }
+
+    #[test]
+    fn test_drift_is_reproducible_from_seed() {
+        let mut a = Drift::new(44100.0, 0.5, 7);
+        let mut b = Drift::new(44100.0, 0.5, 7);
+        let mut c = Drift::new(44100.0, 0.5, 8);
+        let mut differs = false;
+        for _ in 0..44100 {
+            let (va, vb, vc) = (a.process(), b.process(), c.process());
+            assert_eq!(va, vb);
+            differs |= va != vc;
+        }
+        assert!(differs, "Different seeds should drift differently");
+    }
+}
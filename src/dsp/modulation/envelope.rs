@@ -87,6 +87,10 @@ impl Envelope {
     }
 
     /// Set attack curve (-1.0 = logarithmic, 0.0 = linear, +1.0 = exponential)
+    ///
+    /// A positive (exponential) curve starts slowly and speeds up towards the target, so a
+    /// positive attack passes the halfway level later than linear and a negative
+    /// (logarithmic) one sooner. The same holds for decay and release.
     pub fn set_attack_curve(&mut self, curve: f32) {
         self.attack_curve = curve.clamp(-1.0, 1.0);
     }
@@ -125,15 +129,15 @@ impl Envelope {
             // Linear (no curve)
             progress
         } else if curve > 0.0 {
-            // Exponential (fast→slow): use fractional power < 1
-            // powf(1.0 - curve * 0.67) gives smooth transition
-            // curve=0.5 → powf(0.67), curve=1.0 → powf(0.33)
-            // This creates fast initial rise that slows near the end
-            progress.powf(1.0 - curve * 0.67)
-        } else {
-            // Logarithmic (slow→fast): use power > 1
+            // Exponential (slow→fast): use power > 1
+            // powf(1.0 + curve * 0.67) gives smooth transition
+            // curve=0.5 → powf(1.33), curve=1.0 → powf(1.67)
             // This creates slow initial rise that accelerates near the end
-            progress.powf(1.0 - curve * 0.67)
+            progress.powf(1.0 + curve * 0.67)
+        } else {
+            // Logarithmic (fast→slow): use fractional power < 1
+            // This creates fast initial rise that slows near the end
+            progress.powf(1.0 + curve * 0.67)
         }
     }
 
@@ -144,6 +148,21 @@ impl Envelope {
         // Don't reset current_level to allow for retriggering
     }
 
+    /// Re-enter the attack stage from the current level (legato retrigger)
+    ///
+    /// Unlike `note_on()`, the attack resumes at the point of the curve that matches the
+    /// current level, so a voice caught in its release rises back up without a dip.
+    pub fn note_on_legato(&mut self) {
+        let level = self.current_level.clamp(0.0, 1.0);
+        self.linear_progress = if self.attack_curve.abs() < 0.01 {
+            level
+        } else {
+            // Inverse of apply_curve(): progress = level^(1 / exponent)
+            level.powf(1.0 / (1.0 + self.attack_curve * 0.67))
+        };
+        self.stage = EnvelopeStage::Attack;
+    }
+
     /// Release the envelope (note off)
     pub fn note_off(&mut self) {
         if self.stage != EnvelopeStage::Idle {
@@ -218,6 +237,11 @@ impl Envelope {
         self.current_level
     }
 
+    /// Level the current (or last) release started from
+    pub fn release_start_level(&self) -> f32 {
+        self.release_start_level
+    }
+
     /// Reset envelope to idle state
     pub fn reset(&mut self) {
         self.stage = EnvelopeStage::Idle;
@@ -554,42 +578,42 @@ mod tests {
     #[test]
     fn test_apply_curve_exponential() {
         let env = Envelope::new(44100.0);
-        // Exponential curve (curve = 1.0) should produce fast→slow behavior
-        // At 50% progress, output should be > 50% (fast initial rise)
+        // Exponential curve (curve = 1.0) should produce slow→fast behavior
+        // At 50% progress, output should be < 50% (slow initial rise)
         let result = env.apply_curve(0.5, 1.0);
         assert!(
-            result > 0.5,
-            "Exponential curve at 50% progress should be > 50%, got {}",
+            result < 0.5,
+            "Exponential curve at 50% progress should be < 50%, got {}",
             result
         );
 
-        // At 25% progress, should already be significantly higher
-        let result_early = env.apply_curve(0.25, 1.0);
+        // At 75% progress, should accelerate and be close to end
+        let result_late = env.apply_curve(0.75, 1.0);
         assert!(
-            result_early > 0.35,
-            "Exponential curve at 25% progress should be > 35%, got {}",
-            result_early
+            result_late > 0.5 && result_late < 0.85,
+            "Exponential curve at 75% progress should be between 50-85%, got {}",
+            result_late
         );
     }
 
     #[test]
     fn test_apply_curve_logarithmic() {
         let env = Envelope::new(44100.0);
-        // Logarithmic curve (curve = -1.0) should produce slow→fast behavior
-        // At 50% progress, output should be < 50% (slow initial rise)
+        // Logarithmic curve (curve = -1.0) should produce fast→slow behavior
+        // At 50% progress, output should be > 50% (fast initial rise)
         let result = env.apply_curve(0.5, -1.0);
         assert!(
-            result < 0.5,
-            "Logarithmic curve at 50% progress should be < 50%, got {}",
+            result > 0.5,
+            "Logarithmic curve at 50% progress should be > 50%, got {}",
             result
         );
 
-        // At 75% progress, should accelerate and be close to end
-        let result_late = env.apply_curve(0.75, -1.0);
+        // At 25% progress, should already be significantly higher
+        let result_early = env.apply_curve(0.25, -1.0);
         assert!(
-            result_late > 0.5 && result_late < 0.85,
-            "Logarithmic curve at 75% progress should be between 50-85%, got {}",
-            result_late
+            result_early > 0.35,
+            "Logarithmic curve at 25% progress should be > 35%, got {}",
+            result_early
         );
     }
 
@@ -612,13 +636,13 @@ mod tests {
     }
 
     #[test]
-    fn test_envelope_with_exponential_attack_curve() {
+    fn test_envelope_with_logarithmic_attack_curve() {
         let mut env = Envelope::new(44100.0);
         env.set_attack(0.1); // 100ms
-        env.set_attack_curve(1.0); // Exponential (fast→slow)
+        env.set_attack_curve(-1.0); // Logarithmic (fast→slow)
         env.note_on();
 
-        // With exponential curve, should reach 50% level faster than 50% of attack time
+        // With logarithmic curve, should reach 50% level faster than 50% of attack time
         let half_attack_samples = (0.05 * 44100.0) as usize;
         let mut level_at_half_time = 0.0;
 
@@ -629,21 +653,21 @@ mod tests {
             }
         }
 
-        // Exponential attack should be > 50% at halfway point
+        // Logarithmic attack should be > 50% at halfway point
         assert!(
             level_at_half_time > 0.5,
-            "Exponential attack should reach > 50% at halfway point, got {}",
+            "Logarithmic attack should reach > 50% at halfway point, got {}",
             level_at_half_time
         );
     }
 
     #[test]
-    fn test_envelope_with_logarithmic_decay_curve() {
+    fn test_envelope_with_exponential_decay_curve() {
         let mut env = Envelope::new(44100.0);
         env.set_attack(0.001); // Very short attack
         env.set_decay(0.2); // 200ms decay
         env.set_sustain(0.3);
-        env.set_decay_curve(-1.0); // Logarithmic (slow→fast, hangs at high levels)
+        env.set_decay_curve(1.0); // Exponential (slow→fast, hangs at high levels)
         env.note_on();
 
         // Process through attack to reach decay stage
@@ -654,7 +678,7 @@ mod tests {
             }
         }
 
-        // At halfway through decay, logarithmic should still be > 65%
+        // At halfway through decay, exponential should still be > 65%
         // (hangs at high levels longer)
         let half_decay_samples = (0.1 * 44100.0) as usize;
         let mut level_at_half_decay = 0.0;
@@ -666,11 +690,66 @@ mod tests {
             }
         }
 
-        // Logarithmic decay should hang above 65% (linear would be at ~65%)
+        // Exponential decay should hang above 65% (linear would be at ~65%)
         assert!(
             level_at_half_decay > 0.65,
-            "Logarithmic decay should hang above 65% at halfway point, got {}",
+            "Exponential decay should hang above 65% at halfway point, got {}",
             level_at_half_decay
         );
     }
+
+    #[test]
+    fn test_attack_curve_moves_the_midpoint() {
+        fn samples_to_midpoint(curve: f32) -> usize {
+            let mut env = Envelope::new(44100.0);
+            env.set_attack(0.1);
+            env.set_attack_curve(curve);
+            env.note_on();
+            (1..=4410).find(|_| env.process() >= 0.5).unwrap()
+        }
+
+        let linear = samples_to_midpoint(0.0);
+        assert!(
+            (linear as i32 - 2205).abs() <= 1,
+            "Linear: half the attack time"
+        );
+
+        let exponential = samples_to_midpoint(0.5);
+        let logarithmic = samples_to_midpoint(-0.5);
+        assert!(
+            exponential > linear * 9 / 8,
+            "A positive curve passes halfway later: {exponential} vs {linear}"
+        );
+        assert!(
+            logarithmic < linear * 7 / 8,
+            "A negative curve passes halfway sooner: {logarithmic} vs {linear}"
+        );
+    }
+
+    #[test]
+    fn test_note_on_legato_resumes_from_current_level() {
+        for curve in [0.0, 0.6, -0.6] {
+            let mut env = Envelope::new(44100.0);
+            env.set_attack(0.05);
+            env.set_release(0.5);
+            env.set_attack_curve(curve);
+            env.note_on();
+            for _ in 0..4410 {
+                env.process();
+            }
+            env.note_off();
+            let mut level = 0.0;
+            for _ in 0..2205 {
+                level = env.process();
+            }
+
+            env.note_on_legato();
+            let next = env.process();
+            assert_eq!(env.stage(), EnvelopeStage::Attack);
+            assert!(
+                (next - level).abs() < 0.02,
+                "Legato retrigger should continue from {level}, got {next} (curve {curve})"
+            );
+        }
+    }
 }
@@ -6,7 +6,9 @@ pub struct LFO {
     sample_rate: f32,
     phase: f32,
     waveform: LFOWaveform,
-    rate: f32, // Hz
+    rate: f32,        // Hz
+    pulse_width: f32, // Square duty cycle (0.05 to 0.95)
+    unipolar: bool,   // Output 0..1 instead of -1..1
 }
 
 impl LFO {
@@ -20,6 +22,8 @@ impl LFO {
             phase: 0.0,
             waveform: LFOWaveform::Sine,
             rate: 2.0,
+            pulse_width: 0.5,
+            unipolar: false,
         }
     }
 
@@ -33,15 +37,33 @@ impl LFO {
         self.waveform = waveform;
     }
 
+    /// Set the square wave's duty cycle: the fraction of each cycle spent high
+    /// (0.05 to 0.95, 0.5 = even). Other waveforms ignore it.
+    pub fn set_pulse_width(&mut self, pulse_width: f32) {
+        self.pulse_width = pulse_width.clamp(0.05, 0.95);
+    }
+
+    /// Output 0.0 to 1.0 instead of -1.0 to 1.0, so a modulation amount only pushes its
+    /// target one way (e.g. opening a filter from its set cutoff)
+    pub fn set_unipolar(&mut self, unipolar: bool) {
+        self.unipolar = unipolar;
+    }
+
     /// Generate next LFO sample
-    /// Returns a value between -1.0 and 1.0
+    /// Returns a value between -1.0 and 1.0 (0.0 to 1.0 when unipolar)
     pub fn process(&mut self) -> f32 {
         use crate::params::Waveform;
 
         let output = match self.waveform {
             LFOWaveform::Sine => waveform::generate_scalar(self.phase, Waveform::Sine),
             LFOWaveform::Triangle => waveform::generate_scalar(self.phase, Waveform::Triangle),
-            LFOWaveform::Square => waveform::generate_scalar(self.phase, Waveform::Square),
+            LFOWaveform::Square => {
+                if self.phase < self.pulse_width {
+                    1.0
+                } else {
+                    -1.0
+                }
+            }
             LFOWaveform::Saw => waveform::generate_scalar(self.phase, Waveform::Saw),
         };
 
@@ -52,13 +74,22 @@ impl LFO {
             self.phase -= 1.0;
         }
 
-        output
+        if self.unipolar {
+            (output + 1.0) * 0.5
+        } else {
+            output
+        }
     }
 
     /// Reset phase to 0
     pub fn reset(&mut self) {
         self.phase = 0.0;
     }
+
+    /// Jump to a position in the cycle (0.0-1.0)
+    pub fn set_phase(&mut self, phase: f32) {
+        self.phase = phase.rem_euclid(1.0);
+    }
 }
 
 #[cfg(test)]
@@ -113,6 +144,53 @@ mod tests {
         assert!(sample == 1.0 || sample == -1.0);
     }
 
+    #[test]
+    fn test_lfo_square_pulse_width_sets_dwell() {
+        // Fraction of one second (one cycle at 1 Hz) the square spends high
+        let high_fraction = |pulse_width: f32| {
+            let mut lfo = LFO::new(44100.0);
+            lfo.set_waveform(LFOWaveform::Square);
+            lfo.set_rate(1.0);
+            lfo.set_pulse_width(pulse_width);
+            let high = (0..44100).filter(|_| lfo.process() > 0.0).count();
+            high as f32 / 44100.0
+        };
+
+        assert!((high_fraction(0.5) - 0.5).abs() < 0.01);
+        assert!((high_fraction(0.25) - 0.25).abs() < 0.01);
+        assert!((high_fraction(0.8) - 0.8).abs() < 0.01);
+        // Clamped to the usable range
+        assert!((high_fraction(1.0) - 0.95).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_lfo_unipolar_never_negative() {
+        for waveform in [
+            LFOWaveform::Sine,
+            LFOWaveform::Triangle,
+            LFOWaveform::Square,
+            LFOWaveform::Saw,
+        ] {
+            let mut lfo = LFO::new(44100.0);
+            lfo.set_waveform(waveform);
+            lfo.set_rate(5.0);
+            lfo.set_unipolar(true);
+
+            let samples: Vec<f32> = (0..44100).map(|_| lfo.process()).collect();
+            let min = samples.iter().cloned().fold(f32::INFINITY, f32::min);
+            let max = samples.iter().cloned().fold(f32::NEG_INFINITY, f32::max);
+            assert!(min >= 0.0, "{:?} went negative: {}", waveform, min);
+            // Still sweeps the full 0..1 range
+            assert!(
+                min < 0.01 && max > 0.99,
+                "{:?} range {}..{}",
+                waveform,
+                min,
+                max
+            );
+        }
+    }
+
     #[test]
     fn test_lfo_saw() {
         let mut lfo = LFO::new(44100.0);
@@ -1,11 +1,13 @@
-// Modulation Components - Envelopes, LFOs, envelope followers, and parameter mapping
+// Modulation Components - Envelopes, LFOs, drift, envelope followers, and parameter mapping
 
+pub mod drift;
 pub mod envelope;
 pub mod envelope_follower;
 pub mod lfo;
 pub mod parameter_mapper;
 pub mod processor_settings;
 
+pub use drift::Drift;
 pub use envelope::Envelope;
 pub use envelope_follower::{EnvelopeFollower, EnvelopeMode};
 pub use lfo::LFO;
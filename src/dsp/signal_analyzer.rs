@@ -318,8 +318,10 @@ impl SignalAnalyzer {
             }
         }
 
-        // Clear pitch confidence on silence (prevent stale state)
-        let is_silence = mono.abs() < 0.0001; // -80dB threshold
+        // Clear pitch confidence on silence (prevent stale state). Judged on the RMS
+        // envelope: a quiet tone passes near zero twice per cycle, and dropping its pitch
+        // there would flicker `is_pitched` until the next detection.
+        let is_silence = self.rms_envelope < 1e-8; // -80dB threshold (mean square)
         if is_silence {
             self.current_analysis.pitch_confidence = 0.0;
             self.current_analysis.is_pitched = false;
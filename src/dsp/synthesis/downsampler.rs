@@ -1,5 +1,8 @@
 use std::f32::consts::PI;
 
+/// Longest supported FIR (buffers are pre-allocated to this size)
+pub const MAX_TAPS: usize = 32;
+
 /// Kaiser window windowed-sinc FIR downsampler for 4:1 decimation
 /// Uses ~20 taps with Kaiser window (β=8.5) for high stopband attenuation
 pub struct Downsampler {
@@ -15,24 +18,52 @@ impl Downsampler {
     /// # Arguments
     /// * `taps` - Number of filter taps (should be multiple of 4, recommended ~20)
     pub fn new(taps: usize) -> Self {
-        assert!(
-            taps >= 4 && taps.is_multiple_of(4),
-            "Taps must be >= 4 and multiple of 4"
-        );
+        Self::assert_valid_taps(taps);
 
-        let coefficients = Self::calculate_kaiser_sinc_coefficients(taps, 8.5);
+        let mut coefficients = Vec::with_capacity(MAX_TAPS.max(taps));
+        Self::calculate_kaiser_sinc_coefficients(&mut coefficients, taps, 8.5);
+        let mut buffer = Vec::with_capacity(MAX_TAPS.max(taps));
+        buffer.resize(taps, 0.0);
 
         Self {
             taps,
             coefficients,
-            buffer: vec![0.0; taps],
+            buffer,
             buffer_index: 0,
         }
     }
 
-    /// Calculate windowed-sinc filter coefficients with Kaiser window
-    fn calculate_kaiser_sinc_coefficients(taps: usize, beta: f32) -> Vec<f32> {
-        let mut coeffs = Vec::with_capacity(taps);
+    fn assert_valid_taps(taps: usize) {
+        assert!(
+            taps >= 4 && taps.is_multiple_of(4),
+            "Taps must be >= 4 and multiple of 4"
+        );
+    }
+
+    /// Change the filter length (quality vs CPU), clearing the filter state.
+    ///
+    /// Reuses the pre-allocated buffers for lengths up to `MAX_TAPS`, so this does not
+    /// allocate; it does recompute the coefficients, so call it on change only.
+    pub fn set_taps(&mut self, taps: usize) {
+        Self::assert_valid_taps(taps);
+        if taps == self.taps {
+            return;
+        }
+        self.taps = taps;
+        Self::calculate_kaiser_sinc_coefficients(&mut self.coefficients, taps, 8.5);
+        self.buffer.clear();
+        self.buffer.resize(taps, 0.0);
+        self.buffer_index = 0;
+    }
+
+    /// Current filter length
+    pub fn taps(&self) -> usize {
+        self.taps
+    }
+
+    /// Calculate windowed-sinc filter coefficients with Kaiser window into `coeffs`
+    fn calculate_kaiser_sinc_coefficients(coeffs: &mut Vec<f32>, taps: usize, beta: f32) {
+        coeffs.clear();
         let cutoff = 0.25; // Cutoff at 1/4 of input sample rate (for 4:1 decimation)
         let center = (taps - 1) as f32 / 2.0;
 
@@ -59,8 +90,6 @@ impl Downsampler {
         for coeff in coeffs.iter_mut() {
             *coeff /= sum;
         }
-
-        coeffs
     }
 
     /// Modified Bessel function of the first kind (I0)
@@ -250,4 +279,24 @@ mod tests {
         assert!(ds.buffer.iter().all(|&x| x == 0.0));
         assert_eq!(ds.buffer_index, 0);
     }
+
+    #[test]
+    fn test_set_taps_reuses_buffers() {
+        let mut ds = Downsampler::new(20);
+        let capacity = ds.buffer.capacity();
+
+        for taps in [8, MAX_TAPS, 20] {
+            ds.set_taps(taps);
+            assert_eq!(ds.taps(), taps);
+            assert_eq!(ds.coefficients.len(), taps);
+            assert_eq!(ds.buffer.len(), taps);
+            let sum: f32 = ds.coefficients.iter().sum();
+            assert_relative_eq!(sum, 1.0, epsilon = 0.001);
+        }
+        assert_eq!(
+            ds.buffer.capacity(),
+            capacity,
+            "set_taps should not reallocate"
+        );
+    }
 }
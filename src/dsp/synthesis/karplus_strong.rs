@@ -0,0 +1,218 @@
+//! Karplus-Strong plucked string - the voice-level cousin of the `CombFilter` effect
+//!
+//! A short noise burst is written into a delay line one period long and then circulates
+//! through a lowpass loop filter: every pass around the loop repeats the burst (the pitch)
+//! and takes a little more of its high end away (the decay of a real string).
+//!
+//! # Parameters
+//! - **damping**: How quickly the string dies away (0.0 = ~10 s ring, 1.0 = ~0.1 s thud)
+//! - **brightness**: Tone of the pluck and of the loop filter (0.0 = dark and soft,
+//!   1.0 = bright and wiry)
+
+use crate::dsp::synthesis::waveform;
+
+/// Lowest note the delay line is sized for
+const MIN_FREQUENCY: f32 = 20.0;
+
+/// Ring time (seconds to -60 dB) at damping 0.0; each unit of damping divides it by 100
+const MAX_DECAY_SECONDS: f32 = 10.0;
+
+pub struct KarplusStrong {
+    sample_rate: f32,
+
+    /// Circular delay line holding one period of the string
+    buffer: Vec<f32>,
+    write_pos: usize,
+
+    /// Last sample read from the delay line, for the two-point loop filter
+    previous: f32,
+
+    damping: f32,    // 0.0 to 1.0
+    brightness: f32, // 0.0 to 1.0
+}
+
+impl KarplusStrong {
+    /// Create a new string
+    ///
+    /// # Arguments
+    /// * `sample_rate` - Audio sample rate in Hz
+    pub fn new(sample_rate: f32) -> Self {
+        let max_delay_samples = (sample_rate / MIN_FREQUENCY).ceil() as usize;
+        Self {
+            sample_rate,
+            buffer: vec![0.0; max_delay_samples + 2],
+            write_pos: 0,
+            previous: 0.0,
+            damping: 0.5,
+            brightness: 0.5,
+        }
+    }
+
+    /// Set decay damping (0.0 = long ring, 1.0 = short thud)
+    pub fn set_damping(&mut self, damping: f32) {
+        self.damping = damping.clamp(0.0, 1.0);
+    }
+
+    /// Set pluck and loop brightness (0.0 = dark, 1.0 = bright)
+    pub fn set_brightness(&mut self, brightness: f32) {
+        self.brightness = brightness.clamp(0.0, 1.0);
+    }
+
+    /// Delay line length in samples for `frequency`, before the loop filter's own delay
+    fn delay_samples(&self, frequency: f32) -> f32 {
+        let period = self.sample_rate / frequency.clamp(MIN_FREQUENCY, self.sample_rate * 0.25);
+        // The two-point loop filter delays by (1 - brightness) / 2 samples
+        let loop_filter_delay = (1.0 - self.brightness) * 0.5;
+        (period - loop_filter_delay).clamp(1.0, (self.buffer.len() - 2) as f32)
+    }
+
+    /// Excite the string with a fresh noise burst one period long
+    ///
+    /// # Arguments
+    /// * `frequency` - Note frequency in Hz (sets the burst length)
+    /// * `noise_state` - xorshift32 state the burst is drawn from
+    pub fn pluck(&mut self, frequency: f32, noise_state: &mut u32) {
+        self.buffer.fill(0.0);
+        self.write_pos = 0;
+        self.previous = 0.0;
+
+        // Darker plucks smooth the burst with a one-pole lowpass
+        let length = self.delay_samples(frequency).ceil() as usize;
+        let coefficient = 0.1 + 0.9 * self.brightness;
+        let len = self.buffer.len();
+        let mut smoothed = 0.0;
+        for i in 0..length {
+            let white = waveform::u32_to_f32_bipolar(waveform::xorshift32(noise_state));
+            smoothed += coefficient * (white - smoothed);
+            // Oldest sample first, ending just behind the write position
+            self.buffer[len - length + i] = smoothed;
+        }
+
+        // Remove the burst's DC (it would otherwise ring as an offset) and bring it to full scale
+        let burst = &mut self.buffer[len - length..];
+        let mean = burst.iter().sum::<f32>() / length as f32;
+        let peak = burst
+            .iter()
+            .fold(0.0_f32, |peak, &sample| peak.max((sample - mean).abs()));
+        if peak > 0.0 {
+            for sample in burst {
+                *sample = (*sample - mean) / peak;
+            }
+        }
+    }
+
+    /// Generate the next sample
+    ///
+    /// # Arguments
+    /// * `frequency` - Current note frequency in Hz (may glide or be modulated)
+    pub fn process(&mut self, frequency: f32) -> f32 {
+        let len = self.buffer.len();
+        let delay = self.delay_samples(frequency);
+
+        // Fractional read with linear interpolation, as in `CombFilter`
+        let read_pos = self.write_pos as f32 - delay + len as f32;
+        let index = read_pos.floor() as usize % len;
+        let next = (index + 1) % len;
+        let frac = read_pos - read_pos.floor();
+        let delayed = self.buffer[next] * frac + self.buffer[index] * (1.0 - frac);
+
+        // Loop filter: full brightness passes the sample through, zero averages two samples
+        let averaged = 0.5 * (delayed + self.previous);
+        let filtered = delayed * self.brightness + averaged * (1.0 - self.brightness);
+        self.previous = delayed;
+
+        // Per-pass gain giving the requested ring time at this pitch
+        let decay_seconds = MAX_DECAY_SECONDS * 0.01_f32.powf(self.damping);
+        let loop_gain = 0.001_f32.powf(1.0 / (decay_seconds * frequency.max(MIN_FREQUENCY)));
+        let output = filtered * loop_gain;
+
+        self.buffer[self.write_pos] = output;
+        self.write_pos = (self.write_pos + 1) % len;
+        output
+    }
+
+    /// Silence the string
+    pub fn clear(&mut self) {
+        self.buffer.fill(0.0);
+        self.previous = 0.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_RATE: f32 = 44100.0;
+
+    /// Fundamental period estimated from the strongest autocorrelation lag
+    fn estimate_frequency(samples: &[f32]) -> f32 {
+        let min_lag = (SAMPLE_RATE / 2000.0) as usize;
+        let max_lag = (SAMPLE_RATE / 50.0) as usize;
+        let correlation = |lag: usize| -> f32 {
+            samples
+                .iter()
+                .zip(&samples[lag..])
+                .map(|(a, b)| a * b)
+                .sum()
+        };
+        let best = (min_lag..max_lag)
+            .max_by(|&a, &b| correlation(a).total_cmp(&correlation(b)))
+            .unwrap();
+
+        // Parabolic interpolation around the peak for sub-sample accuracy
+        let (left, centre, right) = (
+            correlation(best - 1),
+            correlation(best),
+            correlation(best + 1),
+        );
+        let offset = 0.5 * (left - right) / (left - 2.0 * centre + right);
+        SAMPLE_RATE / (best as f32 + offset)
+    }
+
+    #[test]
+    fn test_pluck_pitch_matches_note() {
+        for (frequency, brightness) in [(110.0, 0.5), (220.0, 0.0), (440.0, 1.0), (329.63, 0.3)] {
+            let mut string = KarplusStrong::new(SAMPLE_RATE);
+            string.set_brightness(brightness);
+            let mut noise_state = 0x12345678;
+            string.pluck(frequency, &mut noise_state);
+
+            let samples: Vec<f32> = (0..8192).map(|_| string.process(frequency)).collect();
+            let measured = estimate_frequency(&samples[2048..]);
+            let cents = 1200.0 * (measured / frequency).log2();
+            assert!(
+                cents.abs() < 10.0,
+                "{} Hz (brightness {}): measured {} Hz ({} cents off)",
+                frequency,
+                brightness,
+                measured,
+                cents
+            );
+        }
+    }
+
+    #[test]
+    fn test_damping_shortens_decay() {
+        let tail_level = |damping: f32| {
+            let mut string = KarplusStrong::new(SAMPLE_RATE);
+            string.set_damping(damping);
+            let mut noise_state = 0x12345678;
+            string.pluck(220.0, &mut noise_state);
+            let samples: Vec<f32> = (0..22050).map(|_| string.process(220.0)).collect();
+            samples[17640..].iter().map(|s| s.abs()).fold(0.0, f32::max)
+        };
+
+        let ringing = tail_level(0.0);
+        let damped = tail_level(1.0);
+        assert!(
+            ringing > 0.05,
+            "undamped string should still ring: {}",
+            ringing
+        );
+        assert!(
+            damped < 0.001,
+            "fully damped string should be silent: {}",
+            damped
+        );
+    }
+}
@@ -1,12 +1,16 @@
 // Synthesis Components - Core oscillators, waveforms, and wavetables
 
 pub mod downsampler;
+pub mod karplus_strong;
+pub mod noise_burst;
 pub mod oscillator;
 pub mod waveform;
 pub mod wavetable;
 pub mod wavetable_library;
 
 pub use downsampler::Downsampler;
+pub use karplus_strong::KarplusStrong;
+pub use noise_burst::NoiseBurst;
 pub use oscillator::Oscillator;
 pub use wavetable::Wavetable;
 pub use wavetable_library::WavetableLibrary;
@@ -0,0 +1,144 @@
+use crate::dsp::synthesis::waveform::{u32_to_f32_bipolar, xorshift32};
+
+/// Level the burst decays to (-60 dB) by the end of its length
+const BURST_END_LEVEL: f32 = 0.001;
+
+/// Short highpassed noise burst for adding click and "air" to note attacks
+///
+/// Each `trigger()` starts a burst at full level that decays exponentially to -60 dB
+/// over the burst length and then stops. A one-pole highpass keeps the burst to the
+/// top of the spectrum so it sharpens the attack without thickening the note.
+pub struct NoiseBurst {
+    sample_rate: f32,
+    length_samples: u32,
+    remaining: u32,
+    gain: f32,
+    decay: f32,
+    hp_coeff: f32,
+    hp_prev_in: f32,
+    hp_prev_out: f32,
+    rng_state: u32,
+}
+
+impl NoiseBurst {
+    /// Create a new burst generator (8 ms, 4 kHz highpass)
+    ///
+    /// # Arguments
+    /// * `sample_rate` - Sample rate in Hz
+    /// * `seed` - PRNG seed (0 is replaced by a fixed non-zero value)
+    pub fn new(sample_rate: f32, seed: u32) -> Self {
+        let mut burst = Self {
+            sample_rate,
+            length_samples: 0,
+            remaining: 0,
+            gain: 0.0,
+            decay: 0.0,
+            hp_coeff: 0.0,
+            hp_prev_in: 0.0,
+            hp_prev_out: 0.0,
+            rng_state: 1,
+        };
+        burst.seed(seed);
+        burst.set_length_ms(8.0);
+        burst.set_highpass(4000.0);
+        burst
+    }
+
+    /// Restart the noise sequence from a seed (same seed = same bursts)
+    pub fn seed(&mut self, seed: u32) {
+        self.rng_state = if seed == 0 { 0x2545_F491 } else { seed };
+    }
+
+    /// Set the burst length in milliseconds (1 to 50 ms); applies from the next trigger
+    pub fn set_length_ms(&mut self, length_ms: f32) {
+        let length_samples = (length_ms.clamp(1.0, 50.0) * 0.001 * self.sample_rate).max(1.0);
+        self.length_samples = length_samples as u32;
+        self.decay = BURST_END_LEVEL.powf(1.0 / length_samples);
+    }
+
+    /// Set the highpass cutoff in Hz (clamped below Nyquist)
+    pub fn set_highpass(&mut self, cutoff_hz: f32) {
+        let cutoff = cutoff_hz.clamp(20.0, self.sample_rate * 0.45);
+        let rc = 1.0 / (2.0 * std::f32::consts::PI * cutoff);
+        let dt = 1.0 / self.sample_rate;
+        self.hp_coeff = rc / (rc + dt);
+    }
+
+    /// Start a new burst from full level
+    pub fn trigger(&mut self) {
+        self.remaining = self.length_samples;
+        self.gain = 1.0;
+        self.hp_prev_in = 0.0;
+        self.hp_prev_out = 0.0;
+    }
+
+    /// Is a burst sounding?
+    pub fn is_active(&self) -> bool {
+        self.remaining > 0
+    }
+
+    /// Generate the next sample (0.0 once the burst has ended)
+    #[inline]
+    pub fn process(&mut self) -> f32 {
+        if self.remaining == 0 {
+            return 0.0;
+        }
+        self.remaining -= 1;
+
+        let noise = u32_to_f32_bipolar(xorshift32(&mut self.rng_state));
+        let highpassed = self.hp_coeff * (self.hp_prev_out + noise - self.hp_prev_in);
+        self.hp_prev_in = noise;
+        self.hp_prev_out = highpassed;
+
+        let output = highpassed * self.gain;
+        self.gain *= self.decay;
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_burst_stops_after_its_length() {
+        let mut burst = NoiseBurst::new(44100.0, 1);
+        burst.set_length_ms(10.0);
+        assert_eq!(burst.process(), 0.0, "Silent until triggered");
+
+        burst.trigger();
+        let samples: Vec<f32> = (0..1000).map(|_| burst.process()).collect();
+        let early_peak = samples[..44].iter().fold(0.0_f32, |m, s| m.max(s.abs()));
+        let late_peak = samples[400..441]
+            .iter()
+            .fold(0.0_f32, |m, s| m.max(s.abs()));
+        assert!(early_peak > 0.1, "Burst should start loud: {}", early_peak);
+        assert!(
+            late_peak < early_peak * 0.01,
+            "Decays to -60 dB: {}",
+            late_peak
+        );
+        assert!(samples[441..].iter().all(|&s| s == 0.0));
+        assert!(!burst.is_active());
+    }
+
+    #[test]
+    fn test_highpass_removes_low_content() {
+        // Mean of the burst over short blocks tracks its low-frequency content
+        let block_mean_energy = |cutoff: f32| {
+            let mut burst = NoiseBurst::new(44100.0, 7);
+            burst.set_length_ms(50.0);
+            burst.set_highpass(cutoff);
+            burst.trigger();
+            let samples: Vec<f32> = (0..2205).map(|_| burst.process()).collect();
+            let total: f32 = samples.iter().map(|s| s * s).sum();
+            let low: f32 = samples
+                .chunks(32)
+                .map(|block| (block.iter().sum::<f32>() / 32.0).powi(2) * 32.0)
+                .sum();
+            low / total
+        };
+
+        assert!(block_mean_energy(8000.0) < block_mean_energy(20.0) * 0.5);
+    }
+}
@@ -1,10 +1,15 @@
 use crate::dsp::synthesis::downsampler::Downsampler;
+use crate::dsp::synthesis::karplus_strong::KarplusStrong;
 use crate::dsp::synthesis::waveform;
-use crate::params::Waveform;
+use crate::params::{OscillatorParams, Waveform};
 
 #[cfg(feature = "simd")]
 use std::simd::{cmp::SimdPartialOrd, f32x4, StdFloat};
 
+/// Phase offset (in cycles) applied at full self-feedback: π/2 rad, where a fed-back
+/// sine reaches a saw-like spectrum without turning chaotic
+const FM_FEEDBACK_MAX_CYCLES: f32 = 0.25;
+
 /// A polyphonic-safe oscillator with 4× oversampling and anti-aliasing.
 ///
 /// This oscillator is the core sound generation component of the synthesizer. It produces
@@ -30,6 +35,7 @@ use std::simd::{cmp::SimdPartialOrd, f32x4, StdFloat};
 /// - **Sawtooth**: Buzzy, bright tone with all harmonics
 /// - **Triangle**: Less bright than square, with only odd harmonics but weaker
 /// - **Pulse**: Square wave variant with variable pulse width (duty cycle)
+/// - **Pluck**: Karplus-Strong plucked string, re-plucked on every note
 ///
 /// ## Wave Shaping
 ///
@@ -88,9 +94,17 @@ pub struct Oscillator {
     /// Synthesized from harmonic amplitudes when set_additive_harmonics() is called
     additive_wavetable: [f32; 2048],
 
-    /// Current harmonic amplitudes for additive synthesis (8 harmonics)
+    /// Current harmonic amplitudes for additive synthesis (up to 32 harmonics)
     /// Index 0 = fundamental, 1 = 2nd harmonic, etc.
-    additive_harmonics: [f32; 8],
+    additive_harmonics: [f32; OscillatorParams::MAX_ADDITIVE_HARMONICS],
+
+    /// When true, the additive wavetable is scaled so its actual peak is 1.0
+    /// (before `additive_gain`), giving every harmonic mix the same peak level.
+    /// When false, the legacy amplitude-sum normalization is used.
+    additive_normalize: bool,
+
+    /// Linear output trim applied to the additive wavetable (1.0 = unity)
+    additive_gain: f32,
 
     /// Current wavetable index when waveform is Wavetable (0 to N-1)
     wavetable_index: usize,
@@ -104,6 +118,20 @@ pub struct Oscillator {
     /// Copied from WavetableLibrary when wavetable_index changes
     /// Stores 4× oversampled wavetable (8192 samples for 2048-sample base)
     current_wavetable_4x: Option<Vec<f32>>,
+
+    /// Karplus-Strong string for the Pluck waveform
+    /// Preallocated so switching to Pluck never allocates on the audio thread
+    pluck: KarplusStrong,
+
+    /// Set on reset so the string is plucked at the next Pluck sample, once the note's
+    /// frequency is known
+    pluck_pending: bool,
+
+    /// Self-feedback FM amount (0.0 to 1.0, 0.0 = off)
+    fm_feedback: f32,
+
+    /// Last two oversampled outputs, averaged to drive self-feedback FM
+    feedback_history: [f32; 2],
 }
 
 impl Oscillator {
@@ -144,10 +172,16 @@ impl Oscillator {
             pink_b1: 0.0,
             pink_b2: 0.0,
             additive_wavetable: [0.0; 2048],
-            additive_harmonics: [1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+            additive_harmonics: std::array::from_fn(|n| if n == 0 { 1.0 } else { 0.0 }),
+            additive_normalize: false,
+            additive_gain: 1.0,
             wavetable_index: 0,
             wavetable_position: 0.0,
             current_wavetable_4x: None,
+            pluck: KarplusStrong::new(sample_rate),
+            pluck_pending: true,
+            fm_feedback: 0.0,
+            feedback_history: [0.0; 2],
         };
         // Generate default wavetable (pure sine from fundamental harmonic)
         osc.generate_additive_wavetable();
@@ -229,22 +263,60 @@ impl Oscillator {
         self.shape = shape.clamp(-1.0, 1.0);
     }
 
+    /// Set the Pluck waveform's string damping and brightness (both 0.0 to 1.0).
+    pub fn set_pluck(&mut self, damping: f32, brightness: f32) {
+        self.pluck.set_damping(damping);
+        self.pluck.set_brightness(brightness);
+    }
+
+    /// Set the self-feedback FM amount (0.0 to 1.0).
+    ///
+    /// The oscillator phase-modulates itself with its own previous output, the DX7
+    /// operator feedback trick. On a sine this walks the spectrum from pure sine
+    /// (0.0) towards a saw (1.0). The last two samples are averaged, as on the DX7,
+    /// to keep high settings from breaking into noise.
+    pub fn set_fm_feedback(&mut self, amount: f32) {
+        self.fm_feedback = amount.clamp(0.0, 1.0);
+    }
+
     /// Set the harmonic amplitudes for additive synthesis and regenerate the wavetable.
     ///
-    /// The harmonics array contains 8 amplitude values (0.0 to 1.0) representing the
-    /// strength of each harmonic component:
+    /// The harmonics slice contains up to 32 amplitude values (0.0 to 1.0) representing the
+    /// strength of each harmonic component (missing ones are silent):
     /// - Index 0: Fundamental frequency (1×)
     /// - Index 1: 2nd harmonic (2×)
     /// - Index 2: 3rd harmonic (3×)
     /// - ...
-    /// - Index 7: 8th harmonic (8×)
+    /// - Index 31: 32nd harmonic (32×)
     ///
-    /// The wavetable is immediately regenerated using additive synthesis:
+    /// When the amplitudes changed, the wavetable is regenerated using additive synthesis:
     /// `waveform = Σ(harmonic[n] × sin(2π × (n+1) × phase))`
     ///
     /// The resulting waveform is normalized to prevent clipping.
-    pub fn set_additive_harmonics(&mut self, harmonics: [f32; 8]) {
-        self.additive_harmonics = harmonics;
+    pub fn set_additive_harmonics(&mut self, harmonics: &[f32]) {
+        let mut amplitudes = [0.0; OscillatorParams::MAX_ADDITIVE_HARMONICS];
+        let count = harmonics.len().min(amplitudes.len());
+        amplitudes[..count].copy_from_slice(&harmonics[..count]);
+        if amplitudes == self.additive_harmonics {
+            return;
+        }
+        self.additive_harmonics = amplitudes;
+        self.generate_additive_wavetable();
+    }
+
+    /// Set the additive level compensation and regenerate the wavetable if it changed.
+    ///
+    /// * `normalize` - Scale the summed harmonics to a consistent peak of 1.0. With this
+    ///   off, the wavetable is divided by the sum of harmonic amplitudes, which keeps it
+    ///   within ±1 but lets the real peak vary with the harmonic mix.
+    /// * `gain` - Manual linear trim applied after normalization (clamped to 0.0-2.0)
+    pub fn set_additive_level(&mut self, normalize: bool, gain: f32) {
+        let gain = gain.clamp(0.0, 2.0);
+        if normalize == self.additive_normalize && gain == self.additive_gain {
+            return;
+        }
+        self.additive_normalize = normalize;
+        self.additive_gain = gain;
         self.generate_additive_wavetable();
     }
 
@@ -300,11 +372,11 @@ impl Oscillator {
             let phase = i as f32 / 2048.0; // 0.0 to 1.0
             let mut sample = 0.0;
 
-            // Sum all 8 harmonics
+            // Sum all harmonics
             for (n, &amplitude) in self.additive_harmonics.iter().enumerate() {
                 if amplitude > 0.001 {
                     // Skip near-zero harmonics for efficiency
-                    let harmonic_freq = (n + 1) as f32; // 1, 2, 3, ..., 8
+                    let harmonic_freq = (n + 1) as f32; // 1, 2, 3, ..., 32
                     sample += amplitude * (2.0 * PI * harmonic_freq * phase).sin();
                 }
             }
@@ -312,6 +384,26 @@ impl Oscillator {
             // Normalize to prevent clipping
             self.additive_wavetable[i] = sample * norm_factor;
         }
+
+        // Optional peak normalization: harmonics rarely all peak at the same phase, so the
+        // amplitude-sum normalization above usually leaves the table well below ±1 by an
+        // amount that depends on the mix. Rescale to the measured peak for consistent level.
+        let mut scale = self.additive_gain;
+        if self.additive_normalize {
+            let peak = self
+                .additive_wavetable
+                .iter()
+                .fold(0.0_f32, |max, &s| max.max(s.abs()));
+            if peak > 0.001 {
+                scale /= peak;
+            }
+        }
+
+        if scale != 1.0 {
+            for sample in self.additive_wavetable.iter_mut() {
+                *sample *= scale;
+            }
+        }
     }
 
     /// Lookup sample from additive wavetable with linear interpolation.
@@ -384,7 +476,7 @@ impl Oscillator {
                 let tanh_approx = driven - x3 / f32x4::splat(3.0);
                 tanh_approx / drive.sqrt()
             }
-            Waveform::Saw => {
+            Waveform::Saw | Waveform::AnalogSaw => {
                 // Saw: morph towards triangle (removes harsh harmonics)
                 let triangle = f32x4::from_array([
                     if self.phase < 0.5 {
@@ -410,7 +502,7 @@ impl Oscillator {
                 ]);
                 samples * (f32x4::splat(1.0) - shape_amount) + triangle * shape_amount
             }
-            Waveform::Square => {
+            Waveform::Square | Waveform::AnalogSquare => {
                 // Square: morph pulse width (but this is handled by Pulse waveform)
                 samples
             }
@@ -428,7 +520,8 @@ impl Oscillator {
             Waveform::WhiteNoise
             | Waveform::PinkNoise
             | Waveform::Additive
-            | Waveform::Wavetable => samples, // Handled separately
+            | Waveform::Wavetable
+            | Waveform::Pluck => samples, // Handled separately
         }
     }
 
@@ -499,6 +592,20 @@ impl Oscillator {
         pink * 0.11 // Scale to roughly match white noise amplitude
     }
 
+    /// Generate a single Pluck sample from the Karplus-Strong string.
+    ///
+    /// Like noise, the string bypasses oversampling: its loop filter already keeps the
+    /// spectrum well below Nyquist. The first sample after a reset plucks the string with
+    /// a burst from the noise PRNG, tuned to the frequency set for the note.
+    fn generate_pluck_sample(&mut self) -> f32 {
+        let frequency = self.phase_increment * self.oversample_rate;
+        if self.pluck_pending {
+            self.pluck_pending = false;
+            self.pluck.pluck(frequency, &mut self.noise_state);
+        }
+        self.pluck.process(frequency)
+    }
+
     /// Apply harmonic morphing to additive waveform (SIMD version).
     ///
     /// The shape parameter morphs the harmonic balance:
@@ -566,10 +673,18 @@ impl Oscillator {
     /// must be done correctly to avoid cumulative rounding errors.
     #[cfg(feature = "simd")]
     pub fn process(&mut self) -> f32 {
+        // Self-feedback needs the per-sample phase modulation path
+        if self.fm_feedback > 0.0 {
+            return self.process_with_fm(0.0, 0.0);
+        }
+
         // Fast path for noise waveforms: bypass oversampling/downsampling
         if matches!(self.waveform, Waveform::WhiteNoise | Waveform::PinkNoise) {
             return self.generate_noise_sample();
         }
+        if self.waveform == Waveform::Pluck {
+            return self.generate_pluck_sample();
+        }
 
         // OPTIMIZATION: Early return if shape is effectively zero (skip expensive shaping)
         if self.shape.abs() < 0.001
@@ -677,10 +792,18 @@ impl Oscillator {
     /// point operations instead of vector operations.
     #[cfg(not(feature = "simd"))]
     pub fn process(&mut self) -> f32 {
+        // Self-feedback needs the per-sample phase modulation path
+        if self.fm_feedback > 0.0 {
+            return self.process_with_fm(0.0, 0.0);
+        }
+
         // Fast path for noise waveforms: bypass oversampling/downsampling
         if matches!(self.waveform, Waveform::WhiteNoise | Waveform::PinkNoise) {
             return self.generate_noise_sample();
         }
+        if self.waveform == Waveform::Pluck {
+            return self.generate_pluck_sample();
+        }
 
         // OPTIMIZATION: Early return if shape is effectively zero (skip expensive shaping)
         if self.shape.abs() < 0.001
@@ -770,6 +893,7 @@ impl Oscillator {
     ///
     /// - Phase modulation: `phase_modulated = phase + modulator_output * fm_amount`
     /// - The modulator_output is clamped to prevent extreme phase shifts that could cause aliasing
+    /// - Self-feedback (see `set_fm_feedback()`) is added on top of the external modulator
     /// - This method processes with oversampling just like the regular process() method
     /// - Noise waveforms bypass oversampling and ignore FM (noise is already broadband)
     pub fn process_with_fm(&mut self, modulator_output: f32, fm_amount: f32) -> f32 {
@@ -777,6 +901,9 @@ impl Oscillator {
         if matches!(self.waveform, Waveform::WhiteNoise | Waveform::PinkNoise) {
             return self.generate_noise_sample();
         }
+        if self.waveform == Waveform::Pluck {
+            return self.generate_pluck_sample();
+        }
 
         // Clamp modulator to prevent extreme phase shifts
         let mod_clamped = modulator_output.clamp(-1.0, 1.0);
@@ -786,8 +913,19 @@ impl Oscillator {
         let mut oversampled = [0.0; 4];
 
         for sample in &mut oversampled {
+            // Self-feedback: up to a quarter cycle (π/2 rad) from the averaged last outputs
+            let feedback_offset = if self.fm_feedback > 0.0 {
+                (self.feedback_history[0] + self.feedback_history[1])
+                    * 0.5
+                    * self.fm_feedback
+                    * FM_FEEDBACK_MAX_CYCLES
+            } else {
+                0.0
+            };
+
             // Apply phase modulation: shift the phase by the modulator output
-            let modulated_phase = (self.phase + phase_offset).fract();
+            // (rem_euclid keeps negative offsets inside 0.0-1.0)
+            let modulated_phase = (self.phase + phase_offset + feedback_offset).rem_euclid(1.0);
 
             *sample = match self.waveform {
                 Waveform::Pulse => {
@@ -821,7 +959,7 @@ impl Oscillator {
                         let tanh_approx = driven - (driven * driven * driven) / 3.0;
                         tanh_approx / drive.sqrt()
                     }
-                    Waveform::Saw => {
+                    Waveform::Saw | Waveform::AnalogSaw => {
                         // Morph towards triangle
                         let triangle = if modulated_phase < 0.5 {
                             4.0 * modulated_phase - 1.0
@@ -839,6 +977,10 @@ impl Oscillator {
                 };
             }
 
+            if self.fm_feedback > 0.0 {
+                self.feedback_history = [*sample, self.feedback_history[0]];
+            }
+
             // Advance phase (carrier's natural frequency progression)
             self.phase += self.phase_increment;
             if self.phase >= 1.0 {
@@ -866,7 +1008,7 @@ impl Oscillator {
                 let tanh_approx = driven - (driven * driven * driven) / 3.0;
                 tanh_approx / drive.sqrt()
             }
-            Waveform::Saw => {
+            Waveform::Saw | Waveform::AnalogSaw => {
                 // Morph towards triangle
                 let triangle = if self.phase < 0.5 {
                     4.0 * self.phase - 1.0
@@ -875,7 +1017,7 @@ impl Oscillator {
                 };
                 sample * (1.0 - shape_amount) + triangle * shape_amount
             }
-            Waveform::Square => sample,
+            Waveform::Square | Waveform::AnalogSquare => sample,
             Waveform::Triangle => {
                 // Add sharpness (morph towards saw)
                 let saw = 2.0 * self.phase - 1.0;
@@ -885,7 +1027,8 @@ impl Oscillator {
             Waveform::WhiteNoise
             | Waveform::PinkNoise
             | Waveform::Additive
-            | Waveform::Wavetable => sample,
+            | Waveform::Wavetable
+            | Waveform::Pluck => sample,
         }
     }
 
@@ -909,6 +1052,18 @@ impl Oscillator {
         self.pink_b0 = 0.0;
         self.pink_b1 = 0.0;
         self.pink_b2 = 0.0;
+
+        self.pluck.clear();
+        self.pluck_pending = true;
+        self.feedback_history = [0.0; 2];
+    }
+
+    /// Set the length of the 4:1 decimation filter (anti-aliasing quality vs CPU).
+    ///
+    /// Longer filters reject more aliasing; see `Quality::downsampler_taps()`.
+    /// Clears the downsampler state, so change it between notes where possible.
+    pub fn set_downsampler_taps(&mut self, taps: usize) {
+        self.downsampler.set_taps(taps);
     }
 
     /// Reset only the internal buffers (downsampler) without changing phase.
@@ -926,6 +1081,10 @@ impl Oscillator {
         self.pink_b0 = 0.0;
         self.pink_b1 = 0.0;
         self.pink_b2 = 0.0;
+
+        // Re-pluck the string for the new note
+        self.pluck_pending = true;
+        self.feedback_history = [0.0; 2];
     }
 }
 
@@ -1146,6 +1305,66 @@ mod tests {
         );
     }
 
+    /// Harmonic magnitudes 1..=count of a 441 Hz tone (exactly 100 samples per cycle)
+    fn harmonic_magnitudes(waveform: Waveform, count: usize) -> Vec<f32> {
+        let mut osc = Oscillator::new(44100.0);
+        osc.set_waveform(waveform);
+        oscillator_harmonic_magnitudes(&mut osc, count)
+    }
+
+    /// Harmonic magnitudes 1..=count of `osc` played at 441 Hz
+    fn oscillator_harmonic_magnitudes(osc: &mut Oscillator, count: usize) -> Vec<f32> {
+        osc.set_frequency(441.0);
+        for _ in 0..1000 {
+            osc.process();
+        }
+        let samples: Vec<f32> = (0..4400).map(|_| osc.process()).collect();
+        (1..=count)
+            .map(|harmonic| {
+                let (mut re, mut im) = (0.0, 0.0);
+                for (n, sample) in samples.iter().enumerate() {
+                    let angle = 2.0 * std::f32::consts::PI * harmonic as f32 * n as f32 / 100.0;
+                    re += sample * angle.cos();
+                    im += sample * angle.sin();
+                }
+                (re * re + im * im).sqrt() / samples.len() as f32
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_analog_waveforms_differ_from_clean_spectra() {
+        // Analog square: the off-center duty cycle adds a 2nd harmonic a clean square lacks
+        let clean = harmonic_magnitudes(Waveform::Square, 2);
+        let analog = harmonic_magnitudes(Waveform::AnalogSquare, 2);
+        assert!(
+            clean[1] / clean[0] < 0.01,
+            "Clean square H2/H1 {}",
+            clean[1] / clean[0]
+        );
+        assert!(
+            analog[1] / analog[0] > 0.03,
+            "Analog square H2/H1 {}",
+            analog[1] / analog[0]
+        );
+
+        // Analog saw: the reset slope rolls off the upper harmonics relative to a clean saw
+        let clean = harmonic_magnitudes(Waveform::Saw, 15);
+        let analog = harmonic_magnitudes(Waveform::AnalogSaw, 15);
+        let clean_ratio = clean[14] / clean[0];
+        let analog_ratio = analog[14] / analog[0];
+        assert!(
+            analog_ratio < clean_ratio * 0.85,
+            "Analog saw H15/H1 {} should fall below clean {}",
+            analog_ratio,
+            clean_ratio
+        );
+        assert!(
+            analog_ratio > clean_ratio * 0.4,
+            "Analog saw should stay saw-like"
+        );
+    }
+
     #[test]
     fn test_aliasing_reduction() {
         let mut osc = Oscillator::new(44100.0);
@@ -1433,4 +1652,141 @@ mod tests {
             prev_sample = sample;
         }
     }
+
+    #[test]
+    fn test_additive_normalize_gives_consistent_peak() {
+        let peak = |osc: &Oscillator| {
+            osc.additive_wavetable
+                .iter()
+                .fold(0.0_f32, |max, &s| max.max(s.abs()))
+        };
+        let mut osc = Oscillator::new(44100.0);
+        let mixes = [
+            [1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+            [1.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+            [1.0, 0.0, 0.5, 0.0, 0.3, 0.0, 0.2, 0.0],
+            [1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0],
+        ];
+
+        // Legacy behavior (default): peak varies with the harmonic mix
+        osc.set_additive_harmonics(&mixes[3]);
+        let legacy_peak = peak(&osc);
+        assert!(legacy_peak < 0.9, "Legacy peak was {}", legacy_peak);
+
+        // Normalized: every mix lands on the same peak, scaled by the trim
+        osc.set_additive_level(true, 0.5);
+        for harmonics in mixes {
+            osc.set_additive_harmonics(&harmonics);
+            assert_relative_eq!(peak(&osc), 0.5, epsilon = 1e-4);
+        }
+
+        // Turning normalization off restores the legacy table
+        osc.set_additive_level(false, 1.0);
+        assert_relative_eq!(peak(&osc), legacy_peak, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_additive_extended_harmonics_spectrum() {
+        use std::f32::consts::PI;
+
+        // Amplitude of harmonic `k` in the single-cycle wavetable (one DFT bin)
+        let harmonic = |osc: &Oscillator, k: usize| {
+            let (re, im) = osc.additive_wavetable.iter().enumerate().fold(
+                (0.0_f32, 0.0_f32),
+                |(re, im), (i, &s)| {
+                    let angle = 2.0 * PI * (k * i) as f32 / 2048.0;
+                    (re + s * angle.cos(), im + s * angle.sin())
+                },
+            );
+            2.0 * (re * re + im * im).sqrt() / 2048.0
+        };
+
+        let mut osc = Oscillator::new(44100.0);
+        let mut harmonics = [0.0; OscillatorParams::MAX_ADDITIVE_HARMONICS];
+        harmonics[0] = 1.0;
+        harmonics[15] = 0.5;
+        harmonics[31] = 0.25;
+        osc.set_additive_harmonics(&harmonics);
+
+        // Legacy normalization divides by the amplitude sum (1.75)
+        assert_relative_eq!(harmonic(&osc, 1), 1.0 / 1.75, epsilon = 1e-3);
+        assert_relative_eq!(harmonic(&osc, 16), 0.5 / 1.75, epsilon = 1e-3);
+        assert_relative_eq!(harmonic(&osc, 32), 0.25 / 1.75, epsilon = 1e-3);
+        assert!(harmonic(&osc, 8) < 1e-3, "Unset harmonics are silent");
+        assert!(harmonic(&osc, 33) < 1e-3, "Nothing above the 32nd harmonic");
+    }
+
+    #[test]
+    fn test_fm_feedback_walks_sine_towards_saw() {
+        let spectrum = |feedback: f32| {
+            let mut osc = Oscillator::new(44100.0);
+            osc.set_fm_feedback(feedback);
+            let magnitudes = oscillator_harmonic_magnitudes(&mut osc, 6);
+            magnitudes
+                .iter()
+                .map(|m| m / magnitudes[0])
+                .collect::<Vec<f32>>()
+        };
+        let upper_energy = |ratios: &[f32]| ratios[1..].iter().map(|r| r * r).sum::<f32>();
+
+        let dry = spectrum(0.0);
+        assert!(upper_energy(&dry) < 1e-3, "No feedback is a pure sine");
+
+        // More feedback, more harmonics
+        let mut previous = upper_energy(&dry);
+        for feedback in [0.25, 0.5, 0.75, 1.0] {
+            let energy = upper_energy(&spectrum(feedback));
+            assert!(
+                energy > previous * 1.1,
+                "Feedback {} harmonic energy {} should exceed {}",
+                feedback,
+                energy,
+                previous
+            );
+            previous = energy;
+        }
+
+        // Full feedback approaches a saw's 1/n series
+        let full = spectrum(1.0);
+        for (n, ratio) in full.iter().enumerate().skip(1) {
+            let saw = 1.0 / (n + 1) as f32;
+            assert!(
+                *ratio > saw * 0.5 && *ratio < saw * 1.2,
+                "Harmonic {} at {} should be near saw level {}",
+                n + 1,
+                ratio,
+                saw
+            );
+        }
+    }
+
+    #[test]
+    fn test_pluck_replucks_on_reset_buffers() {
+        let mut osc = Oscillator::new(44100.0);
+        osc.set_waveform(Waveform::Pluck);
+        osc.set_frequency(220.0);
+        osc.set_pluck(1.0, 0.5);
+
+        let peak = |osc: &mut Oscillator, samples: usize| {
+            (0..samples).fold(0.0_f32, |max, _| max.max(osc.process().abs()))
+        };
+
+        // Fully damped string dies within half a second
+        assert!(
+            peak(&mut osc, 1000) > 0.1,
+            "Pluck should sound at note start"
+        );
+        peak(&mut osc, 22050);
+        assert!(
+            peak(&mut osc, 1000) < 0.001,
+            "Damped string should have decayed"
+        );
+
+        // A new note plucks the string again
+        osc.reset_buffers();
+        assert!(
+            peak(&mut osc, 1000) > 0.1,
+            "Retrigger should re-pluck the string"
+        );
+    }
 }
@@ -25,6 +25,40 @@ pub fn u32_to_f32_bipolar(x: u32) -> f32 {
 #[cfg(feature = "simd")]
 use std::simd::{cmp::SimdPartialOrd, f32x4, StdFloat};
 
+/// Fraction of the analog saw's period spent on the falling reset (0 would be a clean saw)
+const ANALOG_SAW_RESET: f32 = 0.03;
+
+/// How far the analog saw's ramp bows away from a straight line
+const ANALOG_SAW_BEND: f32 = 0.12;
+
+/// Duty cycle of the analog square (a clean square is exactly 0.5)
+const ANALOG_SQUARE_DUTY: f32 = 0.52;
+
+/// How far the analog square's flat tops sag over each half cycle
+const ANALOG_SQUARE_DROOP: f32 = 0.08;
+
+/// Analog-style saw: a bowed ramp with a short reset slope, peak-normalized, no DC
+fn analog_saw(phase: f32) -> f32 {
+    let ramp = if phase < 1.0 - ANALOG_SAW_RESET {
+        2.0 * phase / (1.0 - ANALOG_SAW_RESET) - 1.0
+    } else {
+        1.0 - 2.0 * (phase - (1.0 - ANALOG_SAW_RESET)) / ANALOG_SAW_RESET
+    };
+    // ramp² averages 1/3 over the cycle, so the bend adds no DC
+    (ramp - ANALOG_SAW_BEND * (ramp * ramp - 1.0 / 3.0)) / (1.0 + ANALOG_SAW_BEND * 2.0 / 3.0)
+}
+
+/// Analog-style square: off-center duty cycle with drooping tops, peak-normalized, no DC
+fn analog_square(phase: f32) -> f32 {
+    let level = if phase < ANALOG_SQUARE_DUTY {
+        1.0 - ANALOG_SQUARE_DROOP * phase / ANALOG_SQUARE_DUTY
+    } else {
+        -1.0 + ANALOG_SQUARE_DROOP * (phase - ANALOG_SQUARE_DUTY) / (1.0 - ANALOG_SQUARE_DUTY)
+    };
+    let dc = (2.0 * ANALOG_SQUARE_DUTY - 1.0) * (1.0 - ANALOG_SQUARE_DROOP / 2.0);
+    (level - dc) / (1.0 + dc.abs())
+}
+
 /// Generate a scalar waveform sample at a given normalized phase [0.0, 1.0)
 ///
 /// # Arguments
@@ -70,6 +104,12 @@ pub fn generate_scalar(phase: f32, waveform: Waveform) -> f32 {
             // This path shouldn't be called for these waveforms
             0.0
         }
+        Waveform::AnalogSaw => analog_saw(phase),
+        Waveform::AnalogSquare => analog_square(phase),
+        Waveform::Pluck => {
+            // The plucked string is a stateful delay line, handled by oscillator
+            0.0
+        }
     }
 }
 
@@ -117,6 +157,12 @@ pub fn generate_simd(phases: f32x4, waveform: Waveform) -> f32x4 {
             // This path shouldn't be called for these waveforms
             f32x4::splat(0.0)
         }
+        Waveform::AnalogSaw => f32x4::from_array(phases.to_array().map(analog_saw)),
+        Waveform::AnalogSquare => f32x4::from_array(phases.to_array().map(analog_square)),
+        Waveform::Pluck => {
+            // The plucked string is a stateful delay line, handled by oscillator
+            f32x4::splat(0.0)
+        }
     }
 }
 
@@ -175,6 +221,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_analog_waveforms_range_and_dc() {
+        for waveform in [Waveform::AnalogSaw, Waveform::AnalogSquare] {
+            let samples: Vec<f32> = (0..1000)
+                .map(|i| generate_scalar(i as f32 / 1000.0, waveform))
+                .collect();
+            assert!(
+                samples.iter().all(|s| (-1.0..=1.0).contains(s)),
+                "{} out of range",
+                waveform
+            );
+            let mean = samples.iter().sum::<f32>() / samples.len() as f32;
+            assert!(mean.abs() < 0.01, "{} has DC offset {}", waveform, mean);
+        }
+    }
+
     #[test]
     fn test_pulse_range() {
         for i in 0..100 {
@@ -118,10 +118,25 @@ impl KickGuiState {
 impl Model for KickGuiState {
     fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
         event.map(|msg, meta| {
-            if let GuiMessage::ParamChanged(param_id, normalized) = msg {
-                self.update_param(*param_id, *normalized);
-                cx.needs_redraw();
-                meta.consume();
+            match msg {
+                GuiMessage::ParamChanged(param_id, normalized)
+                | GuiMessage::ParamReset(param_id, normalized) => {
+                    self.update_param(*param_id, *normalized);
+                    cx.needs_redraw();
+                    meta.consume();
+                }
+                GuiMessage::ParamTextEntered(param_id, text) => {
+                    // The knob visual catches up on the next host poll
+                    let parsed = get_kick_registry()
+                        .get_descriptor(*param_id)
+                        .and_then(|desc| desc.parse_value(text));
+                    if let Some(normalized) = parsed {
+                        self.update_param(*param_id, normalized);
+                    }
+                    cx.needs_redraw();
+                    meta.consume();
+                }
+                _ => {}
             }
         });
 
@@ -1,13 +1,176 @@
 // GUI messages for VIZIA event handling
 
+use crate::audio::engine::{EffectLevels, HeldNotes};
+use crate::plugin::ab_compare::AbSlot;
+use crate::plugin::param_descriptor::{
+    PARAM_ENVELOPE_ATTACK, PARAM_ENVELOPE_ATTACK_CURVE, PARAM_ENVELOPE_DECAY,
+    PARAM_ENVELOPE_DECAY_CURVE, PARAM_ENVELOPE_RELEASE, PARAM_ENVELOPE_RELEASE_CURVE,
+    PARAM_ENVELOPE_SUSTAIN, PARAM_FILTER1_ENV_ATTACK, PARAM_FILTER1_ENV_DECAY,
+    PARAM_FILTER1_ENV_RELEASE, PARAM_FILTER1_ENV_SUSTAIN, PARAM_FILTER2_ENV_ATTACK,
+    PARAM_FILTER2_ENV_DECAY, PARAM_FILTER2_ENV_RELEASE, PARAM_FILTER2_ENV_SUSTAIN,
+    PARAM_FILTER3_ENV_ATTACK, PARAM_FILTER3_ENV_DECAY, PARAM_FILTER3_ENV_RELEASE,
+    PARAM_FILTER3_ENV_SUSTAIN,
+};
+use crate::randomize::{RandomizeLocks, RandomizeSection};
+use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 use vizia::prelude::Data;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Data)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Data, Serialize, Deserialize)]
 pub enum UiTab {
     Oscillator,
     FilterLfo,
     Effects,
+    Search,
+}
+
+/// Where the editor was left: the plugin keeps this in its saved state so a reopened
+/// project shows the same tab and scroll position
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct GuiLayout {
+    pub tab: UiTab,
+    /// Vertical scroll of the tab content (0.0 = top, 1.0 = bottom)
+    pub scroll_y: f32,
+}
+
+impl Default for GuiLayout {
+    fn default() -> Self {
+        Self {
+            tab: UiTab::Oscillator,
+            scroll_y: 0.0,
+        }
+    }
+}
+
+/// Envelope the editor shows and edits
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EnvelopeTarget {
+    Amp,
+    /// Filter envelope by filter index (0-based)
+    Filter(usize),
+}
+
+impl Data for EnvelopeTarget {
+    fn same(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+
+impl Data for RandomizeLocks {
+    fn same(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+
+impl EnvelopeTarget {
+    /// Every envelope the editor can switch to, in selector order
+    pub const ALL: [EnvelopeTarget; 4] = [
+        EnvelopeTarget::Amp,
+        EnvelopeTarget::Filter(0),
+        EnvelopeTarget::Filter(1),
+        EnvelopeTarget::Filter(2),
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            EnvelopeTarget::Amp => "Amp",
+            EnvelopeTarget::Filter(0) => "Filter 1",
+            EnvelopeTarget::Filter(1) => "Filter 2",
+            _ => "Filter 3",
+        }
+    }
+
+    /// Parameter IDs: attack, decay, sustain, release
+    pub(crate) fn adsr_param_ids(self) -> [u32; 4] {
+        match self {
+            EnvelopeTarget::Amp => [
+                PARAM_ENVELOPE_ATTACK,
+                PARAM_ENVELOPE_DECAY,
+                PARAM_ENVELOPE_SUSTAIN,
+                PARAM_ENVELOPE_RELEASE,
+            ],
+            EnvelopeTarget::Filter(0) => [
+                PARAM_FILTER1_ENV_ATTACK,
+                PARAM_FILTER1_ENV_DECAY,
+                PARAM_FILTER1_ENV_SUSTAIN,
+                PARAM_FILTER1_ENV_RELEASE,
+            ],
+            EnvelopeTarget::Filter(1) => [
+                PARAM_FILTER2_ENV_ATTACK,
+                PARAM_FILTER2_ENV_DECAY,
+                PARAM_FILTER2_ENV_SUSTAIN,
+                PARAM_FILTER2_ENV_RELEASE,
+            ],
+            _ => [
+                PARAM_FILTER3_ENV_ATTACK,
+                PARAM_FILTER3_ENV_DECAY,
+                PARAM_FILTER3_ENV_SUSTAIN,
+                PARAM_FILTER3_ENV_RELEASE,
+            ],
+        }
+    }
+
+    /// Curve parameter IDs: attack, decay, release (filter envelopes are linear)
+    pub(crate) fn curve_param_ids(self) -> Option<[u32; 3]> {
+        match self {
+            EnvelopeTarget::Amp => Some([
+                PARAM_ENVELOPE_ATTACK_CURVE,
+                PARAM_ENVELOPE_DECAY_CURVE,
+                PARAM_ENVELOPE_RELEASE_CURVE,
+            ]),
+            EnvelopeTarget::Filter(_) => None,
+        }
+    }
+}
+
+/// Kind of indexed section whose settings can be copied between instances
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CopySection {
+    Oscillator,
+    Filter,
+    Lfo,
+}
+
+/// Registry modules shown on the Effects tab
+const EFFECT_MODULES: [&str; 25] = [
+    "Noise Gate",
+    "Master Transient",
+    "Compressor",
+    "Distortion",
+    "Waveshaper",
+    "Bitcrusher",
+    "Multiband Dist",
+    "Multiband Distortion",
+    "Exciter",
+    "Sibilance EQ",
+    "Formant Filter",
+    "Comb Filter",
+    "Phaser",
+    "Flanger",
+    "Ring Mod",
+    "Tremolo",
+    "Chorus",
+    "Doubler",
+    "Choir",
+    "Pitch Shifter",
+    "Delay",
+    "Auto-Pan",
+    "Mid-Side",
+    "Stereo Widener",
+    "Reverb",
+];
+
+impl UiTab {
+    /// Tab that shows the controls of a parameter registry module
+    pub fn for_module(module: &str) -> UiTab {
+        if module.starts_with("Filter") || module.starts_with("LFO") {
+            UiTab::FilterLfo
+        } else if EFFECT_MODULES.contains(&module) {
+            UiTab::Effects
+        } else {
+            UiTab::Oscillator
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -15,11 +178,37 @@ pub enum GuiMessage {
     // Parameter changes: (parameter_id, normalized_value)
     ParamChanged(u32, f32),
 
+    // One-shot parameter edit, e.g. reset to default: (parameter_id, normalized_value)
+    // Reported to the host as a whole automation gesture rather than part of a drag
+    ParamReset(u32, f32),
+
     // Sync knob visual to match model value (param_id, normalized_value)
     // Used after randomization or preset load to update UI
     SyncKnobValue(u32, f32),
 
-    // Preset management
+    // Effective (post-modulation) value for a knob's modulation ring:
+    // (parameter_id, normalized value, or None to hide the ring)
+    SyncModulation(u32, Option<f32>),
+
+    // Notes the engine currently holds, for the note display (engine feed)
+    SyncHeldNotes(HeldNotes),
+
+    // Per-effect output peaks and compressor gain reduction, for the meters (engine feed)
+    SyncEffectLevels(EffectLevels),
+
+    // Typed knob value to parse against the parameter's units: (parameter_id, text)
+    ParamTextEntered(u32, String),
+
+    // Envelope shown in the visual envelope editor (amp or a filter envelope)
+    SetEnvelopeEditorTarget(EnvelopeTarget),
+
+    // Parameter search box text (filters the registry for the Search tab)
+    SetParamSearch(String),
+
+    // Copy one section's settings onto another instance: (kind, from, to), 0-based
+    CopySection(CopySection, usize, usize),
+
+    // Preset management (versioned JSON; load also fires for files dropped on the window)
     PresetLoad(PathBuf),
     PresetSave(PathBuf),
 
@@ -29,7 +218,47 @@ pub enum GuiMessage {
     RandomizeFilters,
     RandomizeEnvelope,
     RandomizeEffects,
+    /// Lock/unlock a section so Randomize leaves it alone
+    ToggleRandomizeLock(RandomizeSection),
+
+    // A/B patch comparison
+    SelectAbSlot(AbSlot),
+    CopyAToB,
+
+    // Analyse the last second of output for mono-sum loss (runs on a worker thread)
+    CheckMonoCompatibility,
+
+    // Macros (0-based index): assign the last parameter moved, or remove every target
+    AssignMacro(usize),
+    ClearMacro(usize),
+
+    // Standalone MIDI input device (index into the port list)
+    SelectMidiPort(usize),
+
+    // Standalone audio output device, sample rate and buffer size (indices into the pickers)
+    SelectAudioDevice(usize),
+    SelectSampleRate(usize),
+    SelectBufferSize(usize),
+
+    // Standalone WAV recording of the output (start asks for a file)
+    ToggleRecording,
+
+    // Standalone preset export (asks for the file to save to)
+    ExportPreset,
+
+    // Standalone computer-keyboard octave/velocity, shown in the status text
+    KeyboardStatus(String),
+
+    // Undo/redo of parameter changes
+    Undo,
+    Redo,
 
     // UI navigation
     SetActiveTab(UiTab),
+
+    // Tab content scrolled (vertical position, 0.0 = top to 1.0 = bottom)
+    SetScrollY(f32),
+
+    // Window/content scale (index into theme::GUI_SCALE_CHOICES)
+    SetGuiScale(usize),
 }
@@ -2,10 +2,13 @@
 //
 // This module is only compiled when the "clap" feature is enabled.
 
+use crate::audio::engine::{SharedModulationFeed, SharedOutputHistory};
+use crate::gui::messages::GuiLayout;
 use crate::gui::shared_ui;
 use crate::gui::theme;
 use crate::gui::GuiState;
 use crate::params::SynthParams;
+use crate::plugin::ab_compare::AbCompare;
 use crate::plugin::gui_param_change::GuiParamChange;
 use parking_lot::{Mutex, RwLock};
 use raw_window_handle::{HasRawWindowHandle, RawWindowHandle as RawHandle};
@@ -39,6 +42,11 @@ pub fn open_editor(
     parent_window: raw_window_handle::RawWindowHandle,
     synth_params: Arc<RwLock<SynthParams>>,
     gui_param_producer: Arc<Mutex<Input<GuiParamChange>>>,
+    modulation_feed: SharedModulationFeed,
+    output_history: SharedOutputHistory,
+    ab_compare: Arc<Mutex<AbCompare>>,
+    gui_scale: Arc<Mutex<f64>>,
+    gui_layout: Arc<Mutex<GuiLayout>>,
 ) -> Option<EditorWindowHandle> {
     // Debug: Log GUI creation
     let debug_msg = "DEBUG: open_editor called - Starting GUI creation\n";
@@ -50,15 +58,23 @@ pub fn open_editor(
 
     // Wrap the raw window handle
     let window_wrapper = WindowHandleWrapper(parent_window);
+    let scale = *gui_scale.lock();
 
     let handle = Application::new(move |cx| {
         // Initialize GUI state with shared parameter access
-        GuiState::new(synth_params.clone(), gui_param_producer.clone()).build(cx);
+        GuiState::new(synth_params.clone(), gui_param_producer.clone())
+            .with_modulation_feed(modulation_feed.clone())
+            .with_output_history(output_history.clone())
+            .with_ab_compare(ab_compare.clone())
+            .with_gui_scale(gui_scale.clone())
+            .with_gui_layout(gui_layout.clone())
+            .build(cx);
 
         // Build the shared UI
         shared_ui::build_ui(cx);
     })
     .inner_size((theme::WINDOW_WIDTH, theme::WINDOW_HEIGHT))
+    .user_scale_factor(scale)
     .open_parented(&window_wrapper);
 
     Some(EditorWindowHandle { _inner: handle })
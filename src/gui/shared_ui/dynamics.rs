@@ -63,6 +63,40 @@ pub fn build_voice_compressor_section(cx: &mut Context) {
     .gap(Pixels(6.0));
 }
 
+pub fn build_voice_click_section(cx: &mut Context) {
+    HStack::new(cx, |cx| {
+        let enabled = current_normalized(cx, PARAM_VOICE_CLICK_ENABLED);
+        let level = current_normalized(cx, PARAM_VOICE_CLICK_LEVEL);
+        let length = current_normalized(cx, PARAM_VOICE_CLICK_LENGTH);
+        let highpass = current_normalized(cx, PARAM_VOICE_CLICK_HIGHPASS);
+
+        param_checkbox(cx, PARAM_VOICE_CLICK_ENABLED, "On", enabled > 0.5);
+        param_knob(
+            cx,
+            PARAM_VOICE_CLICK_LEVEL,
+            "Level",
+            level,
+            default_normalized(PARAM_VOICE_CLICK_LEVEL),
+        );
+        param_knob(
+            cx,
+            PARAM_VOICE_CLICK_LENGTH,
+            "Length",
+            length,
+            default_normalized(PARAM_VOICE_CLICK_LENGTH),
+        );
+        param_knob(
+            cx,
+            PARAM_VOICE_CLICK_HIGHPASS,
+            "HPF",
+            highpass,
+            default_normalized(PARAM_VOICE_CLICK_HIGHPASS),
+        );
+    })
+    .height(Units::Auto)
+    .gap(Pixels(6.0));
+}
+
 pub fn build_transient_shaper_section(cx: &mut Context) {
     HStack::new(cx, |cx| {
         let enabled = current_normalized(cx, PARAM_TRANSIENT_ENABLED);
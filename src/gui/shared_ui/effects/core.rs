@@ -1,7 +1,11 @@
-// Core effects: distortion, chorus, delay, reverb
+// Core effects: distortion, chorus, doubler, choir, delay, reverb
 
-use super::super::helpers::{current_normalized, default_normalized};
-use crate::gui::widgets::{distortion_type_button, param_checkbox, param_knob, tempo_sync_button};
+use super::super::helpers::{current_normalized, default_normalized, effect_header};
+use crate::audio::engine::EffectSlot;
+use crate::gui::widgets::{
+    distortion_type_button, effect_routing_button, feedback_curve_button, param_checkbox,
+    param_knob, reverb_algorithm_button, tempo_sync_button, EffectMeter,
+};
 use crate::plugin::param_descriptor::*;
 use vizia::prelude::*;
 
@@ -14,6 +18,7 @@ pub fn build_distortion_section(cx: &mut Context) {
                 .height(Pixels(22.0));
             let enabled = current_normalized(cx, PARAM_DISTORTION_ENABLED);
             param_checkbox(cx, PARAM_DISTORTION_ENABLED, "On", enabled > 0.5);
+            EffectMeter::new(cx, EffectSlot::Distortion);
         })
         .height(Units::Auto)
         .gap(Pixels(8.0));
@@ -53,6 +58,7 @@ pub fn build_chorus_section(cx: &mut Context) {
                 .height(Pixels(22.0));
             let enabled = current_normalized(cx, PARAM_CHORUS_ENABLED);
             param_checkbox(cx, PARAM_CHORUS_ENABLED, "On", enabled > 0.5);
+            EffectMeter::new(cx, EffectSlot::Chorus);
         })
         .height(Units::Auto)
         .gap(Pixels(8.0));
@@ -61,6 +67,8 @@ pub fn build_chorus_section(cx: &mut Context) {
             let rate_v = current_normalized(cx, PARAM_CHORUS_RATE);
             let depth_v = current_normalized(cx, PARAM_CHORUS_DEPTH);
             let mix_v = current_normalized(cx, PARAM_CHORUS_MIX);
+            let voices_v = current_normalized(cx, PARAM_CHORUS_VOICES);
+            let spread_v = current_normalized(cx, PARAM_CHORUS_SPREAD);
 
             param_knob(
                 cx,
@@ -84,6 +92,94 @@ pub fn build_chorus_section(cx: &mut Context) {
                 mix_v,
                 default_normalized(PARAM_CHORUS_MIX),
             );
+            param_knob(
+                cx,
+                PARAM_CHORUS_VOICES,
+                "Voices",
+                voices_v,
+                default_normalized(PARAM_CHORUS_VOICES),
+            );
+            param_knob(
+                cx,
+                PARAM_CHORUS_SPREAD,
+                "Spread",
+                spread_v,
+                default_normalized(PARAM_CHORUS_SPREAD),
+            );
+        })
+        .height(Units::Auto)
+        .gap(Pixels(6.0));
+    })
+    .gap(Pixels(6.0));
+}
+
+pub fn build_doubler_section(cx: &mut Context) {
+    VStack::new(cx, |cx| {
+        effect_header(cx, PARAM_DOUBLER_ENABLED, "Doubler", EffectSlot::Doubler);
+
+        HStack::new(cx, |cx| {
+            let mix_v = current_normalized(cx, PARAM_DOUBLER_MIX);
+            let spread_v = current_normalized(cx, PARAM_DOUBLER_SPREAD);
+
+            param_knob(
+                cx,
+                PARAM_DOUBLER_MIX,
+                "Mix",
+                mix_v,
+                default_normalized(PARAM_DOUBLER_MIX),
+            );
+            param_knob(
+                cx,
+                PARAM_DOUBLER_SPREAD,
+                "Spread",
+                spread_v,
+                default_normalized(PARAM_DOUBLER_SPREAD),
+            );
+        })
+        .height(Units::Auto)
+        .gap(Pixels(6.0));
+    })
+    .gap(Pixels(6.0));
+}
+
+pub fn build_choir_section(cx: &mut Context) {
+    VStack::new(cx, |cx| {
+        effect_header(cx, PARAM_CHOIR_ENABLED, "Choir", EffectSlot::Choir);
+
+        HStack::new(cx, |cx| {
+            let voices_v = current_normalized(cx, PARAM_CHOIR_VOICES);
+            let detune_v = current_normalized(cx, PARAM_CHOIR_DETUNE);
+            let spread_v = current_normalized(cx, PARAM_CHOIR_SPREAD);
+            let mix_v = current_normalized(cx, PARAM_CHOIR_MIX);
+
+            param_knob(
+                cx,
+                PARAM_CHOIR_VOICES,
+                "Voices",
+                voices_v,
+                default_normalized(PARAM_CHOIR_VOICES),
+            );
+            param_knob(
+                cx,
+                PARAM_CHOIR_DETUNE,
+                "Detune",
+                detune_v,
+                default_normalized(PARAM_CHOIR_DETUNE),
+            );
+            param_knob(
+                cx,
+                PARAM_CHOIR_SPREAD,
+                "Spread",
+                spread_v,
+                default_normalized(PARAM_CHOIR_SPREAD),
+            );
+            param_knob(
+                cx,
+                PARAM_CHOIR_MIX,
+                "Mix",
+                mix_v,
+                default_normalized(PARAM_CHOIR_MIX),
+            );
         })
         .height(Units::Auto)
         .gap(Pixels(6.0));
@@ -100,6 +196,8 @@ pub fn build_delay_section(cx: &mut Context) {
                 .height(Pixels(22.0));
             let enabled = current_normalized(cx, PARAM_DELAY_ENABLED);
             param_checkbox(cx, PARAM_DELAY_ENABLED, "On", enabled > 0.5);
+            effect_routing_button(cx, PARAM_DELAY_ROUTING);
+            EffectMeter::new(cx, EffectSlot::Delay);
         })
         .height(Units::Auto)
         .gap(Pixels(8.0));
@@ -117,6 +215,7 @@ pub fn build_delay_section(cx: &mut Context) {
                 time_v,
                 default_normalized(PARAM_DELAY_TIME_MS),
             );
+            tempo_sync_button(cx, PARAM_DELAY_TEMPO_SYNC);
             param_knob(
                 cx,
                 PARAM_DELAY_FEEDBACK,
@@ -124,6 +223,7 @@ pub fn build_delay_section(cx: &mut Context) {
                 fb_v,
                 default_normalized(PARAM_DELAY_FEEDBACK),
             );
+            feedback_curve_button(cx, PARAM_DELAY_FEEDBACK_CURVE);
             param_knob(
                 cx,
                 PARAM_DELAY_WET,
@@ -155,6 +255,8 @@ pub fn build_reverb_section(cx: &mut Context) {
                 .height(Pixels(22.0));
             let enabled = current_normalized(cx, PARAM_REVERB_ENABLED);
             param_checkbox(cx, PARAM_REVERB_ENABLED, "On", enabled > 0.5);
+            effect_routing_button(cx, PARAM_REVERB_ROUTING);
+            EffectMeter::new(cx, EffectSlot::Reverb);
         })
         .height(Units::Auto)
         .gap(Pixels(8.0));
@@ -166,6 +268,7 @@ pub fn build_reverb_section(cx: &mut Context) {
             let dry_v = current_normalized(cx, PARAM_REVERB_DRY);
             let width_v = current_normalized(cx, PARAM_REVERB_WIDTH);
 
+            reverb_algorithm_button(cx, PARAM_REVERB_ALGORITHM);
             param_knob(
                 cx,
                 PARAM_REVERB_ROOM_SIZE,
@@ -1,19 +1,94 @@
-// Dynamics effects: compressor
+// Dynamics effects: noise gate, transient shaper, compressor, sibilance EQ
 
 use super::super::helpers::{current_normalized, default_normalized, effect_header};
-use crate::gui::widgets::param_knob;
+use crate::audio::engine::EffectSlot;
+use crate::gui::widgets::{param_checkbox, param_knob};
 use crate::plugin::param_descriptor::*;
 use vizia::prelude::*;
 
+pub fn build_gate_section(cx: &mut Context) {
+    VStack::new(cx, |cx| {
+        effect_header(cx, PARAM_GATE_ENABLED, "Noise Gate", EffectSlot::Gate);
+
+        HStack::new(cx, |cx| {
+            let thresh_v = current_normalized(cx, PARAM_GATE_THRESHOLD);
+            let release_v = current_normalized(cx, PARAM_GATE_RELEASE);
+            let smart_v = current_normalized(cx, PARAM_GATE_SMART);
+
+            param_knob(
+                cx,
+                PARAM_GATE_THRESHOLD,
+                "Thresh",
+                thresh_v,
+                default_normalized(PARAM_GATE_THRESHOLD),
+            );
+            param_knob(
+                cx,
+                PARAM_GATE_RELEASE,
+                "Release",
+                release_v,
+                default_normalized(PARAM_GATE_RELEASE),
+            );
+            param_checkbox(cx, PARAM_GATE_SMART, "Smart", smart_v > 0.5);
+        })
+        .height(Units::Auto)
+        .gap(Pixels(6.0));
+    })
+    .gap(Pixels(6.0));
+}
+
+pub fn build_transient_shaper_section(cx: &mut Context) {
+    VStack::new(cx, |cx| {
+        effect_header(
+            cx,
+            PARAM_MASTER_TRANSIENT_ENABLED,
+            "Transient",
+            EffectSlot::TransientShaper,
+        );
+
+        HStack::new(cx, |cx| {
+            let attack_v = current_normalized(cx, PARAM_MASTER_TRANSIENT_ATTACK);
+            let sustain_v = current_normalized(cx, PARAM_MASTER_TRANSIENT_SUSTAIN);
+
+            param_knob(
+                cx,
+                PARAM_MASTER_TRANSIENT_ATTACK,
+                "Attack",
+                attack_v,
+                default_normalized(PARAM_MASTER_TRANSIENT_ATTACK),
+            );
+            param_knob(
+                cx,
+                PARAM_MASTER_TRANSIENT_SUSTAIN,
+                "Sustain",
+                sustain_v,
+                default_normalized(PARAM_MASTER_TRANSIENT_SUSTAIN),
+            );
+        })
+        .height(Units::Auto)
+        .gap(Pixels(6.0));
+    })
+    .gap(Pixels(6.0));
+}
+
 pub fn build_compressor_section(cx: &mut Context) {
     VStack::new(cx, |cx| {
-        effect_header(cx, PARAM_COMPRESSOR_ENABLED, "Compressor");
+        effect_header(
+            cx,
+            PARAM_COMPRESSOR_ENABLED,
+            "Compressor",
+            EffectSlot::Compressor,
+        );
 
         HStack::new(cx, |cx| {
             let thresh_v = current_normalized(cx, PARAM_COMPRESSOR_THRESHOLD);
             let ratio_v = current_normalized(cx, PARAM_COMPRESSOR_RATIO);
             let attack_v = current_normalized(cx, PARAM_COMPRESSOR_ATTACK);
             let release_v = current_normalized(cx, PARAM_COMPRESSOR_RELEASE);
+            let lookahead_v = current_normalized(cx, PARAM_COMPRESSOR_LOOKAHEAD);
+            let knee_v = current_normalized(cx, PARAM_COMPRESSOR_KNEE);
+            let adaptive_v = current_normalized(cx, PARAM_COMPRESSOR_ADAPTIVE);
+            let link_v = current_normalized(cx, PARAM_COMPRESSOR_STEREO_LINK);
 
             param_knob(
                 cx,
@@ -43,6 +118,56 @@ pub fn build_compressor_section(cx: &mut Context) {
                 release_v,
                 default_normalized(PARAM_COMPRESSOR_RELEASE),
             );
+            param_knob(
+                cx,
+                PARAM_COMPRESSOR_LOOKAHEAD,
+                "Look",
+                lookahead_v,
+                default_normalized(PARAM_COMPRESSOR_LOOKAHEAD),
+            );
+            param_knob(
+                cx,
+                PARAM_COMPRESSOR_KNEE,
+                "Knee",
+                knee_v,
+                default_normalized(PARAM_COMPRESSOR_KNEE),
+            );
+            param_checkbox(cx, PARAM_COMPRESSOR_ADAPTIVE, "Adapt", adaptive_v > 0.5);
+            param_checkbox(cx, PARAM_COMPRESSOR_STEREO_LINK, "Link", link_v > 0.5);
+        })
+        .height(Units::Auto)
+        .gap(Pixels(6.0));
+    })
+    .gap(Pixels(6.0));
+}
+
+pub fn build_sibilance_eq_section(cx: &mut Context) {
+    VStack::new(cx, |cx| {
+        effect_header(
+            cx,
+            PARAM_SIBILANCE_EQ_ENABLED,
+            "Sibilance EQ",
+            EffectSlot::SibilanceEq,
+        );
+
+        HStack::new(cx, |cx| {
+            let thresh_v = current_normalized(cx, PARAM_SIBILANCE_EQ_THRESHOLD);
+            let amount_v = current_normalized(cx, PARAM_SIBILANCE_EQ_AMOUNT);
+
+            param_knob(
+                cx,
+                PARAM_SIBILANCE_EQ_THRESHOLD,
+                "Thresh",
+                thresh_v,
+                default_normalized(PARAM_SIBILANCE_EQ_THRESHOLD),
+            );
+            param_knob(
+                cx,
+                PARAM_SIBILANCE_EQ_AMOUNT,
+                "Amount",
+                amount_v,
+                default_normalized(PARAM_SIBILANCE_EQ_AMOUNT),
+            );
         })
         .height(Units::Auto)
         .gap(Pixels(6.0));
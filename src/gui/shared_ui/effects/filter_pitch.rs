@@ -1,13 +1,54 @@
-// Filter and pitch effects: comb filter, ring modulator
+// Filter and pitch effects: formant filter, comb filter, ring modulator, pitch shifter
 
 use super::super::helpers::{current_normalized, default_normalized, effect_header};
-use crate::gui::widgets::param_knob;
+use crate::audio::engine::EffectSlot;
+use crate::gui::widgets::{param_knob, vowel_button};
 use crate::plugin::param_descriptor::*;
 use vizia::prelude::*;
 
+pub fn build_formant_filter_section(cx: &mut Context) {
+    VStack::new(cx, |cx| {
+        effect_header(
+            cx,
+            PARAM_FORMANT_ENABLED,
+            "Formant Filter",
+            EffectSlot::FormantFilter,
+        );
+
+        HStack::new(cx, |cx| {
+            let morph_v = current_normalized(cx, PARAM_FORMANT_MORPH);
+            let mix_v = current_normalized(cx, PARAM_FORMANT_MIX);
+
+            vowel_button(cx, PARAM_FORMANT_VOWEL);
+            param_knob(
+                cx,
+                PARAM_FORMANT_MORPH,
+                "Morph",
+                morph_v,
+                default_normalized(PARAM_FORMANT_MORPH),
+            );
+            param_knob(
+                cx,
+                PARAM_FORMANT_MIX,
+                "Mix",
+                mix_v,
+                default_normalized(PARAM_FORMANT_MIX),
+            );
+        })
+        .height(Units::Auto)
+        .gap(Pixels(6.0));
+    })
+    .gap(Pixels(6.0));
+}
+
 pub fn build_combfilter_section(cx: &mut Context) {
     VStack::new(cx, |cx| {
-        effect_header(cx, PARAM_COMB_ENABLED, "Comb Filter");
+        effect_header(
+            cx,
+            PARAM_COMB_ENABLED,
+            "Comb Filter",
+            EffectSlot::CombFilter,
+        );
 
         HStack::new(cx, |cx| {
             let freq_v = current_normalized(cx, PARAM_COMB_FREQUENCY);
@@ -44,7 +85,7 @@ pub fn build_combfilter_section(cx: &mut Context) {
 
 pub fn build_ringmod_section(cx: &mut Context) {
     VStack::new(cx, |cx| {
-        effect_header(cx, PARAM_RINGMOD_ENABLED, "Ring Mod");
+        effect_header(cx, PARAM_RINGMOD_ENABLED, "Ring Mod", EffectSlot::RingMod);
 
         HStack::new(cx, |cx| {
             let freq_v = current_normalized(cx, PARAM_RINGMOD_FREQUENCY);
@@ -70,3 +111,37 @@ pub fn build_ringmod_section(cx: &mut Context) {
     })
     .gap(Pixels(6.0));
 }
+
+pub fn build_pitch_shifter_section(cx: &mut Context) {
+    VStack::new(cx, |cx| {
+        effect_header(
+            cx,
+            PARAM_PITCH_SHIFTER_ENABLED,
+            "Pitch Shifter",
+            EffectSlot::PitchShifter,
+        );
+
+        HStack::new(cx, |cx| {
+            let semitones_v = current_normalized(cx, PARAM_PITCH_SHIFTER_SEMITONES);
+            let mix_v = current_normalized(cx, PARAM_PITCH_SHIFTER_MIX);
+
+            param_knob(
+                cx,
+                PARAM_PITCH_SHIFTER_SEMITONES,
+                "Semi",
+                semitones_v,
+                default_normalized(PARAM_PITCH_SHIFTER_SEMITONES),
+            );
+            param_knob(
+                cx,
+                PARAM_PITCH_SHIFTER_MIX,
+                "Mix",
+                mix_v,
+                default_normalized(PARAM_PITCH_SHIFTER_MIX),
+            );
+        })
+        .height(Units::Auto)
+        .gap(Pixels(6.0));
+    })
+    .gap(Pixels(6.0));
+}
@@ -1,13 +1,19 @@
 // Lo-fi effects: bitcrusher, waveshaper, exciter
 
 use super::super::helpers::{current_normalized, default_normalized, effect_header};
-use crate::gui::widgets::param_knob;
+use crate::audio::engine::EffectSlot;
+use crate::gui::widgets::{param_checkbox, param_knob};
 use crate::plugin::param_descriptor::*;
 use vizia::prelude::*;
 
 pub fn build_bitcrusher_section(cx: &mut Context) {
     VStack::new(cx, |cx| {
-        effect_header(cx, PARAM_BITCRUSHER_ENABLED, "Bitcrusher");
+        effect_header(
+            cx,
+            PARAM_BITCRUSHER_ENABLED,
+            "Bitcrusher",
+            EffectSlot::Bitcrusher,
+        );
 
         HStack::new(cx, |cx| {
             let rate_v = current_normalized(cx, PARAM_BITCRUSHER_RATE);
@@ -36,7 +42,12 @@ pub fn build_bitcrusher_section(cx: &mut Context) {
 
 pub fn build_waveshaper_section(cx: &mut Context) {
     VStack::new(cx, |cx| {
-        effect_header(cx, PARAM_WAVESHAPER_ENABLED, "Waveshaper");
+        effect_header(
+            cx,
+            PARAM_WAVESHAPER_ENABLED,
+            "Waveshaper",
+            EffectSlot::Waveshaper,
+        );
 
         HStack::new(cx, |cx| {
             let drive_v = current_normalized(cx, PARAM_WAVESHAPER_DRIVE);
@@ -65,12 +76,13 @@ pub fn build_waveshaper_section(cx: &mut Context) {
 
 pub fn build_exciter_section(cx: &mut Context) {
     VStack::new(cx, |cx| {
-        effect_header(cx, PARAM_EXCITER_ENABLED, "Exciter");
+        effect_header(cx, PARAM_EXCITER_ENABLED, "Exciter", EffectSlot::Exciter);
 
         HStack::new(cx, |cx| {
             let freq_v = current_normalized(cx, PARAM_EXCITER_FREQUENCY);
             let drive_v = current_normalized(cx, PARAM_EXCITER_DRIVE);
             let mix_v = current_normalized(cx, PARAM_EXCITER_MIX);
+            let intelligent_v = current_normalized(cx, PARAM_EXCITER_INTELLIGENT);
 
             param_knob(
                 cx,
@@ -93,6 +105,7 @@ pub fn build_exciter_section(cx: &mut Context) {
                 mix_v,
                 default_normalized(PARAM_EXCITER_MIX),
             );
+            param_checkbox(cx, PARAM_EXCITER_INTELLIGENT, "Smart", intelligent_v > 0.5);
         })
         .height(Units::Auto)
         .gap(Pixels(6.0));
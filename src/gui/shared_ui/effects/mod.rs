@@ -20,20 +20,28 @@ pub fn build_effects_section(cx: &mut Context) {
 
         // Single-column list: one effect per row
         // Order matches the audio processing chain in engine/mod.rs
+        effect_row(cx, 125.0, dynamics::build_gate_section);
+        effect_row(cx, 125.0, dynamics::build_transient_shaper_section);
         effect_row(cx, 125.0, dynamics::build_compressor_section);
         effect_row(cx, 125.0, core::build_distortion_section);
         effect_row(cx, 125.0, lofi::build_waveshaper_section);
         effect_row(cx, 125.0, lofi::build_bitcrusher_section);
         effect_row(cx, 200.0, multiband::build_multiband_distortion_section);
         effect_row(cx, 125.0, lofi::build_exciter_section);
+        effect_row(cx, 125.0, dynamics::build_sibilance_eq_section);
+        effect_row(cx, 125.0, filter_pitch::build_formant_filter_section);
         effect_row(cx, 125.0, filter_pitch::build_combfilter_section);
         effect_row(cx, 125.0, modulation::build_phaser_section);
         effect_row(cx, 125.0, modulation::build_flanger_section);
         effect_row(cx, 125.0, filter_pitch::build_ringmod_section);
         effect_row(cx, 125.0, modulation::build_tremolo_section);
         effect_row(cx, 125.0, core::build_chorus_section);
+        effect_row(cx, 125.0, core::build_doubler_section);
+        effect_row(cx, 125.0, core::build_choir_section);
+        effect_row(cx, 125.0, filter_pitch::build_pitch_shifter_section);
         effect_row(cx, 125.0, core::build_delay_section);
         effect_row(cx, 125.0, modulation::build_autopan_section);
+        effect_row(cx, 125.0, multiband::build_mid_side_section);
         effect_row(cx, 125.0, multiband::build_stereo_widener_section);
         effect_row(cx, 125.0, core::build_reverb_section);
     })
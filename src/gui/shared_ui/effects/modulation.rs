@@ -1,7 +1,8 @@
 // Modulation effects: phaser, flanger, tremolo, auto-pan
 
 use super::super::helpers::{current_normalized, default_normalized};
-use crate::gui::widgets::{param_checkbox, param_knob, tempo_sync_button};
+use crate::audio::engine::EffectSlot;
+use crate::gui::widgets::{param_checkbox, param_knob, tempo_sync_button, EffectMeter};
 use crate::plugin::param_descriptor::*;
 use vizia::prelude::*;
 
@@ -14,6 +15,7 @@ pub fn build_phaser_section(cx: &mut Context) {
                 .height(Pixels(22.0));
             let enabled = current_normalized(cx, PARAM_PHASER_ENABLED);
             param_checkbox(cx, PARAM_PHASER_ENABLED, "On", enabled > 0.5);
+            EffectMeter::new(cx, EffectSlot::Phaser);
         })
         .height(Units::Auto)
         .gap(Pixels(8.0));
@@ -70,6 +72,7 @@ pub fn build_flanger_section(cx: &mut Context) {
                 .height(Pixels(22.0));
             let enabled = current_normalized(cx, PARAM_FLANGER_ENABLED);
             param_checkbox(cx, PARAM_FLANGER_ENABLED, "On", enabled > 0.5);
+            EffectMeter::new(cx, EffectSlot::Flanger);
         })
         .height(Units::Auto)
         .gap(Pixels(8.0));
@@ -125,6 +128,7 @@ pub fn build_tremolo_section(cx: &mut Context) {
                 .height(Pixels(22.0));
             let enabled = current_normalized(cx, PARAM_TREMOLO_ENABLED);
             param_checkbox(cx, PARAM_TREMOLO_ENABLED, "On", enabled > 0.5);
+            EffectMeter::new(cx, EffectSlot::Tremolo);
         })
         .height(Units::Auto)
         .gap(Pixels(8.0));
@@ -164,6 +168,7 @@ pub fn build_autopan_section(cx: &mut Context) {
                 .height(Pixels(22.0));
             let enabled = current_normalized(cx, PARAM_AUTOPAN_ENABLED);
             param_checkbox(cx, PARAM_AUTOPAN_ENABLED, "On", enabled > 0.5);
+            EffectMeter::new(cx, EffectSlot::AutoPan);
         })
         .gap(Pixels(8.0))
         .height(Units::Auto);
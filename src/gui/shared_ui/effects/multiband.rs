@@ -1,13 +1,19 @@
-// Multiband effects: multiband distortion, stereo widener
+// Multiband and stereo effects: multiband distortion, mid/side, stereo widener
 
 use super::super::helpers::{current_normalized, default_normalized, effect_header};
+use crate::audio::engine::EffectSlot;
 use crate::gui::widgets::param_knob;
 use crate::plugin::param_descriptor::*;
 use vizia::prelude::*;
 
 pub fn build_multiband_distortion_section(cx: &mut Context) {
     VStack::new(cx, |cx| {
-        effect_header(cx, PARAM_MB_DIST_ENABLED, "Multiband Distortion");
+        effect_header(
+            cx,
+            PARAM_MB_DIST_ENABLED,
+            "Multiband Distortion",
+            EffectSlot::MultibandDistortion,
+        );
 
         // Crossover frequencies
         HStack::new(cx, |cx| {
@@ -98,9 +104,51 @@ pub fn build_multiband_distortion_section(cx: &mut Context) {
     .gap(Pixels(6.0));
 }
 
+pub fn build_mid_side_section(cx: &mut Context) {
+    VStack::new(cx, |cx| {
+        effect_header(cx, PARAM_MID_SIDE_ENABLED, "Mid/Side", EffectSlot::MidSide);
+
+        HStack::new(cx, |cx| {
+            let mid_gain_v = current_normalized(cx, PARAM_MID_SIDE_MID_GAIN);
+            let side_gain_v = current_normalized(cx, PARAM_MID_SIDE_SIDE_GAIN);
+            let low_cut_v = current_normalized(cx, PARAM_MID_SIDE_LOW_CUT);
+
+            param_knob(
+                cx,
+                PARAM_MID_SIDE_MID_GAIN,
+                "Mid",
+                mid_gain_v,
+                default_normalized(PARAM_MID_SIDE_MID_GAIN),
+            );
+            param_knob(
+                cx,
+                PARAM_MID_SIDE_SIDE_GAIN,
+                "Side",
+                side_gain_v,
+                default_normalized(PARAM_MID_SIDE_SIDE_GAIN),
+            );
+            param_knob(
+                cx,
+                PARAM_MID_SIDE_LOW_CUT,
+                "S Cut",
+                low_cut_v,
+                default_normalized(PARAM_MID_SIDE_LOW_CUT),
+            );
+        })
+        .height(Units::Auto)
+        .gap(Pixels(6.0));
+    })
+    .gap(Pixels(6.0));
+}
+
 pub fn build_stereo_widener_section(cx: &mut Context) {
     VStack::new(cx, |cx| {
-        effect_header(cx, PARAM_WIDENER_ENABLED, "Stereo Widener");
+        effect_header(
+            cx,
+            PARAM_WIDENER_ENABLED,
+            "Stereo Widener",
+            EffectSlot::StereoWidener,
+        );
 
         HStack::new(cx, |cx| {
             let haas_delay_v = current_normalized(cx, PARAM_WIDENER_HAAS_DELAY);
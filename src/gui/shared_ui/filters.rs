@@ -1,8 +1,10 @@
 // Filter sections with envelope controls
 
-use super::helpers::{current_normalized, default_normalized};
+use super::helpers::{copy_to_buttons, current_normalized, default_normalized};
 use super::traits::{IndexedSection, ParameterLayout};
-use crate::gui::widgets::{filter_type_button, param_knob};
+use crate::gui::messages::CopySection;
+use crate::gui::widgets::param_cycle_button::param_cycle_button;
+use crate::gui::widgets::{filter_topology_button, filter_type_button, param_checkbox, param_knob};
 use crate::plugin::param_descriptor::*;
 use vizia::prelude::*;
 
@@ -14,7 +16,10 @@ pub struct FilterParams {
     pub bandwidth: u32,
     pub key_tracking: u32,
     pub drive: u32,
+    pub drive_position: u32,
     pub post_drive: u32,
+    pub cutoff_slew: u32,
+    pub res_comp: u32,
     pub env_amount: u32,
     pub env_attack: u32,
     pub env_decay: u32,
@@ -37,7 +42,10 @@ impl IndexedSection for FilterSection {
                 bandwidth: PARAM_FILTER1_BANDWIDTH,
                 key_tracking: PARAM_FILTER1_KEY_TRACKING,
                 drive: PARAM_FILTER1_DRIVE,
+                drive_position: PARAM_FILTER1_DRIVE_POSITION,
                 post_drive: PARAM_FILTER1_POST_DRIVE,
+                cutoff_slew: PARAM_FILTER1_CUTOFF_SLEW,
+                res_comp: PARAM_FILTER1_RES_COMP,
                 env_amount: PARAM_FILTER1_ENV_AMOUNT,
                 env_attack: PARAM_FILTER1_ENV_ATTACK,
                 env_decay: PARAM_FILTER1_ENV_DECAY,
@@ -51,7 +59,10 @@ impl IndexedSection for FilterSection {
                 bandwidth: PARAM_FILTER2_BANDWIDTH,
                 key_tracking: PARAM_FILTER2_KEY_TRACKING,
                 drive: PARAM_FILTER2_DRIVE,
+                drive_position: PARAM_FILTER2_DRIVE_POSITION,
                 post_drive: PARAM_FILTER2_POST_DRIVE,
+                cutoff_slew: PARAM_FILTER2_CUTOFF_SLEW,
+                res_comp: PARAM_FILTER2_RES_COMP,
                 env_amount: PARAM_FILTER2_ENV_AMOUNT,
                 env_attack: PARAM_FILTER2_ENV_ATTACK,
                 env_decay: PARAM_FILTER2_ENV_DECAY,
@@ -65,7 +76,10 @@ impl IndexedSection for FilterSection {
                 bandwidth: PARAM_FILTER3_BANDWIDTH,
                 key_tracking: PARAM_FILTER3_KEY_TRACKING,
                 drive: PARAM_FILTER3_DRIVE,
+                drive_position: PARAM_FILTER3_DRIVE_POSITION,
                 post_drive: PARAM_FILTER3_POST_DRIVE,
+                cutoff_slew: PARAM_FILTER3_CUTOFF_SLEW,
+                res_comp: PARAM_FILTER3_RES_COMP,
                 env_amount: PARAM_FILTER3_ENV_AMOUNT,
                 env_attack: PARAM_FILTER3_ENV_ATTACK,
                 env_decay: PARAM_FILTER3_ENV_DECAY,
@@ -85,6 +99,11 @@ impl IndexedSection for FilterSection {
                     .font_size(14.0)
                     .color(Color::rgb(200, 200, 210));
                 filter_type_button(cx, p.filter_type, index - 1);
+                // Topology is global; show it once, on the first filter
+                if index == 1 {
+                    filter_topology_button(cx, PARAM_FILTER_TOPOLOGY);
+                }
+                copy_to_buttons(cx, CopySection::Filter, index, 3);
             })
             .height(Units::Auto)
             .gap(Pixels(6.0));
@@ -97,6 +116,8 @@ impl IndexedSection for FilterSection {
                 let key_tracking_v = current_normalized(cx, p.key_tracking);
                 let drive_v = current_normalized(cx, p.drive);
                 let post_drive_v = current_normalized(cx, p.post_drive);
+                let cutoff_slew_v = current_normalized(cx, p.cutoff_slew);
+                let res_comp_v = current_normalized(cx, p.res_comp);
 
                 param_knob(
                     cx,
@@ -127,6 +148,7 @@ impl IndexedSection for FilterSection {
                     default_normalized(p.key_tracking),
                 );
                 param_knob(cx, p.drive, "Drive", drive_v, default_normalized(p.drive));
+                param_cycle_button(cx, p.drive_position, "Drive At", &["Pre", "Post"]);
                 param_knob(
                     cx,
                     p.post_drive,
@@ -134,6 +156,14 @@ impl IndexedSection for FilterSection {
                     post_drive_v,
                     default_normalized(p.post_drive),
                 );
+                param_knob(
+                    cx,
+                    p.cutoff_slew,
+                    "Slew",
+                    cutoff_slew_v,
+                    default_normalized(p.cutoff_slew),
+                );
+                param_checkbox(cx, p.res_comp, "ResComp", res_comp_v > 0.5);
             });
 
             // Filter Envelope Section
@@ -1,51 +1,25 @@
 // Helper functions for parameter normalization
 
-use crate::gui::widgets::param_checkbox;
-use crate::gui::GuiState;
+use crate::audio::engine::EffectSlot;
+use crate::gui::messages::CopySection;
+use crate::gui::widgets::{param_checkbox, EffectMeter};
+use crate::gui::{theme, GuiMessage, GuiState};
 use crate::plugin::param_registry;
 use crate::plugin::param_update::param_get;
 use vizia::prelude::*;
 
+/// Current value of a parameter in the knob's normalized space
+///
+/// Uses the descriptor's taper (linear/log/exp) so the initial knob position matches the
+/// mapping applied when the knob is dragged.
 pub fn current_normalized(cx: &mut Context, param_id: u32) -> f32 {
     let arc = GuiState::synth_params.get(cx);
     let params = arc.read();
     let denorm = param_get::get_param(&params, param_id);
-    let registry = param_registry::get_registry();
-    if let Some(desc) = registry.get(param_id) {
-        match &desc.param_type {
-            crate::plugin::param_descriptor::ParamType::Float { min, max, .. } => {
-                if *max > *min {
-                    ((denorm - *min) / (*max - *min)).clamp(0.0, 1.0)
-                } else {
-                    0.0
-                }
-            }
-            crate::plugin::param_descriptor::ParamType::Bool => {
-                if denorm > 0.5 {
-                    1.0
-                } else {
-                    0.0
-                }
-            }
-            crate::plugin::param_descriptor::ParamType::Enum { variants } => {
-                if variants.len() > 1 {
-                    (denorm / (variants.len() - 1) as f32).clamp(0.0, 1.0)
-                } else {
-                    0.0
-                }
-            }
-            crate::plugin::param_descriptor::ParamType::Int { min, max } => {
-                let range = (*max - *min) as f32;
-                if range > 0.0 {
-                    ((denorm - *min as f32) / range).clamp(0.0, 1.0)
-                } else {
-                    0.0
-                }
-            }
-        }
-    } else {
-        0.0
-    }
+    param_registry::get_registry()
+        .get(param_id)
+        .map(|desc| desc.normalize_value(denorm))
+        .unwrap_or(0.0)
 }
 
 pub fn default_normalized(param_id: u32) -> f32 {
@@ -53,8 +27,8 @@ pub fn default_normalized(param_id: u32) -> f32 {
     registry.get(param_id).map(|d| d.default).unwrap_or(0.0)
 }
 
-// Common effect header: checkbox first, then title label
-pub fn effect_header(cx: &mut Context, enabled_param: u32, title: &str) {
+// Common effect header: checkbox first, then title label and the effect's level meter
+pub fn effect_header(cx: &mut Context, enabled_param: u32, title: &str, slot: EffectSlot) {
     HStack::new(cx, |cx| {
         let enabled = current_normalized(cx, enabled_param);
         param_checkbox(cx, enabled_param, "On", enabled > 0.5);
@@ -62,11 +36,39 @@ pub fn effect_header(cx: &mut Context, enabled_param: u32, title: &str) {
             .font_size(14.0)
             .color(Color::rgb(200, 200, 210))
             .height(Pixels(30.0));
+        EffectMeter::new(cx, slot);
     })
     .height(Units::Auto)
     .gap(Pixels(8.0));
 }
 
+// "Copy to N" buttons that copy this instance's settings onto each of the other instances.
+// `index` is the 1-based instance the buttons sit on, `count` how many instances exist.
+pub fn copy_to_buttons(cx: &mut Context, section: CopySection, index: usize, count: usize) {
+    HStack::new(cx, |cx| {
+        Label::new(cx, "Copy to")
+            .font_size(11.0)
+            .color(theme::TEXT_TERTIARY)
+            .height(Pixels(20.0));
+        for target in (1..=count).filter(|&target| target != index) {
+            Button::new(cx, move |cx| Label::new(cx, &target.to_string()))
+                .on_press(move |cx| {
+                    cx.emit(GuiMessage::CopySection(section, index - 1, target - 1))
+                })
+                .width(Pixels(20.0))
+                .height(Pixels(20.0))
+                .font_size(11.0)
+                .color(theme::BUTTON_TEXT_INACTIVE)
+                .background_color(theme::BUTTON_BG_INACTIVE)
+                .corner_radius(Pixels(3.0))
+                .cursor(CursorIcon::Hand);
+        }
+    })
+    .width(Units::Auto)
+    .height(Units::Auto)
+    .gap(Pixels(4.0));
+}
+
 // Common effect row wrapper: full-width VStack with standard gap and explicit height.
 // Usage: effect_row(cx, 125.0, |cx| core::build_distortion_section(cx))
 pub fn effect_row<F>(cx: &mut Context, height_px: f32, builder: F)
@@ -1,9 +1,10 @@
 // LFO sections with modulation routing
 
-use super::helpers::{current_normalized, default_normalized};
+use super::helpers::{copy_to_buttons, current_normalized, default_normalized};
 use super::traits::{IndexedSection, ParameterLayout};
+use crate::gui::messages::CopySection;
 use crate::gui::widgets::param_cycle_button::param_cycle_button;
-use crate::gui::widgets::{lfo_waveform_button, param_knob, tempo_sync_button};
+use crate::gui::widgets::{lfo_waveform_button, param_checkbox, param_knob, tempo_sync_button};
 use crate::plugin::param_descriptor::*;
 use vizia::prelude::*;
 
@@ -19,6 +20,8 @@ pub struct LfoParams {
     pub pan_amount: u32,
     pub pwm_amount: u32,
     pub destination: u32,
+    pub pulse_width: u32,
+    pub unipolar: u32,
 }
 
 /// LFO UI section builder
@@ -40,6 +43,8 @@ impl IndexedSection for LfoSection {
                 pan_amount: PARAM_LFO1_PAN_AMOUNT,
                 pwm_amount: PARAM_LFO1_PWM_AMOUNT,
                 destination: PARAM_LFO1_DESTINATION,
+                pulse_width: PARAM_LFO1_PULSE_WIDTH,
+                unipolar: PARAM_LFO1_UNIPOLAR,
             },
             2 => LfoParams {
                 waveform: PARAM_LFO2_WAVEFORM,
@@ -52,6 +57,8 @@ impl IndexedSection for LfoSection {
                 pan_amount: PARAM_LFO2_PAN_AMOUNT,
                 pwm_amount: PARAM_LFO2_PWM_AMOUNT,
                 destination: PARAM_LFO2_DESTINATION,
+                pulse_width: PARAM_LFO2_PULSE_WIDTH,
+                unipolar: PARAM_LFO2_UNIPOLAR,
             },
             _ => LfoParams {
                 waveform: PARAM_LFO3_WAVEFORM,
@@ -64,6 +71,8 @@ impl IndexedSection for LfoSection {
                 pan_amount: PARAM_LFO3_PAN_AMOUNT,
                 pwm_amount: PARAM_LFO3_PWM_AMOUNT,
                 destination: PARAM_LFO3_DESTINATION,
+                pulse_width: PARAM_LFO3_PULSE_WIDTH,
+                unipolar: PARAM_LFO3_UNIPOLAR,
             },
         }
     }
@@ -84,6 +93,7 @@ impl IndexedSection for LfoSection {
                     "Dest",
                     &["Global", "Osc1", "Osc2", "Osc3"],
                 );
+                copy_to_buttons(cx, CopySection::Lfo, index, 3);
             })
             .height(Units::Auto)
             .gap(Pixels(10.0));
@@ -93,6 +103,8 @@ impl IndexedSection for LfoSection {
                 let rate_v = current_normalized(cx, p.rate);
                 let depth_v = current_normalized(cx, p.depth);
                 let filter_amount_v = current_normalized(cx, p.filter_amount);
+                let pulse_width_v = current_normalized(cx, p.pulse_width);
+                let unipolar_v = current_normalized(cx, p.unipolar);
 
                 param_knob(cx, p.rate, "Rate", rate_v, default_normalized(p.rate));
                 tempo_sync_button(cx, p.tempo_sync);
@@ -104,6 +116,14 @@ impl IndexedSection for LfoSection {
                     filter_amount_v,
                     default_normalized(p.filter_amount),
                 );
+                param_knob(
+                    cx,
+                    p.pulse_width,
+                    "Width",
+                    pulse_width_v,
+                    default_normalized(p.pulse_width),
+                );
+                param_checkbox(cx, p.unipolar, "Uni", unipolar_v > 0.5);
             });
 
             // Modulation targets
@@ -1,8 +1,14 @@
 // Master, envelope, and velocity sections
 
 use super::helpers::{current_normalized, default_normalized};
-use crate::gui::widgets::{param_checkbox, param_knob, EnvelopeEditor};
+use crate::gui::messages::EnvelopeTarget;
+use crate::gui::widgets::{
+    param_checkbox, param_knob, quality_button, tempo_sync_button, EnvelopeEditor,
+};
+use crate::gui::{theme, GuiState};
+use crate::plugin::ab_compare::AbSlot;
 use crate::plugin::param_descriptor::*;
+use crate::randomize::RandomizeSection;
 use vizia::prelude::*;
 
 pub fn build_master_section(cx: &mut Context) {
@@ -11,12 +17,125 @@ pub fn build_master_section(cx: &mut Context) {
         let gain_def = default_normalized(PARAM_MASTER_GAIN);
         let mono = current_normalized(cx, PARAM_MONOPHONIC);
         let hard_sync = current_normalized(cx, PARAM_HARD_SYNC);
+        let fx_bypass = current_normalized(cx, PARAM_EFFECTS_BYPASS);
+        let key_pan = current_normalized(cx, PARAM_KEY_PAN);
+        let poly_legato = current_normalized(cx, PARAM_POLY_LEGATO);
+        let legato_window = current_normalized(cx, PARAM_LEGATO_WINDOW);
+        let solo_note = current_normalized(cx, PARAM_SOLO_NOTE);
+        let headroom = current_normalized(cx, PARAM_HEADROOM);
+        let drift = current_normalized(cx, PARAM_ANALOG_DRIFT);
+        let tuning = current_normalized(cx, PARAM_TUNING);
+        let steal_release = current_normalized(cx, PARAM_STEAL_RELEASE_FIRST);
+        let max_note = current_normalized(cx, PARAM_MAX_NOTE_LENGTH);
+        let paraphonic = current_normalized(cx, PARAM_PARAPHONIC);
+        let midi_channel = current_normalized(cx, PARAM_MIDI_CHANNEL);
+        let retrigger_mod = current_normalized(cx, PARAM_RETRIGGER_MOD);
+        let poly_comp = current_normalized(cx, PARAM_POLY_COMP);
+        let repeat = current_normalized(cx, PARAM_NOTE_REPEAT_ENABLED);
+        let repeat_rate = current_normalized(cx, PARAM_NOTE_REPEAT_RATE);
+        let repeat_gate = current_normalized(cx, PARAM_NOTE_REPEAT_GATE);
+        let repeat_ramp = current_normalized(cx, PARAM_NOTE_REPEAT_VELOCITY_RAMP);
         let amp_v = current_normalized(cx, PARAM_VELOCITY_AMP);
         let filter_v = current_normalized(cx, PARAM_VELOCITY_FILTER);
+        let attack_v = current_normalized(cx, PARAM_VELOCITY_ATTACK);
+        let decay_v = current_normalized(cx, PARAM_VELOCITY_DECAY);
+        let xfade_v = current_normalized(cx, PARAM_VELOCITY_OSC_XFADE);
+        let release_v = current_normalized(cx, PARAM_VELOCITY_RELEASE);
 
         param_knob(cx, PARAM_MASTER_GAIN, "Gain", gain, gain_def);
         param_checkbox(cx, PARAM_MONOPHONIC, "Mono", mono > 0.5);
         param_checkbox(cx, PARAM_HARD_SYNC, "Hard Sync", hard_sync > 0.5);
+        param_checkbox(cx, PARAM_EFFECTS_BYPASS, "FX Bypass", fx_bypass > 0.5);
+        param_knob(
+            cx,
+            PARAM_KEY_PAN,
+            "Key Pan",
+            key_pan,
+            default_normalized(PARAM_KEY_PAN),
+        );
+        param_checkbox(cx, PARAM_POLY_LEGATO, "Poly Legato", poly_legato > 0.5);
+        param_knob(
+            cx,
+            PARAM_LEGATO_WINDOW,
+            "Legato ms",
+            legato_window,
+            default_normalized(PARAM_LEGATO_WINDOW),
+        );
+        param_checkbox(cx, PARAM_SOLO_NOTE, "Solo Note", solo_note > 0.5);
+        param_knob(
+            cx,
+            PARAM_HEADROOM,
+            "Headroom",
+            headroom,
+            default_normalized(PARAM_HEADROOM),
+        );
+        quality_button(cx, PARAM_QUALITY);
+        param_knob(
+            cx,
+            PARAM_ANALOG_DRIFT,
+            "Drift",
+            drift,
+            default_normalized(PARAM_ANALOG_DRIFT),
+        );
+        param_knob(
+            cx,
+            PARAM_TUNING,
+            "Tuning",
+            tuning,
+            default_normalized(PARAM_TUNING),
+        );
+        param_checkbox(
+            cx,
+            PARAM_STEAL_RELEASE_FIRST,
+            "Steal Rel",
+            steal_release > 0.5,
+        );
+        param_knob(
+            cx,
+            PARAM_MAX_NOTE_LENGTH,
+            "Max Note",
+            max_note,
+            default_normalized(PARAM_MAX_NOTE_LENGTH),
+        );
+        param_checkbox(cx, PARAM_PARAPHONIC, "Paraphonic", paraphonic > 0.5);
+        param_knob(
+            cx,
+            PARAM_MIDI_CHANNEL,
+            "MIDI Ch",
+            midi_channel,
+            default_normalized(PARAM_MIDI_CHANNEL),
+        );
+        param_checkbox(cx, PARAM_RETRIGGER_MOD, "Retrig Mod", retrigger_mod > 0.5);
+        param_knob(
+            cx,
+            PARAM_POLY_COMP,
+            "Poly Comp",
+            poly_comp,
+            default_normalized(PARAM_POLY_COMP),
+        );
+        param_checkbox(cx, PARAM_NOTE_REPEAT_ENABLED, "Repeat", repeat > 0.5);
+        tempo_sync_button(cx, PARAM_NOTE_REPEAT_TEMPO_SYNC);
+        param_knob(
+            cx,
+            PARAM_NOTE_REPEAT_RATE,
+            "Rpt Rate",
+            repeat_rate,
+            default_normalized(PARAM_NOTE_REPEAT_RATE),
+        );
+        param_knob(
+            cx,
+            PARAM_NOTE_REPEAT_GATE,
+            "Rpt Gate",
+            repeat_gate,
+            default_normalized(PARAM_NOTE_REPEAT_GATE),
+        );
+        param_knob(
+            cx,
+            PARAM_NOTE_REPEAT_VELOCITY_RAMP,
+            "Rpt Ramp",
+            repeat_ramp,
+            default_normalized(PARAM_NOTE_REPEAT_VELOCITY_RAMP),
+        );
         param_knob(
             cx,
             PARAM_VELOCITY_AMP,
@@ -31,6 +150,34 @@ pub fn build_master_section(cx: &mut Context) {
             filter_v,
             default_normalized(PARAM_VELOCITY_FILTER),
         );
+        param_knob(
+            cx,
+            PARAM_VELOCITY_ATTACK,
+            "Vel→Atk",
+            attack_v,
+            default_normalized(PARAM_VELOCITY_ATTACK),
+        );
+        param_knob(
+            cx,
+            PARAM_VELOCITY_DECAY,
+            "Vel→Dec",
+            decay_v,
+            default_normalized(PARAM_VELOCITY_DECAY),
+        );
+        param_knob(
+            cx,
+            PARAM_VELOCITY_OSC_XFADE,
+            "Vel→XFade",
+            xfade_v,
+            default_normalized(PARAM_VELOCITY_OSC_XFADE),
+        );
+        param_knob(
+            cx,
+            PARAM_VELOCITY_RELEASE,
+            "Vel→Rel",
+            release_v,
+            default_normalized(PARAM_VELOCITY_RELEASE),
+        );
 
         // Randomize button
         Button::new(cx, |cx| Label::new(cx, "🎲 Randomize"))
@@ -40,48 +187,199 @@ pub fn build_master_section(cx: &mut Context) {
             .background_color(Color::rgb(60, 60, 70))
             .corner_radius(Pixels(4.0))
             .cursor(CursorIcon::Hand);
+        build_randomize_locks(cx);
+
+        build_ab_buttons(cx);
+        build_mono_check(cx);
     })
     .gap(Pixels(6.0));
 }
 
+/// Macro knobs: "Set" assigns the last parameter moved, "Clr" removes every target
+pub fn build_macro_section(cx: &mut Context) {
+    HStack::new(cx, |cx| {
+        for (index, &param_id) in PARAM_MACROS.iter().enumerate() {
+            let value = current_normalized(cx, param_id);
+            VStack::new(cx, move |cx| {
+                param_knob(
+                    cx,
+                    param_id,
+                    &format!("Macro {}", index + 1),
+                    value,
+                    default_normalized(param_id),
+                );
+                HStack::new(cx, move |cx| {
+                    macro_button(cx, "Set", crate::gui::GuiMessage::AssignMacro(index));
+                    macro_button(cx, "Clr", crate::gui::GuiMessage::ClearMacro(index));
+                })
+                .height(Auto)
+                .gap(Pixels(2.0));
+            })
+            .width(Auto)
+            .height(Auto)
+            .gap(Pixels(2.0));
+        }
+    })
+    .gap(Pixels(12.0));
+}
+
+fn macro_button(cx: &mut Context, label: &'static str, message: crate::gui::GuiMessage) {
+    Button::new(cx, move |cx| Label::new(cx, label).font_size(10.0))
+        .on_press(move |cx| cx.emit(message.clone()))
+        .height(Pixels(20.0))
+        .background_color(theme::BUTTON_BG_INACTIVE)
+        .corner_radius(Pixels(3.0))
+        .cursor(CursorIcon::Hand);
+}
+
+/// Lock toggles for Randomize: a highlighted section keeps its current settings
+fn build_randomize_locks(cx: &mut Context) {
+    VStack::new(cx, |cx| {
+        Label::new(cx, "Lock")
+            .font_size(10.0)
+            .color(theme::TEXT_SECONDARY);
+        HStack::new(cx, |cx| {
+            for section in RandomizeSection::ALL {
+                let locked = GuiState::randomize_locks.map(move |locks| locks.is_locked(section));
+                Button::new(cx, move |cx| {
+                    Label::new(cx, section.label()).font_size(10.0)
+                })
+                .on_press(move |cx| cx.emit(crate::gui::GuiMessage::ToggleRandomizeLock(section)))
+                .height(Pixels(20.0))
+                .background_color(locked.map(|locked| {
+                    if *locked {
+                        theme::BUTTON_BG_ACTIVE
+                    } else {
+                        theme::BUTTON_BG_INACTIVE
+                    }
+                }))
+                .border_width(Pixels(1.0))
+                .border_color(locked.map(|locked| {
+                    if *locked {
+                        theme::WIDGET_ACCENT
+                    } else {
+                        theme::WIDGET_BORDER
+                    }
+                }))
+                .corner_radius(Pixels(3.0))
+                .cursor(CursorIcon::Hand);
+            }
+        })
+        .height(Auto)
+        .gap(Pixels(2.0));
+    })
+    .width(Auto)
+    .height(Auto)
+    .gap(Pixels(2.0));
+}
+
+/// A/B compare: the live slot is highlighted, "A→B" copies A over B
+fn build_ab_buttons(cx: &mut Context) {
+    let slot_button = |cx: &mut Context, slot: AbSlot, label: &'static str| {
+        let is_b = slot == AbSlot::B;
+        Button::new(cx, move |cx| Label::new(cx, label))
+            .on_press(move |cx| cx.emit(crate::gui::GuiMessage::SelectAbSlot(slot)))
+            .width(Pixels(32.0))
+            .height(Pixels(32.0))
+            .background_color(GuiState::ab_is_b.map(move |b_live| {
+                if *b_live == is_b {
+                    theme::BUTTON_BG_ACTIVE
+                } else {
+                    theme::BUTTON_BG_INACTIVE
+                }
+            }))
+            .border_width(Pixels(1.0))
+            .border_color(GuiState::ab_is_b.map(move |b_live| {
+                if *b_live == is_b {
+                    theme::WIDGET_ACCENT
+                } else {
+                    theme::WIDGET_BORDER
+                }
+            }))
+            .corner_radius(Pixels(4.0))
+            .cursor(CursorIcon::Hand);
+    };
+
+    slot_button(cx, AbSlot::A, "A");
+    slot_button(cx, AbSlot::B, "B");
+    Button::new(cx, |cx| Label::new(cx, "A→B"))
+        .on_press(|cx| cx.emit(crate::gui::GuiMessage::CopyAToB))
+        .width(Pixels(44.0))
+        .height(Pixels(32.0))
+        .background_color(Color::rgb(60, 60, 70))
+        .corner_radius(Pixels(4.0))
+        .cursor(CursorIcon::Hand);
+}
+
+/// Mono compatibility check of the last second of output, with its result underneath
+fn build_mono_check(cx: &mut Context) {
+    VStack::new(cx, |cx| {
+        Button::new(cx, |cx| Label::new(cx, "Mono Check"))
+            .on_press(|cx| cx.emit(crate::gui::GuiMessage::CheckMonoCompatibility))
+            .width(Pixels(100.0))
+            .height(Pixels(32.0))
+            .background_color(Color::rgb(60, 60, 70))
+            .corner_radius(Pixels(4.0))
+            .cursor(CursorIcon::Hand);
+        Label::new(cx, GuiState::mono_report_text)
+            .font_size(10.0)
+            .color(theme::TEXT_SECONDARY)
+            .width(Pixels(220.0));
+    })
+    .width(Auto)
+    .height(Auto)
+    .gap(Pixels(2.0));
+}
+
 pub fn build_envelope_section(cx: &mut Context) {
     HStack::new(cx, |cx| {
-        let attack = current_normalized(cx, PARAM_ENVELOPE_ATTACK);
-        let decay = current_normalized(cx, PARAM_ENVELOPE_DECAY);
-        let sustain = current_normalized(cx, PARAM_ENVELOPE_SUSTAIN);
-        let release = current_normalized(cx, PARAM_ENVELOPE_RELEASE);
         let attack_curve_norm = current_normalized(cx, PARAM_ENVELOPE_ATTACK_CURVE);
         let decay_curve_norm = current_normalized(cx, PARAM_ENVELOPE_DECAY_CURVE);
         let release_curve_norm = current_normalized(cx, PARAM_ENVELOPE_RELEASE_CURVE);
+        let key_scaling = current_normalized(cx, PARAM_ENVELOPE_KEY_SCALING);
 
-        // Convert normalized curve values (0.0-1.0) to actual curve range (-1.0 to +1.0)
-        // ONLY for EnvelopeEditor visualization, NOT for the knobs
-        let attack_curve = attack_curve_norm * 2.0 - 1.0;
-        let decay_curve = decay_curve_norm * 2.0 - 1.0;
-        let release_curve = release_curve_norm * 2.0 - 1.0;
-
-        // Visual envelope editor
-        EnvelopeEditor::new(
-            cx,
-            attack,
-            decay,
-            sustain,
-            release,
-            attack_curve,
-            decay_curve,
-            release_curve,
-            PARAM_ENVELOPE_ATTACK,
-            PARAM_ENVELOPE_DECAY,
-            PARAM_ENVELOPE_SUSTAIN,
-            PARAM_ENVELOPE_RELEASE,
-            PARAM_ENVELOPE_ATTACK_CURVE,
-            PARAM_ENVELOPE_DECAY_CURVE,
-            PARAM_ENVELOPE_RELEASE_CURVE,
-        )
-        .background_color(Color::rgb(25, 25, 30))
-        .border_width(Pixels(1.0))
-        .border_color(Color::rgb(60, 60, 70))
-        .corner_radius(Pixels(4.0));
+        // Visual envelope editor, switchable between the amp and filter envelopes
+        VStack::new(cx, |cx| {
+            Binding::new(cx, GuiState::envelope_editor_target, |cx, target| {
+                let target = target.get(cx);
+                HStack::new(cx, |cx| {
+                    for choice in EnvelopeTarget::ALL {
+                        let selected = choice == target;
+                        Button::new(cx, move |cx| Label::new(cx, choice.label()))
+                            .on_press(move |cx| {
+                                cx.emit(crate::gui::GuiMessage::SetEnvelopeEditorTarget(choice))
+                            })
+                            .height(Pixels(20.0))
+                            .padding_left(Pixels(6.0))
+                            .padding_right(Pixels(6.0))
+                            .font_size(11.0)
+                            .color(if selected {
+                                theme::BUTTON_TEXT_ACTIVE
+                            } else {
+                                theme::BUTTON_TEXT_INACTIVE
+                            })
+                            .background_color(if selected {
+                                theme::BUTTON_BG_ACTIVE
+                            } else {
+                                theme::BUTTON_BG_INACTIVE
+                            })
+                            .corner_radius(Pixels(3.0))
+                            .cursor(CursorIcon::Hand);
+                    }
+                })
+                .height(Units::Auto)
+                .gap(Pixels(4.0));
+
+                EnvelopeEditor::new(cx, target)
+                    .background_color(Color::rgb(25, 25, 30))
+                    .border_width(Pixels(1.0))
+                    .border_color(Color::rgb(60, 60, 70))
+                    .corner_radius(Pixels(4.0));
+            });
+        })
+        .width(Units::Auto)
+        .height(Units::Auto)
+        .gap(Pixels(4.0));
 
         // Curve control knobs
         HStack::new(cx, |cx| {
@@ -106,6 +404,13 @@ pub fn build_envelope_section(cx: &mut Context) {
                 release_curve_norm, // Use normalized value for knob
                 default_normalized(PARAM_ENVELOPE_RELEASE_CURVE),
             );
+            param_knob(
+                cx,
+                PARAM_ENVELOPE_KEY_SCALING,
+                "Key Scale",
+                key_scaling,
+                default_normalized(PARAM_ENVELOPE_KEY_SCALING),
+            );
         })
         .height(Units::Auto)
         .gap(Pixels(6.0));
@@ -117,6 +422,10 @@ pub fn build_velocity_section(cx: &mut Context) {
     HStack::new(cx, |cx| {
         let amp_v = current_normalized(cx, PARAM_VELOCITY_AMP);
         let filter_v = current_normalized(cx, PARAM_VELOCITY_FILTER);
+        let attack_v = current_normalized(cx, PARAM_VELOCITY_ATTACK);
+        let decay_v = current_normalized(cx, PARAM_VELOCITY_DECAY);
+        let xfade_v = current_normalized(cx, PARAM_VELOCITY_OSC_XFADE);
+        let release_v = current_normalized(cx, PARAM_VELOCITY_RELEASE);
 
         param_knob(
             cx,
@@ -132,6 +441,34 @@ pub fn build_velocity_section(cx: &mut Context) {
             filter_v,
             default_normalized(PARAM_VELOCITY_FILTER),
         );
+        param_knob(
+            cx,
+            PARAM_VELOCITY_ATTACK,
+            "Attack",
+            attack_v,
+            default_normalized(PARAM_VELOCITY_ATTACK),
+        );
+        param_knob(
+            cx,
+            PARAM_VELOCITY_DECAY,
+            "Decay",
+            decay_v,
+            default_normalized(PARAM_VELOCITY_DECAY),
+        );
+        param_knob(
+            cx,
+            PARAM_VELOCITY_OSC_XFADE,
+            "Osc XFade",
+            xfade_v,
+            default_normalized(PARAM_VELOCITY_OSC_XFADE),
+        );
+        param_knob(
+            cx,
+            PARAM_VELOCITY_RELEASE,
+            "Release",
+            release_v,
+            default_normalized(PARAM_VELOCITY_RELEASE),
+        );
     })
     .height(Units::Auto)
     .gap(Pixels(6.0));
@@ -7,6 +7,7 @@ pub mod helpers;
 pub mod lfos;
 pub mod master;
 pub mod oscillators;
+pub mod search;
 pub mod traits;
 
 use crate::gui::messages::UiTab;
@@ -50,6 +51,7 @@ pub fn build_ui(cx: &mut Context) {
                     tab_button(cx, "Oscillator", UiTab::Oscillator, active_tab);
                     tab_button(cx, "Filter + LFO", UiTab::FilterLfo, active_tab);
                     tab_button(cx, "Effects", UiTab::Effects, active_tab);
+                    tab_button(cx, "Search", UiTab::Search, active_tab);
                 })
                 .gap(Pixels(8.0))
                 .width(Stretch(1.0))
@@ -57,6 +59,16 @@ pub fn build_ui(cx: &mut Context) {
                 .background_color(Color::rgb(25, 25, 30));
             });
 
+            PickList::new(
+                cx,
+                GuiState::gui_scale_labels,
+                GuiState::gui_scale_index,
+                true,
+            )
+            .on_select(|cx, index| cx.emit(crate::gui::GuiMessage::SetGuiScale(index)))
+            .width(Pixels(80.0))
+            .height(Pixels(30.0));
+
             Label::new(cx, GuiState::last_param_text)
                 .font_size(24.0)
                 .color(theme::TEXT_TERTIARY)
@@ -70,6 +82,24 @@ pub fn build_ui(cx: &mut Context) {
         .padding(Pixels(10.0))
         .background_color(theme::BG_DARK);
 
+        // Held-note keyboard + last note received (spots stuck notes at a glance)
+        HStack::new(cx, |cx| {
+            crate::gui::widgets::NoteDisplay::new(cx)
+                .width(Stretch(1.0))
+                .height(Pixels(24.0));
+            Label::new(cx, GuiState::last_note_text)
+                .font_size(12.0)
+                .color(theme::TEXT_TERTIARY)
+                .width(Pixels(100.0))
+                .height(Pixels(24.0))
+                .text_align(TextAlign::Right);
+        })
+        .height(Pixels(32.0))
+        .width(Stretch(1.0))
+        .padding(Pixels(4.0))
+        .gap(Pixels(8.0))
+        .background_color(theme::BG_DARK);
+
         // Scrollable content area
         ScrollView::new(cx, |cx| {
             Binding::new(cx, GuiState::active_tab, move |cx, active_tab| {
@@ -105,6 +135,20 @@ pub fn build_ui(cx: &mut Context) {
                         .gap(Pixels(theme::COL_GAP))
                         .height(Pixels(225.0));
 
+                        // Row 1.25: Macros
+                        VStack::new(cx, |cx| {
+                            Label::new(cx, "Macros")
+                                .font_size(16.0)
+                                .color(theme::TEXT_SECONDARY)
+                                .height(Pixels(24.0));
+                            master::build_macro_section(cx);
+                        })
+                        .width(Stretch(1.0))
+                        .height(Pixels(140.0))
+                        .padding(Pixels(10.0))
+                        .gap(Pixels(6.0))
+                        .background_color(theme::BG_SECTION);
+
                         // Row 1.5: Voice Dynamics (Compressor + Transient Shaper)
                         HStack::new(cx, |cx| {
                             VStack::new(cx, |cx| {
@@ -130,6 +174,18 @@ pub fn build_ui(cx: &mut Context) {
                             .padding(Pixels(10.0))
                             .gap(Pixels(6.0))
                             .background_color(theme::BG_SECTION);
+
+                            VStack::new(cx, |cx| {
+                                Label::new(cx, "Voice Click")
+                                    .font_size(16.0)
+                                    .color(theme::TEXT_SECONDARY)
+                                    .height(Pixels(24.0));
+                                dynamics::build_voice_click_section(cx);
+                            })
+                            .width(Stretch(1.0))
+                            .padding(Pixels(10.0))
+                            .gap(Pixels(6.0))
+                            .background_color(theme::BG_SECTION);
                         })
                         .gap(Pixels(theme::COL_GAP))
                         .height(Pixels(125.0));
@@ -214,6 +270,9 @@ pub fn build_ui(cx: &mut Context) {
                         // Row 5: Effects
                         effects::build_effects_section(cx);
                     }
+                    UiTab::Search => {
+                        search::build_param_search(cx);
+                    }
                 })
                 .width(Stretch(1.0))
                 .height(Units::Auto)
@@ -224,6 +283,8 @@ pub fn build_ui(cx: &mut Context) {
         })
         .show_horizontal_scrollbar(false)
         .show_vertical_scrollbar(true)
+        .scroll_y(GuiState::scroll_y)
+        .on_scroll(|cx, _x, y| cx.emit(crate::gui::GuiMessage::SetScrollY(y)))
         .width(Stretch(1.0))
         .height(Stretch(1.0))
         .background_color(theme::BG_DARK);
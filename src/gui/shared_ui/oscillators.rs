@@ -1,9 +1,11 @@
-// Oscillator sections: main controls, additive harmonics, wavetable
+// Oscillator sections: main controls, additive harmonics, wavetable, pluck
 
-use super::helpers::{current_normalized, default_normalized};
+use super::helpers::{copy_to_buttons, current_normalized, default_normalized};
 use super::traits::{IndexedSection, ParameterLayout};
+use crate::gui::messages::CopySection;
 use crate::gui::widgets::{
-    fm_source_button, oscillator_waveform_button, param_checkbox, param_knob, param_vslider,
+    filter_routing_button, fm_source_button, oscillator_waveform_button, param_checkbox,
+    param_knob, param_vslider,
 };
 use crate::plugin::param_descriptor::*;
 use vizia::prelude::*;
@@ -21,6 +23,8 @@ pub struct OscillatorParams {
     pub shape: u32,
     pub fm_source: u32,
     pub fm_amount: u32,
+    pub fm_feedback: u32,
+    pub gain_slew: u32,
     pub solo: u32,
     pub unison_normalize: u32,
     pub saturation: u32,
@@ -32,8 +36,18 @@ pub struct OscillatorParams {
     pub h6: u32,
     pub h7: u32,
     pub h8: u32,
+    pub additive_normalize: u32,
+    pub additive_gain: u32,
+    pub harmonic_count: u32,
+    pub harmonic_slope: u32,
     pub wavetable_index: u32,
     pub wavetable_position: u32,
+    pub filter_routing: u32,
+    pub pluck_damping: u32,
+    pub pluck_brightness: u32,
+    pub fixed_freq: u32,
+    pub fixed_freq_hz: u32,
+    pub sample_reduce: u32,
 }
 
 /// Oscillator UI section builder
@@ -56,6 +70,8 @@ impl IndexedSection for OscillatorSection {
                 shape: PARAM_OSC1_SHAPE,
                 fm_source: PARAM_OSC1_FM_SOURCE,
                 fm_amount: PARAM_OSC1_FM_AMOUNT,
+                fm_feedback: PARAM_OSC1_FM_FEEDBACK,
+                gain_slew: PARAM_OSC1_GAIN_SLEW,
                 solo: PARAM_OSC1_SOLO,
                 unison_normalize: PARAM_OSC1_UNISON_NORMALIZE,
                 saturation: PARAM_OSC1_SATURATION,
@@ -67,8 +83,18 @@ impl IndexedSection for OscillatorSection {
                 h6: PARAM_OSC1_H6,
                 h7: PARAM_OSC1_H7,
                 h8: PARAM_OSC1_H8,
+                additive_normalize: PARAM_OSC1_ADDITIVE_NORMALIZE,
+                additive_gain: PARAM_OSC1_ADDITIVE_GAIN,
+                harmonic_count: PARAM_OSC1_HARMONIC_COUNT,
+                harmonic_slope: PARAM_OSC1_HARMONIC_SLOPE,
                 wavetable_index: PARAM_OSC1_WAVETABLE_INDEX,
                 wavetable_position: PARAM_OSC1_WAVETABLE_POSITION,
+                filter_routing: PARAM_OSC1_FILTER_ROUTING,
+                pluck_damping: PARAM_OSC1_PLUCK_DAMPING,
+                pluck_brightness: PARAM_OSC1_PLUCK_BRIGHTNESS,
+                fixed_freq: PARAM_OSC1_FIXED_FREQ,
+                fixed_freq_hz: PARAM_OSC1_FIXED_FREQ_HZ,
+                sample_reduce: PARAM_OSC1_SAMPLE_REDUCE,
             },
             2 => OscillatorParams {
                 waveform: PARAM_OSC2_WAVEFORM,
@@ -82,6 +108,8 @@ impl IndexedSection for OscillatorSection {
                 shape: PARAM_OSC2_SHAPE,
                 fm_source: PARAM_OSC2_FM_SOURCE,
                 fm_amount: PARAM_OSC2_FM_AMOUNT,
+                fm_feedback: PARAM_OSC2_FM_FEEDBACK,
+                gain_slew: PARAM_OSC2_GAIN_SLEW,
                 solo: PARAM_OSC2_SOLO,
                 unison_normalize: PARAM_OSC2_UNISON_NORMALIZE,
                 saturation: PARAM_OSC2_SATURATION,
@@ -93,8 +121,18 @@ impl IndexedSection for OscillatorSection {
                 h6: PARAM_OSC2_H6,
                 h7: PARAM_OSC2_H7,
                 h8: PARAM_OSC2_H8,
+                additive_normalize: PARAM_OSC2_ADDITIVE_NORMALIZE,
+                additive_gain: PARAM_OSC2_ADDITIVE_GAIN,
+                harmonic_count: PARAM_OSC2_HARMONIC_COUNT,
+                harmonic_slope: PARAM_OSC2_HARMONIC_SLOPE,
                 wavetable_index: PARAM_OSC2_WAVETABLE_INDEX,
                 wavetable_position: PARAM_OSC2_WAVETABLE_POSITION,
+                filter_routing: PARAM_OSC2_FILTER_ROUTING,
+                pluck_damping: PARAM_OSC2_PLUCK_DAMPING,
+                pluck_brightness: PARAM_OSC2_PLUCK_BRIGHTNESS,
+                fixed_freq: PARAM_OSC2_FIXED_FREQ,
+                fixed_freq_hz: PARAM_OSC2_FIXED_FREQ_HZ,
+                sample_reduce: PARAM_OSC2_SAMPLE_REDUCE,
             },
             _ => OscillatorParams {
                 waveform: PARAM_OSC3_WAVEFORM,
@@ -108,6 +146,8 @@ impl IndexedSection for OscillatorSection {
                 shape: PARAM_OSC3_SHAPE,
                 fm_source: PARAM_OSC3_FM_SOURCE,
                 fm_amount: PARAM_OSC3_FM_AMOUNT,
+                fm_feedback: PARAM_OSC3_FM_FEEDBACK,
+                gain_slew: PARAM_OSC3_GAIN_SLEW,
                 solo: PARAM_OSC3_SOLO,
                 unison_normalize: PARAM_OSC3_UNISON_NORMALIZE,
                 saturation: PARAM_OSC3_SATURATION,
@@ -119,8 +159,18 @@ impl IndexedSection for OscillatorSection {
                 h6: PARAM_OSC3_H6,
                 h7: PARAM_OSC3_H7,
                 h8: PARAM_OSC3_H8,
+                additive_normalize: PARAM_OSC3_ADDITIVE_NORMALIZE,
+                additive_gain: PARAM_OSC3_ADDITIVE_GAIN,
+                harmonic_count: PARAM_OSC3_HARMONIC_COUNT,
+                harmonic_slope: PARAM_OSC3_HARMONIC_SLOPE,
                 wavetable_index: PARAM_OSC3_WAVETABLE_INDEX,
                 wavetable_position: PARAM_OSC3_WAVETABLE_POSITION,
+                filter_routing: PARAM_OSC3_FILTER_ROUTING,
+                pluck_damping: PARAM_OSC3_PLUCK_DAMPING,
+                pluck_brightness: PARAM_OSC3_PLUCK_BRIGHTNESS,
+                fixed_freq: PARAM_OSC3_FIXED_FREQ,
+                fixed_freq_hz: PARAM_OSC3_FIXED_FREQ_HZ,
+                sample_reduce: PARAM_OSC3_SAMPLE_REDUCE,
             },
         }
     }
@@ -136,9 +186,11 @@ impl IndexedSection for OscillatorSection {
                     .color(Color::rgb(200, 200, 210));
                 oscillator_waveform_button(cx, p.waveform, index - 1);
                 fm_source_button(cx, p.fm_source, index - 1);
+                filter_routing_button(cx, p.filter_routing);
 
                 let solo_v = current_normalized(cx, p.solo);
                 param_checkbox(cx, p.solo, "Solo", solo_v > 0.5);
+                copy_to_buttons(cx, CopySection::Oscillator, index, 3);
             })
             .height(Units::Auto)
             .gap(Pixels(10.0));
@@ -148,8 +200,11 @@ impl IndexedSection for OscillatorSection {
                 let pitch_v = current_normalized(cx, p.pitch);
                 let detune_v = current_normalized(cx, p.detune);
                 let gain_v = current_normalized(cx, p.gain);
+                let gain_slew_v = current_normalized(cx, p.gain_slew);
                 let pan_v = current_normalized(cx, p.pan);
                 let saturation_v = current_normalized(cx, p.saturation);
+                let fixed_freq_v = current_normalized(cx, p.fixed_freq);
+                let fixed_freq_hz_v = current_normalized(cx, p.fixed_freq_hz);
 
                 param_knob(cx, p.pitch, "Pitch", pitch_v, default_normalized(p.pitch));
                 param_knob(
@@ -160,6 +215,13 @@ impl IndexedSection for OscillatorSection {
                     default_normalized(p.detune),
                 );
                 param_knob(cx, p.gain, "Gain", gain_v, default_normalized(p.gain));
+                param_knob(
+                    cx,
+                    p.gain_slew,
+                    "Slew",
+                    gain_slew_v,
+                    default_normalized(p.gain_slew),
+                );
                 param_knob(cx, p.pan, "Pan", pan_v, default_normalized(p.pan));
                 param_knob(
                     cx,
@@ -168,15 +230,25 @@ impl IndexedSection for OscillatorSection {
                     saturation_v,
                     default_normalized(p.saturation),
                 );
+                param_knob(
+                    cx,
+                    p.fixed_freq_hz,
+                    "Fixed Hz",
+                    fixed_freq_hz_v,
+                    default_normalized(p.fixed_freq_hz),
+                );
+                param_checkbox(cx, p.fixed_freq, "Fixed", fixed_freq_v > 0.5);
             });
 
             // Modulation & unison parameters
             Self::build_param_row(cx, |cx| {
                 let fm_amount_v = current_normalized(cx, p.fm_amount);
+                let fm_feedback_v = current_normalized(cx, p.fm_feedback);
                 let unison_v = current_normalized(cx, p.unison);
                 let unison_detune_v = current_normalized(cx, p.unison_detune);
                 let shape_v = current_normalized(cx, p.shape);
                 let unison_normalize_v = current_normalized(cx, p.unison_normalize);
+                let sample_reduce_v = current_normalized(cx, p.sample_reduce);
 
                 param_knob(
                     cx,
@@ -185,6 +257,13 @@ impl IndexedSection for OscillatorSection {
                     fm_amount_v,
                     default_normalized(p.fm_amount),
                 );
+                param_knob(
+                    cx,
+                    p.fm_feedback,
+                    "FM FB",
+                    fm_feedback_v,
+                    default_normalized(p.fm_feedback),
+                );
                 param_knob(
                     cx,
                     p.unison,
@@ -201,6 +280,13 @@ impl IndexedSection for OscillatorSection {
                 );
                 param_knob(cx, p.shape, "Shape", shape_v, default_normalized(p.shape));
                 param_checkbox(cx, p.unison_normalize, "UNorm", unison_normalize_v > 0.5);
+                param_knob(
+                    cx,
+                    p.sample_reduce,
+                    "Rate",
+                    sample_reduce_v,
+                    default_normalized(p.sample_reduce),
+                );
             });
         })
         .height(Units::Auto)
@@ -238,6 +324,33 @@ fn build_additive_section(cx: &mut Context, p: &OscillatorParams, index: usize)
             param_vslider(cx, p.h6, "H6", h6_v, default_normalized(p.h6));
             param_vslider(cx, p.h7, "H7", h7_v, default_normalized(p.h7));
             param_vslider(cx, p.h8, "H8", h8_v, default_normalized(p.h8));
+
+            let additive_gain_v = current_normalized(cx, p.additive_gain);
+            let additive_normalize_v = current_normalized(cx, p.additive_normalize);
+            let harmonic_count_v = current_normalized(cx, p.harmonic_count);
+            let harmonic_slope_v = current_normalized(cx, p.harmonic_slope);
+            param_knob(
+                cx,
+                p.harmonic_count,
+                "Count",
+                harmonic_count_v,
+                default_normalized(p.harmonic_count),
+            );
+            param_knob(
+                cx,
+                p.harmonic_slope,
+                "Slope",
+                harmonic_slope_v,
+                default_normalized(p.harmonic_slope),
+            );
+            param_knob(
+                cx,
+                p.additive_gain,
+                "Trim",
+                additive_gain_v,
+                default_normalized(p.additive_gain),
+            );
+            param_checkbox(cx, p.additive_normalize, "Norm", additive_normalize_v > 0.5);
         })
         .height(Units::Auto)
         .gap(Pixels(2.0));
@@ -280,6 +393,40 @@ fn build_wavetable_section(cx: &mut Context, p: &OscillatorParams, index: usize)
     .gap(Pixels(12.0));
 }
 
+fn build_pluck_section(cx: &mut Context, p: &OscillatorParams, index: usize) {
+    VStack::new(cx, |cx| {
+        Label::new(cx, &format!("Pluck Controls (Osc {})", index))
+            .font_size(12.0)
+            .color(Color::rgb(200, 200, 210))
+            .width(Stretch(1.0))
+            .height(Pixels(22.0));
+
+        HStack::new(cx, |cx| {
+            let damping_v = current_normalized(cx, p.pluck_damping);
+            let brightness_v = current_normalized(cx, p.pluck_brightness);
+
+            param_knob(
+                cx,
+                p.pluck_damping,
+                "Damping",
+                damping_v,
+                default_normalized(p.pluck_damping),
+            );
+            param_knob(
+                cx,
+                p.pluck_brightness,
+                "Bright",
+                brightness_v,
+                default_normalized(p.pluck_brightness),
+            );
+        })
+        .height(Units::Auto)
+        .gap(Pixels(6.0));
+    })
+    .height(Units::Auto)
+    .gap(Pixels(12.0));
+}
+
 // Public API functions for backward compatibility
 
 // Public API functions for backward compatibility
@@ -292,7 +439,8 @@ pub fn build_waveform_specific_section(cx: &mut Context, osc_index: usize) {
         let p = OscillatorSection.get_params(osc_index);
         build_additive_section(cx, &p, osc_index);
         build_wavetable_section(cx, &p, osc_index);
+        build_pluck_section(cx, &p, osc_index);
     })
-    .height(Pixels(125.0))
+    .height(Units::Auto)
     .gap(Pixels(10.0));
 }
@@ -0,0 +1,96 @@
+// Parameter search: filter the registry by name/module and adjust matches inline
+
+use super::helpers::{current_normalized, default_normalized};
+use crate::gui::messages::UiTab;
+use crate::gui::widgets::param_knob;
+use crate::gui::{theme, GuiMessage, GuiState};
+use crate::plugin::param_registry;
+use vizia::prelude::*;
+
+/// Most matches shown at once (each one builds a knob; refine the query to see others)
+const MAX_SEARCH_RESULTS: usize = 48;
+
+pub fn build_param_search(cx: &mut Context) {
+    VStack::new(cx, |cx| {
+        HStack::new(cx, |cx| {
+            Label::new(cx, "Search")
+                .font_size(16.0)
+                .color(theme::TEXT_SECONDARY)
+                .height(Pixels(28.0));
+            Textbox::new(cx, GuiState::param_search)
+                .on_edit(|cx, text| cx.emit(GuiMessage::SetParamSearch(text)))
+                .width(Pixels(320.0))
+                .height(Pixels(28.0))
+                .color(theme::TEXT_BRIGHT)
+                .background_color(theme::WIDGET_BG)
+                .border_width(Pixels(1.0))
+                .border_color(theme::WIDGET_BORDER)
+                .corner_radius(Pixels(3.0));
+            Label::new(
+                cx,
+                GuiState::param_search_results.map(|results| match results.len() {
+                    0 => String::new(),
+                    n if n > MAX_SEARCH_RESULTS => {
+                        format!("{} matches (showing {})", n, MAX_SEARCH_RESULTS)
+                    }
+                    n => format!("{} matches", n),
+                }),
+            )
+            .font_size(12.0)
+            .color(theme::TEXT_TERTIARY)
+            .height(Pixels(28.0));
+        })
+        .height(Units::Auto)
+        .gap(Pixels(8.0));
+
+        Binding::new(cx, GuiState::param_search_results, |cx, results| {
+            let results = results.get(cx);
+            VStack::new(cx, |cx| {
+                for &param_id in results.iter().take(MAX_SEARCH_RESULTS) {
+                    build_result_row(cx, param_id);
+                }
+            })
+            .height(Units::Auto)
+            .gap(Pixels(6.0));
+        });
+    })
+    .width(Stretch(1.0))
+    .height(Units::Auto)
+    .padding(Pixels(10.0))
+    .gap(Pixels(12.0))
+    .background_color(theme::BG_SECTION);
+}
+
+/// One match: module, inline knob, and a button that opens the tab holding the control
+fn build_result_row(cx: &mut Context, param_id: u32) {
+    let Some(desc) = param_registry::get_registry().get(param_id) else {
+        return;
+    };
+    let tab = UiTab::for_module(&desc.module);
+    let module = desc.module.clone();
+    let name = desc.name.clone();
+
+    HStack::new(cx, |cx| {
+        Label::new(cx, &module)
+            .font_size(13.0)
+            .color(theme::TEXT_TERTIARY)
+            .width(Pixels(180.0))
+            .top(Stretch(1.0))
+            .bottom(Stretch(1.0));
+        let value = current_normalized(cx, param_id);
+        param_knob(cx, param_id, &name, value, default_normalized(param_id));
+        Button::new(cx, |cx| Label::new(cx, "Show"))
+            .on_press(move |cx| cx.emit(GuiMessage::SetActiveTab(tab)))
+            .height(Pixels(28.0))
+            .padding(Pixels(6.0))
+            .top(Stretch(1.0))
+            .bottom(Stretch(1.0))
+            .background_color(theme::BUTTON_BG_INACTIVE)
+            .corner_radius(Pixels(4.0))
+            .cursor(CursorIcon::Hand);
+    })
+    .height(Units::Auto)
+    .gap(Pixels(12.0))
+    .background_color(theme::BG_PANEL)
+    .padding(Pixels(4.0));
+}
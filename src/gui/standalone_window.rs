@@ -1,14 +1,16 @@
 // VIZIA standalone window for desktop application
 
-use crate::audio::output::EngineEvent;
+use crate::audio::engine::{SharedModulationFeed, SharedOutputHistory};
+use crate::audio::output::{AudioControl, EngineEvent};
 use crate::gui::shared_ui;
 use crate::gui::theme;
-use crate::gui::GuiState;
+use crate::gui::{GuiMessage, GuiState};
+use crate::midi::handler::MidiHandler;
 use crate::params::SynthParams;
 use crate::plugin::gui_param_change::GuiParamChange;
 use crossbeam_channel::Sender;
 use parking_lot::{Mutex, RwLock};
-use std::collections::HashSet;
+use std::collections::HashMap;
 use std::sync::Arc;
 use triple_buffer::Input;
 use vizia::prelude::*;
@@ -24,6 +26,10 @@ pub fn run_standalone_gui(
     gui_param_producer: Arc<Mutex<Input<GuiParamChange>>>,
     params_producer: Arc<Mutex<Input<SynthParams>>>,
     event_sender: Sender<EngineEvent>,
+    modulation_feed: SharedModulationFeed,
+    output_history: SharedOutputHistory,
+    midi_handler: Option<Arc<Mutex<MidiHandler>>>,
+    audio_control: Option<AudioControl>,
 ) -> Result<(), Box<dyn std::error::Error>> {
     let _ = Application::new(move |cx| {
         // Initialize GUI state with event sender for standalone features
@@ -35,12 +41,19 @@ pub fn run_standalone_gui(
             params_producer.clone(),
             event_sender.clone(),
         )
+        .with_modulation_feed(modulation_feed.clone())
+        .with_output_history(output_history.clone())
+        .with_midi_handler(midi_handler.clone())
+        .with_audio_control(audio_control.clone())
         .build(cx);
 
         // Create root container that captures keyboard events
         // The KeyboardCapture and its children (the UI) are children of the root where
         // GuiState was built, so events will bubble up to GuiState
         KeyboardCapture::new(cx, event_sender.clone(), |cx| {
+            // Standalone-only device selection above the shared layout
+            build_device_bar(cx);
+
             // Build shared UI layout inside the keyboard-capturing container
             shared_ui::build_ui(cx);
         });
@@ -52,11 +65,112 @@ pub fn run_standalone_gui(
     Ok(())
 }
 
+/// MIDI input and audio output pickers, the record and preset export buttons and the
+/// voice/CPU readout (standalone only; the host owns devices and recording in the plugin)
+fn build_device_bar(cx: &mut Context) {
+    HStack::new(cx, |cx| {
+        device_label(cx, "MIDI In");
+        PickList::new(cx, GuiState::midi_ports, GuiState::midi_port_index, true)
+            .on_select(|cx, index| cx.emit(GuiMessage::SelectMidiPort(index)))
+            .width(Pixels(240.0))
+            .height(Pixels(24.0));
+
+        device_label(cx, "Audio Out");
+        PickList::new(
+            cx,
+            GuiState::audio_devices,
+            GuiState::audio_device_index,
+            true,
+        )
+        .on_select(|cx, index| cx.emit(GuiMessage::SelectAudioDevice(index)))
+        .width(Pixels(240.0))
+        .height(Pixels(24.0));
+        PickList::new(
+            cx,
+            GuiState::sample_rate_labels,
+            GuiState::sample_rate_index,
+            true,
+        )
+        .on_select(|cx, index| cx.emit(GuiMessage::SelectSampleRate(index)))
+        .width(Pixels(100.0))
+        .height(Pixels(24.0));
+        PickList::new(
+            cx,
+            GuiState::buffer_size_labels,
+            GuiState::buffer_size_index,
+            true,
+        )
+        .on_select(|cx, index| cx.emit(GuiMessage::SelectBufferSize(index)))
+        .width(Pixels(120.0))
+        .height(Pixels(24.0));
+
+        Button::new(cx, |cx| {
+            Label::new(
+                cx,
+                GuiState::recording.map(|recording| if *recording { "■ Stop" } else { "● Rec" }),
+            )
+        })
+        .on_press(|cx| cx.emit(GuiMessage::ToggleRecording))
+        .width(Pixels(64.0))
+        .height(Pixels(24.0))
+        .background_color(GuiState::recording.map(|recording| {
+            if *recording {
+                theme::RECORD_ACCENT
+            } else {
+                theme::BUTTON_BG_INACTIVE
+            }
+        }))
+        .corner_radius(Pixels(4.0))
+        .cursor(CursorIcon::Hand);
+
+        Button::new(cx, |cx| Label::new(cx, "Export"))
+            .on_press(|cx| cx.emit(GuiMessage::ExportPreset))
+            .width(Pixels(64.0))
+            .height(Pixels(24.0))
+            .background_color(theme::BUTTON_BG_INACTIVE)
+            .corner_radius(Pixels(4.0))
+            .cursor(CursorIcon::Hand);
+
+        Label::new(cx, GuiState::performance_text)
+            .font_size(12.0)
+            .color(theme::TEXT_SECONDARY)
+            .height(Pixels(24.0))
+            .left(Stretch(1.0));
+    })
+    .height(Units::Auto)
+    .gap(Pixels(8.0))
+    .padding(Pixels(4.0));
+}
+
+fn device_label(cx: &mut Context, text: &str) {
+    Label::new(cx, text)
+        .font_size(12.0)
+        .color(theme::TEXT_SECONDARY)
+        .height(Pixels(24.0));
+}
+
+/// Octave shift range for the computer keyboard (±3 octaves around the default layout)
+const MAX_OCTAVE_SHIFT: i8 = 3;
+
+/// Velocity step for the -/= keys
+const VELOCITY_STEP: f32 = 0.1;
+
 /// Container view that captures keyboard events and converts them to MIDI notes
+///
+/// Up/Down arrows shift the keyboard octave, -/= lower/raise the typed-note velocity.
+/// Holding a number key 1-9 plays notes at that fraction of full velocity until it's
+/// released, for accents without changing the -/= setting.
 struct KeyboardCapture {
     event_sender: Sender<EngineEvent>,
-    /// Track which notes are currently held to filter out key repeat events
-    pressed_notes: HashSet<u8>,
+    /// Held keys and the note each one started, so note-offs match even if the
+    /// octave changed while the key was down (also filters key repeat)
+    pressed_notes: HashMap<Code, u8>,
+    /// Octave offset applied to the key map
+    octave_shift: i8,
+    /// Velocity for typed notes (0.0-1.0)
+    velocity: f32,
+    /// Held number key and the velocity it selects, overriding `velocity`
+    velocity_override: Option<(Code, f32)>,
 }
 
 impl KeyboardCapture {
@@ -66,37 +180,103 @@ impl KeyboardCapture {
     {
         Self {
             event_sender,
-            pressed_notes: HashSet::new(),
+            pressed_notes: HashMap::new(),
+            octave_shift: 0,
+            velocity: 1.0,
+            velocity_override: None,
         }
         .build(cx, content)
         .width(Stretch(1.0))
         .height(Stretch(1.0))
         .focusable(true)
     }
+
+    /// Velocity the next typed note plays at
+    fn note_velocity(&self) -> f32 {
+        self.velocity_override
+            .map_or(self.velocity, |(_, velocity)| velocity)
+    }
+
+    /// Show the current octave/velocity in the status text
+    fn report_status(&self, cx: &mut EventContext) {
+        cx.emit(GuiMessage::KeyboardStatus(format!(
+            "Keyboard: octave {:+}, velocity {}",
+            self.octave_shift,
+            (self.note_velocity() * 127.0).round() as u8
+        )));
+    }
 }
 
 impl View for KeyboardCapture {
     fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
         event.map(|window_event, meta| match window_event {
+            WindowEvent::KeyDown(Code::KeyZ, _)
+                if cx.modifiers().ctrl() || cx.modifiers().logo() =>
+            {
+                // Ctrl+Z / Ctrl+Shift+Z (Cmd on macOS); Z alone stays a piano key
+                if cx.modifiers().shift() {
+                    cx.emit(GuiMessage::Redo);
+                } else {
+                    cx.emit(GuiMessage::Undo);
+                }
+                meta.consume();
+            }
+            WindowEvent::KeyDown(Code::ArrowUp | Code::ArrowDown, _) => {
+                let step = if matches!(window_event, WindowEvent::KeyDown(Code::ArrowUp, _)) {
+                    1
+                } else {
+                    -1
+                };
+                self.octave_shift =
+                    (self.octave_shift + step).clamp(-MAX_OCTAVE_SHIFT, MAX_OCTAVE_SHIFT);
+                self.report_status(cx);
+                meta.consume();
+            }
+            WindowEvent::KeyDown(Code::Minus | Code::Equal, _) => {
+                let step = if matches!(window_event, WindowEvent::KeyDown(Code::Equal, _)) {
+                    VELOCITY_STEP
+                } else {
+                    -VELOCITY_STEP
+                };
+                self.velocity = (self.velocity + step).clamp(VELOCITY_STEP, 1.0);
+                self.report_status(cx);
+                meta.consume();
+            }
+            WindowEvent::KeyDown(code, _) if digit_velocity(code).is_some() => {
+                if let Some(velocity) = digit_velocity(code) {
+                    self.velocity_override = Some((*code, velocity));
+                    self.report_status(cx);
+                }
+                meta.consume();
+            }
             WindowEvent::KeyDown(code, _) => {
-                if let Some(note) = key_code_to_midi_note(code) {
-                    // Only send NoteOn if this note isn't already pressed (filter key repeat)
-                    if !self.pressed_notes.contains(&note) {
-                        self.pressed_notes.insert(note);
+                if let Some(base_note) = key_code_to_midi_note(code) {
+                    // Only send NoteOn if this key isn't already pressed (filter key repeat)
+                    if !self.pressed_notes.contains_key(code) {
+                        let note =
+                            (base_note as i16 + self.octave_shift as i16 * 12).clamp(0, 127) as u8;
+                        self.pressed_notes.insert(*code, note);
                         let _ = self.event_sender.try_send(EngineEvent::NoteOn {
                             note,
-                            velocity: 1.0,
+                            velocity: self.note_velocity(),
                         });
                     }
                     meta.consume();
                 }
             }
+            WindowEvent::KeyUp(code, _)
+                if self
+                    .velocity_override
+                    .is_some_and(|(held, _)| held == *code) =>
+            {
+                self.velocity_override = None;
+                self.report_status(cx);
+                meta.consume();
+            }
             WindowEvent::KeyUp(code, _) => {
-                if let Some(note) = key_code_to_midi_note(code) {
-                    // Only send NoteOff if the note was actually pressed
-                    if self.pressed_notes.remove(&note) {
-                        let _ = self.event_sender.try_send(EngineEvent::NoteOff { note });
-                    }
+                // Only send NoteOff if the key was actually pressed
+                if let Some(note) = self.pressed_notes.remove(code) {
+                    let _ = self.event_sender.try_send(EngineEvent::NoteOff { note });
                     meta.consume();
                 }
             }
@@ -109,6 +289,23 @@ impl View for KeyboardCapture {
     }
 }
 
+/// Velocity selected by a number-row key: 1 is softest, 9 is full velocity
+fn digit_velocity(code: &Code) -> Option<f32> {
+    let digit = match code {
+        Code::Digit1 => 1,
+        Code::Digit2 => 2,
+        Code::Digit3 => 3,
+        Code::Digit4 => 4,
+        Code::Digit5 => 5,
+        Code::Digit6 => 6,
+        Code::Digit7 => 7,
+        Code::Digit8 => 8,
+        Code::Digit9 => 9,
+        _ => return None,
+    };
+    Some(digit as f32 / 9.0)
+}
+
 /// Map keyboard code to MIDI note number
 /// Using QWERTY layout: AWSEDFTGYHUJKOLP (white + black keys)
 fn key_code_to_midi_note(code: &Code) -> Option<u8> {
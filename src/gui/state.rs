@@ -1,19 +1,40 @@
 // GUI state for VIZIA - shared between plugin and standalone
 
 #[cfg(feature = "standalone")]
-use crate::audio::output::EngineEvent;
-use crate::gui::messages::UiTab;
+use crate::audio::engine::MAX_POLYPHONY;
+use crate::audio::engine::{
+    ModulationSnapshot, SharedModulationFeed, SharedOutputHistory, SynthEngine, MODULATION_TARGETS,
+};
+#[cfg(feature = "standalone")]
+use crate::audio::output::{self, AudioControl, AudioSettings, EngineEvent};
+use crate::dsp::MonoCompatibilityReport;
+use crate::gui::messages::{CopySection, EnvelopeTarget, GuiLayout, UiTab};
+use crate::gui::theme;
+use crate::gui::widgets::note_display::note_name;
 use crate::gui::GuiMessage;
+#[cfg(feature = "standalone")]
+use crate::midi::handler::MidiHandler;
 use crate::params::SynthParams;
+use crate::plugin::ab_compare::{AbCompare, AbSlot};
 use crate::plugin::gui_param_change::GuiParamChange;
+use crate::plugin::state::{PluginState, PresetManager};
+use crate::plugin::undo_history::UndoHistory;
+use crate::randomize::RandomizeLocks;
 #[cfg(feature = "standalone")]
 use crossbeam_channel::Sender;
+use crossbeam_channel::{Receiver, TryRecvError};
 use parking_lot::{Mutex, RwLock};
 use std::collections::HashSet;
+use std::path::Path;
 use std::sync::Arc;
+use std::time::Instant;
 use triple_buffer::Input;
 use vizia::prelude::*;
 
+/// How often the standalone voice/CPU readout updates
+#[cfg(feature = "standalone")]
+const PERFORMANCE_REFRESH_INTERVAL: std::time::Duration = std::time::Duration::from_millis(250);
+
 /// GUI state that holds synth parameters and provides VIZIA lens access
 ///
 /// This intentionally stays simple: we store the shared Arc and apply updates directly.
@@ -34,6 +55,9 @@ pub struct GuiState {
     /// UI feedback string (e.g. last changed param/value)
     pub last_param_text: String,
 
+    /// Last note received and its velocity (e.g. "C4 vel 100")
+    pub last_note_text: String,
+
     /// Event sender for standalone features (MIDI, panic) - None for plugin
     #[lens(ignore)]
     #[cfg(feature = "standalone")]
@@ -50,6 +74,117 @@ pub struct GuiState {
 
     /// Active UI tab
     pub active_tab: UiTab,
+
+    /// Vertical scroll of the tab content (0.0 = top, 1.0 = bottom)
+    pub scroll_y: f32,
+
+    /// Tab and scroll position (shared with the plugin so they persist in projects)
+    #[lens(ignore)]
+    pub gui_layout: Arc<Mutex<GuiLayout>>,
+
+    /// Envelope shown in the visual envelope editor
+    pub envelope_editor_target: EnvelopeTarget,
+
+    /// Text in the parameter search box
+    pub param_search: String,
+
+    /// Parameters matching `param_search`, in registry order
+    pub param_search_results: Vec<u32>,
+
+    /// Engine feed of effective (post-modulation) values for knob modulation rings
+    #[lens(ignore)]
+    pub modulation_feed: Option<SharedModulationFeed>,
+
+    /// Last snapshot forwarded to knobs (only changes are re-emitted)
+    #[lens(ignore)]
+    pub last_modulation: ModulationSnapshot,
+
+    /// Recent engine output that the mono compatibility check analyses
+    #[lens(ignore)]
+    pub output_history: Option<SharedOutputHistory>,
+
+    /// Result of the running mono compatibility check (analysed on a worker thread)
+    #[lens(ignore)]
+    pub pending_mono_report: Option<Receiver<Option<MonoCompatibilityReport>>>,
+
+    /// Outcome of the last mono compatibility check
+    pub mono_report_text: String,
+
+    /// Last parameter moved in the editor (what a macro's Assign button picks up)
+    #[lens(ignore)]
+    pub last_touched_param: Option<u32>,
+
+    /// Undo/redo snapshots of committed parameter changes
+    #[lens(ignore)]
+    pub undo_history: UndoHistory,
+
+    /// A/B compare slots (shared with the plugin state so they persist in projects)
+    #[lens(ignore)]
+    pub ab_compare: Arc<Mutex<AbCompare>>,
+
+    /// Is slot B live? (drives the A/B button highlight)
+    pub ab_is_b: bool,
+
+    /// Sections Randomize keeps as they are
+    pub randomize_locks: RandomizeLocks,
+
+    /// Chosen GUI scale (shared with the plugin so it persists in projects)
+    #[lens(ignore)]
+    pub gui_scale: Arc<Mutex<f64>>,
+
+    /// Scale picker entries ("100%" ...)
+    pub gui_scale_labels: Vec<String>,
+
+    /// Index of the chosen scale in `theme::GUI_SCALE_CHOICES`
+    pub gui_scale_index: usize,
+
+    /// MIDI input handler for the device picker (standalone only)
+    #[lens(ignore)]
+    #[cfg(feature = "standalone")]
+    pub midi_handler: Option<Arc<Mutex<MidiHandler>>>,
+
+    /// MIDI input port names shown in the device picker
+    pub midi_ports: Vec<String>,
+
+    /// Index of the connected port in `midi_ports`
+    pub midi_port_index: usize,
+
+    /// Audio output control for the device/rate pickers (standalone only)
+    #[lens(ignore)]
+    #[cfg(feature = "standalone")]
+    pub audio_control: Option<AudioControl>,
+
+    /// Audio output device names shown in the device picker
+    pub audio_devices: Vec<String>,
+
+    /// Index of the running device in `audio_devices`
+    pub audio_device_index: usize,
+
+    /// Sample rates the running device supports, in Hz
+    #[lens(ignore)]
+    pub sample_rates: Vec<u32>,
+
+    /// `sample_rates` formatted for the rate picker
+    pub sample_rate_labels: Vec<String>,
+
+    /// Index of the running rate in `sample_rates`
+    pub sample_rate_index: usize,
+
+    /// Buffer size picker entries ("Default" followed by the fixed sizes)
+    pub buffer_size_labels: Vec<String>,
+
+    /// Index of the running buffer size in `buffer_size_labels`
+    pub buffer_size_index: usize,
+
+    /// Is the output being recorded to a WAV file? (drives the record button)
+    pub recording: bool,
+
+    /// Voice count / CPU load readout (standalone only)
+    pub performance_text: String,
+
+    /// When `performance_text` was last refreshed
+    #[lens(ignore)]
+    pub performance_refreshed_at: Instant,
 }
 
 impl GuiState {
@@ -58,11 +193,13 @@ impl GuiState {
         synth_params: Arc<RwLock<SynthParams>>,
         gui_param_producer: Arc<Mutex<Input<GuiParamChange>>>,
     ) -> Self {
+        let initial_params = *synth_params.read();
         Self {
             synth_params,
             gui_param_producer,
             params_producer: None,
             last_param_text: String::new(),
+            last_note_text: String::new(),
             #[cfg(feature = "standalone")]
             event_sender: None,
             pressed_keys: HashSet::new(),
@@ -70,6 +207,43 @@ impl GuiState {
             osc2_waveform: 0,
             osc3_waveform: 0,
             active_tab: UiTab::Oscillator,
+            scroll_y: 0.0,
+            gui_layout: Arc::new(Mutex::new(GuiLayout::default())),
+            envelope_editor_target: EnvelopeTarget::Amp,
+            param_search: String::new(),
+            param_search_results: Vec::new(),
+            modulation_feed: None,
+            last_modulation: ModulationSnapshot::default(),
+            output_history: None,
+            pending_mono_report: None,
+            mono_report_text: String::new(),
+            last_touched_param: None,
+            undo_history: UndoHistory::new(),
+            ab_compare: Arc::new(Mutex::new(AbCompare::new(&initial_params))),
+            ab_is_b: false,
+            randomize_locks: RandomizeLocks::default(),
+            gui_scale: Arc::new(Mutex::new(theme::DEFAULT_GUI_SCALE)),
+            gui_scale_labels: theme::GUI_SCALE_CHOICES
+                .iter()
+                .map(|scale| format!("{:.0}%", scale * 100.0))
+                .collect(),
+            gui_scale_index: theme::nearest_gui_scale_index(theme::DEFAULT_GUI_SCALE),
+            #[cfg(feature = "standalone")]
+            midi_handler: None,
+            midi_ports: Vec::new(),
+            midi_port_index: 0,
+            #[cfg(feature = "standalone")]
+            audio_control: None,
+            audio_devices: Vec::new(),
+            audio_device_index: 0,
+            sample_rates: Vec::new(),
+            sample_rate_labels: Vec::new(),
+            sample_rate_index: 0,
+            buffer_size_labels: Vec::new(),
+            buffer_size_index: 0,
+            recording: false,
+            performance_text: String::new(),
+            performance_refreshed_at: Instant::now(),
         }
     }
 
@@ -81,22 +255,129 @@ impl GuiState {
         params_producer: Arc<Mutex<Input<SynthParams>>>,
         event_sender: Sender<EngineEvent>,
     ) -> Self {
+        let initial_params = *synth_params.read();
         Self {
             synth_params,
             gui_param_producer,
             params_producer: Some(params_producer),
             last_param_text: String::new(),
+            last_note_text: String::new(),
             event_sender: Some(event_sender),
             pressed_keys: HashSet::new(),
             osc1_waveform: 0, // Default: Sine
             osc2_waveform: 0,
             osc3_waveform: 0,
             active_tab: UiTab::Oscillator,
+            scroll_y: 0.0,
+            gui_layout: Arc::new(Mutex::new(GuiLayout::default())),
+            envelope_editor_target: EnvelopeTarget::Amp,
+            param_search: String::new(),
+            param_search_results: Vec::new(),
+            modulation_feed: None,
+            last_modulation: ModulationSnapshot::default(),
+            output_history: None,
+            pending_mono_report: None,
+            mono_report_text: String::new(),
+            last_touched_param: None,
+            undo_history: UndoHistory::new(),
+            ab_compare: Arc::new(Mutex::new(AbCompare::new(&initial_params))),
+            ab_is_b: false,
+            randomize_locks: RandomizeLocks::default(),
+            gui_scale: Arc::new(Mutex::new(theme::DEFAULT_GUI_SCALE)),
+            gui_scale_labels: theme::GUI_SCALE_CHOICES
+                .iter()
+                .map(|scale| format!("{:.0}%", scale * 100.0))
+                .collect(),
+            gui_scale_index: theme::nearest_gui_scale_index(theme::DEFAULT_GUI_SCALE),
+            midi_handler: None,
+            midi_ports: Vec::new(),
+            midi_port_index: 0,
+            audio_control: None,
+            audio_devices: Vec::new(),
+            audio_device_index: 0,
+            sample_rates: Vec::new(),
+            sample_rate_labels: Vec::new(),
+            sample_rate_index: 0,
+            buffer_size_labels: Vec::new(),
+            buffer_size_index: 0,
+            recording: false,
+            performance_text: String::new(),
+            performance_refreshed_at: Instant::now(),
+        }
+    }
+
+    /// Attach the engine's modulation feed so modulated knobs show their effective value
+    pub fn with_modulation_feed(mut self, feed: SharedModulationFeed) -> Self {
+        self.modulation_feed = Some(feed);
+        self
+    }
+
+    /// Attach the engine's output history so the mono compatibility check can analyse it
+    pub fn with_output_history(mut self, history: SharedOutputHistory) -> Self {
+        self.output_history = Some(history);
+        self
+    }
+
+    /// Forward changed modulation values to the knobs (normalized, `None` = hide ring)
+    fn poll_modulation(&mut self, cx: &mut EventContext) {
+        let Some(feed) = self.modulation_feed.as_ref() else {
+            return;
+        };
+
+        let snapshot = match feed.lock().as_mut() {
+            Some(consumer) => *consumer.read(),
+            None => ModulationSnapshot::default(),
+        };
+
+        if snapshot != self.last_modulation {
+            let registry = crate::plugin::param_registry::get_registry();
+            for (slot, &param_id) in MODULATION_TARGETS.iter().enumerate() {
+                if snapshot.values[slot] == self.last_modulation.values[slot] {
+                    continue;
+                }
+                let normalized = snapshot.values[slot].and_then(|value| {
+                    registry
+                        .get(param_id)
+                        .map(|desc| desc.normalize_value(value))
+                });
+                cx.emit_custom(
+                    Event::new(GuiMessage::SyncModulation(param_id, normalized))
+                        .propagate(vizia::prelude::Propagation::Subtree),
+                );
+            }
+            if snapshot.held_notes != self.last_modulation.held_notes {
+                cx.emit_custom(
+                    Event::new(GuiMessage::SyncHeldNotes(snapshot.held_notes))
+                        .propagate(vizia::prelude::Propagation::Subtree),
+                );
+            }
+            if snapshot.effect_levels != self.last_modulation.effect_levels {
+                cx.emit_custom(
+                    Event::new(GuiMessage::SyncEffectLevels(snapshot.effect_levels))
+                        .propagate(vizia::prelude::Propagation::Subtree),
+                );
+            }
+            if snapshot.last_note != self.last_modulation.last_note {
+                if let Some((note, velocity)) = snapshot.last_note {
+                    self.last_note_text = format!(
+                        "{} vel {}",
+                        note_name(note),
+                        (velocity * 127.0).round() as u8
+                    );
+                }
+            }
+            self.last_modulation = snapshot;
         }
+
+        // Keep the redraw loop alive so the rings follow the audio
+        cx.needs_redraw();
     }
 
     /// Update parameter value and write to synth_params
-    pub fn update_param(&mut self, param_id: u32, normalized_value: f32) {
+    ///
+    /// `gesture` marks a one-shot edit (reset, typed value) that the plugin reports to the
+    /// host as a complete begin/set/end gesture so it can be recorded as automation.
+    pub fn update_param(&mut self, param_id: u32, normalized_value: f32, gesture: bool) {
         // Write to synth_params
         let mut params = self.synth_params.write();
         crate::plugin::param_update::param_apply::apply_param(
@@ -131,6 +412,7 @@ impl GuiState {
         producer.write(GuiParamChange {
             param_id,
             normalized: normalized_value.clamp(0.0, 1.0),
+            gesture,
         });
     }
 }
@@ -140,11 +422,48 @@ impl Model for GuiState {
         event.map(|msg, meta| match msg {
             GuiMessage::SetActiveTab(tab) => {
                 self.active_tab = *tab;
+                self.gui_layout.lock().tab = *tab;
+                cx.needs_redraw();
+                meta.consume();
+            }
+            GuiMessage::SetScrollY(scroll_y) => {
+                self.scroll_y = *scroll_y;
+                self.gui_layout.lock().scroll_y = *scroll_y;
+                meta.consume();
+            }
+            GuiMessage::CopySection(section, from, to) => {
+                let mut params = *self.synth_params.read();
+                let (copied, name) = match section {
+                    CopySection::Oscillator => (params.copy_osc(*from, *to), "Osc"),
+                    CopySection::Filter => (params.copy_filter(*from, *to), "Filter"),
+                    CopySection::Lfo => (params.copy_lfo(*from, *to), "LFO"),
+                };
+                if copied {
+                    self.record_undo(None);
+                    self.load_params(params);
+                    self.emit_all_param_syncs(cx);
+                    self.last_param_text =
+                        format!("Copied {} {} to {} {}", name, from + 1, name, to + 1);
+                }
                 cx.needs_redraw();
                 meta.consume();
             }
-            GuiMessage::ParamChanged(param_id, normalized) => {
-                self.update_param(*param_id, *normalized);
+            GuiMessage::SetEnvelopeEditorTarget(target) => {
+                self.envelope_editor_target = *target;
+                meta.consume();
+            }
+            GuiMessage::SetParamSearch(query) => {
+                self.param_search = query.clone();
+                self.param_search_results =
+                    crate::plugin::param_registry::get_registry().search(query);
+                meta.consume();
+            }
+            GuiMessage::ParamChanged(param_id, normalized)
+            | GuiMessage::ParamReset(param_id, normalized) => {
+                self.record_undo(Some(*param_id));
+                let gesture = matches!(msg, GuiMessage::ParamReset(..));
+                self.update_param(*param_id, *normalized, gesture);
+                self.sync_macro_targets(cx, *param_id);
 
                 // Provide immediate visual feedback in the UI with formatted values
                 let registry = crate::plugin::param_registry::get_registry();
@@ -162,7 +481,33 @@ impl Model for GuiState {
                 cx.needs_redraw();
                 meta.consume();
             }
+            GuiMessage::ParamTextEntered(param_id, text) => {
+                let registry = crate::plugin::param_registry::get_registry();
+                if let Some(desc) = registry.get(*param_id) {
+                    match desc.parse_value(text) {
+                        Some(normalized) => {
+                            self.record_undo(Some(*param_id));
+                            self.update_param(*param_id, normalized, true);
+                            self.sync_macro_targets(cx, *param_id);
+                            self.last_param_text =
+                                format!("{}: {}", desc.name, desc.format_value(normalized));
+                            cx.emit_custom(
+                                Event::new(GuiMessage::SyncKnobValue(*param_id, normalized))
+                                    .propagate(vizia::prelude::Propagation::Subtree),
+                            );
+                        }
+                        None => {
+                            self.last_param_text =
+                                format!("{}: can't parse \"{}\"", desc.name, text);
+                        }
+                    }
+                }
+
+                cx.needs_redraw();
+                meta.consume();
+            }
             GuiMessage::Randomize => {
+                self.record_undo(None);
                 self.apply_randomized_params();
                 self.last_param_text = "🎲 Randomized!".to_string();
 
@@ -172,24 +517,244 @@ impl Model for GuiState {
                 cx.needs_redraw();
                 meta.consume();
             }
+            GuiMessage::ToggleRandomizeLock(section) => {
+                self.randomize_locks.toggle(*section);
+                meta.consume();
+            }
+            GuiMessage::SelectAbSlot(slot) => {
+                let current = *self.synth_params.read();
+                let switched = self.ab_compare.lock().switch_to(*slot, &current);
+                if let Some(params) = switched {
+                    self.record_undo(None);
+                    self.load_params(params);
+                    self.emit_all_param_syncs(cx);
+                }
+                self.ab_is_b = *slot == AbSlot::B;
+                cx.needs_redraw();
+                meta.consume();
+            }
+            GuiMessage::AssignMacro(index) => {
+                let registry = crate::plugin::param_registry::get_registry();
+                let target = self
+                    .last_touched_param
+                    .and_then(|id| registry.get(id).map(|desc| (id, desc)));
+                match target {
+                    Some((param_id, desc)) => {
+                        // Sweep from where the parameter is now towards the far end
+                        let mut params = *self.synth_params.read();
+                        let min = desc.normalize_value(
+                            crate::plugin::param_update::param_get::get_param(&params, param_id),
+                        );
+                        let max = if min < 1.0 { 1.0 } else { 0.0 };
+                        if params.macros[*index].assign(param_id, min, max) {
+                            self.record_undo(None);
+                            self.load_params(params);
+                            self.last_param_text =
+                                format!("Macro {} → {}: {}", index + 1, desc.module, desc.name);
+                        } else {
+                            self.last_param_text = format!("Macro {} is full", index + 1);
+                        }
+                    }
+                    None => {
+                        self.last_param_text = "Move a parameter, then assign it".to_string();
+                    }
+                }
+                cx.needs_redraw();
+                meta.consume();
+            }
+            GuiMessage::ClearMacro(index) => {
+                let mut params = *self.synth_params.read();
+                self.record_undo(None);
+                params.macros[*index].clear();
+                self.load_params(params);
+                self.last_param_text = format!("Macro {} cleared", index + 1);
+                cx.needs_redraw();
+                meta.consume();
+            }
+            GuiMessage::CopyAToB => {
+                let current = *self.synth_params.read();
+                let reload = self.ab_compare.lock().copy_a_to_b(&current);
+                if let Some(params) = reload {
+                    self.record_undo(None);
+                    self.load_params(params);
+                    self.emit_all_param_syncs(cx);
+                }
+                self.last_param_text = "Copied A → B".to_string();
+                cx.needs_redraw();
+                meta.consume();
+            }
+            #[cfg(feature = "standalone")]
+            GuiMessage::SelectMidiPort(index) => {
+                if let Some(handler) = self.midi_handler.as_ref() {
+                    let result = handler.lock().connect_port(*index);
+                    self.last_param_text = match result {
+                        Ok(name) => format!("MIDI input: {}", name),
+                        Err(e) => format!("MIDI input failed: {}", e),
+                    };
+                }
+                self.sync_midi_ports();
+                cx.needs_redraw();
+                meta.consume();
+            }
+            #[cfg(feature = "standalone")]
+            GuiMessage::SelectAudioDevice(index) => {
+                if let Some(device) = self.audio_devices.get(*index).cloned() {
+                    let current = self.current_audio_settings();
+                    // Keep the rate if the new device can run it, else use its default
+                    let sample_rate = current.sample_rate.filter(|rate| {
+                        output::supported_sample_rates(Some(&device))
+                            .is_ok_and(|rates| rates.contains(rate))
+                    });
+                    self.apply_audio_settings(AudioSettings {
+                        device: Some(device),
+                        sample_rate,
+                        ..current
+                    });
+                }
+                cx.needs_redraw();
+                meta.consume();
+            }
+            #[cfg(feature = "standalone")]
+            GuiMessage::SelectSampleRate(index) => {
+                if let Some(&rate) = self.sample_rates.get(*index) {
+                    self.apply_audio_settings(AudioSettings {
+                        sample_rate: Some(rate),
+                        ..self.current_audio_settings()
+                    });
+                }
+                cx.needs_redraw();
+                meta.consume();
+            }
+            #[cfg(feature = "standalone")]
+            GuiMessage::SelectBufferSize(index) => {
+                // Entry 0 is the device default
+                let buffer_size = index
+                    .checked_sub(1)
+                    .and_then(|i| output::BUFFER_SIZE_CHOICES.get(i).copied());
+                self.apply_audio_settings(AudioSettings {
+                    buffer_size,
+                    ..self.current_audio_settings()
+                });
+                cx.needs_redraw();
+                meta.consume();
+            }
+            #[cfg(feature = "standalone")]
+            GuiMessage::ToggleRecording => {
+                self.toggle_recording();
+                cx.needs_redraw();
+                meta.consume();
+            }
+            #[cfg(feature = "standalone")]
+            GuiMessage::ExportPreset => {
+                if let Some(path) = rfd::FileDialog::new()
+                    .add_filter("DSynth preset", &["json"])
+                    .set_file_name("dsynth-preset.json")
+                    .save_file()
+                {
+                    self.save_preset_file(&path);
+                }
+                cx.needs_redraw();
+                meta.consume();
+            }
+            GuiMessage::CheckMonoCompatibility => {
+                self.check_mono_compatibility();
+                cx.needs_redraw();
+                meta.consume();
+            }
+            GuiMessage::PresetLoad(path) => {
+                self.load_preset_file(cx, path);
+                cx.needs_redraw();
+                meta.consume();
+            }
+            GuiMessage::PresetSave(path) => {
+                self.save_preset_file(path);
+                cx.needs_redraw();
+                meta.consume();
+            }
+            GuiMessage::SetGuiScale(index) => {
+                if let Some(&scale) = theme::GUI_SCALE_CHOICES.get(*index) {
+                    *self.gui_scale.lock() = scale;
+                    self.gui_scale_index = *index;
+                    // Scales the window along with every view in it
+                    cx.set_user_scale_factor(scale);
+                }
+                meta.consume();
+            }
+            GuiMessage::KeyboardStatus(status) => {
+                self.last_param_text = status.clone();
+                cx.needs_redraw();
+                meta.consume();
+            }
+            GuiMessage::Undo | GuiMessage::Redo => {
+                let current = *self.synth_params.read();
+                let restored = if matches!(msg, GuiMessage::Undo) {
+                    self.undo_history.undo(&current)
+                } else {
+                    self.undo_history.redo(&current)
+                };
+                if let Some(params) = restored {
+                    self.load_params(params);
+                    self.emit_all_param_syncs(cx);
+                    self.last_param_text = if matches!(msg, GuiMessage::Undo) {
+                        "Undo".to_string()
+                    } else {
+                        "Redo".to_string()
+                    };
+                }
+                cx.needs_redraw();
+                meta.consume();
+            }
+            _ => {}
+        });
+
+        // The engine publishes at MODULATION_FEED_HZ; polling on redraw picks that up
+        event.map(|window_event, meta| match window_event {
+            WindowEvent::Redraw => {
+                self.poll_modulation(cx);
+                self.poll_mono_report();
+
+                // Hot-plugged devices show up in the picker as the watcher finds them
+                #[cfg(feature = "standalone")]
+                self.sync_midi_ports();
+
+                #[cfg(feature = "standalone")]
+                self.refresh_performance();
+            }
+            // Files dropped anywhere on the window bubble up to here
+            WindowEvent::Drop(DropData::File(path)) => {
+                self.load_preset_file(cx, path);
+                cx.needs_redraw();
+                meta.consume();
+            }
             _ => {}
         });
     }
 }
 
 impl GuiState {
-    /// Apply randomized parameters to all synth parameters
+    /// Apply randomized parameters to every section that isn't locked
     fn apply_randomized_params(&mut self) {
-        use crate::randomize::randomize_synth_params;
+        use crate::randomize::randomize_synth_params_locked;
 
         // Generate randomized parameters
         let mut rng = rand::thread_rng();
-        let randomized = randomize_synth_params(&mut rng);
+        let current = *self.synth_params.read();
+        let randomized = randomize_synth_params_locked(&mut rng, &current, self.randomize_locks);
+
+        self.load_params(randomized);
+    }
 
-        // Write randomized params to shared state and audio thread
+    /// Replace the whole patch (randomize, undo/redo) and push it to the audio thread
+    ///
+    /// Knob visuals are not updated here; callers follow up with `emit_all_param_syncs`.
+    pub fn load_params(&mut self, new_params: SynthParams) {
+        // Write to shared state and audio thread
         let mut params = self.synth_params.write();
-        // Copy randomized values to the shared params
-        *params = randomized;
+        *params = new_params;
+
+        self.osc1_waveform = params.oscillators[0].waveform as i32;
+        self.osc2_waveform = params.oscillators[1].waveform as i32;
+        self.osc3_waveform = params.oscillators[2].waveform as i32;
 
         // For standalone: Write full SynthParams to the engine's triple-buffer
         if let Some(ref producer) = self.params_producer {
@@ -206,9 +771,336 @@ impl GuiState {
         producer.write(crate::plugin::gui_param_change::GuiParamChange {
             param_id: 0xFFFFFFFF,
             normalized: rand::random::<f32>(),
+            gesture: false,
         });
     }
 
+    /// Use externally owned A/B slots (the plugin keeps them in its saved state)
+    pub fn with_ab_compare(mut self, ab_compare: Arc<Mutex<AbCompare>>) -> Self {
+        self.ab_is_b = ab_compare.lock().active() == AbSlot::B;
+        self.ab_compare = ab_compare;
+        self
+    }
+
+    /// Share the GUI scale with the plugin (restored from the project state)
+    pub fn with_gui_scale(mut self, gui_scale: Arc<Mutex<f64>>) -> Self {
+        self.gui_scale_index = theme::nearest_gui_scale_index(*gui_scale.lock());
+        self.gui_scale = gui_scale;
+        self
+    }
+
+    /// Share the tab and scroll position with the plugin (restored from the project state)
+    pub fn with_gui_layout(mut self, gui_layout: Arc<Mutex<GuiLayout>>) -> Self {
+        let layout = *gui_layout.lock();
+        self.active_tab = layout.tab;
+        self.scroll_y = layout.scroll_y;
+        self.gui_layout = gui_layout;
+        self
+    }
+
+    /// Attach the MIDI handler that the device picker controls
+    #[cfg(feature = "standalone")]
+    pub fn with_midi_handler(mut self, midi_handler: Option<Arc<Mutex<MidiHandler>>>) -> Self {
+        self.midi_handler = midi_handler;
+        self.sync_midi_ports();
+        self
+    }
+
+    /// Mirror the handler's port list and connection into the picker
+    ///
+    /// Uses `try_lock` so a port rescan in progress never stalls the GUI.
+    #[cfg(feature = "standalone")]
+    fn sync_midi_ports(&mut self) {
+        let Some(handler) = self.midi_handler.as_ref() else {
+            return;
+        };
+        let Some(handler) = handler.try_lock() else {
+            return;
+        };
+        if handler.available_ports() != self.midi_ports.as_slice() {
+            self.midi_ports = handler.available_ports().to_vec();
+        }
+        let connected = handler
+            .connected_port()
+            .and_then(|name| self.midi_ports.iter().position(|p| p == name));
+        if let Some(index) = connected {
+            self.midi_port_index = index;
+        }
+    }
+
+    /// Attach the audio output that the device/rate pickers control
+    #[cfg(feature = "standalone")]
+    pub fn with_audio_control(mut self, audio_control: Option<AudioControl>) -> Self {
+        self.audio_control = audio_control;
+        self.buffer_size_labels = std::iter::once("Default".to_string())
+            .chain(
+                output::BUFFER_SIZE_CHOICES
+                    .iter()
+                    .map(|frames| format!("{} samples", frames)),
+            )
+            .collect();
+        self.sync_audio_settings();
+        self
+    }
+
+    /// Settings of the running audio stream (default if audio isn't running)
+    #[cfg(feature = "standalone")]
+    fn current_audio_settings(&self) -> AudioSettings {
+        self.audio_control
+            .as_ref()
+            .map(AudioControl::settings)
+            .unwrap_or_default()
+    }
+
+    /// Rebuild the audio stream with new settings and report the outcome
+    ///
+    /// On failure the output falls back to the default device, which the pickers then show.
+    #[cfg(feature = "standalone")]
+    fn apply_audio_settings(&mut self, settings: AudioSettings) {
+        let Some(control) = self.audio_control.as_ref() else {
+            self.last_param_text = "Audio output is not running".to_string();
+            return;
+        };
+        self.last_param_text = match control.apply(settings) {
+            Ok(running) => format!(
+                "Audio output: {} at {} Hz",
+                running.device.unwrap_or_default(),
+                running.sample_rate.unwrap_or_default()
+            ),
+            Err(e) => format!("Audio output failed, using default device: {}", e),
+        };
+        self.sync_audio_settings();
+    }
+
+    /// Start recording to a file the user picks, or finish the running recording
+    #[cfg(feature = "standalone")]
+    fn toggle_recording(&mut self) {
+        let Some(control) = self.audio_control.as_ref() else {
+            self.last_param_text = "Audio output is not running".to_string();
+            return;
+        };
+        let recorder = control.recorder();
+        let sample_rate = control.settings().sample_rate.unwrap_or(44100);
+
+        if recorder.is_recording() {
+            self.last_param_text = match recorder.stop() {
+                Ok(Some(summary)) => {
+                    let mut text = format!(
+                        "Recorded {:.1} s to {}",
+                        summary.frames as f64 / sample_rate as f64,
+                        summary.path.display()
+                    );
+                    if summary.dropped_frames > 0 {
+                        text.push_str(&format!(" ({} frames dropped)", summary.dropped_frames));
+                    }
+                    text
+                }
+                Ok(None) => String::new(),
+                Err(e) => format!("Recording failed: {}", e),
+            };
+        } else if let Some(path) = rfd::FileDialog::new()
+            .add_filter("WAV audio", &["wav"])
+            .set_file_name("dsynth-recording.wav")
+            .save_file()
+        {
+            self.last_param_text = match recorder.start(&path, sample_rate) {
+                Ok(()) => format!("Recording to {}", path.display()),
+                Err(e) => format!("Recording failed: {}", e),
+            };
+        }
+        self.recording = recorder.is_recording();
+    }
+
+    /// Analyse the last second of output on a worker thread (`poll_mono_report` shows it)
+    fn check_mono_compatibility(&mut self) {
+        if self.pending_mono_report.is_some() {
+            return;
+        }
+        let Some(history) = self.output_history.clone() else {
+            self.mono_report_text = "No engine output to check".to_string();
+            return;
+        };
+        // The flagged width settings are read from the patch as it is now
+        let params = *self.synth_params.read();
+        let (sender, receiver) = crossbeam_channel::bounded(1);
+        let spawned = std::thread::Builder::new()
+            .name("mono-check".to_string())
+            .spawn(move || {
+                let _ = sender.send(SynthEngine::mono_compatibility_report(&history, &params));
+            });
+        self.mono_report_text = match spawned {
+            Ok(_) => {
+                self.pending_mono_report = Some(receiver);
+                "Checking mono compatibility…".to_string()
+            }
+            Err(e) => format!("Mono check failed: {}", e),
+        };
+    }
+
+    /// Show the mono compatibility result once the worker thread has sent it
+    fn poll_mono_report(&mut self) {
+        let Some(receiver) = self.pending_mono_report.as_ref() else {
+            return;
+        };
+        self.mono_report_text = match receiver.try_recv() {
+            Ok(Some(report)) => report.summary(),
+            Ok(None) => "Nothing played yet: play a few notes, then check".to_string(),
+            Err(TryRecvError::Empty) => return,
+            Err(TryRecvError::Disconnected) => "Mono check failed".to_string(),
+        };
+        self.pending_mono_report = None;
+    }
+
+    /// Load a preset file into the patch as one undo step
+    ///
+    /// Goes through `PresetManager::import_preset`, so older preset versions are migrated
+    /// and anything that isn't a DSynth preset is rejected with the reason in the status text.
+    fn load_preset_file(&mut self, cx: &mut EventContext, path: &Path) {
+        let file_name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        match PresetManager::import_preset(path) {
+            Ok(state) => {
+                self.record_undo(None);
+                self.load_params(*state.params());
+                self.emit_all_param_syncs(cx);
+                self.last_param_text = format!(
+                    "Loaded preset {}",
+                    state.preset_name().unwrap_or(&file_name)
+                );
+            }
+            Err(e) => {
+                self.last_param_text = format!("Can't load {}: {}", file_name, e);
+            }
+        }
+    }
+
+    /// Save the current patch as a preset named after the file
+    fn save_preset_file(&mut self, path: &Path) {
+        let name = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned());
+        let state = PluginState::from_params(*self.synth_params.read(), name);
+        let result = match path.to_str() {
+            Some(path_str) => PresetManager::save_preset(&state, path_str),
+            None => Err(crate::plugin::state::StateError::InvalidData(
+                "Preset path is not valid UTF-8".to_string(),
+            )),
+        };
+        self.last_param_text = match result {
+            Ok(()) => format!("Saved preset to {}", path.display()),
+            Err(e) => format!("Can't save preset: {}", e),
+        };
+    }
+
+    /// Update the voice/CPU readout, at most every `PERFORMANCE_REFRESH_INTERVAL`
+    #[cfg(feature = "standalone")]
+    fn refresh_performance(&mut self) {
+        if self.performance_refreshed_at.elapsed() < PERFORMANCE_REFRESH_INTERVAL {
+            return;
+        }
+        self.performance_refreshed_at = Instant::now();
+
+        let Some(control) = self.audio_control.as_ref() else {
+            return;
+        };
+        let stats = control.stats();
+        self.performance_text = format!(
+            "Voices {}/{}  CPU {:.0}%",
+            stats.active_voices(),
+            MAX_POLYPHONY,
+            stats.cpu_load() * 100.0
+        );
+    }
+
+    /// Mirror the running device, rate and buffer size into the pickers
+    #[cfg(feature = "standalone")]
+    fn sync_audio_settings(&mut self) {
+        let settings = self.current_audio_settings();
+        // Changing the stream finishes any recording
+        self.recording = self
+            .audio_control
+            .as_ref()
+            .is_some_and(|control| control.recorder().is_recording());
+
+        self.audio_devices = output::list_output_devices().unwrap_or_default();
+        if let Some(device) = settings.device.as_ref() {
+            if !self.audio_devices.contains(device) {
+                self.audio_devices.push(device.clone());
+            }
+        }
+        self.audio_device_index = settings
+            .device
+            .as_ref()
+            .and_then(|device| self.audio_devices.iter().position(|d| d == device))
+            .unwrap_or(0);
+
+        self.sample_rates =
+            output::supported_sample_rates(settings.device.as_deref()).unwrap_or_default();
+        if let Some(rate) = settings.sample_rate {
+            if !self.sample_rates.contains(&rate) {
+                self.sample_rates.push(rate);
+                self.sample_rates.sort_unstable();
+            }
+        }
+        self.sample_rate_labels = self
+            .sample_rates
+            .iter()
+            .map(|rate| format!("{} Hz", rate))
+            .collect();
+        self.sample_rate_index = settings
+            .sample_rate
+            .and_then(|rate| self.sample_rates.iter().position(|&r| r == rate))
+            .unwrap_or(0);
+
+        self.buffer_size_index = settings
+            .buffer_size
+            .and_then(|frames| {
+                output::BUFFER_SIZE_CHOICES
+                    .iter()
+                    .position(|&f| f == frames)
+            })
+            .map_or(0, |i| i + 1);
+    }
+
+    /// Snapshot the current patch into the undo history before a change
+    fn record_undo(&mut self, param_id: Option<u32>) {
+        let before = *self.synth_params.read();
+        self.undo_history
+            .record(param_id, &before, std::time::Instant::now());
+    }
+
+    /// Remember the parameter for macro assignment, or, if it's a macro, move the knobs
+    /// of the targets it just wrote
+    fn sync_macro_targets(&mut self, cx: &mut EventContext, param_id: u32) {
+        use crate::plugin::param_descriptor::{is_macro_param, PARAM_MACROS};
+        use vizia::prelude::Propagation;
+
+        if !is_macro_param(param_id) {
+            self.last_touched_param = Some(param_id);
+            return;
+        }
+        let Some(index) = PARAM_MACROS.iter().position(|&id| id == param_id) else {
+            return;
+        };
+
+        let registry = crate::plugin::param_registry::get_registry();
+        let params = *self.synth_params.read();
+        for (param_id, _) in params.macros[index].targets() {
+            if let Some(desc) = registry.get(param_id) {
+                let value = crate::plugin::param_update::param_get::get_param(&params, param_id);
+                cx.emit_custom(
+                    Event::new(GuiMessage::SyncKnobValue(
+                        param_id,
+                        desc.normalize_value(value),
+                    ))
+                    .propagate(Propagation::Subtree),
+                );
+            }
+        }
+    }
+
     /// Emit SyncKnobValue messages for all parameters to update UI visuals
     fn emit_all_param_syncs(&self, cx: &mut EventContext) {
         use crate::plugin::param_registry::get_registry;
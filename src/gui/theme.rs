@@ -6,6 +6,28 @@ use vizia::prelude::*;
 pub const WINDOW_WIDTH: u32 = 1200;
 pub const WINDOW_HEIGHT: u32 = 800;
 
+// ========== GUI Scale ==========
+/// Scale factors offered in the scale picker (window size and all content scale together)
+pub const GUI_SCALE_CHOICES: [f64; 6] = [0.75, 1.0, 1.25, 1.5, 1.75, 2.0];
+pub const DEFAULT_GUI_SCALE: f64 = 1.0;
+
+/// Window size at a GUI scale
+pub fn scaled_window_size(scale: f64) -> (u32, u32) {
+    (
+        (WINDOW_WIDTH as f64 * scale).round() as u32,
+        (WINDOW_HEIGHT as f64 * scale).round() as u32,
+    )
+}
+
+/// Index of the offered scale closest to `scale` (restoring saved or out-of-range values)
+pub fn nearest_gui_scale_index(scale: f64) -> usize {
+    GUI_SCALE_CHOICES
+        .iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| (*a - scale).abs().total_cmp(&(*b - scale).abs()))
+        .map_or(1, |(index, _)| index)
+}
+
 // ========== Layout Constants ==========
 pub const OSC_COL_WIDTH: f32 = 360.0;
 pub const ROW_GAP: f32 = 12.0;
@@ -41,6 +63,8 @@ pub const WIDGET_BG: Color = Color::rgb(55, 55, 62);
 pub const WIDGET_BORDER: Color = Color::rgb(90, 90, 100);
 pub const WIDGET_ACCENT: Color = Color::rgb(200, 200, 210);
 pub const WIDGET_TRACK: Color = Color::rgb(120, 120, 130);
+pub const MODULATION_ACCENT: Color = Color::rgb(90, 170, 230);
+pub const RECORD_ACCENT: Color = Color::rgb(220, 70, 70);
 
 // Active states
 pub const ACTIVE_BG: Color = Color::rgb(60, 60, 70);
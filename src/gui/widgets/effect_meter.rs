@@ -0,0 +1,103 @@
+use crate::audio::engine::{EffectLevels, EffectSlot};
+use vizia::prelude::*;
+
+/// Bottom of the level scale in dB (the bar is empty below this)
+const METER_FLOOR_DB: f32 = -60.0;
+
+/// Gain reduction that fills the compressor's reduction bar
+const MAX_REDUCTION_DB: f32 = 24.0;
+
+/// Map a linear peak onto the 0-1 bar length (dB scale)
+fn level_fraction(peak: f32) -> f32 {
+    if peak <= 0.0 {
+        return 0.0;
+    }
+    let db = 20.0 * peak.log10();
+    ((db - METER_FLOOR_DB) / -METER_FLOOR_DB).clamp(0.0, 1.0)
+}
+
+/// Small horizontal output meter for one effect section
+///
+/// Fed by `GuiMessage::SyncEffectLevels` from the engine's GUI feed. The compressor's meter
+/// also draws its gain reduction as a bar growing from the right.
+pub struct EffectMeter {
+    slot: EffectSlot,
+    level: f32,
+    reduction_db: f32,
+}
+
+impl EffectMeter {
+    pub fn new(cx: &mut Context, slot: EffectSlot) -> Handle<'_, Self> {
+        Self {
+            slot,
+            level: 0.0,
+            reduction_db: 0.0,
+        }
+        .build(cx, |_cx| {})
+        .width(Pixels(60.0))
+        .height(Pixels(6.0))
+        .top(Stretch(1.0))
+        .bottom(Stretch(1.0))
+    }
+}
+
+impl View for EffectMeter {
+    fn element(&self) -> Option<&'static str> {
+        Some("effect-meter")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|gui_msg: &crate::gui::GuiMessage, _meta| {
+            if let crate::gui::GuiMessage::SyncEffectLevels(levels) = gui_msg {
+                let level = level_fraction(levels.peak(self.slot));
+                let reduction_db = if self.slot == EffectSlot::Compressor {
+                    levels.compressor_reduction_db
+                } else {
+                    0.0
+                };
+                if level != self.level || reduction_db != self.reduction_db {
+                    self.level = level;
+                    self.reduction_db = reduction_db;
+                    cx.needs_redraw();
+                }
+            }
+        });
+    }
+
+    fn draw(&self, cx: &mut DrawContext, canvas: &Canvas) {
+        use vizia::vg::{Paint, Path, Rect};
+
+        let bounds = cx.bounds();
+        let draw_bar = |x: f32, width: f32, color: vizia::vg::Color| {
+            let mut path = Path::new();
+            path.add_rect(Rect::from_xywh(x, bounds.y, width, bounds.height()), None);
+            let mut paint = Paint::default();
+            paint.set_color(color);
+            paint.set_anti_alias(true);
+            canvas.draw_path(&path, &paint);
+        };
+
+        draw_bar(
+            bounds.x,
+            bounds.width(),
+            vizia::vg::Color::from_rgb(30, 30, 35),
+        );
+        if self.level > 0.0 {
+            // Green up to -6 dB, amber above
+            let color = if self.level > 0.9 {
+                vizia::vg::Color::from_rgb(230, 170, 60)
+            } else {
+                vizia::vg::Color::from_rgb(90, 200, 120)
+            };
+            draw_bar(bounds.x, bounds.width() * self.level, color);
+        }
+        if self.reduction_db > 0.0 {
+            let width = bounds.width() * (self.reduction_db / MAX_REDUCTION_DB).min(1.0);
+            draw_bar(
+                bounds.x + bounds.width() - width,
+                width,
+                vizia::vg::Color::from_rgb(220, 80, 80),
+            );
+        }
+    }
+}
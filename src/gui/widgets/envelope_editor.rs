@@ -1,9 +1,12 @@
+use crate::gui::messages::EnvelopeTarget;
 use crate::gui::GuiState;
 use crate::plugin::{param_registry, param_update::param_get};
 use vizia::prelude::*;
 
 /// Visual ADSR envelope editor with draggable control points
 ///
+/// Edits the amp envelope or any filter envelope, chosen by `EnvelopeTarget`; the drag
+/// logic is the same and only the parameter IDs it writes change.
 /// Reads parameter values from shared SynthParams for consistency with knobs/automation.
 /// Dragging uses delta-from-start to avoid cursor drift and handle reset.
 #[derive(Lens)]
@@ -13,9 +16,8 @@ pub struct EnvelopeEditor {
     decay_param_id: u32,
     sustain_param_id: u32,
     release_param_id: u32,
-    attack_curve_param_id: u32,
-    decay_curve_param_id: u32,
-    release_curve_param_id: u32,
+    #[lens(ignore)]
+    curve_param_ids: Option<[u32; 3]>,
 
     // Interaction state
     #[lens(ignore)]
@@ -44,32 +46,15 @@ pub(crate) enum DraggedHandle {
 }
 
 impl EnvelopeEditor {
-    #[allow(clippy::too_many_arguments)] // UI builder; params map directly to UI controls.
-    pub fn new(
-        cx: &mut Context,
-        _attack_value: f32,
-        _decay_value: f32,
-        _sustain_value: f32,
-        _release_value: f32,
-        _attack_curve_value: f32,
-        _decay_curve_value: f32,
-        _release_curve_value: f32,
-        attack_param_id: u32,
-        decay_param_id: u32,
-        sustain_param_id: u32,
-        release_param_id: u32,
-        attack_curve_param_id: u32,
-        decay_curve_param_id: u32,
-        release_curve_param_id: u32,
-    ) -> Handle<'_, Self> {
+    pub fn new(cx: &mut Context, target: EnvelopeTarget) -> Handle<'_, Self> {
+        let [attack_param_id, decay_param_id, sustain_param_id, release_param_id] =
+            target.adsr_param_ids();
         Self {
             attack_param_id,
             decay_param_id,
             sustain_param_id,
             release_param_id,
-            attack_curve_param_id,
-            decay_curve_param_id,
-            release_curve_param_id,
+            curve_param_ids: target.curve_param_ids(),
             drag_state: None,
             hovered_handle: None,
             drag_start_mouse: (0.0, 0.0),
@@ -111,7 +96,7 @@ impl EnvelopeEditor {
         if curve.abs() < 0.01 {
             progress
         } else {
-            progress.powf(1.0 - curve * 0.67)
+            progress.powf(1.0 + curve * 0.67)
         }
     }
 
@@ -266,15 +251,16 @@ impl View for EnvelopeEditor {
         // Redraw when parameters change elsewhere
         event.map(|gui_msg: &crate::gui::GuiMessage, _meta| {
             if let crate::gui::GuiMessage::ParamChanged(param_id, _)
+            | crate::gui::GuiMessage::ParamReset(param_id, _)
             | crate::gui::GuiMessage::SyncKnobValue(param_id, _) = gui_msg
             {
                 if *param_id == self.attack_param_id
                     || *param_id == self.decay_param_id
                     || *param_id == self.sustain_param_id
                     || *param_id == self.release_param_id
-                    || *param_id == self.attack_curve_param_id
-                    || *param_id == self.decay_curve_param_id
-                    || *param_id == self.release_curve_param_id
+                    || self
+                        .curve_param_ids
+                        .is_some_and(|curves| curves.contains(param_id))
                 {
                     cx.needs_redraw();
                 }
@@ -347,13 +333,11 @@ impl View for EnvelopeEditor {
         let sustain = Self::get_normalized_param(cx, self.sustain_param_id);
         let release = Self::get_normalized_param(cx, self.release_param_id);
 
-        let attack_curve = (Self::get_normalized_param(cx, self.attack_curve_param_id) * 2.0 - 1.0)
-            .clamp(-1.0, 1.0);
-        let decay_curve = (Self::get_normalized_param(cx, self.decay_curve_param_id) * 2.0 - 1.0)
-            .clamp(-1.0, 1.0);
-        let release_curve = (Self::get_normalized_param(cx, self.release_curve_param_id) * 2.0
-            - 1.0)
-            .clamp(-1.0, 1.0);
+        // Curves are stored normalized (0.5 = linear); map to -1.0..1.0
+        let [attack_curve, decay_curve, release_curve] =
+            self.curve_param_ids.map_or([0.0; 3], |ids| {
+                ids.map(|id| (Self::get_normalized_param(cx, id) * 2.0 - 1.0).clamp(-1.0, 1.0))
+            });
 
         self.draw_envelope_curve(
             canvas,
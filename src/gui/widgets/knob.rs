@@ -1,10 +1,55 @@
 use crate::gui::theme;
 use vizia::prelude::*;
 
+/// Normalized change per pixel of vertical drag
+const DRAG_SENSITIVITY: f32 = 1.0 / 200.0;
+
+/// Normalized change per pixel while Shift is held (10x finer)
+const FINE_DRAG_SENSITIVITY: f32 = DRAG_SENSITIVITY / 10.0;
+
+/// Hover tooltip with a parameter's name, live value, default and range
+///
+/// Only parameters in the synth's registry get one (the kick plugin's knobs have none).
+pub trait ParamTooltip {
+    fn param_tooltip<L>(self, param_id: u32, normalized: L) -> Self
+    where
+        L: Lens<Target = f32>;
+}
+
+impl<V: View> ParamTooltip for Handle<'_, V> {
+    fn param_tooltip<L>(self, param_id: u32, normalized: L) -> Self
+    where
+        L: Lens<Target = f32>,
+    {
+        let Some(desc) = crate::plugin::param_registry::get_registry().get(param_id) else {
+            return self;
+        };
+        let desc = desc.clone();
+        self.tooltip(move |cx| {
+            let desc = desc.clone();
+            Tooltip::new(cx, move |cx| {
+                Label::new(cx, normalized.map(move |value| desc.tooltip_text(*value)))
+                    .font_size(11.0);
+            })
+        })
+    }
+}
+
+/// Internal knob events
+#[derive(Debug, Clone)]
+enum KnobEvent {
+    /// Text entry finished (submitted with Enter or focus lost)
+    SubmitText(String),
+}
+
 /// A simple reactive rotary knob widget with optional label
 ///
 /// Visual feedback via CSS rotation of an indicator line.
-/// Drag vertically to change value, double-click to reset.
+/// Drag vertically to change value (hold Shift for fine adjustment), right-click to reset
+/// to the default, double-click to type an exact value ("1k", "-6 dB", "250ms").
+///
+/// The knob works in normalized space; the parameter's taper (linear, log, exp) is applied
+/// by `ParamDescriptor::denormalize`, so log parameters like cutoff sweep evenly per octave.
 #[derive(Lens)]
 pub struct Knob {
     /// Current normalized value (0.0 to 1.0)
@@ -25,6 +70,21 @@ pub struct Knob {
     /// Value at start of drag
     drag_start_value: f32,
 
+    /// Was Shift held on the last drag update?
+    fine_drag: bool,
+
+    /// Is the type-in text box open?
+    editing: bool,
+
+    /// Text being typed into the value box
+    edit_text: String,
+
+    /// Effective (post-modulation) normalized value reported by the engine
+    modulated_value: f32,
+
+    /// Is the parameter currently being modulated on a sounding voice?
+    is_modulated: bool,
+
     /// Optional label
     label: Option<String>,
 }
@@ -43,6 +103,11 @@ impl Knob {
             is_dragging: false,
             drag_start_y: 0.0,
             drag_start_value: 0.0,
+            fine_drag: false,
+            editing: false,
+            edit_text: String::new(),
+            modulated_value: 0.0,
+            is_modulated: false,
             label: None,
         }
         .build(cx, |cx| {
@@ -70,12 +135,54 @@ impl Knob {
                     .top(Pixels(theme::KNOB_SIZE / 2.0 - 20.0))
                     .translate((Pixels(0.0), Pixels(10.0)))
                     .rotate(Knob::normalized_value.map(|v| Angle::Deg(v * 270.0 - 135.0)));
+
+                // Modulation ring marker: a dot on the knob rim at the effective value,
+                // shown only while the engine reports this parameter as modulated. The
+                // knob-sized container rotates about the knob center, carrying the dot.
+                ZStack::new(cx, |cx| {
+                    Element::new(cx)
+                        .class("knob-modulation")
+                        .width(Pixels(6.0))
+                        .height(Pixels(6.0))
+                        .background_color(theme::MODULATION_ACCENT)
+                        .corner_radius(Percentage(50.0))
+                        .left(Pixels(theme::KNOB_SIZE / 2.0 - 3.0))
+                        .top(Pixels(0.0));
+                })
+                .width(Pixels(theme::KNOB_SIZE))
+                .height(Pixels(theme::KNOB_SIZE))
+                .rotate(Knob::modulated_value.map(|v| Angle::Deg(v * 270.0 - 135.0)))
+                .visibility(Knob::is_modulated);
+
+                // Type-in value box, shown over the knob while editing
+                Binding::new(cx, Knob::editing, |cx, editing| {
+                    if !editing.get(cx) {
+                        return;
+                    }
+
+                    Textbox::new(cx, Knob::edit_text)
+                        .on_submit(|cx, text, _enter_pressed| {
+                            cx.emit(KnobEvent::SubmitText(text));
+                        })
+                        .on_build(|cx| cx.emit(TextEvent::StartEdit))
+                        .width(Pixels(theme::KNOB_CELL_WIDTH))
+                        .height(Pixels(20.0))
+                        .left(Pixels((theme::KNOB_SIZE - theme::KNOB_CELL_WIDTH) / 2.0))
+                        .top(Pixels(theme::KNOB_SIZE / 2.0 - 10.0))
+                        .font_size(11.0)
+                        .color(theme::TEXT_BRIGHT)
+                        .background_color(theme::WIDGET_BG)
+                        .border_width(Pixels(1.0))
+                        .border_color(theme::WIDGET_ACCENT)
+                        .corner_radius(Pixels(3.0));
+                });
             })
             .width(Pixels(theme::KNOB_SIZE))
             .height(Pixels(theme::KNOB_SIZE));
         })
         .width(Pixels(theme::KNOB_SIZE))
         .height(Pixels(theme::KNOB_SIZE))
+        .param_tooltip(param_id, Knob::normalized_value)
     }
 
     /// Builder method to add a label above the knob
@@ -104,13 +211,39 @@ impl View for Knob {
                     self.normalized_value = normalized.clamp(0.0, 1.0);
                 }
             }
+            if let crate::gui::GuiMessage::SyncModulation(param_id, modulated) = gui_msg {
+                if *param_id == self.param_id {
+                    self.is_modulated = modulated.is_some();
+                    if let Some(value) = modulated {
+                        self.modulated_value = value.clamp(0.0, 1.0);
+                    }
+                }
+            }
+        });
+
+        // Typed values are parsed by the owning GUI model, which knows the parameter's
+        // descriptor, and come back to this knob as SyncKnobValue
+        event.map(|knob_event, meta| match knob_event {
+            KnobEvent::SubmitText(text) => {
+                if self.editing {
+                    self.editing = false;
+                    if !text.trim().is_empty() {
+                        cx.emit(crate::gui::GuiMessage::ParamTextEntered(
+                            self.param_id,
+                            text.clone(),
+                        ));
+                    }
+                }
+                meta.consume();
+            }
         });
 
         event.map(|window_event, meta| match window_event {
-            WindowEvent::MouseDown(MouseButton::Left) => {
+            WindowEvent::MouseDown(MouseButton::Left) if !self.editing => {
                 self.is_dragging = true;
                 self.drag_start_y = cx.mouse().cursor_y;
                 self.drag_start_value = self.normalized_value;
+                self.fine_drag = cx.modifiers().shift();
                 cx.capture();
                 cx.set_active(true);
                 meta.consume();
@@ -118,8 +251,21 @@ impl View for Knob {
 
             WindowEvent::MouseMove(_x, y) => {
                 if self.is_dragging {
+                    // Re-anchor when Shift is pressed/released mid-drag so the value doesn't jump
+                    let fine = cx.modifiers().shift();
+                    if fine != self.fine_drag {
+                        self.fine_drag = fine;
+                        self.drag_start_y = *y;
+                        self.drag_start_value = self.normalized_value;
+                    }
+
+                    let sensitivity = if fine {
+                        FINE_DRAG_SENSITIVITY
+                    } else {
+                        DRAG_SENSITIVITY
+                    };
                     let delta_y = *y - self.drag_start_y;
-                    self.update_from_drag(delta_y, 1.0 / 200.0);
+                    self.update_from_drag(delta_y, sensitivity);
 
                     cx.emit(crate::gui::GuiMessage::ParamChanged(
                         self.param_id,
@@ -139,15 +285,24 @@ impl View for Knob {
                 }
             }
 
-            WindowEvent::MouseDoubleClick(MouseButton::Left) => {
+            WindowEvent::MouseDown(MouseButton::Right) => {
                 self.normalized_value = self.default_value;
-                cx.emit(crate::gui::GuiMessage::ParamChanged(
+                cx.emit(crate::gui::GuiMessage::ParamReset(
                     self.param_id,
                     self.normalized_value,
                 ));
                 meta.consume();
             }
 
+            WindowEvent::MouseDoubleClick(MouseButton::Left) => {
+                self.is_dragging = false;
+                cx.release();
+                cx.set_active(false);
+                self.edit_text.clear();
+                self.editing = true;
+                meta.consume();
+            }
+
             _ => {}
         });
     }
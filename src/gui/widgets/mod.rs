@@ -2,29 +2,43 @@ pub mod knob;
 pub use knob::{param_knob, Knob};
 
 // The remaining widgets are only used by the main poly synth UI.
+#[cfg(any(feature = "clap", feature = "standalone"))]
+pub mod effect_meter;
+
 #[cfg(any(feature = "clap", feature = "standalone"))]
 pub mod envelope_editor;
 
 #[cfg(any(feature = "clap", feature = "standalone"))]
 pub mod vslider;
 
+#[cfg(any(feature = "clap", feature = "standalone"))]
+pub mod note_display;
+
 #[cfg(any(feature = "clap", feature = "standalone"))]
 pub mod param_checkbox;
 
 #[cfg(any(feature = "clap", feature = "standalone"))]
 pub mod param_cycle_button;
 
+#[cfg(any(feature = "clap", feature = "standalone"))]
+pub use effect_meter::EffectMeter;
+
 #[cfg(any(feature = "clap", feature = "standalone"))]
 pub use envelope_editor::EnvelopeEditor;
 
 #[cfg(any(feature = "clap", feature = "standalone"))]
 pub use vslider::{param_vslider, VSlider};
 
+#[cfg(any(feature = "clap", feature = "standalone"))]
+pub use note_display::NoteDisplay;
+
 #[cfg(any(feature = "clap", feature = "standalone"))]
 pub use param_checkbox::param_checkbox;
 
 #[cfg(any(feature = "clap", feature = "standalone"))]
 pub use param_cycle_button::{
-    distortion_type_button, filter_type_button, fm_source_button, lfo_waveform_button,
-    oscillator_waveform_button, tempo_sync_button,
+    distortion_type_button, effect_routing_button, feedback_curve_button, filter_routing_button,
+    filter_topology_button, filter_type_button, fm_source_button, lfo_waveform_button,
+    oscillator_waveform_button, quality_button, reverb_algorithm_button, tempo_sync_button,
+    vowel_button,
 };
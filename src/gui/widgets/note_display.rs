@@ -0,0 +1,101 @@
+use crate::audio::engine::HeldNotes;
+use vizia::prelude::*;
+
+/// Note names within an octave (sharps only)
+const NOTE_NAMES: [&str; 12] = [
+    "C", "C#", "D", "D#", "E", "F", "F#", "G", "G#", "A", "A#", "B",
+];
+
+/// Number of white keys in the MIDI range 0-127
+const WHITE_KEY_COUNT: usize = 75;
+
+/// Note name with octave, middle C (60) = "C4"
+pub fn note_name(note: u8) -> String {
+    format!("{}{}", NOTE_NAMES[note as usize % 12], note as i32 / 12 - 1)
+}
+
+fn is_black_key(note: u8) -> bool {
+    matches!(note % 12, 1 | 3 | 6 | 8 | 10)
+}
+
+/// Mini keyboard across the full MIDI range, highlighting the notes the engine holds
+///
+/// Fed by `GuiMessage::SyncHeldNotes` from the engine's GUI feed, so a key that stays lit
+/// after it was let go is a stuck note.
+pub struct NoteDisplay {
+    held: HeldNotes,
+}
+
+impl NoteDisplay {
+    pub fn new(cx: &mut Context) -> Handle<'_, Self> {
+        Self {
+            held: HeldNotes::default(),
+        }
+        .build(cx, |_cx| {})
+    }
+}
+
+impl View for NoteDisplay {
+    fn element(&self) -> Option<&'static str> {
+        Some("note-display")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|gui_msg: &crate::gui::GuiMessage, _meta| {
+            if let crate::gui::GuiMessage::SyncHeldNotes(held) = gui_msg {
+                if *held != self.held {
+                    self.held = *held;
+                    cx.needs_redraw();
+                }
+            }
+        });
+    }
+
+    fn draw(&self, cx: &mut DrawContext, canvas: &Canvas) {
+        use vizia::vg::{Paint, Path, Rect};
+
+        let bounds = cx.bounds();
+        let key_width = bounds.width() / WHITE_KEY_COUNT as f32;
+
+        let draw_key = |x: f32, width: f32, height: f32, color: vizia::vg::Color| {
+            let mut path = Path::new();
+            path.add_rect(Rect::from_xywh(x, bounds.y, width, height), None);
+            let mut paint = Paint::default();
+            paint.set_color(color);
+            paint.set_anti_alias(true);
+            canvas.draw_path(&path, &paint);
+        };
+        let held_color = vizia::vg::Color::from_rgb(90, 170, 230);
+
+        // White keys first, then the black keys on top straddling the gaps
+        let mut white_index = 0;
+        for note in 0..128u8 {
+            if is_black_key(note) {
+                continue;
+            }
+            let color = if self.held.contains(note) {
+                held_color
+            } else {
+                vizia::vg::Color::from_rgb(200, 200, 210)
+            };
+            let x = bounds.x + white_index as f32 * key_width;
+            draw_key(x + 0.5, key_width - 1.0, bounds.height(), color);
+            white_index += 1;
+        }
+
+        let mut white_index = 0;
+        for note in 0..128u8 {
+            if !is_black_key(note) {
+                white_index += 1;
+                continue;
+            }
+            let color = if self.held.contains(note) {
+                held_color
+            } else {
+                vizia::vg::Color::from_rgb(30, 30, 35)
+            };
+            let x = bounds.x + white_index as f32 * key_width - key_width * 0.3;
+            draw_key(x, key_width * 0.6, bounds.height() * 0.6, color);
+        }
+    }
+}
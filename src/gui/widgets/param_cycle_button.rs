@@ -314,7 +314,7 @@ pub fn param_cycle_button(
     .gap(Pixels(4.0));
 }
 
-// Helper function for oscillator waveforms (8 options in registry)
+// Helper function for oscillator waveforms (11 options in registry)
 pub fn oscillator_waveform_button(cx: &mut Context, param_id: u32, _osc_index: usize) {
     const OPTIONS: &[&str] = &[
         "Sine",
@@ -326,6 +326,9 @@ pub fn oscillator_waveform_button(cx: &mut Context, param_id: u32, _osc_index: u
         "Pink",
         "Add",
         "Wavetable",
+        "A.Saw",
+        "A.Square",
+        "Pluck",
     ];
     param_enum_popup_button(cx, param_id, "Waveform", OPTIONS);
 }
@@ -364,6 +367,48 @@ pub fn distortion_type_button(cx: &mut Context, param_id: u32) {
     param_enum_popup_button(cx, param_id, "Type", OPTIONS);
 }
 
+// Helper function for reverb algorithms (order from ReverbAlgorithm enum)
+pub fn reverb_algorithm_button(cx: &mut Context, param_id: u32) {
+    const OPTIONS: &[&str] = &["Room", "Hall", "Plate"];
+    param_enum_popup_button(cx, param_id, "Algo", OPTIONS);
+}
+
+// Helper function for formant filter vowels (order from Vowel enum)
+pub fn vowel_button(cx: &mut Context, param_id: u32) {
+    const OPTIONS: &[&str] = &["A", "E", "I", "O", "U"];
+    param_enum_popup_button(cx, param_id, "Vowel", OPTIONS);
+}
+
+// Helper function for global quality (order from Quality enum)
+pub fn quality_button(cx: &mut Context, param_id: u32) {
+    const OPTIONS: &[&str] = &["Draft", "Normal", "High"];
+    param_enum_popup_button(cx, param_id, "Quality", OPTIONS);
+}
+
+// Helper function for filter topology (order from FilterTopology enum)
+pub fn filter_topology_button(cx: &mut Context, param_id: u32) {
+    const OPTIONS: &[&str] = &["Parallel", "Serial"];
+    param_enum_popup_button(cx, param_id, "Chain", OPTIONS);
+}
+
+// Helper function for oscillator filter routing (order from FilterRouting enum)
+pub fn filter_routing_button(cx: &mut Context, param_id: u32) {
+    const OPTIONS: &[&str] = &["Paired", "Filter 1", "Filter 2", "Filter 3", "Bypass"];
+    param_enum_popup_button(cx, param_id, "Flt", OPTIONS);
+}
+
+// Helper function for delay feedback curves (order from FeedbackCurve enum)
+pub fn feedback_curve_button(cx: &mut Context, param_id: u32) {
+    const OPTIONS: &[&str] = &["Linear", "Perceptual"];
+    param_enum_popup_button(cx, param_id, "Curve", OPTIONS);
+}
+
+// Helper function for effect series/send routing (order from EffectRouting enum)
+pub fn effect_routing_button(cx: &mut Context, param_id: u32) {
+    const OPTIONS: &[&str] = &["Series", "Send"];
+    param_enum_popup_button(cx, param_id, "Route", OPTIONS);
+}
+
 // Helper function for tempo sync modes (order from TempoSync enum)
 pub fn tempo_sync_button(cx: &mut Context, param_id: u32) {
     const OPTIONS: &[&str] = &[
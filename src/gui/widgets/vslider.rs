@@ -1,4 +1,5 @@
 use crate::gui::theme;
+use crate::gui::widgets::knob::ParamTooltip;
 use vizia::prelude::*;
 
 /// A simple reactive vertical slider widget.
@@ -74,6 +75,7 @@ impl VSlider {
         .width(Pixels(theme::SLIDER_WIDTH))
         .height(Pixels(theme::SLIDER_HEIGHT))
         .cursor(CursorIcon::Hand)
+        .param_tooltip(param_id, VSlider::normalized_value)
     }
 
     fn update_from_y(&mut self, bounds: BoundingBox, y: f32) {
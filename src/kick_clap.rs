@@ -548,6 +548,8 @@ impl PluginParams for DsynthKickParams {
             is_automatable: desc.automation
                 == crate::plugin::param_descriptor::AutomationState::ReadWrite,
             is_hidden: false,
+            is_modulatable: false,
+            is_modulatable_per_note: false,
         })
     }
 
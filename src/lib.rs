@@ -159,6 +159,7 @@ pub mod preset;
 ///
 /// This module contains functions for sound design exploration:
 /// - `randomize_synth_params()`: Generates a random but musically useful parameter configuration
+/// - `randomize_synth_params_locked()`: The same, keeping the sections in a `RandomizeLocks` mask
 ///
 /// Randomization is useful for discovering new sounds and creative exploration. The randomization
 /// logic ensures parameters stay within reasonable ranges to avoid silent or broken sounds.
@@ -19,9 +19,11 @@
 #[cfg(feature = "standalone")]
 use crossbeam_channel::bounded;
 #[cfg(feature = "standalone")]
+use dsynth::audio::engine::{OutputHistory, SynthEngine};
+#[cfg(feature = "standalone")]
 use dsynth::audio::output::EngineEvent;
 #[cfg(feature = "standalone")]
-use dsynth::audio::{engine::SynthEngine, output::AudioOutput};
+use dsynth::audio::output::{AudioControl, AudioSettings};
 #[cfg(feature = "standalone")]
 use dsynth::gui::run_standalone_gui;
 #[cfg(feature = "standalone")]
@@ -39,9 +41,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("DSynth - Digital Synthesizer");
     println!("============================\n");
 
-    // Fixed sample rate for the audio engine. This determines the frequency at which audio
-    // samples are generated and must match the audio device's sample rate. 44100 Hz is a
-    // common professional audio rate balancing quality and CPU efficiency.
+    // Initial sample rate for the audio engine. The audio output retunes the engine to the
+    // device's actual rate when the stream starts (and whenever the user picks another
+    // device or rate), so this only matters until then.
     const SAMPLE_RATE: f32 = 44100.0;
 
     // Create shared synthesizer parameters that both GUI and audio thread can access.
@@ -69,7 +71,15 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Initialize the synthesizer engine with the configured sample rate.
     // The engine receives parameter updates via the params_consumer triple-buffer.
-    let engine = SynthEngine::new(SAMPLE_RATE, params_consumer);
+    let mut engine = SynthEngine::new(SAMPLE_RATE, params_consumer);
+
+    // The GUI reads effective (post-modulation) values from this feed to draw modulation
+    // rings on knobs. Attach it before the engine moves to the audio thread.
+    let modulation_feed = Arc::new(Mutex::new(Some(engine.modulation_feed())));
+
+    // The last second of output, kept for the GUI's mono compatibility check
+    let output_history = OutputHistory::shared(SAMPLE_RATE);
+    engine.set_output_history(output_history.clone());
 
     // Initialize and start the audio output handler. This component:
     // - Creates a real-time audio callback registered with CoreAudio
@@ -77,14 +87,20 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     // - Sends those samples to the audio device for playback
     // - Handles the audio thread's event loop, consuming events from event_rx
     //
-    // This runs on a high-priority audio thread to minimize latency. If audio fails to
-    // start (e.g., no audio device available), we continue anyway since audio is not
-    // strictly required to use the GUI.
+    // This runs on a high-priority audio thread to minimize latency. The stream itself is
+    // owned by a small control thread so the GUI can switch device, sample rate and buffer
+    // size at runtime. If audio fails to start (e.g., no audio device available), we
+    // continue anyway since audio is not strictly required to use the GUI.
     println!("Starting audio output...");
-    let _audio_output = match AudioOutput::new(engine, event_rx) {
-        Ok(output) => {
-            println!("✓ Audio output started at {} Hz", output.sample_rate());
-            Some(output)
+    let audio_control = match AudioControl::spawn(engine, event_rx, AudioSettings::default()) {
+        Ok(control) => {
+            let settings = control.settings();
+            println!(
+                "✓ Audio output started on {} at {} Hz",
+                settings.device.as_deref().unwrap_or("default device"),
+                settings.sample_rate.unwrap_or_default()
+            );
+            Some(control)
         }
         Err(e) => {
             eprintln!("✗ Failed to start audio: {}", e);
@@ -103,9 +119,12 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     // optional - it's OK if it fails to initialize (e.g., on systems without MIDI support
     // or if no devices are connected). The GUI keyboard controls will still work.
     println!("\nStarting MIDI input...");
-    let _midi_handler = match MidiHandler::new_with_engine_event_sender(event_tx.clone()) {
+    let midi_handler = match MidiHandler::new_with_engine_event_sender(event_tx.clone()) {
         Ok(handler) => {
             println!("✓ MIDI handler started");
+            // Shared with the GUI's device picker; the watcher reconnects when devices change
+            let handler = Arc::new(Mutex::new(handler));
+            MidiHandler::spawn_hot_reconnect(handler.clone());
             Some(handler)
         }
         Err(e) => {
@@ -118,7 +137,10 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("\n--- Starting GUI ---\n");
     println!("Keyboard mapping:");
     println!("  AWSEDFTGYHUJKOLP - Piano keys (C4-D#5)");
-    println!("  ZXCVBNM - Lower octave (C3-B3)\n");
+    println!("  ZXCVBNM - Lower octave (C3-B3)");
+    println!("  Up/Down - Shift keyboard octave (±3)");
+    println!("  -/= - Lower/raise typed-note velocity");
+    println!("  1-9 (hold) - Play at that velocity (1 soft, 9 full) while held\n");
 
     // Start and run the VIZIA GUI on the main thread. VIZIA provides a unified interface
     // shared between standalone and plugin, with keyboard-to-MIDI conversion for desktop use.
@@ -128,6 +150,10 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         Arc::new(Mutex::new(gui_param_producer)),
         Arc::new(Mutex::new(params_producer)),
         event_tx,
+        modulation_feed,
+        output_history,
+        midi_handler,
+        audio_control,
     )?;
 
     Ok(())
@@ -2,15 +2,19 @@
 
 #![allow(deprecated)]
 
-use crate::audio::engine::SynthEngine;
-use crate::params::SynthParams;
+use crate::audio::engine::{OutputHistory, SharedModulationFeed, SharedOutputHistory, SynthEngine};
+use crate::gui::messages::GuiLayout;
+use crate::params::{MacroParams, SynthParams, MACRO_COUNT};
+use crate::plugin::ab_compare::{AbCompare, AB_STATE_KEY};
 use crate::plugin::gui_param_change::GuiParamChange;
+use crate::plugin::param_descriptor::is_voice_param;
 use crate::plugin::param_registry;
 use crate::plugin::param_update::{param_apply, param_get};
+use crate::plugin::state::{flip_legacy_curve_macros, PresetBank, ENVELOPE_CURVE_PARAMS};
 use dsynth_clap::ParamId;
 use dsynth_clap::{
-    clap_sys, generate_clap_entry, ClapPlugin, ClapProcessor, Events, ParamDescriptor, ParamType,
-    PluginDescriptor, PluginParams, PluginState, ProcessStatus,
+    clap_sys, generate_clap_entry, ClapPlugin, ClapProcessor, Events, ParamDescriptor, ParamMod,
+    ParamType, PluginDescriptor, PluginParams, PluginState, ProcessStatus, RemoteControlsPage,
 };
 use parking_lot::{Mutex, RwLock};
 use std::ffi::{c_void, CStr, CString};
@@ -70,6 +74,65 @@ fn shared_params() -> &'static Arc<RwLock<SynthParams>> {
 
 static PARAMS_DIRTY: AtomicBool = AtomicBool::new(true);
 
+/// A/B compare slots, shared between the editor and state save/load
+fn shared_ab_compare() -> &'static Arc<Mutex<AbCompare>> {
+    static AB: OnceLock<Arc<Mutex<AbCompare>>> = OnceLock::new();
+    AB.get_or_init(|| Arc::new(Mutex::new(AbCompare::default())))
+}
+
+/// Key under which the editor scale is stored in the plugin state
+const GUI_SCALE_STATE_KEY: &str = "gui_scale";
+
+/// Editor scale chosen in the GUI, shared between the editor and state save/load
+fn shared_gui_scale() -> &'static Arc<Mutex<f64>> {
+    static SCALE: OnceLock<Arc<Mutex<f64>>> = OnceLock::new();
+    SCALE.get_or_init(|| Arc::new(Mutex::new(crate::gui::theme::DEFAULT_GUI_SCALE)))
+}
+
+/// Key under which the macro assignments are stored in the plugin state (the macro
+/// positions are ordinary parameters)
+const MACROS_STATE_KEY: &str = "macros";
+
+/// Host state version; version 2 flipped the envelope curve sign (see `plugin::state`)
+const HOST_STATE_VERSION: u32 = 2;
+
+/// Key under which the editor tab and scroll position are stored in the plugin state
+const GUI_LAYOUT_STATE_KEY: &str = "gui_layout";
+
+/// Editor tab and scroll position, shared between the editor and state save/load
+fn shared_gui_layout() -> &'static Arc<Mutex<GuiLayout>> {
+    static LAYOUT: OnceLock<Arc<Mutex<GuiLayout>>> = OnceLock::new();
+    LAYOUT.get_or_init(|| Arc::new(Mutex::new(GuiLayout::default())))
+}
+
+/// Engine → editor modulation feed, re-attached each time the engine is rebuilt
+fn modulation_feed() -> &'static SharedModulationFeed {
+    static FEED: OnceLock<SharedModulationFeed> = OnceLock::new();
+    FEED.get_or_init(|| Arc::new(Mutex::new(None)))
+}
+
+/// Recent engine output for the editor's mono compatibility check, handed to each
+/// rebuilt engine (which resizes it to its sample rate)
+fn output_history() -> &'static SharedOutputHistory {
+    static HISTORY: OnceLock<SharedOutputHistory> = OnceLock::new();
+    HISTORY.get_or_init(|| OutputHistory::shared(44100.0))
+}
+
+/// Preset bank for MIDI program changes, loaded once from `$DSYNTH_PRESET_DIR`
+/// (empty if unset or unreadable, so program changes are ignored).
+///
+/// Reads from disk on first use, so only call it off the audio thread; the processor
+/// keeps the reference it gets at creation.
+fn program_bank() -> &'static PresetBank {
+    static BANK: OnceLock<PresetBank> = OnceLock::new();
+    BANK.get_or_init(|| {
+        std::env::var("DSYNTH_PRESET_DIR")
+            .ok()
+            .and_then(|dir| PresetBank::load_dir(&dir).ok())
+            .unwrap_or_default()
+    })
+}
+
 // =============================================================================
 // Plugin
 // =============================================================================
@@ -194,6 +257,30 @@ impl ClapPlugin for DsynthMainPlugin {
         }
     }
 
+    fn remote_controls_pages() -> &'static [RemoteControlsPage] {
+        use crate::plugin::param_descriptor::{
+            PARAM_DELAY_WET, PARAM_DISTORTION_DRIVE, PARAM_ENVELOPE_ATTACK, PARAM_ENVELOPE_RELEASE,
+            PARAM_FILTER1_CUTOFF, PARAM_FILTER1_RESONANCE, PARAM_MASTER_GAIN, PARAM_REVERB_WET,
+        };
+
+        // The controls most patches are played with, for 8-knob controllers
+        const PAGES: [RemoteControlsPage; 1] = [RemoteControlsPage {
+            section: "Main",
+            name: "Performance",
+            param_ids: [
+                Some(PARAM_FILTER1_CUTOFF),
+                Some(PARAM_FILTER1_RESONANCE),
+                Some(PARAM_ENVELOPE_ATTACK),
+                Some(PARAM_ENVELOPE_RELEASE),
+                Some(PARAM_REVERB_WET),
+                Some(PARAM_DELAY_WET),
+                Some(PARAM_DISTORTION_DRIVE),
+                Some(PARAM_MASTER_GAIN),
+            ],
+        }];
+        &PAGES
+    }
+
     fn create_processor(&mut self, sample_rate: f32) -> Self::Processor {
         let consumer = self.gui_param_consumer.take().unwrap_or_else(|| {
             let buf = TripleBuffer::new(&GuiParamChange::default());
@@ -291,6 +378,11 @@ impl ClapPlugin for DsynthMainPlugin {
             parent,
             self.synth_params.clone(),
             self.gui_param_producer.clone(),
+            modulation_feed().clone(),
+            output_history().clone(),
+            shared_ab_compare().clone(),
+            shared_gui_scale().clone(),
+            shared_gui_layout().clone(),
         ) {
             Some(handle) => {
                 self.gui_window = Some(handle);
@@ -341,6 +433,8 @@ impl DsynthMainPlugin {
     }
 
     unsafe fn gui_get_size_unchecked(&mut self, width: *mut u32, height: *mut u32) -> bool {
+        // The editor opens at the size for the user's chosen scale
+        self.gui_size = crate::gui::theme::scaled_window_size(*shared_gui_scale().lock());
         *width = self.gui_size.0;
         *height = self.gui_size.1;
         true
@@ -384,6 +478,11 @@ impl DsynthMainPlugin {
             raw_handle,
             self.synth_params.clone(),
             self.gui_param_producer.clone(),
+            modulation_feed().clone(),
+            output_history().clone(),
+            shared_ab_compare().clone(),
+            shared_gui_scale().clone(),
+            shared_gui_layout().clone(),
         ) {
             Some(handle) => {
                 self.gui_window = Some(handle);
@@ -398,48 +497,113 @@ impl DsynthMainPlugin {
 // Processor
 // =============================================================================
 
+/// Most parameters the host can modulate globally at once (further ones are ignored)
+const MAX_GLOBAL_PARAM_MODS: usize = 64;
+
 pub struct DsynthMainProcessor {
     engine: SynthEngine,
 
     param_producer: Input<SynthParams>,
     current_params: SynthParams,
 
+    /// Host modulation offsets (normalized) applied on top of `current_params` for the
+    /// whole synth, as opposed to per-note modulation which goes to the engine's voices
+    global_param_mods: Vec<(ParamId, f32)>,
+
     gui_param_consumer: Output<GuiParamChange>,
     last_gui_change: GuiParamChange,
 
+    /// Presets for MIDI program changes, loaded before processing starts
+    program_bank: &'static PresetBank,
+
     sample_rate: f32,
 }
 
 impl DsynthMainProcessor {
     pub fn new(sample_rate: f32, gui_param_consumer: Output<GuiParamChange>) -> Self {
         let (mut producer, consumer) = crate::audio::create_parameter_buffer();
-        let engine = SynthEngine::new(sample_rate, consumer);
+        let mut engine = SynthEngine::new(sample_rate, consumer);
+        *modulation_feed().lock() = Some(engine.modulation_feed());
+        engine.set_output_history(output_history().clone());
 
         let initial_params = *shared_params().read();
         producer.write(initial_params);
 
         PARAMS_DIRTY.store(false, Ordering::Release);
 
+        // Load the program bank here, off the audio thread
+        let program_bank = program_bank();
+
         Self {
             engine,
             param_producer: producer,
             current_params: initial_params,
+            global_param_mods: Vec::with_capacity(MAX_GLOBAL_PARAM_MODS),
             gui_param_consumer,
             last_gui_change: GuiParamChange::default(),
+            program_bank,
             sample_rate,
         }
     }
 
+    /// Send the audio-thread params to the engine with the host's global modulation on top
+    fn publish_params(&mut self) {
+        if self.global_param_mods.is_empty() {
+            self.param_producer.write(self.current_params);
+            return;
+        }
+        let mut params = self.current_params;
+        for &(id, amount) in &self.global_param_mods {
+            if let Some(base) = DsynthMainParams::get_normalized(&self.current_params, id) {
+                param_apply::apply_param(&mut params, id, (base + amount).clamp(0.0, 1.0));
+            }
+        }
+        self.param_producer.write(params);
+    }
+
+    /// Host modulation event: per-note modulation of a voice-level parameter goes to the
+    /// voices playing that note; everything else modulates the parameter for the whole synth
+    fn apply_param_mod(&mut self, param_mod: ParamMod) {
+        let amount = param_mod.amount as f32;
+        if param_mod.is_per_note() && is_voice_param(param_mod.param_id) {
+            // A note that already ended has nothing left to modulate
+            let key = param_mod.key.map(|key| key.clamp(0, 127) as u8);
+            self.engine
+                .modulate_voice_param(param_mod.note_id, key, param_mod.param_id, amount);
+            return;
+        }
+
+        let existing = self
+            .global_param_mods
+            .iter()
+            .position(|&(id, _)| id == param_mod.param_id);
+        match existing {
+            Some(index) if amount == 0.0 => {
+                self.global_param_mods.swap_remove(index);
+            }
+            Some(index) => self.global_param_mods[index].1 = amount,
+            None if amount != 0.0 && self.global_param_mods.len() < MAX_GLOBAL_PARAM_MODS => {
+                self.global_param_mods.push((param_mod.param_id, amount));
+            }
+            None => return,
+        }
+        self.publish_params();
+    }
+
     fn sync_params_if_dirty(&mut self) {
         if PARAMS_DIRTY.swap(false, Ordering::AcqRel) {
             let params = *shared_params().read();
             self.current_params = params;
-            self.param_producer.write(self.current_params);
+            self.publish_params();
         }
     }
 
+    /// Apply the latest editor change and report it to the host
+    ///
+    /// Resets and typed-in values arrive as single changes, so they're wrapped in a
+    /// begin/set/end gesture for the host to record as automation.
     #[inline]
-    fn maybe_apply_gui_param_change(&mut self) {
+    fn maybe_apply_gui_param_change(&mut self, events: &Events) {
         let change = *self.gui_param_consumer.read();
         if change == self.last_gui_change {
             return;
@@ -453,84 +617,186 @@ impl DsynthMainProcessor {
         // Special signal: 0xFFFFFFFF means "full sync" (e.g., after randomization)
         if change.param_id == 0xFFFF_FFFF {
             self.current_params = *shared_params().read();
-            self.param_producer.write(self.current_params);
+            self.publish_params();
             return;
         }
 
         // Apply GUI change to the audio-thread copy.
         param_apply::apply_param(&mut self.current_params, change.param_id, change.normalized);
-        self.param_producer.write(self.current_params);
-    }
+        self.publish_params();
 
-    fn handle_events(&mut self, events: &Events) {
         unsafe {
-            for i in 0..events.input_event_count() {
-                let Some(event) = events.input_event(i) else {
-                    continue;
-                };
+            if change.gesture {
+                events.push_param_gesture(change.param_id, true);
+            }
+            events.push_param_value(change.param_id, change.normalized as f64);
+            if change.gesture {
+                events.push_param_gesture(change.param_id, false);
+            }
+        }
+    }
 
-                if event.space_id != clap_sys::events::CLAP_CORE_EVENT_SPACE_ID {
-                    continue;
-                }
+    /// Tell the host about every parameter that differs between two patches
+    ///
+    /// Used after a program change swaps the whole patch, so the host's automation lanes
+    /// and generic editor don't keep showing the old values.
+    ///
+    /// # Safety
+    /// The host must provide a valid output events pointer for the duration of this call.
+    unsafe fn report_changed_params(
+        events: &Events,
+        previous: &SynthParams,
+        current: &SynthParams,
+    ) {
+        let registry = param_registry::get_registry();
+        for id in registry.iter_ids() {
+            let (Some(before), Some(after)) = (
+                DsynthMainParams::get_normalized(previous, id),
+                DsynthMainParams::get_normalized(current, id),
+            ) else {
+                continue;
+            };
+            if before != after {
+                events.push_param_value(id, after as f64);
+            }
+        }
+    }
 
-                match event.type_ {
-                    clap_sys::events::CLAP_EVENT_NOTE_ON => {
-                        let e = &*(event as *const _ as *const clap_sys::events::clap_event_note);
-                        self.engine.note_on(e.key as u8, e.velocity as f32);
-                    }
-                    clap_sys::events::CLAP_EVENT_NOTE_OFF => {
-                        let e = &*(event as *const _ as *const clap_sys::events::clap_event_note);
-                        self.engine.note_off(e.key as u8);
-                    }
-                    clap_sys::events::CLAP_EVENT_MIDI => {
-                        let e = &*(event as *const _ as *const clap_sys::events::clap_event_midi);
-                        let status = e.data[0] & 0xF0;
-                        let key = e.data[1];
-                        let vel = e.data[2];
-
-                        match status {
-                            0x90 => {
-                                if vel == 0 {
-                                    self.engine.note_off(key);
-                                } else {
-                                    self.engine.note_on(key, (vel as f32) / 127.0);
-                                }
-                            }
-                            0x80 => {
-                                self.engine.note_off(key);
-                            }
-                            _ => {}
+    /// Dispatch the queued events due at or before `frame`, starting at `next`
+    ///
+    /// Returns the index of the first event still pending and its time (`u32::MAX` once the
+    /// queue is drained), so `process` can render up to each event's sample offset instead
+    /// of applying the whole block's events at its start.
+    ///
+    /// # Safety
+    /// The host must provide a valid input events pointer for the duration of this call.
+    unsafe fn dispatch_events_until(
+        &mut self,
+        events: &Events,
+        mut next: u32,
+        frame: u32,
+    ) -> (u32, u32) {
+        while let Some(event) = events.input_event(next) {
+            if event.time > frame {
+                return (next, event.time);
+            }
+            self.handle_event(events, event);
+            next += 1;
+        }
+        (next, u32::MAX)
+    }
+
+    unsafe fn handle_event(
+        &mut self,
+        events: &Events,
+        event: &clap_sys::events::clap_event_header,
+    ) {
+        if event.space_id != clap_sys::events::CLAP_CORE_EVENT_SPACE_ID {
+            return;
+        }
+
+        match event.type_ {
+            clap_sys::events::CLAP_EVENT_NOTE_ON => {
+                let e = &*(event as *const _ as *const clap_sys::events::clap_event_note);
+                // Channel -1 is a wildcard that matches any filter
+                if e.channel < 0 || self.engine.accepts_midi_channel(e.channel as u8) {
+                    // Keep the host's note ID so per-note modulation can find the voice
+                    let note_id = (e.note_id >= 0).then_some(e.note_id);
+                    self.engine
+                        .note_on_with_id(e.key as u8, e.velocity as f32, note_id);
+                }
+            }
+            clap_sys::events::CLAP_EVENT_NOTE_OFF => {
+                let e = &*(event as *const _ as *const clap_sys::events::clap_event_note);
+                if e.channel < 0 || self.engine.accepts_midi_channel(e.channel as u8) {
+                    self.engine
+                        .note_off_with_velocity(e.key as u8, e.velocity as f32);
+                }
+            }
+            clap_sys::events::CLAP_EVENT_MIDI => {
+                let e = &*(event as *const _ as *const clap_sys::events::clap_event_midi);
+                // Channel messages on other channels belong to other instances
+                if !self.engine.accepts_midi_channel(e.data[0] & 0x0F) {
+                    return;
+                }
+                let status = e.data[0] & 0xF0;
+                let key = e.data[1];
+                let vel = e.data[2];
+
+                match status {
+                    0x90 => {
+                        if vel == 0 {
+                            self.engine.note_off(key);
+                        } else {
+                            self.engine.note_on(key, (vel as f32) / 127.0);
                         }
                     }
-                    clap_sys::events::CLAP_EVENT_PARAM_VALUE => {
-                        // Some hosts may send param events in the process event stream.
-                        // Handle them for compatibility, updating the audio-thread params only.
-                        let e = &*(event as *const _
-                            as *const clap_sys::events::clap_event_param_value);
-                        let id = e.param_id as ParamId;
-                        let normalized = e.value as f32;
-                        param_apply::apply_param(&mut self.current_params, id, normalized);
-                        self.param_producer.write(self.current_params);
-
-                        // Keep shared params in sync so hosts reading get_value
-                        // don't snap UI back to stale defaults.
-                        {
-                            let mut params = shared_params().write();
-                            param_apply::apply_param(&mut params, id, normalized);
-                        }
-                        PARAMS_DIRTY.store(true, Ordering::Release);
+                    0x80 => {
+                        self.engine
+                            .note_off_with_velocity(key, (vel as f32) / 127.0);
                     }
-                    clap_sys::events::CLAP_EVENT_TRANSPORT => {
-                        let e =
-                            &*(event as *const _ as *const clap_sys::events::clap_event_transport);
-                        const CLAP_TRANSPORT_HAS_TEMPO: u32 = 1 << 0;
-                        if (e.flags & CLAP_TRANSPORT_HAS_TEMPO) != 0 {
-                            self.engine.set_tempo(e.tempo);
+                    // Channel mode messages: All Notes Off fades out, All Sound Off cuts
+                    0xB0 if key == 123 => self.engine.all_notes_off(),
+                    0xB0 if key == 120 => self.engine.panic(),
+                    0xC0 => {
+                        // Program change: data[1] is the program number
+                        if let Some(params) = self.engine.select_program(
+                            key,
+                            self.program_bank,
+                            &mut self.param_producer,
+                        ) {
+                            let previous = self.current_params;
+                            self.current_params = params;
+                            *shared_params().write() = params;
+                            self.publish_params();
+                            Self::report_changed_params(events, &previous, &params);
                         }
                     }
                     _ => {}
                 }
             }
+            clap_sys::events::CLAP_EVENT_PARAM_VALUE => {
+                // Some hosts may send param events in the process event stream.
+                // Handle them for compatibility, updating the audio-thread params only.
+                let e = &*(event as *const _ as *const clap_sys::events::clap_event_param_value);
+                let id = e.param_id as ParamId;
+                let normalized = e.value as f32;
+                param_apply::apply_param(&mut self.current_params, id, normalized);
+                self.publish_params();
+
+                // Keep shared params in sync so hosts reading get_value
+                // don't snap UI back to stale defaults.
+                {
+                    let mut params = shared_params().write();
+                    param_apply::apply_param(&mut params, id, normalized);
+                }
+                PARAMS_DIRTY.store(true, Ordering::Release);
+            }
+            clap_sys::events::CLAP_EVENT_PARAM_MOD => {
+                if let Some(param_mod) = Events::param_mod(event) {
+                    self.apply_param_mod(param_mod);
+                }
+            }
+            clap_sys::events::CLAP_EVENT_TRANSPORT => {
+                let e = &*(event as *const _ as *const clap_sys::events::clap_event_transport);
+                const CLAP_TRANSPORT_HAS_TEMPO: u32 = 1 << 0;
+                const CLAP_TRANSPORT_HAS_BEATS_TIMELINE: u32 = 1 << 1;
+                const CLAP_TRANSPORT_IS_PLAYING: u32 = 1 << 4;
+                const CLAP_BEATTIME_FACTOR: f64 = (1u64 << 31) as f64;
+                if (e.flags & CLAP_TRANSPORT_HAS_TEMPO) != 0 {
+                    self.engine.set_tempo(e.tempo);
+                }
+                // Lock synced LFOs to the timeline while the host plays
+                let playing_with_beats =
+                    CLAP_TRANSPORT_HAS_BEATS_TIMELINE | CLAP_TRANSPORT_IS_PLAYING;
+                if (e.flags & playing_with_beats) == playing_with_beats {
+                    let beats = e.song_pos_beats as f64 / CLAP_BEATTIME_FACTOR;
+                    self.engine.set_song_position(beats);
+                } else {
+                    self.engine.clear_song_position();
+                }
+            }
+            _ => {}
         }
     }
 }
@@ -538,19 +804,58 @@ impl DsynthMainProcessor {
 impl ClapProcessor for DsynthMainProcessor {
     fn process(&mut self, audio: &mut dsynth_clap::AudioBuffers, events: &Events) -> ProcessStatus {
         self.sync_params_if_dirty();
-        self.maybe_apply_gui_param_change();
-        self.handle_events(events);
+        self.maybe_apply_gui_param_change(events);
 
         let frames = audio.frames_count() as usize;
 
         unsafe {
+            // Events are applied at their sample offsets rather than at the start of the
+            // block, so note timing doesn't depend on the host's buffer size.
+            let (mut next_event, mut next_event_time) = self.dispatch_events_until(events, 0, 0);
+
+            // Effect/Custom port configs: feed the host input through the engine so the
+            // input gain can drive the effects chain. Instrument config has no input port.
+            if audio.input_port_count() > 0 {
+                if let Some((in_l, in_r, out_l, out_r)) = audio.io_stereo_mut(0, 0) {
+                    let n = frames
+                        .min(in_l.len())
+                        .min(in_r.len())
+                        .min(out_l.len())
+                        .min(out_r.len());
+
+                    // Sample-by-sample so in-place (aliased) host buffers are safe.
+                    for i in 0..n {
+                        if i as u32 >= next_event_time {
+                            (next_event, next_event_time) =
+                                self.dispatch_events_until(events, next_event, i as u32);
+                        }
+                        let (l, r) = self.engine.process_with_input(in_l[i], in_r[i]);
+                        out_l[i] = l;
+                        out_r[i] = r;
+                    }
+
+                    for i in n..frames.min(out_l.len()).min(out_r.len()) {
+                        out_l[i] = 0.0;
+                        out_r[i] = 0.0;
+                    }
+
+                    self.dispatch_events_until(events, next_event, u32::MAX);
+                    return ProcessStatus::Continue;
+                }
+            }
+
             let Some((out_l, out_r)) = audio.output_stereo_mut(0) else {
+                self.dispatch_events_until(events, next_event, u32::MAX);
                 return ProcessStatus::Continue;
             };
 
             let n = frames.min(out_l.len()).min(out_r.len());
 
             for i in 0..n {
+                if i as u32 >= next_event_time {
+                    (next_event, next_event_time) =
+                        self.dispatch_events_until(events, next_event, i as u32);
+                }
                 let (l, r) = self.engine.process();
                 out_l[i] = l;
                 out_r[i] = r;
@@ -564,6 +869,9 @@ impl ClapProcessor for DsynthMainProcessor {
                     out_r[i] = 0.0;
                 }
             }
+
+            // Anything timed past the rendered frames still applies before the next block
+            self.dispatch_events_until(events, next_event, u32::MAX);
         }
 
         ProcessStatus::Continue
@@ -579,28 +887,40 @@ impl ClapProcessor for DsynthMainProcessor {
         // Recreate engine + param buffer at the new rate.
         let (producer, consumer) = crate::audio::create_parameter_buffer();
         self.engine = SynthEngine::new(sample_rate, consumer);
+        *modulation_feed().lock() = Some(self.engine.modulation_feed());
+        self.engine.set_output_history(output_history().clone());
         self.param_producer = producer;
 
         let params = *shared_params().read();
         self.current_params = params;
-        self.param_producer.write(self.current_params);
+        self.publish_params();
     }
 
     fn reset(&mut self) {
         // Recreate engine + param buffer, keep GUI consumer.
         let (producer, consumer) = crate::audio::create_parameter_buffer();
         self.engine = SynthEngine::new(self.sample_rate, consumer);
+        *modulation_feed().lock() = Some(self.engine.modulation_feed());
+        self.engine.set_output_history(output_history().clone());
         self.param_producer = producer;
         self.last_gui_change = GuiParamChange::default();
 
         let params = *shared_params().read();
         self.current_params = params;
-        self.param_producer.write(self.current_params);
+        self.publish_params();
     }
 
     fn deactivate(&mut self) {
         // No-op: engine will be dropped when processor is dropped.
     }
+
+    fn latency(&self) -> u32 {
+        self.engine.latency_samples()
+    }
+
+    fn tail(&self) -> u32 {
+        self.engine.tail_length_samples()
+    }
 }
 
 // =============================================================================
@@ -652,15 +972,23 @@ impl PluginParams for DsynthMainParams {
             },
         };
 
+        // Host modulation of a switch isn't meaningful, so only continuous params offer it
+        let continuous = !matches!(
+            desc.param_type,
+            crate::plugin::param_descriptor::ParamType::Bool
+        );
+
         Some(ParamDescriptor {
             id,
             name: desc.name.clone(),
-            module: desc.module.clone(),
+            module: desc.module_path(),
             param_type,
             unit: desc.unit.clone(),
             is_automatable: desc.automation
                 == crate::plugin::param_descriptor::AutomationState::ReadWrite,
             is_hidden: false,
+            is_modulatable: continuous,
+            is_modulatable_per_note: continuous && is_voice_param(id),
         })
     }
 
@@ -682,7 +1010,7 @@ impl PluginParams for DsynthMainParams {
         let params = shared_params().read();
 
         let mut state = PluginState {
-            version: 1,
+            version: HOST_STATE_VERSION,
             ..Default::default()
         };
 
@@ -692,15 +1020,72 @@ impl PluginParams for DsynthMainParams {
             }
         }
 
+        if let Ok(json) = shared_ab_compare().lock().snapshot(&params).to_json() {
+            state.set_extra(AB_STATE_KEY, json);
+        }
+        if let Ok(json) = serde_json::to_string(&params.macros) {
+            state.set_extra(MACROS_STATE_KEY, json);
+        }
+        state.set_extra(GUI_SCALE_STATE_KEY, shared_gui_scale().lock().to_string());
+        if let Ok(json) = serde_json::to_string(&*shared_gui_layout().lock()) {
+            state.set_extra(GUI_LAYOUT_STATE_KEY, json);
+        }
+
         state
     }
 
     fn load_state(state: &PluginState) {
+        // Projects saved before version 2 have their envelope curves the other way round
+        let legacy_curves = state.version < HOST_STATE_VERSION;
         {
             let mut params = shared_params().write();
-            for (&id, &normalized) in state.params.iter() {
-                param_apply::apply_param(&mut params, id, normalized);
+            if let Some(mut macros) = state
+                .get_extra(MACROS_STATE_KEY)
+                .and_then(|json| serde_json::from_str::<[MacroParams; MACRO_COUNT]>(json).ok())
+            {
+                if legacy_curves {
+                    flip_legacy_curve_macros(&mut macros);
+                }
+                params.macros = macros;
             }
+
+            // Macros and harmonic slopes first (see `apply_saved_params`): they write other
+            // parameters, whose saved values (which may have been tweaked since) must win
+            let saved = state.params.iter().map(|(&id, &normalized)| {
+                if legacy_curves && ENVELOPE_CURVE_PARAMS.contains(&id) {
+                    (id, 1.0 - normalized)
+                } else {
+                    (id, normalized)
+                }
+            });
+            param_apply::apply_saved_params(&mut params, saved);
+
+            // Projects saved before A/B existed start with both slots on the loaded patch
+            let ab = state
+                .get_extra(AB_STATE_KEY)
+                .and_then(|json| AbCompare::from_json(json).ok())
+                .map(|mut ab| {
+                    if legacy_curves {
+                        ab.flip_legacy_envelope_curves();
+                    }
+                    ab
+                })
+                .unwrap_or_else(|| AbCompare::new(&params));
+            *shared_ab_compare().lock() = ab;
+        }
+        if let Some(scale) = state
+            .get_extra(GUI_SCALE_STATE_KEY)
+            .and_then(|scale| scale.parse::<f64>().ok())
+        {
+            let index = crate::gui::theme::nearest_gui_scale_index(scale);
+            *shared_gui_scale().lock() = crate::gui::theme::GUI_SCALE_CHOICES[index];
+        }
+        // Projects saved before the layout was stored open on the first tab, at the top
+        if let Some(layout) = state
+            .get_extra(GUI_LAYOUT_STATE_KEY)
+            .and_then(|json| serde_json::from_str::<GuiLayout>(json).ok())
+        {
+            *shared_gui_layout().lock() = layout;
         }
         PARAMS_DIRTY.store(true, Ordering::Release);
     }
@@ -0,0 +1,157 @@
+//! MIDI clock tempo tracking
+//!
+//! External sequencers send 24 clock messages (0xF8) per quarter note. `MidiClock` turns
+//! their timestamps into a BPM, averaging over one beat of intervals to smooth out the
+//! jitter of USB/driver timing.
+
+/// MIDI clock ticks per quarter note
+pub const TICKS_PER_BEAT: usize = 24;
+
+/// Intervals needed before the first tempo is reported (a sixteenth note)
+const MIN_INTERVALS: usize = TICKS_PER_BEAT / 4;
+
+/// Smallest BPM change reported, so averaging noise doesn't retrigger tempo updates
+pub const TEMPO_REPORT_THRESHOLD: f64 = 0.1;
+
+/// A gap longer than this (one tick at 10 BPM) means the clock stopped; averaging restarts
+const MAX_TICK_GAP_US: u64 = 250_000;
+
+/// Derives tempo from MIDI clock timestamps
+///
+/// Allocation-free, so it can live inside the MIDI input callback.
+#[derive(Debug, Clone)]
+pub struct MidiClock {
+    last_tick_us: Option<u64>,
+    /// Ring buffer of the most recent tick intervals in microseconds
+    intervals: [u64; TICKS_PER_BEAT],
+    count: usize,
+    next: usize,
+    reported_bpm: Option<f64>,
+}
+
+impl Default for MidiClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MidiClock {
+    pub fn new() -> Self {
+        Self {
+            last_tick_us: None,
+            intervals: [0; TICKS_PER_BEAT],
+            count: 0,
+            next: 0,
+            reported_bpm: None,
+        }
+    }
+
+    /// Register a clock tick
+    ///
+    /// # Arguments
+    /// * `timestamp_us` - Arrival time in microseconds (midir's callback timestamp)
+    ///
+    /// # Returns
+    /// The new tempo when it moved by at least `TEMPO_REPORT_THRESHOLD`
+    pub fn tick(&mut self, timestamp_us: u64) -> Option<f64> {
+        let last = self.last_tick_us.replace(timestamp_us);
+        let interval = timestamp_us.saturating_sub(last?);
+
+        if interval == 0 || interval > MAX_TICK_GAP_US {
+            self.count = 0;
+            return None;
+        }
+
+        self.intervals[self.next] = interval;
+        self.next = (self.next + 1) % TICKS_PER_BEAT;
+        self.count = (self.count + 1).min(TICKS_PER_BEAT);
+
+        let bpm = self.bpm()?;
+        let moved = self
+            .reported_bpm
+            .is_none_or(|reported| (bpm - reported).abs() >= TEMPO_REPORT_THRESHOLD);
+        if moved {
+            self.reported_bpm = Some(bpm);
+            return Some(bpm);
+        }
+        None
+    }
+
+    /// Averaged tempo, once enough ticks have arrived
+    pub fn bpm(&self) -> Option<f64> {
+        if self.count < MIN_INTERVALS {
+            return None;
+        }
+        // With a full ring buffer every slot is valid; before that, only the first `count`
+        let total: u64 = self.intervals[..self.count].iter().sum();
+        let mean_interval_us = total as f64 / self.count as f64;
+        Some(60_000_000.0 / (mean_interval_us * TICKS_PER_BEAT as f64))
+    }
+
+    /// Forget tick timing (e.g. after the input port changes); the last tempo is kept
+    pub fn reset(&mut self) {
+        self.last_tick_us = None;
+        self.count = 0;
+        self.next = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Tick interval in microseconds for a tempo
+    fn interval_us(bpm: f64) -> u64 {
+        (60_000_000.0 / (bpm * TICKS_PER_BEAT as f64)).round() as u64
+    }
+
+    #[test]
+    fn test_clock_derives_tempo() {
+        let mut clock = MidiClock::new();
+        let step = interval_us(128.0);
+
+        let reported: Vec<f64> = (0..48).filter_map(|i| clock.tick(i * step)).collect();
+        assert_eq!(reported.len(), 1, "Steady clock should report once");
+        assert!((reported[0] - 128.0).abs() < 0.05, "{}", reported[0]);
+    }
+
+    #[test]
+    fn test_clock_averages_jitter() {
+        let mut clock = MidiClock::new();
+        let step = interval_us(120.0) as i64;
+
+        // ±1ms of alternating jitter on every tick
+        let mut time = 0i64;
+        for i in 0..96 {
+            let jitter = if i % 2 == 0 { 1000 } else { -1000 };
+            clock.tick((time + jitter) as u64);
+            time += step;
+        }
+        let bpm = clock.bpm().unwrap();
+        assert!(
+            (bpm - 120.0).abs() < 0.5,
+            "Jitter should average out: {bpm}"
+        );
+    }
+
+    #[test]
+    fn test_clock_follows_tempo_change_and_gaps() {
+        let mut clock = MidiClock::new();
+        let mut time = 0;
+        for _ in 0..48 {
+            clock.tick(time);
+            time += interval_us(100.0);
+        }
+        let mut last = None;
+        for _ in 0..48 {
+            last = clock.tick(time).or(last);
+            time += interval_us(140.0);
+        }
+        assert!((last.unwrap() - 140.0).abs() < 0.1);
+
+        // A long pause (clock stopped) restarts averaging instead of skewing the tempo
+        time += 2_000_000;
+        assert_eq!(clock.tick(time), None);
+        assert_eq!(clock.bpm(), None);
+    }
+}
@@ -1,21 +1,49 @@
 use crossbeam_channel::{unbounded, Receiver, Sender};
 use midir::{MidiInput, MidiInputConnection};
+use parking_lot::Mutex;
 use std::error::Error;
+use std::sync::Arc;
+use std::time::Duration;
 
 use crate::audio::output::EngineEvent;
+use crate::midi::clock::MidiClock;
 
 /// MIDI message events
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum MidiEvent {
-    NoteOn { note: u8, velocity: u8 },
-    NoteOff { note: u8 },
-    ControlChange { controller: u8, value: u8 },
+    NoteOn {
+        note: u8,
+        velocity: u8,
+    },
+    NoteOff {
+        note: u8,
+    },
+    ControlChange {
+        controller: u8,
+        value: u8,
+    },
+    /// System real-time: timing clock (24 per quarter note)
+    Clock,
+    Start,
+    Continue,
+    Stop,
 }
 
+/// How often the hot-reconnect watcher rescans MIDI ports
+pub const PORT_SCAN_INTERVAL: Duration = Duration::from_secs(1);
+
 /// MIDI input handler
 pub struct MidiHandler {
     _connection: Option<MidiInputConnection<()>>,
     event_receiver: Receiver<MidiEvent>,
+    /// Where note events go (set by `new_with_engine_event_sender`; needed to reconnect)
+    engine_event_sender: Option<Sender<EngineEvent>>,
+    /// Port the user picked (by name, so it survives reordering); `None` = first available
+    preferred_port: Option<String>,
+    /// Name of the port currently connected
+    connected_port: Option<String>,
+    /// Port names from the last scan
+    available_ports: Vec<String>,
 }
 
 impl MidiHandler {
@@ -30,6 +58,10 @@ impl MidiHandler {
 
         // Try to connect to the first available MIDI input port
         let ports = midi_in.ports();
+        let port_names: Vec<String> = ports
+            .iter()
+            .filter_map(|port| midi_in.port_name(port).ok())
+            .collect();
 
         let connection = if let Some(port) = ports.first() {
             let port_name = midi_in.port_name(port)?;
@@ -52,9 +84,14 @@ impl MidiHandler {
             None
         };
 
+        let connected_port = connection.as_ref().and(port_names.first().cloned());
         let handler = Self {
             _connection: connection,
             event_receiver: receiver.clone(),
+            engine_event_sender: None,
+            preferred_port: None,
+            connected_port,
+            available_ports: port_names,
         };
 
         Ok((handler, receiver))
@@ -63,57 +100,174 @@ impl MidiHandler {
     /// Create a MIDI handler that sends note events directly to the audio thread.
     ///
     /// This avoids the extra forwarding thread and avoids allocations in the MIDI callback by
-    /// using `try_send()` on the provided bounded channel.
+    /// using `try_send()` on the provided bounded channel. Connects to the first available
+    /// port; use `connect_port` to pick another.
     pub fn new_with_engine_event_sender(
         engine_event_sender: Sender<EngineEvent>,
     ) -> Result<Self, Box<dyn Error>> {
-        let midi_in = MidiInput::new("DSynth MIDI Input")?;
-
         // Keep the old receiver field around as a dummy to avoid changing the struct shape.
         let (_dummy_tx, dummy_rx) = unbounded();
 
-        let ports = midi_in.ports();
-        let connection = if let Some(port) = ports.first() {
-            let port_name = midi_in.port_name(port)?;
-            println!("Connecting to MIDI port: {}", port_name);
+        let mut handler = Self {
+            _connection: None,
+            event_receiver: dummy_rx,
+            engine_event_sender: Some(engine_event_sender),
+            preferred_port: None,
+            connected_port: None,
+            available_ports: Vec::new(),
+        };
+        handler.refresh()?;
+        if handler.connected_port.is_none() {
+            println!("No MIDI input ports available");
+        }
 
-            let connection = midi_in.connect(
-                port,
-                "dsynth-input",
-                move |_timestamp, message, _| {
-                    if let Some(event) = Self::parse_midi_message(message) {
-                        match event {
-                            MidiEvent::NoteOn { note, velocity } => {
-                                let _ = engine_event_sender.try_send(EngineEvent::NoteOn {
-                                    note,
-                                    velocity: velocity_to_float(velocity),
-                                });
-                            }
-                            MidiEvent::NoteOff { note } => {
-                                let _ = engine_event_sender.try_send(EngineEvent::NoteOff { note });
-                            }
-                            MidiEvent::ControlChange { .. } => {
-                                // CC handling can be added here
+        Ok(handler)
+    }
+
+    /// Connect to the input port at `index` (as listed by `list_ports`/`available_ports`)
+    ///
+    /// The choice is remembered by name, so `refresh` reconnects to it if the device is
+    /// unplugged and plugged back in.
+    ///
+    /// # Returns
+    /// Name of the connected port
+    pub fn connect_port(&mut self, index: usize) -> Result<String, Box<dyn Error>> {
+        let ports = Self::list_ports()?;
+        let name = ports
+            .get(index)
+            .cloned()
+            .ok_or_else(|| format!("No MIDI input port {index}"))?;
+        self.preferred_port = Some(name.clone());
+        self.available_ports = ports;
+        self.open_port(index)?;
+        Ok(name)
+    }
+
+    /// Rescan ports and (re)connect to the preferred one, or the first if none was picked
+    ///
+    /// Called periodically by the hot-reconnect watcher (see `spawn_hot_reconnect`).
+    ///
+    /// # Returns
+    /// `true` if the connection changed
+    pub fn refresh(&mut self) -> Result<bool, Box<dyn Error>> {
+        self.available_ports = Self::list_ports()?;
+        let target = choose_port(&self.available_ports, self.preferred_port.as_deref());
+
+        let target_name = target.map(|i| self.available_ports[i].clone());
+        if target_name == self.connected_port {
+            return Ok(false);
+        }
+
+        match target {
+            Some(index) => self.open_port(index)?,
+            None => {
+                // Picked device went away: drop the stale connection until it's back
+                self._connection = None;
+                self.connected_port = None;
+            }
+        }
+        Ok(true)
+    }
+
+    /// Port names from the last scan
+    pub fn available_ports(&self) -> &[String] {
+        &self.available_ports
+    }
+
+    /// Name of the currently connected port
+    pub fn connected_port(&self) -> Option<&str> {
+        self.connected_port.as_deref()
+    }
+
+    /// Start a background thread that calls `refresh` every `PORT_SCAN_INTERVAL`, so devices
+    /// plugged in (or back in) after startup are picked up without restarting.
+    pub fn spawn_hot_reconnect(handler: Arc<Mutex<Self>>) -> std::thread::JoinHandle<()> {
+        std::thread::spawn(move || loop {
+            std::thread::sleep(PORT_SCAN_INTERVAL);
+            let mut guard = handler.lock();
+            if let Ok(true) = guard.refresh() {
+                match guard.connected_port() {
+                    Some(name) => println!("MIDI input connected: {}", name),
+                    None => println!("MIDI input disconnected"),
+                }
+            }
+        })
+    }
+
+    /// Replace the current connection with one to port `index`, forwarding to the engine
+    fn open_port(&mut self, index: usize) -> Result<(), Box<dyn Error>> {
+        let engine_event_sender = self
+            .engine_event_sender
+            .clone()
+            .ok_or("MIDI handler has no engine event sender")?;
+
+        // Release the old port before opening the new one
+        self._connection = None;
+        self.connected_port = None;
+
+        let midi_in = MidiInput::new("DSynth MIDI Input")?;
+        let ports = midi_in.ports();
+        let port = ports.get(index).ok_or("MIDI input port disappeared")?;
+        let port_name = midi_in.port_name(port)?;
+        println!("Connecting to MIDI port: {}", port_name);
+
+        // Tempo tracking for an external clock; lives in the callback (allocation-free)
+        let mut clock = MidiClock::new();
+
+        let connection = midi_in.connect(
+            port,
+            "dsynth-input",
+            move |timestamp, message, _| {
+                if let Some(event) = Self::parse_midi_message(message) {
+                    match event {
+                        MidiEvent::NoteOn { note, velocity } => {
+                            let _ = engine_event_sender.try_send(EngineEvent::NoteOn {
+                                note,
+                                velocity: velocity_to_float(velocity),
+                            });
+                        }
+                        MidiEvent::NoteOff { note } => {
+                            let _ = engine_event_sender.try_send(EngineEvent::NoteOff { note });
+                        }
+                        MidiEvent::ControlChange { .. } => {
+                            // CC handling can be added here
+                        }
+                        MidiEvent::Clock => {
+                            if let Some(bpm) = clock.tick(timestamp) {
+                                let _ = engine_event_sender.try_send(EngineEvent::SetTempo { bpm });
                             }
                         }
+                        MidiEvent::Start => {
+                            let _ = engine_event_sender.try_send(EngineEvent::TransportStart);
+                        }
+                        MidiEvent::Stop => {
+                            let _ = engine_event_sender.try_send(EngineEvent::TransportStop);
+                        }
+                        MidiEvent::Continue => {
+                            // Resume without realigning: the LFOs kept running while stopped
+                        }
                     }
-                },
-                (),
-            )?;
-            Some(connection)
-        } else {
-            println!("No MIDI input ports available");
-            None
-        };
+                }
+            },
+            (),
+        )?;
 
-        Ok(Self {
-            _connection: connection,
-            event_receiver: dummy_rx,
-        })
+        self._connection = Some(connection);
+        self.connected_port = Some(port_name);
+        Ok(())
     }
 
     /// Parse MIDI message bytes into MidiEvent
     fn parse_midi_message(message: &[u8]) -> Option<MidiEvent> {
+        // System real-time messages are a single status byte
+        match message.first()? {
+            0xF8 => return Some(MidiEvent::Clock),
+            0xFA => return Some(MidiEvent::Start),
+            0xFB => return Some(MidiEvent::Continue),
+            0xFC => return Some(MidiEvent::Stop),
+            _ => {}
+        }
+
         if message.len() < 2 {
             return None;
         }
@@ -170,6 +324,16 @@ impl MidiHandler {
     }
 }
 
+/// Pick the port to connect to: the preferred one if present, else the first port when
+/// nothing was picked. A picked-but-missing device yields `None` (wait for it to return
+/// rather than silently grabbing another controller).
+fn choose_port(ports: &[String], preferred: Option<&str>) -> Option<usize> {
+    match preferred {
+        Some(name) => ports.iter().position(|p| p == name),
+        None => (!ports.is_empty()).then_some(0),
+    }
+}
+
 /// Helper function to convert MIDI velocity (0-127) to normalized value (0.0-1.0)
 pub fn velocity_to_float(velocity: u8) -> f32 {
     velocity as f32 / 127.0
@@ -245,6 +409,26 @@ mod tests {
         assert_eq!(event, None);
     }
 
+    #[test]
+    fn test_parse_realtime_messages() {
+        assert_eq!(
+            MidiHandler::parse_midi_message(&[0xF8]),
+            Some(MidiEvent::Clock)
+        );
+        assert_eq!(
+            MidiHandler::parse_midi_message(&[0xFA]),
+            Some(MidiEvent::Start)
+        );
+        assert_eq!(
+            MidiHandler::parse_midi_message(&[0xFB]),
+            Some(MidiEvent::Continue)
+        );
+        assert_eq!(
+            MidiHandler::parse_midi_message(&[0xFC]),
+            Some(MidiEvent::Stop)
+        );
+    }
+
     #[test]
     fn test_velocity_to_float() {
         assert_eq!(velocity_to_float(0), 0.0);
@@ -291,6 +475,19 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_choose_port_prefers_picked_device() {
+        let ports = vec!["Keys".to_string(), "Pads".to_string()];
+        assert_eq!(choose_port(&ports, None), Some(0), "Default to first");
+        assert_eq!(choose_port(&ports, Some("Pads")), Some(1));
+        assert_eq!(
+            choose_port(&ports[..1], Some("Pads")),
+            None,
+            "Unplugged pick shouldn't fall back to another device"
+        );
+        assert_eq!(choose_port(&[], None), None);
+    }
+
     #[test]
     fn test_midi_event_channel_independence() {
         // Different channels should parse the same
@@ -1,2 +1,4 @@
+pub mod clock;
+
 #[cfg(feature = "standalone")]
 pub mod handler;
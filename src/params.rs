@@ -13,6 +13,16 @@ pub enum Waveform {
     PinkNoise,
     Additive,
     Wavetable,
+    /// Saw with a slightly bowed ramp and a finite reset slope, like a capacitor-based VCO.
+    /// Same harmonic series as `Saw`, but the top harmonics roll off gently instead of
+    /// falling at a strict 1/n.
+    AnalogSaw,
+    /// Square with a slightly off-center duty cycle and drooping tops. The duty offset adds
+    /// faint even harmonics (a clean square has only odd ones); the droop softens the lows.
+    AnalogSquare,
+    /// Karplus-Strong plucked string: a noise burst excites a delay line tuned to the note,
+    /// decaying through a lowpass loop filter (see `pluck_damping`/`pluck_brightness`).
+    Pluck,
 }
 
 impl fmt::Display for Waveform {
@@ -27,6 +37,9 @@ impl fmt::Display for Waveform {
             Waveform::PinkNoise => write!(f, "Pink Noise"),
             Waveform::Additive => write!(f, "Additive"),
             Waveform::Wavetable => write!(f, "Wavetable"),
+            Waveform::AnalogSaw => write!(f, "Analog Saw"),
+            Waveform::AnalogSquare => write!(f, "Analog Square"),
+            Waveform::Pluck => write!(f, "Pluck"),
         }
     }
 }
@@ -55,6 +68,77 @@ impl fmt::Display for FilterType {
     }
 }
 
+/// Which voice filter an oscillator feeds
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum FilterRouting {
+    #[default]
+    Paired, // Oscillator N → Filter N (original behavior)
+    Filter1,
+    Filter2,
+    Filter3,
+    Bypass, // Skip filtering (and filter drive) entirely
+}
+
+impl FilterRouting {
+    /// Filter slot (0-2) this routing targets for the oscillator at `osc_index`,
+    /// or `None` when bypassed.
+    pub fn filter_index(self, osc_index: usize) -> Option<usize> {
+        match self {
+            FilterRouting::Paired => Some(osc_index.min(2)),
+            FilterRouting::Filter1 => Some(0),
+            FilterRouting::Filter2 => Some(1),
+            FilterRouting::Filter3 => Some(2),
+            FilterRouting::Bypass => None,
+        }
+    }
+}
+
+/// How the three voice filters are combined
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum FilterTopology {
+    #[default]
+    Parallel, // Each filter processes its routed oscillators; outputs are summed
+    Serial, // Filter 1 → Filter 2 → Filter 3 on the full voice signal
+}
+
+impl fmt::Display for FilterTopology {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FilterTopology::Parallel => write!(f, "Parallel"),
+            FilterTopology::Serial => write!(f, "Serial"),
+        }
+    }
+}
+
+/// Where a filter's Drive saturation sits relative to the filter
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum DrivePosition {
+    #[default]
+    Pre, // Saturate before the filter: the filter tames the added harmonics
+    Post, // Saturate after the filter: harmonics and resonant peaks pass through the clipper
+}
+
+impl fmt::Display for DrivePosition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DrivePosition::Pre => write!(f, "Pre"),
+            DrivePosition::Post => write!(f, "Post"),
+        }
+    }
+}
+
+impl fmt::Display for FilterRouting {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FilterRouting::Paired => write!(f, "Paired"),
+            FilterRouting::Filter1 => write!(f, "Filter 1"),
+            FilterRouting::Filter2 => write!(f, "Filter 2"),
+            FilterRouting::Filter3 => write!(f, "Filter 3"),
+            FilterRouting::Bypass => write!(f, "Bypass"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct OscillatorParams {
     pub waveform: Waveform,
@@ -75,6 +159,112 @@ pub struct OscillatorParams {
     pub wavetable_position: f32, // Wavetable morphing position (0.0 to 1.0)
     #[serde(default)]
     pub saturation: f32, // Oscillator-level saturation/warmth (0.0 to 1.0)
+    #[serde(default)]
+    pub additive_normalize: bool, // Peak-normalize the summed additive harmonics
+    #[serde(default = "default_additive_gain")]
+    pub additive_gain: f32, // Additive output trim (0.0 to 2.0, linear)
+    #[serde(default)]
+    pub filter_routing: FilterRouting, // Which voice filter this oscillator feeds
+    #[serde(default = "default_pluck_tone")]
+    pub pluck_damping: f32, // Pluck string decay damping (0.0 = long ring, 1.0 = short)
+    #[serde(default = "default_pluck_tone")]
+    pub pluck_brightness: f32, // Pluck excitation/loop brightness (0.0 to 1.0)
+    #[serde(default)]
+    pub fixed_freq: Option<f32>, // Fixed frequency in Hz (0.1 to 20000) ignoring the note, None = key-tracked
+    #[serde(default)]
+    pub sample_reduce: f32, // Pre-filter sample-rate reduction target in Hz (0.0 = off, 100.0 to 44100.0)
+    #[serde(default)]
+    pub additive_harmonics_high: [f32; 24], // Harmonics 9 to 32, continuing `additive_harmonics` (0.0 to 1.0)
+    #[serde(default = "default_additive_harmonic_count")]
+    pub additive_harmonic_count: usize, // Harmonics the additive oscillator sums (8 to 32)
+    #[serde(default = "default_additive_slope")]
+    pub additive_slope: f32, // Decay slope last written to all harmonics (0.0 to 3.0)
+    #[serde(default)]
+    pub fm_feedback: f32, // Self-feedback FM amount (0.0 to 1.0, 0.0 = off)
+    #[serde(default = "default_gain_slew_ms")]
+    pub gain_slew_ms: f32, // Gain smoothing time constant in ms (0.0 = instant, up to 100.0)
+}
+
+impl OscillatorParams {
+    /// Frequency a fixed-frequency oscillator starts at when the mode is switched on
+    pub const DEFAULT_FIXED_FREQ_HZ: f32 = 440.0;
+
+    /// Most harmonics an additive oscillator can sum
+    pub const MAX_ADDITIVE_HARMONICS: usize = 32;
+
+    /// Amplitude of additive harmonic `index` (0 = fundamental) across both harmonic arrays
+    pub fn additive_harmonic(&self, index: usize) -> f32 {
+        if index < self.additive_harmonics.len() {
+            self.additive_harmonics[index]
+        } else {
+            self.additive_harmonics_high
+                .get(index - self.additive_harmonics.len())
+                .copied()
+                .unwrap_or(0.0)
+        }
+    }
+
+    /// Set the amplitude of additive harmonic `index` (0 = fundamental); out of range is ignored
+    pub fn set_additive_harmonic(&mut self, index: usize, amplitude: f32) {
+        let low = self.additive_harmonics.len();
+        if index < low {
+            self.additive_harmonics[index] = amplitude;
+        } else if let Some(slot) = self.additive_harmonics_high.get_mut(index - low) {
+            *slot = amplitude;
+        }
+    }
+
+    /// Harmonic amplitudes the oscillator sums: the first `additive_harmonic_count`, the
+    /// rest silent
+    pub fn additive_harmonic_amplitudes(&self) -> [f32; Self::MAX_ADDITIVE_HARMONICS] {
+        let count = self
+            .additive_harmonic_count
+            .min(Self::MAX_ADDITIVE_HARMONICS);
+        std::array::from_fn(|index| {
+            if index < count {
+                self.additive_harmonic(index)
+            } else {
+                0.0
+            }
+        })
+    }
+
+    /// Harmonic decay slope macro: sets each of the first `additive_harmonic_count`
+    /// harmonics to `1 / n^slope` (1.0 = saw-like, 2.0 = darker, 0.0 = all equal)
+    pub fn set_additive_slope(&mut self, slope: f32) {
+        self.additive_slope = slope;
+        let count = self
+            .additive_harmonic_count
+            .min(Self::MAX_ADDITIVE_HARMONICS);
+        for index in 0..count {
+            self.set_additive_harmonic(index, ((index + 1) as f32).powf(-slope));
+        }
+    }
+}
+
+/// The original 8 harmonics for presets saved before the count existed.
+fn default_additive_harmonic_count() -> usize {
+    8
+}
+
+/// Saw-like slope for presets saved before the field existed.
+fn default_additive_slope() -> f32 {
+    1.0
+}
+
+/// Default additive trim for presets saved before the field existed.
+fn default_additive_gain() -> f32 {
+    1.0
+}
+
+/// Default oscillator gain slew for presets saved before the field existed.
+fn default_gain_slew_ms() -> f32 {
+    5.0
+}
+
+/// Default pluck damping and brightness for presets saved before the fields existed.
+fn default_pluck_tone() -> f32 {
+    0.5
 }
 
 impl Default for OscillatorParams {
@@ -94,9 +284,21 @@ impl Default for OscillatorParams {
             shape: 0.0,
             solo: false,
             additive_harmonics: [1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0], // Default: fundamental only
-            wavetable_index: 0,      // Default: first wavetable
-            wavetable_position: 0.0, // Default: no morphing
-            saturation: 0.0,         // Default: no oscillator saturation
+            wavetable_index: 0,        // Default: first wavetable
+            wavetable_position: 0.0,   // Default: no morphing
+            saturation: 0.0,           // Default: no oscillator saturation
+            additive_normalize: false, // Default: legacy amplitude-sum normalization
+            additive_gain: 1.0,
+            filter_routing: FilterRouting::Paired, // Oscillator N → Filter N
+            pluck_damping: 0.5,
+            pluck_brightness: 0.5,
+            fixed_freq: None,   // Default: follow the played note
+            sample_reduce: 0.0, // Default: full rate
+            additive_harmonics_high: [0.0; 24],
+            additive_harmonic_count: 8, // Default: the original 8-harmonic set
+            additive_slope: 1.0,
+            fm_feedback: 0.0,
+            gain_slew_ms: 5.0, // Mutes and level automation without clicks
         }
     }
 }
@@ -110,9 +312,20 @@ pub struct FilterParams {
     pub key_tracking: f32, // Key tracking amount (0.0 to 1.0)
     pub envelope: FilterEnvelopeParams,
     #[serde(default)]
-    pub drive: f32, // Pre-filter saturation drive (0.0 to 1.0)
+    pub drive: f32, // Saturation drive, placed by drive_position (0.0 to 1.0)
+    #[serde(default)]
+    pub drive_position: DrivePosition, // Whether Drive saturates before or after the filter
     #[serde(default)]
     pub post_drive: f32, // Post-filter saturation drive (0.0 to 1.0)
+    #[serde(default = "default_cutoff_slew_ms")]
+    pub cutoff_slew_ms: f32, // Cutoff smoothing time constant in ms (0.0 = instant, up to 100.0)
+    #[serde(default)]
+    pub res_comp: bool, // Turn output down as resonance rises (keeps high-Q level in check)
+}
+
+/// Default cutoff slew for presets saved before the field existed.
+fn default_cutoff_slew_ms() -> f32 {
+    5.0
 }
 
 impl Default for FilterParams {
@@ -124,8 +337,11 @@ impl Default for FilterParams {
             bandwidth: 1.0, // 1 octave for bandpass
             key_tracking: 0.0,
             envelope: FilterEnvelopeParams::default(),
-            drive: 0.0,      // Default: no pre-filter saturation
-            post_drive: 0.0, // Default: no post-filter saturation
+            drive: 0.0,                         // Default: no pre-filter saturation
+            drive_position: DrivePosition::Pre, // Drive ahead of the filter, as before
+            post_drive: 0.0,                    // Default: no post-filter saturation
+            cutoff_slew_ms: 5.0, // Fast enough to track automation without zipper noise
+            res_comp: false,     // Off: preserve the raw resonant level
         }
     }
 }
@@ -139,6 +355,8 @@ pub struct EnvelopeParams {
     pub attack_curve: f32,  // -1.0 (logarithmic) to +1.0 (exponential)
     pub decay_curve: f32,   // -1.0 (logarithmic) to +1.0 (exponential)
     pub release_curve: f32, // -1.0 (logarithmic) to +1.0 (exponential)
+    #[serde(default)]
+    pub key_scaling: f32, // 0.0 to 1.0: decay/release shorten on higher notes (halve per octave at 1.0)
 }
 
 impl Default for EnvelopeParams {
@@ -152,6 +370,7 @@ impl Default for EnvelopeParams {
             attack_curve: 0.0,  // Linear by default
             decay_curve: 0.0,   // Linear by default
             release_curve: 0.0, // Linear by default
+            key_scaling: 0.0,   // Same decay/release on every note
         }
     }
 }
@@ -211,6 +430,122 @@ pub enum DistortionType {
     Diode,
 }
 
+/// Reverb algorithm (comb/allpass tuning and decay profile)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum ReverbAlgorithm {
+    #[default]
+    Room, // Original Freeverb tuning
+    Hall,  // Longer delays, slower decay, darker tail
+    Plate, // Short dense delays, bright and diffuse
+}
+
+impl fmt::Display for ReverbAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReverbAlgorithm::Room => write!(f, "Room"),
+            ReverbAlgorithm::Hall => write!(f, "Hall"),
+            ReverbAlgorithm::Plate => write!(f, "Plate"),
+        }
+    }
+}
+
+/// Vowel imposed by the formant filter effect
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum Vowel {
+    #[default]
+    A, // "father"
+    E, // "bed"
+    I, // "see"
+    O, // "law"
+    U, // "boot"
+}
+
+impl fmt::Display for Vowel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Vowel::A => write!(f, "A"),
+            Vowel::E => write!(f, "E"),
+            Vowel::I => write!(f, "I"),
+            Vowel::O => write!(f, "O"),
+            Vowel::U => write!(f, "U"),
+        }
+    }
+}
+
+/// Global processing quality: trades CPU for anti-aliasing and interpolation fidelity
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum Quality {
+    Draft, // Short oscillator decimation filter (live / low CPU)
+    #[default]
+    Normal, // Original settings
+    High,  // Long decimation filter, oversampled distortion, cubic delay interpolation
+}
+
+impl Quality {
+    /// FIR length of the oscillators' 4:1 decimation filter
+    pub fn downsampler_taps(self) -> usize {
+        match self {
+            Quality::Draft => 8,
+            Quality::Normal => 20,
+            Quality::High => 32,
+        }
+    }
+
+    /// Whether the distortion effect runs its waveshaper at 2× rate
+    pub fn oversampled_distortion(self) -> bool {
+        self == Quality::High
+    }
+
+    /// Whether the delay reads fractional delay times with cubic interpolation
+    pub fn cubic_delay(self) -> bool {
+        self == Quality::High
+    }
+}
+
+impl fmt::Display for Quality {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Quality::Draft => write!(f, "Draft"),
+            Quality::Normal => write!(f, "Normal"),
+            Quality::High => write!(f, "High"),
+        }
+    }
+}
+
+/// Delay feedback knob response
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum FeedbackCurve {
+    #[default]
+    Linear, // Knob value is the feedback gain
+    Perceptual, // Knob spaces repeat counts exponentially (no cliff near max)
+}
+
+impl fmt::Display for FeedbackCurve {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FeedbackCurve::Linear => write!(f, "Linear"),
+            FeedbackCurve::Perceptual => write!(f, "Perceptual"),
+        }
+    }
+}
+
+/// Where an effect sits in the master chain
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum EffectRouting {
+    #[default]
+    Series, // Processes the output of the effects before it
+    Send, // Fed from the pre-effects signal; its wet return is summed at the end of the chain
+}
+
+impl fmt::Display for EffectRouting {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EffectRouting::Series => write!(f, "Series"),
+            EffectRouting::Send => write!(f, "Send"),
+        }
+    }
+}
+
 /// Tempo sync modes for LFO and effect rates
 /// Allows musical timing divisions synchronized to DAW tempo
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
@@ -336,6 +671,17 @@ pub struct LFOParams {
 
     #[serde(default)]
     pub destination: LfoDestination, // Routing destination (Global/Osc1/Osc2/Osc3)
+
+    #[serde(default = "default_lfo_pulse_width")]
+    pub pulse_width: f32, // Square duty cycle, 0.05 to 0.95 (0.5 = even high/low)
+
+    #[serde(default)]
+    pub unipolar: bool, // Output 0..1 instead of -1..1, so targets only move one way
+}
+
+/// Even square duty for presets saved before the field existed.
+fn default_lfo_pulse_width() -> f32 {
+    0.5
 }
 
 impl Default for LFOParams {
@@ -351,6 +697,8 @@ impl Default for LFOParams {
             pan_amount: 0.0,           // Disabled by default
             pwm_amount: 0.0,           // Disabled by default
             destination: LfoDestination::Global, // Default: route to all oscillators
+            pulse_width: 0.5,          // Even square
+            unipolar: false,           // Bipolar (-1..1)
         }
     }
 }
@@ -370,6 +718,31 @@ pub struct VelocityParams {
     /// Formula: `cutoff_offset = filter_sensitivity * (velocity - 0.5)`
     /// Higher velocity raises the filter cutoff, lower velocity lowers it.
     pub filter_sensitivity: f32,
+
+    /// Velocity → amp envelope attack time (0.0 = velocity doesn't affect timing, 1.0 = full)
+    ///
+    /// Formula: `attack_time = attack * (1.0 - 0.9 * attack_sensitivity * velocity)`
+    /// Harder hits attack faster, like struck and plucked instruments.
+    #[serde(default)]
+    pub attack_sensitivity: f32,
+
+    /// Velocity → amp envelope decay time (same formula as `attack_sensitivity`)
+    #[serde(default)]
+    pub decay_sensitivity: f32,
+
+    /// Velocity crossfade between oscillators 1 and 2 (0.0 = off/fixed gains, 1.0 = full)
+    ///
+    /// Formula: `osc1_gain *= 1.0 - amount * velocity`, `osc2_gain *= 1.0 - amount * (1.0 - velocity)`
+    /// Soft hits favor oscillator 1 and hard hits oscillator 2.
+    #[serde(default)]
+    pub osc_crossfade: f32,
+
+    /// Release velocity → amp envelope release time (0.0 = release velocity ignored, 1.0 = full)
+    ///
+    /// Formula: `release_time = release * (1.0 - 0.9 * release_sensitivity * release_velocity)`
+    /// Lifting a key quickly releases faster, on controllers that send note-off velocity.
+    #[serde(default)]
+    pub release_sensitivity: f32,
 }
 
 impl Default for VelocityParams {
@@ -377,6 +750,10 @@ impl Default for VelocityParams {
         Self {
             amp_sensitivity: 0.5, // Reduced from 0.7 to prevent weak sounds at velocity < 64
             filter_sensitivity: 0.5,
+            attack_sensitivity: 0.0, // Envelope timing ignores velocity
+            decay_sensitivity: 0.0,
+            osc_crossfade: 0.0,       // Oscillator gains ignore velocity
+            release_sensitivity: 0.0, // Release time ignores note-off velocity
         }
     }
 }
@@ -389,6 +766,10 @@ pub struct ReverbParams {
     pub wet: f32,       // 0.0 to 1.0
     pub dry: f32,       // 0.0 to 1.0
     pub width: f32,     // 0.0 to 1.0 (stereo width)
+    #[serde(default)]
+    pub algorithm: ReverbAlgorithm,
+    #[serde(default)]
+    pub routing: EffectRouting, // In the chain (Series) or a parallel send
 }
 
 impl Default for ReverbParams {
@@ -400,6 +781,8 @@ impl Default for ReverbParams {
             wet: 0.33,
             dry: 0.67,
             width: 1.0,
+            algorithm: ReverbAlgorithm::Room,
+            routing: EffectRouting::Series,
         }
     }
 }
@@ -407,10 +790,16 @@ impl Default for ReverbParams {
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct DelayParams {
     pub enabled: bool,
-    pub time_ms: f32,  // 1.0 to 2000.0
+    pub time_ms: f32, // 1.0 to 2000.0 (when tempo_sync = Hz)
+    #[serde(default)]
+    pub tempo_sync: TempoSync, // Tempo sync mode (Hz = free time in ms)
     pub feedback: f32, // 0.0 to 0.95
-    pub wet: f32,      // 0.0 to 1.0
-    pub dry: f32,      // 0.0 to 1.0
+    pub wet: f32,     // 0.0 to 1.0
+    pub dry: f32,     // 0.0 to 1.0
+    #[serde(default)]
+    pub feedback_curve: FeedbackCurve,
+    #[serde(default)]
+    pub routing: EffectRouting, // In the chain (Series) or a parallel send
 }
 
 impl Default for DelayParams {
@@ -418,9 +807,12 @@ impl Default for DelayParams {
         Self {
             enabled: false,
             time_ms: 500.0,
+            tempo_sync: TempoSync::Hz,
             feedback: 0.3,
             wet: 0.3,
             dry: 0.7,
+            feedback_curve: FeedbackCurve::Linear,
+            routing: EffectRouting::Series,
         }
     }
 }
@@ -433,6 +825,20 @@ pub struct ChorusParams {
     pub tempo_sync: TempoSync, // Tempo sync mode
     pub depth: f32, // 0.0 to 1.0
     pub mix: f32,  // 0.0 to 1.0
+    #[serde(default = "default_chorus_voices")]
+    pub voices: usize, // 1 to 8 chorus voices per channel
+    #[serde(default = "default_chorus_spread")]
+    pub spread: f32, // 0.0 to 1.0 (L/R modulation offset)
+}
+
+/// Default chorus voice count for presets saved before the field existed.
+fn default_chorus_voices() -> usize {
+    4
+}
+
+/// Default chorus stereo spread for presets saved before the field existed.
+fn default_chorus_spread() -> f32 {
+    0.5
 }
 
 impl Default for ChorusParams {
@@ -443,6 +849,8 @@ impl Default for ChorusParams {
             tempo_sync: TempoSync::Hz,
             depth: 0.5,
             mix: 0.5,
+            voices: 4,   // Classic 4-voice ensemble
+            spread: 0.5, // Eighth-cycle L/R offset
         }
     }
 }
@@ -662,6 +1070,22 @@ pub struct CompressorParams {
     pub ratio: f32,     // Compression ratio (1.0 to 20.0)
     pub attack: f32,    // Attack time in milliseconds
     pub release: f32,   // Release time in milliseconds
+    /// Scale the attack by the detected signal type (`SynthEngine::current_signal_type()`):
+    /// slower on tonal material, faster on noisy/percussive material
+    #[serde(default)]
+    pub adaptive: bool,
+    /// One gain for both channels (true) or independent per-channel detection (false)
+    #[serde(default = "default_compressor_stereo_link")]
+    pub stereo_link: bool,
+    #[serde(default)]
+    pub lookahead: f32, // Lookahead in milliseconds (0.0 to 10.0, 0 = none)
+    #[serde(default)]
+    pub knee: f32, // Soft-knee width in dB around the threshold (0.0 = hard knee, to 20.0)
+}
+
+/// Linked detection for presets saved before the field existed.
+fn default_compressor_stereo_link() -> bool {
+    true
 }
 
 impl Default for CompressorParams {
@@ -672,6 +1096,10 @@ impl Default for CompressorParams {
             ratio: 4.0,
             attack: 10.0,
             release: 100.0,
+            adaptive: false,
+            stereo_link: true,
+            lookahead: 0.0,
+            knee: 0.0,
         }
     }
 }
@@ -763,6 +1191,11 @@ pub struct ExciterParams {
     pub frequency: f32, // High-pass cutoff (2000.0 to 12000.0 Hz)
     pub drive: f32,     // Harmonic drive amount (0.0 to 1.0)
     pub mix: f32,       // Wet/dry mix (0.0 to 1.0)
+    /// Use the content-aware `IntelligentExciter` instead of the fixed high-pass exciter:
+    /// pitch-tracked harmonics on tonal material, bypassed on noisy or already-bright input.
+    /// `drive` sets its harmonic amount; `frequency` is unused.
+    #[serde(default)]
+    pub intelligent: bool,
 }
 
 impl Default for ExciterParams {
@@ -772,6 +1205,169 @@ impl Default for ExciterParams {
             frequency: 5000.0,
             drive: 0.5,
             mix: 0.3,
+            intelligent: false,
+        }
+    }
+}
+
+/// Formant filter parameters - vowel resonances for talk-box/vocal textures
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct FormantFilterParams {
+    pub enabled: bool,
+    pub vowel: Vowel, // Base vowel
+    pub morph: f32,   // Glide toward the next vowel (0.0 to 1.0)
+    pub mix: f32,     // Wet/dry mix (0.0 to 1.0)
+}
+
+impl Default for FormantFilterParams {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            vowel: Vowel::A,
+            morph: 0.0,
+            mix: 1.0,
+        }
+    }
+}
+
+/// Pitch shifter parameters - harmonizer-style thickening of the full output
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PitchShifterParams {
+    pub enabled: bool,
+    pub semitones: f32, // Shift interval (-12.0 to 12.0 semitones)
+    pub mix: f32,       // Wet/dry mix (0.0 to 1.0)
+}
+
+impl Default for PitchShifterParams {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            semitones: 7.0, // A fifth up
+            mix: 0.5,
+        }
+    }
+}
+
+/// Noise gate parameters - silences the output between notes (hiss, drive noise, tails)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct GateParams {
+    pub enabled: bool,
+    pub threshold: f32, // Threshold in dB (-80.0 to -20.0)
+    pub release: f32,   // Release time in milliseconds (10.0 to 1000.0)
+    /// Use the signal-aware `SmartGate` instead of the level-only `NoiseGate`: transients and
+    /// sibilance always pass, and tonal material is gated on its sustained level so a fading
+    /// note closes the gate once instead of chattering.
+    #[serde(default)]
+    pub smart: bool,
+}
+
+impl Default for GateParams {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            threshold: -50.0,
+            release: 100.0,
+            smart: false,
+        }
+    }
+}
+
+/// Sibilance EQ parameters - a high shelf that dips only while sibilance is detected
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SibilanceEqParams {
+    pub enabled: bool,
+    pub threshold: f32, // Sibilance strength the cut starts at (0.0 to 1.0)
+    pub amount: f32,    // Maximum cut (0.0 to 1.0 = 12 dB)
+}
+
+impl Default for SibilanceEqParams {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            threshold: 0.5,
+            amount: 0.5,
+        }
+    }
+}
+
+/// Master transient shaper parameters - attack and body of the whole mix, driven by
+/// transient detection (unlike the per-voice `TransientShaperParams`, which follows
+/// each voice's envelope)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct MasterTransientShaperParams {
+    pub enabled: bool,
+    pub attack: f32, // Transient gain (-1.0 = soften to 0.1×, 0.0 = neutral, 1.0 = 3×)
+    pub sustain: f32, // Gain between transients (-1.0 = 0.1×, 0.0 = neutral, 1.0 = 2×)
+}
+
+impl Default for MasterTransientShaperParams {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            attack: 0.0,
+            sustain: 0.0,
+        }
+    }
+}
+
+/// Mid/side insert parameters - independent level of the centre and the stereo
+/// difference, plus a low cut on the side signal to keep the bass mono
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct MidSideParams {
+    pub enabled: bool,
+    pub mid_gain: f32,     // Mid channel gain (0.0 to 2.0)
+    pub side_gain: f32,    // Side channel gain (0.0 to 2.0)
+    pub side_low_cut: f32, // Side high-pass in Hz (20.0 = off, up to 500.0)
+}
+
+impl Default for MidSideParams {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            mid_gain: 1.0,
+            side_gain: 1.0,
+            side_low_cut: 20.0,
+        }
+    }
+}
+
+/// Doubler parameters - a delayed copy that adapts to the material (shorter and quieter
+/// on transients, fuller on sustained tones)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct DoublerParams {
+    pub enabled: bool,
+    pub mix: f32,    // Doubling intensity (0.0 to 1.0)
+    pub spread: f32, // Stereo spread of the double (0.0 to 1.0)
+}
+
+impl Default for DoublerParams {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            mix: 0.5,
+            spread: 0.7,
+        }
+    }
+}
+
+/// Choir parameters - an ensemble of delayed copies spread across the stereo field
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ChoirParams {
+    pub enabled: bool,
+    pub voices: usize, // 2 to 8 ensemble voices
+    pub detune: f32,   // Total detune spread in cents (0.0 to 50.0)
+    pub spread: f32,   // Stereo spread (0.0 = mono, 1.0 = full width)
+    pub mix: f32,      // 0.0 to 1.0
+}
+
+impl Default for ChoirParams {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            voices: 4,
+            detune: 15.0,
+            spread: 0.8,
+            mix: 0.5,
         }
     }
 }
@@ -806,6 +1402,129 @@ pub struct EffectsParams {
     pub waveshaper: WaveshaperParams,
     #[serde(default)]
     pub exciter: ExciterParams,
+    #[serde(default)]
+    pub formant_filter: FormantFilterParams,
+    #[serde(default)]
+    pub pitch_shifter: PitchShifterParams,
+    #[serde(default)]
+    pub gate: GateParams,
+    #[serde(default)]
+    pub sibilance_eq: SibilanceEqParams,
+    #[serde(default)]
+    pub transient_shaper: MasterTransientShaperParams,
+    #[serde(default)]
+    pub mid_side: MidSideParams,
+    #[serde(default)]
+    pub doubler: DoublerParams,
+    #[serde(default)]
+    pub choir: ChoirParams,
+}
+
+/// Note repeat (roll) - held keys retrigger at a tempo-synced rate
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct NoteRepeatParams {
+    pub enabled: bool,
+    pub rate: f32,             // Repeats per second when tempo_sync = Hz (1.0 to 32.0)
+    pub tempo_sync: TempoSync, // Repeat division
+    pub gate: f32,             // Portion of each step the note is held (0.05 to 1.0)
+    pub velocity_ramp: f32,    // Velocity change per beat while the key is held (-1.0 to 1.0)
+}
+
+impl Default for NoteRepeatParams {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            rate: 8.0,
+            tempo_sync: TempoSync::Sixteenth,
+            gate: 0.5,
+            velocity_ramp: 0.0,
+        }
+    }
+}
+
+/// Number of macro knobs
+pub const MACRO_COUNT: usize = 4;
+
+/// Targets a single macro can drive
+pub const MACRO_ASSIGNMENTS: usize = 8;
+
+/// One macro target: turning the macro from 0 to 1 sweeps the parameter from `min` to `max`
+///
+/// `min` and `max` are normalized (0.0-1.0) so any registered parameter can be a target;
+/// `max` below `min` inverts the direction.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
+pub struct MacroAssignment {
+    #[serde(default)]
+    pub param_id: Option<u32>, // Registry parameter ID (None = unused slot)
+    pub min: f32, // Normalized target value at macro 0.0
+    pub max: f32, // Normalized target value at macro 1.0
+}
+
+impl MacroAssignment {
+    /// Normalized value of the target for a macro position (0.0-1.0)
+    pub fn target_value(&self, macro_value: f32) -> f32 {
+        let t = macro_value.clamp(0.0, 1.0);
+        (self.min + (self.max - self.min) * t).clamp(0.0, 1.0)
+    }
+}
+
+/// A macro knob and the parameters it drives
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
+pub struct MacroParams {
+    pub value: f32, // Macro position, 0.0 to 1.0
+    pub assignments: [MacroAssignment; MACRO_ASSIGNMENTS],
+}
+
+impl MacroParams {
+    /// Targets with a parameter assigned, with their parameter IDs
+    pub fn targets(&self) -> impl Iterator<Item = (u32, &MacroAssignment)> {
+        self.assignments
+            .iter()
+            .filter_map(|a| a.param_id.map(|param_id| (param_id, a)))
+    }
+
+    /// Assign `param_id` to sweep from `min` to `max` (normalized)
+    ///
+    /// Reassigning a parameter updates its range. Returns false if every slot is taken.
+    pub fn assign(&mut self, param_id: u32, min: f32, max: f32) -> bool {
+        let slot = match self
+            .assignments
+            .iter()
+            .position(|a| a.param_id == Some(param_id))
+        {
+            Some(index) => index,
+            None => match self.assignments.iter().position(|a| a.param_id.is_none()) {
+                Some(index) => index,
+                None => return false,
+            },
+        };
+        self.assignments[slot] = MacroAssignment {
+            param_id: Some(param_id),
+            min: min.clamp(0.0, 1.0),
+            max: max.clamp(0.0, 1.0),
+        };
+        true
+    }
+
+    /// Remove a target; returns whether it was assigned
+    pub fn unassign(&mut self, param_id: u32) -> bool {
+        match self
+            .assignments
+            .iter_mut()
+            .find(|a| a.param_id == Some(param_id))
+        {
+            Some(assignment) => {
+                *assignment = MacroAssignment::default();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Remove every target
+    pub fn clear(&mut self) {
+        self.assignments = [MacroAssignment::default(); MACRO_ASSIGNMENTS];
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
@@ -826,6 +1545,75 @@ pub struct SynthParams {
     pub monophonic: bool, // Monophonic mode - only one note at a time
     #[serde(default)]
     pub hard_sync_enabled: bool, // Hard sync chain: OSC1→OSC2→OSC3 for bright harmonics
+    #[serde(default)]
+    pub effects_bypass: bool, // Skip the whole effects chain (individual enables are preserved)
+    #[serde(default)]
+    pub input_gain_db: f32, // Drive into the effects while processing input (-24.0 to +24.0 dB)
+    #[serde(default)]
+    pub key_pan_amount: f32, // Keyboard panning around middle C (-1.0 to 1.0, positive = low notes left)
+    #[serde(default)]
+    pub poly_legato: bool, // Poly mode: reuse a just-released voice for the next note (legato)
+    #[serde(default = "default_legato_window_ms")]
+    pub legato_window_ms: f32, // Max time since release for poly legato reuse (0.0 to 500.0 ms)
+    #[serde(default)]
+    pub solo_note_mode: bool, // Poly mode: each new note releases all sounding notes first
+    #[serde(default)]
+    pub headroom_db: f32, // Attenuation before the effects chain, made up after (0.0 to 24.0 dB)
+    #[serde(default)]
+    pub filter_topology: FilterTopology, // Parallel (per-routing) or serial filter chain
+    #[serde(default)]
+    pub quality: Quality, // Anti-aliasing / interpolation quality vs CPU
+    #[serde(default)]
+    pub analog_drift: f32, // Slow random pitch/cutoff wander per voice (0.0 to 1.0)
+    #[serde(default)]
+    pub tuning_cents: f32, // Global tuning offset for every voice (-100.0 to +100.0 cents)
+    #[serde(default)]
+    pub steal_prefers_release: bool, // Voice stealing takes releasing voices before held ones
+    #[serde(default)]
+    pub max_note_seconds: f32, // Auto-release held notes after this long (0.0 = unlimited, to 600.0 s)
+    #[serde(default)]
+    pub paraphonic: bool, // All voices share one filter (filter 1) and amp envelope
+    #[serde(default)]
+    pub midi_channel: u8, // MIDI channel notes are accepted on (0 = omni, 1 to 16)
+    #[serde(default)]
+    pub retrigger_mod_on_note: bool, // Reset LFO and modulation-effect phases on each new note
+    #[serde(default)]
+    pub note_repeat: NoteRepeatParams,
+    #[serde(default)]
+    pub macros: [MacroParams; MACRO_COUNT], // Macro knobs, each driving up to 8 parameters
+    #[serde(default)]
+    pub voice_click_enabled: bool, // Highpassed noise burst at each note-on (attack click/air)
+    #[serde(default = "default_voice_click_level")]
+    pub voice_click_level: f32, // Burst level, 0.0 to 1.0
+    #[serde(default = "default_voice_click_length_ms")]
+    pub voice_click_length_ms: f32, // Burst length, 1.0 to 50.0 ms
+    #[serde(default = "default_voice_click_highpass_hz")]
+    pub voice_click_highpass_hz: f32, // Burst highpass cutoff, 500.0 to 16000.0 Hz
+    #[serde(default = "default_poly_comp_amount")]
+    pub poly_comp_amount: f32, // Level drop as voices stack (0.0 = plain sum, 1.0 = equal power)
+}
+
+/// Default poly legato window for presets saved before the field existed.
+fn default_legato_window_ms() -> f32 {
+    50.0
+}
+
+/// Poly compensation for presets saved before the amount existed (the old fixed curve).
+fn default_poly_comp_amount() -> f32 {
+    0.7
+}
+
+/// Voice click settings for presets saved before the click existed (it stays off).
+fn default_voice_click_level() -> f32 {
+    0.3
+}
+
+fn default_voice_click_length_ms() -> f32 {
+    8.0
+}
+
+fn default_voice_click_highpass_hz() -> f32 {
+    4000.0
 }
 
 impl Default for SynthParams {
@@ -860,6 +1648,134 @@ impl Default for SynthParams {
             master_gain: 1.0, // Utilize available headroom - limiter protects at 0.98 threshold
             monophonic: false,
             hard_sync_enabled: false, // Hard sync disabled by default
+            effects_bypass: false,
+            input_gain_db: 0.0, // Unity: external input passes at its original level
+            key_pan_amount: 0.0, // Keyboard panning off
+            poly_legato: false,
+            legato_window_ms: 50.0, // Catches fast repeated notes and trills
+            solo_note_mode: false,
+            headroom_db: 0.0, // Effects run at the mixed level (original gain staging)
+            filter_topology: FilterTopology::Parallel,
+            quality: Quality::Normal,
+            analog_drift: 0.0, // Perfectly stable digital pitch and cutoff
+            tuning_cents: 0.0, // A4 = 440 Hz
+            steal_prefers_release: false, // Steal purely by loudness
+            max_note_seconds: 0.0, // Notes sustain until their note-off
+            paraphonic: false, // Each voice has its own filters and envelope
+            midi_channel: 0,   // Omni: respond to every channel
+            retrigger_mod_on_note: false, // LFOs and modulation effects run freely
+            note_repeat: NoteRepeatParams::default(),
+            macros: [MacroParams::default(); MACRO_COUNT], // Unassigned
+            voice_click_enabled: false,
+            voice_click_level: 0.3,
+            voice_click_length_ms: 8.0, // Short enough to read as a click, not a hiss
+            voice_click_highpass_hz: 4000.0, // Air and tick without body
+            poly_comp_amount: 0.7,      // 1/N^0.35: tames chords while keeping them loud
+        }
+    }
+}
+
+impl SynthParams {
+    /// Copy oscillator `from`'s full settings onto oscillator `to` (0-based)
+    ///
+    /// Returns false (and changes nothing) if either index is out of range or they match.
+    pub fn copy_osc(&mut self, from: usize, to: usize) -> bool {
+        Self::copy_slot(&mut self.oscillators, from, to)
+    }
+
+    /// Copy filter `from`'s settings onto filter `to` (0-based)
+    pub fn copy_filter(&mut self, from: usize, to: usize) -> bool {
+        Self::copy_slot(&mut self.filters, from, to)
+    }
+
+    /// Copy LFO `from`'s settings onto LFO `to` (0-based)
+    pub fn copy_lfo(&mut self, from: usize, to: usize) -> bool {
+        Self::copy_slot(&mut self.lfos, from, to)
+    }
+
+    fn copy_slot<T: Copy>(slots: &mut [T], from: usize, to: usize) -> bool {
+        if from == to || from >= slots.len() || to >= slots.len() {
+            return false;
+        }
+        slots[to] = slots[from];
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_copy_osc_changes_only_the_target() {
+        let mut params = SynthParams::default();
+        params.oscillators[0].pitch = 7.0;
+        params.oscillators[0].unison = 5;
+        params.filters[1].cutoff = 1234.0;
+
+        assert!(params.copy_osc(0, 2));
+        assert_eq!(params.oscillators[2], params.oscillators[0]);
+        assert_eq!(params.oscillators[1], SynthParams::default().oscillators[1]);
+
+        assert!(params.copy_filter(1, 0));
+        assert_eq!(params.filters[0].cutoff, 1234.0);
+        assert!(params.copy_lfo(2, 1));
+
+        let before = params;
+        assert!(!params.copy_osc(1, 1));
+        assert!(!params.copy_lfo(0, 3));
+        assert_eq!(params, before);
+    }
+
+    #[test]
+    fn test_macro_assignments() {
+        let mut macro_params = MacroParams::default();
+        assert!(macro_params.assign(10, 0.2, 0.6));
+        assert!(macro_params.assign(11, 1.0, 0.0));
+        // Reassigning updates the range in place
+        assert!(macro_params.assign(10, 0.0, 0.5));
+        assert_eq!(macro_params.targets().count(), 2);
+
+        let (id, target) = macro_params.targets().next().unwrap();
+        assert_eq!((id, target.target_value(0.5)), (10, 0.25));
+        let (id, inverted) = macro_params.targets().nth(1).unwrap();
+        assert_eq!((id, inverted.target_value(0.25)), (11, 0.75));
+
+        for id in 20..(20 + MACRO_ASSIGNMENTS as u32 - 2) {
+            assert!(macro_params.assign(id, 0.0, 1.0));
+        }
+        assert!(!macro_params.assign(99, 0.0, 1.0), "all slots are taken");
+
+        assert!(macro_params.unassign(11));
+        assert!(!macro_params.unassign(11));
+        assert!(macro_params.assign(99, 0.0, 1.0));
+
+        // ID 0 (master gain) is a parameter like any other, not an empty slot
+        assert!(macro_params.unassign(99));
+        assert!(macro_params.assign(0, 0.0, 1.0));
+        assert!(macro_params.targets().any(|(id, _)| id == 0));
+        assert!(macro_params.unassign(0));
+        macro_params.clear();
+        assert_eq!(macro_params.targets().count(), 0);
+    }
+
+    #[test]
+    fn test_additive_harmonic_count_and_slope() {
+        let mut osc = OscillatorParams::default();
+        osc.set_additive_harmonic(20, 0.5);
+        assert_eq!(osc.additive_harmonics_high[12], 0.5);
+        assert_eq!(osc.additive_harmonic(20), 0.5);
+
+        // Harmonics past the count stay silent
+        assert_eq!(osc.additive_harmonic_amplitudes()[20], 0.0);
+        osc.additive_harmonic_count = 32;
+        assert_eq!(osc.additive_harmonic_amplitudes()[20], 0.5);
+
+        // The slope macro writes every active harmonic
+        osc.set_additive_slope(1.0);
+        let amplitudes = osc.additive_harmonic_amplitudes();
+        for (index, amplitude) in amplitudes.iter().enumerate() {
+            assert!((amplitude - 1.0 / (index + 1) as f32).abs() < 1e-6);
         }
     }
 }
@@ -1,11 +1,13 @@
 // Shared parameter + state system used by:
 // - dsynth-clap plugin adapters (main/kick/voice)
 // - the unified VIZIA GUI (standalone + plugin)
+pub mod ab_compare;
 pub mod gui_param_change;
 pub mod param_descriptor;
 pub mod param_registry;
 pub mod param_update;
 pub mod state;
+pub mod undo_history;
 
 // Kick drum synthesizer parameter registry
 #[cfg(feature = "kick-clap")]
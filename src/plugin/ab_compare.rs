@@ -0,0 +1,166 @@
+/// A/B patch comparison slots
+///
+/// Two full `SynthParams` snapshots the user can flip between while tweaking. The live
+/// parameters always belong to the active slot; that slot's stored copy is only refreshed
+/// when switching away (or saving), so edits are never lost by comparing.
+use crate::params::SynthParams;
+use serde::{Deserialize, Serialize};
+
+/// Which comparison slot is live
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AbSlot {
+    #[default]
+    A,
+    B,
+}
+
+impl AbSlot {
+    fn index(self) -> usize {
+        match self {
+            AbSlot::A => 0,
+            AbSlot::B => 1,
+        }
+    }
+}
+
+/// Key under which the slots are stored in the CLAP plugin state
+pub const AB_STATE_KEY: &str = "ab_compare";
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct AbCompare {
+    active: AbSlot,
+    slots: [SynthParams; 2],
+}
+
+impl Default for AbCompare {
+    fn default() -> Self {
+        Self::new(&SynthParams::default())
+    }
+}
+
+impl AbCompare {
+    /// Start with both slots holding the current patch, A active
+    pub fn new(current: &SynthParams) -> Self {
+        Self {
+            active: AbSlot::A,
+            slots: [*current; 2],
+        }
+    }
+
+    /// Currently live slot
+    pub fn active(&self) -> AbSlot {
+        self.active
+    }
+
+    /// Make `slot` live
+    ///
+    /// Stores `current` into the slot being left and returns the patch to load, or `None`
+    /// if `slot` is already active.
+    pub fn switch_to(&mut self, slot: AbSlot, current: &SynthParams) -> Option<SynthParams> {
+        if slot == self.active {
+            return None;
+        }
+        self.slots[self.active.index()] = *current;
+        self.active = slot;
+        Some(self.slots[slot.index()])
+    }
+
+    /// Copy slot A into slot B
+    ///
+    /// Returns the patch to load when B is live (it just changed under the user).
+    pub fn copy_a_to_b(&mut self, current: &SynthParams) -> Option<SynthParams> {
+        let a = match self.active {
+            AbSlot::A => *current,
+            AbSlot::B => self.slots[0],
+        };
+        self.slots = [a; 2];
+        (self.active == AbSlot::B).then_some(a)
+    }
+
+    /// Copy with the live slot refreshed from `current`, for saving
+    pub fn snapshot(&self, current: &SynthParams) -> Self {
+        let mut snapshot = self.clone();
+        snapshot.slots[self.active.index()] = *current;
+        snapshot
+    }
+
+    /// Convert both slots from before the envelope curve sign flipped
+    pub fn flip_legacy_envelope_curves(&mut self) {
+        self.slots
+            .iter_mut()
+            .for_each(super::state::flip_legacy_envelope_curves);
+    }
+
+    /// Serialize for the plugin state
+    pub fn to_json(&self) -> Result<String, super::state::StateError> {
+        serde_json::to_string(self)
+            .map_err(|e| super::state::StateError::SerializationError(e.to_string()))
+    }
+
+    /// Deserialize from the plugin state
+    pub fn from_json(json: &str) -> Result<Self, super::state::StateError> {
+        serde_json::from_str(json)
+            .map_err(|e| super::state::StateError::DeserializationError(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn with_gain(gain: f32) -> SynthParams {
+        SynthParams {
+            master_gain: gain,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_switching_keeps_edits_in_each_slot() {
+        let mut ab = AbCompare::new(&with_gain(0.1));
+
+        // Edit A to 0.2, flip to B (still the original patch)
+        assert_eq!(
+            ab.switch_to(AbSlot::B, &with_gain(0.2)),
+            Some(with_gain(0.1))
+        );
+        assert_eq!(ab.active(), AbSlot::B);
+        assert_eq!(ab.switch_to(AbSlot::B, &with_gain(0.3)), None);
+
+        // Edit B to 0.3, flip back: A kept its edit, then B kept its edit
+        assert_eq!(
+            ab.switch_to(AbSlot::A, &with_gain(0.3)),
+            Some(with_gain(0.2))
+        );
+        assert_eq!(
+            ab.switch_to(AbSlot::B, &with_gain(0.2)),
+            Some(with_gain(0.3))
+        );
+    }
+
+    #[test]
+    fn test_copy_a_to_b_and_persist() {
+        let mut ab = AbCompare::new(&with_gain(0.1));
+        assert_eq!(
+            ab.copy_a_to_b(&with_gain(0.4)),
+            None,
+            "A live: nothing to reload"
+        );
+        assert_eq!(
+            ab.switch_to(AbSlot::B, &with_gain(0.4)),
+            Some(with_gain(0.4))
+        );
+
+        let saved = ab.snapshot(&with_gain(0.5)).to_json().unwrap();
+        let mut restored = AbCompare::from_json(&saved).unwrap();
+        assert_eq!(restored.active(), AbSlot::B);
+        assert_eq!(
+            restored.switch_to(AbSlot::A, &with_gain(0.5)),
+            Some(with_gain(0.4))
+        );
+        assert_eq!(
+            restored.switch_to(AbSlot::B, &with_gain(0.4)),
+            Some(with_gain(0.5))
+        );
+    }
+}
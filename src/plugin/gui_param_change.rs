@@ -11,6 +11,9 @@ pub struct GuiParamChange {
     pub param_id: ParamId,
     /// Normalized value (0.0-1.0)
     pub normalized: f32,
+    /// One-shot edit (reset to default, typed-in value) that the host should record as a
+    /// complete begin/set/end gesture; drags are reported as plain value changes
+    pub gesture: bool,
 }
 
 impl Default for GuiParamChange {
@@ -18,6 +21,7 @@ impl Default for GuiParamChange {
         Self {
             param_id: 0,
             normalized: 0.0,
+            gesture: false,
         }
     }
 }
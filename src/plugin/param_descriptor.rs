@@ -32,16 +32,48 @@ const MODULE_ENVELOPE: u8 = 0x0A;
 const MODULE_VELOCITY: u8 = 0x0B;
 const MODULE_EFFECTS: u8 = 0x0C;
 const MODULE_VOICE: u8 = 0x0D; // Voice-level parameters (per-voice compressor)
+const MODULE_MACRO: u8 = 0x0E;
 
 // Helper function to create parameter IDs
 const fn make_param_id(module: u8, index: u32) -> ParamId {
     ((module as u32) << 24) | (index & 0xFFFFFF)
 }
 
+/// Whether a parameter is rendered per voice (oscillators, voice filters, LFOs and the
+/// amp envelope), so a host can modulate it for a single note
+pub const fn is_voice_param(id: ParamId) -> bool {
+    let module = (id >> 24) as u8;
+    module >= MODULE_OSC1 && module <= MODULE_ENVELOPE
+}
+
 // Master parameters
 pub const PARAM_MASTER_GAIN: ParamId = make_param_id(MODULE_MASTER, 0);
 pub const PARAM_MONOPHONIC: ParamId = make_param_id(MODULE_MASTER, 1);
 pub const PARAM_HARD_SYNC: ParamId = make_param_id(MODULE_MASTER, 2);
+pub const PARAM_EFFECTS_BYPASS: ParamId = make_param_id(MODULE_MASTER, 3);
+pub const PARAM_INPUT_GAIN: ParamId = make_param_id(MODULE_MASTER, 4);
+pub const PARAM_KEY_PAN: ParamId = make_param_id(MODULE_MASTER, 5);
+pub const PARAM_POLY_LEGATO: ParamId = make_param_id(MODULE_MASTER, 6);
+pub const PARAM_LEGATO_WINDOW: ParamId = make_param_id(MODULE_MASTER, 7);
+pub const PARAM_SOLO_NOTE: ParamId = make_param_id(MODULE_MASTER, 8);
+pub const PARAM_HEADROOM: ParamId = make_param_id(MODULE_MASTER, 9);
+pub const PARAM_FILTER_TOPOLOGY: ParamId = make_param_id(MODULE_MASTER, 10);
+pub const PARAM_QUALITY: ParamId = make_param_id(MODULE_MASTER, 11);
+pub const PARAM_ANALOG_DRIFT: ParamId = make_param_id(MODULE_MASTER, 12);
+pub const PARAM_TUNING: ParamId = make_param_id(MODULE_MASTER, 13);
+pub const PARAM_STEAL_RELEASE_FIRST: ParamId = make_param_id(MODULE_MASTER, 14);
+pub const PARAM_MAX_NOTE_LENGTH: ParamId = make_param_id(MODULE_MASTER, 15);
+pub const PARAM_PARAPHONIC: ParamId = make_param_id(MODULE_MASTER, 16);
+pub const PARAM_MIDI_CHANNEL: ParamId = make_param_id(MODULE_MASTER, 17);
+pub const PARAM_RETRIGGER_MOD: ParamId = make_param_id(MODULE_MASTER, 18);
+pub const PARAM_POLY_COMP: ParamId = make_param_id(MODULE_MASTER, 24);
+
+// Note repeat
+pub const PARAM_NOTE_REPEAT_ENABLED: ParamId = make_param_id(MODULE_MASTER, 19);
+pub const PARAM_NOTE_REPEAT_RATE: ParamId = make_param_id(MODULE_MASTER, 20);
+pub const PARAM_NOTE_REPEAT_TEMPO_SYNC: ParamId = make_param_id(MODULE_MASTER, 21);
+pub const PARAM_NOTE_REPEAT_GATE: ParamId = make_param_id(MODULE_MASTER, 22);
+pub const PARAM_NOTE_REPEAT_VELOCITY_RAMP: ParamId = make_param_id(MODULE_MASTER, 23);
 
 // Oscillator 1
 pub const PARAM_OSC1_WAVEFORM: ParamId = make_param_id(MODULE_OSC1, 0);
@@ -131,6 +163,9 @@ pub const PARAM_FILTER1_ENV_RELEASE: ParamId = make_param_id(MODULE_FILTER1, 8);
 pub const PARAM_FILTER1_ENV_AMOUNT: ParamId = make_param_id(MODULE_FILTER1, 9);
 pub const PARAM_FILTER1_DRIVE: ParamId = make_param_id(MODULE_FILTER1, 10);
 pub const PARAM_FILTER1_POST_DRIVE: ParamId = make_param_id(MODULE_FILTER1, 11);
+pub const PARAM_FILTER1_CUTOFF_SLEW: ParamId = make_param_id(MODULE_FILTER1, 12);
+pub const PARAM_FILTER1_RES_COMP: ParamId = make_param_id(MODULE_FILTER1, 13);
+pub const PARAM_FILTER1_DRIVE_POSITION: ParamId = make_param_id(MODULE_FILTER1, 14);
 
 // Filter 2
 pub const PARAM_FILTER2_TYPE: ParamId = make_param_id(MODULE_FILTER2, 0);
@@ -145,6 +180,9 @@ pub const PARAM_FILTER2_ENV_RELEASE: ParamId = make_param_id(MODULE_FILTER2, 8);
 pub const PARAM_FILTER2_ENV_AMOUNT: ParamId = make_param_id(MODULE_FILTER2, 9);
 pub const PARAM_FILTER2_DRIVE: ParamId = make_param_id(MODULE_FILTER2, 10);
 pub const PARAM_FILTER2_POST_DRIVE: ParamId = make_param_id(MODULE_FILTER2, 11);
+pub const PARAM_FILTER2_CUTOFF_SLEW: ParamId = make_param_id(MODULE_FILTER2, 12);
+pub const PARAM_FILTER2_RES_COMP: ParamId = make_param_id(MODULE_FILTER2, 13);
+pub const PARAM_FILTER2_DRIVE_POSITION: ParamId = make_param_id(MODULE_FILTER2, 14);
 
 // Filter 3
 pub const PARAM_FILTER3_TYPE: ParamId = make_param_id(MODULE_FILTER3, 0);
@@ -159,6 +197,9 @@ pub const PARAM_FILTER3_ENV_RELEASE: ParamId = make_param_id(MODULE_FILTER3, 8);
 pub const PARAM_FILTER3_ENV_AMOUNT: ParamId = make_param_id(MODULE_FILTER3, 9);
 pub const PARAM_FILTER3_DRIVE: ParamId = make_param_id(MODULE_FILTER3, 10);
 pub const PARAM_FILTER3_POST_DRIVE: ParamId = make_param_id(MODULE_FILTER3, 11);
+pub const PARAM_FILTER3_CUTOFF_SLEW: ParamId = make_param_id(MODULE_FILTER3, 12);
+pub const PARAM_FILTER3_RES_COMP: ParamId = make_param_id(MODULE_FILTER3, 13);
+pub const PARAM_FILTER3_DRIVE_POSITION: ParamId = make_param_id(MODULE_FILTER3, 14);
 
 // LFO 1
 pub const PARAM_LFO1_WAVEFORM: ParamId = make_param_id(MODULE_LFO1, 0);
@@ -171,6 +212,8 @@ pub const PARAM_LFO1_GAIN_AMOUNT: ParamId = make_param_id(MODULE_LFO1, 6);
 pub const PARAM_LFO1_PAN_AMOUNT: ParamId = make_param_id(MODULE_LFO1, 7);
 pub const PARAM_LFO1_PWM_AMOUNT: ParamId = make_param_id(MODULE_LFO1, 8);
 pub const PARAM_LFO1_DESTINATION: ParamId = make_param_id(MODULE_LFO1, 9);
+pub const PARAM_LFO1_PULSE_WIDTH: ParamId = make_param_id(MODULE_LFO1, 10);
+pub const PARAM_LFO1_UNIPOLAR: ParamId = make_param_id(MODULE_LFO1, 11);
 
 // LFO 2
 pub const PARAM_LFO2_WAVEFORM: ParamId = make_param_id(MODULE_LFO2, 0);
@@ -183,6 +226,8 @@ pub const PARAM_LFO2_GAIN_AMOUNT: ParamId = make_param_id(MODULE_LFO2, 6);
 pub const PARAM_LFO2_PAN_AMOUNT: ParamId = make_param_id(MODULE_LFO2, 7);
 pub const PARAM_LFO2_PWM_AMOUNT: ParamId = make_param_id(MODULE_LFO2, 8);
 pub const PARAM_LFO2_DESTINATION: ParamId = make_param_id(MODULE_LFO2, 9);
+pub const PARAM_LFO2_PULSE_WIDTH: ParamId = make_param_id(MODULE_LFO2, 10);
+pub const PARAM_LFO2_UNIPOLAR: ParamId = make_param_id(MODULE_LFO2, 11);
 
 // LFO 3
 pub const PARAM_LFO3_WAVEFORM: ParamId = make_param_id(MODULE_LFO3, 0);
@@ -195,6 +240,8 @@ pub const PARAM_LFO3_GAIN_AMOUNT: ParamId = make_param_id(MODULE_LFO3, 6);
 pub const PARAM_LFO3_PAN_AMOUNT: ParamId = make_param_id(MODULE_LFO3, 7);
 pub const PARAM_LFO3_PWM_AMOUNT: ParamId = make_param_id(MODULE_LFO3, 8);
 pub const PARAM_LFO3_DESTINATION: ParamId = make_param_id(MODULE_LFO3, 9);
+pub const PARAM_LFO3_PULSE_WIDTH: ParamId = make_param_id(MODULE_LFO3, 10);
+pub const PARAM_LFO3_UNIPOLAR: ParamId = make_param_id(MODULE_LFO3, 11);
 
 // Envelope (shared by all voices)
 pub const PARAM_ENVELOPE_ATTACK: ParamId = make_param_id(MODULE_ENVELOPE, 0);
@@ -204,10 +251,15 @@ pub const PARAM_ENVELOPE_RELEASE: ParamId = make_param_id(MODULE_ENVELOPE, 3);
 pub const PARAM_ENVELOPE_ATTACK_CURVE: ParamId = make_param_id(MODULE_ENVELOPE, 4);
 pub const PARAM_ENVELOPE_DECAY_CURVE: ParamId = make_param_id(MODULE_ENVELOPE, 5);
 pub const PARAM_ENVELOPE_RELEASE_CURVE: ParamId = make_param_id(MODULE_ENVELOPE, 6);
+pub const PARAM_ENVELOPE_KEY_SCALING: ParamId = make_param_id(MODULE_ENVELOPE, 7);
 
 // Velocity sensitivity
 pub const PARAM_VELOCITY_AMP: ParamId = make_param_id(MODULE_VELOCITY, 0);
 pub const PARAM_VELOCITY_FILTER: ParamId = make_param_id(MODULE_VELOCITY, 1);
+pub const PARAM_VELOCITY_ATTACK: ParamId = make_param_id(MODULE_VELOCITY, 2);
+pub const PARAM_VELOCITY_DECAY: ParamId = make_param_id(MODULE_VELOCITY, 3);
+pub const PARAM_VELOCITY_OSC_XFADE: ParamId = make_param_id(MODULE_VELOCITY, 4);
+pub const PARAM_VELOCITY_RELEASE: ParamId = make_param_id(MODULE_VELOCITY, 5);
 
 // Effects
 pub const PARAM_REVERB_ROOM_SIZE: ParamId = make_param_id(MODULE_EFFECTS, 0);
@@ -215,14 +267,19 @@ pub const PARAM_REVERB_DAMPING: ParamId = make_param_id(MODULE_EFFECTS, 1);
 pub const PARAM_REVERB_WET: ParamId = make_param_id(MODULE_EFFECTS, 2);
 pub const PARAM_REVERB_DRY: ParamId = make_param_id(MODULE_EFFECTS, 3);
 pub const PARAM_REVERB_WIDTH: ParamId = make_param_id(MODULE_EFFECTS, 4);
+pub const PARAM_REVERB_ALGORITHM: ParamId = make_param_id(MODULE_EFFECTS, 79);
 pub const PARAM_DELAY_TIME_MS: ParamId = make_param_id(MODULE_EFFECTS, 5);
+pub const PARAM_DELAY_TEMPO_SYNC: ParamId = make_param_id(MODULE_EFFECTS, 78);
 pub const PARAM_DELAY_FEEDBACK: ParamId = make_param_id(MODULE_EFFECTS, 6);
+pub const PARAM_DELAY_FEEDBACK_CURVE: ParamId = make_param_id(MODULE_EFFECTS, 80);
 pub const PARAM_DELAY_WET: ParamId = make_param_id(MODULE_EFFECTS, 7);
 pub const PARAM_DELAY_DRY: ParamId = make_param_id(MODULE_EFFECTS, 8);
 pub const PARAM_CHORUS_RATE: ParamId = make_param_id(MODULE_EFFECTS, 9);
 pub const PARAM_CHORUS_TEMPO_SYNC: ParamId = make_param_id(MODULE_EFFECTS, 73);
 pub const PARAM_CHORUS_DEPTH: ParamId = make_param_id(MODULE_EFFECTS, 10);
 pub const PARAM_CHORUS_MIX: ParamId = make_param_id(MODULE_EFFECTS, 11);
+pub const PARAM_CHORUS_VOICES: ParamId = make_param_id(MODULE_EFFECTS, 81);
+pub const PARAM_CHORUS_SPREAD: ParamId = make_param_id(MODULE_EFFECTS, 82);
 pub const PARAM_DISTORTION_TYPE: ParamId = make_param_id(MODULE_EFFECTS, 12);
 pub const PARAM_DISTORTION_DRIVE: ParamId = make_param_id(MODULE_EFFECTS, 13);
 pub const PARAM_DISTORTION_MIX: ParamId = make_param_id(MODULE_EFFECTS, 14);
@@ -283,6 +340,7 @@ pub const PARAM_COMPRESSOR_THRESHOLD: ParamId = make_param_id(MODULE_EFFECTS, 46
 pub const PARAM_COMPRESSOR_RATIO: ParamId = make_param_id(MODULE_EFFECTS, 47);
 pub const PARAM_COMPRESSOR_ATTACK: ParamId = make_param_id(MODULE_EFFECTS, 48);
 pub const PARAM_COMPRESSOR_RELEASE: ParamId = make_param_id(MODULE_EFFECTS, 49);
+pub const PARAM_COMPRESSOR_ADAPTIVE: ParamId = make_param_id(MODULE_EFFECTS, 98);
 
 // Bitcrusher
 pub const PARAM_BITCRUSHER_RATE: ParamId = make_param_id(MODULE_EFFECTS, 50);
@@ -297,6 +355,59 @@ pub const PARAM_EXCITER_FREQUENCY: ParamId = make_param_id(MODULE_EFFECTS, 69);
 pub const PARAM_EXCITER_DRIVE: ParamId = make_param_id(MODULE_EFFECTS, 70);
 pub const PARAM_EXCITER_MIX: ParamId = make_param_id(MODULE_EFFECTS, 71);
 pub const PARAM_EXCITER_ENABLED: ParamId = make_param_id(MODULE_EFFECTS, 72);
+pub const PARAM_EXCITER_INTELLIGENT: ParamId = make_param_id(MODULE_EFFECTS, 90);
+
+// Formant Filter
+pub const PARAM_FORMANT_VOWEL: ParamId = make_param_id(MODULE_EFFECTS, 83);
+pub const PARAM_FORMANT_MORPH: ParamId = make_param_id(MODULE_EFFECTS, 84);
+pub const PARAM_FORMANT_MIX: ParamId = make_param_id(MODULE_EFFECTS, 85);
+pub const PARAM_FORMANT_ENABLED: ParamId = make_param_id(MODULE_EFFECTS, 86);
+
+// Pitch Shifter
+pub const PARAM_PITCH_SHIFTER_SEMITONES: ParamId = make_param_id(MODULE_EFFECTS, 87);
+pub const PARAM_PITCH_SHIFTER_MIX: ParamId = make_param_id(MODULE_EFFECTS, 88);
+pub const PARAM_PITCH_SHIFTER_ENABLED: ParamId = make_param_id(MODULE_EFFECTS, 89);
+
+// Noise Gate
+pub const PARAM_GATE_THRESHOLD: ParamId = make_param_id(MODULE_EFFECTS, 91);
+pub const PARAM_GATE_RELEASE: ParamId = make_param_id(MODULE_EFFECTS, 92);
+pub const PARAM_GATE_SMART: ParamId = make_param_id(MODULE_EFFECTS, 93);
+pub const PARAM_GATE_ENABLED: ParamId = make_param_id(MODULE_EFFECTS, 94);
+
+// Sibilance EQ
+pub const PARAM_SIBILANCE_EQ_THRESHOLD: ParamId = make_param_id(MODULE_EFFECTS, 95);
+pub const PARAM_SIBILANCE_EQ_AMOUNT: ParamId = make_param_id(MODULE_EFFECTS, 96);
+pub const PARAM_SIBILANCE_EQ_ENABLED: ParamId = make_param_id(MODULE_EFFECTS, 97);
+
+// Master Transient Shaper
+pub const PARAM_MASTER_TRANSIENT_ATTACK: ParamId = make_param_id(MODULE_EFFECTS, 99);
+pub const PARAM_MASTER_TRANSIENT_SUSTAIN: ParamId = make_param_id(MODULE_EFFECTS, 100);
+pub const PARAM_MASTER_TRANSIENT_ENABLED: ParamId = make_param_id(MODULE_EFFECTS, 101);
+
+// Mid/Side
+pub const PARAM_MID_SIDE_MID_GAIN: ParamId = make_param_id(MODULE_EFFECTS, 102);
+pub const PARAM_MID_SIDE_SIDE_GAIN: ParamId = make_param_id(MODULE_EFFECTS, 103);
+pub const PARAM_MID_SIDE_LOW_CUT: ParamId = make_param_id(MODULE_EFFECTS, 104);
+pub const PARAM_MID_SIDE_ENABLED: ParamId = make_param_id(MODULE_EFFECTS, 105);
+
+// Doubler
+pub const PARAM_DOUBLER_MIX: ParamId = make_param_id(MODULE_EFFECTS, 106);
+pub const PARAM_DOUBLER_SPREAD: ParamId = make_param_id(MODULE_EFFECTS, 107);
+pub const PARAM_DOUBLER_ENABLED: ParamId = make_param_id(MODULE_EFFECTS, 108);
+
+// Choir
+pub const PARAM_CHOIR_VOICES: ParamId = make_param_id(MODULE_EFFECTS, 109);
+pub const PARAM_CHOIR_DETUNE: ParamId = make_param_id(MODULE_EFFECTS, 110);
+pub const PARAM_CHOIR_SPREAD: ParamId = make_param_id(MODULE_EFFECTS, 111);
+pub const PARAM_CHOIR_MIX: ParamId = make_param_id(MODULE_EFFECTS, 112);
+pub const PARAM_CHOIR_ENABLED: ParamId = make_param_id(MODULE_EFFECTS, 113);
+
+// Series/send routing for the time-based effects
+pub const PARAM_DELAY_ROUTING: ParamId = make_param_id(MODULE_EFFECTS, 114);
+pub const PARAM_REVERB_ROUTING: ParamId = make_param_id(MODULE_EFFECTS, 115);
+pub const PARAM_COMPRESSOR_STEREO_LINK: ParamId = make_param_id(MODULE_EFFECTS, 116);
+pub const PARAM_COMPRESSOR_LOOKAHEAD: ParamId = make_param_id(MODULE_EFFECTS, 117);
+pub const PARAM_COMPRESSOR_KNEE: ParamId = make_param_id(MODULE_EFFECTS, 118);
 
 // Effect enable/disable toggles (IDs 54-62)
 pub const PARAM_PHASER_ENABLED: ParamId = make_param_id(MODULE_EFFECTS, 54);
@@ -322,6 +433,64 @@ pub const PARAM_OSC1_UNISON_NORMALIZE: ParamId = make_param_id(MODULE_OSC1, 22);
 pub const PARAM_OSC2_UNISON_NORMALIZE: ParamId = make_param_id(MODULE_OSC2, 22);
 pub const PARAM_OSC3_UNISON_NORMALIZE: ParamId = make_param_id(MODULE_OSC3, 22);
 
+// Additive level compensation (per oscillator)
+pub const PARAM_OSC1_ADDITIVE_NORMALIZE: ParamId = make_param_id(MODULE_OSC1, 24);
+pub const PARAM_OSC1_ADDITIVE_GAIN: ParamId = make_param_id(MODULE_OSC1, 25);
+pub const PARAM_OSC2_ADDITIVE_NORMALIZE: ParamId = make_param_id(MODULE_OSC2, 24);
+pub const PARAM_OSC2_ADDITIVE_GAIN: ParamId = make_param_id(MODULE_OSC2, 25);
+pub const PARAM_OSC3_ADDITIVE_NORMALIZE: ParamId = make_param_id(MODULE_OSC3, 24);
+pub const PARAM_OSC3_ADDITIVE_GAIN: ParamId = make_param_id(MODULE_OSC3, 25);
+
+// Oscillator → filter routing (per oscillator)
+pub const PARAM_OSC1_FILTER_ROUTING: ParamId = make_param_id(MODULE_OSC1, 26);
+pub const PARAM_OSC2_FILTER_ROUTING: ParamId = make_param_id(MODULE_OSC2, 26);
+pub const PARAM_OSC3_FILTER_ROUTING: ParamId = make_param_id(MODULE_OSC3, 26);
+
+// Pluck (Karplus-Strong) string tone (per oscillator)
+pub const PARAM_OSC1_PLUCK_DAMPING: ParamId = make_param_id(MODULE_OSC1, 27);
+pub const PARAM_OSC1_PLUCK_BRIGHTNESS: ParamId = make_param_id(MODULE_OSC1, 28);
+pub const PARAM_OSC2_PLUCK_DAMPING: ParamId = make_param_id(MODULE_OSC2, 27);
+pub const PARAM_OSC2_PLUCK_BRIGHTNESS: ParamId = make_param_id(MODULE_OSC2, 28);
+pub const PARAM_OSC3_PLUCK_DAMPING: ParamId = make_param_id(MODULE_OSC3, 27);
+pub const PARAM_OSC3_PLUCK_BRIGHTNESS: ParamId = make_param_id(MODULE_OSC3, 28);
+
+// Fixed-frequency mode (per oscillator)
+pub const PARAM_OSC1_FIXED_FREQ: ParamId = make_param_id(MODULE_OSC1, 29);
+pub const PARAM_OSC1_FIXED_FREQ_HZ: ParamId = make_param_id(MODULE_OSC1, 30);
+pub const PARAM_OSC2_FIXED_FREQ: ParamId = make_param_id(MODULE_OSC2, 29);
+pub const PARAM_OSC2_FIXED_FREQ_HZ: ParamId = make_param_id(MODULE_OSC2, 30);
+pub const PARAM_OSC3_FIXED_FREQ: ParamId = make_param_id(MODULE_OSC3, 29);
+pub const PARAM_OSC3_FIXED_FREQ_HZ: ParamId = make_param_id(MODULE_OSC3, 30);
+
+// Pre-filter sample-rate reduction (per oscillator)
+pub const PARAM_OSC1_SAMPLE_REDUCE: ParamId = make_param_id(MODULE_OSC1, 31);
+pub const PARAM_OSC2_SAMPLE_REDUCE: ParamId = make_param_id(MODULE_OSC2, 31);
+pub const PARAM_OSC3_SAMPLE_REDUCE: ParamId = make_param_id(MODULE_OSC3, 31);
+
+// Extended additive harmonics 9-32 (contiguous ranges), harmonic count and slope macro
+pub const PARAM_OSC1_H9: ParamId = make_param_id(MODULE_OSC1, 32);
+pub const PARAM_OSC1_H32: ParamId = make_param_id(MODULE_OSC1, 55);
+pub const PARAM_OSC1_HARMONIC_COUNT: ParamId = make_param_id(MODULE_OSC1, 56);
+pub const PARAM_OSC1_HARMONIC_SLOPE: ParamId = make_param_id(MODULE_OSC1, 57);
+pub const PARAM_OSC2_H9: ParamId = make_param_id(MODULE_OSC2, 32);
+pub const PARAM_OSC2_H32: ParamId = make_param_id(MODULE_OSC2, 55);
+pub const PARAM_OSC2_HARMONIC_COUNT: ParamId = make_param_id(MODULE_OSC2, 56);
+pub const PARAM_OSC2_HARMONIC_SLOPE: ParamId = make_param_id(MODULE_OSC2, 57);
+pub const PARAM_OSC3_H9: ParamId = make_param_id(MODULE_OSC3, 32);
+pub const PARAM_OSC3_H32: ParamId = make_param_id(MODULE_OSC3, 55);
+pub const PARAM_OSC3_HARMONIC_COUNT: ParamId = make_param_id(MODULE_OSC3, 56);
+pub const PARAM_OSC3_HARMONIC_SLOPE: ParamId = make_param_id(MODULE_OSC3, 57);
+
+// Self-feedback FM
+pub const PARAM_OSC1_FM_FEEDBACK: ParamId = make_param_id(MODULE_OSC1, 58);
+pub const PARAM_OSC2_FM_FEEDBACK: ParamId = make_param_id(MODULE_OSC2, 58);
+pub const PARAM_OSC3_FM_FEEDBACK: ParamId = make_param_id(MODULE_OSC3, 58);
+
+// Gain smoothing (anti-click)
+pub const PARAM_OSC1_GAIN_SLEW: ParamId = make_param_id(MODULE_OSC1, 59);
+pub const PARAM_OSC2_GAIN_SLEW: ParamId = make_param_id(MODULE_OSC2, 59);
+pub const PARAM_OSC3_GAIN_SLEW: ParamId = make_param_id(MODULE_OSC3, 59);
+
 // Voice Compressor (per-voice transient control)
 pub const PARAM_VOICE_COMP_ENABLED: ParamId = make_param_id(MODULE_VOICE, 0);
 pub const PARAM_VOICE_COMP_THRESHOLD: ParamId = make_param_id(MODULE_VOICE, 1);
@@ -334,6 +503,24 @@ pub const PARAM_TRANSIENT_ENABLED: ParamId = make_param_id(MODULE_VOICE, 7);
 pub const PARAM_TRANSIENT_ATTACK: ParamId = make_param_id(MODULE_VOICE, 8);
 pub const PARAM_TRANSIENT_SUSTAIN: ParamId = make_param_id(MODULE_VOICE, 9);
 
+// Voice click (noise burst at note-on)
+pub const PARAM_VOICE_CLICK_ENABLED: ParamId = make_param_id(MODULE_VOICE, 10);
+pub const PARAM_VOICE_CLICK_LEVEL: ParamId = make_param_id(MODULE_VOICE, 11);
+pub const PARAM_VOICE_CLICK_LENGTH: ParamId = make_param_id(MODULE_VOICE, 12);
+pub const PARAM_VOICE_CLICK_HIGHPASS: ParamId = make_param_id(MODULE_VOICE, 13);
+
+// Macro knobs (each drives the parameters assigned to it)
+pub const PARAM_MACRO1: ParamId = make_param_id(MODULE_MACRO, 0);
+pub const PARAM_MACRO2: ParamId = make_param_id(MODULE_MACRO, 1);
+pub const PARAM_MACRO3: ParamId = make_param_id(MODULE_MACRO, 2);
+pub const PARAM_MACRO4: ParamId = make_param_id(MODULE_MACRO, 3);
+pub const PARAM_MACROS: [ParamId; 4] = [PARAM_MACRO1, PARAM_MACRO2, PARAM_MACRO3, PARAM_MACRO4];
+
+/// Whether a parameter is a macro knob (macros can't target other macros)
+pub const fn is_macro_param(id: ParamId) -> bool {
+    (id >> 24) as u8 == MODULE_MACRO
+}
+
 /// Value skewing for logarithmic/exponential parameter curves
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum ValueSkew {
@@ -378,7 +565,8 @@ pub struct ParamDescriptor {
     pub id: ParamId,
     /// Human-readable name ("Master Gain", "Osc 1 Pitch", etc.)
     pub name: String,
-    /// Module name for grouping ("Master", "Oscillator 1", "Filter 1", etc.)
+    /// Module name for grouping ("Master", "Oscillator 1", "Filter 1", etc.); a "/" starts
+    /// a sub-level in the host's parameter tree ("Oscillator 1/Additive")
     pub module: String,
     /// Parameter type (Float, Bool, Enum, Int)
     pub param_type: ParamType,
@@ -718,6 +906,91 @@ impl ParamDescriptor {
             }
         }
     }
+
+    /// Module as a "/"-separated path, so hosts can show the parameters as a tree
+    ///
+    /// Effects share one ID namespace and are grouped under "Effects" ("Effects/Reverb");
+    /// other modules carry any sub-levels in their name ("Oscillator 1/Additive").
+    pub fn module_path(&self) -> String {
+        if (self.id >> 24) as u8 == MODULE_EFFECTS {
+            format!("Effects/{}", self.module)
+        } else {
+            self.module.clone()
+        }
+    }
+
+    /// Hover text for a control: name and module, current value, default, and range
+    ///
+    /// Values go through `format_value`, so log/exp parameters show their real
+    /// (denormalized) values and units.
+    pub fn tooltip_text(&self, normalized: f32) -> String {
+        let mut text = format!(
+            "{} ({})\n{}\nDefault: {}",
+            self.name,
+            self.module,
+            self.format_value(normalized),
+            self.format_value(self.default)
+        );
+        if matches!(
+            self.param_type,
+            ParamType::Float { .. } | ParamType::Int { .. }
+        ) {
+            text.push_str(&format!(
+                "\nRange: {} to {}",
+                self.format_value(0.0),
+                self.format_value(1.0)
+            ));
+        }
+        text
+    }
+
+    /// Parse user-typed text into a normalized value (inverse of `format_value`)
+    ///
+    /// Accepts a bare number in the parameter's display units, optionally followed by the
+    /// unit ("440 Hz", "-6dB", "50%"). A `k` multiplier is honored ("1k", "2.5kHz"), and
+    /// "s"/"ms" convert between each other. Bools accept on/off, enums accept a variant name.
+    /// Out-of-range values are clamped; returns `None` when the text can't be understood.
+    pub fn parse_value(&self, text: &str) -> Option<f32> {
+        let text = text.trim();
+        match &self.param_type {
+            ParamType::Float { .. } | ParamType::Int { .. } => {
+                let split = text
+                    .find(|c: char| !(c.is_ascii_digit() || matches!(c, '.' | '-' | '+')))
+                    .unwrap_or(text.len());
+                let mut value: f32 = text[..split].trim().parse().ok()?;
+                let mut suffix = text[split..].trim().to_lowercase();
+
+                if let Some(rest) = suffix.strip_prefix('k') {
+                    value *= 1000.0;
+                    suffix = rest.trim_start().to_string();
+                }
+
+                let unit = self.unit.as_deref().unwrap_or("").to_lowercase();
+                match (unit.as_str(), suffix.as_str()) {
+                    ("%", _) if suffix.is_empty() || suffix == "%" => value /= 100.0,
+                    (_, "") => {}
+                    ("ms", "s") => value *= 1000.0,
+                    ("s", "ms") => value /= 1000.0,
+                    (unit, suffix) if unit == suffix => {}
+                    _ => return None,
+                }
+
+                value.is_finite().then(|| self.normalize_value(value))
+            }
+            ParamType::Bool => match text.to_lowercase().as_str() {
+                "on" | "true" | "yes" | "1" => Some(1.0),
+                "off" | "false" | "no" | "0" => Some(0.0),
+                _ => None,
+            },
+            ParamType::Enum { variants } => {
+                let index = variants
+                    .iter()
+                    .position(|v| v.eq_ignore_ascii_case(text))
+                    .or_else(|| text.parse::<usize>().ok())?;
+                (index < variants.len()).then(|| self.normalize_value(index as f32))
+            }
+        }
+    }
 }
 
 impl fmt::Display for ParamDescriptor {
@@ -806,4 +1079,53 @@ mod tests {
         assert_eq!(osc1_module, MODULE_OSC1);
         assert_eq!(filter1_module, MODULE_FILTER1);
     }
+
+    #[test]
+    fn test_parse_value_honors_units() {
+        let cutoff =
+            ParamDescriptor::float_log(0, "Cutoff", "Test", 20.0, 20000.0, 1000.0, Some("Hz"));
+        for text in ["1k", "1000", "1000 Hz", "1kHz", "1 khz"] {
+            let normalized = cutoff.parse_value(text).expect(text);
+            assert!(
+                (cutoff.denormalize(normalized) - 1000.0).abs() < 0.5,
+                "{text}"
+            );
+        }
+        assert_eq!(cutoff.parse_value("99k"), Some(1.0), "Clamped to max");
+        assert_eq!(cutoff.parse_value("loud"), None);
+        assert_eq!(cutoff.parse_value("10 dB"), None, "Wrong unit rejected");
+
+        let time = ParamDescriptor::float(0, "Time", "Test", 0.0, 2000.0, 250.0, Some("ms"));
+        assert!((time.denormalize(time.parse_value("1.5s").unwrap()) - 1500.0).abs() < 0.01);
+
+        let mix = ParamDescriptor::float(0, "Mix", "Test", 0.0, 1.0, 0.5, Some("%"));
+        assert!((mix.parse_value("25%").unwrap() - 0.25).abs() < 1e-6);
+
+        let mode = ParamDescriptor::enum_param(
+            0,
+            "Mode",
+            "Test",
+            vec!["Lowpass".to_string(), "Highpass".to_string()],
+            0,
+        );
+        assert_eq!(mode.parse_value("highpass"), Some(1.0));
+        assert_eq!(mode.parse_value("Bandpass"), None);
+    }
+
+    #[test]
+    fn test_tooltip_shows_value_default_and_range() {
+        let cutoff =
+            ParamDescriptor::float_log(0, "Cutoff", "Filter 1", 20.0, 20000.0, 1000.0, Some("Hz"));
+        let text = cutoff.tooltip_text(cutoff.normalize_value(440.0));
+        assert_eq!(
+            text,
+            "Cutoff (Filter 1)\n440 Hz\nDefault: 1000 Hz\nRange: 20.0 Hz to 20000 Hz"
+        );
+
+        let enabled = ParamDescriptor::bool(0, "Enabled", "Reverb", false);
+        assert_eq!(
+            enabled.tooltip_text(1.0),
+            "Enabled (Reverb)\nOn\nDefault: Off"
+        );
+    }
 }
@@ -69,6 +69,228 @@ impl ParamRegistry {
             PARAM_HARD_SYNC,
             ParamDescriptor::bool(PARAM_HARD_SYNC, "Hard Sync (1→2→3)", "Master", false)
         );
+        add_param!(
+            PARAM_EFFECTS_BYPASS,
+            ParamDescriptor::bool(PARAM_EFFECTS_BYPASS, "Bypass Effects", "Master", false)
+        );
+        add_param!(
+            PARAM_INPUT_GAIN,
+            ParamDescriptor::float(
+                PARAM_INPUT_GAIN,
+                "Input Gain",
+                "Master",
+                -24.0,
+                24.0,
+                0.0,
+                Some("dB")
+            )
+        );
+        add_param!(
+            PARAM_KEY_PAN,
+            ParamDescriptor::float(PARAM_KEY_PAN, "Key Pan", "Master", -1.0, 1.0, 0.0, None)
+        );
+        add_param!(
+            PARAM_POLY_LEGATO,
+            ParamDescriptor::bool(PARAM_POLY_LEGATO, "Poly Legato", "Master", false)
+        );
+        add_param!(
+            PARAM_LEGATO_WINDOW,
+            ParamDescriptor::float(
+                PARAM_LEGATO_WINDOW,
+                "Legato Window",
+                "Master",
+                0.0,
+                500.0,
+                50.0,
+                Some("ms")
+            )
+        );
+        add_param!(
+            PARAM_SOLO_NOTE,
+            ParamDescriptor::bool(PARAM_SOLO_NOTE, "Solo Note", "Master", false)
+        );
+        add_param!(
+            PARAM_HEADROOM,
+            ParamDescriptor::float(
+                PARAM_HEADROOM,
+                "Headroom",
+                "Master",
+                0.0,
+                24.0,
+                0.0,
+                Some("dB")
+            )
+        );
+        add_param!(
+            PARAM_FILTER_TOPOLOGY,
+            ParamDescriptor::enum_param(
+                PARAM_FILTER_TOPOLOGY,
+                "Filter Topology",
+                "Master",
+                vec!["Parallel".into(), "Serial".into()],
+                0 // Default: Parallel (original per-oscillator filtering)
+            )
+        );
+        add_param!(
+            PARAM_QUALITY,
+            ParamDescriptor::enum_param(
+                PARAM_QUALITY,
+                "Quality",
+                "Master",
+                vec!["Draft".into(), "Normal".into(), "High".into()],
+                1 // Default: Normal
+            )
+        );
+        add_param!(
+            PARAM_ANALOG_DRIFT,
+            ParamDescriptor::float(PARAM_ANALOG_DRIFT, "Analog Drift", "Master", 0.0, 1.0, 0.0, None)
+        );
+        add_param!(
+            PARAM_TUNING,
+            ParamDescriptor::float(
+                PARAM_TUNING,
+                "Tuning",
+                "Master",
+                -100.0,
+                100.0,
+                0.0,
+                Some("cents")
+            )
+        );
+        add_param!(
+            PARAM_STEAL_RELEASE_FIRST,
+            ParamDescriptor::bool(
+                PARAM_STEAL_RELEASE_FIRST,
+                "Steal Releasing First",
+                "Master",
+                false
+            )
+        );
+        add_param!(
+            PARAM_MAX_NOTE_LENGTH,
+            ParamDescriptor::float(
+                PARAM_MAX_NOTE_LENGTH,
+                "Max Note Length",
+                "Master",
+                0.0,
+                600.0,
+                0.0, // Unlimited
+                Some("s")
+            )
+        );
+        add_param!(
+            PARAM_PARAPHONIC,
+            ParamDescriptor::bool(PARAM_PARAPHONIC, "Paraphonic", "Master", false)
+        );
+        add_param!(
+            PARAM_MIDI_CHANNEL,
+            ParamDescriptor::int(
+                PARAM_MIDI_CHANNEL,
+                "MIDI Channel",
+                "Master",
+                0,
+                16,
+                0 // Omni
+            )
+        );
+        add_param!(
+            PARAM_RETRIGGER_MOD,
+            ParamDescriptor::bool(PARAM_RETRIGGER_MOD, "Retrigger Mod", "Master", false)
+        );
+        add_param!(
+            PARAM_POLY_COMP,
+            ParamDescriptor::float(
+                PARAM_POLY_COMP,
+                "Poly Comp",
+                "Master",
+                0.0,
+                1.0,
+                0.7, // The original 1/N^0.35 curve
+                Some("%")
+            )
+        );
+
+        // Note repeat
+        add_param!(
+            PARAM_NOTE_REPEAT_ENABLED,
+            ParamDescriptor::bool(PARAM_NOTE_REPEAT_ENABLED, "Enabled", "Note Repeat", false)
+        );
+        add_param!(
+            PARAM_NOTE_REPEAT_RATE,
+            ParamDescriptor::float_log(
+                PARAM_NOTE_REPEAT_RATE,
+                "Rate",
+                "Note Repeat",
+                1.0,
+                32.0,
+                8.0,
+                Some("Hz")
+            )
+        );
+        add_param!(
+            PARAM_NOTE_REPEAT_TEMPO_SYNC,
+            ParamDescriptor::enum_param(
+                PARAM_NOTE_REPEAT_TEMPO_SYNC,
+                "Sync",
+                "Note Repeat",
+                vec![
+                    "Hz".into(),
+                    "1/1".into(),
+                    "1/2".into(),
+                    "1/4".into(),
+                    "1/8".into(),
+                    "1/16".into(),
+                    "1/32".into(),
+                    "1/4T".into(),
+                    "1/8T".into(),
+                    "1/16T".into(),
+                    "1/4D".into(),
+                    "1/8D".into(),
+                    "1/16D".into(),
+                ],
+                5 // Default: 1/16
+            )
+        );
+        add_param!(
+            PARAM_NOTE_REPEAT_GATE,
+            ParamDescriptor::float(
+                PARAM_NOTE_REPEAT_GATE,
+                "Gate",
+                "Note Repeat",
+                0.05,
+                1.0,
+                0.5,
+                None
+            )
+        );
+        add_param!(
+            PARAM_NOTE_REPEAT_VELOCITY_RAMP,
+            ParamDescriptor::float(
+                PARAM_NOTE_REPEAT_VELOCITY_RAMP,
+                "Velocity Ramp",
+                "Note Repeat",
+                -1.0,
+                1.0,
+                0.0,
+                None
+            )
+        );
+
+        // Macros
+        for (index, &id) in PARAM_MACROS.iter().enumerate() {
+            add_param!(
+                id,
+                ParamDescriptor::float(
+                    id,
+                    format!("Macro {}", index + 1),
+                    "Macros",
+                    0.0,
+                    1.0,
+                    0.0,
+                    None
+                )
+            );
+        }
 
         // Oscillator 1
         add_param!(
@@ -87,6 +309,9 @@ impl ParamRegistry {
                     "Pink Noise".into(),
                     "Additive".into(),
                     "Wavetable".into(),
+                    "Analog Saw".into(),
+                    "Analog Square".into(),
+                    "Pluck".into(),
                 ],
                 0 // Default: Sine
             )
@@ -248,6 +473,9 @@ impl ParamRegistry {
                     "Pink Noise".into(),
                     "Additive".into(),
                     "Wavetable".into(),
+                    "Analog Saw".into(),
+                    "Analog Square".into(),
+                    "Pluck".into(),
                 ],
                 1 // Default: Saw
             )
@@ -409,6 +637,9 @@ impl ParamRegistry {
                     "Pink Noise".into(),
                     "Additive".into(),
                     "Wavetable".into(),
+                    "Analog Saw".into(),
+                    "Analog Square".into(),
+                    "Pluck".into(),
                 ],
                 2 // Default: Square
             )
@@ -708,6 +939,36 @@ impl ParamRegistry {
                     Some("%")
                 )
             );
+
+            add_param!(
+                base_id + 12,
+                ParamDescriptor::float_exp(
+                    base_id + 12,
+                    "Cutoff Slew",
+                    &module,
+                    0.0,
+                    100.0,
+                    5.0,
+                    2.0,
+                    Some("ms")
+                )
+            );
+
+            add_param!(
+                base_id + 13,
+                ParamDescriptor::bool(base_id + 13, "Resonance Comp", &module, false)
+            );
+
+            add_param!(
+                base_id + 14,
+                ParamDescriptor::enum_param(
+                    base_id + 14,
+                    "Drive Position",
+                    &module,
+                    vec!["Pre".into(), "Post".into()],
+                    0 // Default: Pre (drive ahead of the filter)
+                )
+            );
         }
 
         // LFOs
@@ -838,6 +1099,24 @@ impl ParamRegistry {
                     0 // Default: Global
                 )
             );
+
+            add_param!(
+                base_id + 10,
+                ParamDescriptor::float(
+                    base_id + 10,
+                    "Pulse Width",
+                    &module,
+                    0.05,
+                    0.95,
+                    0.5,
+                    Some("")
+                )
+            );
+
+            add_param!(
+                base_id + 11,
+                ParamDescriptor::bool(base_id + 11, "Unipolar", &module, false)
+            );
         }
 
         // Envelope (ADSR)
@@ -925,6 +1204,18 @@ impl ParamRegistry {
                 Some("")
             )
         );
+        add_param!(
+            PARAM_ENVELOPE_KEY_SCALING,
+            ParamDescriptor::float(
+                PARAM_ENVELOPE_KEY_SCALING,
+                "Key Scaling",
+                "Envelope",
+                0.0,
+                1.0,
+                0.0,
+                Some("")
+            )
+        );
 
         // Velocity
         add_param!(
@@ -951,6 +1242,54 @@ impl ParamRegistry {
                 Some("")
             )
         );
+        add_param!(
+            PARAM_VELOCITY_ATTACK,
+            ParamDescriptor::float(
+                PARAM_VELOCITY_ATTACK,
+                "Attack",
+                "Velocity",
+                0.0,
+                1.0,
+                0.0,
+                Some("")
+            )
+        );
+        add_param!(
+            PARAM_VELOCITY_DECAY,
+            ParamDescriptor::float(
+                PARAM_VELOCITY_DECAY,
+                "Decay",
+                "Velocity",
+                0.0,
+                1.0,
+                0.0,
+                Some("")
+            )
+        );
+        add_param!(
+            PARAM_VELOCITY_OSC_XFADE,
+            ParamDescriptor::float(
+                PARAM_VELOCITY_OSC_XFADE,
+                "Osc Crossfade",
+                "Velocity",
+                0.0,
+                1.0,
+                0.0,
+                Some("")
+            )
+        );
+        add_param!(
+            PARAM_VELOCITY_RELEASE,
+            ParamDescriptor::float(
+                PARAM_VELOCITY_RELEASE,
+                "Release Velocity",
+                "Velocity",
+                0.0,
+                1.0,
+                0.0,
+                Some("")
+            )
+        );
 
         // Effects
         add_param!(
@@ -997,6 +1336,26 @@ impl ParamRegistry {
                 Some("")
             )
         );
+        add_param!(
+            PARAM_REVERB_ALGORITHM,
+            ParamDescriptor::enum_param(
+                PARAM_REVERB_ALGORITHM,
+                "Algorithm",
+                "Reverb",
+                vec!["Room".into(), "Hall".into(), "Plate".into()],
+                0 // Default: Room (original tuning)
+            )
+        );
+        add_param!(
+            PARAM_REVERB_ROUTING,
+            ParamDescriptor::enum_param(
+                PARAM_REVERB_ROUTING,
+                "Routing",
+                "Reverb",
+                vec!["Series".into(), "Send".into()],
+                0 // Default: Series (matches existing presets)
+            )
+        );
 
         add_param!(
             PARAM_DELAY_TIME_MS,
@@ -1010,6 +1369,30 @@ impl ParamRegistry {
                 Some("ms")
             )
         );
+        add_param!(
+            PARAM_DELAY_TEMPO_SYNC,
+            ParamDescriptor::enum_param(
+                PARAM_DELAY_TEMPO_SYNC,
+                "Sync",
+                "Delay",
+                vec![
+                    "Hz".into(),
+                    "1/1".into(),
+                    "1/2".into(),
+                    "1/4".into(),
+                    "1/8".into(),
+                    "1/16".into(),
+                    "1/32".into(),
+                    "1/4T".into(),
+                    "1/8T".into(),
+                    "1/16T".into(),
+                    "1/4D".into(),
+                    "1/8D".into(),
+                    "1/16D".into(),
+                ],
+                0 // Default: Hz (free time in ms)
+            )
+        );
         add_param!(
             PARAM_DELAY_FEEDBACK,
             ParamDescriptor::float(
@@ -1023,13 +1406,33 @@ impl ParamRegistry {
             )
         );
         add_param!(
-            PARAM_DELAY_WET,
-            ParamDescriptor::float(PARAM_DELAY_WET, "Wet", "Delay", 0.0, 1.0, 0.3, Some(""))
+            PARAM_DELAY_FEEDBACK_CURVE,
+            ParamDescriptor::enum_param(
+                PARAM_DELAY_FEEDBACK_CURVE,
+                "Feedback Curve",
+                "Delay",
+                vec!["Linear".into(), "Perceptual".into()],
+                0 // Default: Linear (matches existing presets)
+            )
         );
         add_param!(
-            PARAM_DELAY_DRY,
-            ParamDescriptor::float(PARAM_DELAY_DRY, "Dry", "Delay", 0.0, 1.0, 0.7, Some(""))
-        );
+            PARAM_DELAY_ROUTING,
+            ParamDescriptor::enum_param(
+                PARAM_DELAY_ROUTING,
+                "Routing",
+                "Delay",
+                vec!["Series".into(), "Send".into()],
+                0 // Default: Series (matches existing presets)
+            )
+        );
+        add_param!(
+            PARAM_DELAY_WET,
+            ParamDescriptor::float(PARAM_DELAY_WET, "Wet", "Delay", 0.0, 1.0, 0.3, Some(""))
+        );
+        add_param!(
+            PARAM_DELAY_DRY,
+            ParamDescriptor::float(PARAM_DELAY_DRY, "Dry", "Delay", 0.0, 1.0, 0.7, Some(""))
+        );
 
         add_param!(
             PARAM_CHORUS_RATE,
@@ -1083,6 +1486,14 @@ impl ParamRegistry {
             PARAM_CHORUS_MIX,
             ParamDescriptor::float(PARAM_CHORUS_MIX, "Mix", "Chorus", 0.0, 1.0, 0.5, Some(""))
         );
+        add_param!(
+            PARAM_CHORUS_VOICES,
+            ParamDescriptor::int(PARAM_CHORUS_VOICES, "Voices", "Chorus", 1, 8, 4)
+        );
+        add_param!(
+            PARAM_CHORUS_SPREAD,
+            ParamDescriptor::float(PARAM_CHORUS_SPREAD, "Spread", "Chorus", 0.0, 1.0, 0.5, Some(""))
+        );
 
         add_param!(
             PARAM_DISTORTION_TYPE,
@@ -1646,6 +2057,43 @@ impl ParamRegistry {
                 Some("ms")
             )
         );
+        add_param!(
+            PARAM_COMPRESSOR_ADAPTIVE,
+            ParamDescriptor::bool(PARAM_COMPRESSOR_ADAPTIVE, "Adaptive", "Compressor", false)
+        );
+        add_param!(
+            PARAM_COMPRESSOR_STEREO_LINK,
+            ParamDescriptor::bool(
+                PARAM_COMPRESSOR_STEREO_LINK,
+                "Stereo Link",
+                "Compressor",
+                true
+            )
+        );
+        add_param!(
+            PARAM_COMPRESSOR_LOOKAHEAD,
+            ParamDescriptor::float(
+                PARAM_COMPRESSOR_LOOKAHEAD,
+                "Lookahead",
+                "Compressor",
+                0.0,
+                10.0,
+                0.0,
+                Some("ms")
+            )
+        );
+        add_param!(
+            PARAM_COMPRESSOR_KNEE,
+            ParamDescriptor::float(
+                PARAM_COMPRESSOR_KNEE,
+                "Knee",
+                "Compressor",
+                0.0,
+                20.0,
+                0.0,
+                Some("dB")
+            )
+        );
 
         // Bitcrusher parameters
         add_param!(
@@ -1770,6 +2218,278 @@ impl ParamRegistry {
             PARAM_EXCITER_ENABLED,
             ParamDescriptor::bool(PARAM_EXCITER_ENABLED, "Enabled", "Exciter", false)
         );
+        add_param!(
+            PARAM_EXCITER_INTELLIGENT,
+            ParamDescriptor::bool(PARAM_EXCITER_INTELLIGENT, "Intelligent", "Exciter", false)
+        );
+
+        // Formant Filter
+        add_param!(
+            PARAM_FORMANT_VOWEL,
+            ParamDescriptor::enum_param(
+                PARAM_FORMANT_VOWEL,
+                "Vowel",
+                "Formant Filter",
+                vec!["A".into(), "E".into(), "I".into(), "O".into(), "U".into()],
+                0 // Default: A
+            )
+        );
+        add_param!(
+            PARAM_FORMANT_MORPH,
+            ParamDescriptor::float(
+                PARAM_FORMANT_MORPH,
+                "Morph",
+                "Formant Filter",
+                0.0,
+                1.0,
+                0.0,
+                Some("%")
+            )
+        );
+        add_param!(
+            PARAM_FORMANT_MIX,
+            ParamDescriptor::float(
+                PARAM_FORMANT_MIX,
+                "Mix",
+                "Formant Filter",
+                0.0,
+                1.0,
+                1.0,
+                Some("%")
+            )
+        );
+        add_param!(
+            PARAM_FORMANT_ENABLED,
+            ParamDescriptor::bool(PARAM_FORMANT_ENABLED, "Enabled", "Formant Filter", false)
+        );
+
+        // Pitch Shifter
+        add_param!(
+            PARAM_PITCH_SHIFTER_SEMITONES,
+            ParamDescriptor::float(
+                PARAM_PITCH_SHIFTER_SEMITONES,
+                "Semitones",
+                "Pitch Shifter",
+                -12.0,
+                12.0,
+                7.0,
+                Some("semitones")
+            )
+        );
+        add_param!(
+            PARAM_PITCH_SHIFTER_MIX,
+            ParamDescriptor::float(
+                PARAM_PITCH_SHIFTER_MIX,
+                "Mix",
+                "Pitch Shifter",
+                0.0,
+                1.0,
+                0.5,
+                Some("%")
+            )
+        );
+        add_param!(
+            PARAM_PITCH_SHIFTER_ENABLED,
+            ParamDescriptor::bool(
+                PARAM_PITCH_SHIFTER_ENABLED,
+                "Enabled",
+                "Pitch Shifter",
+                false
+            )
+        );
+
+        // Noise Gate
+        add_param!(
+            PARAM_GATE_THRESHOLD,
+            ParamDescriptor::float(
+                PARAM_GATE_THRESHOLD,
+                "Threshold",
+                "Noise Gate",
+                -80.0,
+                -20.0,
+                -50.0,
+                Some("dB")
+            )
+        );
+        add_param!(
+            PARAM_GATE_RELEASE,
+            ParamDescriptor::float_log(
+                PARAM_GATE_RELEASE,
+                "Release",
+                "Noise Gate",
+                10.0,
+                1000.0,
+                100.0,
+                Some("ms")
+            )
+        );
+        add_param!(
+            PARAM_GATE_SMART,
+            ParamDescriptor::bool(PARAM_GATE_SMART, "Smart", "Noise Gate", false)
+        );
+        add_param!(
+            PARAM_GATE_ENABLED,
+            ParamDescriptor::bool(PARAM_GATE_ENABLED, "Enabled", "Noise Gate", false)
+        );
+
+        // Sibilance EQ
+        add_param!(
+            PARAM_SIBILANCE_EQ_THRESHOLD,
+            ParamDescriptor::float(
+                PARAM_SIBILANCE_EQ_THRESHOLD,
+                "Threshold",
+                "Sibilance EQ",
+                0.0,
+                1.0,
+                0.5,
+                None
+            )
+        );
+        add_param!(
+            PARAM_SIBILANCE_EQ_AMOUNT,
+            ParamDescriptor::float(
+                PARAM_SIBILANCE_EQ_AMOUNT,
+                "Amount",
+                "Sibilance EQ",
+                0.0,
+                1.0,
+                0.5,
+                Some("%")
+            )
+        );
+        add_param!(
+            PARAM_SIBILANCE_EQ_ENABLED,
+            ParamDescriptor::bool(PARAM_SIBILANCE_EQ_ENABLED, "Enabled", "Sibilance EQ", false)
+        );
+
+        // Master Transient Shaper
+        add_param!(
+            PARAM_MASTER_TRANSIENT_ATTACK,
+            ParamDescriptor::float(
+                PARAM_MASTER_TRANSIENT_ATTACK,
+                "Attack",
+                "Master Transient",
+                -1.0,
+                1.0,
+                0.0,
+                None
+            )
+        );
+        add_param!(
+            PARAM_MASTER_TRANSIENT_SUSTAIN,
+            ParamDescriptor::float(
+                PARAM_MASTER_TRANSIENT_SUSTAIN,
+                "Sustain",
+                "Master Transient",
+                -1.0,
+                1.0,
+                0.0,
+                None
+            )
+        );
+        add_param!(
+            PARAM_MASTER_TRANSIENT_ENABLED,
+            ParamDescriptor::bool(
+                PARAM_MASTER_TRANSIENT_ENABLED,
+                "Enabled",
+                "Master Transient",
+                false
+            )
+        );
+
+        // Mid/Side
+        add_param!(
+            PARAM_MID_SIDE_MID_GAIN,
+            ParamDescriptor::float(
+                PARAM_MID_SIDE_MID_GAIN,
+                "Mid Gain",
+                "Mid-Side",
+                0.0,
+                2.0,
+                1.0,
+                Some("")
+            )
+        );
+        add_param!(
+            PARAM_MID_SIDE_SIDE_GAIN,
+            ParamDescriptor::float(
+                PARAM_MID_SIDE_SIDE_GAIN,
+                "Side Gain",
+                "Mid-Side",
+                0.0,
+                2.0,
+                1.0,
+                Some("")
+            )
+        );
+        add_param!(
+            PARAM_MID_SIDE_LOW_CUT,
+            ParamDescriptor::float_log(
+                PARAM_MID_SIDE_LOW_CUT,
+                "Side Low Cut",
+                "Mid-Side",
+                20.0,
+                500.0,
+                20.0,
+                Some("Hz")
+            )
+        );
+        add_param!(
+            PARAM_MID_SIDE_ENABLED,
+            ParamDescriptor::bool(PARAM_MID_SIDE_ENABLED, "Enabled", "Mid-Side", false)
+        );
+
+        // Doubler
+        add_param!(
+            PARAM_DOUBLER_MIX,
+            ParamDescriptor::float(PARAM_DOUBLER_MIX, "Mix", "Doubler", 0.0, 1.0, 0.5, Some(""))
+        );
+        add_param!(
+            PARAM_DOUBLER_SPREAD,
+            ParamDescriptor::float(
+                PARAM_DOUBLER_SPREAD,
+                "Spread",
+                "Doubler",
+                0.0,
+                1.0,
+                0.7,
+                Some("")
+            )
+        );
+        add_param!(
+            PARAM_DOUBLER_ENABLED,
+            ParamDescriptor::bool(PARAM_DOUBLER_ENABLED, "Enabled", "Doubler", false)
+        );
+
+        // Choir
+        add_param!(
+            PARAM_CHOIR_VOICES,
+            ParamDescriptor::int(PARAM_CHOIR_VOICES, "Voices", "Choir", 2, 8, 4)
+        );
+        add_param!(
+            PARAM_CHOIR_DETUNE,
+            ParamDescriptor::float(
+                PARAM_CHOIR_DETUNE,
+                "Detune",
+                "Choir",
+                0.0,
+                50.0,
+                15.0,
+                Some("cents")
+            )
+        );
+        add_param!(
+            PARAM_CHOIR_SPREAD,
+            ParamDescriptor::float(PARAM_CHOIR_SPREAD, "Spread", "Choir", 0.0, 1.0, 0.8, Some(""))
+        );
+        add_param!(
+            PARAM_CHOIR_MIX,
+            ParamDescriptor::float(PARAM_CHOIR_MIX, "Mix", "Choir", 0.0, 1.0, 0.5, Some(""))
+        );
+        add_param!(
+            PARAM_CHOIR_ENABLED,
+            ParamDescriptor::bool(PARAM_CHOIR_ENABLED, "Enabled", "Choir", false)
+        );
 
         // Effect enable/disable toggles
         add_param!(
@@ -1920,6 +2640,48 @@ impl ParamRegistry {
             )
         );
 
+        // Voice click
+        add_param!(
+            PARAM_VOICE_CLICK_ENABLED,
+            ParamDescriptor::bool(PARAM_VOICE_CLICK_ENABLED, "Enabled", "Voice Click", false)
+        );
+        add_param!(
+            PARAM_VOICE_CLICK_LEVEL,
+            ParamDescriptor::float(
+                PARAM_VOICE_CLICK_LEVEL,
+                "Level",
+                "Voice Click",
+                0.0,
+                1.0,
+                0.3,
+                Some("%")
+            )
+        );
+        add_param!(
+            PARAM_VOICE_CLICK_LENGTH,
+            ParamDescriptor::float(
+                PARAM_VOICE_CLICK_LENGTH,
+                "Length",
+                "Voice Click",
+                1.0,
+                50.0,
+                8.0,
+                Some("ms")
+            )
+        );
+        add_param!(
+            PARAM_VOICE_CLICK_HIGHPASS,
+            ParamDescriptor::float_log(
+                PARAM_VOICE_CLICK_HIGHPASS,
+                "Highpass",
+                "Voice Click",
+                500.0,
+                16000.0,
+                4000.0,
+                Some("Hz")
+            )
+        );
+
         // Wavetable parameters (Oscillator 1)
         add_param!(
             PARAM_OSC1_WAVETABLE_INDEX,
@@ -2024,6 +2786,237 @@ impl ParamRegistry {
             )
         );
 
+        // Additive level compensation (normalize toggle + trim)
+        for (normalize_id, gain_id, module) in [
+            (
+                PARAM_OSC1_ADDITIVE_NORMALIZE,
+                PARAM_OSC1_ADDITIVE_GAIN,
+                "Oscillator 1 Additive",
+            ),
+            (
+                PARAM_OSC2_ADDITIVE_NORMALIZE,
+                PARAM_OSC2_ADDITIVE_GAIN,
+                "Oscillator 2 Additive",
+            ),
+            (
+                PARAM_OSC3_ADDITIVE_NORMALIZE,
+                PARAM_OSC3_ADDITIVE_GAIN,
+                "Oscillator 3 Additive",
+            ),
+        ] {
+            add_param!(
+                normalize_id,
+                ParamDescriptor::bool(normalize_id, "Normalize", module, false)
+            );
+            add_param!(
+                gain_id,
+                ParamDescriptor::float(gain_id, "Trim", module, 0.0, 2.0, 1.0, Some("lin"))
+            );
+        }
+
+        // Oscillator → filter routing
+        for (routing_id, module) in [
+            (PARAM_OSC1_FILTER_ROUTING, "Oscillator 1"),
+            (PARAM_OSC2_FILTER_ROUTING, "Oscillator 2"),
+            (PARAM_OSC3_FILTER_ROUTING, "Oscillator 3"),
+        ] {
+            add_param!(
+                routing_id,
+                ParamDescriptor::enum_param(
+                    routing_id,
+                    "Filter Routing",
+                    module,
+                    vec![
+                        "Paired".into(),
+                        "Filter 1".into(),
+                        "Filter 2".into(),
+                        "Filter 3".into(),
+                        "Bypass".into(),
+                    ],
+                    0 // Default: Paired (oscillator N → filter N)
+                )
+            );
+        }
+
+        // Pluck (Karplus-Strong) string tone
+        for (damping_id, brightness_id, module) in [
+            (
+                PARAM_OSC1_PLUCK_DAMPING,
+                PARAM_OSC1_PLUCK_BRIGHTNESS,
+                "Oscillator 1 Pluck",
+            ),
+            (
+                PARAM_OSC2_PLUCK_DAMPING,
+                PARAM_OSC2_PLUCK_BRIGHTNESS,
+                "Oscillator 2 Pluck",
+            ),
+            (
+                PARAM_OSC3_PLUCK_DAMPING,
+                PARAM_OSC3_PLUCK_BRIGHTNESS,
+                "Oscillator 3 Pluck",
+            ),
+        ] {
+            add_param!(
+                damping_id,
+                ParamDescriptor::float(damping_id, "Damping", module, 0.0, 1.0, 0.5, Some("%"))
+            );
+            add_param!(
+                brightness_id,
+                ParamDescriptor::float(
+                    brightness_id,
+                    "Brightness",
+                    module,
+                    0.0,
+                    1.0,
+                    0.5,
+                    Some("%")
+                )
+            );
+        }
+
+        // Fixed-frequency mode (toggle + frequency)
+        for (fixed_id, hz_id, module) in [
+            (PARAM_OSC1_FIXED_FREQ, PARAM_OSC1_FIXED_FREQ_HZ, "Oscillator 1"),
+            (PARAM_OSC2_FIXED_FREQ, PARAM_OSC2_FIXED_FREQ_HZ, "Oscillator 2"),
+            (PARAM_OSC3_FIXED_FREQ, PARAM_OSC3_FIXED_FREQ_HZ, "Oscillator 3"),
+        ] {
+            add_param!(
+                fixed_id,
+                ParamDescriptor::bool(fixed_id, "Fixed Freq", module, false)
+            );
+            add_param!(
+                hz_id,
+                ParamDescriptor::float_log(
+                    hz_id,
+                    "Fixed Freq Hz",
+                    module,
+                    0.1,
+                    20000.0,
+                    crate::params::OscillatorParams::DEFAULT_FIXED_FREQ_HZ,
+                    Some("Hz")
+                )
+            );
+        }
+
+        // Pre-filter sample-rate reduction (0 Hz = off)
+        for (reduce_id, module) in [
+            (PARAM_OSC1_SAMPLE_REDUCE, "Oscillator 1"),
+            (PARAM_OSC2_SAMPLE_REDUCE, "Oscillator 2"),
+            (PARAM_OSC3_SAMPLE_REDUCE, "Oscillator 3"),
+        ] {
+            add_param!(
+                reduce_id,
+                ParamDescriptor::float_exp(
+                    reduce_id,
+                    "Sample Reduce",
+                    module,
+                    0.0,
+                    44100.0,
+                    0.0,
+                    3.0, // Most of the travel in the crunchy low range
+                    Some("Hz")
+                )
+            );
+        }
+
+        // Extended additive harmonics (9-32), harmonic count and decay slope macro
+        for (h9_id, h32_id, count_id, slope_id, module) in [
+            (
+                PARAM_OSC1_H9,
+                PARAM_OSC1_H32,
+                PARAM_OSC1_HARMONIC_COUNT,
+                PARAM_OSC1_HARMONIC_SLOPE,
+                "Oscillator 1",
+            ),
+            (
+                PARAM_OSC2_H9,
+                PARAM_OSC2_H32,
+                PARAM_OSC2_HARMONIC_COUNT,
+                PARAM_OSC2_HARMONIC_SLOPE,
+                "Oscillator 2",
+            ),
+            (
+                PARAM_OSC3_H9,
+                PARAM_OSC3_H32,
+                PARAM_OSC3_HARMONIC_COUNT,
+                PARAM_OSC3_HARMONIC_SLOPE,
+                "Oscillator 3",
+            ),
+        ] {
+            let additive_module = format!("{}/Additive", module);
+            for param_id in h9_id..=h32_id {
+                add_param!(
+                    param_id,
+                    ParamDescriptor::float(
+                        param_id,
+                        format!("Harmonic {}", param_id - h9_id + 9),
+                        additive_module.clone(),
+                        0.0,
+                        1.0,
+                        0.0,
+                        Some("")
+                    )
+                );
+            }
+            add_param!(
+                count_id,
+                ParamDescriptor::int(count_id, "Harmonic Count", additive_module.clone(), 8, 32, 8)
+            );
+            add_param!(
+                slope_id,
+                ParamDescriptor::float(
+                    slope_id,
+                    "Harmonic Slope",
+                    additive_module,
+                    0.0,
+                    3.0,
+                    1.0, // 1/n: saw-like
+                    Some("")
+                )
+            );
+        }
+
+        // Self-feedback FM (0 = off)
+        for (feedback_id, module) in [
+            (PARAM_OSC1_FM_FEEDBACK, "Oscillator 1"),
+            (PARAM_OSC2_FM_FEEDBACK, "Oscillator 2"),
+            (PARAM_OSC3_FM_FEEDBACK, "Oscillator 3"),
+        ] {
+            add_param!(
+                feedback_id,
+                ParamDescriptor::float(
+                    feedback_id,
+                    "FM Feedback",
+                    module,
+                    0.0,
+                    1.0,
+                    0.0,
+                    Some("")
+                )
+            );
+        }
+
+        // Gain smoothing (0 ms = instant)
+        for (slew_id, module) in [
+            (PARAM_OSC1_GAIN_SLEW, "Oscillator 1"),
+            (PARAM_OSC2_GAIN_SLEW, "Oscillator 2"),
+            (PARAM_OSC3_GAIN_SLEW, "Oscillator 3"),
+        ] {
+            add_param!(
+                slew_id,
+                ParamDescriptor::float_exp(
+                    slew_id,
+                    "Gain Slew",
+                    module,
+                    0.0,
+                    100.0,
+                    5.0,
+                    2.0,
+                    Some("ms")
+                )
+            );
+        }
+
         // Sort parameter IDs for consistent iteration
         param_ids.sort();
 
@@ -2083,6 +3076,25 @@ impl ParamRegistry {
             .filter(|desc| desc.name.to_lowercase().contains(&name_lower))
             .collect()
     }
+
+    /// Search parameters by name and module, in registry order
+    ///
+    /// Every whitespace-separated term must appear (case-insensitive) in the parameter's
+    /// name or module, so "osc 2 pitch" finds "Pitch" in "Oscillator 2". An empty query
+    /// matches nothing.
+    pub fn search(&self, query: &str) -> Vec<ParamId> {
+        let terms: Vec<String> = query.split_whitespace().map(str::to_lowercase).collect();
+        if terms.is_empty() {
+            return Vec::new();
+        }
+        self.iter_descriptors()
+            .filter(|desc| {
+                let haystack = format!("{} {}", desc.module, desc.name).to_lowercase();
+                terms.iter().all(|term| haystack.contains(term.as_str()))
+            })
+            .map(|desc| desc.id)
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -2140,6 +3152,27 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_knob_taper_round_trips() {
+        // Knobs display normalize_value(current) and drag through denormalize, so the two
+        // must agree or a knob would jump on the first drag
+        let registry = get_registry();
+        for id in registry.iter_ids() {
+            let desc = registry.get(id).unwrap();
+            if let ParamType::Float { .. } = desc.param_type {
+                for step in 0..=10 {
+                    let normalized = step as f32 / 10.0;
+                    let round_trip = desc.normalize_value(desc.denormalize(normalized));
+                    assert!(
+                        (round_trip - normalized).abs() < 1e-3,
+                        "{} taper mismatch at {normalized}: {round_trip}",
+                        desc.name
+                    );
+                }
+            }
+        }
+    }
+
     #[test]
     fn test_find_by_module() {
         let registry = get_registry();
@@ -2150,6 +3183,16 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_module_paths() {
+        let registry = get_registry();
+        let path = |id| registry.get(id).unwrap().module_path();
+        assert_eq!(path(PARAM_OSC1_PITCH), "Oscillator 1");
+        assert_eq!(path(PARAM_OSC2_H9), "Oscillator 2/Additive");
+        assert_eq!(path(PARAM_REVERB_WET), "Effects/Reverb");
+        assert_eq!(path(PARAM_MID_SIDE_ENABLED), "Effects/Mid-Side");
+    }
+
     #[test]
     fn test_find_by_name() {
         let registry = get_registry();
@@ -2159,4 +3202,17 @@ mod tests {
             "Should find pitch parameters for all oscillators"
         );
     }
+
+    #[test]
+    fn test_search_matches_all_terms() {
+        let registry = get_registry();
+        let results = registry.search("oscillator 2 pitch");
+        assert!(results.contains(&PARAM_OSC2_PITCH));
+        assert!(!results.contains(&PARAM_OSC1_PITCH));
+
+        let cutoff = registry.search("CUTOFF");
+        assert!(cutoff.contains(&PARAM_FILTER1_CUTOFF));
+        assert!(registry.search("   ").is_empty());
+        assert!(registry.search("no such parameter").is_empty());
+    }
 }
@@ -82,6 +82,26 @@ pub mod param_apply {
     use super::ParamId;
     use crate::params::SynthParams;
 
+    /// Apply a saved set of normalized values (e.g. the host state) to a SynthParams struct
+    ///
+    /// Parameters that write other parameters go first so the saved values of their
+    /// targets win whatever order `saved` comes in: macros (which drive their targets),
+    /// then the harmonic slopes (which rewrite every harmonic).
+    pub fn apply_saved_params(
+        params: &mut SynthParams,
+        saved: impl IntoIterator<Item = (ParamId, f32)>,
+    ) {
+        let mut saved: Vec<_> = saved.into_iter().collect();
+        saved.sort_by_key(|&(id, _)| match id {
+            id if is_macro_param(id) => 0,
+            PARAM_OSC1_HARMONIC_SLOPE | PARAM_OSC2_HARMONIC_SLOPE | PARAM_OSC3_HARMONIC_SLOPE => 1,
+            _ => 2,
+        });
+        for (id, normalized) in saved {
+            apply_param(params, id, normalized);
+        }
+    }
+
     /// Apply a normalized parameter value (0.0-1.0) to a SynthParams struct
     pub fn apply_param(params: &mut SynthParams, param_id: ParamId, normalized: f32) {
         let denorm = {
@@ -98,6 +118,50 @@ pub mod param_apply {
             PARAM_MASTER_GAIN => params.master_gain = denorm,
             PARAM_MONOPHONIC => params.monophonic = denorm > 0.5,
             PARAM_HARD_SYNC => params.hard_sync_enabled = denorm > 0.5,
+            PARAM_EFFECTS_BYPASS => params.effects_bypass = denorm > 0.5,
+            PARAM_INPUT_GAIN => params.input_gain_db = denorm,
+            PARAM_KEY_PAN => params.key_pan_amount = denorm.clamp(-1.0, 1.0),
+            PARAM_POLY_LEGATO => params.poly_legato = denorm > 0.5,
+            PARAM_LEGATO_WINDOW => params.legato_window_ms = denorm,
+            PARAM_SOLO_NOTE => params.solo_note_mode = denorm > 0.5,
+            PARAM_HEADROOM => params.headroom_db = denorm.clamp(0.0, 24.0),
+            PARAM_FILTER_TOPOLOGY => {
+                if let Some(topology) = denorm_to_filter_topology(denorm) {
+                    params.filter_topology = topology;
+                }
+            }
+            PARAM_QUALITY => {
+                if let Some(quality) = denorm_to_quality(denorm) {
+                    params.quality = quality;
+                }
+            }
+            PARAM_ANALOG_DRIFT => params.analog_drift = denorm.clamp(0.0, 1.0),
+            PARAM_TUNING => params.tuning_cents = denorm.clamp(-100.0, 100.0),
+            PARAM_STEAL_RELEASE_FIRST => params.steal_prefers_release = denorm > 0.5,
+            PARAM_MAX_NOTE_LENGTH => params.max_note_seconds = denorm.clamp(0.0, 600.0),
+            PARAM_PARAPHONIC => params.paraphonic = denorm > 0.5,
+            PARAM_MIDI_CHANNEL => params.midi_channel = (denorm.round() as u8).min(16),
+            PARAM_RETRIGGER_MOD => params.retrigger_mod_on_note = denorm > 0.5,
+            PARAM_POLY_COMP => params.poly_comp_amount = denorm,
+
+            // Note repeat
+            PARAM_NOTE_REPEAT_ENABLED => params.note_repeat.enabled = denorm > 0.5,
+            PARAM_NOTE_REPEAT_RATE => params.note_repeat.rate = denorm.clamp(1.0, 32.0),
+            PARAM_NOTE_REPEAT_TEMPO_SYNC => {
+                if let Some(ts) = denorm_to_tempo_sync(denorm) {
+                    params.note_repeat.tempo_sync = ts;
+                }
+            }
+            PARAM_NOTE_REPEAT_GATE => params.note_repeat.gate = denorm.clamp(0.05, 1.0),
+            PARAM_NOTE_REPEAT_VELOCITY_RAMP => {
+                params.note_repeat.velocity_ramp = denorm.clamp(-1.0, 1.0)
+            }
+
+            // Macros
+            PARAM_MACRO1 => apply_macro(params, 0, denorm),
+            PARAM_MACRO2 => apply_macro(params, 1, denorm),
+            PARAM_MACRO3 => apply_macro(params, 2, denorm),
+            PARAM_MACRO4 => apply_macro(params, 3, denorm),
 
             // Oscillator 1
             PARAM_OSC1_WAVEFORM => {
@@ -264,6 +328,13 @@ pub mod param_apply {
             PARAM_FILTER1_ENV_AMOUNT => params.filters[0].envelope.amount = denorm,
             PARAM_FILTER1_DRIVE => params.filters[0].drive = denorm,
             PARAM_FILTER1_POST_DRIVE => params.filters[0].post_drive = denorm,
+            PARAM_FILTER1_CUTOFF_SLEW => params.filters[0].cutoff_slew_ms = denorm,
+            PARAM_FILTER1_RES_COMP => params.filters[0].res_comp = denorm > 0.5,
+            PARAM_FILTER1_DRIVE_POSITION => {
+                if let Some(position) = denorm_to_drive_position(denorm) {
+                    params.filters[0].drive_position = position;
+                }
+            }
 
             // Filter 2 Envelope
             PARAM_FILTER2_ENV_ATTACK => params.filters[1].envelope.attack = denorm,
@@ -273,6 +344,13 @@ pub mod param_apply {
             PARAM_FILTER2_ENV_AMOUNT => params.filters[1].envelope.amount = denorm,
             PARAM_FILTER2_DRIVE => params.filters[1].drive = denorm,
             PARAM_FILTER2_POST_DRIVE => params.filters[1].post_drive = denorm,
+            PARAM_FILTER2_CUTOFF_SLEW => params.filters[1].cutoff_slew_ms = denorm,
+            PARAM_FILTER2_RES_COMP => params.filters[1].res_comp = denorm > 0.5,
+            PARAM_FILTER2_DRIVE_POSITION => {
+                if let Some(position) = denorm_to_drive_position(denorm) {
+                    params.filters[1].drive_position = position;
+                }
+            }
 
             // Filter 3 Envelope
             PARAM_FILTER3_ENV_ATTACK => params.filters[2].envelope.attack = denorm,
@@ -282,6 +360,13 @@ pub mod param_apply {
             PARAM_FILTER3_ENV_AMOUNT => params.filters[2].envelope.amount = denorm,
             PARAM_FILTER3_DRIVE => params.filters[2].drive = denorm,
             PARAM_FILTER3_POST_DRIVE => params.filters[2].post_drive = denorm,
+            PARAM_FILTER3_CUTOFF_SLEW => params.filters[2].cutoff_slew_ms = denorm,
+            PARAM_FILTER3_RES_COMP => params.filters[2].res_comp = denorm > 0.5,
+            PARAM_FILTER3_DRIVE_POSITION => {
+                if let Some(position) = denorm_to_drive_position(denorm) {
+                    params.filters[2].drive_position = position;
+                }
+            }
 
             // LFOs
             PARAM_LFO1_WAVEFORM => {
@@ -301,6 +386,8 @@ pub mod param_apply {
             PARAM_LFO1_GAIN_AMOUNT => params.lfos[0].gain_amount = denorm,
             PARAM_LFO1_PAN_AMOUNT => params.lfos[0].pan_amount = denorm,
             PARAM_LFO1_PWM_AMOUNT => params.lfos[0].pwm_amount = denorm,
+            PARAM_LFO1_PULSE_WIDTH => params.lfos[0].pulse_width = denorm,
+            PARAM_LFO1_UNIPOLAR => params.lfos[0].unipolar = denorm > 0.5,
             PARAM_LFO1_DESTINATION => {
                 if let Some(dest) = denorm_to_lfo_destination(denorm) {
                     params.lfos[0].destination = dest;
@@ -324,6 +411,8 @@ pub mod param_apply {
             PARAM_LFO2_GAIN_AMOUNT => params.lfos[1].gain_amount = denorm,
             PARAM_LFO2_PAN_AMOUNT => params.lfos[1].pan_amount = denorm,
             PARAM_LFO2_PWM_AMOUNT => params.lfos[1].pwm_amount = denorm,
+            PARAM_LFO2_PULSE_WIDTH => params.lfos[1].pulse_width = denorm,
+            PARAM_LFO2_UNIPOLAR => params.lfos[1].unipolar = denorm > 0.5,
             PARAM_LFO2_DESTINATION => {
                 if let Some(dest) = denorm_to_lfo_destination(denorm) {
                     params.lfos[1].destination = dest;
@@ -347,6 +436,8 @@ pub mod param_apply {
             PARAM_LFO3_GAIN_AMOUNT => params.lfos[2].gain_amount = denorm,
             PARAM_LFO3_PAN_AMOUNT => params.lfos[2].pan_amount = denorm,
             PARAM_LFO3_PWM_AMOUNT => params.lfos[2].pwm_amount = denorm,
+            PARAM_LFO3_PULSE_WIDTH => params.lfos[2].pulse_width = denorm,
+            PARAM_LFO3_UNIPOLAR => params.lfos[2].unipolar = denorm > 0.5,
             PARAM_LFO3_DESTINATION => {
                 if let Some(dest) = denorm_to_lfo_destination(denorm) {
                     params.lfos[2].destination = dest;
@@ -361,10 +452,15 @@ pub mod param_apply {
             PARAM_ENVELOPE_ATTACK_CURVE => params.envelope.attack_curve = denorm,
             PARAM_ENVELOPE_DECAY_CURVE => params.envelope.decay_curve = denorm,
             PARAM_ENVELOPE_RELEASE_CURVE => params.envelope.release_curve = denorm,
+            PARAM_ENVELOPE_KEY_SCALING => params.envelope.key_scaling = denorm.clamp(0.0, 1.0),
 
             // Velocity
             PARAM_VELOCITY_AMP => params.velocity.amp_sensitivity = denorm,
             PARAM_VELOCITY_FILTER => params.velocity.filter_sensitivity = denorm,
+            PARAM_VELOCITY_ATTACK => params.velocity.attack_sensitivity = denorm,
+            PARAM_VELOCITY_DECAY => params.velocity.decay_sensitivity = denorm,
+            PARAM_VELOCITY_OSC_XFADE => params.velocity.osc_crossfade = denorm,
+            PARAM_VELOCITY_RELEASE => params.velocity.release_sensitivity = denorm,
 
             // Effects
             PARAM_REVERB_ROOM_SIZE => params.effects.reverb.room_size = denorm,
@@ -372,8 +468,33 @@ pub mod param_apply {
             PARAM_REVERB_WET => params.effects.reverb.wet = denorm,
             PARAM_REVERB_DRY => params.effects.reverb.dry = denorm,
             PARAM_REVERB_WIDTH => params.effects.reverb.width = denorm,
+            PARAM_REVERB_ALGORITHM => {
+                if let Some(algorithm) = denorm_to_reverb_algorithm(denorm) {
+                    params.effects.reverb.algorithm = algorithm;
+                }
+            }
+            PARAM_REVERB_ROUTING => {
+                if let Some(routing) = denorm_to_effect_routing(denorm) {
+                    params.effects.reverb.routing = routing;
+                }
+            }
             PARAM_DELAY_TIME_MS => params.effects.delay.time_ms = denorm,
+            PARAM_DELAY_TEMPO_SYNC => {
+                if let Some(ts) = denorm_to_tempo_sync(denorm) {
+                    params.effects.delay.tempo_sync = ts;
+                }
+            }
             PARAM_DELAY_FEEDBACK => params.effects.delay.feedback = denorm,
+            PARAM_DELAY_FEEDBACK_CURVE => {
+                if let Some(curve) = denorm_to_feedback_curve(denorm) {
+                    params.effects.delay.feedback_curve = curve;
+                }
+            }
+            PARAM_DELAY_ROUTING => {
+                if let Some(routing) = denorm_to_effect_routing(denorm) {
+                    params.effects.delay.routing = routing;
+                }
+            }
             PARAM_DELAY_WET => params.effects.delay.wet = denorm,
             PARAM_DELAY_DRY => params.effects.delay.dry = denorm,
             PARAM_CHORUS_RATE => params.effects.chorus.rate = denorm,
@@ -384,6 +505,8 @@ pub mod param_apply {
             }
             PARAM_CHORUS_DEPTH => params.effects.chorus.depth = denorm,
             PARAM_CHORUS_MIX => params.effects.chorus.mix = denorm,
+            PARAM_CHORUS_VOICES => params.effects.chorus.voices = (denorm as usize).clamp(1, 8),
+            PARAM_CHORUS_SPREAD => params.effects.chorus.spread = denorm,
             PARAM_DISTORTION_TYPE => {
                 if let Some(dt) = denorm_to_distortion_type(denorm) {
                     params.effects.distortion.dist_type = dt;
@@ -417,6 +540,91 @@ pub mod param_apply {
             PARAM_OSC2_UNISON_NORMALIZE => params.oscillators[1].unison_normalize = denorm > 0.5,
             PARAM_OSC3_UNISON_NORMALIZE => params.oscillators[2].unison_normalize = denorm > 0.5,
 
+            // Additive level compensation
+            PARAM_OSC1_ADDITIVE_NORMALIZE => {
+                params.oscillators[0].additive_normalize = denorm > 0.5
+            }
+            PARAM_OSC1_ADDITIVE_GAIN => params.oscillators[0].additive_gain = denorm,
+            PARAM_OSC2_ADDITIVE_NORMALIZE => {
+                params.oscillators[1].additive_normalize = denorm > 0.5
+            }
+            PARAM_OSC2_ADDITIVE_GAIN => params.oscillators[1].additive_gain = denorm,
+            PARAM_OSC3_ADDITIVE_NORMALIZE => {
+                params.oscillators[2].additive_normalize = denorm > 0.5
+            }
+            PARAM_OSC3_ADDITIVE_GAIN => params.oscillators[2].additive_gain = denorm,
+
+            // Oscillator → filter routing
+            PARAM_OSC1_FILTER_ROUTING => {
+                if let Some(routing) = denorm_to_filter_routing(denorm) {
+                    params.oscillators[0].filter_routing = routing;
+                }
+            }
+            PARAM_OSC2_FILTER_ROUTING => {
+                if let Some(routing) = denorm_to_filter_routing(denorm) {
+                    params.oscillators[1].filter_routing = routing;
+                }
+            }
+            PARAM_OSC3_FILTER_ROUTING => {
+                if let Some(routing) = denorm_to_filter_routing(denorm) {
+                    params.oscillators[2].filter_routing = routing;
+                }
+            }
+
+            // Pluck (Karplus-Strong) string tone
+            PARAM_OSC1_PLUCK_DAMPING => params.oscillators[0].pluck_damping = denorm,
+            PARAM_OSC1_PLUCK_BRIGHTNESS => params.oscillators[0].pluck_brightness = denorm,
+            PARAM_OSC2_PLUCK_DAMPING => params.oscillators[1].pluck_damping = denorm,
+            PARAM_OSC2_PLUCK_BRIGHTNESS => params.oscillators[1].pluck_brightness = denorm,
+            PARAM_OSC3_PLUCK_DAMPING => params.oscillators[2].pluck_damping = denorm,
+            PARAM_OSC3_PLUCK_BRIGHTNESS => params.oscillators[2].pluck_brightness = denorm,
+
+            // Fixed-frequency mode: the frequency only applies while the mode is on
+            PARAM_OSC1_FIXED_FREQ => set_fixed_freq_enabled(&mut params.oscillators[0], denorm),
+            PARAM_OSC2_FIXED_FREQ => set_fixed_freq_enabled(&mut params.oscillators[1], denorm),
+            PARAM_OSC3_FIXED_FREQ => set_fixed_freq_enabled(&mut params.oscillators[2], denorm),
+            PARAM_OSC1_FIXED_FREQ_HZ => set_fixed_freq_hz(&mut params.oscillators[0], denorm),
+            PARAM_OSC2_FIXED_FREQ_HZ => set_fixed_freq_hz(&mut params.oscillators[1], denorm),
+            PARAM_OSC3_FIXED_FREQ_HZ => set_fixed_freq_hz(&mut params.oscillators[2], denorm),
+
+            // Pre-filter sample-rate reduction
+            PARAM_OSC1_SAMPLE_REDUCE => params.oscillators[0].sample_reduce = denorm,
+            PARAM_OSC2_SAMPLE_REDUCE => params.oscillators[1].sample_reduce = denorm,
+            PARAM_OSC3_SAMPLE_REDUCE => params.oscillators[2].sample_reduce = denorm,
+
+            // Extended additive harmonics, harmonic count and slope macro
+            PARAM_OSC1_H9..=PARAM_OSC1_H32 => params.oscillators[0]
+                .set_additive_harmonic((param_id - PARAM_OSC1_H9) as usize + 8, denorm),
+            PARAM_OSC2_H9..=PARAM_OSC2_H32 => params.oscillators[1]
+                .set_additive_harmonic((param_id - PARAM_OSC2_H9) as usize + 8, denorm),
+            PARAM_OSC3_H9..=PARAM_OSC3_H32 => params.oscillators[2]
+                .set_additive_harmonic((param_id - PARAM_OSC3_H9) as usize + 8, denorm),
+            PARAM_OSC1_HARMONIC_COUNT => {
+                params.oscillators[0].additive_harmonic_count =
+                    (denorm.round() as usize).clamp(8, 32)
+            }
+            PARAM_OSC2_HARMONIC_COUNT => {
+                params.oscillators[1].additive_harmonic_count =
+                    (denorm.round() as usize).clamp(8, 32)
+            }
+            PARAM_OSC3_HARMONIC_COUNT => {
+                params.oscillators[2].additive_harmonic_count =
+                    (denorm.round() as usize).clamp(8, 32)
+            }
+            PARAM_OSC1_HARMONIC_SLOPE => params.oscillators[0].set_additive_slope(denorm),
+            PARAM_OSC2_HARMONIC_SLOPE => params.oscillators[1].set_additive_slope(denorm),
+            PARAM_OSC3_HARMONIC_SLOPE => params.oscillators[2].set_additive_slope(denorm),
+
+            // Self-feedback FM
+            PARAM_OSC1_FM_FEEDBACK => params.oscillators[0].fm_feedback = denorm,
+            PARAM_OSC2_FM_FEEDBACK => params.oscillators[1].fm_feedback = denorm,
+            PARAM_OSC3_FM_FEEDBACK => params.oscillators[2].fm_feedback = denorm,
+
+            // Gain smoothing
+            PARAM_OSC1_GAIN_SLEW => params.oscillators[0].gain_slew_ms = denorm,
+            PARAM_OSC2_GAIN_SLEW => params.oscillators[1].gain_slew_ms = denorm,
+            PARAM_OSC3_GAIN_SLEW => params.oscillators[2].gain_slew_ms = denorm,
+
             // New Effects - Phaser
             PARAM_PHASER_RATE => params.effects.phaser.rate = denorm,
             PARAM_PHASER_TEMPO_SYNC => {
@@ -471,6 +679,10 @@ pub mod param_apply {
             PARAM_COMPRESSOR_RATIO => params.effects.compressor.ratio = denorm,
             PARAM_COMPRESSOR_ATTACK => params.effects.compressor.attack = denorm,
             PARAM_COMPRESSOR_RELEASE => params.effects.compressor.release = denorm,
+            PARAM_COMPRESSOR_LOOKAHEAD => params.effects.compressor.lookahead = denorm,
+            PARAM_COMPRESSOR_KNEE => params.effects.compressor.knee = denorm.clamp(0.0, 20.0),
+            PARAM_COMPRESSOR_ADAPTIVE => params.effects.compressor.adaptive = denorm > 0.5,
+            PARAM_COMPRESSOR_STEREO_LINK => params.effects.compressor.stereo_link = denorm > 0.5,
 
             // New Effects - Bitcrusher
             PARAM_BITCRUSHER_RATE => params.effects.bitcrusher.sample_rate = denorm,
@@ -484,6 +696,48 @@ pub mod param_apply {
             PARAM_EXCITER_FREQUENCY => params.effects.exciter.frequency = denorm,
             PARAM_EXCITER_DRIVE => params.effects.exciter.drive = denorm,
             PARAM_EXCITER_MIX => params.effects.exciter.mix = denorm,
+            PARAM_EXCITER_INTELLIGENT => params.effects.exciter.intelligent = denorm > 0.5,
+
+            // Formant Filter
+            PARAM_FORMANT_VOWEL => {
+                if let Some(vowel) = denorm_to_vowel(denorm) {
+                    params.effects.formant_filter.vowel = vowel;
+                }
+            }
+            PARAM_FORMANT_MORPH => params.effects.formant_filter.morph = denorm,
+            PARAM_FORMANT_MIX => params.effects.formant_filter.mix = denorm,
+
+            // Pitch Shifter
+            PARAM_PITCH_SHIFTER_SEMITONES => params.effects.pitch_shifter.semitones = denorm,
+            PARAM_PITCH_SHIFTER_MIX => params.effects.pitch_shifter.mix = denorm,
+
+            // Noise Gate
+            PARAM_GATE_THRESHOLD => params.effects.gate.threshold = denorm,
+            PARAM_GATE_RELEASE => params.effects.gate.release = denorm,
+            PARAM_GATE_SMART => params.effects.gate.smart = denorm > 0.5,
+
+            // Sibilance EQ
+            PARAM_SIBILANCE_EQ_THRESHOLD => params.effects.sibilance_eq.threshold = denorm,
+            PARAM_SIBILANCE_EQ_AMOUNT => params.effects.sibilance_eq.amount = denorm,
+
+            // Master Transient Shaper
+            PARAM_MASTER_TRANSIENT_ATTACK => params.effects.transient_shaper.attack = denorm,
+            PARAM_MASTER_TRANSIENT_SUSTAIN => params.effects.transient_shaper.sustain = denorm,
+
+            // Mid/Side
+            PARAM_MID_SIDE_MID_GAIN => params.effects.mid_side.mid_gain = denorm,
+            PARAM_MID_SIDE_SIDE_GAIN => params.effects.mid_side.side_gain = denorm,
+            PARAM_MID_SIDE_LOW_CUT => params.effects.mid_side.side_low_cut = denorm,
+
+            // Doubler
+            PARAM_DOUBLER_MIX => params.effects.doubler.mix = denorm,
+            PARAM_DOUBLER_SPREAD => params.effects.doubler.spread = denorm,
+
+            // Choir
+            PARAM_CHOIR_VOICES => params.effects.choir.voices = (denorm as usize).clamp(2, 8),
+            PARAM_CHOIR_DETUNE => params.effects.choir.detune = denorm,
+            PARAM_CHOIR_SPREAD => params.effects.choir.spread = denorm,
+            PARAM_CHOIR_MIX => params.effects.choir.mix = denorm,
 
             // Effect enable/disable toggles
             PARAM_PHASER_ENABLED => params.effects.phaser.enabled = denorm > 0.5,
@@ -496,6 +750,16 @@ pub mod param_apply {
             PARAM_BITCRUSHER_ENABLED => params.effects.bitcrusher.enabled = denorm > 0.5,
             PARAM_WAVESHAPER_ENABLED => params.effects.waveshaper.enabled = denorm > 0.5,
             PARAM_EXCITER_ENABLED => params.effects.exciter.enabled = denorm > 0.5,
+            PARAM_FORMANT_ENABLED => params.effects.formant_filter.enabled = denorm > 0.5,
+            PARAM_PITCH_SHIFTER_ENABLED => params.effects.pitch_shifter.enabled = denorm > 0.5,
+            PARAM_GATE_ENABLED => params.effects.gate.enabled = denorm > 0.5,
+            PARAM_SIBILANCE_EQ_ENABLED => params.effects.sibilance_eq.enabled = denorm > 0.5,
+            PARAM_MASTER_TRANSIENT_ENABLED => {
+                params.effects.transient_shaper.enabled = denorm > 0.5
+            }
+            PARAM_MID_SIDE_ENABLED => params.effects.mid_side.enabled = denorm > 0.5,
+            PARAM_DOUBLER_ENABLED => params.effects.doubler.enabled = denorm > 0.5,
+            PARAM_CHOIR_ENABLED => params.effects.choir.enabled = denorm > 0.5,
             PARAM_REVERB_ENABLED => params.effects.reverb.enabled = denorm > 0.5,
             PARAM_DELAY_ENABLED => params.effects.delay.enabled = denorm > 0.5,
             PARAM_CHORUS_ENABLED => params.effects.chorus.enabled = denorm > 0.5,
@@ -517,13 +781,21 @@ pub mod param_apply {
             PARAM_TRANSIENT_ATTACK => params.transient_shaper.attack_boost = denorm,
             PARAM_TRANSIENT_SUSTAIN => params.transient_shaper.sustain_reduction = denorm,
 
+            // Voice click
+            PARAM_VOICE_CLICK_ENABLED => params.voice_click_enabled = denorm > 0.5,
+            PARAM_VOICE_CLICK_LEVEL => params.voice_click_level = denorm.clamp(0.0, 1.0),
+            PARAM_VOICE_CLICK_LENGTH => params.voice_click_length_ms = denorm.clamp(1.0, 50.0),
+            PARAM_VOICE_CLICK_HIGHPASS => {
+                params.voice_click_highpass_hz = denorm.clamp(500.0, 16000.0)
+            }
+
             _ => {} // Unknown parameter, ignore
         }
     }
 
     fn denorm_to_waveform(denorm: f32) -> Option<crate::params::Waveform> {
         use crate::params::Waveform;
-        // denorm is already the enum index (0-8) from registry.denormalize()
+        // denorm is already the enum index (0-10) from registry.denormalize()
         match denorm.round() as i32 {
             0 => Some(Waveform::Sine),
             1 => Some(Waveform::Saw),
@@ -534,6 +806,9 @@ pub mod param_apply {
             6 => Some(Waveform::PinkNoise),
             7 => Some(Waveform::Additive),
             8 => Some(Waveform::Wavetable),
+            9 => Some(Waveform::AnalogSaw),
+            10 => Some(Waveform::AnalogSquare),
+            11 => Some(Waveform::Pluck),
             _ => None,
         }
     }
@@ -593,6 +868,128 @@ pub mod param_apply {
         }
     }
 
+    fn denorm_to_reverb_algorithm(denorm: f32) -> Option<crate::params::ReverbAlgorithm> {
+        use crate::params::ReverbAlgorithm;
+        // denorm is already the enum index (0-2) from registry.denormalize()
+        match denorm.round() as i32 {
+            0 => Some(ReverbAlgorithm::Room),
+            1 => Some(ReverbAlgorithm::Hall),
+            2 => Some(ReverbAlgorithm::Plate),
+            _ => None,
+        }
+    }
+
+    fn denorm_to_vowel(denorm: f32) -> Option<crate::params::Vowel> {
+        use crate::params::Vowel;
+        // denorm is already the enum index (0-4) from registry.denormalize()
+        match denorm.round() as i32 {
+            0 => Some(Vowel::A),
+            1 => Some(Vowel::E),
+            2 => Some(Vowel::I),
+            3 => Some(Vowel::O),
+            4 => Some(Vowel::U),
+            _ => None,
+        }
+    }
+
+    fn denorm_to_quality(denorm: f32) -> Option<crate::params::Quality> {
+        use crate::params::Quality;
+        // denorm is already the enum index (0-2) from registry.denormalize()
+        match denorm.round() as i32 {
+            0 => Some(Quality::Draft),
+            1 => Some(Quality::Normal),
+            2 => Some(Quality::High),
+            _ => None,
+        }
+    }
+
+    fn denorm_to_filter_topology(denorm: f32) -> Option<crate::params::FilterTopology> {
+        use crate::params::FilterTopology;
+        // denorm is already the enum index (0-1) from registry.denormalize()
+        match denorm.round() as i32 {
+            0 => Some(FilterTopology::Parallel),
+            1 => Some(FilterTopology::Serial),
+            _ => None,
+        }
+    }
+
+    fn denorm_to_drive_position(denorm: f32) -> Option<crate::params::DrivePosition> {
+        use crate::params::DrivePosition;
+        // denorm is already the enum index (0-1) from registry.denormalize()
+        match denorm.round() as i32 {
+            0 => Some(DrivePosition::Pre),
+            1 => Some(DrivePosition::Post),
+            _ => None,
+        }
+    }
+
+    fn denorm_to_filter_routing(denorm: f32) -> Option<crate::params::FilterRouting> {
+        use crate::params::FilterRouting;
+        // denorm is already the enum index (0-4) from registry.denormalize()
+        match denorm.round() as i32 {
+            0 => Some(FilterRouting::Paired),
+            1 => Some(FilterRouting::Filter1),
+            2 => Some(FilterRouting::Filter2),
+            3 => Some(FilterRouting::Filter3),
+            4 => Some(FilterRouting::Bypass),
+            _ => None,
+        }
+    }
+
+    fn set_fixed_freq_enabled(osc: &mut crate::params::OscillatorParams, denorm: f32) {
+        osc.fixed_freq = if denorm > 0.5 {
+            Some(
+                osc.fixed_freq
+                    .unwrap_or(crate::params::OscillatorParams::DEFAULT_FIXED_FREQ_HZ),
+            )
+        } else {
+            None
+        };
+    }
+
+    fn set_fixed_freq_hz(osc: &mut crate::params::OscillatorParams, denorm: f32) {
+        if let Some(hz) = osc.fixed_freq.as_mut() {
+            *hz = denorm;
+        }
+    }
+
+    fn denorm_to_effect_routing(denorm: f32) -> Option<crate::params::EffectRouting> {
+        use crate::params::EffectRouting;
+        // denorm is already the enum index (0-1) from registry.denormalize()
+        match denorm.round() as i32 {
+            0 => Some(EffectRouting::Series),
+            1 => Some(EffectRouting::Send),
+            _ => None,
+        }
+    }
+
+    fn denorm_to_feedback_curve(denorm: f32) -> Option<crate::params::FeedbackCurve> {
+        use crate::params::FeedbackCurve;
+        // denorm is already the enum index (0-1) from registry.denormalize()
+        match denorm.round() as i32 {
+            0 => Some(FeedbackCurve::Linear),
+            1 => Some(FeedbackCurve::Perceptual),
+            _ => None,
+        }
+    }
+
+    /// Set macro `index` and write each of its targets within the target's assigned range
+    fn apply_macro(params: &mut SynthParams, index: usize, value: f32) {
+        params.macros[index].value = value.clamp(0.0, 1.0);
+
+        let macro_params = params.macros[index];
+        for (param_id, assignment) in macro_params.targets() {
+            // A macro driving another macro could recurse without end
+            if !is_macro_param(param_id) {
+                apply_param(
+                    params,
+                    param_id,
+                    assignment.target_value(macro_params.value),
+                );
+            }
+        }
+    }
+
     fn denorm_to_tempo_sync(denorm: f32) -> Option<crate::params::TempoSync> {
         use crate::params::TempoSync;
         // denorm is already the enum index (0-12) from registry.denormalize()
@@ -640,6 +1037,78 @@ pub mod param_get {
                     0.0
                 }
             }
+            PARAM_EFFECTS_BYPASS => {
+                if params.effects_bypass {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+            PARAM_INPUT_GAIN => params.input_gain_db,
+            PARAM_KEY_PAN => params.key_pan_amount,
+            PARAM_POLY_LEGATO => {
+                if params.poly_legato {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+            PARAM_LEGATO_WINDOW => params.legato_window_ms,
+            PARAM_SOLO_NOTE => {
+                if params.solo_note_mode {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+            PARAM_HEADROOM => params.headroom_db,
+            PARAM_FILTER_TOPOLOGY => filter_topology_to_denorm(params.filter_topology),
+            PARAM_QUALITY => quality_to_denorm(params.quality),
+            PARAM_ANALOG_DRIFT => params.analog_drift,
+            PARAM_TUNING => params.tuning_cents,
+            PARAM_STEAL_RELEASE_FIRST => {
+                if params.steal_prefers_release {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+            PARAM_MAX_NOTE_LENGTH => params.max_note_seconds,
+            PARAM_PARAPHONIC => {
+                if params.paraphonic {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+            PARAM_MIDI_CHANNEL => params.midi_channel as f32,
+            PARAM_RETRIGGER_MOD => {
+                if params.retrigger_mod_on_note {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+            PARAM_POLY_COMP => params.poly_comp_amount,
+
+            // Note repeat
+            PARAM_NOTE_REPEAT_ENABLED => {
+                if params.note_repeat.enabled {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+            PARAM_NOTE_REPEAT_RATE => params.note_repeat.rate,
+            PARAM_NOTE_REPEAT_TEMPO_SYNC => tempo_sync_to_denorm(params.note_repeat.tempo_sync),
+            PARAM_NOTE_REPEAT_GATE => params.note_repeat.gate,
+            PARAM_NOTE_REPEAT_VELOCITY_RAMP => params.note_repeat.velocity_ramp,
+
+            // Macros
+            PARAM_MACRO1 => params.macros[0].value,
+            PARAM_MACRO2 => params.macros[1].value,
+            PARAM_MACRO3 => params.macros[2].value,
+            PARAM_MACRO4 => params.macros[3].value,
 
             // Oscillator 1
             PARAM_OSC1_WAVEFORM => waveform_to_denorm(params.oscillators[0].waveform),
@@ -765,6 +1234,17 @@ pub mod param_get {
             PARAM_FILTER1_ENV_AMOUNT => params.filters[0].envelope.amount,
             PARAM_FILTER1_DRIVE => params.filters[0].drive,
             PARAM_FILTER1_POST_DRIVE => params.filters[0].post_drive,
+            PARAM_FILTER1_CUTOFF_SLEW => params.filters[0].cutoff_slew_ms,
+            PARAM_FILTER1_RES_COMP => {
+                if params.filters[0].res_comp {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+            PARAM_FILTER1_DRIVE_POSITION => {
+                drive_position_to_denorm(params.filters[0].drive_position)
+            }
 
             PARAM_FILTER2_TYPE => filter_type_to_denorm(params.filters[1].filter_type),
             PARAM_FILTER2_CUTOFF => params.filters[1].cutoff,
@@ -778,6 +1258,17 @@ pub mod param_get {
             PARAM_FILTER2_ENV_AMOUNT => params.filters[1].envelope.amount,
             PARAM_FILTER2_DRIVE => params.filters[1].drive,
             PARAM_FILTER2_POST_DRIVE => params.filters[1].post_drive,
+            PARAM_FILTER2_CUTOFF_SLEW => params.filters[1].cutoff_slew_ms,
+            PARAM_FILTER2_RES_COMP => {
+                if params.filters[1].res_comp {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+            PARAM_FILTER2_DRIVE_POSITION => {
+                drive_position_to_denorm(params.filters[1].drive_position)
+            }
 
             PARAM_FILTER3_TYPE => filter_type_to_denorm(params.filters[2].filter_type),
             PARAM_FILTER3_CUTOFF => params.filters[2].cutoff,
@@ -791,6 +1282,17 @@ pub mod param_get {
             PARAM_FILTER3_ENV_AMOUNT => params.filters[2].envelope.amount,
             PARAM_FILTER3_DRIVE => params.filters[2].drive,
             PARAM_FILTER3_POST_DRIVE => params.filters[2].post_drive,
+            PARAM_FILTER3_CUTOFF_SLEW => params.filters[2].cutoff_slew_ms,
+            PARAM_FILTER3_RES_COMP => {
+                if params.filters[2].res_comp {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+            PARAM_FILTER3_DRIVE_POSITION => {
+                drive_position_to_denorm(params.filters[2].drive_position)
+            }
 
             // LFOs
             PARAM_LFO1_WAVEFORM => lfo_waveform_to_denorm(params.lfos[0].waveform),
@@ -802,6 +1304,14 @@ pub mod param_get {
             PARAM_LFO1_GAIN_AMOUNT => params.lfos[0].gain_amount,
             PARAM_LFO1_PAN_AMOUNT => params.lfos[0].pan_amount,
             PARAM_LFO1_PWM_AMOUNT => params.lfos[0].pwm_amount,
+            PARAM_LFO1_PULSE_WIDTH => params.lfos[0].pulse_width,
+            PARAM_LFO1_UNIPOLAR => {
+                if params.lfos[0].unipolar {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
             PARAM_LFO1_DESTINATION => lfo_destination_to_denorm(params.lfos[0].destination),
 
             PARAM_LFO2_WAVEFORM => lfo_waveform_to_denorm(params.lfos[1].waveform),
@@ -813,6 +1323,14 @@ pub mod param_get {
             PARAM_LFO2_GAIN_AMOUNT => params.lfos[1].gain_amount,
             PARAM_LFO2_PAN_AMOUNT => params.lfos[1].pan_amount,
             PARAM_LFO2_PWM_AMOUNT => params.lfos[1].pwm_amount,
+            PARAM_LFO2_PULSE_WIDTH => params.lfos[1].pulse_width,
+            PARAM_LFO2_UNIPOLAR => {
+                if params.lfos[1].unipolar {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
             PARAM_LFO2_DESTINATION => lfo_destination_to_denorm(params.lfos[1].destination),
 
             PARAM_LFO3_WAVEFORM => lfo_waveform_to_denorm(params.lfos[2].waveform),
@@ -824,6 +1342,14 @@ pub mod param_get {
             PARAM_LFO3_GAIN_AMOUNT => params.lfos[2].gain_amount,
             PARAM_LFO3_PAN_AMOUNT => params.lfos[2].pan_amount,
             PARAM_LFO3_PWM_AMOUNT => params.lfos[2].pwm_amount,
+            PARAM_LFO3_PULSE_WIDTH => params.lfos[2].pulse_width,
+            PARAM_LFO3_UNIPOLAR => {
+                if params.lfos[2].unipolar {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
             PARAM_LFO3_DESTINATION => lfo_destination_to_denorm(params.lfos[2].destination),
 
             // Envelope
@@ -834,10 +1360,15 @@ pub mod param_get {
             PARAM_ENVELOPE_ATTACK_CURVE => params.envelope.attack_curve,
             PARAM_ENVELOPE_DECAY_CURVE => params.envelope.decay_curve,
             PARAM_ENVELOPE_RELEASE_CURVE => params.envelope.release_curve,
+            PARAM_ENVELOPE_KEY_SCALING => params.envelope.key_scaling,
 
             // Velocity
             PARAM_VELOCITY_AMP => params.velocity.amp_sensitivity,
             PARAM_VELOCITY_FILTER => params.velocity.filter_sensitivity,
+            PARAM_VELOCITY_ATTACK => params.velocity.attack_sensitivity,
+            PARAM_VELOCITY_DECAY => params.velocity.decay_sensitivity,
+            PARAM_VELOCITY_OSC_XFADE => params.velocity.osc_crossfade,
+            PARAM_VELOCITY_RELEASE => params.velocity.release_sensitivity,
 
             // Effects - Reverb
             PARAM_REVERB_ROOM_SIZE => params.effects.reverb.room_size,
@@ -845,10 +1376,17 @@ pub mod param_get {
             PARAM_REVERB_WET => params.effects.reverb.wet,
             PARAM_REVERB_DRY => params.effects.reverb.dry,
             PARAM_REVERB_WIDTH => params.effects.reverb.width,
+            PARAM_REVERB_ALGORITHM => reverb_algorithm_to_denorm(params.effects.reverb.algorithm),
+            PARAM_REVERB_ROUTING => effect_routing_to_denorm(params.effects.reverb.routing),
 
             // Effects - Delay
             PARAM_DELAY_TIME_MS => params.effects.delay.time_ms,
+            PARAM_DELAY_TEMPO_SYNC => tempo_sync_to_denorm(params.effects.delay.tempo_sync),
             PARAM_DELAY_FEEDBACK => params.effects.delay.feedback,
+            PARAM_DELAY_FEEDBACK_CURVE => {
+                feedback_curve_to_denorm(params.effects.delay.feedback_curve)
+            }
+            PARAM_DELAY_ROUTING => effect_routing_to_denorm(params.effects.delay.routing),
             PARAM_DELAY_WET => params.effects.delay.wet,
             PARAM_DELAY_DRY => params.effects.delay.dry,
 
@@ -857,6 +1395,8 @@ pub mod param_get {
             PARAM_CHORUS_TEMPO_SYNC => tempo_sync_to_denorm(params.effects.chorus.tempo_sync),
             PARAM_CHORUS_DEPTH => params.effects.chorus.depth,
             PARAM_CHORUS_MIX => params.effects.chorus.mix,
+            PARAM_CHORUS_VOICES => params.effects.chorus.voices as f32,
+            PARAM_CHORUS_SPREAD => params.effects.chorus.spread,
 
             // Effects - Distortion
             PARAM_DISTORTION_TYPE => distortion_type_to_denorm(params.effects.distortion.dist_type),
@@ -904,6 +1444,91 @@ pub mod param_get {
                 }
             }
 
+            // Additive level compensation
+            PARAM_OSC1_ADDITIVE_NORMALIZE => {
+                if params.oscillators[0].additive_normalize {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+            PARAM_OSC1_ADDITIVE_GAIN => params.oscillators[0].additive_gain,
+            PARAM_OSC2_ADDITIVE_NORMALIZE => {
+                if params.oscillators[1].additive_normalize {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+            PARAM_OSC2_ADDITIVE_GAIN => params.oscillators[1].additive_gain,
+            PARAM_OSC3_ADDITIVE_NORMALIZE => {
+                if params.oscillators[2].additive_normalize {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+            PARAM_OSC3_ADDITIVE_GAIN => params.oscillators[2].additive_gain,
+
+            // Oscillator → filter routing
+            PARAM_OSC1_FILTER_ROUTING => {
+                filter_routing_to_denorm(params.oscillators[0].filter_routing)
+            }
+            PARAM_OSC2_FILTER_ROUTING => {
+                filter_routing_to_denorm(params.oscillators[1].filter_routing)
+            }
+            PARAM_OSC3_FILTER_ROUTING => {
+                filter_routing_to_denorm(params.oscillators[2].filter_routing)
+            }
+
+            // Pluck (Karplus-Strong) string tone
+            PARAM_OSC1_PLUCK_DAMPING => params.oscillators[0].pluck_damping,
+            PARAM_OSC1_PLUCK_BRIGHTNESS => params.oscillators[0].pluck_brightness,
+            PARAM_OSC2_PLUCK_DAMPING => params.oscillators[1].pluck_damping,
+            PARAM_OSC2_PLUCK_BRIGHTNESS => params.oscillators[1].pluck_brightness,
+            PARAM_OSC3_PLUCK_DAMPING => params.oscillators[2].pluck_damping,
+            PARAM_OSC3_PLUCK_BRIGHTNESS => params.oscillators[2].pluck_brightness,
+
+            // Fixed-frequency mode
+            PARAM_OSC1_FIXED_FREQ => fixed_freq_enabled_to_denorm(&params.oscillators[0]),
+            PARAM_OSC2_FIXED_FREQ => fixed_freq_enabled_to_denorm(&params.oscillators[1]),
+            PARAM_OSC3_FIXED_FREQ => fixed_freq_enabled_to_denorm(&params.oscillators[2]),
+            PARAM_OSC1_FIXED_FREQ_HZ => fixed_freq_hz(&params.oscillators[0]),
+            PARAM_OSC2_FIXED_FREQ_HZ => fixed_freq_hz(&params.oscillators[1]),
+            PARAM_OSC3_FIXED_FREQ_HZ => fixed_freq_hz(&params.oscillators[2]),
+
+            // Pre-filter sample-rate reduction
+            PARAM_OSC1_SAMPLE_REDUCE => params.oscillators[0].sample_reduce,
+            PARAM_OSC2_SAMPLE_REDUCE => params.oscillators[1].sample_reduce,
+            PARAM_OSC3_SAMPLE_REDUCE => params.oscillators[2].sample_reduce,
+
+            // Extended additive harmonics, harmonic count and slope macro
+            PARAM_OSC1_H9..=PARAM_OSC1_H32 => {
+                params.oscillators[0].additive_harmonic((param_id - PARAM_OSC1_H9) as usize + 8)
+            }
+            PARAM_OSC2_H9..=PARAM_OSC2_H32 => {
+                params.oscillators[1].additive_harmonic((param_id - PARAM_OSC2_H9) as usize + 8)
+            }
+            PARAM_OSC3_H9..=PARAM_OSC3_H32 => {
+                params.oscillators[2].additive_harmonic((param_id - PARAM_OSC3_H9) as usize + 8)
+            }
+            PARAM_OSC1_HARMONIC_COUNT => params.oscillators[0].additive_harmonic_count as f32,
+            PARAM_OSC2_HARMONIC_COUNT => params.oscillators[1].additive_harmonic_count as f32,
+            PARAM_OSC3_HARMONIC_COUNT => params.oscillators[2].additive_harmonic_count as f32,
+            PARAM_OSC1_HARMONIC_SLOPE => params.oscillators[0].additive_slope,
+            PARAM_OSC2_HARMONIC_SLOPE => params.oscillators[1].additive_slope,
+            PARAM_OSC3_HARMONIC_SLOPE => params.oscillators[2].additive_slope,
+
+            // Self-feedback FM
+            PARAM_OSC1_FM_FEEDBACK => params.oscillators[0].fm_feedback,
+            PARAM_OSC2_FM_FEEDBACK => params.oscillators[1].fm_feedback,
+            PARAM_OSC3_FM_FEEDBACK => params.oscillators[2].fm_feedback,
+
+            // Gain smoothing
+            PARAM_OSC1_GAIN_SLEW => params.oscillators[0].gain_slew_ms,
+            PARAM_OSC2_GAIN_SLEW => params.oscillators[1].gain_slew_ms,
+            PARAM_OSC3_GAIN_SLEW => params.oscillators[2].gain_slew_ms,
+
             // New Effects - Phaser
             PARAM_PHASER_RATE => params.effects.phaser.rate,
             PARAM_PHASER_TEMPO_SYNC => tempo_sync_to_denorm(params.effects.phaser.tempo_sync),
@@ -942,6 +1567,22 @@ pub mod param_get {
             PARAM_COMPRESSOR_RATIO => params.effects.compressor.ratio,
             PARAM_COMPRESSOR_ATTACK => params.effects.compressor.attack,
             PARAM_COMPRESSOR_RELEASE => params.effects.compressor.release,
+            PARAM_COMPRESSOR_LOOKAHEAD => params.effects.compressor.lookahead,
+            PARAM_COMPRESSOR_KNEE => params.effects.compressor.knee,
+            PARAM_COMPRESSOR_ADAPTIVE => {
+                if params.effects.compressor.adaptive {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+            PARAM_COMPRESSOR_STEREO_LINK => {
+                if params.effects.compressor.stereo_link {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
 
             // New Effects - Bitcrusher
             PARAM_BITCRUSHER_RATE => params.effects.bitcrusher.sample_rate,
@@ -955,6 +1596,56 @@ pub mod param_get {
             PARAM_EXCITER_FREQUENCY => params.effects.exciter.frequency,
             PARAM_EXCITER_DRIVE => params.effects.exciter.drive,
             PARAM_EXCITER_MIX => params.effects.exciter.mix,
+            PARAM_EXCITER_INTELLIGENT => {
+                if params.effects.exciter.intelligent {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+
+            // Formant Filter
+            PARAM_FORMANT_VOWEL => vowel_to_denorm(params.effects.formant_filter.vowel),
+            PARAM_FORMANT_MORPH => params.effects.formant_filter.morph,
+            PARAM_FORMANT_MIX => params.effects.formant_filter.mix,
+
+            // Pitch Shifter
+            PARAM_PITCH_SHIFTER_SEMITONES => params.effects.pitch_shifter.semitones,
+            PARAM_PITCH_SHIFTER_MIX => params.effects.pitch_shifter.mix,
+
+            // Noise Gate
+            PARAM_GATE_THRESHOLD => params.effects.gate.threshold,
+            PARAM_GATE_RELEASE => params.effects.gate.release,
+            PARAM_GATE_SMART => {
+                if params.effects.gate.smart {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+
+            // Sibilance EQ
+            PARAM_SIBILANCE_EQ_THRESHOLD => params.effects.sibilance_eq.threshold,
+            PARAM_SIBILANCE_EQ_AMOUNT => params.effects.sibilance_eq.amount,
+
+            // Master Transient Shaper
+            PARAM_MASTER_TRANSIENT_ATTACK => params.effects.transient_shaper.attack,
+            PARAM_MASTER_TRANSIENT_SUSTAIN => params.effects.transient_shaper.sustain,
+
+            // Mid/Side
+            PARAM_MID_SIDE_MID_GAIN => params.effects.mid_side.mid_gain,
+            PARAM_MID_SIDE_SIDE_GAIN => params.effects.mid_side.side_gain,
+            PARAM_MID_SIDE_LOW_CUT => params.effects.mid_side.side_low_cut,
+
+            // Doubler
+            PARAM_DOUBLER_MIX => params.effects.doubler.mix,
+            PARAM_DOUBLER_SPREAD => params.effects.doubler.spread,
+
+            // Choir
+            PARAM_CHOIR_VOICES => params.effects.choir.voices as f32,
+            PARAM_CHOIR_DETUNE => params.effects.choir.detune,
+            PARAM_CHOIR_SPREAD => params.effects.choir.spread,
+            PARAM_CHOIR_MIX => params.effects.choir.mix,
 
             // Effect enable/disable toggles
             PARAM_PHASER_ENABLED => {
@@ -1027,6 +1718,62 @@ pub mod param_get {
                     0.0
                 }
             }
+            PARAM_FORMANT_ENABLED => {
+                if params.effects.formant_filter.enabled {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+            PARAM_PITCH_SHIFTER_ENABLED => {
+                if params.effects.pitch_shifter.enabled {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+            PARAM_GATE_ENABLED => {
+                if params.effects.gate.enabled {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+            PARAM_SIBILANCE_EQ_ENABLED => {
+                if params.effects.sibilance_eq.enabled {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+            PARAM_MASTER_TRANSIENT_ENABLED => {
+                if params.effects.transient_shaper.enabled {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+            PARAM_MID_SIDE_ENABLED => {
+                if params.effects.mid_side.enabled {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+            PARAM_DOUBLER_ENABLED => {
+                if params.effects.doubler.enabled {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+            PARAM_CHOIR_ENABLED => {
+                if params.effects.choir.enabled {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
             PARAM_REVERB_ENABLED => {
                 if params.effects.reverb.enabled {
                     1.0
@@ -1096,10 +1843,107 @@ pub mod param_get {
             PARAM_TRANSIENT_ATTACK => params.transient_shaper.attack_boost,
             PARAM_TRANSIENT_SUSTAIN => params.transient_shaper.sustain_reduction,
 
+            // Voice click
+            PARAM_VOICE_CLICK_ENABLED => {
+                if params.voice_click_enabled {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+            PARAM_VOICE_CLICK_LEVEL => params.voice_click_level,
+            PARAM_VOICE_CLICK_LENGTH => params.voice_click_length_ms,
+            PARAM_VOICE_CLICK_HIGHPASS => params.voice_click_highpass_hz,
+
             _ => 0.0,
         }
     }
 
+    fn reverb_algorithm_to_denorm(algorithm: crate::params::ReverbAlgorithm) -> f32 {
+        use crate::params::ReverbAlgorithm;
+        match algorithm {
+            ReverbAlgorithm::Room => 0.0,
+            ReverbAlgorithm::Hall => 1.0,
+            ReverbAlgorithm::Plate => 2.0,
+        }
+    }
+
+    fn vowel_to_denorm(vowel: crate::params::Vowel) -> f32 {
+        use crate::params::Vowel;
+        match vowel {
+            Vowel::A => 0.0,
+            Vowel::E => 1.0,
+            Vowel::I => 2.0,
+            Vowel::O => 3.0,
+            Vowel::U => 4.0,
+        }
+    }
+
+    fn quality_to_denorm(quality: crate::params::Quality) -> f32 {
+        use crate::params::Quality;
+        match quality {
+            Quality::Draft => 0.0,
+            Quality::Normal => 1.0,
+            Quality::High => 2.0,
+        }
+    }
+
+    fn filter_topology_to_denorm(topology: crate::params::FilterTopology) -> f32 {
+        use crate::params::FilterTopology;
+        match topology {
+            FilterTopology::Parallel => 0.0,
+            FilterTopology::Serial => 1.0,
+        }
+    }
+
+    fn drive_position_to_denorm(position: crate::params::DrivePosition) -> f32 {
+        use crate::params::DrivePosition;
+        match position {
+            DrivePosition::Pre => 0.0,
+            DrivePosition::Post => 1.0,
+        }
+    }
+
+    fn filter_routing_to_denorm(routing: crate::params::FilterRouting) -> f32 {
+        use crate::params::FilterRouting;
+        match routing {
+            FilterRouting::Paired => 0.0,
+            FilterRouting::Filter1 => 1.0,
+            FilterRouting::Filter2 => 2.0,
+            FilterRouting::Filter3 => 3.0,
+            FilterRouting::Bypass => 4.0,
+        }
+    }
+
+    fn fixed_freq_enabled_to_denorm(osc: &crate::params::OscillatorParams) -> f32 {
+        if osc.fixed_freq.is_some() {
+            1.0
+        } else {
+            0.0
+        }
+    }
+
+    fn fixed_freq_hz(osc: &crate::params::OscillatorParams) -> f32 {
+        osc.fixed_freq
+            .unwrap_or(crate::params::OscillatorParams::DEFAULT_FIXED_FREQ_HZ)
+    }
+
+    fn effect_routing_to_denorm(routing: crate::params::EffectRouting) -> f32 {
+        use crate::params::EffectRouting;
+        match routing {
+            EffectRouting::Series => 0.0,
+            EffectRouting::Send => 1.0,
+        }
+    }
+
+    fn feedback_curve_to_denorm(curve: crate::params::FeedbackCurve) -> f32 {
+        use crate::params::FeedbackCurve;
+        match curve {
+            FeedbackCurve::Linear => 0.0,
+            FeedbackCurve::Perceptual => 1.0,
+        }
+    }
+
     fn distortion_type_to_denorm(dt: crate::params::DistortionType) -> f32 {
         use crate::params::DistortionType;
         match dt {
@@ -1128,6 +1972,9 @@ pub mod param_get {
             Waveform::PinkNoise => 6.0,
             Waveform::Additive => 7.0,
             Waveform::Wavetable => 8.0,
+            Waveform::AnalogSaw => 9.0,
+            Waveform::AnalogSquare => 10.0,
+            Waveform::Pluck => 11.0,
         }
     }
 
@@ -1224,4 +2071,73 @@ mod tests {
         param_apply::apply_param(&mut params, PARAM_MASTER_GAIN, 0.5);
         // Should not crash
     }
+
+    #[test]
+    fn test_macro_drives_assigned_targets() {
+        use super::super::param_descriptor::*;
+        let mut params = SynthParams::default();
+        params.macros[1].assign(PARAM_FILTER1_RESONANCE, 0.2, 0.6);
+        params.macros[1].assign(PARAM_MASTER_GAIN, 1.0, 0.0);
+        // Ignored: a macro can't drive another macro
+        params.macros[1].assign(PARAM_MACRO1, 0.0, 1.0);
+
+        param_apply::apply_param(&mut params, PARAM_MACRO2, 0.5);
+        assert_eq!(param_get::get_param(&params, PARAM_MACRO2), 0.5);
+        let resonance = get_normalized(&params, PARAM_FILTER1_RESONANCE);
+        assert!((resonance - 0.4).abs() < 1e-4, "resonance at {}", resonance);
+        let gain = get_normalized(&params, PARAM_MASTER_GAIN);
+        assert!(
+            (gain - 0.5).abs() < 1e-4,
+            "inverted range: gain at {}",
+            gain
+        );
+        assert_eq!(params.macros[0].value, 0.0);
+
+        // Untouched macros leave the patch alone
+        let before = params;
+        param_apply::apply_param(&mut params, PARAM_MACRO3, 1.0);
+        assert_eq!(params.filters, before.filters);
+        assert_eq!(params.master_gain, before.master_gain);
+    }
+
+    #[test]
+    fn test_saved_harmonic_edits_survive_the_slope() {
+        use super::super::param_descriptor::*;
+        let registry = super::super::param_registry::get_registry();
+        let mut params = SynthParams::default();
+        let slope = registry.get(PARAM_OSC1_HARMONIC_SLOPE).unwrap();
+        param_apply::apply_param(
+            &mut params,
+            PARAM_OSC1_HARMONIC_SLOPE,
+            slope.normalize_value(2.0),
+        );
+        param_apply::apply_param(&mut params, PARAM_OSC1_H3, 0.9);
+        param_apply::apply_param(&mut params, PARAM_OSC1_H9 + 3, 0.7);
+
+        // Save, with the slope coming after the harmonics it would overwrite
+        let mut saved: Vec<_> = registry
+            .iter_ids()
+            .map(|id| (id, get_normalized(&params, id)))
+            .collect();
+        saved.sort_by_key(|&(id, _)| id == PARAM_OSC1_HARMONIC_SLOPE);
+
+        let mut loaded = SynthParams::default();
+        param_apply::apply_saved_params(&mut loaded, saved);
+        let (osc, saved_osc) = (&loaded.oscillators[0], &params.oscillators[0]);
+        assert_eq!(osc.additive_harmonics, saved_osc.additive_harmonics);
+        assert_eq!(
+            osc.additive_harmonics_high,
+            saved_osc.additive_harmonics_high
+        );
+        assert!((get_normalized(&loaded, PARAM_OSC1_H3) - 0.9).abs() < 1e-4);
+        assert!((get_normalized(&loaded, PARAM_OSC1_H9 + 3) - 0.7).abs() < 1e-4);
+    }
+
+    fn get_normalized(params: &SynthParams, id: ParamId) -> f32 {
+        let registry = super::super::param_registry::get_registry();
+        registry
+            .get(id)
+            .unwrap()
+            .normalize_value(param_get::get_param(params, id))
+    }
 }
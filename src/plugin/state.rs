@@ -9,10 +9,22 @@ use serde::{Deserialize, Serialize};
 use std::error::Error;
 use std::fmt;
 
-use crate::params::SynthParams;
+use crate::params::{MacroParams, SynthParams};
+use crate::plugin::param_descriptor::{
+    ParamId, PARAM_ENVELOPE_ATTACK_CURVE, PARAM_ENVELOPE_DECAY_CURVE, PARAM_ENVELOPE_RELEASE_CURVE,
+};
 
 /// Current plugin state format version
-const STATE_VERSION: u32 = 1;
+///
+/// Version 2 flipped the envelope curve sign: a positive curve now starts slowly.
+const STATE_VERSION: u32 = 2;
+
+/// Envelope curve parameters whose sign flipped in state version 2
+pub const ENVELOPE_CURVE_PARAMS: [ParamId; 3] = [
+    PARAM_ENVELOPE_ATTACK_CURVE,
+    PARAM_ENVELOPE_DECAY_CURVE,
+    PARAM_ENVELOPE_RELEASE_CURVE,
+];
 
 /// Maximum state size (10MB safety limit)
 const MAX_STATE_SIZE: usize = 10 * 1024 * 1024;
@@ -112,6 +124,11 @@ impl PluginState {
         let state: PluginState = bincode::deserialize(data)
             .map_err(|e| StateError::DeserializationError(e.to_string()))?;
 
+        // v1 has the same layout, only the envelope curve sign differs
+        if state.version == 1 {
+            return Ok(PresetMigration::upgrade_to_v2(state));
+        }
+
         // Check version for forward compatibility
         if state.version != STATE_VERSION {
             return Err(StateError::VersionMismatch {
@@ -154,7 +171,7 @@ pub struct PresetMigration;
 impl PresetMigration {
     /// Migrate a preset JSON string to the current `PluginState` schema.
     ///
-    /// Currently only provides a best-effort path for v0 → v1.
+    /// Provides a best-effort path for v0 → v1 and flips envelope curves for v1 → v2.
     pub fn migrate_if_needed(json: &str) -> Result<PluginState, StateError> {
         // Try to parse as current version first
         if let Ok(state) = PluginState::from_json(json) {
@@ -168,25 +185,39 @@ impl PresetMigration {
         let version = v.get("version").and_then(|v| v.as_u64()).unwrap_or(0) as u32;
 
         match version {
-            1 => {
+            2 => {
                 // Already current version, just deserialize
                 PluginState::from_json(json)
             }
-            0 | 2..=u32::MAX => {
-                // Either no version or future version - try to coerce into v1.
+            0 | 1 => {
+                // Saved before the envelope curve sign flipped
+                Self::upgrade_to_v1(v).map(Self::upgrade_to_v2)
+            }
+            3..=u32::MAX => {
+                // Future version - try to coerce into the current format.
                 Self::upgrade_to_v1(v)
             }
         }
     }
 
+    /// Upgrade a v1 state to v2 by flipping its envelope curves
+    fn upgrade_to_v2(mut state: PluginState) -> PluginState {
+        flip_legacy_envelope_curves(&mut state.params);
+        state.version = STATE_VERSION;
+        state
+    }
+
     /// Upgrade a v0 preset to v1 format
     fn upgrade_to_v1(value: serde_json::Value) -> Result<PluginState, StateError> {
         // Extract parameters from v0 format
         // This is a placeholder - actual migration would depend on v0 schema
         let params_value = value
             .get("params")
+            .filter(|params| params.is_object())
             .cloned()
-            .unwrap_or(serde_json::json!({}));
+            .ok_or_else(|| {
+                StateError::InvalidData("Not a DSynth preset (no parameters)".to_string())
+            })?;
         let params: SynthParams = serde_json::from_value(params_value).map_err(|e| {
             StateError::DeserializationError(format!("Failed to migrate preset: {}", e))
         })?;
@@ -204,6 +235,31 @@ impl PresetMigration {
     }
 }
 
+/// Convert envelope curves saved before state version 2
+///
+/// A positive curve used to start fast and now starts slowly, so negating the curves (and
+/// mirroring macro ranges that sweep them) keeps old patches sounding the same.
+pub fn flip_legacy_envelope_curves(params: &mut SynthParams) {
+    let envelope = &mut params.envelope;
+    envelope.attack_curve = -envelope.attack_curve;
+    envelope.decay_curve = -envelope.decay_curve;
+    envelope.release_curve = -envelope.release_curve;
+    flip_legacy_curve_macros(&mut params.macros);
+}
+
+/// Mirror the normalized ranges of macro assignments targeting an envelope curve
+pub fn flip_legacy_curve_macros(macros: &mut [MacroParams]) {
+    for assignment in macros.iter_mut().flat_map(|m| m.assignments.iter_mut()) {
+        if assignment
+            .param_id
+            .is_some_and(|id| ENVELOPE_CURVE_PARAMS.contains(&id))
+        {
+            assignment.min = 1.0 - assignment.min;
+            assignment.max = 1.0 - assignment.max;
+        }
+    }
+}
+
 /// Preset manager for loading/saving user presets
 pub struct PresetManager;
 
@@ -217,6 +273,26 @@ impl PresetManager {
             .and_then(|contents| PresetMigration::migrate_if_needed(&contents))
     }
 
+    /// Load a preset dropped onto (or picked in) the GUI
+    ///
+    /// Checks the `.json` extension before reading so a stray audio or image file gets a
+    /// clear error instead of a JSON parse message.
+    pub fn import_preset(path: &std::path::Path) -> Result<PluginState, StateError> {
+        if !path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("json"))
+        {
+            return Err(StateError::InvalidData(format!(
+                "{} is not a .json preset",
+                path.display()
+            )));
+        }
+        let path = path
+            .to_str()
+            .ok_or_else(|| StateError::InvalidData("Preset path is not valid UTF-8".to_string()))?;
+        Self::load_preset(path)
+    }
+
     /// Save a preset to file
     pub fn save_preset(state: &PluginState, path: &str) -> Result<(), StateError> {
         let json = state.to_json()?;
@@ -252,9 +328,57 @@ impl PresetManager {
     }
 }
 
+/// An ordered bank of presets addressed by MIDI program number
+///
+/// Loaded up front (off the audio thread) so program changes only copy parameters.
+#[derive(Debug, Clone, Default)]
+pub struct PresetBank {
+    presets: Vec<PluginState>,
+}
+
+impl PresetBank {
+    /// Create a bank from already-loaded presets (program 0 is the first entry)
+    pub fn from_presets(presets: Vec<PluginState>) -> Self {
+        Self { presets }
+    }
+
+    /// Load every `.json` preset in a directory, ordered by file name
+    ///
+    /// Files that fail to load are skipped; later presets move down one program number.
+    pub fn load_dir(dir: &str) -> Result<Self, StateError> {
+        let mut names = PresetManager::list_presets(dir)?;
+        names.sort();
+
+        let presets = names
+            .iter()
+            .filter_map(|name| {
+                let path = std::path::Path::new(dir).join(format!("{}.json", name));
+                PresetManager::load_preset(path.to_str()?).ok()
+            })
+            .collect();
+        Ok(Self { presets })
+    }
+
+    /// Number of programs in the bank
+    pub fn len(&self) -> usize {
+        self.presets.len()
+    }
+
+    /// Whether the bank has no programs
+    pub fn is_empty(&self) -> bool {
+        self.presets.is_empty()
+    }
+
+    /// Get the preset for a program number (None if out of range)
+    pub fn get(&self, program: u8) -> Option<&PluginState> {
+        self.presets.get(program as usize)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use approx::assert_relative_eq;
 
     #[test]
     fn test_plugin_state_creation() {
@@ -315,6 +439,75 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_v1_presets_keep_their_envelope_curves() {
+        let mut state = PluginState::from_params(SynthParams::default(), None);
+        state.params_mut().envelope.attack_curve = 0.6;
+        state.params_mut().envelope.release_curve = -0.4;
+        state.params_mut().macros[0].assign(PARAM_ENVELOPE_DECAY_CURVE, 0.2, 0.9);
+        state.version = 1;
+
+        let json = state.to_json().unwrap();
+        let migrated = PresetMigration::migrate_if_needed(&json).unwrap();
+        assert_eq!(migrated.version, STATE_VERSION);
+        assert_eq!(migrated.params().envelope.attack_curve, -0.6);
+        assert_eq!(migrated.params().envelope.release_curve, 0.4);
+        let (_, decay) = migrated.params().macros[0].targets().next().unwrap();
+        assert_relative_eq!(decay.min, 0.8, epsilon = 1e-6);
+        assert_relative_eq!(decay.max, 0.1, epsilon = 1e-6);
+
+        let restored = PluginState::from_bytes(&state.to_bytes().unwrap()).unwrap();
+        assert_eq!(restored.params().envelope.attack_curve, -0.6);
+
+        // Current presets load untouched
+        let current = PresetMigration::migrate_if_needed(&migrated.to_json().unwrap()).unwrap();
+        assert_eq!(current.params().envelope.attack_curve, -0.6);
+    }
+
+    #[test]
+    fn test_preset_bank_orders_by_file_name() {
+        let dir = tempfile::tempdir().unwrap();
+        for (file, gain) in [("b_second", 0.5), ("a_first", 0.25), ("c_third", 0.75)] {
+            let mut state = PluginState::from_params(SynthParams::default(), None);
+            state.params_mut().master_gain = gain;
+            let path = dir.path().join(format!("{}.json", file));
+            PresetManager::save_preset(&state, path.to_str().unwrap()).unwrap();
+        }
+
+        let bank = PresetBank::load_dir(dir.path().to_str().unwrap()).unwrap();
+        assert_eq!(bank.len(), 3);
+        assert_eq!(bank.get(0).unwrap().params().master_gain, 0.25);
+        assert_eq!(bank.get(1).unwrap().params().master_gain, 0.5);
+        assert_eq!(bank.get(2).unwrap().params().master_gain, 0.75);
+        assert!(bank.get(3).is_none());
+    }
+
+    #[test]
+    fn test_import_rejects_incompatible_files() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let wav = dir.path().join("take.wav");
+        std::fs::write(&wav, b"RIFF").unwrap();
+        assert!(matches!(
+            PresetManager::import_preset(&wav),
+            Err(StateError::InvalidData(_))
+        ));
+
+        let other_json = dir.path().join("settings.json");
+        std::fs::write(&other_json, r#"{"theme": "dark"}"#).unwrap();
+        assert!(matches!(
+            PresetManager::import_preset(&other_json),
+            Err(StateError::InvalidData(_))
+        ));
+
+        let mut state = PluginState::from_params(SynthParams::default(), None);
+        state.params_mut().master_gain = 0.3;
+        let preset = dir.path().join("patch.JSON");
+        PresetManager::save_preset(&state, preset.to_str().unwrap()).unwrap();
+        let imported = PresetManager::import_preset(&preset).unwrap();
+        assert_eq!(imported.params().master_gain, 0.3);
+    }
+
     #[test]
     fn test_preset_default() {
         let preset = PresetManager::default_preset();
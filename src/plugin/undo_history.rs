@@ -0,0 +1,153 @@
+use super::param_descriptor::ParamId;
+use crate::params::SynthParams;
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+/// Maximum number of undo steps kept (oldest are dropped first)
+pub const MAX_UNDO_STEPS: usize = 100;
+
+/// Changes to the same parameter closer together than this merge into one undo step,
+/// so a knob drag (dozens of ParamChanged events) undoes in one go.
+pub const COALESCE_WINDOW: Duration = Duration::from_millis(500);
+
+/// Undo/redo history of whole-`SynthParams` snapshots for the GUI.
+///
+/// The GUI calls `record` with the parameters as they were *before* each committed change.
+/// `undo`/`redo` swap the current parameters for the stored snapshot; the caller loads the
+/// returned `SynthParams` into the engine.
+#[derive(Debug, Clone, Default)]
+pub struct UndoHistory {
+    undo_stack: VecDeque<SynthParams>,
+    redo_stack: Vec<SynthParams>,
+    /// Parameter and time of the last recorded change, for coalescing.
+    /// `None` param means a whole-patch change (randomize, preset), which never coalesces.
+    last_change: Option<(Option<ParamId>, Instant)>,
+}
+
+impl UndoHistory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record the state before a change
+    ///
+    /// # Arguments
+    /// * `param_id` - Parameter being changed, or `None` for whole-patch changes
+    /// * `before` - Parameters before the change is applied
+    /// * `now` - Time of the change (used to coalesce rapid edits of one parameter)
+    pub fn record(&mut self, param_id: Option<ParamId>, before: &SynthParams, now: Instant) {
+        let coalesce = match (param_id, self.last_change) {
+            (Some(id), Some((Some(last_id), at))) => {
+                id == last_id && now.duration_since(at) < COALESCE_WINDOW
+            }
+            _ => false,
+        };
+        self.last_change = Some((param_id, now));
+        if coalesce {
+            return;
+        }
+
+        if self.undo_stack.len() == MAX_UNDO_STEPS {
+            self.undo_stack.pop_front();
+        }
+        self.undo_stack.push_back(*before);
+        self.redo_stack.clear();
+    }
+
+    /// Step back: returns the parameters to load, or `None` if there's nothing to undo
+    pub fn undo(&mut self, current: &SynthParams) -> Option<SynthParams> {
+        let previous = self.undo_stack.pop_back()?;
+        self.redo_stack.push(*current);
+        self.last_change = None;
+        Some(previous)
+    }
+
+    /// Step forward again after an undo
+    pub fn redo(&mut self, current: &SynthParams) -> Option<SynthParams> {
+        let next = self.redo_stack.pop()?;
+        self.undo_stack.push_back(*current);
+        self.last_change = None;
+        Some(next)
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.undo_stack.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redo_stack.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugin::param_descriptor::{PARAM_FILTER1_CUTOFF, PARAM_MASTER_GAIN};
+
+    fn with_gain(gain: f32) -> SynthParams {
+        SynthParams {
+            master_gain: gain,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_undo_redo_round_trip() {
+        let mut history = UndoHistory::new();
+        let start = Instant::now();
+
+        history.record(Some(PARAM_MASTER_GAIN), &with_gain(0.1), start);
+        history.record(
+            Some(PARAM_FILTER1_CUTOFF),
+            &with_gain(0.2),
+            start + Duration::from_millis(10),
+        );
+
+        assert_eq!(history.undo(&with_gain(0.3)), Some(with_gain(0.2)));
+        assert_eq!(history.undo(&with_gain(0.2)), Some(with_gain(0.1)));
+        assert_eq!(history.undo(&with_gain(0.1)), None);
+
+        assert_eq!(history.redo(&with_gain(0.1)), Some(with_gain(0.2)));
+        assert_eq!(history.redo(&with_gain(0.2)), Some(with_gain(0.3)));
+        assert!(!history.can_redo());
+    }
+
+    #[test]
+    fn test_rapid_changes_to_one_param_coalesce() {
+        let mut history = UndoHistory::new();
+        let start = Instant::now();
+
+        // A knob drag: many changes to the same param in quick succession
+        for i in 0..20 {
+            history.record(
+                Some(PARAM_MASTER_GAIN),
+                &with_gain(i as f32 * 0.01),
+                start + Duration::from_millis(i * 20),
+            );
+        }
+        assert_eq!(history.undo(&with_gain(0.5)), Some(with_gain(0.0)));
+        assert!(!history.can_undo(), "Drag should be a single undo step");
+
+        // A pause longer than the window starts a new step
+        history.record(Some(PARAM_MASTER_GAIN), &with_gain(0.6), start);
+        history.record(
+            Some(PARAM_MASTER_GAIN),
+            &with_gain(0.7),
+            start + COALESCE_WINDOW * 2,
+        );
+        assert_eq!(history.undo(&with_gain(0.8)), Some(with_gain(0.7)));
+        assert!(history.can_undo());
+    }
+
+    #[test]
+    fn test_new_change_clears_redo() {
+        let mut history = UndoHistory::new();
+        let start = Instant::now();
+        history.record(None, &with_gain(0.1), start);
+        history.undo(&with_gain(0.2));
+        assert!(history.can_redo());
+
+        history.record(None, &with_gain(0.1), start);
+        assert!(!history.can_redo());
+    }
+}
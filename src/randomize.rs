@@ -1,6 +1,95 @@
 use rand::Rng;
 
-use crate::params::{FilterType, LFOWaveform, SynthParams, Waveform};
+use crate::params::{FilterType, LFOWaveform, SynthParams, Vowel, Waveform};
+
+/// Groups of parameters that can be held fixed while the rest is randomized
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RandomizeSection {
+    Oscillators,
+    /// Filters including their envelopes
+    Filters,
+    Lfos,
+    /// Amp envelope
+    Envelope,
+    /// Effect chain plus the voice compressor and transient shaper
+    Effects,
+}
+
+impl RandomizeSection {
+    pub const ALL: [RandomizeSection; 5] = [
+        RandomizeSection::Oscillators,
+        RandomizeSection::Filters,
+        RandomizeSection::Lfos,
+        RandomizeSection::Envelope,
+        RandomizeSection::Effects,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            RandomizeSection::Oscillators => "Osc",
+            RandomizeSection::Filters => "Filter",
+            RandomizeSection::Lfos => "LFO",
+            RandomizeSection::Envelope => "Env",
+            RandomizeSection::Effects => "FX",
+        }
+    }
+
+    fn bit(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+/// Set of locked sections; locked sections keep their current values when randomizing
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RandomizeLocks(u8);
+
+impl RandomizeLocks {
+    pub fn is_locked(self, section: RandomizeSection) -> bool {
+        self.0 & section.bit() != 0
+    }
+
+    pub fn set(&mut self, section: RandomizeSection, locked: bool) {
+        if locked {
+            self.0 |= section.bit();
+        } else {
+            self.0 &= !section.bit();
+        }
+    }
+
+    pub fn toggle(&mut self, section: RandomizeSection) {
+        self.0 ^= section.bit();
+    }
+}
+
+/// Randomize everything except the sections locked in `locks`, which are copied from `current`
+///
+/// Parameters outside every section (master, velocity) are always randomized. Macro
+/// knobs and their assignments are controller setup rather than sound, so they're kept.
+pub fn randomize_synth_params_locked<R: Rng + ?Sized>(
+    rng: &mut R,
+    current: &SynthParams,
+    locks: RandomizeLocks,
+) -> SynthParams {
+    let mut params = randomize_synth_params(rng);
+    params.macros = current.macros;
+    for section in RandomizeSection::ALL {
+        if !locks.is_locked(section) {
+            continue;
+        }
+        match section {
+            RandomizeSection::Oscillators => params.oscillators = current.oscillators,
+            RandomizeSection::Filters => params.filters = current.filters,
+            RandomizeSection::Lfos => params.lfos = current.lfos,
+            RandomizeSection::Envelope => params.envelope = current.envelope,
+            RandomizeSection::Effects => {
+                params.effects = current.effects;
+                params.voice_compressor = current.voice_compressor;
+                params.transient_shaper = current.transient_shaper;
+            }
+        }
+    }
+    params
+}
 
 /// Generate randomized parameters for sound design exploration.
 ///
@@ -18,6 +107,9 @@ pub fn randomize_synth_params<R: Rng + ?Sized>(rng: &mut R) -> SynthParams {
         Waveform::PinkNoise,
         Waveform::Additive,
         Waveform::Wavetable,
+        Waveform::AnalogSaw,
+        Waveform::AnalogSquare,
+        Waveform::Pluck,
     ];
     let filter_types = [
         FilterType::Lowpass,
@@ -36,6 +128,7 @@ pub fn randomize_synth_params<R: Rng + ?Sized>(rng: &mut R) -> SynthParams {
         DistortionType::HardClip,
         DistortionType::Cubic,
     ];
+    let vowels = [Vowel::A, Vowel::E, Vowel::I, Vowel::O, Vowel::U];
 
     let mut params = SynthParams::default();
 
@@ -78,6 +171,12 @@ pub fn randomize_synth_params<R: Rng + ?Sized>(rng: &mut R) -> SynthParams {
             osc.wavetable_position = rng.gen_range(0.0..=1.0);
         }
 
+        // String tone (for Pluck waveform); keep damping short of a dead thud
+        if osc.waveform == Waveform::Pluck {
+            osc.pluck_damping = rng.gen_range(0.0..=0.7);
+            osc.pluck_brightness = rng.gen_range(0.0..=1.0);
+        }
+
         // Keep solo/other toggles deterministic (default).
     }
 
@@ -113,6 +212,7 @@ pub fn randomize_synth_params<R: Rng + ?Sized>(rng: &mut R) -> SynthParams {
         lfo.gain_amount = rng.gen_range(-0.5..=0.5); // Bipolar gain modulation
         lfo.pan_amount = rng.gen_range(0.0..=0.8); // Pan modulation
         lfo.pwm_amount = rng.gen_range(0.0..=0.7); // PWM/shape modulation
+        lfo.pulse_width = rng.gen_range(0.2..=0.8); // Square duty cycle
     }
 
     // Velocity
@@ -172,6 +272,22 @@ pub fn randomize_synth_params<R: Rng + ?Sized>(rng: &mut R) -> SynthParams {
     params.effects.chorus.depth = rng.gen_range(0.2..=0.8);
     params.effects.chorus.mix = rng.gen_range(0.2..=0.6);
 
+    // Doubler
+    params.effects.doubler.enabled = rng.gen_bool(0.1); // 10% chance enabled
+    if params.effects.doubler.enabled {
+        params.effects.doubler.mix = rng.gen_range(0.3..=0.7);
+        params.effects.doubler.spread = rng.gen_range(0.4..=1.0);
+    }
+
+    // Choir
+    params.effects.choir.enabled = rng.gen_bool(0.1); // 10% chance enabled
+    if params.effects.choir.enabled {
+        params.effects.choir.voices = rng.gen_range(3..=6);
+        params.effects.choir.detune = rng.gen_range(8.0..=25.0);
+        params.effects.choir.spread = rng.gen_range(0.5..=1.0);
+        params.effects.choir.mix = rng.gen_range(0.2..=0.5);
+    }
+
     // Distortion
     params.effects.distortion.enabled = rng.gen_bool(0.3); // 30% chance enabled
     params.effects.distortion.dist_type =
@@ -193,6 +309,14 @@ pub fn randomize_synth_params<R: Rng + ?Sized>(rng: &mut R) -> SynthParams {
         params.effects.multiband_distortion.mix = rng.gen_range(0.3..=0.7);
     }
 
+    // Mid/Side
+    params.effects.mid_side.enabled = rng.gen_bool(0.1); // 10% chance enabled
+    if params.effects.mid_side.enabled {
+        params.effects.mid_side.mid_gain = rng.gen_range(0.8..=1.1);
+        params.effects.mid_side.side_gain = rng.gen_range(0.8..=1.6);
+        params.effects.mid_side.side_low_cut = rng.gen_range(20.0..=200.0);
+    }
+
     // Stereo Widener
     params.effects.stereo_widener.enabled = rng.gen_bool(0.3); // 30% chance enabled
     if params.effects.stereo_widener.enabled {
@@ -250,6 +374,21 @@ pub fn randomize_synth_params<R: Rng + ?Sized>(rng: &mut R) -> SynthParams {
         params.effects.ring_mod.depth = rng.gen_range(0.3..=0.7);
     }
 
+    // Noise Gate (low thresholds, so it only ever catches the tails)
+    params.effects.gate.enabled = rng.gen_bool(0.1); // 10% chance enabled
+    if params.effects.gate.enabled {
+        params.effects.gate.threshold = rng.gen_range(-70.0..=-55.0);
+        params.effects.gate.release = rng.gen_range(50.0..=300.0);
+        params.effects.gate.smart = rng.gen_bool(0.5);
+    }
+
+    // Master Transient Shaper
+    params.effects.transient_shaper.enabled = rng.gen_bool(0.1); // 10% chance enabled
+    if params.effects.transient_shaper.enabled {
+        params.effects.transient_shaper.attack = rng.gen_range(-0.5..=0.8);
+        params.effects.transient_shaper.sustain = rng.gen_range(-0.5..=0.3);
+    }
+
     // Compressor
     params.effects.compressor.enabled = rng.gen_bool(0.3); // 30% chance enabled
     if params.effects.compressor.enabled {
@@ -257,6 +396,8 @@ pub fn randomize_synth_params<R: Rng + ?Sized>(rng: &mut R) -> SynthParams {
         params.effects.compressor.ratio = rng.gen_range(2.0..=8.0);
         params.effects.compressor.attack = rng.gen_range(5.0..=30.0);
         params.effects.compressor.release = rng.gen_range(50.0..=200.0);
+        params.effects.compressor.adaptive = rng.gen_bool(0.3);
+        params.effects.compressor.knee = rng.gen_range(0.0..=9.0);
     }
 
     // Bitcrusher
@@ -279,6 +420,30 @@ pub fn randomize_synth_params<R: Rng + ?Sized>(rng: &mut R) -> SynthParams {
         params.effects.exciter.frequency = rng.gen_range(3000.0..=9000.0);
         params.effects.exciter.drive = rng.gen_range(0.3..=0.7);
         params.effects.exciter.mix = rng.gen_range(0.2..=0.5);
+        params.effects.exciter.intelligent = rng.gen_bool(0.3);
+    }
+
+    // Sibilance EQ
+    params.effects.sibilance_eq.enabled = rng.gen_bool(0.15); // 15% chance enabled
+    if params.effects.sibilance_eq.enabled {
+        params.effects.sibilance_eq.threshold = rng.gen_range(0.3..=0.7);
+        params.effects.sibilance_eq.amount = rng.gen_range(0.3..=0.8);
+    }
+
+    // Formant Filter
+    params.effects.formant_filter.enabled = rng.gen_bool(0.1); // 10% chance enabled
+    if params.effects.formant_filter.enabled {
+        params.effects.formant_filter.vowel = vowels[rng.gen_range(0..vowels.len())];
+        params.effects.formant_filter.morph = rng.gen_range(0.0..=1.0);
+        params.effects.formant_filter.mix = rng.gen_range(0.4..=0.8);
+    }
+
+    // Pitch Shifter (consonant intervals: octaves, fifths, fourths)
+    params.effects.pitch_shifter.enabled = rng.gen_bool(0.1); // 10% chance enabled
+    if params.effects.pitch_shifter.enabled {
+        let intervals = [-12.0, -5.0, 5.0, 7.0, 12.0];
+        params.effects.pitch_shifter.semitones = intervals[rng.gen_range(0..intervals.len())];
+        params.effects.pitch_shifter.mix = rng.gen_range(0.2..=0.5);
     }
 
     params
@@ -328,6 +328,8 @@ impl PluginParams for DsynthVoiceParams {
             is_automatable: desc.automation
                 == crate::plugin::param_descriptor::AutomationState::ReadWrite,
             is_hidden: false,
+            is_modulatable: false,
+            is_modulatable_per_note: false,
         })
     }
 
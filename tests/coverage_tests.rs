@@ -586,13 +586,59 @@ fn test_all_notes_off() {
 
     assert_eq!(engine.active_voice_count(), 8);
 
-    // All notes off
+    // All notes off: every voice fades out over 5 ms, then goes idle
     engine.all_notes_off();
+    for _ in 0..441 {
+        engine.process();
+    }
+    assert_eq!(engine.active_voice_count(), 0);
 
-    // All voices should transition to release (still active)
-    // but trigger note on should work immediately
     engine.note_on(60, 0.8);
     assert_eq!(engine.active_voice_count(), 1);
+
+    // Panic is the hard reset: voices stop on the spot
+    engine.panic();
+    assert_eq!(engine.active_voice_count(), 0);
+}
+
+/// Test that all notes off fades the voices out instead of cutting them mid-waveform.
+#[test]
+fn test_all_notes_off_fades_without_click() {
+    let sample_rate = 44100.0;
+    let (_param_producer, param_consumer) = create_parameter_buffer();
+    let mut engine = SynthEngine::new(sample_rate, param_consumer);
+
+    // Largest sample-to-sample step and largest level over `samples`
+    let render = |engine: &mut SynthEngine, samples: usize| {
+        let mut previous = engine.process().0;
+        let (mut max_step, mut peak) = (0.0_f32, previous.abs());
+        for _ in 1..samples {
+            let left = engine.process().0;
+            max_step = max_step.max((left - previous).abs());
+            peak = peak.max(left.abs());
+            previous = left;
+        }
+        (max_step, peak)
+    };
+
+    for note in [48, 55, 60, 64] {
+        engine.note_on(note, 1.0);
+    }
+    render(&mut engine, 4410);
+    let (steady_step, steady_peak) = render(&mut engine, 441);
+    assert!(steady_peak > 0.05, "The chord should be sounding");
+
+    engine.all_notes_off();
+    let (fade_step, _) = render(&mut engine, 441);
+    assert!(
+        fade_step <= steady_step * 1.1,
+        "The fade moved the output by {} in one sample; the chord's own slope is {}",
+        fade_step,
+        steady_step
+    );
+
+    let (_, tail_peak) = render(&mut engine, 441);
+    assert!(tail_peak < 1e-4, "Silent after the fade: {}", tail_peak);
 }
 
 /// Test that extreme parameter values don't crash the engine.
@@ -649,7 +695,7 @@ fn test_randomize_generates_valid_parameters() {
 
         // Check oscillators
         for osc in &params.oscillators {
-            assert!(osc.waveform as u32 <= 8, "Waveform should be valid enum");
+            assert!(osc.waveform as u32 <= 11, "Waveform should be valid enum");
             assert!(
                 osc.pitch >= -24.0 && osc.pitch <= 24.0,
                 "Pitch should be in semitones ±24"
@@ -741,6 +787,39 @@ fn test_randomize_produces_audible_output() {
     }
 }
 
+/// Test that locked sections survive randomization while the rest changes.
+#[test]
+fn test_randomize_keeps_locked_sections() {
+    use dsynth::randomize::{randomize_synth_params_locked, RandomizeLocks, RandomizeSection};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    let mut rng = StdRng::seed_from_u64(7);
+    let current = SynthParams::default();
+    let mut locks = RandomizeLocks::default();
+    locks.set(RandomizeSection::Oscillators, true);
+    locks.set(RandomizeSection::Effects, true);
+    assert!(locks.is_locked(RandomizeSection::Oscillators));
+    assert!(!locks.is_locked(RandomizeSection::Filters));
+
+    let params = randomize_synth_params_locked(&mut rng, &current, locks);
+    assert_eq!(params.oscillators, current.oscillators);
+    assert_eq!(params.effects, current.effects);
+    assert_eq!(params.voice_compressor, current.voice_compressor);
+    assert_ne!(
+        params.filters, current.filters,
+        "Unlocked filters should change"
+    );
+    assert_ne!(
+        params.envelope, current.envelope,
+        "Unlocked envelope should change"
+    );
+
+    locks.toggle(RandomizeSection::Oscillators);
+    let params = randomize_synth_params_locked(&mut rng, &current, locks);
+    assert_ne!(params.oscillators, current.oscillators);
+}
+
 /// Test that randomize doesn't create "broken" presets.
 #[test]
 fn test_randomize_parameter_ranges() {
@@ -259,6 +259,7 @@ mod optimization_tests {
             attack_curve: 0.0,
             decay_curve: 0.0,
             release_curve: 0.0,
+            key_scaling: 0.0,
         };
         let wavetable_library = dsynth::dsp::WavetableLibrary::new();
         voice.update_parameters(